@@ -6,9 +6,9 @@
 //!
 //! The result matches the output of `samtools idxstats <src>`.
 
-use std::{env, io, path::PathBuf, str};
+use std::{env, io, path::PathBuf};
 
-use noodles_bam as bam;
+use noodles_bam::{self as bam, index_statistics};
 
 fn main() -> io::Result<()> {
     let src = env::args().nth(1).map(PathBuf::from).expect("missing src");
@@ -16,32 +16,19 @@ fn main() -> io::Result<()> {
     let mut reader = bam::io::indexed_reader::Builder::default().build_from_path(src)?;
     let header = reader.read_header()?;
 
-    let index = reader.index();
-
-    for ((reference_sequence_name_buf, reference_sequence), index_reference_sequence) in header
-        .reference_sequences()
-        .iter()
-        .zip(index.reference_sequences())
-    {
-        let reference_sequence_name = str::from_utf8(reference_sequence_name_buf)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-        let (mapped_record_count, unmapped_record_count) = index_reference_sequence
-            .metadata()
-            .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
-            .unwrap_or_default();
+    let statistics = index_statistics::build(&header, reader.index())?;
 
+    for reference_sequence in statistics.reference_sequences() {
         println!(
             "{}\t{}\t{}\t{}",
-            reference_sequence_name,
+            reference_sequence.name(),
             reference_sequence.length(),
-            mapped_record_count,
-            unmapped_record_count
+            reference_sequence.mapped_record_count(),
+            reference_sequence.unmapped_record_count()
         );
     }
 
-    let unmapped_record_count = index.unplaced_unmapped_record_count().unwrap_or_default();
-    println!("*\t0\t0\t{unmapped_record_count}");
+    println!("*\t0\t0\t{}", statistics.unplaced_unmapped_record_count());
 
     Ok(())
 }
@@ -3,7 +3,7 @@ mod query;
 mod record;
 mod record_buf;
 
-use futures::{stream, Stream};
+use futures::{future, stream, Stream, TryStreamExt};
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::BinningIndex;
@@ -35,6 +35,25 @@ use crate::{io::reader::resolve_region, Record, MAGIC_NUMBER};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Set the number of BGZF decoding workers
+///
+/// [`Reader::new`] wraps the input stream with a default async BGZF decoder, which decompresses
+/// blocks concurrently using a worker per available logical CPU. The number of workers can be
+/// set by building the decoder using [`noodles_bgzf::r#async::reader::Builder`] and wrapping it
+/// using [`Reader::from`].
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_bam as bam;
+/// use noodles_bgzf as bgzf;
+/// use tokio::io;
+///
+/// let decoder = bgzf::r#async::reader::Builder::default()
+///     .set_worker_count(NonZeroUsize::MIN)
+///     .build_from_reader(io::empty());
+/// let _reader = bam::r#async::io::Reader::from(decoder);
+/// ```
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
@@ -351,6 +370,52 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns a stream of unmapped records after querying for the unmapped region.
+    ///
+    /// This is the async equivalent of [`crate::io::Reader::query_unmapped`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use futures::TryStreamExt;
+    /// use noodles_bam::{self as bam, bai};
+    /// use tokio::fs::File;
+    ///
+    /// let mut reader = File::open("sample.bam").await.map(bam::AsyncReader::new)?;
+    /// reader.read_header().await?;
+    ///
+    /// let index = bai::r#async::read("sample.bam.bai").await?;
+    /// let mut query = reader.query_unmapped(&index).await?;
+    ///
+    /// while let Some(record) = query.try_next().await? {
+    ///     // ...
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn query_unmapped<I>(
+        &mut self,
+        index: &I,
+    ) -> io::Result<impl Stream<Item = io::Result<Record>> + '_>
+    where
+        I: BinningIndex,
+    {
+        if let Some(pos) = index.last_first_record_start_position() {
+            self.get_mut().seek(pos).await?;
+        } else {
+            self.get_mut()
+                .seek(bgzf::VirtualPosition::default())
+                .await?;
+            self.read_header().await?;
+        }
+
+        Ok(self
+            .records()
+            .try_filter(|record| future::ready(record.flags().is_unmapped())))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -403,4 +468,67 @@ mod tests {
             Err(ref e) if e.kind() == io::ErrorKind::InvalidData
         ));
     }
+
+    #[tokio::test]
+    async fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use noodles_sam::{
+            alignment::{
+                io::Write as _,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::{indexed_writer::Index, IndexedWriter};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder().set_name("r1").build(),
+        ];
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        let Index::Bai(index) = writer.finish(&header)? else {
+            panic!("expected BAI");
+        };
+
+        let src = writer.get_ref().get_ref().clone();
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header().await?;
+
+        let names: Vec<_> = reader
+            .query_unmapped(&index)
+            .await?
+            .map_ok(|record| record.name().map(|name| name.to_owned()))
+            .try_collect()
+            .await?;
+
+        assert_eq!(names, [Some("r1".into())]);
+
+        Ok(())
+    }
 }
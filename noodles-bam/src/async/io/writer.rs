@@ -1,9 +1,13 @@
+//! Async BAM writer.
+
+mod builder;
 mod header;
 
 use noodles_bgzf as bgzf;
 use noodles_sam as sam;
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
+pub use self::builder::Builder;
 use self::header::write_header;
 use crate::Record;
 
@@ -58,6 +62,9 @@ where
 
     /// Shuts down the output stream.
     ///
+    /// When the underlying writer is a BGZF writer, this flushes any pending blocks and writes the
+    /// BGZF EOF block.
+    ///
     /// # Examples
     ///
     /// ```
@@ -192,3 +199,48 @@ impl<W> From<W> for Writer<W> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::alignment::RecordBuf;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_shutdown_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = Builder::default()
+            .set_worker_count(NonZeroUsize::try_from(2)?)
+            .build_from_writer(Vec::new());
+
+        let header = sam::Header::default();
+        writer.write_header(&header).await?;
+
+        let records: Vec<_> = (0..4096)
+            .map(|i| RecordBuf::builder().set_name(format!("r{i}")).build())
+            .collect();
+
+        for record in &records {
+            writer.write_alignment_record(&header, record).await?;
+        }
+
+        writer.shutdown().await?;
+
+        let data = writer.into_inner().into_inner();
+
+        let eof = bgzf::Writer::new(Vec::new()).finish()?;
+        assert!(data.ends_with(&eof));
+
+        let mut reader = crate::io::Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .record_bufs(&header)
+            .collect::<std::io::Result<_>>()?;
+
+        assert_eq!(actual, records);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::{num::NonZeroUsize, path::Path};
+
+use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+use tokio::{
+    fs::File,
+    io::{self, AsyncWrite},
+};
+
+use super::Writer;
+
+/// An async BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    compression_level: Option<CompressionLevel>,
+    worker_count: Option<NonZeroUsize>,
+}
+
+impl Builder {
+    /// Sets the compression level.
+    ///
+    /// By default, the compression level is set to level 6.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_bgzf::writer::CompressionLevel;
+    ///
+    /// let builder = bam::r#async::io::writer::Builder::default()
+    ///     .set_compression_level(CompressionLevel::best());
+    /// ```
+    pub fn set_compression_level(mut self, compression_level: CompressionLevel) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Sets the worker count.
+    ///
+    /// BGZF blocks are compressed on up to this many blocking tasks concurrently. By default, the
+    /// worker count is set to the number of available logical CPUs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bam as bam;
+    ///
+    /// let builder = bam::r#async::io::writer::Builder::default()
+    ///     .set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Builds an async BAM writer from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> tokio::io::Result<()> {
+    /// use noodles_bam as bam;
+    ///
+    /// let writer = bam::r#async::io::writer::Builder::default()
+    ///     .build_from_path("out.bam")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_from_path<P>(self, dst: P) -> io::Result<Writer<bgzf::AsyncWriter<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        File::create(dst)
+            .await
+            .map(|file| self.build_from_writer(file))
+    }
+
+    /// Builds an async BAM writer from an async writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use tokio::io;
+    ///
+    /// let writer = bam::r#async::io::writer::Builder::default()
+    ///     .build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<bgzf::AsyncWriter<W>>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut builder = bgzf::r#async::writer::Builder::default();
+
+        if let Some(compression_level) = self.compression_level {
+            builder = builder.set_compression_level(compression_level);
+        }
+
+        if let Some(worker_count) = self.worker_count {
+            builder = builder.set_worker_count(worker_count);
+        }
+
+        Writer::from(builder.build_from_writer(writer))
+    }
+}
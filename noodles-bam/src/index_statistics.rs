@@ -0,0 +1,193 @@
+//! BAM index statistics.
+
+use std::io;
+
+use bstr::{BStr, BString};
+use noodles_csi::BinningIndex;
+use noodles_sam as sam;
+
+/// Index statistics of a reference sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferenceSequenceStatistics {
+    name: BString,
+    length: usize,
+    mapped_record_count: u64,
+    unmapped_record_count: u64,
+}
+
+impl ReferenceSequenceStatistics {
+    /// Returns the reference sequence name.
+    pub fn name(&self) -> &BStr {
+        self.name.as_ref()
+    }
+
+    /// Returns the reference sequence length.
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Returns the number of mapped records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the number of placed unmapped records.
+    pub fn unmapped_record_count(&self) -> u64 {
+        self.unmapped_record_count
+    }
+}
+
+/// Index statistics.
+///
+/// This is the same data as the output of `samtools idxstats`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct IndexStatistics {
+    reference_sequences: Vec<ReferenceSequenceStatistics>,
+    unplaced_unmapped_record_count: u64,
+}
+
+impl IndexStatistics {
+    /// Returns the statistics of each reference sequence, in header order.
+    pub fn reference_sequences(&self) -> &[ReferenceSequenceStatistics] {
+        &self.reference_sequences
+    }
+
+    /// Returns the number of unplaced unmapped records.
+    pub fn unplaced_unmapped_record_count(&self) -> u64 {
+        self.unplaced_unmapped_record_count
+    }
+}
+
+/// Builds index statistics from a header and an index.
+///
+/// The record counts are read from the index metadata (the pseudo-bin) of each reference
+/// sequence. If an index reference sequence has no metadata, its counts are 0. No records are
+/// read.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::{bai, index_statistics};
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let index = bai::Index::default();
+///
+/// let statistics = index_statistics::build(&header, &index)?;
+/// assert!(statistics.reference_sequences().is_empty());
+/// assert_eq!(statistics.unplaced_unmapped_record_count(), 0);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn build<I>(header: &sam::Header, index: &I) -> io::Result<IndexStatistics>
+where
+    I: BinningIndex + ?Sized,
+{
+    let reference_sequences = header.reference_sequences();
+    let mut index_reference_sequences = index.reference_sequences();
+
+    let mut statistics = Vec::with_capacity(reference_sequences.len());
+
+    for (name, reference_sequence) in reference_sequences {
+        let (mapped_record_count, unmapped_record_count) = index_reference_sequences
+            .next()
+            .and_then(|index_reference_sequence| index_reference_sequence.metadata())
+            .map(|metadata| {
+                (
+                    metadata.mapped_record_count(),
+                    metadata.unmapped_record_count(),
+                )
+            })
+            .unwrap_or_default();
+
+        statistics.push(ReferenceSequenceStatistics {
+            name: name.clone(),
+            length: usize::from(reference_sequence.length()),
+            mapped_record_count,
+            unmapped_record_count,
+        });
+    }
+
+    if index_reference_sequences.next().is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "index reference sequence count exceeds header reference sequence count",
+        ));
+    }
+
+    Ok(IndexStatistics {
+        reference_sequences: statistics,
+        unplaced_unmapped_record_count: index.unplaced_unmapped_record_count().unwrap_or_default(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use indexmap::IndexMap;
+    use noodles_bgzf as bgzf;
+    use noodles_csi::binning_index::index::{reference_sequence::Metadata, ReferenceSequence};
+    use noodles_sam::header::record::value::{map::ReferenceSequence as ReferenceSequenceMap, Map};
+
+    use super::*;
+    use crate::bai;
+
+    #[test]
+    fn test_build() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequenceMap>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequenceMap>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let metadata = Metadata::new(
+            bgzf::VirtualPosition::default(),
+            bgzf::VirtualPosition::default(),
+            2,
+            1,
+        );
+
+        let index = bai::Index::builder()
+            .set_reference_sequences(vec![ReferenceSequence::new(
+                IndexMap::new(),
+                Vec::new(),
+                Some(metadata),
+            )])
+            .set_unplaced_unmapped_record_count(5)
+            .build();
+
+        let actual = build(&header, &index)?;
+
+        let expected = IndexStatistics {
+            reference_sequences: vec![
+                ReferenceSequenceStatistics {
+                    name: BString::from("sq0"),
+                    length: 8,
+                    mapped_record_count: 2,
+                    unmapped_record_count: 1,
+                },
+                ReferenceSequenceStatistics {
+                    name: BString::from("sq1"),
+                    length: 13,
+                    mapped_record_count: 0,
+                    unmapped_record_count: 0,
+                },
+            ],
+            unplaced_unmapped_record_count: 5,
+        };
+
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            build(&sam::Header::default(), &index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
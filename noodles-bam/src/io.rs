@@ -1,7 +1,14 @@
 //! BAM I/O.
 
 pub mod indexed_reader;
+pub mod indexed_writer;
+pub mod multithreaded_reader;
 pub mod reader;
+pub mod split_writer;
 pub mod writer;
 
-pub use self::{indexed_reader::IndexedReader, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, indexed_writer::IndexedWriter,
+    multithreaded_reader::MultithreadedReader, reader::Reader, split_writer::SplitWriter,
+    writer::Writer,
+};
@@ -11,7 +11,7 @@ use noodles_sam::{self as sam, alignment::RecordBuf};
 
 pub use self::builder::Builder;
 use super::{
-    reader::{Query, RecordBufs, Records},
+    reader::{MultiQuery, Query, RecordBufs, Records},
     Reader,
 };
 use crate::Record;
@@ -99,6 +99,17 @@ impl<R> IndexedReader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
 {
+    /// Seeks to the first record of the given reference sequence.
+    ///
+    /// See [`Reader::seek_to_reference_sequence`].
+    pub fn seek_to_reference_sequence(
+        &mut self,
+        reference_sequence_id: usize,
+    ) -> io::Result<Option<bgzf::VirtualPosition>> {
+        self.inner
+            .seek_to_reference_sequence(&self.index, reference_sequence_id)
+    }
+
     /// Returns an iterator over records that intersect the given region.
     pub fn query<'a>(
         &'a mut self,
@@ -108,8 +119,24 @@ where
         self.inner.query(header, &self.index, region)
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    pub fn query_many<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'a, R>> {
+        self.inner.query_many(header, &self.index, regions)
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     pub fn query_unmapped(&mut self) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
         self.inner.query_unmapped(&self.index)
     }
+
+    /// Returns an iterator over all unmapped records, both placed and unplaced.
+    pub fn query_all_unmapped(
+        &mut self,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + '_> {
+        self.inner.query_all_unmapped(&self.index)
+    }
 }
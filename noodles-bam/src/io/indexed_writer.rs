@@ -0,0 +1,413 @@
+//! Indexed BAM writer.
+
+mod builder;
+mod index;
+mod index_format;
+
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi as csi;
+use noodles_csi::binning_index::{
+    binning,
+    index::reference_sequence::{
+        bin::Chunk,
+        index::{BinnedIndex, LinearIndex},
+    },
+    Indexer as BinningIndexer,
+};
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+pub use self::{builder::Builder, index::Index, index_format::IndexFormat};
+use super::Writer;
+use crate::{bai, Record};
+
+const BAI_MIN_SHIFT: u8 = 14;
+const BAI_DEPTH: u8 = 5;
+
+enum Indexer {
+    Bai(BinningIndexer<LinearIndex>),
+    Csi(BinningIndexer<BinnedIndex>),
+}
+
+impl Indexer {
+    fn new(index_format: IndexFormat) -> Self {
+        match index_format {
+            IndexFormat::Bai => Self::Bai(BinningIndexer::default()),
+            IndexFormat::Csi { min_shift, depth } => {
+                Self::Csi(BinningIndexer::new(min_shift, depth))
+            }
+        }
+    }
+
+    fn add_record(
+        &mut self,
+        alignment_context: Option<(usize, Position, Position, bool)>,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        match self {
+            Self::Bai(indexer) => indexer.add_record(alignment_context, chunk),
+            Self::Csi(indexer) => indexer.add_record(alignment_context, chunk),
+        }
+    }
+
+    fn build(self, reference_sequence_count: usize) -> Index {
+        match self {
+            Self::Bai(indexer) => Index::Bai(indexer.build(reference_sequence_count)),
+            Self::Csi(indexer) => Index::Csi(indexer.build(reference_sequence_count)),
+        }
+    }
+}
+
+/// An indexed BAM writer.
+///
+/// This builds a BAM index (BAI) or a coordinate-sorted index (CSI) while writing records,
+/// removing the need to index the output in a separate pass. Records must be coordinate-sorted.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam as bam;
+/// use noodles_csi::BinningIndex;
+/// use noodles_sam as sam;
+///
+/// let mut writer = bam::io::IndexedWriter::new(Vec::new());
+///
+/// let header = sam::Header::default();
+/// writer.write_header(&header)?;
+///
+/// let record = bam::Record::default();
+/// writer.write_record(&header, &record)?;
+///
+/// let index = writer.finish(&header)?;
+///
+/// if let bam::io::indexed_writer::Index::Bai(index) = index {
+///     assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    index_format: IndexFormat,
+    indexer: Option<Indexer>,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed BAM writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder. This builds a BAM index (BAI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// let writer = bam::io::IndexedWriter::new(io::sink());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_index_format(inner, IndexFormat::default())
+    }
+
+    fn with_index_format(inner: W, index_format: IndexFormat) -> Self {
+        Self {
+            inner: Writer::new(inner),
+            index_format,
+            indexer: Some(Indexer::new(index_format)),
+            index_dst: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut bgzf::Writer<W> {
+        self.inner.get_mut()
+    }
+
+    /// Writes a SAM header.
+    ///
+    /// This fails if a reference sequence is too long to be indexed with the index format.
+    pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        let (min_shift, depth) = match self.index_format {
+            IndexFormat::Bai => (BAI_MIN_SHIFT, BAI_DEPTH),
+            IndexFormat::Csi { min_shift, depth } => (min_shift, depth),
+        };
+
+        let max_position = usize::from(binning::max_position(min_shift, depth)?);
+
+        for (name, reference_sequence) in header.reference_sequences() {
+            let length = usize::from(reference_sequence.length());
+
+            if length > max_position {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "reference sequence too long to index: {name} ({length} > {max_position})"
+                    ),
+                ));
+            }
+        }
+
+        self.inner.write_header(header)
+    }
+
+    /// Writes a BAM record.
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        self.write_alignment_record(header, record)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.bai` or
+    /// `<dst>.csi`.
+    ///
+    /// This method can only be called once. Writing records or finishing the writer again returns
+    /// an error.
+    pub fn finish(&mut self, header: &sam::Header) -> io::Result<Index> {
+        let indexer = self.indexer.take().ok_or_else(finished_error)?;
+        self.inner.try_finish()?;
+
+        let index = indexer.build(header.reference_sequences().len());
+
+        if let Some(dst) = self.index_dst.take() {
+            match &index {
+                Index::Bai(index) => bai::write(dst, index)?,
+                Index::Csi(index) => csi::write(dst, index)?,
+            }
+        }
+
+        Ok(index)
+    }
+}
+
+impl<W> sam::alignment::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        let indexer = self.indexer.as_mut().ok_or_else(finished_error)?;
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_alignment_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+        let alignment_context = alignment_context(header, record)?;
+
+        indexer.add_record(alignment_context, chunk)
+    }
+
+    fn finish(&mut self, header: &sam::Header) -> io::Result<()> {
+        IndexedWriter::finish(self, header).map(|_| ())
+    }
+}
+
+fn finished_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "writer already finished")
+}
+
+fn alignment_context(
+    header: &sam::Header,
+    record: &dyn sam::alignment::Record,
+) -> io::Result<Option<(usize, Position, Position, bool)>> {
+    let reference_sequence_id = record.reference_sequence_id(header).transpose()?;
+    let alignment_start = record.alignment_start().transpose()?;
+    let alignment_end = record.alignment_end().transpose()?;
+
+    match (reference_sequence_id, alignment_start, alignment_end) {
+        (Some(id), Some(start), Some(end)) => {
+            let is_mapped = !record.flags()?.is_unmapped();
+            Ok(Some((id, start, end.max(start), is_mapped)))
+        }
+        _ => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_csi::{binning_index::ReferenceSequence as _, BinningIndex};
+    use sam::{
+        alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let records = [
+            (0, 1, Flags::empty()),
+            (0, 5, Flags::empty()),
+            (1, 3, Flags::UNMAPPED),
+        ];
+
+        for (reference_sequence_id, alignment_start, flags) in records {
+            let record = RecordBuf::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::try_from(alignment_start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.write_alignment_record(&header, &RecordBuf::default())?;
+
+        let Index::Bai(index) = writer.finish(&header)? else {
+            panic!("expected BAI");
+        };
+
+        let reference_sequences = index.reference_sequences();
+        assert_eq!(reference_sequences.len(), 2);
+
+        let metadata = reference_sequences[0].metadata().expect("missing metadata");
+        assert_eq!(metadata.mapped_record_count(), 2);
+        assert_eq!(metadata.unmapped_record_count(), 0);
+
+        let metadata = reference_sequences[1].metadata().expect("missing metadata");
+        assert_eq!(metadata.mapped_record_count(), 0);
+        assert_eq!(metadata.unmapped_record_count(), 1);
+
+        assert_eq!(index.unplaced_unmapped_record_count(), Some(1));
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &RecordBuf::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            writer.finish(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_csi() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1 << 30)?),
+            )
+            .build();
+
+        let mut writer = IndexedWriter::new(io::sink());
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let index_format = IndexFormat::Csi {
+            min_shift: 14,
+            depth: 6,
+        };
+        let mut writer = IndexedWriter::with_index_format(io::sink(), index_format);
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from((1 << 29) + 1)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+
+        let Index::Csi(index) = writer.finish(&header)? else {
+            panic!("expected CSI");
+        };
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 6);
+
+        let start = Position::try_from(1 << 29)?;
+        let end = Position::try_from((1 << 29) + 8)?;
+        let chunks = index.query(0, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let mut writer = IndexedWriter::new(io::sink());
+
+        for reference_sequence_id in [1, 0] {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build();
+
+            if reference_sequence_id == 1 {
+                writer.write_alignment_record(&header, &record)?;
+            } else {
+                assert!(matches!(
+                    writer.write_alignment_record(&header, &record),
+                    Err(e) if e.kind() == io::ErrorKind::InvalidInput
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    ffi::{OsStr, OsString},
+    fs::File,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use super::{IndexFormat, IndexedWriter};
+
+/// An indexed BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    index_format: IndexFormat,
+}
+
+impl Builder {
+    /// Sets the index format.
+    ///
+    /// By default, this is BAI. CSI is required to index reference sequences with lengths >=
+    /// 2^29.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::indexed_writer::{Builder, IndexFormat};
+    ///
+    /// let builder = Builder::default().set_index_format(IndexFormat::Csi {
+    ///     min_shift: 14,
+    ///     depth: 6,
+    /// });
+    /// ```
+    pub fn set_index_format(mut self, index_format: IndexFormat) -> Self {
+        self.index_format = index_format;
+        self
+    }
+
+    /// Builds an indexed BAM writer from a path.
+    ///
+    /// When the writer is finished, the index is written to `<dst>.bai` or `<dst>.csi`, depending
+    /// on the index format.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_path("out.bam")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<IndexedWriter<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref();
+
+        let file = File::create(dst)?;
+
+        let ext = match self.index_format {
+            IndexFormat::Bai => "bai",
+            IndexFormat::Csi { .. } => "csi",
+        };
+
+        let mut writer = IndexedWriter::with_index_format(file, self.index_format);
+        writer.index_dst = Some(build_index_dst(dst, ext));
+
+        Ok(writer)
+    }
+
+    /// Builds an indexed BAM writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::io::indexed_writer::Builder;
+    /// let writer = Builder::default().build_from_writer(io::sink());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> IndexedWriter<W>
+    where
+        W: Write,
+    {
+        IndexedWriter::with_index_format(writer, self.index_format)
+    }
+}
+
+fn build_index_dst<P, S>(dst: P, ext: S) -> PathBuf
+where
+    P: AsRef<Path>,
+    S: AsRef<OsStr>,
+{
+    let mut s = OsString::from(dst.as_ref());
+    s.push(".");
+    s.push(ext);
+    PathBuf::from(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_index_dst() {
+        assert_eq!(
+            build_index_dst("out.bam", "bai"),
+            PathBuf::from("out.bam.bai")
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use noodles_csi as csi;
+
+use crate::bai;
+
+/// An index built by an indexed BAM writer.
+#[derive(Debug)]
+pub enum Index {
+    /// A BAM index (BAI).
+    Bai(bai::Index),
+    /// A coordinate-sorted index (CSI).
+    Csi(csi::Index),
+}
@@ -0,0 +1,18 @@
+/// The index format built by an indexed BAM writer.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum IndexFormat {
+    /// A BAM index (BAI).
+    ///
+    /// BAI can only index positions < 2^29.
+    #[default]
+    Bai,
+    /// A coordinate-sorted index (CSI).
+    ///
+    /// CSI can index positions < 2^(`min_shift` + 3 × `depth`).
+    Csi {
+        /// The number of bits for the minimum interval.
+        min_shift: u8,
+        /// The depth of the binning index.
+        depth: u8,
+    },
+}
@@ -0,0 +1,98 @@
+//! Multithreaded BAM reader.
+
+mod records;
+
+use std::{
+    io::{self, Read},
+    num::NonZeroUsize,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_sam as sam;
+
+pub use self::records::Records;
+use super::Reader;
+
+/// A multithreaded BAM reader.
+///
+/// BGZF blocks are decompressed by a pool of worker threads, and records are split and decoded on
+/// a separate reader thread. Records are returned to the consumer in order, in batches, which
+/// keeps the calling thread free for processing.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use std::{fs::File, num::NonZeroUsize, thread};
+/// use noodles_bam as bam;
+///
+/// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+/// let file = File::open("sample.bam")?;
+/// let mut reader = bam::io::MultithreadedReader::with_worker_count(worker_count, file);
+///
+/// let _header = reader.read_header()?;
+///
+/// for result in reader.records() {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct MultithreadedReader<R> {
+    inner: Reader<bgzf::MultithreadedReader<R>>,
+}
+
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Send + 'static,
+{
+    /// Creates a multithreaded BAM reader with a worker count of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// let reader = bam::io::MultithreadedReader::new(io::empty());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self::with_worker_count(NonZeroUsize::MIN, inner)
+    }
+
+    /// Creates a multithreaded BAM reader with a worker count.
+    ///
+    /// The worker count is the number of threads used to decompress BGZF blocks. An additional
+    /// thread is used to read, split, and decode records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bam as bam;
+    /// let worker_count = NonZeroUsize::MIN;
+    /// let reader = bam::io::MultithreadedReader::with_worker_count(worker_count, io::empty());
+    /// ```
+    pub fn with_worker_count(worker_count: NonZeroUsize, inner: R) -> Self {
+        Self {
+            inner: Reader::from(bgzf::MultithreadedReader::with_worker_count(
+                worker_count,
+                inner,
+            )),
+        }
+    }
+
+    /// Reads the SAM header.
+    ///
+    /// The position of the stream is expected to be at the start.
+    pub fn read_header(&mut self) -> io::Result<sam::Header> {
+        self.inner.read_header()
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    /// This consumes the reader, moving it to the reader thread.
+    pub fn records(self) -> Records {
+        Records::new(self.inner)
+    }
+}
@@ -0,0 +1,175 @@
+use std::{
+    io::{self, Read},
+    sync::mpsc::{self, Receiver, SyncSender},
+    thread::{self, JoinHandle},
+    vec,
+};
+
+use noodles_bgzf as bgzf;
+
+use crate::{io::Reader, Record};
+
+const BATCH_SIZE: usize = 4096;
+const QUEUE_CAPACITY: usize = 4;
+
+type Batch = io::Result<Vec<Record>>;
+
+/// An iterator over records of a multithreaded BAM reader.
+///
+/// This is created by calling [`super::MultithreadedReader::records`].
+pub struct Records {
+    rx: Option<Receiver<Batch>>,
+    reader_handle: Option<JoinHandle<()>>,
+    batch: vec::IntoIter<Record>,
+}
+
+impl Records {
+    pub(super) fn new<R>(reader: Reader<bgzf::MultithreadedReader<R>>) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        let (tx, rx) = mpsc::sync_channel(QUEUE_CAPACITY);
+        let reader_handle = thread::spawn(move || read_batches(reader, tx));
+
+        Self {
+            rx: Some(rx),
+            reader_handle: Some(reader_handle),
+            batch: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl Iterator for Records {
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.batch.next() {
+                return Some(Ok(record));
+            }
+
+            // The reader thread hangs up after the last batch or an error.
+            match self.rx.as_ref()?.recv() {
+                Ok(Ok(batch)) => self.batch = batch.into_iter(),
+                Ok(Err(e)) => return Some(Err(e)),
+                Err(_) => {
+                    self.rx = None;
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Records {
+    fn drop(&mut self) {
+        // Dropping the receiver stops the reader thread on its next send.
+        drop(self.rx.take());
+
+        if let Some(handle) = self.reader_handle.take() {
+            handle.join().ok();
+        }
+    }
+}
+
+fn read_batches<R>(mut reader: Reader<bgzf::MultithreadedReader<R>>, tx: SyncSender<Batch>)
+where
+    R: Read + Send + 'static,
+{
+    loop {
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+
+        while batch.len() < BATCH_SIZE {
+            let mut record = Record::default();
+
+            match reader.read_record(&mut record) {
+                Ok(0) => {
+                    if !batch.is_empty() {
+                        tx.send(Ok(batch)).ok();
+                    }
+
+                    return;
+                }
+                Ok(_) => batch.push(record),
+                Err(e) => {
+                    tx.send(Err(e)).ok();
+                    return;
+                }
+            }
+        }
+
+        if tx.send(Ok(batch)).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::io::{MultithreadedReader, Writer};
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const RECORD_COUNT: usize = BATCH_SIZE * 2 + 1;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let record = sam::alignment::RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+        let mut reader = MultithreadedReader::with_worker_count(worker_count, io::Cursor::new(src));
+        reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .records()
+            .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names.len(), RECORD_COUNT);
+
+        for (i, name) in names.iter().enumerate() {
+            assert_eq!(name.as_deref(), Some(format!("r{i}").as_bytes()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_drop_before_end() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for _ in 0..BATCH_SIZE * QUEUE_CAPACITY * 2 {
+            writer.write_alignment_record(&header, &sam::alignment::RecordBuf::default())?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = MultithreadedReader::new(io::Cursor::new(src));
+        reader.read_header()?;
+
+        let mut records = reader.records();
+        assert!(records.next().transpose()?.is_some());
+        drop(records);
+
+        Ok(())
+    }
+}
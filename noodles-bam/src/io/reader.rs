@@ -2,6 +2,7 @@
 
 mod builder;
 pub(crate) mod header;
+mod multi_query;
 pub(crate) mod query;
 mod record;
 mod record_buf;
@@ -11,6 +12,7 @@ mod records;
 use std::{
     ffi::CStr,
     io::{self, Read},
+    iter,
 };
 
 use bstr::BString;
@@ -19,7 +21,10 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
+pub use self::{
+    builder::Builder, multi_query::MultiQuery, query::Query, record_bufs::RecordBufs,
+    records::Records,
+};
 use self::{record::read_record, record_buf::read_record_buf};
 use crate::Record;
 
@@ -315,6 +320,73 @@ where
         Ok(self.get_ref().virtual_position())
     }
 
+    /// Seeks to the first record of the given reference sequence.
+    ///
+    /// The start position is read from the reference sequence metadata or, if it is missing, the
+    /// first query chunk of the whole reference sequence. Reading then continues forward from this
+    /// position, i.e., past the end of the reference sequence, so the caller is expected to stop
+    /// at the first record with a different reference sequence ID.
+    ///
+    /// This returns the position of the first record or `None` if the reference sequence has no
+    /// records. In the latter case, the stream position is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let index = bai::read("sample.bam.bai")?;
+    ///
+    /// if reader.seek_to_reference_sequence(&index, 1)?.is_some() {
+    ///     for result in reader.records() {
+    ///         let record = result?;
+    ///
+    ///         if record.reference_sequence_id().transpose()? != Some(1) {
+    ///             break;
+    ///         }
+    ///
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn seek_to_reference_sequence<I>(
+        &mut self,
+        index: &I,
+        reference_sequence_id: usize,
+    ) -> io::Result<Option<bgzf::VirtualPosition>>
+    where
+        I: BinningIndex,
+    {
+        let reference_sequence = index
+            .reference_sequences()
+            .nth(reference_sequence_id)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid reference sequence ID: {reference_sequence_id}"),
+                )
+            })?;
+
+        let start_position = match reference_sequence.metadata() {
+            Some(metadata) => Some(metadata.start_position()),
+            None => index
+                .query(reference_sequence_id, (..).into())?
+                .first()
+                .map(|chunk| chunk.start()),
+        };
+
+        if let Some(position) = start_position {
+            self.get_mut().seek_to_virtual_position(position)?;
+        }
+
+        Ok(start_position)
+    }
+
     /// Returns an iterator over records that intersect the given region.
     ///
     /// To query for unmapped records, use [`Self::query_unmapped`].
@@ -358,6 +430,56 @@ where
         ))
     }
 
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each record is read at most once, even if it
+    /// intersects more than one region. Each record is returned with the indices of the regions
+    /// it intersects, in the order the regions are given.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::read("sample.bam.bai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:10-21".parse()?];
+    /// let query = reader.query_many(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_many<I>(
+        &mut self,
+        header: &sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'_, R>>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::merge_chunks;
+
+        let mut chunks = Vec::new();
+        let mut resolved_regions = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let reference_sequence_id = resolve_region(header.reference_sequences(), region)?;
+            chunks.extend(index.query(reference_sequence_id, region.interval())?);
+            resolved_regions.push((reference_sequence_id, region.interval()));
+        }
+
+        let chunks = merge_chunks(&chunks);
+
+        Ok(MultiQuery::new(self.get_mut(), chunks, resolved_regions))
+    }
+
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     ///
     /// # Examples
@@ -398,6 +520,85 @@ where
                 .unwrap_or(true)
         }))
     }
+
+    /// Returns an iterator over all unmapped records, both placed and unplaced.
+    ///
+    /// Unlike [`Self::query_unmapped`], this includes placed unmapped records, i.e., unmapped
+    /// records that have a reference sequence ID and position, typically of their mate. The index
+    /// metadata (the pseudo-bin) is used to only read reference sequences that have unmapped
+    /// records and the unplaced unmapped records at the end of the stream, which avoids a full
+    /// scan. Records are returned in stream order.
+    ///
+    /// This is similar to `samtools view -f 4`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let index = bai::read("sample.bam.bai")?;
+    ///
+    /// for result in reader.query_all_unmapped(&index)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn query_all_unmapped<'r, I>(
+        &'r mut self,
+        index: &I,
+    ) -> io::Result<impl Iterator<Item = io::Result<Record>> + 'r>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::{index::reference_sequence::bin::Chunk, merge_chunks};
+
+        let mut chunks = Vec::new();
+        let mut placed_end = None;
+
+        for reference_sequence in index.reference_sequences() {
+            if let Some(metadata) = reference_sequence.metadata() {
+                if metadata.unmapped_record_count() > 0 {
+                    chunks.push(Chunk::new(
+                        metadata.start_position(),
+                        metadata.end_position(),
+                    ));
+                }
+
+                placed_end = placed_end.max(Some(metadata.end_position()));
+            }
+        }
+
+        if index.unplaced_unmapped_record_count() != Some(0) {
+            let start = match placed_end {
+                Some(position) => position,
+                None => self.seek_to_first_record()?,
+            };
+
+            chunks.push(Chunk::new(start, bgzf::VirtualPosition::MAX));
+        }
+
+        let chunks = merge_chunks(&chunks);
+        let mut reader = Reader::from(noodles_csi::io::Query::new(self.get_mut(), chunks));
+
+        Ok(iter::from_fn(move || loop {
+            let mut record = Record::default();
+
+            match reader.read_record(&mut record) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    if record.flags().is_unmapped() {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -450,3 +651,122 @@ pub(crate) fn resolve_region(
             )
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::io::{indexed_writer::Index, IndexedWriter};
+
+    #[test]
+    fn test_seek_to_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+
+        let Index::Bai(index) = writer.finish(&header)? else {
+            panic!("expected BAI");
+        };
+
+        let src = writer.get_ref().get_ref().clone();
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header()?;
+
+        assert!(reader.seek_to_reference_sequence(&index, 0)?.is_none());
+        assert!(reader.seek_to_reference_sequence(&index, 2).is_err());
+
+        assert!(reader.seek_to_reference_sequence(&index, 1)?.is_some());
+
+        let names: Vec<_> = reader
+            .records()
+            .map(|result| result.map(|record| record.name().map(|name| name.to_owned())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some("r0".into())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_all_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .build(),
+            RecordBuf::builder().set_name("r2").build(),
+        ];
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        let Index::Bai(index) = writer.finish(&header)? else {
+            panic!("expected BAI");
+        };
+
+        let src = writer.get_ref().get_ref().clone();
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .query_all_unmapped(&index)?
+            .map(|result| result.map(|record| record.name().map(|name| name.to_owned())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some("r1".into()), Some("r2".into())]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_sam::alignment::Record as _;
+
+use super::Reader;
+use crate::Record;
+
+/// An iterator over records of a BAM reader that intersect any of the given regions.
+///
+/// This is created by calling [`Reader::query_many`].
+pub struct MultiQuery<'a, R> {
+    reader: Reader<csi::io::Query<'a, R>>,
+    regions: Vec<(usize, Interval)>,
+    record: Record,
+}
+
+impl<'a, R> MultiQuery<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'a mut R,
+        chunks: Vec<Chunk>,
+        regions: Vec<(usize, Interval)>,
+    ) -> Self {
+        Self {
+            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            regions,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'a, R> Iterator for MultiQuery<'a, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Record, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => match intersecting_regions(&self.record, &self.regions) {
+                    Ok(indices) if indices.is_empty() => {}
+                    Ok(indices) => return Some(Ok((self.record.clone(), indices))),
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn intersecting_regions(record: &Record, regions: &[(usize, Interval)]) -> io::Result<Vec<usize>> {
+    let (Some(id), Some(start), Some(end)) = (
+        record.reference_sequence_id().transpose()?,
+        record.alignment_start().transpose()?,
+        record.alignment_end().transpose()?,
+    ) else {
+        return Ok(Vec::new());
+    };
+
+    let alignment_interval = (start..=end).into();
+
+    Ok(regions
+        .iter()
+        .enumerate()
+        .filter(|(_, (reference_sequence_id, interval))| {
+            *reference_sequence_id == id && interval.intersects(alignment_interval)
+        })
+        .map(|(i, _)| i)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::io::{indexed_writer::Index, IndexedWriter};
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(5)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_flags(Flags::empty())
+                .set_alignment_start(Position::try_from(10)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+        ];
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        let Index::Bai(index) = writer.finish(&header)? else {
+            panic!("expected BAI");
+        };
+
+        let src = writer.get_ref().get_ref().clone();
+        let mut reader = Reader::new(Cursor::new(src));
+
+        let regions = ["sq0:2-6".parse()?, "sq0:5-11".parse()?];
+        let query = reader.query_many(&header, &index, &regions)?;
+
+        let actual: Vec<_> = query
+            .map(|result| {
+                result.and_then(|(record, region_indices)| {
+                    RecordBuf::try_from_alignment_record(&header, &record)
+                        .map(|record| (record, region_indices))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = [
+            (records[0].clone(), vec![0]),
+            (records[1].clone(), vec![0, 1]),
+            (records[2].clone(), vec![1]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
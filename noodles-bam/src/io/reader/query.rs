@@ -105,7 +105,6 @@ mod tests {
     use noodles_sam::{
         self as sam,
         alignment::{
-            io::Write,
             record::{
                 cigar::{op::Kind, Op},
                 Flags,
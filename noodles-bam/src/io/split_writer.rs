@@ -0,0 +1,424 @@
+//! Split BAM writer.
+
+mod builder;
+mod split_by;
+
+use std::io::{self, Write};
+
+use bstr::BStr;
+use noodles_bgzf as bgzf;
+use noodles_sam::{
+    self as sam,
+    alignment::record::data::field::{Tag, Value},
+};
+
+pub use self::{builder::Builder, split_by::SplitBy};
+use super::Writer;
+use crate::Record;
+
+type MakeWriter<W> = Box<dyn FnMut(Option<&BStr>) -> io::Result<W>>;
+
+/// A split BAM writer.
+///
+/// This routes records to one BAM output per reference sequence or per read group, similar to
+/// `samtools split`. Outputs are created on the first record written to them and are each given
+/// a header and finished with a BGZF EOF block.
+///
+/// When splitting by read group, the header of each output only includes its own read group.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_bam as bam;
+/// use noodles_sam as sam;
+///
+/// let mut writer = bam::io::SplitWriter::new(
+///     bam::io::split_writer::SplitBy::ReferenceSequence,
+///     |_| Ok(io::sink()),
+/// );
+///
+/// let header = sam::Header::default();
+/// let record = bam::Record::default();
+/// writer.write_record(&header, &record)?;
+///
+/// writer.finish()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct SplitWriter<W>
+where
+    W: Write,
+{
+    split_by: SplitBy,
+    make_writer: MakeWriter<W>,
+    writers: Vec<Option<Writer<bgzf::Writer<W>>>>,
+    unassigned_writer: Option<Writer<bgzf::Writer<W>>>,
+}
+
+impl<W> SplitWriter<W>
+where
+    W: Write,
+{
+    /// Creates a split BAM writer.
+    ///
+    /// `make_writer` is called with the name of the reference sequence or read group of each
+    /// output, or `None` for the unassigned output. Each stream is wrapped in a BGZF encoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::io::{split_writer::SplitBy, SplitWriter};
+    /// let writer = SplitWriter::new(SplitBy::ReadGroup, |_| Ok(io::sink()));
+    /// ```
+    pub fn new<F>(split_by: SplitBy, make_writer: F) -> Self
+    where
+        F: FnMut(Option<&BStr>) -> io::Result<W> + 'static,
+    {
+        Self {
+            split_by,
+            make_writer: Box::new(make_writer),
+            writers: Vec::new(),
+            unassigned_writer: None,
+        }
+    }
+
+    /// Returns the record key used to split the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::io::{split_writer::SplitBy, SplitWriter};
+    /// let writer = SplitWriter::new(SplitBy::ReadGroup, |_| Ok(io::sink()));
+    /// assert_eq!(writer.split_by(), SplitBy::ReadGroup);
+    /// ```
+    pub fn split_by(&self) -> SplitBy {
+        self.split_by
+    }
+
+    /// Writes a BAM record.
+    pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
+        self.write_alignment_record(header, record)
+    }
+
+    /// Writes an alignment record.
+    ///
+    /// The output is chosen by the record's reference sequence ID or read group. If the output
+    /// does not yet exist, it is created and its header is written.
+    pub fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        let i = match self.split_by {
+            SplitBy::ReferenceSequence => record.reference_sequence_id(header).transpose()?,
+            SplitBy::ReadGroup => read_group_index(header, record)?,
+        };
+
+        let (slot, name) = match i {
+            Some(i) => {
+                let name = output_name(header, self.split_by, i)?;
+
+                if i >= self.writers.len() {
+                    self.writers.resize_with(i + 1, || None);
+                }
+
+                (&mut self.writers[i], Some(name))
+            }
+            None => (&mut self.unassigned_writer, None),
+        };
+
+        let writer = match slot {
+            Some(writer) => writer,
+            None => {
+                let inner = (self.make_writer)(name)?;
+                let mut writer = Writer::new(inner);
+
+                let output_header = match i {
+                    Some(i) if self.split_by == SplitBy::ReadGroup => read_group_header(header, i),
+                    _ => header.clone(),
+                };
+
+                writer.write_header(&output_header)?;
+
+                slot.insert(writer)
+            }
+        };
+
+        writer.write_alignment_record(header, record)
+    }
+
+    /// Finishes all outputs.
+    ///
+    /// This writes the BGZF EOF block of each output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam::io::{split_writer::SplitBy, SplitWriter};
+    /// let mut writer = SplitWriter::new(SplitBy::ReadGroup, |_| Ok(io::sink()));
+    /// writer.finish()?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn finish(&mut self) -> io::Result<()> {
+        for writer in self
+            .writers
+            .iter_mut()
+            .chain(Some(&mut self.unassigned_writer))
+            .flatten()
+        {
+            writer.try_finish()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<W> sam::alignment::io::Write for SplitWriter<W>
+where
+    W: Write,
+{
+    fn write_alignment_header(&mut self, _: &sam::Header) -> io::Result<()> {
+        // Headers are written when each output is created.
+        Ok(())
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        SplitWriter::write_alignment_record(self, header, record)
+    }
+
+    fn finish(&mut self, _: &sam::Header) -> io::Result<()> {
+        SplitWriter::finish(self)
+    }
+}
+
+fn read_group_index(
+    header: &sam::Header,
+    record: &dyn sam::alignment::Record,
+) -> io::Result<Option<usize>> {
+    let data = record.data();
+
+    let id = match data.get(&Tag::READ_GROUP).transpose()? {
+        Some(Value::String(id)) => id,
+        Some(_) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid read group value type",
+            ))
+        }
+        None => return Ok(None),
+    };
+
+    header
+        .read_groups()
+        .get_index_of(id)
+        .map(Some)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("read group not in header: {id}"),
+            )
+        })
+}
+
+fn output_name(header: &sam::Header, split_by: SplitBy, i: usize) -> io::Result<&BStr> {
+    let (name, message) = match split_by {
+        SplitBy::ReferenceSequence => (
+            header
+                .reference_sequences()
+                .get_index(i)
+                .map(|(name, _)| name),
+            "invalid reference sequence ID",
+        ),
+        SplitBy::ReadGroup => (
+            header.read_groups().get_index(i).map(|(id, _)| id),
+            "invalid read group ID",
+        ),
+    };
+
+    name.map(|name| name.as_ref())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, message))
+}
+
+fn read_group_header(header: &sam::Header, i: usize) -> sam::Header {
+    let read_group = header
+        .read_groups()
+        .get_index(i)
+        .map(|(id, map)| (id.clone(), map.clone()));
+
+    let mut output_header = header.clone();
+    *output_header.read_groups_mut() = read_group.into_iter().collect();
+    output_header
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap, num::NonZeroUsize, rc::Rc};
+
+    use noodles_sam::{
+        alignment::{record_buf::data::field::Value as ValueBuf, RecordBuf},
+        header::record::value::{
+            map::{ReadGroup, ReferenceSequence},
+            Map,
+        },
+    };
+
+    use super::*;
+    use crate::io::Reader;
+
+    type Outputs = Rc<RefCell<HashMap<Option<String>, Vec<u8>>>>;
+
+    struct OutputWriter {
+        outputs: Outputs,
+        name: Option<String>,
+    }
+
+    impl Write for OutputWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let mut outputs = self.outputs.borrow_mut();
+            outputs.entry(self.name.clone()).or_default().extend(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn build_split_writer(split_by: SplitBy, outputs: &Outputs) -> SplitWriter<OutputWriter> {
+        let outputs = outputs.clone();
+
+        SplitWriter::new(split_by, move |name| {
+            Ok(OutputWriter {
+                outputs: outputs.clone(),
+                name: name.map(|s| s.to_string()),
+            })
+        })
+    }
+
+    fn read_output(
+        outputs: &Outputs,
+        name: Option<&str>,
+    ) -> io::Result<(sam::Header, Vec<RecordBuf>)> {
+        let outputs = outputs.borrow();
+        let src = outputs
+            .get(&name.map(String::from))
+            .expect("missing output");
+
+        let mut reader = Reader::new(&src[..]);
+        let header = reader.read_header()?;
+        let records = reader.record_bufs(&header).collect::<io::Result<_>>()?;
+
+        Ok((header, records))
+    }
+
+    #[test]
+    fn test_write_alignment_record_split_by_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let outputs = Outputs::default();
+        let mut writer = build_split_writer(SplitBy::ReferenceSequence, &outputs);
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_reference_sequence_id(1)
+                .build(),
+            RecordBuf::builder().set_name("r1").build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_reference_sequence_id(1)
+                .build(),
+        ];
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.finish()?;
+
+        assert_eq!(outputs.borrow().len(), 2);
+
+        let (actual_header, actual_records) = read_output(&outputs, Some("sq1"))?;
+        assert_eq!(actual_header, header);
+        assert_eq!(actual_records, [records[0].clone(), records[2].clone()]);
+
+        let (_, actual_records) = read_output(&outputs, None)?;
+        assert_eq!(actual_records, [records[1].clone()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_split_by_read_group() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .build();
+
+        let outputs = Outputs::default();
+        let mut writer = build_split_writer(SplitBy::ReadGroup, &outputs);
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [(Tag::READ_GROUP, ValueBuf::from("rg1"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+        writer.finish()?;
+
+        let (actual_header, actual_records) = read_output(&outputs, Some("rg1"))?;
+
+        let expected_header = sam::Header::builder()
+            .add_read_group("rg1", Map::<ReadGroup>::default())
+            .build();
+
+        assert_eq!(actual_header, expected_header);
+        assert_eq!(actual_records, [record]);
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [(Tag::READ_GROUP, ValueBuf::from("rg2"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_name() {
+        let header = sam::Header::default();
+
+        let e = output_name(&header, SplitBy::ReferenceSequence, 0).unwrap_err();
+        assert_eq!(e.to_string(), "invalid reference sequence ID");
+
+        let e = output_name(&header, SplitBy::ReadGroup, 0).unwrap_err();
+        assert_eq!(e.to_string(), "invalid read group ID");
+    }
+}
@@ -0,0 +1,83 @@
+use std::{
+    fs::{self, File},
+    io,
+    path::{Path, PathBuf},
+};
+
+use bstr::BStr;
+
+use super::{SplitBy, SplitWriter};
+
+const UNASSIGNED_NAME: &str = "unassigned";
+
+/// A split BAM writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    split_by: SplitBy,
+}
+
+impl Builder {
+    /// Sets the record key used to split the output.
+    ///
+    /// By default, this is [`SplitBy::ReferenceSequence`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::split_writer::{Builder, SplitBy};
+    /// let builder = Builder::default().set_split_by(SplitBy::ReadGroup);
+    /// ```
+    pub fn set_split_by(mut self, split_by: SplitBy) -> Self {
+        self.split_by = split_by;
+        self
+    }
+
+    /// Builds a split BAM writer that writes to files in a directory.
+    ///
+    /// The directory is created if it does not exist. Each output is written to
+    /// `<dst>/<name>.bam`, where `<name>` is the reference sequence name or read group ID, and
+    /// unassigned records are written to `<dst>/unassigned.bam`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam::io::split_writer::Builder;
+    /// let writer = Builder::default().build_from_path("out")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<SplitWriter<File>>
+    where
+        P: AsRef<Path>,
+    {
+        let dst = dst.as_ref().to_path_buf();
+        fs::create_dir_all(&dst)?;
+
+        Ok(SplitWriter::new(self.split_by, move |name| {
+            File::create(build_output_dst(&dst, name))
+        }))
+    }
+}
+
+fn build_output_dst(dst: &Path, name: Option<&BStr>) -> PathBuf {
+    let name = name.map(|s| s.to_string());
+    let filename = format!("{}.bam", name.as_deref().unwrap_or(UNASSIGNED_NAME));
+    dst.join(filename)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_output_dst() {
+        assert_eq!(
+            build_output_dst(Path::new("out"), Some(BStr::new("sq0"))),
+            PathBuf::from("out/sq0.bam")
+        );
+
+        assert_eq!(
+            build_output_dst(Path::new("out"), None),
+            PathBuf::from("out/unassigned.bam")
+        );
+    }
+}
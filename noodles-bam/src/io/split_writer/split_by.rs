@@ -0,0 +1,13 @@
+/// The record key used to split BAM output.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SplitBy {
+    /// Split by reference sequence.
+    ///
+    /// Records without a reference sequence ID are written to the unassigned output.
+    #[default]
+    ReferenceSequence,
+    /// Split by read group (`RG`).
+    ///
+    /// Records without a read group are written to the unassigned output.
+    ReadGroup,
+}
@@ -7,7 +7,7 @@ use std::io::{self, Write};
 
 use byteorder::{LittleEndian, WriteBytesExt};
 use noodles_bgzf as bgzf;
-use noodles_sam::{self as sam, alignment::io::Write as _};
+use noodles_sam as sam;
 
 pub use self::builder::Builder;
 use crate::Record;
@@ -30,6 +30,21 @@ use crate::Record;
 /// writer.write_record(&header, &record)?;
 /// # Ok::<(), io::Error>(())
 /// ```
+///
+/// ## Use a custom BGZF encoder
+///
+/// [`Writer::new`] wraps the output stream with a default BGZF encoder. This can be swapped for a
+/// custom encoder, e.g., [`noodles_bgzf::MultithreadedWriter`], using [`Writer::from`].
+///
+/// ```
+/// # use std::{io, num::NonZeroUsize, thread};
+/// use noodles_bam as bam;
+/// use noodles_bgzf as bgzf;
+///
+/// let worker_count = thread::available_parallelism().unwrap_or(NonZeroUsize::MIN);
+/// let encoder = bgzf::MultithreadedWriter::with_worker_count(worker_count, io::sink());
+/// let _writer = bam::io::Writer::from(encoder);
+/// ```
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
@@ -122,6 +137,47 @@ where
     pub fn write_record(&mut self, header: &sam::Header, record: &Record) -> io::Result<()> {
         self.write_alignment_record(header, record)
     }
+
+    /// Writes an alignment record.
+    ///
+    /// The record is encoded directly from its [`sam::alignment::Record`] fields, i.e., records
+    /// read from other formats (e.g., SAM or CRAM) do not need to first be converted to a
+    /// [`sam::alignment::RecordBuf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let mut writer = bam::io::Writer::new(io::sink());
+    /// writer.write_header(&header)?;
+    ///
+    /// let record = RecordBuf::default();
+    /// writer.write_alignment_record(&header, &record)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        use crate::record::codec::encode;
+
+        self.buf.clear();
+        encode(&mut self.buf, header, record)?;
+
+        let block_size = u32::try_from(self.buf.len())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.inner.write_u32::<LittleEndian>(block_size)?;
+
+        self.inner.write_all(&self.buf)?;
+
+        Ok(())
+    }
 }
 
 impl<W> Writer<bgzf::Writer<W>>
@@ -184,18 +240,7 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        use crate::record::codec::encode;
-
-        self.buf.clear();
-        encode(&mut self.buf, header, record)?;
-
-        let block_size = u32::try_from(self.buf.len())
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-        self.inner.write_u32::<LittleEndian>(block_size)?;
-
-        self.inner.write_all(&self.buf)?;
-
-        Ok(())
+        Writer::write_alignment_record(self, header, record)
     }
 
     fn finish(&mut self, _: &sam::Header) -> io::Result<()> {
@@ -388,4 +433,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_alignment_record_with_sam_record() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::{
+            alignment::record::cigar::{op::Kind, Op},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let sam_record =
+            sam::Record::try_from(&b"r0\t0\tsq0\t2\t13\t4M\t*\t0\t0\tACGT\tNDLS\tNH:i:1\n"[..])?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &sam_record)?;
+        writer.try_finish()?;
+
+        let mut reader = Reader::new(writer.get_ref().get_ref().as_slice());
+        reader.read_header()?;
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+
+        let expected = RecordBuf::try_from_alignment_record(&header, &sam_record)?;
+        assert_eq!(record, expected);
+        assert_eq!(record.reference_sequence_id(), Some(0));
+        assert_eq!(record.cigar().as_ref(), [Op::new(Kind::Match, 4)]);
+
+        Ok(())
+    }
 }
@@ -48,6 +48,7 @@
 pub mod r#async;
 
 pub mod bai;
+pub mod index_statistics;
 pub mod io;
 pub mod record;
 
@@ -13,7 +13,10 @@ use bstr::BStr;
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
-    alignment::record::{Flags, MappingQuality},
+    alignment::record::{
+        data::field::{Tag, Value},
+        Flags, MappingQuality,
+    },
 };
 
 pub(crate) use self::fields::Fields;
@@ -192,6 +195,57 @@ impl Record {
     pub fn data(&self) -> Data<'_> {
         self.0.data()
     }
+
+    /// Inserts a data field.
+    ///
+    /// If a field with the same tag exists, it is replaced in place. Otherwise, the field is
+    /// appended. The record buffer is patched directly, i.e., no other fields are decoded or
+    /// reencoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam::alignment::record::data::field::{Tag, Value};
+    ///
+    /// let mut record = bam::Record::default();
+    /// record.insert_data_field(Tag::ALIGNMENT_HIT_COUNT, &Value::UInt8(1))?;
+    ///
+    /// assert!(matches!(
+    ///     record.data().get(&Tag::ALIGNMENT_HIT_COUNT).transpose()?,
+    ///     Some(Value::UInt8(1))
+    /// ));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn insert_data_field(&mut self, tag: Tag, value: &Value<'_>) -> io::Result<()> {
+        self.0.insert_data_field(tag, value)
+    }
+
+    /// Removes a data field.
+    ///
+    /// This returns whether a field with the given tag was removed. Like
+    /// [`Self::insert_data_field`], the record buffer is patched directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// use noodles_sam::alignment::record::data::field::{Tag, Value};
+    ///
+    /// let mut record = bam::Record::default();
+    /// record.insert_data_field(Tag::ALIGNMENT_HIT_COUNT, &Value::UInt8(1))?;
+    ///
+    /// assert!(record.remove_data_field(Tag::ALIGNMENT_HIT_COUNT)?);
+    /// assert!(record.data().is_empty());
+    ///
+    /// assert!(!record.remove_data_field(Tag::ALIGNMENT_HIT_COUNT)?);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn remove_data_field(&mut self, tag: Tag) -> io::Result<bool> {
+        self.0.remove_data_field(tag)
+    }
 }
 
 impl fmt::Debug for Record {
@@ -342,4 +396,140 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_field_iterators_outlive_wrappers() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::alignment::{
+            record::{
+                cigar::{op::Kind, Op},
+                data::field::{Tag, Value},
+                Flags,
+            },
+            record_buf::{data::field::Value as ValueBuf, QualityScores},
+            RecordBuf,
+        };
+
+        use crate::record::codec::encode;
+
+        fn bases(record: &Record) -> impl Iterator<Item = u8> + '_ {
+            record.sequence().iter()
+        }
+
+        fn scores(record: &Record) -> impl Iterator<Item = u8> + '_ {
+            record.quality_scores().iter()
+        }
+
+        fn ops(record: &Record) -> impl Iterator<Item = io::Result<Op>> + '_ {
+            record.cigar().iter()
+        }
+
+        fn fields(record: &Record) -> impl Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_ {
+            record.data().iter()
+        }
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::UNMAPPED)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .set_data(
+                [(Tag::COMMENT, ValueBuf::from("noodles"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+        encode(&mut buf, &header, &record)?;
+        let record = Fields::try_from(buf).map(Record)?;
+
+        assert_eq!(bases(&record).collect::<Vec<_>>(), b"ACGT");
+        assert_eq!(scores(&record).collect::<Vec<_>>(), [45, 35, 43, 50]);
+        assert_eq!(
+            ops(&record).collect::<io::Result<Vec<_>>>()?,
+            [Op::new(Kind::Match, 4)]
+        );
+
+        let actual: Vec<_> = fields(&record).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].0, Tag::COMMENT);
+        assert!(matches!(actual[0].1, Value::String(s) if s == "noodles"));
+
+        assert_eq!(record.quality_scores().get(1), Some(35));
+        assert!(record.quality_scores().get(4).is_none());
+        assert_eq!(
+            record.cigar().get(0).transpose()?,
+            Some(Op::new(Kind::Match, 4))
+        );
+        assert!(record.cigar().get(1).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_and_remove_data_field() -> Result<(), Box<dyn std::error::Error>> {
+        use bstr::ByteSlice;
+        use noodles_sam::alignment::{
+            record::data::field::{Tag, Value},
+            record_buf::{data::field::Value as ValueBuf, Data as DataBuf},
+            RecordBuf,
+        };
+
+        let header = sam::Header::default();
+
+        let record_buf = RecordBuf::builder()
+            .set_name("r0")
+            .set_data(
+                [
+                    (Tag::READ_GROUP, ValueBuf::from("rg0")),
+                    (Tag::ALIGNMENT_HIT_COUNT, ValueBuf::from(1u8)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+        crate::record::codec::encode(&mut buf, &header, &record_buf)?;
+        let mut record = Record(Fields::try_from(buf)?);
+
+        fn fields(record: &Record) -> io::Result<Vec<(Tag, ValueBuf)>> {
+            let data = DataBuf::try_from(record.data())?;
+            Ok(data
+                .iter()
+                .map(|(tag, value)| (tag, value.clone()))
+                .collect())
+        }
+
+        // replace a field with a longer value
+        record.insert_data_field(Tag::READ_GROUP, &Value::String(b"rg_long".as_bstr()))?;
+        // append a new field
+        record.insert_data_field(Tag::CELL_BARCODE_ID, &Value::String(b"ACGT".as_bstr()))?;
+
+        assert_eq!(
+            fields(&record)?,
+            [
+                (Tag::READ_GROUP, ValueBuf::from("rg_long")),
+                (Tag::ALIGNMENT_HIT_COUNT, ValueBuf::from(1u8)),
+                (Tag::CELL_BARCODE_ID, ValueBuf::from("ACGT")),
+            ]
+        );
+
+        assert!(record.remove_data_field(Tag::READ_GROUP)?);
+        assert!(!record.remove_data_field(Tag::READ_GROUP)?);
+
+        assert_eq!(
+            fields(&record)?,
+            [
+                (Tag::ALIGNMENT_HIT_COUNT, ValueBuf::from(1u8)),
+                (Tag::CELL_BARCODE_ID, ValueBuf::from("ACGT")),
+            ]
+        );
+
+        assert_eq!(record.name(), Some(b"r0".as_bstr()));
+
+        Ok(())
+    }
 }
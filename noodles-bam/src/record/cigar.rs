@@ -25,8 +25,21 @@ impl<'a> Cigar<'a> {
         self.0.len() / CHUNK_SIZE
     }
 
+    /// Returns the CIGAR operation at the given index.
+    pub fn get(&self, i: usize) -> Option<io::Result<Op>> {
+        use crate::record::codec::decoder::cigar::op::decode_op;
+
+        let start = i.checked_mul(CHUNK_SIZE)?;
+        let end = start.checked_add(CHUNK_SIZE)?;
+        let buf = self.0.get(start..end)?;
+
+        // SAFETY: `buf.len() == CHUNK_SIZE`.
+        let n = u32::from_le_bytes(buf.try_into().unwrap());
+        Some(decode_op(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+    }
+
     /// Returns an iterator over CIGAR operations.
-    pub fn iter(&self) -> impl Iterator<Item = io::Result<Op>> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<Op>> + 'a {
         use crate::record::codec::decoder::cigar::op::decode_op;
 
         self.0.chunks(CHUNK_SIZE).map(|chunk| {
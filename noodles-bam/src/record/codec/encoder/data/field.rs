@@ -15,7 +15,7 @@ use noodles_sam::alignment::record::{
 pub use self::value::put_value;
 use self::{tag::put_tag, ty::put_type};
 
-pub(super) fn put_field<B>(dst: &mut B, tag: Tag, value: &Value) -> io::Result<()>
+pub(crate) fn put_field<B>(dst: &mut B, tag: Tag, value: &Value) -> io::Result<()>
 where
     B: BufMut,
 {
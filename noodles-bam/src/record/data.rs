@@ -25,7 +25,7 @@ impl<'a> Data<'a> {
     }
 
     /// Returns the value of the given tag.
-    pub fn get<K>(&self, tag: &K) -> Option<io::Result<Value<'_>>>
+    pub fn get<K>(&self, tag: &K) -> Option<io::Result<Value<'a>>>
     where
         K: Borrow<[u8; 2]>,
     {
@@ -44,7 +44,7 @@ impl<'a> Data<'a> {
     }
 
     /// Returns an iterator over all tag-value pairs.
-    pub fn iter(&self) -> impl Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<(Tag, Value<'a>)>> + 'a {
         let mut src = self.0;
 
         iter::from_fn(move || {
@@ -10,7 +10,7 @@ use noodles_sam::alignment::record::data::field::{Tag, Value};
 
 pub(crate) use self::{tag::decode_tag, ty::decode_type, value::decode_value};
 
-pub(super) fn decode_field<'a>(src: &mut &'a [u8]) -> io::Result<(Tag, Value<'a>)> {
+pub(crate) fn decode_field<'a>(src: &mut &'a [u8]) -> io::Result<(Tag, Value<'a>)> {
     let tag = decode_tag(src)?;
 
     let ty = decode_type(src)?;
@@ -2,9 +2,10 @@
 
 mod bounds;
 
-use std::{io, mem};
+use std::{io, mem, ops::Range};
 
 use bstr::{BStr, ByteSlice};
+use noodles_sam::alignment::record::data::field::{Tag, Value};
 
 use self::bounds::Bounds;
 use super::{Cigar, Data, QualityScores, Sequence};
@@ -121,6 +122,52 @@ impl Fields {
         Data::new(src)
     }
 
+    pub(super) fn insert_data_field(&mut self, tag: Tag, value: &Value<'_>) -> io::Result<()> {
+        use super::codec::encoder::data::field::put_field;
+
+        let mut field = Vec::new();
+        put_field(&mut field, tag, value)?;
+
+        // The data fields are the last component of the record buffer, so no other bounds need to
+        // be updated.
+        match self.data_field_range(tag)? {
+            Some(range) => {
+                self.buf.splice(range, field);
+            }
+            None => self.buf.extend(field),
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn remove_data_field(&mut self, tag: Tag) -> io::Result<bool> {
+        match self.data_field_range(tag)? {
+            Some(range) => {
+                self.buf.drain(range);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn data_field_range(&self, tag: Tag) -> io::Result<Option<Range<usize>>> {
+        use super::data::field::decode_field;
+
+        let mut src = &self.buf[self.bounds.data_range()];
+
+        while !src.is_empty() {
+            let start = self.buf.len() - src.len();
+            let (t, _) = decode_field(&mut src)?;
+            let end = self.buf.len() - src.len();
+
+            if t == tag {
+                return Ok(Some(start..end));
+            }
+        }
+
+        Ok(None)
+    }
+
     pub(crate) fn index(&mut self) -> io::Result<()> {
         index(&self.buf[..], &mut self.bounds)
     }
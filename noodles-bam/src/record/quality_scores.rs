@@ -18,6 +18,16 @@ impl<'a> QualityScores<'a> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns the score at the given index.
+    pub fn get(&self, i: usize) -> Option<u8> {
+        self.0.get(i).copied()
+    }
+
+    /// Returns an iterator over the scores.
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
+        self.0.iter().copied()
+    }
 }
 
 impl<'a> sam::alignment::record::QualityScores for QualityScores<'a> {
@@ -30,7 +40,7 @@ impl<'a> sam::alignment::record::QualityScores for QualityScores<'a> {
     }
 
     fn iter(&self) -> Box<dyn Iterator<Item = u8> + '_> {
-        Box::new(self.as_ref().iter().copied())
+        Box::new(self.iter())
     }
 }
 
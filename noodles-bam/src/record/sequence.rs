@@ -64,8 +64,8 @@ impl<'a> Sequence<'a> {
     }
 
     /// Returns an iterator over the bases in the sequence.
-    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
-        Iter::new(self.as_ref(), 0, self.len())
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
+        Iter::new(self.src, 0, self.len())
     }
 }
 
@@ -59,7 +59,7 @@ impl<'a> Subsequence<'a> {
     }
 
     /// Returns an iterator over the bases in the subsequence.
-    pub fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+    pub fn iter(&self) -> impl Iterator<Item = u8> + 'a {
         Iter::new(self.src, self.start, self.end)
     }
 }
@@ -10,7 +10,14 @@ use noodles_vcf as vcf;
 use tokio::io::{self, AsyncRead, AsyncReadExt, AsyncSeek};
 
 use self::{header::read_header, query::query, record::read_record};
-use crate::Record;
+use crate::{
+    io::{
+        reader::V2_1,
+        writer::{MAJOR, MINOR},
+    },
+    record::compat::upgrade_record,
+    Record,
+};
 
 /// An async BCF reader.
 ///
@@ -36,8 +43,28 @@ use crate::Record;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## Set the number of BGZF decoding workers
+///
+/// [`Reader::new`] wraps the input stream with a default async BGZF decoder, which decompresses
+/// blocks concurrently using a worker per available logical CPU. The number of workers can be
+/// set by building the decoder using [`noodles_bgzf::r#async::reader::Builder`] and wrapping it
+/// using [`Reader::from`].
+///
+/// ```
+/// # use std::num::NonZeroUsize;
+/// use noodles_bcf as bcf;
+/// use noodles_bgzf as bgzf;
+/// use tokio::io;
+///
+/// let decoder = bgzf::r#async::reader::Builder::default()
+///     .set_worker_count(NonZeroUsize::MIN)
+///     .build_from_reader(io::empty());
+/// let _reader = bcf::r#async::io::Reader::from(decoder);
+/// ```
 pub struct Reader<R> {
     inner: R,
+    format_version: (u8, u8),
 }
 
 impl<R> Reader<R>
@@ -88,7 +115,8 @@ where
 
     /// Reads the VCF header.
     ///
-    /// The BCF magic number is checked, and the file format version is discarded.
+    /// The BCF magic number is checked, and the file format version is read. Records of BCF 2.1
+    /// files are upgraded to BCF 2.2 when read.
     ///
     /// The position of the stream is expected to be at the start.
     ///
@@ -109,7 +137,7 @@ where
     /// ```
     pub async fn read_header(&mut self) -> io::Result<vcf::Header> {
         read_magic(&mut self.inner).await?;
-        read_format_version(&mut self.inner).await?;
+        self.format_version = read_format_version(&mut self.inner).await?;
         read_header(&mut self.inner).await
     }
 
@@ -140,7 +168,13 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record).await
+        let n = read_record(&mut self.inner, record).await?;
+
+        if n > 0 && self.format_version == V2_1 {
+            upgrade_record(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an (async) stream over lazy records starting from the current (input) stream
@@ -173,14 +207,12 @@ where
     /// ```
     pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
         Box::pin(stream::try_unfold(
-            (&mut self.inner, Record::default()),
-            |(mut reader, mut record)| async {
-                read_record(&mut reader, &mut record)
-                    .await
-                    .map(|n| match n {
-                        0 => None,
-                        _ => Some((record.clone(), (reader, record))),
-                    })
+            (self, Record::default()),
+            |(reader, mut record)| async {
+                reader.read_record(&mut record).await.map(|n| match n {
+                    0 => None,
+                    _ => Some((record.clone(), (reader, record))),
+                })
             },
         ))
     }
@@ -259,7 +291,10 @@ where
 
 impl<R> From<R> for Reader<R> {
     fn from(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            format_version: (MAJOR, MINOR),
+        }
     }
 }
 
@@ -289,7 +324,13 @@ where
     let major_version = reader.read_u8().await?;
     let minor_version = reader.read_u8().await?;
 
-    Ok((major_version, minor_version))
+    match (major_version, minor_version) {
+        V2_1 | (MAJOR, MINOR) => Ok((major_version, minor_version)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported BCF version: {major_version}.{minor_version}"),
+        )),
+    }
 }
 
 #[cfg(test)]
@@ -322,6 +363,14 @@ mod tests {
         let data = [0x02, 0x01];
         let mut reader = &data[..];
         assert_eq!(read_format_version(&mut reader).await?, (2, 1));
+
+        let data = [0x01, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_format_version(&mut reader).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
         Ok(())
     }
 }
@@ -157,7 +157,7 @@ where
         use crate::io::writer::write_record;
 
         self.buf.clear();
-        write_record(&mut self.buf, header, &self.string_maps, record)?;
+        write_record(&mut self.buf, header, &self.string_maps, None, record)?;
         self.inner.write_all(&self.buf).await
     }
 }
@@ -12,7 +12,7 @@ use noodles_csi::BinningIndex;
 use noodles_vcf::{self as vcf, variant::RecordBuf};
 
 use super::{
-    reader::{Query, RecordBufs},
+    reader::{MultiQuery, Query, RecordBufs},
     Reader,
 };
 use crate::Record;
@@ -105,4 +105,13 @@ where
     ) -> io::Result<Query<'r, 'h, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    pub fn query_many<'r, 'h>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'r, 'h, R>> {
+        self.inner.query_many(header, &self.index, regions)
+    }
 }
@@ -2,12 +2,13 @@
 
 mod builder;
 mod header;
+mod multi_query;
 pub(crate) mod query;
 pub(crate) mod record;
 pub(crate) mod record_buf;
 mod record_bufs;
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{builder::Builder, multi_query::MultiQuery, query::Query, record_bufs::RecordBufs};
 
 use std::{
     io::{self, BufRead, Read},
@@ -21,7 +22,13 @@ use noodles_csi::BinningIndex;
 use noodles_vcf::{self as vcf, header::string_maps::ContigStringMap, variant::RecordBuf};
 
 use self::{header::read_header, record::read_record, record_buf::read_record_buf};
-use crate::Record;
+use crate::{
+    io::writer::{MAJOR, MINOR},
+    record::compat::upgrade_record,
+    Record,
+};
+
+pub(crate) const V2_1: (u8, u8) = (2, 1);
 
 /// A BCF reader.
 ///
@@ -29,6 +36,7 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    format_version: (u8, u8),
 }
 
 impl<R> Reader<R>
@@ -79,9 +87,12 @@ where
 
     /// Reads the VCF header.
     ///
-    /// This verifies the BCF magic number, discards the file format version, and reads and parses
+    /// This verifies the BCF magic number, reads the file format version, and reads and parses
     /// the raw VCF header. Associated string maps are also built from the raw header.
     ///
+    /// BCF 2.1 and 2.2 are supported. Records of BCF 2.1 files are upgraded to BCF 2.2 when read,
+    /// i.e., integer values that are reserved in BCF 2.2 are widened to the next integer type.
+    ///
     /// The position of the stream is expected to be at the start.
     ///
     /// # Examples
@@ -95,7 +106,7 @@ where
     /// ```
     pub fn read_header(&mut self) -> io::Result<vcf::Header> {
         read_magic(&mut self.inner)?;
-        read_format_version(&mut self.inner)?;
+        self.format_version = read_format_version(&mut self.inner)?;
         read_header(&mut self.inner)
     }
 
@@ -128,7 +139,13 @@ where
         header: &vcf::Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, header, &mut self.buf, record)
+        read_record_buf(
+            &mut self.inner,
+            header,
+            &mut self.buf,
+            self.format_version,
+            record,
+        )
     }
 
     /// Reads a single record without eagerly decoding (most of) its fields.
@@ -155,7 +172,13 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if n > 0 && self.format_version == V2_1 {
+            upgrade_record(record)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -270,12 +293,70 @@ where
 
         Ok(Query::new(
             &mut self.inner,
+            self.format_version,
             header,
             chunks,
             reference_sequence_id,
             region.interval(),
         ))
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each record is read at most once, even if it
+    /// intersects more than one region. Each record is returned with the indices of the regions
+    /// it intersects, in the order the regions are given.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bcf as bcf;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = csi::read("sample.bcf.csi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:10-21".parse()?];
+    /// let query = reader.query_many(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_many<'r, 'h, I>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::merge_chunks;
+
+        let mut chunks = Vec::new();
+        let mut resolved_regions = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let reference_sequence_id = resolve_region(header.string_maps().contigs(), region)?;
+            chunks.extend(index.query(reference_sequence_id, region.interval())?);
+            resolved_regions.push((reference_sequence_id, region.interval()));
+        }
+
+        let chunks = merge_chunks(&chunks);
+
+        Ok(MultiQuery::new(
+            &mut self.inner,
+            self.format_version,
+            chunks,
+            resolved_regions,
+            header,
+        ))
+    }
 }
 
 impl<R> From<R> for Reader<R> {
@@ -283,6 +364,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            format_version: (MAJOR, MINOR),
         }
     }
 }
@@ -333,7 +415,13 @@ where
     let major_version = reader.read_u8()?;
     let minor_version = reader.read_u8()?;
 
-    Ok((major_version, minor_version))
+    match (major_version, minor_version) {
+        V2_1 | (MAJOR, MINOR) => Ok((major_version, minor_version)),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("unsupported BCF version: {major_version}.{minor_version}"),
+        )),
+    }
 }
 
 pub(crate) fn resolve_region(
@@ -381,6 +469,77 @@ mod tests {
         let data = [0x02, 0x01];
         let mut reader = &data[..];
         assert_eq!(read_format_version(&mut reader)?, (2, 1));
+
+        let data = [0x02, 0x02];
+        let mut reader = &data[..];
+        assert_eq!(read_format_version(&mut reader)?, (2, 2));
+
+        let data = [0x01, 0x00];
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_format_version(&mut reader),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_v2_1() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::record::info::field::{key, Value};
+
+        const HEADER: &[u8] = b"##fileformat=VCFv4.2
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+\0";
+
+        const SITE: &[u8] = &[
+            0x00, 0x00, 0x00, 0x00, // chrom = 0
+            0x00, 0x00, 0x00, 0x00, // pos = 0 (0-based)
+            0x01, 0x00, 0x00, 0x00, // rlen = 1
+            0x01, 0x00, 0x80, 0x7f, // qual = missing
+            0x01, 0x00, // n_info = 1
+            0x01, 0x00, // n_allele = 1
+            0x00, 0x00, 0x00, // n_sample = 0
+            0x00, // n_fmt = 0
+            0x07, // ID = missing
+            0x17, b'A', // REF = A
+            0x00, // FILTER = missing
+            0x11, 0x01, // infos[0].key = 1 (DP)
+            0x11, 0x86, // infos[0].value = -122
+        ];
+
+        let mut src = Vec::new();
+        src.extend(b"BCF\x02\x01");
+        src.extend(u32::try_from(HEADER.len())?.to_le_bytes());
+        src.extend(HEADER);
+        src.extend(u32::try_from(SITE.len())?.to_le_bytes());
+        src.extend(0u32.to_le_bytes());
+        src.extend(SITE);
+
+        let mut reader = Reader::from(&src[..]);
+        let header = reader.read_header()?;
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+
+        assert!(matches!(
+            record.info().get(&header, key::TOTAL_DEPTH),
+            Some(Ok(Some(Value::Integer(-122))))
+        ));
+
+        let mut reader = Reader::from(&src[..]);
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+
+        assert_eq!(
+            record.info().get(key::TOTAL_DEPTH),
+            Some(Some(
+                &vcf::variant::record_buf::info::field::Value::Integer(-122)
+            ))
+        );
+
         Ok(())
     }
 }
@@ -0,0 +1,160 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+use noodles_vcf as vcf;
+
+use super::{query::intersects, Reader};
+use crate::Record;
+
+/// An iterator over records of a BCF reader that intersect any of the given regions.
+///
+/// This is created by calling [`Reader::query_many`].
+pub struct MultiQuery<'r, 'h, R> {
+    reader: Reader<csi::io::Query<'r, R>>,
+    regions: Vec<(usize, Interval)>,
+    header: &'h vcf::Header,
+    record: Record,
+}
+
+impl<'r, 'h, R> MultiQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        format_version: (u8, u8),
+        chunks: Vec<Chunk>,
+        regions: Vec<(usize, Interval)>,
+        header: &'h vcf::Header,
+    ) -> Self {
+        let mut reader = Reader::from(csi::io::Query::new(reader, chunks));
+        reader.format_version = format_version;
+
+        Self {
+            reader,
+            regions,
+            header,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for MultiQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Record, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => match intersecting_regions(self.header, &self.record, &self.regions) {
+                    Ok(indices) if indices.is_empty() => {}
+                    Ok(indices) => return Some(Ok((self.record.clone(), indices))),
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn intersecting_regions(
+    header: &vcf::Header,
+    record: &Record,
+    regions: &[(usize, Interval)],
+) -> io::Result<Vec<usize>> {
+    let mut indices = Vec::new();
+
+    for (i, (reference_sequence_id, interval)) in regions.iter().enumerate() {
+        if intersects(header, record, *reference_sequence_id, *interval)? {
+            indices.push(i);
+        }
+    }
+
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_vcf::{
+        header::record::value::{map::Contig, Map},
+        variant::{io::Write, Record as _, RecordBuf},
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let records = [("sq0", 1), ("sq1", 1), ("sq1", 5), ("sq1", 10)]
+            .into_iter()
+            .map(|(reference_sequence_name, position)| {
+                Position::try_from(position).map(|position| {
+                    RecordBuf::builder()
+                        .set_reference_sequence_name(reference_sequence_name)
+                        .set_variant_start(position)
+                        .set_reference_bases("ACGT")
+                        .build()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let mut indexer = csi::binning_index::Indexer::default();
+
+        for (reference_sequence_id, record) in [0, 1, 1, 1].into_iter().zip(&records) {
+            let start_position = writer.get_ref().virtual_position();
+            writer.write_variant_record(&header, record)?;
+            let end_position = writer.get_ref().virtual_position();
+
+            let start = record.variant_start().expect("missing variant start");
+            let end = record.variant_end(&header)?;
+            let chunk = Chunk::new(start_position, end_position);
+
+            indexer.add_record(Some((reference_sequence_id, start, end, true)), chunk)?;
+        }
+
+        let index: csi::Index = indexer.build(header.contigs().len());
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(Cursor::new(src));
+        let header = reader.read_header()?;
+
+        let regions = ["sq1:2-6".parse()?, "sq1:4-11".parse()?, "sq0".parse()?];
+        let query = reader.query_many(&header, &index, &regions)?;
+
+        let actual: Vec<_> = query
+            .map(|result| {
+                result.and_then(|(record, region_indices)| {
+                    RecordBuf::try_from_variant_record(&header, &record)
+                        .map(|record| (record, region_indices))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = [
+            (records[0].clone(), vec![2]),
+            (records[1].clone(), vec![0, 1]),
+            (records[2].clone(), vec![0, 1]),
+            (records[3].clone(), vec![1]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -25,13 +25,17 @@ where
 {
     pub(super) fn new(
         reader: &'r mut R,
+        format_version: (u8, u8),
         header: &'h vcf::Header,
         chunks: Vec<Chunk>,
         reference_sequence_id: usize,
         interval: Interval,
     ) -> Self {
+        let mut reader = Reader::from(csi::io::Query::new(reader, chunks));
+        reader.format_version = format_version;
+
         Self {
-            reader: Reader::from(csi::io::Query::new(reader, chunks)),
+            reader,
             header,
             reference_sequence_id,
             interval,
@@ -61,7 +65,7 @@ where
     }
 }
 
-fn intersects(
+pub(super) fn intersects(
     header: &vcf::Header,
     record: &Record,
     reference_sequence_id: usize,
@@ -7,12 +7,17 @@ pub(super) fn read_record_buf<R>(
     reader: &mut R,
     header: &vcf::Header,
     buf: &mut Vec<u8>,
+    format_version: (u8, u8),
     record: &mut RecordBuf,
 ) -> io::Result<usize>
 where
     R: Read,
 {
-    use crate::record::codec::decoder::{read_samples, read_site};
+    use super::V2_1;
+    use crate::record::{
+        codec::decoder::{read_samples, read_site},
+        compat::{upgrade_samples, upgrade_site},
+    };
 
     let l_shared = match reader.read_u32::<LittleEndian>() {
         Ok(n) => usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
@@ -26,11 +31,21 @@ where
 
     buf.resize(l_shared, 0);
     reader.read_exact(buf)?;
+
+    if format_version == V2_1 {
+        upgrade_site(buf)?;
+    }
+
     let mut src = &buf[..];
     let (n_fmt, n_sample) = read_site(&mut src, header, record)?;
 
     buf.resize(l_indiv, 0);
     reader.read_exact(buf)?;
+
+    if format_version == V2_1 {
+        upgrade_samples(buf, n_fmt, n_sample)?;
+    }
+
     let mut src = &buf[..];
 
     *record.samples_mut() = read_samples(&mut src, header, n_sample, n_fmt)
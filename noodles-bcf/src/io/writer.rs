@@ -8,10 +8,10 @@ use std::io::{self, Write};
 
 use byteorder::WriteBytesExt;
 use noodles_bgzf as bgzf;
-use noodles_vcf::{self as vcf, header::StringMaps};
+use noodles_vcf::{self as vcf, header::StringMaps, io::SampleSelection};
 
 pub use self::builder::Builder;
-use self::header::write_header;
+use self::header::{add_missing_idx, write_header};
 pub(crate) use self::record::write_record;
 use crate::Record;
 
@@ -22,6 +22,8 @@ pub(crate) const MINOR: u8 = 2;
 pub struct Writer<W> {
     inner: W,
     string_maps: StringMaps,
+    sample_selection: Option<SampleSelection>,
+    sample_indices: Option<Vec<usize>>,
 }
 
 impl<W> Writer<W>
@@ -69,6 +71,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// Contig, INFO, FILTER, and FORMAT records without an `IDX` field are written with their
+    /// dictionary index.
+    ///
     /// # Examples
     ///
     /// ```
@@ -88,7 +93,15 @@ where
         self.string_maps = StringMaps::try_from(header)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        write_header(&mut self.inner, header)
+        let header = add_missing_idx(header, &self.string_maps);
+
+        if let Some(sample_selection) = &self.sample_selection {
+            self.sample_indices = Some(sample_selection.resolve(header.sample_names())?);
+            let header = sample_selection.subset_header(&header)?;
+            write_header(&mut self.inner, &header)
+        } else {
+            write_header(&mut self.inner, &header)
+        }
     }
 
     /// Writes a record.
@@ -121,7 +134,13 @@ where
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, header: &vcf::Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, &self.string_maps, record)
+        write_record(
+            &mut self.inner,
+            header,
+            &self.string_maps,
+            self.sample_indices.as_deref(),
+            record,
+        )
     }
 }
 
@@ -168,6 +187,8 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             string_maps: StringMaps::default(),
+            sample_selection: None,
+            sample_indices: None,
         }
     }
 }
@@ -185,7 +206,13 @@ where
         header: &vcf::Header,
         record: &dyn vcf::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, &self.string_maps, record)
+        write_record(
+            &mut self.inner,
+            header,
+            &self.string_maps,
+            self.sample_indices.as_deref(),
+            record,
+        )
     }
 }
 
@@ -221,4 +248,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_with_sample_selection() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::{
+                io::Write as _,
+                record::samples::keys::key,
+                record_buf::{samples::sample::Value, Samples},
+                RecordBuf,
+            },
+        };
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_format(key::GENOTYPE, Map::from(key::GENOTYPE))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let samples = Samples::new(
+            [String::from(key::GENOTYPE)].into_iter().collect(),
+            vec![
+                vec![Some(Value::from("0/0"))],
+                vec![Some(Value::from("0/1"))],
+                vec![Some(Value::from("1/1"))],
+            ],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_samples(samples)
+            .build();
+
+        let mut writer = Writer::from(Vec::new());
+        writer.sample_selection = Some(SampleSelection::Names(vec![
+            String::from("sample2"),
+            String::from("sample0"),
+        ]));
+
+        writer.write_header(&header)?;
+        writer.write_variant_record(&header, &record)?;
+
+        let mut reader = crate::io::Reader::from(&writer.get_ref()[..]);
+        let actual_header = reader.read_header()?;
+
+        let expected: vcf::header::SampleNames = [String::from("sample2"), String::from("sample0")]
+            .into_iter()
+            .collect();
+        assert_eq!(actual_header.sample_names(), &expected);
+
+        let mut actual_record = RecordBuf::default();
+        reader.read_record_buf(&actual_header, &mut actual_record)?;
+
+        let actual: Vec<_> = actual_record
+            .samples()
+            .values()
+            .map(|sample| sample.values().to_vec())
+            .collect();
+
+        let expected = [
+            vec![Some(Value::Genotype("1/1".parse()?))],
+            vec![Some(Value::Genotype("0/0".parse()?))],
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
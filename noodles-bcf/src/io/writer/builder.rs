@@ -7,12 +7,15 @@ use std::{
 use noodles_bgzf as bgzf;
 
 use super::Writer;
+use noodles_vcf::io::SampleSelection;
+
 use crate::io::CompressionMethod;
 
 /// A BCF writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    sample_selection: Option<SampleSelection>,
 }
 
 impl Builder {
@@ -29,6 +32,26 @@ impl Builder {
         self
     }
 
+    /// Restricts writing to a selection of samples.
+    ///
+    /// The header written by [`Writer::write_header`] only includes the selected sample names, and
+    /// only the selected samples of each record are encoded. Records are still written using the
+    /// full header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::writer::Builder;
+    /// use noodles_vcf::io::SampleSelection;
+    ///
+    /// let builder = Builder::default()
+    ///     .set_sample_selection(SampleSelection::Names(vec![String::from("sample0")]));
+    /// ```
+    pub fn set_sample_selection(mut self, sample_selection: SampleSelection) -> Self {
+        self.sample_selection = Some(sample_selection);
+        self
+    }
+
     /// Builds a BCF writer from a path.
     ///
     /// # Examples
@@ -64,6 +87,9 @@ impl Builder {
             Some(CompressionMethod::None) => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::from(inner)
+        let mut writer = Writer::from(inner);
+        writer.sample_selection = self.sample_selection;
+
+        writer
     }
 }
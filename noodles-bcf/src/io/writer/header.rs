@@ -1,10 +1,11 @@
 use std::{
+    borrow::Cow,
     ffi::CString,
     io::{self, Write},
 };
 
 use byteorder::{LittleEndian, WriteBytesExt};
-use noodles_vcf as vcf;
+use noodles_vcf::{self as vcf, header::StringMaps};
 
 pub(super) fn write_header<W>(writer: &mut W, header: &vcf::Header) -> io::Result<()>
 where
@@ -24,6 +25,59 @@ where
     Ok(())
 }
 
+// Sets the `IDX` field of contig, INFO, FILTER, and FORMAT records that do not have one to their
+// dictionary index, so that readers can rebuild the same dictionaries from the written header.
+pub(super) fn add_missing_idx<'h>(
+    header: &'h vcf::Header,
+    string_maps: &StringMaps,
+) -> Cow<'h, vcf::Header> {
+    let is_complete = header
+        .contigs()
+        .values()
+        .all(|contig| contig.idx().is_some())
+        && header.infos().values().all(|info| info.idx().is_some())
+        && header
+            .filters()
+            .values()
+            .all(|filter| filter.idx().is_some())
+        && header
+            .formats()
+            .values()
+            .all(|format| format.idx().is_some());
+
+    if is_complete {
+        return Cow::Borrowed(header);
+    }
+
+    let mut header = header.clone();
+
+    for (id, contig) in header.contigs_mut() {
+        if contig.idx().is_none() {
+            *contig.idx_mut() = string_maps.contigs().get_index_of(id);
+        }
+    }
+
+    for (id, info) in header.infos_mut() {
+        if info.idx().is_none() {
+            *info.idx_mut() = string_maps.strings().get_index_of(id);
+        }
+    }
+
+    for (id, filter) in header.filters_mut() {
+        if filter.idx().is_none() {
+            *filter.idx_mut() = string_maps.strings().get_index_of(id);
+        }
+    }
+
+    for (id, format) in header.formats_mut() {
+        if format.idx().is_none() {
+            *format.idx_mut() = string_maps.strings().get_index_of(id);
+        }
+    }
+
+    Cow::Owned(header)
+}
+
 pub(crate) fn serialize_header(header: &vcf::Header) -> io::Result<Vec<u8>> {
     let mut writer = vcf::io::Writer::new(Vec::new());
     writer.write_header(header)?;
@@ -55,4 +109,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_add_missing_idx() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{
+                map::{Contig, Filter, Info},
+                Map,
+            },
+            variant::record::info::field::key,
+        };
+
+        let header = vcf::Header::builder()
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::builder().set_idx(3).build()?)
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        let actual = add_missing_idx(&header, &string_maps);
+
+        assert_eq!(actual.filters()["q10"].idx(), Some(2));
+        assert_eq!(actual.infos()[key::TOTAL_DEPTH].idx(), Some(1));
+        assert_eq!(actual.contigs()["sq0"].idx(), Some(0));
+        assert_eq!(actual.contigs()["sq1"].idx(), Some(3));
+
+        let string_maps = StringMaps::try_from(actual.as_ref())?;
+        assert!(matches!(
+            add_missing_idx(&actual, &string_maps),
+            Cow::Borrowed(_)
+        ));
+
+        Ok(())
+    }
 }
@@ -7,6 +7,7 @@ pub fn write_record<W, R>(
     writer: &mut W,
     header: &vcf::Header,
     string_maps: &StringMaps,
+    sample_indices: Option<&[usize]>,
     record: &R,
 ) -> io::Result<()>
 where
@@ -16,7 +17,7 @@ where
     use crate::record::codec::encoder::{samples::write_samples, site::write_site};
 
     let mut site_buf = Vec::new();
-    write_site(&mut site_buf, header, string_maps, record)?;
+    write_site(&mut site_buf, header, string_maps, sample_indices, record)?;
 
     let l_shared = u32::try_from(site_buf.len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -24,8 +25,19 @@ where
     let mut samples_buf = Vec::new();
     let samples = record.samples()?;
 
-    if !samples.is_empty() {
-        write_samples(&mut samples_buf, header, string_maps, samples)?;
+    let has_samples = match sample_indices {
+        Some(indices) => !indices.is_empty(),
+        None => !samples.is_empty(),
+    };
+
+    if has_samples {
+        write_samples(
+            &mut samples_buf,
+            header,
+            string_maps,
+            sample_indices,
+            samples,
+        )?;
     };
 
     let l_indiv = u32::try_from(samples_buf.len())
@@ -61,7 +73,7 @@ mod tests {
             .build();
 
         let mut buf = Vec::new();
-        write_record(&mut buf, &header, &string_maps, &record)?;
+        write_record(&mut buf, &header, &string_maps, None, &record)?;
 
         let expected = [
             0x1c, 0x00, 0x00, 0x00, // l_shared = 28
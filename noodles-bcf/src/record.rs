@@ -2,10 +2,12 @@
 
 mod alternate_bases;
 pub(crate) mod codec;
+pub(crate) mod compat;
 mod fields;
 mod filters;
 mod ids;
 mod info;
+mod layout;
 mod reference_bases;
 pub mod samples;
 mod value;
@@ -18,7 +20,7 @@ use noodles_vcf::{self as vcf, header::StringMaps};
 use self::fields::Fields;
 pub(crate) use self::value::Value;
 pub use self::{
-    alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info,
+    alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info, layout::Layout,
     reference_bases::ReferenceBases, samples::Samples,
 };
 
@@ -235,6 +237,129 @@ impl Record {
     pub fn samples(&self) -> io::Result<Samples<'_>> {
         self.0.samples()
     }
+
+    /// Returns the encoded layout of the record.
+    ///
+    /// The layout includes the sizes of the shared and individual blocks, the allele, format, and
+    /// sample counts, and the encoded size of each info field and samples series.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::default();
+    /// let record = bcf::Record::default();
+    /// let layout = record.layout(&header)?;
+    ///
+    /// assert_eq!(layout.shared_len(), 28);
+    /// assert_eq!(layout.indiv_len(), 0);
+    /// assert_eq!(layout.allele_count(), 1);
+    /// assert!(layout.info_field_sizes().is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn layout<'h>(&self, header: &'h vcf::Header) -> io::Result<Layout<'h>> {
+        self.0.layout(header)
+    }
+
+    /// Replaces the filters.
+    ///
+    /// Only the filters are encoded. The other fields of the record are kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Filter, Map},
+    ///     variant::{record::Filters as _, record_buf::Filters},
+    /// };
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+    ///     .build();
+    /// *header.string_maps_mut() = vcf::header::StringMaps::try_from(&header)?;
+    ///
+    /// let mut record = bcf::Record::default();
+    /// let filters: Filters = [String::from("q10")].into_iter().collect();
+    /// record.set_filters(&header, &filters)?;
+    ///
+    /// let filters = record.filters();
+    /// let actual: Vec<_> = filters.iter(&header).collect::<Result<_, _>>()?;
+    /// assert_eq!(actual, ["q10"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn set_filters<F>(&mut self, header: &vcf::Header, filters: F) -> io::Result<()>
+    where
+        F: vcf::variant::record::Filters,
+    {
+        use self::codec::encoder::site::write_filters;
+
+        let mut buf = Vec::new();
+        write_filters(&mut buf, header, header.string_maps(), filters)?;
+        self.0.replace_filters(&buf)
+    }
+
+    /// Inserts an info field.
+    ///
+    /// If a field with the given key exists, its value is replaced. Otherwise, the field is
+    /// appended to the info fields. Only the given field is encoded. The other fields of the
+    /// record are kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info, Map},
+    ///     variant::record::info::field::{key, Value},
+    /// };
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+    ///     .build();
+    /// *header.string_maps_mut() = vcf::header::StringMaps::try_from(&header)?;
+    ///
+    /// let mut record = bcf::Record::default();
+    /// record.insert_info(&header, key::TOTAL_DEPTH, Some(Value::Integer(13)))?;
+    ///
+    /// assert!(matches!(
+    ///     record.info().get(&header, key::TOTAL_DEPTH),
+    ///     Some(Ok(Some(Value::Integer(13))))
+    /// ));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn insert_info(
+        &mut self,
+        header: &vcf::Header,
+        key: &str,
+        value: Option<vcf::variant::record::info::field::Value<'_>>,
+    ) -> io::Result<()> {
+        use self::codec::encoder::site::write_info_field;
+
+        if !header.infos().contains_key(key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("info key missing from header: {key:?}"),
+            ));
+        }
+
+        let string_string_map = header.string_maps().strings();
+
+        let j = string_string_map.get_index_of(key).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("info key missing from string map: {key:?}"),
+            )
+        })?;
+
+        let mut buf = Vec::new();
+        write_info_field(&mut buf, string_string_map, key, value)?;
+        self.0.insert_info_field(j, &buf)
+    }
 }
 
 impl fmt::Debug for Record {
@@ -17,8 +17,13 @@ use noodles_vcf as vcf;
 
 use self::info::read_info;
 pub(crate) use self::{
-    bases::read_ref_alt, chromosome_id::read_chrom, filters::read_filter, ids::read_id,
-    position::read_pos, quality_score::read_qual, string_map::read_string_map_entry,
+    bases::read_ref_alt,
+    chromosome_id::read_chrom,
+    filters::read_filter,
+    ids::read_id,
+    position::read_pos,
+    quality_score::read_qual,
+    string_map::{read_string_map_entry, read_string_map_index},
 };
 pub use self::{samples::read_samples, value::read_value};
 
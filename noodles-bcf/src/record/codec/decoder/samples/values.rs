@@ -77,8 +77,8 @@ fn read_i8_values(src: &mut &[u8], sample_count: usize) -> Result<Vec<Option<Val
 
         match value {
             Int8::Value(n) => values.push(Some(Value::from(i32::from(n)))),
-            Int8::Missing => values.push(None),
-            _ => todo!("unhandled i8 value: {:?}", value),
+            Int8::Missing | Int8::EndOfVector => values.push(None),
+            Int8::Reserved(_) => return Err(DecodeError::ReservedValue),
         }
     }
 
@@ -98,19 +98,15 @@ fn read_i8_array_values(
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int8::from)
-            .filter_map(|value| match value {
-                Int8::Value(n) => Some(Some(i32::from(n))),
-                Int8::Missing => Some(None),
+            .map_while(|value| match value {
+                Int8::Value(n) => Some(Ok(Some(i32::from(n)))),
+                Int8::Missing => Some(Ok(None)),
                 Int8::EndOfVector => None,
-                _ => todo!("unhandled i8 array value: {:?}", value),
+                Int8::Reserved(_) => Some(Err(DecodeError::ReservedValue)),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
-        }
+        values.push(array_value(vs));
     }
 
     Ok(values)
@@ -129,8 +125,8 @@ fn read_i16_values(
 
         match value {
             Int16::Value(n) => values.push(Some(Value::from(i32::from(n)))),
-            Int16::Missing => values.push(None),
-            _ => todo!("unhandled i16 value: {:?}", value),
+            Int16::Missing | Int16::EndOfVector => values.push(None),
+            Int16::Reserved(_) => return Err(DecodeError::ReservedValue),
         }
     }
 
@@ -150,19 +146,15 @@ fn read_i16_array_values(
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int16::from)
-            .filter_map(|value| match value {
-                Int16::Value(n) => Some(Some(i32::from(n))),
-                Int16::Missing => Some(None),
+            .map_while(|value| match value {
+                Int16::Value(n) => Some(Ok(Some(i32::from(n)))),
+                Int16::Missing => Some(Ok(None)),
                 Int16::EndOfVector => None,
-                _ => todo!("unhandled i16 array value: {:?}", value),
+                Int16::Reserved(_) => Some(Err(DecodeError::ReservedValue)),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
-        }
+        values.push(array_value(vs));
     }
 
     Ok(values)
@@ -181,8 +173,8 @@ fn read_i32_values(
 
         match value {
             Int32::Value(n) => values.push(Some(Value::from(n))),
-            Int32::Missing => values.push(None),
-            _ => todo!("unhandled i32 value: {:?}", value),
+            Int32::Missing | Int32::EndOfVector => values.push(None),
+            Int32::Reserved(_) => return Err(DecodeError::ReservedValue),
         }
     }
 
@@ -202,19 +194,15 @@ fn read_i32_array_values(
         let vs: Vec<_> = buf
             .into_iter()
             .map(Int32::from)
-            .filter_map(|value| match value {
-                Int32::Value(n) => Some(Some(n)),
-                Int32::Missing => Some(None),
+            .map_while(|value| match value {
+                Int32::Value(n) => Some(Ok(Some(n))),
+                Int32::Missing => Some(Ok(None)),
                 Int32::EndOfVector => None,
-                _ => todo!("unhandled i32 array value: {:?}", value),
+                Int32::Reserved(_) => Some(Err(DecodeError::ReservedValue)),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
-        }
+        values.push(array_value(vs));
     }
 
     Ok(values)
@@ -233,8 +221,8 @@ fn read_f32_values(
 
         match value {
             Float::Value(n) => values.push(Some(Value::from(n))),
-            Float::Missing => values.push(None),
-            _ => todo!("unhandled f32 value: {:?}", value),
+            Float::Missing | Float::EndOfVector => values.push(None),
+            Float::Reserved(_) => return Err(DecodeError::ReservedValue),
         }
     }
 
@@ -254,24 +242,32 @@ fn read_f32_array_values(
         let vs: Vec<_> = buf
             .into_iter()
             .map(Float::from)
-            .filter_map(|value| match value {
-                Float::Value(n) => Some(Some(n)),
-                Float::Missing => Some(None),
+            .map_while(|value| match value {
+                Float::Value(n) => Some(Ok(Some(n))),
+                Float::Missing => Some(Ok(None)),
                 Float::EndOfVector => None,
-                _ => todo!("unhandled f32 array value: {:?}", value),
+                Float::Reserved(_) => Some(Err(DecodeError::ReservedValue)),
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
 
-        if vs.len() == 1 && vs[0].is_none() {
-            values.push(None);
-        } else {
-            values.push(Some(Value::from(vs)));
-        }
+        values.push(array_value(vs));
     }
 
     Ok(values)
 }
 
+// A sample array value is missing if it is empty, i.e., starts with an end-of-vector value, or
+// only holds a single missing value.
+fn array_value<N>(vs: Vec<Option<N>>) -> Option<Value>
+where
+    Value: From<Vec<Option<N>>>,
+{
+    match vs[..] {
+        [] | [None] => None,
+        _ => Some(Value::from(vs)),
+    }
+}
+
 fn read_string_until_nul<'a>(src: &mut &'a [u8], len: usize) -> Result<&'a str, DecodeError> {
     const NUL: u8 = 0x00;
 
@@ -473,6 +469,7 @@ pub enum DecodeError {
     InvalidRawValue(raw_value::DecodeError),
     InvalidString(str::Utf8Error),
     InvalidGenotype,
+    ReservedValue,
 }
 
 impl error::Error for DecodeError {
@@ -494,6 +491,7 @@ impl fmt::Display for DecodeError {
             Self::InvalidRawValue(_) => write!(f, "invalid raw value"),
             Self::InvalidString(_) => write!(f, "invalid string"),
             Self::InvalidGenotype => write!(f, "invalid genotype"),
+            Self::ReservedValue => write!(f, "reserved value"),
         }
     }
 }
@@ -538,6 +536,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_read_values_with_int8_end_of_vector_values() {
+        let mut src = &[
+            0x11, // Some(Type::Int8(1))
+            0x05, // Some(5)
+            0x81, // None (end of vector)
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(1), format::Type::Integer, 2),
+            Ok(vec![Some(Value::from(5)), None])
+        );
+
+        let mut src = &[
+            0x31, // Some(Type::Int8(3))
+            0x00, 0x03, 0x09, // Some([Some(0), Some(3), Some(9)])
+            0x00, 0x80, 0x81, // Some([Some(0), None])
+            0x81, 0x81, 0x81, // None
+        ][..];
+
+        assert_eq!(
+            read_values(
+                &mut src,
+                Number::ReferenceAlternateBases,
+                format::Type::Integer,
+                3
+            ),
+            Ok(vec![
+                Some(Value::from(vec![Some(0), Some(3), Some(9)])),
+                Some(Value::from(vec![Some(0), None])),
+                None,
+            ])
+        );
+
+        let mut src = &[
+            0x21, // Some(Type::Int8(2))
+            0x05, 0x82, // Some([Some(5), (reserved)])
+        ][..];
+
+        assert_eq!(
+            read_values(&mut src, Number::Count(2), format::Type::Integer, 1),
+            Err(DecodeError::ReservedValue)
+        );
+    }
+
     #[test]
     fn test_read_values_with_int16_values() {
         let mut src = &[
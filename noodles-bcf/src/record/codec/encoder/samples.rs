@@ -14,6 +14,7 @@ pub fn write_samples<W, S>(
     writer: &mut W,
     header: &vcf::Header,
     string_maps: &StringMaps,
+    sample_indices: Option<&[usize]>,
     samples: S,
 ) -> io::Result<()>
 where
@@ -25,9 +26,29 @@ where
     for (i, result) in samples.column_names(header).enumerate() {
         let key = result?;
 
+        let format = header.formats().get(key).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("format key missing from header: {key:?}"),
+            )
+        })?;
+
         write_key(writer, string_maps.strings(), key)?;
 
         let rows: Vec<_> = samples.iter().collect();
+
+        let rows: Vec<_> = match sample_indices {
+            Some(indices) => indices
+                .iter()
+                .map(|&j| {
+                    rows.get(j).ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "missing sample")
+                    })
+                })
+                .collect::<io::Result<_>>()?,
+            None => rows.iter().collect(),
+        };
+
         let mut values = Vec::new();
 
         for sample in rows.iter() {
@@ -35,10 +56,6 @@ where
             values.push(value);
         }
 
-        let format = header.formats().get(key).ok_or_else(|| {
-            io::Error::new(io::ErrorKind::InvalidData, "missing FORMAT header record")
-        })?;
-
         if key == key::GENOTYPE {
             write_genotype_values(writer, &values)?;
         } else {
@@ -90,7 +107,7 @@ mod tests {
         );
 
         let mut buf = Vec::new();
-        write_samples(&mut buf, &header, &string_maps, &genotypes)?;
+        write_samples(&mut buf, &header, &string_maps, None, &genotypes)?;
 
         let expected = [
             0x11, // string string map index type = Some(Type::Int(1))
@@ -109,4 +126,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_samples_with_key_missing_from_header() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{map::Info, Map},
+            variant::{
+                record::{info::field::key as info_key, samples::keys::key},
+                record_buf::samples::sample::Value,
+            },
+        };
+
+        // `DP` is in the string map as an INFO key but is not defined as a FORMAT key.
+        let header = vcf::Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let samples = vcf::variant::record_buf::Samples::new(
+            [String::from(key::READ_DEPTH)].into_iter().collect(),
+            vec![vec![Some(Value::from(5))]],
+        );
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_samples(&mut buf, &header, &string_maps, None, &samples),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     cmp,
     io::{self, Write},
 };
@@ -172,7 +173,8 @@ fn write_integer_array_values<W>(writer: &mut W, values: &[Option<Value<'_>>]) -
 where
     W: Write,
 {
-    let mut max_len = usize::MIN;
+    // A missing value is written as a single missing value.
+    let mut max_len = 1;
     let (mut min, mut max) = (i32::MAX, i32::MIN);
 
     for value in values {
@@ -395,14 +397,14 @@ fn write_float_array_values<W>(writer: &mut W, values: &[Option<Value<'_>>]) ->
 where
     W: Write,
 {
+    // A missing value is written as a single missing value.
     let max_len = values
         .iter()
-        .flat_map(|value| match value {
-            Some(Value::Array(Array::Float(vs))) => Some(vs.len()),
-            _ => None,
+        .map(|value| match value {
+            Some(Value::Array(Array::Float(vs))) => vs.len(),
+            _ => 1,
         })
-        .max()
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing float array values"))?;
+        .fold(1, cmp::max);
 
     write_type(writer, Some(Type::Float(max_len)))?;
 
@@ -463,7 +465,7 @@ where
 
     let string_values: Vec<_> = raw_strings
         .iter()
-        .map(|s| s.as_ref().map(|t| Value::String(t.as_ref())))
+        .map(|s| s.as_deref().map(|t| Value::String(Cow::from(t))))
         .collect();
 
     write_string_values(writer, &string_values)
@@ -505,7 +507,7 @@ where
 
     let string_values: Vec<_> = raw_strings
         .iter()
-        .map(|s| s.as_ref().map(|t| Value::String(t.as_ref())))
+        .map(|s| s.as_deref().map(|t| Value::String(Cow::from(t))))
         .collect();
 
     write_string_values(writer, &string_values)
@@ -574,7 +576,7 @@ where
                     }
 
                     match result? {
-                        Some(t) => s.push_str(t),
+                        Some(t) => s.push_str(&t),
                         None => s.push(MISSING),
                     }
                 }
@@ -1184,6 +1186,30 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_values_with_missing_array_values() -> io::Result<()> {
+        let values = [None, None];
+
+        let format = Map::<Format>::new(Number::Count(2), format::Type::Integer, String::new());
+        let mut buf = Vec::new();
+        write_values(&mut buf, &format, &values)?;
+        assert_eq!(buf, [0x11, 0x80, 0x80]); // Some(Type::Int8(1)), [None, None]
+
+        let format = Map::<Format>::new(Number::Count(2), format::Type::Float, String::new());
+        buf.clear();
+        write_values(&mut buf, &format, &values)?;
+
+        let expected = [
+            0x15, // Some(Type::Float(1))
+            0x01, 0x00, 0x80, 0x7f, // None
+            0x01, 0x00, 0x80, 0x7f, // None
+        ];
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_values_with_character_values() -> Result<(), Box<dyn std::error::Error>> {
         let format = Map::<Format>::new(Number::Count(1), format::Type::Character, String::new());
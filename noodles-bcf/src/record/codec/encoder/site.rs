@@ -17,10 +17,10 @@ use noodles_vcf::{
 };
 
 use self::{
-    bases::write_bases, filters::write_filters, ids::write_ids, info::write_info,
-    position::write_position, quality_score::write_quality_score,
-    reference_sequence_id::write_reference_sequence_id,
+    bases::write_bases, ids::write_ids, info::write_info, position::write_position,
+    quality_score::write_quality_score, reference_sequence_id::write_reference_sequence_id,
 };
+pub(crate) use self::{filters::write_filters, info::write_field as write_info_field};
 
 const MAX_SAMPLE_NAME_COUNT: u32 = (1 << 24) - 1;
 
@@ -28,6 +28,7 @@ pub fn write_site<W, R>(
     writer: &mut W,
     header: &vcf::Header,
     string_maps: &StringMaps,
+    sample_indices: Option<&[usize]>,
     record: &R,
 ) -> io::Result<()>
 where
@@ -54,12 +55,16 @@ where
 
     write_n_allele(writer, record.alternate_bases().len())?;
 
-    let samples = record.samples()?;
-    write_n_fmt_sample(
-        writer,
-        header.sample_names().len(),
-        samples.column_names(header).count(),
-    )?;
+    let sample_count = sample_indices
+        .map(|indices| indices.len())
+        .unwrap_or(header.sample_names().len());
+
+    let format_count = match sample_indices {
+        Some([]) => 0,
+        _ => record.samples()?.column_names(header).count(),
+    };
+
+    write_n_fmt_sample(writer, sample_count, format_count)?;
 
     write_ids(writer, record.ids())?;
     write_bases(writer, record.reference_bases(), record.alternate_bases())?;
@@ -2,7 +2,7 @@ use std::io::{self, Write};
 
 use noodles_vcf::{self as vcf, header::StringMaps, variant::record::Filters};
 
-pub(super) fn write_filters<W, F>(
+pub(crate) fn write_filters<W, F>(
     writer: &mut W,
     header: &vcf::Header,
     string_maps: &StringMaps,
@@ -14,11 +14,20 @@ where
 {
     use crate::record::codec::encoder::string_map::write_string_map_indices;
 
+    const PASS: &str = "PASS";
+
     let indices: Vec<_> = filters
         .iter(header)
         .map(|result| {
             let id = result?;
 
+            if id != PASS && !header.filters().contains_key(id) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("filter missing from header: {id:?}"),
+                ));
+            }
+
             string_maps.strings().get_index_of(id).ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -92,4 +101,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_filters_with_filter_missing_from_header() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use vcf::{
+            header::record::value::{map::Info, Map},
+            variant::{record::info::field::key, record_buf::Filters},
+        };
+
+        let header = vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .build();
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let filters: Filters = [String::from(key::TOTAL_DEPTH)].into_iter().collect();
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_filters(&mut buf, &header, &string_maps, &filters),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -4,7 +4,7 @@ use std::io::{self, Write};
 
 use noodles_vcf::{self as vcf, header::StringMaps, variant::record::Info};
 
-use self::field::write_field;
+pub(crate) use self::field::write_field;
 
 pub fn write_info<W, I>(
     writer: &mut W,
@@ -18,8 +18,57 @@ where
 {
     for result in info.iter(header) {
         let (key, value) = result?;
+
+        if !header.infos().contains_key(key) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("info key missing from header: {key:?}"),
+            ));
+        }
+
         write_field(writer, string_maps.strings(), key, value)?;
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::header::StringMaps;
+
+    use super::*;
+
+    #[test]
+    fn test_write_info_with_key_missing_from_header() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::{map::Format, Map},
+            variant::{
+                record::{info::field::key, samples::keys::key as format_key},
+                record_buf::{info::field::Value, Info as InfoBuf},
+            },
+        };
+
+        // `DP` is in the string map as a FORMAT key but is not defined as an INFO key.
+        let header = vcf::Header::builder()
+            .add_format(
+                format_key::READ_DEPTH,
+                Map::<Format>::from(format_key::READ_DEPTH),
+            )
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+
+        let info: InfoBuf = [(String::from(key::TOTAL_DEPTH), Some(Value::from(13)))]
+            .into_iter()
+            .collect();
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_info(&mut buf, &header, &string_maps, &info),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
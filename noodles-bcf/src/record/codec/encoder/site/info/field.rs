@@ -7,7 +7,7 @@ use noodles_vcf::{header::string_maps::StringStringMap, variant::record::info::f
 
 use self::{key::write_key, value::write_value};
 
-pub(super) fn write_field<W>(
+pub(crate) fn write_field<W>(
     writer: &mut W,
     string_string_map: &StringStringMap,
     key: &str,
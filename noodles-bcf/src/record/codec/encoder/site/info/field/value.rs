@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     cmp,
     io::{self, Write},
 };
@@ -23,7 +24,7 @@ where
         Some(field::Value::Float(n)) => write_float_value(writer, n),
         Some(field::Value::Flag) => write_flag_value(writer),
         Some(field::Value::Character(c)) => write_character_value(writer, c),
-        Some(field::Value::String(s)) => write_string_value(writer, s),
+        Some(field::Value::String(s)) => write_string_value(writer, &s),
         Some(field::Value::Array(field::value::Array::Integer(values))) => {
             write_integer_array_value(writer, values)
         }
@@ -273,7 +274,7 @@ where
 
 fn write_string_array_value<W>(
     writer: &mut W,
-    values: Box<dyn Values<'_, &str> + '_>,
+    values: Box<dyn Values<'_, Cow<'_, str>> + '_>,
 ) -> io::Result<()>
 where
     W: Write,
@@ -286,7 +287,7 @@ where
         }
 
         if let Some(t) = result? {
-            s.push_str(t);
+            s.push_str(&t);
         } else {
             s.push(MISSING_VALUE);
         }
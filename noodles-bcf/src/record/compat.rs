@@ -0,0 +1,254 @@
+//! BCF 2.1 compatibility.
+//!
+//! BCF 2.2 reserves the integer values directly above the missing and end-of-vector sentinels
+//! (e.g., `0x82..=0x87` for int8). In BCF 2.1, only the sentinels themselves are special, so
+//! these are regular values. Upgrading a 2.1 record widens each integer vector that holds such a
+//! value to the next integer type, after which it can be read as a BCF 2.2 record.
+
+use std::{io, mem};
+
+use super::value::{read_type, Type};
+use crate::Record;
+
+const SITE_FIELDS_LEN: usize = 24;
+
+const INT8_MISSING: u8 = 0x80;
+const INT8_END_OF_VECTOR: u8 = 0x81;
+const INT16_MISSING: u16 = 0x8000;
+const INT16_END_OF_VECTOR: u16 = 0x8001;
+const INT32_MISSING: u32 = 0x80000000;
+const INT32_END_OF_VECTOR: u32 = 0x80000001;
+
+const INT16_TYPE: u8 = 2;
+const INT32_TYPE: u8 = 3;
+
+/// Upgrades a BCF 2.1 record to BCF 2.2.
+pub(crate) fn upgrade_record(record: &mut Record) -> io::Result<()> {
+    let fields = record.fields_mut();
+
+    let (format_count, sample_count) = upgrade_site(fields.site_buf_mut())?;
+    fields.index()?;
+
+    upgrade_samples(fields.samples_buf_mut(), format_count, sample_count)
+}
+
+/// Upgrades the site buffer of a BCF 2.1 record.
+///
+/// This returns the number of format keys and samples of the record.
+pub(crate) fn upgrade_site(buf: &mut Vec<u8>) -> io::Result<(usize, usize)> {
+    if buf.len() < SITE_FIELDS_LEN {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let info_field_count = usize::from(u16::from_le_bytes([buf[16], buf[17]]));
+    let allele_count = usize::from(u16::from_le_bytes([buf[18], buf[19]]));
+    let sample_count = usize::try_from(u32::from_le_bytes([buf[20], buf[21], buf[22], 0x00]))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let format_count = usize::from(buf[23]);
+
+    let mut src = &buf[SITE_FIELDS_LEN..];
+
+    // ID, REF, ALT, and FILTER cannot hold values in the reserved ranges.
+    for _ in 0..(1 + allele_count + 1) {
+        let ty = read_type(&mut src)?;
+        split_to(&mut src, ty.map(|t| t.size()).unwrap_or_default())?;
+    }
+
+    let mut dst = buf[..buf.len() - src.len()].to_vec();
+
+    for _ in 0..info_field_count {
+        // key
+        copy_value(&mut src, &mut dst)?;
+        upgrade_values(&mut src, &mut dst, 1)?;
+    }
+
+    dst.extend_from_slice(src);
+    *buf = dst;
+
+    Ok((format_count, sample_count))
+}
+
+/// Upgrades the samples buffer of a BCF 2.1 record.
+pub(crate) fn upgrade_samples(
+    buf: &mut Vec<u8>,
+    format_count: usize,
+    sample_count: usize,
+) -> io::Result<()> {
+    let mut src = &buf[..];
+    let mut dst = Vec::with_capacity(buf.len());
+
+    for _ in 0..format_count {
+        // key
+        copy_value(&mut src, &mut dst)?;
+        upgrade_values(&mut src, &mut dst, sample_count)?;
+    }
+
+    dst.extend_from_slice(src);
+    *buf = dst;
+
+    Ok(())
+}
+
+fn copy_value(src: &mut &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+    let start = *src;
+    let ty = read_type(src)?;
+    split_to(src, ty.map(|t| t.size()).unwrap_or_default())?;
+    dst.extend_from_slice(&start[..start.len() - src.len()]);
+    Ok(())
+}
+
+// Upgrades a type descriptor followed by `n` values of that type.
+fn upgrade_values(src: &mut &[u8], dst: &mut Vec<u8>, n: usize) -> io::Result<()> {
+    let start = *src;
+    let ty = read_type(src)?;
+    let descriptor = &start[..start.len() - src.len()];
+
+    let len = ty
+        .map(|t| t.size())
+        .unwrap_or_default()
+        .checked_mul(n)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid value length"))?;
+
+    let data = split_to(src, len)?;
+
+    match ty {
+        Some(Type::Int8(_)) if data.iter().copied().any(is_reserved_int8) => {
+            push_descriptor(dst, descriptor, INT16_TYPE);
+
+            for &b in data {
+                let m = match b {
+                    INT8_MISSING => INT16_MISSING as i16,
+                    INT8_END_OF_VECTOR => INT16_END_OF_VECTOR as i16,
+                    _ => i16::from(b as i8),
+                };
+
+                dst.extend_from_slice(&m.to_le_bytes());
+            }
+        }
+        Some(Type::Int16(_)) if data.chunks_exact(2).any(is_reserved_int16) => {
+            push_descriptor(dst, descriptor, INT32_TYPE);
+
+            for chunk in data.chunks_exact(mem::size_of::<u16>()) {
+                let m = match u16::from_le_bytes([chunk[0], chunk[1]]) {
+                    INT16_MISSING => INT32_MISSING as i32,
+                    INT16_END_OF_VECTOR => INT32_END_OF_VECTOR as i32,
+                    n => i32::from(n as i16),
+                };
+
+                dst.extend_from_slice(&m.to_le_bytes());
+            }
+        }
+        Some(Type::Int32(_)) if data.chunks_exact(4).any(is_reserved_int32) => {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "int32 value is reserved in BCF 2.2",
+            ));
+        }
+        _ => {
+            dst.extend_from_slice(descriptor);
+            dst.extend_from_slice(data);
+        }
+    }
+
+    Ok(())
+}
+
+fn push_descriptor(dst: &mut Vec<u8>, descriptor: &[u8], raw_ty: u8) {
+    // The type length (high nibble and any overflow length value) is unchanged.
+    let (encoding, len) = descriptor.split_first().expect("missing type encoding");
+    dst.push(encoding & 0xf0 | raw_ty);
+    dst.extend_from_slice(len);
+}
+
+fn is_reserved_int8(b: u8) -> bool {
+    (0x82..=0x87).contains(&b)
+}
+
+fn is_reserved_int16(buf: &[u8]) -> bool {
+    (0x8002..=0x8007).contains(&u16::from_le_bytes([buf[0], buf[1]]))
+}
+
+fn is_reserved_int32(buf: &[u8]) -> bool {
+    (0x80000002..=0x80000007).contains(&u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]))
+}
+
+fn split_to<'a>(src: &mut &'a [u8], i: usize) -> io::Result<&'a [u8]> {
+    if src.len() < i {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    let (buf, rest) = src.split_at(i);
+    *src = rest;
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_samples() -> io::Result<()> {
+        let mut buf = vec![
+            0x11, 0x01, // key = 1
+            0x21, 0x05, 0x82, // Int8(2) [5, -126]
+            0x7f, 0x81, // [127, EOV]
+        ];
+
+        upgrade_samples(&mut buf, 1, 2)?;
+
+        let expected = [
+            0x11, 0x01, // key = 1
+            0x22, 0x05, 0x00, 0x82, 0xff, // Int16(2) [5, -126]
+            0x7f, 0x00, 0x01, 0x80, // [127, EOV]
+        ];
+
+        assert_eq!(buf, expected);
+
+        let mut buf = vec![
+            0x11, 0x01, // key = 1
+            0x11, 0x80, // Int8(1) [missing]
+        ];
+
+        upgrade_samples(&mut buf, 1, 1)?;
+        assert_eq!(buf, [0x11, 0x01, 0x11, 0x80]);
+
+        let mut buf = vec![
+            0x11, 0x01, // key = 1
+            0x13, 0x02, 0x00, 0x00, 0x80, // Int32(1) [reserved]
+        ];
+
+        assert!(matches!(
+            upgrade_samples(&mut buf, 1, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_upgrade_site() -> io::Result<()> {
+        let mut buf = vec![
+            0x00, 0x00, 0x00, 0x00, // chrom = 0
+            0x00, 0x00, 0x00, 0x00, // pos = 0 (0-based)
+            0x01, 0x00, 0x00, 0x00, // rlen = 1
+            0x01, 0x00, 0x80, 0x7f, // qual = missing
+            0x01, 0x00, // n_info = 1
+            0x01, 0x00, // n_allele = 1
+            0x00, 0x00, 0x00, // n_sample = 0
+            0x00, // n_fmt = 0
+            0x07, // ID = missing
+            0x17, b'A', // REF = A
+            0x00, // FILTER = missing
+            0x11, 0x01, // infos[0].key = 1
+            0x11, 0x86, // infos[0].value = Int8(1) [-122]
+        ];
+
+        assert_eq!(upgrade_site(&mut buf)?, (0, 0));
+        assert_eq!(
+            &buf[24..],
+            [0x07, 0x17, b'A', 0x00, 0x11, 0x01, 0x12, 0x86, 0xff]
+        );
+
+        Ok(())
+    }
+}
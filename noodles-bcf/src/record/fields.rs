@@ -2,8 +2,10 @@ mod bounds;
 
 use std::{io, mem};
 
+use noodles_vcf as vcf;
+
 use self::bounds::Bounds;
-use super::{AlternateBases, Filters, Ids, Info, ReferenceBases, Samples};
+use super::{AlternateBases, Filters, Ids, Info, Layout, ReferenceBases, Samples};
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct Fields {
@@ -118,9 +120,78 @@ impl Fields {
         })
     }
 
+    pub(super) fn layout<'h>(&self, header: &'h vcf::Header) -> io::Result<Layout<'h>> {
+        use super::layout::read_sized_field;
+
+        let mut src = &self.site_buf[self.bounds.info_range()];
+
+        let info_field_sizes = (0..self.info_field_count())
+            .map(|_| read_sized_field(&mut src, header, 1))
+            .collect::<io::Result<_>>()?;
+
+        let sample_count = self.sample_count()?;
+        let mut src = &self.samples_buf[..];
+
+        let series_sizes = (0..self.format_key_count())
+            .map(|_| read_sized_field(&mut src, header, sample_count))
+            .collect::<io::Result<_>>()?;
+
+        Ok(Layout {
+            shared_len: self.site_buf.len(),
+            indiv_len: self.samples_buf.len(),
+            allele_count: self.allele_count(),
+            format_count: self.format_key_count(),
+            sample_count,
+            info_field_sizes,
+            series_sizes,
+        })
+    }
+
     pub(crate) fn index(&mut self) -> io::Result<()> {
         index(&self.site_buf, &mut self.bounds)
     }
+
+    // `src` is an encoded filters vector, including its type descriptor.
+    pub(super) fn replace_filters(&mut self, src: &[u8]) -> io::Result<()> {
+        let range = self.bounds.filters_range();
+        self.site_buf.splice(range, src.iter().copied());
+        self.index()
+    }
+
+    // `src` is an encoded info field (key and value). A field with the string map index `j` is
+    // replaced. Otherwise, `src` is appended to the list of fields.
+    pub(super) fn insert_info_field(&mut self, j: usize, src: &[u8]) -> io::Result<()> {
+        use super::{codec::decoder::read_string_map_index, value::skip_value};
+
+        let info_start = self.bounds.info_range().start;
+        let field_count = self.info_field_count();
+
+        let mut buf = &self.site_buf[info_start..];
+
+        for _ in 0..field_count {
+            let start = self.site_buf.len() - buf.len();
+
+            let i = read_string_map_index(&mut buf)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            skip_value(&mut buf)?;
+
+            if i == j {
+                let end = self.site_buf.len() - buf.len();
+                self.site_buf.splice(start..end, src.iter().copied());
+                return Ok(());
+            }
+        }
+
+        let n = u16::try_from(field_count + 1)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+        let end = self.site_buf.len() - buf.len();
+        self.site_buf.splice(end..end, src.iter().copied());
+        self.site_buf[bounds::INFO_FIELD_COUNT_RANGE].copy_from_slice(&n.to_le_bytes());
+
+        Ok(())
+    }
 }
 
 fn index(buf: &[u8], bounds: &mut Bounds) -> io::Result<()> {
@@ -225,3 +296,126 @@ impl Default for Fields {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_fields() -> io::Result<Fields> {
+        let mut fields = Fields::default();
+
+        let site_buf = fields.site_buf_mut();
+        site_buf[bounds::INFO_FIELD_COUNT_RANGE].copy_from_slice(&[0x02, 0x00]); // n_info = 2
+        site_buf.extend([
+            0x11, 0x01, 0x11, 0x05, // infos[0] = (1, 5)
+            0x11, 0x02, 0x21, 0x08, 0x0d, // infos[1] = (2, [8, 13])
+        ]);
+
+        fields.samples_buf_mut().extend([0x11, 0x03, 0x11, 0x00]);
+        fields.index()?;
+
+        Ok(fields)
+    }
+
+    #[test]
+    fn test_replace_filters() -> io::Result<()> {
+        let mut fields = build_fields()?;
+        fields.replace_filters(&[0x21, 0x01, 0x02])?;
+
+        assert_eq!(
+            &fields.site_buf[bounds::FORMAT_KEY_COUNT_INDEX + 1..],
+            [
+                0x07, // ids = []
+                0x17, b'N', // ref = N
+                0x21, 0x01, 0x02, // filters = [1, 2]
+                0x11, 0x01, 0x11, 0x05, // infos[0] = (1, 5)
+                0x11, 0x02, 0x21, 0x08, 0x0d, // infos[1] = (2, [8, 13])
+            ]
+        );
+
+        assert_eq!(fields.bounds.filters_range(), 27..30);
+        assert_eq!(fields.samples_buf, [0x11, 0x03, 0x11, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_info_field() -> io::Result<()> {
+        let mut fields = build_fields()?;
+
+        fields.insert_info_field(1, &[0x11, 0x01, 0x21, 0x03, 0x05])?;
+        assert_eq!(fields.info_field_count(), 2);
+        assert_eq!(
+            &fields.site_buf[fields.bounds.info_range()],
+            [
+                0x11, 0x01, 0x21, 0x03, 0x05, // infos[0] = (1, [3, 5])
+                0x11, 0x02, 0x21, 0x08, 0x0d, // infos[1] = (2, [8, 13])
+            ]
+        );
+
+        fields.insert_info_field(3, &[0x11, 0x03, 0x00])?;
+        assert_eq!(fields.info_field_count(), 3);
+        assert_eq!(
+            &fields.site_buf[fields.bounds.info_range()],
+            [
+                0x11, 0x01, 0x21, 0x03, 0x05, // infos[0] = (1, [3, 5])
+                0x11, 0x02, 0x21, 0x08, 0x0d, // infos[1] = (2, [8, 13])
+                0x11, 0x03, 0x00, // infos[2] = (3, None)
+            ]
+        );
+
+        assert_eq!(fields.samples_buf, [0x11, 0x03, 0x11, 0x00]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_layout() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::{
+                record::value::{
+                    map::{Format, Info},
+                    Map,
+                },
+                StringMaps,
+            },
+            variant::record::{info::field::key as info_key, samples::keys::key as format_key},
+        };
+
+        let mut header = vcf::Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(info_key::ALLELE_FREQUENCIES),
+            )
+            .add_format(
+                format_key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(format_key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        let mut fields = build_fields()?;
+        fields.site_buf[bounds::SAMPLE_COUNT_RANGE].copy_from_slice(&[0x01, 0x00, 0x00]); // n_sample = 1
+        fields.site_buf[bounds::FORMAT_KEY_COUNT_INDEX] = 0x01; // n_fmt = 1
+        fields.index()?;
+
+        let layout = fields.layout(&header)?;
+
+        assert_eq!(layout.shared_len(), 37);
+        assert_eq!(layout.indiv_len(), 4);
+        assert_eq!(layout.allele_count(), 1);
+        assert_eq!(layout.info_field_count(), 2);
+        assert_eq!(layout.format_count(), 1);
+        assert_eq!(layout.sample_count(), 1);
+        assert_eq!(layout.info_field_sizes(), [("DP", 4), ("AF", 5)]);
+        assert_eq!(layout.series_sizes(), [("GQ", 4)]);
+
+        Ok(())
+    }
+}
@@ -2,12 +2,10 @@ mod field;
 
 use std::io;
 
-use noodles_vcf::{
-    self as vcf,
-    variant::record::{info::field::Value, Info as _},
-};
+use noodles_vcf::{self as vcf, variant::record::info::field::Value};
 
-use self::field::read_field;
+use self::field::{read_field, read_field_value};
+use super::{codec::decoder::read_string_map_index, value::skip_value};
 
 /// BCF record info.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -22,19 +20,29 @@ impl<'r> Info<'r> {
     }
 
     /// Returns the value with the given key.
+    ///
+    /// Fields are matched by their string map index. The values of fields before the matching
+    /// field are skipped using their type lengths and are not decoded.
     pub fn get<'h: 'r>(
         &'r self,
         header: &'h vcf::Header,
         key: &str,
     ) -> Option<io::Result<Option<Value<'r>>>> {
-        for result in self.iter(header) {
-            match result {
-                Ok((k, v)) => {
-                    if k == key {
-                        return Some(Ok(v));
-                    }
-                }
-                Err(e) => return Some(Err(e)),
+        let j = header.string_maps().strings().get_index_of(key)?;
+        let mut src = self.src;
+
+        for _ in 0..self.field_count {
+            let i = match read_string_map_index(&mut src) {
+                Ok(i) => i,
+                Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
+            };
+
+            if i == j {
+                return Some(read_field_value(&mut src, header, key));
+            }
+
+            if let Err(e) = skip_value(&mut src) {
+                return Some(Err(e));
             }
         }
 
@@ -76,3 +84,52 @@ impl<'r> vcf::variant::record::Info for Info<'r> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::{
+        header::{
+            record::value::{map::Info as InfoMap, Map},
+            StringMaps,
+        },
+        variant::record::info::field::key,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_get() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = vcf::Header::builder()
+            .add_info(
+                key::ANCESTRAL_ALLELE,
+                Map::<InfoMap>::from(key::ANCESTRAL_ALLELE),
+            )
+            .add_info(key::TOTAL_DEPTH, Map::<InfoMap>::from(key::TOTAL_DEPTH))
+            .build();
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        let src = &[
+            0x11, 0x01, // AA
+            0x27, 0xff, 0xfe, // (invalid UTF-8 string)
+            0x11, 0x02, // DP
+            0x11, 0x0d, // 13
+        ];
+
+        let info = Info::new(src, 2);
+
+        assert!(matches!(
+            info.get(&header, key::TOTAL_DEPTH),
+            Some(Ok(Some(Value::Integer(13))))
+        ));
+
+        assert!(matches!(
+            info.get(&header, key::ANCESTRAL_ALLELE),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(info.get(&header, key::ALLELE_COUNT).is_none());
+
+        Ok(())
+    }
+}
@@ -14,13 +14,21 @@ pub(super) fn read_field<'a, 'h: 'a>(
     let key = read_string_map_entry(src, header.string_maps().strings())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
+    let value = read_field_value(src, header, key)?;
+
+    Ok((key, value))
+}
+
+pub(super) fn read_field_value<'a>(
+    src: &mut &'a [u8],
+    header: &vcf::Header,
+    key: &str,
+) -> io::Result<Option<Value<'a>>> {
     let (number, ty) = header
         .infos()
         .get(key)
         .map(|info| (info.number(), info.ty()))
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing info map entry"))?;
 
-    let value = read_value(src, number, ty)?;
-
-    Ok((key, value))
+    read_value(src, number, ty)
 }
@@ -1,4 +1,4 @@
-use std::{io, iter};
+use std::{borrow::Cow, io, iter};
 
 use noodles_vcf::{
     self as vcf,
@@ -154,7 +154,7 @@ fn read_character_array_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value
 fn read_string_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     match read_typed_value(src)? {
         None | Some(TypedValue::String(None)) => Ok(None),
-        Some(TypedValue::String(Some(s))) => Ok(Some(Value::String(s))),
+        Some(TypedValue::String(Some(s))) => Ok(Some(Value::String(Cow::from(s)))),
         v => Err(type_mismatch_error(v, Type::String)),
     }
 }
@@ -394,7 +394,7 @@ mod tests {
                 Ok(Some(Value::Array(Array::String(values)))) => {
                     assert!(matches!(
                         values.iter().collect::<io::Result<Vec<_>>>(),
-                        Ok(vs) if vs == expected
+                        Ok(vs) if vs.iter().map(|v| v.as_deref()).eq(expected.iter().copied())
                     ));
                 }
                 _ => panic!(),
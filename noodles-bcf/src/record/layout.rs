@@ -0,0 +1,103 @@
+use std::io;
+
+use noodles_vcf as vcf;
+
+/// The encoded layout of a BCF record.
+///
+/// This describes the sizes of the blocks and fields of a record as they are encoded, which is
+/// useful for profiling the size of a BCF file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Layout<'h> {
+    pub(super) shared_len: usize,
+    pub(super) indiv_len: usize,
+    pub(super) allele_count: usize,
+    pub(super) format_count: usize,
+    pub(super) sample_count: usize,
+    pub(super) info_field_sizes: Vec<(&'h str, usize)>,
+    pub(super) series_sizes: Vec<(&'h str, usize)>,
+}
+
+impl<'h> Layout<'h> {
+    /// Returns the size of the shared (site) block in bytes.
+    ///
+    /// This is `l_shared`, the size of the site fields: CHROM, POS, rlen, QUAL, the counts, ID,
+    /// REF, ALT, FILTER, and INFO.
+    pub fn shared_len(&self) -> usize {
+        self.shared_len
+    }
+
+    /// Returns the size of the individual (samples) block in bytes.
+    ///
+    /// This is `l_indiv`, the size of the genotype fields.
+    pub fn indiv_len(&self) -> usize {
+        self.indiv_len
+    }
+
+    /// Returns the number of alleles, including the reference allele (`n_allele`).
+    pub fn allele_count(&self) -> usize {
+        self.allele_count
+    }
+
+    /// Returns the number of info fields (`n_info`).
+    pub fn info_field_count(&self) -> usize {
+        self.info_field_sizes.len()
+    }
+
+    /// Returns the number of format keys (`n_fmt`).
+    pub fn format_count(&self) -> usize {
+        self.format_count
+    }
+
+    /// Returns the number of samples (`n_sample`).
+    pub fn sample_count(&self) -> usize {
+        self.sample_count
+    }
+
+    /// Returns the encoded size of each info field in bytes, in record order.
+    ///
+    /// Each size includes the encoded key, the value type, and the value data.
+    pub fn info_field_sizes(&self) -> &[(&'h str, usize)] {
+        &self.info_field_sizes
+    }
+
+    /// Returns the encoded size of each samples series in bytes, in record order.
+    ///
+    /// Each size includes the encoded key, the value type, and the value data of all samples.
+    pub fn series_sizes(&self) -> &[(&'h str, usize)] {
+        &self.series_sizes
+    }
+}
+
+// Reads the string map index at the start of `src` and the value that follows it, returning the
+// key and the number of bytes read. The value data is `data_count` times the size of its type.
+pub(super) fn read_sized_field<'h>(
+    src: &mut &[u8],
+    header: &'h vcf::Header,
+    data_count: usize,
+) -> io::Result<(&'h str, usize)> {
+    use super::{codec::decoder::read_string_map_index, value::read_type};
+
+    let prev_len = src.len();
+
+    let i =
+        read_string_map_index(src).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    let key =
+        header.string_maps().strings().get_index(i).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid string map index")
+        })?;
+
+    let len = read_type(src)?
+        .map(|ty| ty.size())
+        .unwrap_or_default()
+        .checked_mul(data_count)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid value length"))?;
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
+
+    *src = &src[len..];
+
+    Ok((key, prev_len - src.len()))
+}
@@ -58,19 +58,19 @@ impl<'r> Samples<'r> {
     }
 
     /// Returns the series with the given column name.
+    ///
+    /// Series are matched by their string map index. Series before the matching series are
+    /// skipped using their type lengths, and none of their values are decoded.
     pub fn select<'h: 'r>(
         &'r self,
         header: &'h vcf::Header,
         column_name: &str,
     ) -> Option<io::Result<Series<'r>>> {
-        for result in self.series() {
-            let series = match result {
-                Ok(s) => s,
-                Err(e) => return Some(Err(e)),
-            };
+        let id = header.string_maps().strings().get_index_of(column_name)?;
 
-            match series.name(header) {
-                Ok(name) if name == column_name => return Some(Ok(series)),
+        for result in self.series() {
+            match result {
+                Ok(series) if series.id() == id => return Some(Ok(series)),
                 Ok(_) => {}
                 Err(e) => return Some(Err(e)),
             }
@@ -152,3 +152,60 @@ impl<'r> vcf::variant::record::Samples for Samples<'r> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::{
+        header::{
+            record::value::{map::Format, Map},
+            StringMaps,
+        },
+        variant::record::samples::{keys::key, series::Value, Sample as _},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_select() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = vcf::Header::builder()
+            .add_format(key::READ_DEPTH, Map::<Format>::from(key::READ_DEPTH))
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        let src = &[
+            0x11, 0x01, // DP
+            0x11, 0x05, 0x08, // [5, 8]
+            0x11, 0x02, // GQ
+            0x11, 0x0d, 0x15, // [13, 21]
+        ];
+
+        let samples = Samples::new(src, 2, 2);
+
+        let series = samples
+            .select(&header, key::CONDITIONAL_GENOTYPE_QUALITY)
+            .transpose()?
+            .expect("missing series");
+        assert_eq!(series.name(&header)?, key::CONDITIONAL_GENOTYPE_QUALITY);
+
+        assert!(samples.select(&header, key::GENOTYPE).is_none());
+
+        let sample = samples.get_index(1).expect("missing sample");
+        assert!(matches!(
+            sample.get(&header, key::CONDITIONAL_GENOTYPE_QUALITY),
+            Some(Ok(Some(Value::Integer(21))))
+        ));
+        assert!(matches!(
+            sample.get_index(&header, 0),
+            Some(Ok(Some(Value::Integer(8))))
+        ));
+
+        Ok(())
+    }
+}
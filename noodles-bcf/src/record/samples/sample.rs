@@ -2,7 +2,7 @@ use std::io;
 
 use noodles_vcf::{self as vcf, variant::record::samples::series::Value};
 
-use super::Samples;
+use super::{Samples, Series};
 
 /// A BCF record sample.
 pub struct Sample<'r> {
@@ -22,18 +22,12 @@ impl<'r> vcf::variant::record::samples::Sample for Sample<'r> {
         header: &'h vcf::Header,
         key: &str,
     ) -> Option<io::Result<Option<Value<'a>>>> {
-        for result in self.iter(header) {
-            match result {
-                Ok((k, v)) => {
-                    if k == key {
-                        return Some(Ok(v));
-                    }
-                }
-                Err(e) => return Some(Err(e)),
-            }
-        }
+        let series = match self.samples.select(header, key)? {
+            Ok(series) => series,
+            Err(e) => return Some(Err(e)),
+        };
 
-        None
+        Some(get_value(&series, header, self.i))
     }
 
     fn get_index<'a, 'h: 'a>(
@@ -41,9 +35,12 @@ impl<'r> vcf::variant::record::samples::Sample for Sample<'r> {
         header: &'h vcf::Header,
         i: usize,
     ) -> Option<io::Result<Option<Value<'a>>>> {
-        self.iter(header)
-            .nth(i)
-            .map(|result| result.map(|(_, value)| value))
+        let series = match self.samples.series().nth(i)? {
+            Ok(series) => series,
+            Err(e) => return Some(Err(e)),
+        };
+
+        Some(get_value(&series, header, self.i))
     }
 
     fn iter<'a, 'h: 'a>(
@@ -55,14 +52,20 @@ impl<'r> vcf::variant::record::samples::Sample for Sample<'r> {
         Box::new(series.map(|result| {
             result.and_then(|series| {
                 let name = series.name(header)?;
-
-                let value = series
-                    .get(header, self.i)
-                    .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing value"))?
-                    .transpose()?;
-
+                let value = get_value(&series, header, self.i)?;
                 Ok((name, value))
             })
         }))
     }
 }
+
+fn get_value<'r>(
+    series: &Series<'r>,
+    header: &vcf::Header,
+    i: usize,
+) -> io::Result<Option<Value<'r>>> {
+    series
+        .get(header, i)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing value"))?
+        .transpose()
+}
@@ -2,7 +2,7 @@
 
 pub mod value;
 
-use std::{io, mem, ops::Range, str};
+use std::{borrow::Cow, io, mem, ops::Range, str};
 
 use noodles_vcf::{
     self as vcf,
@@ -23,6 +23,10 @@ pub struct Series<'r> {
 }
 
 impl<'r> Series<'r> {
+    pub(super) fn id(&self) -> usize {
+        self.id
+    }
+
     /// Returns the name.
     pub fn name<'h>(&self, header: &'h vcf::Header) -> io::Result<&'h str> {
         header
@@ -105,11 +109,7 @@ impl<'r> Series<'r> {
             _ => todo!("unhandled type"),
         };
 
-        match value {
-            Some(Some(value)) => Some(Some(Ok(value))),
-            Some(None) => Some(None),
-            None => None,
-        }
+        value.map(|result| result.transpose())
     }
 }
 
@@ -143,20 +143,16 @@ impl<'r> vcf::variant::record::samples::Series for Series<'r> {
 }
 
 pub(super) fn read_series<'a>(src: &mut &'a [u8], sample_count: usize) -> io::Result<Series<'a>> {
-    fn size_of(ty: Type) -> usize {
-        match ty {
-            Type::Int8(n) => mem::size_of::<i8>() * n,
-            Type::Int16(n) => mem::size_of::<i16>() * n,
-            Type::Int32(n) => mem::size_of::<i32>() * n,
-            Type::Float(n) => mem::size_of::<f32>() * n,
-            Type::String(n) => mem::size_of::<u8>() * n,
-        }
-    }
-
     let id = read_string_map_index(src)?;
-    let ty = read_type(src)?.expect("invalid type");
+    let ty = read_type(src)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid type"))?;
+
+    let len = ty.size() * sample_count;
+
+    if src.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+    }
 
-    let len = size_of(ty) * sample_count;
     let (buf, rest) = src.split_at(len);
 
     *src = rest;
@@ -181,87 +177,157 @@ fn range<N>(i: usize, len: usize) -> Range<usize> {
     start..end
 }
 
-fn get_i8_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i8_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int8;
 
     let src = src.get(range::<i8>(i, len))?;
 
     let value = match Int8::from(src[0] as i8) {
-        Int8::Value(n) => Some(Value::Integer(i32::from(n))),
-        Int8::Missing => None,
-        Int8::EndOfVector | Int8::Reserved(_) => todo!(),
+        Int8::Value(n) => Ok(Some(Value::Integer(i32::from(n)))),
+        Int8::Missing | Int8::EndOfVector => Ok(None),
+        Int8::Reserved(_) => Err(reserved_value_error()),
     };
 
     Some(value)
 }
 
-fn get_i8_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i8_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
+    use crate::record::codec::value::Int8;
+
     let src = src.get(range::<i8>(i, len))?;
-    let values = Values::<'_, i8>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    let n = array_len(Values::<'_, i8>::new(src).iter().map(|value| match value {
+        Int8::EndOfVector => None,
+        value => Some(value == Int8::Missing),
+    }));
+
+    Some(Ok(n.map(|n| {
+        let values = Values::<'_, i8>::new(&src[..mem::size_of::<i8>() * n]);
+        Value::Array(Array::Integer(Box::new(values)))
+    })))
 }
 
-fn get_i16_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i16_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int16;
 
     let src = src.get(range::<i16>(i, len))?;
 
     // SAFETY: `src` is 2 bytes.
     let value = match Int16::from(i16::from_le_bytes(src.try_into().unwrap())) {
-        Int16::Value(n) => Some(Value::Integer(i32::from(n))),
-        Int16::Missing => None,
-        Int16::EndOfVector | Int16::Reserved(_) => todo!(),
+        Int16::Value(n) => Ok(Some(Value::Integer(i32::from(n)))),
+        Int16::Missing | Int16::EndOfVector => Ok(None),
+        Int16::Reserved(_) => Err(reserved_value_error()),
     };
 
     Some(value)
 }
 
-fn get_i16_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i16_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
+    use crate::record::codec::value::Int16;
+
     let src = src.get(range::<i16>(i, len))?;
-    let values = Values::<'_, i16>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    let n = array_len(Values::<'_, i16>::new(src).iter().map(|value| match value {
+        Int16::EndOfVector => None,
+        value => Some(value == Int16::Missing),
+    }));
+
+    Some(Ok(n.map(|n| {
+        let values = Values::<'_, i16>::new(&src[..mem::size_of::<i16>() * n]);
+        Value::Array(Array::Integer(Box::new(values)))
+    })))
 }
 
-fn get_i32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i32_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Int32;
 
     let src = src.get(range::<i32>(i, len))?;
 
-    // SAFETY: `src` is 2 bytes.
+    // SAFETY: `src` is 4 bytes.
     let value = match Int32::from(i32::from_le_bytes(src.try_into().unwrap())) {
-        Int32::Value(n) => Some(Value::Integer(n)),
-        Int32::Missing => None,
-        Int32::EndOfVector | Int32::Reserved(_) => todo!(),
+        Int32::Value(n) => Ok(Some(Value::Integer(n))),
+        Int32::Missing | Int32::EndOfVector => Ok(None),
+        Int32::Reserved(_) => Err(reserved_value_error()),
     };
 
     Some(value)
 }
 
-fn get_i32_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_i32_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
+    use crate::record::codec::value::Int32;
+
     let src = src.get(range::<i32>(i, len))?;
-    let values = Values::<'_, i32>::new(src);
-    Some(Some(Value::Array(Array::Integer(Box::new(values)))))
+
+    let n = array_len(Values::<'_, i32>::new(src).iter().map(|value| match value {
+        Int32::EndOfVector => None,
+        value => Some(value == Int32::Missing),
+    }));
+
+    Some(Ok(n.map(|n| {
+        let values = Values::<'_, i32>::new(&src[..mem::size_of::<i32>() * n]);
+        Value::Array(Array::Integer(Box::new(values)))
+    })))
 }
 
-fn get_f32_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_f32_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     use crate::record::codec::value::Float;
 
     let src = src.get(range::<f32>(i, len))?;
 
-    // SAFETY: `src` is 2 bytes.
+    // SAFETY: `src` is 4 bytes.
     let value = match Float::from(f32::from_le_bytes(src.try_into().unwrap())) {
-        Float::Value(n) => Some(Value::Float(n)),
-        Float::Missing => None,
-        Float::EndOfVector | Float::Reserved(_) => todo!(),
+        Float::Value(n) => Ok(Some(Value::Float(n))),
+        Float::Missing | Float::EndOfVector => Ok(None),
+        Float::Reserved(_) => Err(reserved_value_error()),
     };
 
     Some(value)
 }
 
-fn get_f32_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_f32_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
+    use crate::record::codec::value::Float;
+
     let src = src.get(range::<f32>(i, len))?;
-    let values = Values::<'_, f32>::new(src);
-    Some(Some(Value::Array(Array::Float(Box::new(values)))))
+
+    let n = array_len(Values::<'_, f32>::new(src).iter().map(|value| match value {
+        Float::EndOfVector => None,
+        value => Some(value == Float::Missing),
+    }));
+
+    Some(Ok(n.map(|n| {
+        let values = Values::<'_, f32>::new(&src[..mem::size_of::<f32>() * n]);
+        Value::Array(Array::Float(Box::new(values)))
+    })))
+}
+
+// Returns the number of values in a sample array before the first end-of-vector value.
+//
+// Each item is `None` for an end-of-vector value and otherwise whether the value is missing. The
+// array value is missing (`None`) if it is empty or only holds a single missing value.
+fn array_len<I>(values: I) -> Option<usize>
+where
+    I: Iterator<Item = Option<bool>>,
+{
+    let mut len = 0;
+    let mut is_missing = false;
+
+    for value_is_missing in values.map_while(|value| value) {
+        if len == 0 {
+            is_missing = value_is_missing;
+        }
+
+        len += 1;
+    }
+
+    match len {
+        0 => None,
+        1 if is_missing => None,
+        n => Some(n),
+    }
+}
+
+fn reserved_value_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "reserved value")
 }
 
 fn get_string(src: &[u8], len: usize, i: usize) -> Option<&str> {
@@ -281,7 +347,7 @@ fn get_string(src: &[u8], len: usize, i: usize) -> Option<&str> {
     )
 }
 
-fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     const MISSING: char = '.';
 
     let s = get_string(src, len, i)?;
@@ -290,28 +356,32 @@ fn get_char_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>>
     let c = s.chars().next().unwrap();
 
     match c {
-        MISSING => Some(None),
-        _ => Some(Some(Value::Character(c))),
+        MISSING => Some(Ok(None)),
+        _ => Some(Ok(Some(Value::Character(c)))),
     }
 }
 
-fn get_char_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_char_array_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     let s = get_string(src, len, i)?;
-    Some(Some(Value::Array(Array::Character(Box::new(s)))))
+    Some(Ok(Some(Value::Array(Array::Character(Box::new(s))))))
 }
 
-fn get_string_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_string_value(src: &[u8], len: usize, i: usize) -> Option<io::Result<Option<Value<'_>>>> {
     const MISSING: &str = ".";
 
     match get_string(src, len, i)? {
-        MISSING => Some(None),
-        s => Some(Some(Value::String(s))),
+        MISSING => Some(Ok(None)),
+        s => Some(Ok(Some(Value::String(Cow::from(s))))),
     }
 }
 
-fn get_string_array_value(src: &[u8], len: usize, i: usize) -> Option<Option<Value<'_>>> {
+fn get_string_array_value(
+    src: &[u8],
+    len: usize,
+    i: usize,
+) -> Option<io::Result<Option<Value<'_>>>> {
     let s = get_string(src, len, i)?;
-    Some(Some(Value::Array(Array::String(Box::new(s)))))
+    Some(Ok(Some(Value::Array(Array::String(Box::new(s))))))
 }
 
 fn get_genotype_value(src: &[u8], len: usize, i: usize) -> Option<Option<io::Result<Value<'_>>>> {
@@ -419,11 +489,54 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
 
+    #[test]
+    fn test_get_with_int8_end_of_vector_and_reserved_values() {
+        let header = build_header_with_format(NAME, Number::Count(1), format::Type::Integer);
+        let id = header.string_maps().strings().get_index_of(NAME).unwrap();
+
+        let series = Series {
+            id,
+            ty: Type::Int8(1),
+            src: &[
+                0x81, // None (end of vector)
+                0x82, // (reserved)
+            ],
+        };
+
+        assert!(matches!(series.get(&header, 0), Some(None)));
+        assert!(matches!(
+            series.get(&header, 1),
+            Some(Some(Err(e))) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let header = build_header_with_format(NAME, Number::Count(3), format::Type::Integer);
+
+        let series = Series {
+            id,
+            ty: Type::Int8(3),
+            src: &[
+                0x15, 0x80, 0x81, // Some([Some(21), None])
+                0x81, 0x81, 0x81, // None
+            ],
+        };
+
+        match series.get(&header, 0) {
+            Some(Some(Ok(Value::Array(Array::Integer(values))))) => {
+                assert_eq!(values.len(), 2);
+                let actual: Vec<_> = values.iter().collect::<io::Result<_>>().unwrap();
+                assert_eq!(actual, [Some(21), None]);
+            }
+            _ => panic!(),
+        }
+
+        assert!(matches!(series.get(&header, 1), Some(None)));
+    }
+
     #[test]
     fn test_get_with_int16_values() {
         fn t(series: &Series<'_>, header: &vcf::Header, i: usize, expected: Option<i32>) {
@@ -494,7 +607,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -569,7 +682,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(5), Some(8)]));
         t(&series, &header, 1, Some(&[Some(13), None]));
         t(&series, &header, 2, Some(&[Some(21)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -644,7 +757,7 @@ mod tests {
         t(&series, &header, 0, Some(&[Some(0.0), Some(1.0)]));
         t(&series, &header, 1, Some(&[Some(0.0), None]));
         t(&series, &header, 2, Some(&[Some(0.0)]));
-        t(&series, &header, 3, Some(&[None])); // FIXME
+        t(&series, &header, 3, None);
 
         assert!(series.get(&header, 4).is_none());
     }
@@ -726,7 +839,7 @@ mod tests {
                 None => None,
             };
 
-            assert_eq!(actual, expected);
+            assert_eq!(actual.as_deref(), expected);
         }
 
         let header = build_header_with_format(NAME, Number::Count(1), format::Type::String);
@@ -755,10 +868,9 @@ mod tests {
         fn t(series: &Series<'_>, header: &vcf::Header, i: usize, expected: &[Option<&str>]) {
             match series.get(header, i).unwrap().unwrap().unwrap() {
                 Value::Array(Array::String(values)) => {
-                    assert_eq!(
-                        values.iter().collect::<Result<Vec<_>, _>>().unwrap(),
-                        expected,
-                    );
+                    let actual: Vec<_> = values.iter().collect::<Result<_, _>>().unwrap();
+                    let actual: Vec<_> = actual.iter().map(|v| v.as_deref()).collect();
+                    assert_eq!(actual, expected);
                 }
                 _ => panic!(),
             }
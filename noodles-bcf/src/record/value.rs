@@ -47,6 +47,12 @@ pub(crate) fn read_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>
     }
 }
 
+// The value is skipped using its type length without validating or decoding its data.
+pub(crate) fn skip_value(src: &mut &[u8]) -> io::Result<()> {
+    let len = read_type(src)?.map(|ty| ty.size()).unwrap_or_default();
+    split_to(src, len).map(|_| ())
+}
+
 fn read_int8_value<'a>(src: &mut &'a [u8]) -> io::Result<Option<Value<'a>>> {
     read_i8(src).map(|n| Some(Value::Int8(Some(Int8::from(n)))))
 }
@@ -236,4 +242,27 @@ mod tests {
             Ok(Some(Value::String(Some("ndls"))))
         ));
     }
+
+    #[test]
+    fn test_skip_value() -> io::Result<()> {
+        let mut src = &[
+            0x00, // None
+            0x21, 0x08, 0x0d, // [8, 13]
+            0x27, 0xff, 0xfe, // invalid UTF-8 string
+            0x11, 0x05, // 5
+        ][..];
+
+        skip_value(&mut src)?;
+        skip_value(&mut src)?;
+        skip_value(&mut src)?;
+        assert_eq!(src, [0x11, 0x05]);
+
+        let mut src = &[0x31, 0x08][..];
+        assert!(matches!(
+            skip_value(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
 }
@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, mem};
 
 use super::read_value;
 
@@ -11,6 +11,19 @@ pub(crate) enum Type {
     String(usize),
 }
 
+impl Type {
+    /// Returns the number of bytes of the data of a value of this type.
+    pub(crate) fn size(&self) -> usize {
+        match *self {
+            Self::Int8(n) => mem::size_of::<i8>() * n,
+            Self::Int16(n) => mem::size_of::<i16>() * n,
+            Self::Int32(n) => mem::size_of::<i32>() * n,
+            Self::Float(n) => mem::size_of::<f32>() * n,
+            Self::String(n) => mem::size_of::<u8>() * n,
+        }
+    }
+}
+
 const MAX_TYPE_LEN: usize = 0x0f;
 
 pub(crate) fn read_type(src: &mut &[u8]) -> io::Result<Option<Type>> {
@@ -89,4 +102,13 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(Type::Int8(3).size(), 3);
+        assert_eq!(Type::Int16(3).size(), 6);
+        assert_eq!(Type::Int32(3).size(), 12);
+        assert_eq!(Type::Float(3).size(), 12);
+        assert_eq!(Type::String(3).size(), 3);
+    }
 }
@@ -7,7 +7,7 @@ pub(super) fn write_feature_start<W>(writer: &mut W, position: Position) -> io::
 where
     W: Write,
 {
-    let n = usize::from(position) - 1;
+    let n = position.to_zero_based();
     let mut dst = [0; usize::FORMATTED_SIZE_DECIMAL];
     let buf = lexical_core::write(n, &mut dst);
     writer.write_all(buf)
@@ -9,7 +9,7 @@ where
     let c = match strand {
         Some(Strand::Forward) => b"+",
         Some(Strand::Reverse) => b"-",
-        None => b".",
+        Some(Strand::Unknown | Strand::Both) | None => b".",
     };
 
     writer.write_all(c)
@@ -35,6 +35,10 @@ mod tests {
         write_strand(&mut buf, Some(Strand::Reverse))?;
         assert_eq!(buf, b"-");
 
+        buf.clear();
+        write_strand(&mut buf, Some(Strand::Both))?;
+        assert_eq!(buf, b".");
+
         Ok(())
     }
 }
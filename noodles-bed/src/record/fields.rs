@@ -165,13 +165,9 @@ fn parse_reference_sequence_name(buf: &[u8]) -> &BStr {
 
 fn parse_feature_start(buf: &[u8]) -> io::Result<Position> {
     parse_int::<usize>(buf).and_then(|n| {
-        n.checked_add(1)
-            .ok_or_else(|| {
-                io::Error::new(io::ErrorKind::InvalidData, "attempt to add with overflow")
-            })
-            .and_then(|m| {
-                Position::try_from(m).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-            })
+        Position::from_zero_based(n).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "attempt to add with overflow")
+        })
     })
 }
 
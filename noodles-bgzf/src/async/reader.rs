@@ -21,6 +21,10 @@ use crate::{gzi, Block, VirtualPosition};
 
 pin_project! {
     /// An async BGZF reader.
+    ///
+    /// Blocks are decompressed concurrently on blocking tasks (see
+    /// [`tokio::task::spawn_blocking`]) and yielded in stream order. The number of blocks that
+    /// are decompressed concurrently can be set using [`Builder::set_worker_count`].
     pub struct Reader<R>
     where
         R: AsyncRead,
@@ -313,6 +317,34 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_read_with_worker_count() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::writer;
+
+        let data: Vec<u8> = (0..=u8::MAX).cycle().take(1 << 16).collect();
+
+        let mut writer = writer::Builder::default()
+            .set_block_size(NonZeroUsize::try_from(1 << 8).unwrap())
+            .build_from_writer(Vec::new());
+        writer.write_all(&data)?;
+        let src = writer.finish()?;
+
+        for worker_count in [1, 2, 8] {
+            let mut reader = Builder::default()
+                .set_worker_count(NonZeroUsize::try_from(worker_count).unwrap())
+                .build_from_reader(&src[..]);
+
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf).await?;
+
+            assert_eq!(buf, data);
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -15,6 +15,9 @@ pub struct Builder {
 impl Builder {
     /// Sets a worker count.
     ///
+    /// This is the maximum number of blocks that are decompressed concurrently. Decompressed
+    /// blocks are always yielded in stream order.
+    ///
     /// By default, the worker count is set to the number of available logical CPUs.
     ///
     /// # Examples
@@ -35,9 +35,9 @@ impl Future for Inflate {
 }
 
 fn inflate(src: Bytes) -> io::Result<Block> {
-    use crate::reader::frame::parse_block;
+    use crate::{deflate::Backend, reader::frame::parse_block};
 
     let mut block = Block::default();
-    parse_block(&src, &mut block)?;
+    parse_block(Backend::default(), &src, &mut block)?;
     Ok(block)
 }
@@ -16,12 +16,10 @@ use tokio::io::{self, AsyncWrite};
 
 pub use self::builder::Builder;
 use self::{deflate::Deflate, deflater::Deflater};
-use crate::writer::MAX_BUF_SIZE;
-
-#[cfg(feature = "libdeflate")]
-type CompressionLevel = libdeflater::CompressionLvl;
-#[cfg(not(feature = "libdeflate"))]
-type CompressionLevel = flate2::Compression;
+use crate::{
+    deflate::Backend,
+    writer::{CompressionLevel, MAX_BUF_SIZE},
+};
 
 pin_project! {
     /// An async BGZF writer.
@@ -32,6 +30,7 @@ pin_project! {
         #[pin]
         eof_buf: Bytes,
         compression_level: CompressionLevel,
+        backend: Backend,
     }
 }
 
@@ -51,6 +50,32 @@ where
         Builder::default().build_from_writer(inner)
     }
 
+    /// Returns a reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let writer = bgzf::AsyncWriter::new(Vec::new());
+    /// assert!(writer.get_ref().is_empty());
+    /// ```
+    pub fn get_ref(&self) -> &W {
+        self.sink.get_ref().get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bgzf::AsyncWriter::new(Vec::new());
+    /// assert!(writer.get_mut().is_empty());
+    /// ```
+    pub fn get_mut(&mut self) -> &mut W {
+        self.sink.get_mut().get_mut()
+    }
+
     /// Returns the underlying writer.
     ///
     /// # Examples
@@ -71,6 +96,24 @@ where
     fn has_remaining(&self) -> bool {
         self.buf.len() < MAX_BUF_SIZE
     }
+
+    // Sends the staged data to be compressed without waiting for it to be written.
+    fn poll_send_buf(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let mut this = self.project();
+
+        if this.buf.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+
+        ready!(this.sink.as_mut().poll_ready(cx))?;
+
+        let buf = this.buf.split();
+        this.sink
+            .as_mut()
+            .start_send(Deflate::new(*this.backend, buf, *this.compression_level))?;
+
+        Poll::Ready(Ok(()))
+    }
 }
 
 impl<W> AsyncWrite for Writer<W>
@@ -83,7 +126,7 @@ where
         buf: &[u8],
     ) -> Poll<io::Result<usize>> {
         if !self.has_remaining() {
-            if let Err(e) = ready!(self.as_mut().poll_flush(cx)) {
+            if let Err(e) = ready!(self.as_mut().poll_send_buf(cx)) {
                 return Poll::Ready(Err(e));
             }
         }
@@ -94,43 +137,62 @@ where
         Poll::Ready(Ok(amt))
     }
 
-    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
-        let mut this = self.project();
-
-        if this.buf.is_empty() {
-            return Poll::Ready(Ok(()));
-        }
-
-        ready!(this.sink.as_mut().poll_ready(cx))?;
-
-        let buf = this.buf.split();
-        this.sink
-            .as_mut()
-            .start_send(Deflate::new(buf, *this.compression_level))?;
-
-        Poll::Ready(Ok(()))
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_send_buf(cx))?;
+        self.project().sink.poll_flush(cx)
     }
 
     fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
         ready!(self.as_mut().poll_flush(cx))?;
 
-        let mut this = self.project();
-        let mut sink = this.sink.as_mut();
+        let this = self.project();
+        let mut inner = this.sink.get_mut().get_mut().get_mut();
+        let mut eof_buf = this.eof_buf;
 
-        ready!(sink.as_mut().poll_close(cx))?;
+        while eof_buf.has_remaining() {
+            let bytes_written = ready!(Pin::new(&mut inner).poll_write(cx, eof_buf.chunk()))?;
 
-        let mut inner = sink.get_mut().get_mut().get_mut();
-
-        while this.eof_buf.has_remaining() {
-            let bytes_written = ready!(Pin::new(&mut inner).poll_write(cx, this.eof_buf.chunk()))?;
-
-            this.eof_buf.advance(bytes_written);
+            eof_buf.advance(bytes_written);
 
             if bytes_written == 0 {
                 return Poll::Ready(Err(io::Error::from(io::ErrorKind::WriteZero)));
             }
         }
 
-        Poll::Ready(Ok(()))
+        Pin::new(&mut inner).poll_shutdown(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+    use crate::writer::BGZF_EOF;
+
+    #[tokio::test]
+    async fn test_flush() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+
+        writer.write_all(b"noodles").await?;
+        assert!(writer.get_ref().is_empty());
+
+        writer.flush().await?;
+        assert!(!writer.get_ref().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles").await?;
+        writer.shutdown().await?;
+
+        let data = writer.into_inner();
+        let eof_start = data.len() - BGZF_EOF.len();
+        assert_eq!(&data[eof_start..], BGZF_EOF);
+
+        Ok(())
     }
 }
@@ -7,6 +7,7 @@ use tokio_util::codec::FramedWrite;
 
 use super::{Deflater, Writer};
 use crate::{
+    deflate::Backend,
     r#async::BlockCodec,
     writer::{CompressionLevel, BGZF_EOF, MAX_BUF_SIZE},
 };
@@ -15,6 +16,7 @@ use crate::{
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_level: Option<CompressionLevel>,
+    backend: Backend,
     worker_count: Option<NonZeroUsize>,
 }
 
@@ -36,6 +38,22 @@ impl Builder {
         self
     }
 
+    /// Sets the DEFLATE backend.
+    ///
+    /// By default, the backend is libdeflate when the `libdeflate` feature is enabled; otherwise,
+    /// flate2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, deflate::Backend};
+    /// let builder = bgzf::r#async::writer::Builder::default().set_backend(Backend::Flate2);
+    /// ```
+    pub fn set_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Sets a worker count.
     ///
     /// By default, the worker count is set to the number of available logical CPUs.
@@ -77,7 +95,8 @@ impl Builder {
             sink: Deflater::new(FramedWrite::new(writer, BlockCodec)).buffer(worker_count.get()),
             buf: BytesMut::with_capacity(MAX_BUF_SIZE),
             eof_buf: Bytes::from_static(BGZF_EOF),
-            compression_level: compression_level.into(),
+            compression_level,
+            backend: self.backend,
         }
     }
 
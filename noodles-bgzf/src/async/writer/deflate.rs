@@ -9,8 +9,10 @@ use bytes::BytesMut;
 use pin_project_lite::pin_project;
 use tokio::task::JoinHandle;
 
-use super::CompressionLevel;
-use crate::deflate;
+use crate::{
+    deflate::{self, Backend},
+    writer::CompressionLevel,
+};
 
 // (CDATA, CRC32, ISIZE)
 pub type GzData = (Vec<u8>, u32, usize);
@@ -23,12 +25,12 @@ pin_project! {
 }
 
 impl Deflate {
-    pub fn new(data: BytesMut, compression_level: CompressionLevel) -> Self {
+    pub fn new(backend: Backend, data: BytesMut, compression_level: CompressionLevel) -> Self {
         Self {
             handle: tokio::task::spawn_blocking(move || {
                 let mut dst = Vec::new();
 
-                deflate::encode(&data, compression_level, &mut dst)
+                deflate::encode(backend, &data, compression_level, &mut dst)
                     .map(|crc32| (dst, crc32, data.len()))
             }),
         }
@@ -30,6 +30,10 @@ where
         Self { sink, state: None }
     }
 
+    pub fn get_ref(&self) -> &W {
+        self.sink.get_ref()
+    }
+
     pub fn get_mut(&mut self) -> &mut W {
         self.sink.get_mut()
     }
@@ -1,35 +1,91 @@
+//! DEFLATE backends.
+
+mod backend;
+
+pub use self::backend::Backend;
+
 use std::io;
 
 use flate2::Crc;
 
+use crate::writer::CompressionLevel;
+
+pub(crate) fn decode(backend: Backend, src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+    match backend {
+        Backend::Flate2 => decode_with_flate2(src, dst),
+        #[cfg(feature = "libdeflate")]
+        Backend::Libdeflate => decode_with_libdeflate(src, dst),
+    }
+}
+
 #[cfg(feature = "libdeflate")]
-pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+fn decode_with_libdeflate(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
     use libdeflater::Decompressor;
 
     let mut decoder = Decompressor::new();
 
-    decoder
+    let n = decoder
         .deflate_decompress(src, dst)
-        .map(|_| ())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    if n == dst.len() {
+        Ok(())
+    } else {
+        Err(invalid_decoded_size())
+    }
 }
 
-#[cfg(not(feature = "libdeflate"))]
-pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+fn decode_with_flate2(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
     use std::io::Read;
 
     use flate2::bufread::DeflateDecoder;
 
     let mut decoder = DeflateDecoder::new(src);
-    decoder.read_exact(dst)
+
+    decoder.read_exact(dst).map_err(|e| match e.kind() {
+        io::ErrorKind::UnexpectedEof => invalid_decoded_size(),
+        _ => e,
+    })?;
+
+    // The stream is expected to be fully consumed.
+    if decoder.read(&mut [0])? == 0 {
+        Ok(())
+    } else {
+        Err(invalid_decoded_size())
+    }
+}
+
+fn invalid_decoded_size() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "block data size mismatch: decoded size does not match ISIZE",
+    )
 }
 
-#[cfg(feature = "libdeflate")]
 pub(crate) fn encode(
+    backend: Backend,
     src: &[u8],
-    compression_level: libdeflater::CompressionLvl,
+    compression_level: CompressionLevel,
     dst: &mut Vec<u8>,
 ) -> io::Result<u32> {
+    match backend {
+        Backend::Flate2 => encode_with_flate2(src, compression_level.into(), dst)?,
+        #[cfg(feature = "libdeflate")]
+        Backend::Libdeflate => encode_with_libdeflate(src, compression_level.into(), dst)?,
+    }
+
+    let mut crc = Crc::new();
+    crc.update(src);
+
+    Ok(crc.sum())
+}
+
+#[cfg(feature = "libdeflate")]
+fn encode_with_libdeflate(
+    src: &[u8],
+    compression_level: libdeflater::CompressionLvl,
+    dst: &mut Vec<u8>,
+) -> io::Result<()> {
     use libdeflater::Compressor;
 
     let mut encoder = Compressor::new(compression_level);
@@ -43,30 +99,112 @@ pub(crate) fn encode(
 
     dst.truncate(len);
 
-    let mut crc = Crc::new();
-    crc.update(src);
-
-    Ok(crc.sum())
+    Ok(())
 }
 
-#[cfg(not(feature = "libdeflate"))]
-pub(crate) fn encode(
+fn encode_with_flate2(
     src: &[u8],
     compression_level: flate2::Compression,
     dst: &mut Vec<u8>,
-) -> io::Result<u32> {
+) -> io::Result<()> {
     use std::io::Write;
 
-    use flate2::write::DeflateEncoder;
+    use flate2::{write::DeflateEncoder, Compression};
 
     dst.clear();
 
-    let mut encoder = DeflateEncoder::new(dst, compression_level);
-    encoder.write_all(src)?;
-    encoder.finish()?;
+    // libdeflate already emits a single stored block when compression is disabled, but the flate2
+    // backends may split the input into multiple stored blocks.
+    if compression_level == Compression::none() {
+        encode_stored(src, dst)
+    } else {
+        let mut encoder = DeflateEncoder::new(dst, compression_level);
+        encoder.write_all(src)?;
+        encoder.finish()?;
+        Ok(())
+    }
+}
 
-    let mut crc = Crc::new();
-    crc.update(src);
+// § 3.2.4 "Non-compressed blocks (BTYPE=00)" (RFC 1951)
+fn encode_stored(src: &[u8], dst: &mut Vec<u8>) -> io::Result<()> {
+    // BFINAL = 1, BTYPE = 00
+    const HEADER: u8 = 0x01;
 
-    Ok(crc.sum())
+    let len =
+        u16::try_from(src.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let nlen = !len;
+
+    dst.push(HEADER);
+    dst.extend(len.to_le_bytes());
+    dst.extend(nlen.to_le_bytes());
+    dst.extend(src);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> io::Result<()> {
+        fn t(backend: Backend) -> io::Result<()> {
+            let mut src = Vec::new();
+            encode(backend, b"noodles", CompressionLevel::default(), &mut src)?;
+
+            let mut dst = vec![0; 7];
+            decode(backend, &src, &mut dst)?;
+            assert_eq!(dst, b"noodles");
+
+            let mut dst = vec![0; 6];
+            assert!(matches!(
+                decode(backend, &src, &mut dst),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+
+            let mut dst = vec![0; 8];
+            assert!(matches!(
+                decode(backend, &src, &mut dst),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+
+            Ok(())
+        }
+
+        t(Backend::Flate2)?;
+
+        #[cfg(feature = "libdeflate")]
+        t(Backend::Libdeflate)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encode_with_no_compression() -> io::Result<()> {
+        let backend = Backend::Flate2;
+
+        let mut dst = Vec::new();
+        encode(backend, b"noodles", CompressionLevel::NONE, &mut dst)?;
+
+        let expected = [
+            0x01, 0x07, 0x00, 0xf8, 0xff, b'n', b'o', b'o', b'd', b'l', b'e', b's',
+        ];
+        assert_eq!(dst, expected);
+
+        let src = vec![0; crate::writer::MAX_BUF_SIZE];
+        encode(backend, &src, CompressionLevel::NONE, &mut dst)?;
+
+        let len = src.len() as u16;
+        assert_eq!(dst.len(), 5 + src.len());
+        assert_eq!(dst[0], 0x01);
+        assert_eq!(dst[1..3], len.to_le_bytes());
+        assert_eq!(dst[3..5], (!len).to_le_bytes());
+        assert_eq!(dst[5..], src);
+
+        let mut buf = vec![0; src.len()];
+        decode(backend, &dst, &mut buf)?;
+        assert_eq!(buf, src);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,32 @@
+/// A DEFLATE backend.
+///
+/// This is the implementation used to encode and decode the compressed data of BGZF blocks. By
+/// default, libdeflate is used when the `libdeflate` feature is enabled; otherwise, flate2.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Backend {
+    /// [flate2](https://crates.io/crates/flate2).
+    ///
+    /// This uses zlib-ng when the `zlib-ng` feature is enabled and miniz_oxide otherwise.
+    /// Compression levels greater than 9 are clamped to 9.
+    #[cfg_attr(not(feature = "libdeflate"), default)]
+    Flate2,
+    /// [libdeflate](https://github.com/ebiggers/libdeflate).
+    ///
+    /// This requires the `libdeflate` feature.
+    #[cfg(feature = "libdeflate")]
+    #[cfg_attr(feature = "libdeflate", default)]
+    Libdeflate,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        #[cfg(feature = "libdeflate")]
+        assert_eq!(Backend::default(), Backend::Libdeflate);
+        #[cfg(not(feature = "libdeflate"))]
+        assert_eq!(Backend::default(), Backend::Flate2);
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use super::writer::BGZF_EOF;
+
+/// Returns whether the stream ends with a BGZF EOF block.
+///
+/// A missing EOF block typically indicates a truncated file. The position of the stream is
+/// restored after checking.
+///
+/// A stream missing an EOF block can be repaired by appending one using
+/// [`crate::Writer::write_eof`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_bgzf as bgzf;
+///
+/// let data = bgzf::Writer::new(Vec::new()).finish()?;
+/// assert!(bgzf::is_eof_present(&mut Cursor::new(&data))?);
+///
+/// let mut reader = Cursor::new(&data[..data.len() - 1]);
+/// assert!(!bgzf::is_eof_present(&mut reader)?);
+/// # Ok::<_, io::Error>(())
+/// ```
+///
+/// ## Append a missing EOF block
+///
+/// ```no_run
+/// # use std::{fs::{File, OpenOptions}, io};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut file = File::open("in.gz")?;
+///
+/// if !bgzf::is_eof_present(&mut file)? {
+///     let file = OpenOptions::new().append(true).open("in.gz")?;
+///     let mut writer = bgzf::Writer::new(file);
+///     writer.write_eof()?;
+/// }
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn is_eof_present<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let pos = reader.stream_position()?;
+    let result = read_eof_present(reader);
+    reader.seek(SeekFrom::Start(pos))?;
+    result
+}
+
+fn read_eof_present<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let eof_len = BGZF_EOF.len() as u64;
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    if len < eof_len {
+        return Ok(false);
+    }
+
+    reader.seek(SeekFrom::Start(len - eof_len))?;
+
+    let mut buf = [0; 28];
+    reader.read_exact(&mut buf)?;
+
+    Ok(buf == BGZF_EOF)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_is_eof_present() -> io::Result<()> {
+        let mut reader = Cursor::new(Vec::new());
+        assert!(!is_eof_present(&mut reader)?);
+
+        let mut reader = Cursor::new(BGZF_EOF);
+        reader.set_position(8);
+        assert!(is_eof_present(&mut reader)?);
+        assert_eq!(reader.position(), 8);
+
+        let mut data = BGZF_EOF.to_vec();
+        data.push(0x00);
+        let mut reader = Cursor::new(data);
+        assert!(!is_eof_present(&mut reader)?);
+
+        Ok(())
+    }
+}
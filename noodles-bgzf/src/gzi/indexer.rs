@@ -0,0 +1,73 @@
+use super::Index;
+
+/// A gzip index (GZI) indexer.
+///
+/// This records the compressed and uncompressed start positions of each block as blocks are
+/// added.
+#[derive(Debug)]
+pub struct Indexer {
+    index: Index,
+    compressed_position: u64,
+    uncompressed_position: u64,
+}
+
+impl Indexer {
+    /// Adds a block.
+    ///
+    /// This records the start positions of the block that follows the given one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    /// let mut indexer = gzi::Indexer::default();
+    /// indexer.add_block(34, 7);
+    /// ```
+    pub fn add_block(&mut self, compressed_size: u64, uncompressed_size: u64) {
+        self.compressed_position += compressed_size;
+        self.uncompressed_position += uncompressed_size;
+
+        self.index
+            .push((self.compressed_position, self.uncompressed_position));
+    }
+
+    /// Returns the index built so far.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    /// let indexer = gzi::Indexer::default();
+    /// assert_eq!(indexer.index(), &[(0, 0)]);
+    /// ```
+    pub fn index(&self) -> &Index {
+        &self.index
+    }
+
+    /// Builds a gzip index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::gzi;
+    ///
+    /// let mut indexer = gzi::Indexer::default();
+    /// indexer.add_block(34, 7);
+    ///
+    /// let index = indexer.build();
+    /// assert_eq!(index, [(0, 0), (34, 7)]);
+    /// ```
+    pub fn build(self) -> Index {
+        self.index
+    }
+}
+
+impl Default for Indexer {
+    fn default() -> Self {
+        Self {
+            index: vec![(0, 0)],
+            compressed_position: 0,
+            uncompressed_position: 0,
+        }
+    }
+}
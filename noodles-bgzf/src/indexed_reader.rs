@@ -9,9 +9,12 @@ use std::io::{self, BufRead, Read, Seek, SeekFrom};
 use super::{gzi, Reader, VirtualPosition};
 
 /// An indexed BGZF reader.
+///
+/// This uses a gzip index (GZI) to seek to uncompressed positions.
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: gzi::Index,
+    uncompressed_position: u64,
 }
 
 impl<R> IndexedReader<R> {
@@ -75,6 +78,7 @@ where
         Self {
             inner: Reader::new(inner),
             index,
+            uncompressed_position: 0,
         }
     }
 
@@ -127,7 +131,9 @@ where
     R: Read,
 {
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
-        self.inner.read(buf)
+        let n = self.inner.read(buf)?;
+        self.uncompressed_position += n as u64;
+        Ok(n)
     }
 }
 
@@ -141,6 +147,42 @@ where
 
     fn consume(&mut self, amt: usize) {
         self.inner.consume(amt);
+        self.uncompressed_position += amt as u64;
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: Read + Seek,
+{
+    fn seek_to_uncompressed_position(&mut self, pos: u64) -> io::Result<u64> {
+        if self.index.is_empty() {
+            return Err(empty_index_error());
+        }
+
+        self.inner.seek_by_uncompressed_position(&self.index, pos)?;
+        self.uncompressed_position = pos;
+        Ok(pos)
+    }
+
+    fn uncompressed_len(&mut self) -> io::Result<u64> {
+        let (_, last_uncompressed_position) =
+            self.index.last().copied().ok_or_else(empty_index_error)?;
+
+        self.seek_to_uncompressed_position(last_uncompressed_position)?;
+
+        loop {
+            let src = self.fill_buf()?;
+
+            if src.is_empty() {
+                break;
+            }
+
+            let amt = src.len();
+            self.consume(amt);
+        }
+
+        Ok(self.uncompressed_position)
     }
 }
 
@@ -149,9 +191,89 @@ where
     R: Read + Seek,
 {
     fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
-        match pos {
-            SeekFrom::Start(pos) => self.inner.seek_by_uncompressed_position(&self.index, pos),
-            _ => unimplemented!(),
-        }
+        let (base, offset) = match pos {
+            SeekFrom::Start(pos) => return self.seek_to_uncompressed_position(pos),
+            SeekFrom::End(offset) => (self.uncompressed_len()?, offset),
+            SeekFrom::Current(offset) => (self.uncompressed_position, offset),
+        };
+
+        let pos = base.checked_add_signed(offset).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        })?;
+
+        self.seek_to_uncompressed_position(pos)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.uncompressed_position)
+    }
+}
+
+fn empty_index_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "empty index")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::writer;
+
+    fn build_reader() -> io::Result<IndexedReader<io::Cursor<Vec<u8>>>> {
+        let mut writer = writer::Builder::default()
+            .set_build_gzi_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-bgzf")?;
+        writer.flush()?;
+
+        let index = writer.gzi_index().cloned().unwrap_or_default();
+        let data = writer.finish()?;
+
+        Ok(IndexedReader::new(io::Cursor::new(data), index))
+    }
+
+    #[test]
+    fn test_seek() -> io::Result<()> {
+        let mut reader = build_reader()?;
+        let mut buf = [0; 3];
+
+        assert_eq!(reader.seek(SeekFrom::Start(5))?, 5);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"es-");
+        assert_eq!(reader.stream_position()?, 8);
+
+        assert_eq!(reader.seek(SeekFrom::Current(-6))?, 2);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"odl");
+
+        assert_eq!(reader.seek(SeekFrom::End(-3))?, 9);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"gzf");
+
+        assert!(matches!(
+            reader.seek(SeekFrom::Current(-13)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut reader = IndexedReader::new(io::empty(), gzi::Index::default());
+
+        assert!(matches!(
+            reader.seek(SeekFrom::End(0)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.seek(SeekFrom::Start(0)),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
     }
 }
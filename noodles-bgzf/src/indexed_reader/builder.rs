@@ -17,12 +17,30 @@ pub struct Builder {
 
 impl Builder {
     /// Sets a GZ index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{gzi, indexed_reader::Builder};
+    /// let builder = Builder::default().set_index(gzi::Index::default());
+    /// ```
     pub fn set_index(mut self, index: gzi::Index) -> Self {
         self.index = Some(index);
         self
     }
 
     /// Builds an indexed BGZF reader from a path.
+    ///
+    /// If no index is set, the associated index (`<src>.gzi`) is read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::io;
+    /// use noodles_bgzf::indexed_reader::Builder;
+    /// let reader = Builder::default().build_from_path("sample.fa.gz")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
     pub fn build_from_path<P>(self, src: P) -> io::Result<IndexedReader<File>>
     where
         P: AsRef<Path>,
@@ -37,10 +55,28 @@ impl Builder {
 
         let inner = self.reader_builder.build_from_path(src)?;
 
-        Ok(IndexedReader { inner, index })
+        Ok(IndexedReader {
+            inner,
+            index,
+            uncompressed_position: 0,
+        })
     }
 
-    /// Builds a indexed BGZF reader from a reader.
+    /// Builds an indexed BGZF reader from a reader.
+    ///
+    /// An index must be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf::{gzi, indexed_reader::Builder};
+    ///
+    /// let reader = Builder::default()
+    ///     .set_index(gzi::Index::default())
+    ///     .build_from_reader(io::empty())?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
     pub fn build_from_reader<R>(self, reader: R) -> io::Result<IndexedReader<R>>
     where
         R: Read,
@@ -51,7 +87,11 @@ impl Builder {
 
         let inner = self.reader_builder.build_from_reader(reader);
 
-        Ok(IndexedReader { inner, index })
+        Ok(IndexedReader {
+            inner,
+            index,
+            uncompressed_position: 0,
+        })
     }
 }
 
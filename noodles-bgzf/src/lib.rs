@@ -38,7 +38,8 @@
 pub mod r#async;
 
 mod block;
-pub(crate) mod deflate;
+pub mod deflate;
+mod eof;
 mod gz;
 pub mod gzi;
 pub mod indexed_reader;
@@ -46,13 +47,14 @@ pub mod io;
 mod multithreaded_reader;
 pub mod multithreaded_writer;
 pub mod reader;
+mod recompress;
 pub mod virtual_position;
 pub mod writer;
 
 pub use self::{
-    indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
-    multithreaded_writer::MultithreadedWriter, reader::Reader, virtual_position::VirtualPosition,
-    writer::Writer,
+    eof::is_eof_present, indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
+    multithreaded_writer::MultithreadedWriter, reader::Reader, recompress::recompress,
+    virtual_position::VirtualPosition, writer::Writer,
 };
 
 #[cfg(feature = "async")]
@@ -140,6 +142,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_self_with_backends() -> io::Result<()> {
+        use self::deflate::Backend;
+
+        // With the `zlib-ng` feature, `Backend::Flate2` uses zlib-ng.
+        let backends = [
+            Backend::Flate2,
+            #[cfg(feature = "libdeflate")]
+            Backend::Libdeflate,
+        ];
+
+        for compression_level in [
+            writer::CompressionLevel::NONE,
+            writer::CompressionLevel::default(),
+            writer::CompressionLevel::BEST,
+        ] {
+            for writer_backend in backends {
+                let mut writer = writer::Builder::default()
+                    .set_compression_level(compression_level)
+                    .set_backend(writer_backend)
+                    .build_from_writer(Vec::new());
+
+                writer.write_all(b"noodles-bgzf")?;
+                let data = writer.finish()?;
+
+                for reader_backend in backends {
+                    let mut reader = reader::Builder::default()
+                        .set_backend(reader_backend)
+                        .build_from_reader(&data[..]);
+
+                    let mut buf = Vec::new();
+                    reader.read_to_end(&mut buf)?;
+
+                    assert_eq!(buf, b"noodles-bgzf");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_self_multithreaded() -> io::Result<()> {
         let mut writer = MultithreadedWriter::new(Vec::new());
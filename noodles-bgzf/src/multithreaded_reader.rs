@@ -257,6 +257,82 @@ where
     }
 }
 
+impl<R> MultithreadedReader<R>
+where
+    R: Read + Send + Seek + 'static,
+{
+    /// Seeks the stream to the given virtual position.
+    ///
+    /// This pauses the workers, moves the underlying stream's cursor to the compressed position,
+    /// and resumes reading. The next block is then read, decompressed, and has its own cursor moved
+    /// to the uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::MultithreadedReader::new(io::empty());
+    /// reader.seek(bgzf::VirtualPosition::MIN)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
+        let (cpos, upos) = pos.into();
+
+        self.get_mut().seek(SeekFrom::Start(cpos))?;
+        self.position = cpos;
+
+        self.read_block()?;
+
+        self.buffer.block.data_mut().set_position(usize::from(upos));
+
+        Ok(pos)
+    }
+
+    /// Seeks the stream to the given uncompressed position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::MultithreadedReader::new(io::empty());
+    /// let index = vec![(0, 0)];
+    /// reader.seek_by_uncompressed_position(&index, 0)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek_by_uncompressed_position(
+        &mut self,
+        index: &gzi::Index,
+        pos: u64,
+    ) -> io::Result<u64> {
+        let i = index.partition_point(|r| r.1 <= pos);
+
+        let record = i
+            .checked_sub(1)
+            .and_then(|j| index.get(j))
+            .copied()
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid index: missing block for position",
+                )
+            })?;
+
+        let cpos = record.0;
+        self.get_mut().seek(SeekFrom::Start(cpos))?;
+        self.position = cpos;
+
+        self.read_block()?;
+
+        let upos = usize::try_from(pos - record.1)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        self.buffer.block.data_mut().set_position(upos);
+
+        Ok(pos)
+    }
+}
+
 impl<R> Drop for MultithreadedReader<R> {
     fn drop(&mut self) {
         if !matches!(self.state, State::Done) {
@@ -322,40 +398,17 @@ where
     R: Read + Send + Seek + 'static,
 {
     fn seek_to_virtual_position(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
-        let (cpos, upos) = pos.into();
-
-        self.get_mut().seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
-
-        self.buffer.block.data_mut().set_position(usize::from(upos));
-
-        Ok(pos)
+        self.seek(pos)
     }
 
     fn seek_with_index(&mut self, index: &gzi::Index, pos: SeekFrom) -> io::Result<u64> {
-        let SeekFrom::Start(pos) = pos else {
-            unimplemented!();
-        };
-
-        assert!(!index.is_empty());
-
-        let i = index.partition_point(|r| r.1 <= pos);
-        // SAFETY: `i` is > 0.
-        let record = index[i - 1];
-
-        let cpos = record.0;
-        self.get_mut().seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
-
-        let upos = usize::try_from(pos - record.1)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        self.buffer.block.data_mut().set_position(upos);
-
-        Ok(pos)
+        match pos {
+            SeekFrom::Start(pos) => self.seek_by_uncompressed_position(index, pos),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unsupported seek position",
+            )),
+        }
     }
 }
 
@@ -401,7 +454,7 @@ where
 }
 
 fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<JoinHandle<()>> {
-    use super::reader::frame::parse_block;
+    use super::{deflate::Backend, reader::frame::parse_block};
 
     (0..worker_count.get())
         .map(|_| {
@@ -409,7 +462,8 @@ fn spawn_inflaters(worker_count: NonZeroUsize, inflate_rx: InflateRx) -> Vec<Joi
 
             thread::spawn(move || {
                 while let Ok((mut buffer, buffered_tx)) = inflate_rx.recv() {
-                    let result = parse_block(&buffer.buf, &mut buffer.block).map(|_| buffer);
+                    let result = parse_block(Backend::default(), &buffer.buf, &mut buffer.block)
+                        .map(|_| buffer);
                     buffered_tx.send(result).unwrap();
                 }
             })
@@ -423,21 +477,21 @@ mod tests {
 
     use super::*;
 
+    #[rustfmt::skip]
+    static DATA: &[u8] = &[
+        // block 0 (b"noodles")
+        0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+        0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+        0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        // EOF block
+        0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+        0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
     #[test]
     fn test_seek_to_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
         use crate::io::Seek;
 
-        #[rustfmt::skip]
-        static DATA: &[u8] = &[
-            // block 0 (b"noodles")
-            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
-            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
-            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
-            // EOF block
-            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
-            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        ];
-
         const EOF_VIRTUAL_POSITION: VirtualPosition = match VirtualPosition::new(63, 0) {
             Some(pos) => pos,
             None => unreachable!(),
@@ -466,4 +520,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_by_uncompressed_position() -> io::Result<()> {
+        let mut reader =
+            MultithreadedReader::with_worker_count(NonZeroUsize::MIN, Cursor::new(DATA));
+
+        let index = vec![(0, 0)];
+        let pos = reader.seek_by_uncompressed_position(&index, 4)?;
+        assert_eq!(pos, 4);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, b"les");
+
+        let index = Vec::new();
+        assert!(matches!(
+            reader.seek_by_uncompressed_position(&index, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
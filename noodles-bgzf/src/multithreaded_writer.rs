@@ -13,7 +13,10 @@ use bytes::{Bytes, BytesMut};
 use crossbeam_channel::{Receiver, Sender};
 
 pub use self::builder::Builder;
-use super::writer::{CompressionLevelImpl, MAX_BUF_SIZE};
+use super::{
+    deflate::Backend,
+    writer::{CompressionLevel, MAX_BUF_SIZE},
+};
 
 type FrameParts = (Vec<u8>, u32, usize);
 type BufferedTx = Sender<io::Result<FrameParts>>;
@@ -92,7 +95,10 @@ where
     /// ```
     pub fn finish(&mut self) -> io::Result<W> {
         self.flush()?;
+        self.shutdown()
+    }
 
+    fn shutdown(&mut self) -> io::Result<W> {
         let state = mem::replace(&mut self.state, State::Done);
 
         match state {
@@ -136,7 +142,10 @@ where
 
         let (buffered_tx, buffered_rx) = crossbeam_channel::bounded(1);
 
-        write_tx.send(buffered_rx).unwrap();
+        if write_tx.send(buffered_rx).is_err() {
+            // The writer worker only hangs up early when it fails to write to the inner writer.
+            return Err(self.take_writer_error());
+        }
 
         let src = self.buf.split().freeze();
         let message = (src, buffered_tx);
@@ -144,6 +153,13 @@ where
 
         Ok(())
     }
+
+    fn take_writer_error(&mut self) -> io::Error {
+        match self.shutdown() {
+            Ok(_) => io::Error::from(io::ErrorKind::BrokenPipe),
+            Err(e) => e,
+        }
+    }
 }
 
 impl<W> Drop for MultithreadedWriter<W>
@@ -201,23 +217,19 @@ where
     })
 }
 
-fn spawn_deflaters<L>(
-    compression_level: L,
+fn spawn_deflaters(
+    backend: Backend,
+    compression_level: CompressionLevel,
     worker_count: NonZeroUsize,
     deflate_rx: DeflateRx,
-) -> Vec<JoinHandle<()>>
-where
-    L: Into<CompressionLevelImpl>,
-{
-    let compression_level = compression_level.into();
-
+) -> Vec<JoinHandle<()>> {
     (0..worker_count.get())
         .map(|_| {
             let deflate_rx = deflate_rx.clone();
 
             thread::spawn(move || {
                 while let Ok((src, buffered_tx)) = deflate_rx.recv() {
-                    let result = compress(&src, compression_level);
+                    let result = compress(backend, &src, compression_level);
                     buffered_tx.send(result).ok();
                 }
             })
@@ -225,9 +237,43 @@ where
         .collect()
 }
 
-fn compress(src: &[u8], compression_level: CompressionLevelImpl) -> io::Result<FrameParts> {
+fn compress(
+    backend: Backend,
+    src: &[u8],
+    compression_level: CompressionLevel,
+) -> io::Result<FrameParts> {
     use super::deflate;
     let mut dst = Vec::new();
-    let crc32 = deflate::encode(src, compression_level, &mut dst)?;
+    let crc32 = deflate::encode(backend, src, compression_level, &mut dst)?;
     Ok((dst, crc32, src.len()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_with_failing_inner_writer() {
+        struct FailingWriter;
+
+        impl Write for FailingWriter {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::PermissionDenied))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = MultithreadedWriter::new(FailingWriter);
+        let data = vec![0; MAX_BUF_SIZE];
+
+        let result = (0..8).try_for_each(|_| writer.write_all(&data));
+
+        assert!(matches!(
+            result,
+            Err(e) if e.kind() == io::ErrorKind::PermissionDenied
+        ));
+    }
+}
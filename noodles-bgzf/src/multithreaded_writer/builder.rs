@@ -3,11 +3,12 @@ use std::{io::Write, num::NonZeroUsize};
 use bytes::BytesMut;
 
 use super::MultithreadedWriter;
-use crate::writer::CompressionLevel;
+use crate::{deflate::Backend, writer::CompressionLevel};
 
 /// A multithreaded BGZF writer builder.
 pub struct Builder {
     compression_level: CompressionLevel,
+    backend: Backend,
     worker_count: NonZeroUsize,
 }
 
@@ -25,6 +26,19 @@ impl Builder {
         self
     }
 
+    /// Sets the DEFLATE backend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{deflate::Backend, multithreaded_writer::Builder};
+    /// let builder = Builder::default().set_backend(Backend::Flate2);
+    /// ```
+    pub fn set_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Sets the worker count.
     ///
     /// # Examples
@@ -60,8 +74,12 @@ impl Builder {
         let (deflate_tx, deflate_rx) = crossbeam_channel::bounded(worker_count);
 
         let writer_handle = spawn_writer(writer, write_rx);
-        let deflater_handles =
-            spawn_deflaters(self.compression_level, self.worker_count, deflate_rx);
+        let deflater_handles = spawn_deflaters(
+            self.backend,
+            self.compression_level,
+            self.worker_count,
+            deflate_rx,
+        );
 
         MultithreadedWriter {
             state: State::Running {
@@ -79,6 +97,7 @@ impl Default for Builder {
     fn default() -> Self {
         Self {
             compression_level: CompressionLevel::default(),
+            backend: Backend::default(),
             worker_count: NonZeroUsize::MIN,
         }
     }
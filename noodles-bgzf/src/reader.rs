@@ -2,12 +2,14 @@
 
 mod builder;
 pub(crate) mod frame;
+mod raw_block;
+mod raw_blocks;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, raw_block::RawBlock, raw_blocks::RawBlocks};
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
-use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
+use super::{deflate::Backend, gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
 ///
@@ -16,6 +18,9 @@ use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
 /// correctly track (virtual) positions, the reader _cannot_ be double buffered (e.g., using
 /// [`std::io::BufReader`]).
 ///
+/// Each block is verified when it is decompressed: if the decoded data does not match the block's
+/// CRC32 or ISIZE, reading fails with an [`io::ErrorKind::InvalidData`] error.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -31,6 +36,7 @@ pub struct Reader<R> {
     buf: Vec<u8>,
     position: u64,
     block: Block,
+    backend: Backend,
 }
 
 impl<R> Reader<R> {
@@ -91,7 +97,7 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Builder.build_from_reader(inner)
+        Builder::default().build_from_reader(inner)
     }
 
     /// Returns the current position of the stream.
@@ -122,6 +128,73 @@ where
         self.block.virtual_position()
     }
 
+    /// Reads a raw block.
+    ///
+    /// The block is read as-is from the underlying stream and is not decompressed. Any remaining
+    /// uncompressed data from a previously read block is discarded.
+    ///
+    /// This returns the size of the compressed block. If the stream reached EOF, this returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    ///
+    /// let mut block = bgzf::reader::RawBlock::default();
+    /// assert_eq!(reader.read_raw_block(&mut block)?, 28);
+    /// assert_eq!(block.position(), 0);
+    /// assert_eq!(block.uncompressed_size(), 0);
+    ///
+    /// assert_eq!(reader.read_raw_block(&mut block)?, 0);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn read_raw_block(&mut self, block: &mut RawBlock) -> io::Result<usize> {
+        use self::frame::read_frame_into;
+
+        if read_frame_into(&mut self.inner, &mut block.buf)?.is_none() {
+            block.buf.clear();
+            return Ok(0);
+        }
+
+        block.position = self.position;
+
+        self.block.set_position(self.position);
+        self.block.set_size(block.size());
+
+        let data = self.block.data_mut();
+        data.set_position(0);
+        data.resize(0);
+
+        self.position += block.size();
+
+        Ok(block.buf.len())
+    }
+
+    /// Returns an iterator over raw blocks starting from the current stream position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    ///
+    /// for result in reader.raw_blocks() {
+    ///     let block = result?;
+    ///     assert_eq!(block.uncompressed_size(), 0);
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn raw_blocks(&mut self) -> RawBlocks<'_, R> {
+        RawBlocks::new(self)
+    }
+
     fn read_nonempty_block_with<F>(&mut self, mut f: F) -> io::Result<usize>
     where
         F: FnMut(&[u8], &mut Block) -> io::Result<()>,
@@ -144,12 +217,16 @@ where
 
     fn read_block(&mut self) -> io::Result<usize> {
         use self::frame::parse_block;
-        self.read_nonempty_block_with(parse_block)
+
+        let backend = self.backend;
+        self.read_nonempty_block_with(|src, block| parse_block(backend, src, block))
     }
 
     fn read_block_into_buf(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         use self::frame::parse_block_into_buf;
-        self.read_nonempty_block_with(|src, block| parse_block_into_buf(src, block, buf))
+
+        let backend = self.backend;
+        self.read_nonempty_block_with(|src, block| parse_block_into_buf(backend, src, block, buf))
     }
 }
 
@@ -419,4 +496,42 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_raw_block() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::writer::BGZF_EOF;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let block_0_size = writer.get_ref().len() as u64;
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let mut block = RawBlock::default();
+
+        assert_eq!(reader.read_raw_block(&mut block)?, block_0_size as usize);
+        assert_eq!(block.position(), 0);
+        assert_eq!(block.size(), block_0_size);
+        assert_eq!(block.uncompressed_size(), 7);
+        assert_eq!(block.inflate()?, b"noodles");
+        assert_eq!(
+            reader.virtual_position(),
+            VirtualPosition::try_from((block_0_size, 0)).unwrap()
+        );
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"bgzf");
+
+        let mut reader = Reader::new(&data[..]);
+        let blocks: Vec<_> = reader.raw_blocks().collect::<io::Result<_>>()?;
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[2].as_ref(), BGZF_EOF);
+
+        Ok(())
+    }
 }
@@ -5,13 +5,31 @@ use std::{
 };
 
 use super::Reader;
-use crate::Block;
+use crate::{deflate::Backend, Block};
 
 /// A BGZF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    backend: Backend,
+}
 
 impl Builder {
+    /// Sets the DEFLATE backend.
+    ///
+    /// By default, the backend is libdeflate when the `libdeflate` feature is enabled; otherwise,
+    /// flate2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, deflate::Backend};
+    /// let builder = bgzf::reader::Builder::default().set_backend(Backend::Flate2);
+    /// ```
+    pub fn set_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -48,6 +66,7 @@ impl Builder {
             buf: Vec::new(),
             position: 0,
             block: Block::default(),
+            backend: self.backend,
         }
     }
 }
@@ -3,7 +3,7 @@ use std::io::{self, Read};
 use bytes::Buf;
 use flate2::Crc;
 
-use crate::{gz, Block, BGZF_HEADER_SIZE};
+use crate::{deflate::Backend, gz, Block, BGZF_HEADER_SIZE};
 
 const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
 
@@ -114,14 +114,15 @@ where
     Ok((crc32, r#isize))
 }
 
-pub(crate) fn parse_block(src: &[u8], block: &mut Block) -> io::Result<()> {
+pub(crate) fn parse_block(backend: Backend, src: &[u8], block: &mut Block) -> io::Result<()> {
     let (block_size, cdata, crc32, r#isize) = parse_frame(src)?;
     block_initialize(block, block_size, isize);
-    inflate(cdata, crc32, block.data_mut().as_mut())?;
+    inflate(backend, cdata, crc32, block.data_mut().as_mut())?;
     Ok(())
 }
 
 pub(super) fn parse_block_into_buf(
+    backend: Backend,
     src: &[u8],
     block: &mut Block,
     buf: &mut [u8],
@@ -129,7 +130,18 @@ pub(super) fn parse_block_into_buf(
     let (block_size, cdata, crc32, r#isize) = parse_frame(src)?;
     block_initialize(block, block_size, isize);
     block.data_mut().set_position(r#isize);
-    inflate(cdata, crc32, &mut buf[..r#isize])?;
+    inflate(backend, cdata, crc32, &mut buf[..r#isize])?;
+    Ok(())
+}
+
+pub(super) fn parse_block_into_vec(
+    backend: Backend,
+    src: &[u8],
+    dst: &mut Vec<u8>,
+) -> io::Result<()> {
+    let (_, cdata, crc32, r#isize) = parse_frame(src)?;
+    dst.resize(r#isize, 0);
+    inflate(backend, cdata, crc32, dst)?;
     Ok(())
 }
 
@@ -153,10 +165,10 @@ fn block_initialize(block: &mut Block, block_size: u64, r#isize: usize) {
     data.resize(r#isize);
 }
 
-fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
+fn inflate(backend: Backend, src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
     use crate::deflate;
 
-    deflate::decode(src, dst)?;
+    deflate::decode(backend, src, dst)?;
 
     let mut crc = Crc::new();
     crc.update(dst);
@@ -239,4 +251,39 @@ mod tests {
         let mut buf = Vec::new();
         assert!(read_frame_into(&mut reader, &mut buf).is_err());
     }
+
+    #[test]
+    fn test_parse_block_with_invalid_trailer() -> io::Result<()> {
+        use std::io::Write;
+
+        let mut writer = crate::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let src = writer.get_ref().clone();
+
+        let mut block = Block::default();
+        parse_block(Backend::default(), &src, &mut block)?;
+        assert_eq!(block.data().as_ref(), b"noodles");
+
+        let crc32_start = src.len() - gz::TRAILER_SIZE;
+        let isize_start = crc32_start + 4;
+
+        // CRC32
+        let mut data = src.clone();
+        data[crc32_start] ^= 0xff;
+        assert!(matches!(
+            parse_block(Backend::default(), &data, &mut block),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        // ISIZE
+        let mut data = src.clone();
+        data[isize_start] += 1;
+        assert!(matches!(
+            parse_block(Backend::default(), &data, &mut block),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,166 @@
+use std::io;
+
+use crate::{deflate::Backend, gz, BGZF_HEADER_SIZE};
+
+/// A raw BGZF block.
+///
+/// A raw block holds the compressed frame (header, compressed data, and trailer) of a single BGZF
+/// block. It is not decompressed unless [`RawBlock::inflate`] or [`RawBlock::inflate_into`] is
+/// called.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RawBlock {
+    pub(super) position: u64,
+    pub(super) buf: Vec<u8>,
+}
+
+impl RawBlock {
+    /// Returns the position of the block in the compressed stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::reader::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.position(), 0);
+    /// ```
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the size of the compressed block.
+    ///
+    /// This is the size of the entire frame, i.e., `BSIZE` + 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::reader::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.size(), 0);
+    /// ```
+    pub fn size(&self) -> u64 {
+        self.buf.len() as u64
+    }
+
+    /// Returns the compressed data (`CDATA`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::reader::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert!(block.compressed_data().is_empty());
+    /// ```
+    pub fn compressed_data(&self) -> &[u8] {
+        let end = self.buf.len().saturating_sub(gz::TRAILER_SIZE);
+        self.buf.get(BGZF_HEADER_SIZE..end).unwrap_or_default()
+    }
+
+    /// Returns the CRC32 of the uncompressed data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::reader::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.crc32(), 0);
+    /// ```
+    pub fn crc32(&self) -> u32 {
+        self.trailer().map(|(crc32, _)| crc32).unwrap_or_default()
+    }
+
+    /// Returns the size of the uncompressed data (`ISIZE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::reader::RawBlock;
+    /// let block = RawBlock::default();
+    /// assert_eq!(block.uncompressed_size(), 0);
+    /// ```
+    pub fn uncompressed_size(&self) -> u32 {
+        self.trailer()
+            .map(|(_, r#isize)| r#isize)
+            .unwrap_or_default()
+    }
+
+    /// Decompresses the block data.
+    ///
+    /// The decompressed data is verified using the block's CRC32 and `ISIZE`.
+    ///
+    /// This allocates a new buffer for each call. To reuse an output buffer, see
+    /// [`Self::inflate_into`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let data = bgzf::Writer::new(Vec::new()).finish()?;
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    ///
+    /// let mut block = bgzf::reader::RawBlock::default();
+    /// reader.read_raw_block(&mut block)?;
+    ///
+    /// assert!(block.inflate()?.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn inflate(&self) -> io::Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        self.inflate_into(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Decompresses the block data into the given buffer.
+    ///
+    /// The buffer is resized to the uncompressed size (`ISIZE`) and overwritten with the
+    /// decompressed data. Its allocation is reused, which avoids allocating a new buffer for
+    /// every block when decompressing many blocks.
+    ///
+    /// The decompressed data is verified using the block's CRC32 and `ISIZE`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Vec::new());
+    /// writer.write_all(b"noodles")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let mut reader = bgzf::Reader::new(&data[..]);
+    /// let mut block = bgzf::reader::RawBlock::default();
+    /// let mut buf = Vec::new();
+    ///
+    /// reader.read_raw_block(&mut block)?;
+    /// block.inflate_into(&mut buf)?;
+    /// assert_eq!(buf, b"noodles");
+    ///
+    /// reader.read_raw_block(&mut block)?;
+    /// block.inflate_into(&mut buf)?;
+    /// assert!(buf.is_empty());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn inflate_into(&self, dst: &mut Vec<u8>) -> io::Result<()> {
+        use super::frame::parse_block_into_vec;
+        parse_block_into_vec(Backend::default(), &self.buf, dst)
+    }
+
+    fn trailer(&self) -> Option<(u32, u32)> {
+        let start = self.buf.len().checked_sub(gz::TRAILER_SIZE)?;
+        let src = self.buf.get(start..)?;
+
+        let crc32 = u32::from_le_bytes(src[..4].try_into().ok()?);
+        let r#isize = u32::from_le_bytes(src[4..].try_into().ok()?);
+
+        Some((crc32, r#isize))
+    }
+}
+
+impl AsRef<[u8]> for RawBlock {
+    fn as_ref(&self) -> &[u8] {
+        &self.buf
+    }
+}
@@ -0,0 +1,36 @@
+use std::io::{self, Read};
+
+use super::{RawBlock, Reader};
+
+/// An iterator over raw blocks of a BGZF reader.
+///
+/// This is created by calling [`Reader::raw_blocks`].
+pub struct RawBlocks<'r, R> {
+    inner: &'r mut Reader<R>,
+}
+
+impl<'r, R> RawBlocks<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(inner: &'r mut Reader<R>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'r, R> Iterator for RawBlocks<'r, R>
+where
+    R: Read,
+{
+    type Item = io::Result<RawBlock>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut block = RawBlock::default();
+
+        match self.inner.read_raw_block(&mut block) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(block)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
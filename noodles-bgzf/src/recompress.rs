@@ -0,0 +1,104 @@
+use std::io::{self, Read, Write};
+
+use super::{reader::RawBlock, Block, Reader};
+
+/// Recompresses a BGZF stream.
+///
+/// This copies each block from the reader to the writer, decompressing the block data and writing
+/// it as a single block to the writer, e.g., a [`crate::Writer`] or
+/// [`crate::MultithreadedWriter`] with a different compression level or worker count. Block
+/// boundaries in the uncompressed stream are preserved. Empty blocks, including the EOF block, are
+/// skipped; finishing the writer is left to the caller.
+///
+/// This returns the number of uncompressed bytes copied.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf::{self as bgzf, writer::CompressionLevel};
+///
+/// let mut writer = bgzf::writer::Builder::default()
+///     .set_compression_level(CompressionLevel::FAST)
+///     .build_from_writer(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let src = writer.finish()?;
+///
+/// let mut reader = bgzf::Reader::new(&src[..]);
+/// let mut writer = bgzf::writer::Builder::default()
+///     .set_compression_level(CompressionLevel::BEST)
+///     .build_from_writer(Vec::new());
+///
+/// assert_eq!(bgzf::recompress(&mut reader, &mut writer)?, 7);
+///
+/// let dst = writer.finish()?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn recompress<R, W>(reader: &mut Reader<R>, writer: &mut W) -> io::Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    use super::{deflate::Backend, reader::frame::parse_block};
+
+    let mut raw_block = RawBlock::default();
+    let mut block = Block::default();
+    let mut len = 0;
+
+    while reader.read_raw_block(&mut raw_block)? != 0 {
+        parse_block(Backend::default(), raw_block.as_ref(), &mut block)?;
+
+        let data = block.data().as_ref();
+
+        if data.is_empty() {
+            continue;
+        }
+
+        writer.write_all(data)?;
+        writer.flush()?;
+
+        len += data.len() as u64;
+    }
+
+    Ok(len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{writer::CompressionLevel, MultithreadedWriter, Writer};
+
+    #[test]
+    fn test_recompress() -> io::Result<()> {
+        let mut writer = crate::writer::Builder::default()
+            .set_compression_level(CompressionLevel::NONE)
+            .build_from_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"-bgzf")?;
+        let src = writer.finish()?;
+
+        let mut reader = Reader::new(&src[..]);
+        let mut writer = Writer::new(Vec::new());
+        assert_eq!(recompress(&mut reader, &mut writer)?, 12);
+        let dst = writer.finish()?;
+
+        let mut reader = Reader::new(&dst[..]);
+        let blocks: Vec<_> = reader.raw_blocks().collect::<io::Result<_>>()?;
+        let uncompressed_sizes: Vec<_> = blocks.iter().map(|b| b.uncompressed_size()).collect();
+        assert_eq!(uncompressed_sizes, [7, 5, 0]);
+
+        let mut reader = Reader::new(&src[..]);
+        let mut writer = MultithreadedWriter::new(Vec::new());
+        recompress(&mut reader, &mut writer)?;
+        let dst = writer.finish()?;
+
+        let mut reader = Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ pub use self::{builder::Builder, compression_level::CompressionLevel};
 use std::io::{self, Write};
 
 pub(crate) use self::frame::write_frame;
-use super::{gz, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
+use super::{deflate::Backend, gz, gzi, VirtualPosition, BGZF_HEADER_SIZE, BGZF_MAX_ISIZE};
 
 // The max DEFLATE overhead for 65536 bytes of data at compression level 0.
 //
@@ -41,11 +41,6 @@ pub(crate) static BGZF_EOF: &[u8] = &[
     0x00, 0x00, 0x00, 0x00, // ISIZE = 0
 ];
 
-#[cfg(feature = "libdeflate")]
-pub(crate) type CompressionLevelImpl = libdeflater::CompressionLvl;
-#[cfg(not(feature = "libdeflate"))]
-pub(crate) type CompressionLevelImpl = flate2::Compression;
-
 /// A BZGF writer.
 ///
 /// This implements [`std::io::Write`], consuming uncompressed data and emitting compressed data.
@@ -70,8 +65,12 @@ where
     inner: Option<W>,
     position: u64,
     staging_buf: Vec<u8>,
+    block_size: usize,
     compression_buf: Vec<u8>,
-    compression_level: CompressionLevelImpl,
+    compression_level: CompressionLevel,
+    backend: Backend,
+    gzi_indexer: Option<gzi::Indexer>,
+    is_eof_written: bool,
 }
 
 impl<W> Writer<W>
@@ -135,6 +134,10 @@ where
 
     /// Returns the current virtual position of the stream.
     ///
+    /// This is the virtual position of the next byte to be written. Because blocks are flushed as
+    /// soon as they are full, calling this before and after writing a record gives the record's
+    /// start and end virtual positions, respectively.
+    ///
     /// # Panics
     ///
     /// This panics if the stream flushed >= 256 TiB of compressed data.
@@ -153,11 +156,42 @@ where
         VirtualPosition::try_from((self.position, uncompressed_position)).unwrap()
     }
 
+    /// Returns the gzip index (GZI) of the blocks written so far.
+    ///
+    /// This is only available if the writer was built with GZI indexing enabled
+    /// ([`Builder::set_build_gzi_index`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::writer::Builder::default()
+    ///     .set_build_gzi_index(true)
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// writer.write_all(b"noodles")?;
+    /// writer.flush()?;
+    ///
+    /// let index = writer.gzi_index();
+    /// assert_eq!(index, Some(&vec![(0, 0), (writer.position(), 7)]));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn gzi_index(&self) -> Option<&gzi::Index> {
+        self.gzi_indexer.as_ref().map(|indexer| indexer.index())
+    }
+
     fn flush_block(&mut self) -> io::Result<()> {
         use crate::deflate;
 
         let compressed_data = &mut self.compression_buf;
-        let crc32 = deflate::encode(&self.staging_buf, self.compression_level, compressed_data)?;
+        let crc32 = deflate::encode(
+            self.backend,
+            &self.staging_buf,
+            self.compression_level,
+            compressed_data,
+        )?;
 
         let inner = self.inner.as_mut().unwrap();
         let uncompressed_len = self.staging_buf.len();
@@ -165,7 +199,43 @@ where
 
         self.position += block_size as u64;
 
+        if let Some(indexer) = self.gzi_indexer.as_mut() {
+            indexer.add_block(block_size as u64, uncompressed_len as u64);
+        }
+
         self.staging_buf.clear();
+        self.is_eof_written = false;
+
+        Ok(())
+    }
+
+    /// Flushes any remaining buffers and writes a BGZF EOF block.
+    ///
+    /// Unlike finishing the stream, the writer can continue to be used. When the writer is
+    /// finished, another EOF block is only written if blocks were written after this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Write};
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let mut writer = bgzf::Writer::new(Cursor::new(Vec::new()));
+    /// writer.write_all(b"noodles-bgzf")?;
+    /// writer.write_eof()?;
+    ///
+    /// let mut inner = writer.finish()?;
+    /// assert!(bgzf::is_eof_present(&mut inner)?);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_eof(&mut self) -> io::Result<()> {
+        self.flush()?;
+
+        let inner = self.inner.as_mut().unwrap();
+        inner.write_all(BGZF_EOF)?;
+
+        self.position += BGZF_EOF.len() as u64;
+        self.is_eof_written = true;
 
         Ok(())
     }
@@ -189,12 +259,11 @@ where
     pub fn try_finish(&mut self) -> io::Result<()> {
         self.flush()?;
 
-        let inner = self.inner.as_mut().unwrap();
-        let result = inner.write_all(BGZF_EOF);
-
-        self.position += BGZF_EOF.len() as u64;
-
-        result
+        if self.is_eof_written {
+            Ok(())
+        } else {
+            self.write_eof()
+        }
     }
 
     /// Returns the underlying writer after finishing the output stream.
@@ -220,11 +289,11 @@ where
     }
 
     fn remaining(&self) -> usize {
-        MAX_BUF_SIZE - self.staging_buf.len()
+        self.block_size - self.staging_buf.len()
     }
 
     fn has_remaining(&self) -> bool {
-        self.staging_buf.len() < MAX_BUF_SIZE
+        self.staging_buf.len() < self.block_size
     }
 }
 
@@ -302,4 +371,106 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_eof() -> io::Result<()> {
+        use crate::is_eof_present;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.write_eof()?;
+        let data = writer.finish()?;
+
+        let mut reader = io::Cursor::new(&data);
+        assert!(is_eof_present(&mut reader)?);
+        assert!(!data[..data.len() - BGZF_EOF.len()].ends_with(BGZF_EOF));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_eof()?;
+        writer.write_all(b"noodles")?;
+        let data = writer.finish()?;
+
+        assert!(data.starts_with(BGZF_EOF));
+        assert!(is_eof_present(&mut io::Cursor::new(&data))?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gzi_index() -> io::Result<()> {
+        let mut writer = Builder::default()
+            .set_build_gzi_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let position = writer.position();
+
+        writer.write_all(b"bgzf")?;
+        writer.flush()?;
+
+        let expected = vec![(0, 0), (position, 7), (writer.position(), 11)];
+        assert_eq!(writer.gzi_index(), Some(&expected));
+
+        let data = writer.finish()?;
+
+        let mut reader = crate::Reader::new(io::Cursor::new(data));
+        reader.seek_by_uncompressed_position(&expected, 8)?;
+
+        let mut buf = Vec::new();
+        io::Read::read_to_end(&mut reader, &mut buf)?;
+        assert_eq!(buf, b"gzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_block_size() -> io::Result<()> {
+        use std::num::NonZeroUsize;
+
+        const BLOCK_SIZE: NonZeroUsize = match NonZeroUsize::new(4) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let mut writer = Builder::default()
+            .set_block_size(BLOCK_SIZE)
+            .build_from_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+        assert_eq!(writer.virtual_position().uncompressed(), 3);
+
+        let data = writer.finish()?;
+
+        let mut reader = crate::Reader::new(&data[..]);
+        let blocks: Vec<_> = reader.raw_blocks().collect::<io::Result<_>>()?;
+        let uncompressed_sizes: Vec<_> = blocks.iter().map(|b| b.uncompressed_size()).collect();
+        assert_eq!(uncompressed_sizes, [4, 3, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_no_compression() -> io::Result<()> {
+        let mut writer = Builder::default()
+            .set_compression_level(CompressionLevel::NONE)
+            .build_from_writer(Vec::new());
+
+        writer.write_all(b"noodles")?;
+
+        let data = writer.finish()?;
+
+        let mut expected = vec![
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x25, 0x00, // header, BSIZE = 37
+            0x01, 0x07, 0x00, 0xf8, 0xff, b'n', b'o', b'o', b'd', b'l', b'e', b's', // CDATA
+            0xa1, 0x58, 0x2a, 0x80, // CRC32
+            0x07, 0x00, 0x00, 0x00, // ISIZE
+        ];
+        expected.extend_from_slice(BGZF_EOF);
+
+        assert_eq!(data, expected);
+
+        Ok(())
+    }
 }
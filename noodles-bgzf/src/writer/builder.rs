@@ -1,11 +1,15 @@
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize};
 
 use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
+use crate::{deflate::Backend, gzi};
 
 /// A BGZF writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_level: CompressionLevel,
+    backend: Backend,
+    block_size: Option<NonZeroUsize>,
+    build_gzi_index: bool,
 }
 
 impl Builder {
@@ -26,6 +30,58 @@ impl Builder {
         self
     }
 
+    /// Sets the DEFLATE backend.
+    ///
+    /// By default, the backend is libdeflate when the `libdeflate` feature is enabled; otherwise,
+    /// flate2.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::{self as bgzf, deflate::Backend};
+    /// let builder = bgzf::writer::Builder::default().set_backend(Backend::Flate2);
+    /// ```
+    pub fn set_backend(mut self, backend: Backend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Sets the uncompressed block size.
+    ///
+    /// This is the maximum amount of uncompressed data written to a single block. Sizes larger
+    /// than the maximum allowed block size are clamped. By default, the block size is the maximum
+    /// allowed size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let builder = bgzf::writer::Builder::default()
+    ///     .set_block_size(NonZeroUsize::try_from(4096).unwrap());
+    /// ```
+    pub fn set_block_size(mut self, block_size: NonZeroUsize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
+    /// Sets whether to build a gzip index (GZI) while writing.
+    ///
+    /// When enabled, the start positions of each written block are recorded and can be retrieved
+    /// using [`Writer::gzi_index`]. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::writer::Builder::default().set_build_gzi_index(true);
+    /// ```
+    pub fn set_build_gzi_index(mut self, build_gzi_index: bool) -> Self {
+        self.build_gzi_index = build_gzi_index;
+        self
+    }
+
     /// Builds a BGZF writer from a writer.
     ///
     /// # Examples
@@ -39,12 +95,21 @@ impl Builder {
     where
         W: Write,
     {
+        let block_size = self
+            .block_size
+            .map(|n| n.get().min(MAX_BUF_SIZE))
+            .unwrap_or(MAX_BUF_SIZE);
+
         Writer {
             inner: Some(writer),
             position: 0,
-            staging_buf: Vec::with_capacity(MAX_BUF_SIZE),
+            staging_buf: Vec::with_capacity(block_size),
+            block_size,
             compression_buf: Vec::new(),
-            compression_level: self.compression_level.into(),
+            compression_level: self.compression_level,
+            backend: self.backend,
+            gzi_indexer: self.build_gzi_index.then(gzi::Indexer::default),
+            is_eof_written: false,
         }
     }
 
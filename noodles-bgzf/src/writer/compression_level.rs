@@ -13,6 +13,10 @@ pub struct CompressionLevel(u8);
 
 impl CompressionLevel {
     /// No compression.
+    ///
+    /// Block data is written as a single uncompressed (stored) DEFLATE block. Combined with a
+    /// block size of 65280 bytes (see [`super::Builder::set_block_size`]), this produces the same
+    /// output as `bgzip --compress-level 0`.
     pub const NONE: Self = Self(0);
 
     /// A compression level optimized for speed.
@@ -137,10 +141,10 @@ impl From<CompressionLevel> for libdeflater::CompressionLvl {
     }
 }
 
-#[cfg(not(feature = "libdeflate"))]
 impl From<CompressionLevel> for flate2::Compression {
     fn from(compression_level: CompressionLevel) -> Self {
-        Self::new(u32::from(u8::from(compression_level)))
+        // flate2 supports levels up to 9, but libdeflate allows higher levels.
+        Self::new(u32::from(u8::from(compression_level)).min(9))
     }
 }
 
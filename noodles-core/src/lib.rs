@@ -4,5 +4,7 @@
 
 pub mod position;
 pub mod region;
+pub mod region_set;
+pub mod strand;
 
-pub use self::{position::Position, region::Region};
+pub use self::{position::Position, region::Region, region_set::RegionSet, strand::Strand};
@@ -38,6 +38,27 @@ impl Position {
         }
     }
 
+    /// Creates a position from a 0-based position.
+    ///
+    /// This returns `None` if the given value is the maximum `usize` value, as it cannot be
+    /// represented as a 1-based position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert_eq!(Position::from_zero_based(0), Some(Position::MIN));
+    /// assert_eq!(Position::from_zero_based(7), Position::new(8));
+    /// assert!(Position::from_zero_based(usize::MAX).is_none());
+    /// ```
+    pub const fn from_zero_based(n: usize) -> Option<Self> {
+        if let Some(m) = n.checked_add(1) {
+            Self::new(m)
+        } else {
+            None
+        }
+    }
+
     /// Returns the inner value.
     ///
     /// # Examples
@@ -50,6 +71,22 @@ impl Position {
         self.0.get()
     }
 
+    /// Returns the position as a 0-based value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// assert_eq!(Position::MIN.to_zero_based(), 0);
+    ///
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.to_zero_based(), 7);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn to_zero_based(&self) -> usize {
+        self.0.get() - 1
+    }
+
     /// Adds an unsigned integer to a 1-based position.
     ///
     /// This returns `None` if the operation overflowed.
@@ -69,6 +106,27 @@ impl Position {
             None
         }
     }
+
+    /// Subtracts an unsigned integer from a 1-based position.
+    ///
+    /// This returns `None` if the result is less than [`Self::MIN`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.checked_sub(5), Position::new(3));
+    /// assert!(position.checked_sub(8).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn checked_sub(self, other: usize) -> Option<Self> {
+        if let Some(n) = self.0.get().checked_sub(other) {
+            Self::new(n)
+        } else {
+            None
+        }
+    }
 }
 
 impl fmt::Display for Position {
@@ -23,6 +23,10 @@ use super::Position;
 /// end positions are 1-based, inclusive. If no end position is given, it is assumed to span from
 /// the start to the end of the reference sequence. If no interval is given, it is assumed to span
 /// the entirety of the reference sequence.
+///
+/// When parsing, the interval may also be given as `start-` (i.e., an open end) or
+/// `start+length`, and positions may include thousands separators, e.g.,
+/// `sq0:1,000,000-2,000,000`. See [`Interval`] for details.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Region {
     name: BString,
@@ -219,6 +223,22 @@ mod tests {
 
         let end = Position::try_from(8)?;
         assert_eq!("sq3:5-8".parse(), Ok(Region::new("sq3", start..=end)));
+        assert_eq!("sq4:5-".parse(), Ok(Region::new("sq4", start..)));
+        assert_eq!("sq5:5+4".parse(), Ok(Region::new("sq5", start..=end)));
+
+        let start = Position::try_from(1000000)?;
+        let end = Position::try_from(2000000)?;
+        assert_eq!(
+            "sq6:1,000,000-2,000,000".parse(),
+            Ok(Region::new("sq6", start..=end))
+        );
+
+        assert!(matches!(
+            "sq7:5+0".parse::<Region>(),
+            Err(ParseError::InvalidInterval(
+                interval::ParseError::InvalidLength(_)
+            ))
+        ));
 
         assert_eq!("".parse::<Region>(), Err(ParseError::Empty));
 
@@ -1,7 +1,9 @@
 //! Genomic region interval.
 
 use std::{
+    borrow::Cow,
     error, fmt,
+    num::{self, NonZeroUsize},
     ops::{Bound, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeToInclusive},
     str::FromStr,
 };
@@ -12,6 +14,25 @@ use crate::{position, Position};
 ///
 /// An interval can be closed ([a, b]), left-closed and right-unbounded ([a, ∞)), left-unbounded
 /// and right-closed ((-∞, b]), or unbounded ((-∞, ∞)).
+///
+/// When parsed, a raw interval can be one of `start`, `start-`, `start-end`, or `start+length`,
+/// where positions are 1-based. Positions and lengths may include thousands separators (`,`)
+/// between groups of three digits.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::{region::Interval, Position};
+///
+/// let start = Position::try_from(1000)?;
+/// let end = Position::try_from(1499)?;
+///
+/// assert_eq!("1,000".parse(), Ok(Interval::from(start..)));
+/// assert_eq!("1,000-".parse(), Ok(Interval::from(start..)));
+/// assert_eq!("1,000-1,499".parse(), Ok(Interval::from(start..=end)));
+/// assert_eq!("1000+500".parse(), Ok(Interval::from(start..=end)));
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Interval {
     start: Option<Position>,
@@ -59,6 +80,94 @@ impl Interval {
         self.end
     }
 
+    /// Returns the number of positions in this interval.
+    ///
+    /// This returns `None` if the interval is not closed, i.e., either its start or end is
+    /// unbounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let interval = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// assert_eq!(interval.len(), Some(9));
+    ///
+    /// let interval = Interval::from(Position::try_from(5)?..);
+    /// assert!(interval.len().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> Option<usize> {
+        let (start, end) = self.start.zip(self.end)?;
+
+        Some(
+            usize::from(end)
+                .checked_sub(usize::from(start))
+                .map(|n| n + 1)
+                .unwrap_or_default(),
+        )
+    }
+
+    /// Returns whether this interval contains no positions.
+    ///
+    /// This is only the case when the start is greater than the end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let interval = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// assert!(!interval.is_empty());
+    ///
+    /// let interval = Interval::from(Position::try_from(13)?..=Position::try_from(5)?);
+    /// assert!(interval.is_empty());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => start > end,
+            _ => false,
+        }
+    }
+
+    /// Returns this interval as a closed interval bounded by the given sequence length.
+    ///
+    /// An unbounded start is resolved to [`Position::MIN`], and the end is resolved to the smaller
+    /// of the given length and the end of this interval. This returns `None` if the resulting
+    /// interval is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let interval = Interval::from(Position::try_from(5)?..);
+    /// assert_eq!(
+    ///     interval.clamp(13),
+    ///     Some(Interval::from(Position::try_from(5)?..=Position::try_from(13)?))
+    /// );
+    ///
+    /// let interval = Interval::from(..=Position::try_from(8)?);
+    /// assert_eq!(
+    ///     interval.clamp(13),
+    ///     Some(Interval::from(Position::MIN..=Position::try_from(8)?))
+    /// );
+    ///
+    /// let interval = Interval::from(Position::try_from(21)?..);
+    /// assert!(interval.clamp(13).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn clamp(&self, sequence_length: usize) -> Option<Self> {
+        let len = Position::new(sequence_length)?;
+
+        let start = self.start.unwrap_or(Position::MIN);
+        let end = self.end.map(|end| end.min(len)).unwrap_or(len);
+
+        let interval = Self::from(start..=end);
+        (!interval.is_empty()).then_some(interval)
+    }
+
     /// Returns whether the given position is in this interval.
     ///
     /// # Examples
@@ -142,12 +251,18 @@ pub enum ParseError {
     InvalidStartPosition(position::ParseError),
     /// The end position is invalid.
     InvalidEndPosition(position::ParseError),
+    /// The length is invalid.
+    InvalidLength(num::ParseIntError),
+    /// The end position computed from the start position and length is out of range.
+    LengthOutOfRange,
 }
 
 impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::InvalidStartPosition(e) | Self::InvalidEndPosition(e) => Some(e),
+            Self::InvalidLength(e) => Some(e),
+            Self::LengthOutOfRange => None,
         }
     }
 }
@@ -157,6 +272,8 @@ impl fmt::Display for ParseError {
         match self {
             Self::InvalidStartPosition(_) => f.write_str("invalid start position"),
             Self::InvalidEndPosition(_) => f.write_str("invalid end position"),
+            Self::InvalidLength(_) => f.write_str("invalid length"),
+            Self::LengthOutOfRange => f.write_str("length out of range"),
         }
     }
 }
@@ -169,28 +286,69 @@ impl FromStr for Interval {
             return Ok(Self::from(..));
         }
 
+        if let Some((raw_start, raw_len)) = s.split_once('+') {
+            let start = parse_position(raw_start).map_err(ParseError::InvalidStartPosition)?;
+
+            let len: NonZeroUsize = strip_thousands_separators(raw_len)
+                .parse()
+                .map_err(ParseError::InvalidLength)?;
+
+            let end = start
+                .checked_add(len.get() - 1)
+                .ok_or(ParseError::LengthOutOfRange)?;
+
+            return Ok(Self::from(start..=end));
+        }
+
         let mut components = s.splitn(2, '-');
 
         let start = match components.next() {
-            Some(t) => t
-                .parse()
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidStartPosition)?,
             None => None,
         };
 
         let end = match components.next() {
-            Some(t) => t
-                .parse()
+            Some("") | None => None,
+            Some(t) => parse_position(t)
                 .map(Some)
                 .map_err(ParseError::InvalidEndPosition)?,
-            None => None,
         };
 
         Ok(Self { start, end })
     }
 }
 
+fn parse_position(s: &str) -> Result<Position, position::ParseError> {
+    strip_thousands_separators(s).parse()
+}
+
+// Separators are only removed when they delimit groups of three digits, e.g., `1,000`. Otherwise,
+// the input is returned as is and fails to parse as a number.
+fn strip_thousands_separators(s: &str) -> Cow<'_, str> {
+    const SEPARATOR: char = ',';
+    const GROUP_LEN: usize = 3;
+
+    if !s.contains(SEPARATOR) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut groups = s.split(SEPARATOR);
+
+    let is_valid = groups
+        .next()
+        .map(|group| (1..=GROUP_LEN).contains(&group.len()))
+        .unwrap_or_default()
+        && groups.all(|group| group.len() == GROUP_LEN);
+
+    if is_valid {
+        Cow::Owned(s.chars().filter(|&c| c != SEPARATOR).collect())
+    } else {
+        Cow::Borrowed(s)
+    }
+}
+
 impl From<RangeFrom<Position>> for Interval {
     fn from(range: RangeFrom<Position>) -> Self {
         Self {
@@ -332,17 +490,63 @@ mod tests {
         assert_eq!("".parse(), Ok(Interval::from(..)));
         assert_eq!("8".parse(), Ok(Interval::from(start..)));
         assert_eq!("8-13".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("8-".parse(), Ok(Interval::from(start..)));
+        assert_eq!("8+6".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("8+1".parse(), Ok(Interval::from(start..=start)));
+
+        let start = Position::try_from(1000000)?;
+        let end = Position::try_from(2000000)?;
+        assert_eq!(
+            "1,000,000-2,000,000".parse(),
+            Ok(Interval::from(start..=end))
+        );
+        assert_eq!(
+            "1,000,000+1,000,001".parse(),
+            Ok(Interval::from(start..=end))
+        );
 
         assert!(matches!(
             "x".parse::<Interval>(),
             Err(ParseError::InvalidStartPosition(_))
         ));
 
+        for s in ["1,,000", ",5", "1,00", "1000,000", "1,000,"] {
+            assert!(matches!(
+                s.parse::<Interval>(),
+                Err(ParseError::InvalidStartPosition(_))
+            ));
+        }
+
+        assert!(matches!(
+            "1+1,0000".parse::<Interval>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+
         assert!(matches!(
             "1-x".parse::<Interval>(),
             Err(ParseError::InvalidEndPosition(_))
         ));
 
+        assert!(matches!(
+            "x+1".parse::<Interval>(),
+            Err(ParseError::InvalidStartPosition(_))
+        ));
+
+        assert!(matches!(
+            "1+x".parse::<Interval>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+
+        assert!(matches!(
+            "1+0".parse::<Interval>(),
+            Err(ParseError::InvalidLength(_))
+        ));
+
+        assert_eq!(
+            format!("2+{}", usize::MAX).parse::<Interval>(),
+            Err(ParseError::LengthOutOfRange)
+        );
+
         Ok(())
     }
 }
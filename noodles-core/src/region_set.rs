@@ -0,0 +1,354 @@
+//! Genomic region set.
+
+mod intervals;
+
+use bstr::{BStr, BString};
+
+use self::intervals::Intervals;
+use super::{region::Interval, Position, Region};
+
+/// A set of genomic regions.
+///
+/// A region set is a collection of disjoint intervals grouped by reference sequence name.
+/// Overlapping and adjacent intervals are merged when regions are inserted, so the set is always
+/// in a normalized form. Reference sequence names are kept in insertion order, and the intervals
+/// of each reference sequence are sorted by start position.
+///
+/// Unbounded interval starts and ends are treated as [`Position::MIN`] and [`Position::MAX`],
+/// respectively.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegionSet {
+    entries: Vec<(BString, Intervals)>,
+}
+
+impl RegionSet {
+    /// Returns whether the set has no regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::RegionSet;
+    /// let set = RegionSet::default();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|(_, intervals)| intervals.is_empty())
+    }
+
+    /// Returns the number of disjoint regions in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let set: RegionSet = [
+    ///     Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?),
+    ///     Region::new("sq0", Position::try_from(7)?..=Position::try_from(13)?),
+    ///     Region::new("sq1", ..),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn len(&self) -> usize {
+        self.entries
+            .iter()
+            .map(|(_, intervals)| intervals.len())
+            .sum()
+    }
+
+    /// Adds a region to the set.
+    ///
+    /// The region is merged with any overlapping or adjacent regions in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let mut set = RegionSet::default();
+    /// set.insert(Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?));
+    /// set.insert(Region::new("sq0", Position::try_from(9)?..=Position::try_from(13)?));
+    ///
+    /// let regions: Vec<_> = set.iter().collect();
+    /// assert_eq!(
+    ///     regions,
+    ///     [Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn insert(&mut self, region: Region) {
+        let (start, end) = resolve_interval(region.interval());
+
+        if start > end {
+            return;
+        }
+
+        let name = region.name();
+
+        match self.entries.iter_mut().find(|(n, _)| n == name) {
+            Some((_, intervals)) => intervals.insert(start, end),
+            None => {
+                let mut intervals = Intervals::default();
+                intervals.insert(start, end);
+                self.entries.push((name.into(), intervals));
+            }
+        }
+    }
+
+    /// Returns whether the given position on the given reference sequence is in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let set: RegionSet = [Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(set.contains(b"sq0", Position::try_from(5)?));
+    /// assert!(!set.contains(b"sq0", Position::try_from(13)?));
+    /// assert!(!set.contains(b"sq1", Position::try_from(5)?));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains<N>(&self, name: N, position: Position) -> bool
+    where
+        N: AsRef<[u8]>,
+    {
+        self.get(name.as_ref().into())
+            .map(|intervals| intervals.contains(position))
+            .unwrap_or_default()
+    }
+
+    /// Returns the union of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let a: RegionSet = [Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let b: RegionSet = [Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let regions: Vec<_> = a.union(&b).iter().collect();
+    /// assert_eq!(
+    ///     regions,
+    ///     [Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        let mut set = self.clone();
+        set.extend(other.iter());
+        set
+    }
+
+    /// Returns the intersection of this set and the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let a: RegionSet = [Region::new("sq0", Position::try_from(5)?..=Position::try_from(8)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let b: RegionSet = [Region::new("sq0", Position::try_from(8)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let regions: Vec<_> = a.intersection(&b).iter().collect();
+    /// assert_eq!(
+    ///     regions,
+    ///     [Region::new("sq0", Position::try_from(8)?..=Position::try_from(8)?)],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        let entries = self
+            .entries
+            .iter()
+            .filter_map(|(name, a)| {
+                let b = other.get(name.as_ref())?;
+                let intervals = a.intersection(b);
+                (!intervals.is_empty()).then(|| (name.clone(), intervals))
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns the difference of this set and the given set.
+    ///
+    /// The result contains the regions in this set that are not in the given set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region, RegionSet};
+    ///
+    /// let a: RegionSet = [Region::new("sq0", Position::try_from(5)?..=Position::try_from(13)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let b: RegionSet = [Region::new("sq0", Position::try_from(8)?..=Position::try_from(9)?)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let regions: Vec<_> = a.difference(&b).iter().collect();
+    /// assert_eq!(
+    ///     regions,
+    ///     [
+    ///         Region::new("sq0", Position::try_from(5)?..=Position::try_from(7)?),
+    ///         Region::new("sq0", Position::try_from(10)?..=Position::try_from(13)?),
+    ///     ],
+    /// );
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn difference(&self, other: &Self) -> Self {
+        let entries = self
+            .entries
+            .iter()
+            .filter_map(|(name, a)| {
+                let intervals = match other.get(name.as_ref()) {
+                    Some(b) => a.difference(b),
+                    None => a.clone(),
+                };
+
+                (!intervals.is_empty()).then(|| (name.clone(), intervals))
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Returns an iterator over the regions in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Region, RegionSet};
+    /// let set: RegionSet = [Region::new("sq0", ..)].into_iter().collect();
+    /// let regions: Vec<_> = set.iter().collect();
+    /// assert_eq!(regions, [Region::new("sq0", ..)]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = Region> + '_ {
+        self.entries.iter().flat_map(|(name, intervals)| {
+            intervals
+                .iter()
+                .map(move |(start, end)| Region::new(name.clone(), build_interval(start, end)))
+        })
+    }
+
+    fn get(&self, name: &BStr) -> Option<&Intervals> {
+        self.entries
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, intervals)| intervals)
+    }
+}
+
+impl Extend<Region> for RegionSet {
+    fn extend<T: IntoIterator<Item = Region>>(&mut self, iter: T) {
+        for region in iter {
+            self.insert(region);
+        }
+    }
+}
+
+impl FromIterator<Region> for RegionSet {
+    fn from_iter<T: IntoIterator<Item = Region>>(iter: T) -> Self {
+        let mut set = Self::default();
+        set.extend(iter);
+        set
+    }
+}
+
+fn resolve_interval(interval: Interval) -> (Position, Position) {
+    (
+        interval.start().unwrap_or(Position::MIN),
+        interval.end().unwrap_or(Position::MAX),
+    )
+}
+
+fn build_interval(start: Position, end: Position) -> Interval {
+    match (start, end) {
+        (Position::MIN, Position::MAX) => Interval::from(..),
+        (_, Position::MAX) => Interval::from(start..),
+        _ => Interval::from(start..=end),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_operations() -> Result<(), crate::position::TryFromIntError> {
+        fn region(name: &str, start: usize, end: usize) -> Region {
+            let start = Position::new(start).unwrap();
+            let end = Position::new(end).unwrap();
+            Region::new(name, start..=end)
+        }
+
+        let a: RegionSet = [
+            region("sq0", 5, 8),
+            region("sq0", 13, 21),
+            region("sq1", 1, 10),
+        ]
+        .into_iter()
+        .collect();
+
+        let b: RegionSet = [
+            region("sq0", 1, 5),
+            region("sq0", 21, 34),
+            region("sq2", 1, 3),
+        ]
+        .into_iter()
+        .collect();
+
+        let actual: Vec<_> = a.union(&b).iter().collect();
+        let expected = [
+            region("sq0", 1, 8),
+            region("sq0", 13, 34),
+            region("sq1", 1, 10),
+            region("sq2", 1, 3),
+        ];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = a.intersection(&b).iter().collect();
+        let expected = [region("sq0", 5, 5), region("sq0", 21, 21)];
+        assert_eq!(actual, expected);
+
+        let actual: Vec<_> = a.difference(&b).iter().collect();
+        let expected = [
+            region("sq0", 6, 8),
+            region("sq0", 13, 20),
+            region("sq1", 1, 10),
+        ];
+        assert_eq!(actual, expected);
+
+        let c: RegionSet = [Region::new("sq0", ..)].into_iter().collect();
+        let actual: Vec<_> = c.difference(&a).iter().collect();
+        let expected = [
+            region("sq0", 1, 4),
+            region("sq0", 9, 12),
+            Region::new("sq0", Position::try_from(22)?..),
+        ];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
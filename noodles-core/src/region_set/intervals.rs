@@ -0,0 +1,110 @@
+use crate::Position;
+
+/// A sorted list of disjoint, non-adjacent closed intervals.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(super) struct Intervals(Vec<(Position, Position)>);
+
+impl Intervals {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        self.0.iter().copied()
+    }
+
+    pub fn insert(&mut self, mut start: Position, mut end: Position) {
+        // The first interval that ends at or after the position before `start`.
+        let i = self
+            .0
+            .partition_point(|(_, e)| e.checked_add(1).map(|n| n < start).unwrap_or(false));
+
+        // The first interval that starts after the position after `end`.
+        let j = self.0.partition_point(|(s, _)| match end.checked_add(1) {
+            Some(n) => *s <= n,
+            None => true,
+        });
+
+        if i < j {
+            start = start.min(self.0[i].0);
+            end = end.max(self.0[j - 1].1);
+        }
+
+        self.0.splice(i..j, [(start, end)]);
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        let i = self.0.partition_point(|(_, e)| *e < position);
+        self.0.get(i).map(|(s, _)| *s <= position).unwrap_or(false)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.0.get(i), other.0.get(j))
+        {
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+
+            if start <= end {
+                intervals.push((start, end));
+            }
+
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self(intervals)
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let mut j = 0;
+
+        for &(a_start, a_end) in &self.0 {
+            let mut start = Some(a_start);
+
+            while let Some(&(b_start, b_end)) = other.0.get(j) {
+                if b_end < a_start {
+                    j += 1;
+                    continue;
+                }
+
+                if b_start > a_end {
+                    break;
+                }
+
+                if let Some(s) = start {
+                    if let Some(e) = b_start.checked_sub(1).filter(|e| s <= *e) {
+                        intervals.push((s, e));
+                    }
+                }
+
+                start = b_end.checked_add(1);
+
+                if b_end > a_end {
+                    break;
+                }
+
+                j += 1;
+            }
+
+            if let Some(s) = start {
+                if s <= a_end {
+                    intervals.push((s, a_end));
+                }
+            }
+        }
+
+        Self(intervals)
+    }
+}
@@ -0,0 +1,142 @@
+//! Strand.
+
+use std::{error, fmt, str::FromStr};
+
+/// A strand.
+///
+/// This is the strand shared across formats. Formats that can leave a strand unset typically wrap
+/// it in an `Option`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Strand {
+    /// Forward (sense or coding) strand (`+`).
+    Forward,
+    /// Reverse (antisense or complementary) strand (`-`).
+    Reverse,
+    /// Strandedness is relevant but unknown (`?`).
+    Unknown,
+    /// Both strands, i.e., unstranded (`.`).
+    Both,
+}
+
+impl Strand {
+    /// Returns the opposite strand.
+    ///
+    /// Unknown and both strands are their own opposites.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Strand;
+    /// assert_eq!(Strand::Forward.reverse(), Strand::Reverse);
+    /// assert_eq!(Strand::Reverse.reverse(), Strand::Forward);
+    /// assert_eq!(Strand::Both.reverse(), Strand::Both);
+    /// ```
+    pub const fn reverse(self) -> Self {
+        match self {
+            Self::Forward => Self::Reverse,
+            Self::Reverse => Self::Forward,
+            Self::Unknown => Self::Unknown,
+            Self::Both => Self::Both,
+        }
+    }
+}
+
+impl AsRef<str> for Strand {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Forward => "+",
+            Self::Reverse => "-",
+            Self::Unknown => "?",
+            Self::Both => ".",
+        }
+    }
+}
+
+impl fmt::Display for Strand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw strand fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The strand is invalid.
+    Invalid(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid(s) => write!(f, "expected {{+, -, ?, .}}, got {s}"),
+        }
+    }
+}
+
+impl FromStr for Strand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "+" => Ok(Self::Forward),
+            "-" => Ok(Self::Reverse),
+            "?" => Ok(Self::Unknown),
+            "." => Ok(Self::Both),
+            _ => Err(ParseError::Invalid(s.into())),
+        }
+    }
+}
+
+impl From<Strand> for char {
+    fn from(strand: Strand) -> Self {
+        match strand {
+            Strand::Forward => '+',
+            Strand::Reverse => '-',
+            Strand::Unknown => '?',
+            Strand::Both => '.',
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Strand::Forward.to_string(), "+");
+        assert_eq!(Strand::Reverse.to_string(), "-");
+        assert_eq!(Strand::Unknown.to_string(), "?");
+        assert_eq!(Strand::Both.to_string(), ".");
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), ParseError> {
+        assert_eq!("+".parse::<Strand>()?, Strand::Forward);
+        assert_eq!("-".parse::<Strand>()?, Strand::Reverse);
+        assert_eq!("?".parse::<Strand>()?, Strand::Unknown);
+        assert_eq!(".".parse::<Strand>()?, Strand::Both);
+
+        assert_eq!("".parse::<Strand>(), Err(ParseError::Empty));
+        assert_eq!(
+            "!".parse::<Strand>(),
+            Err(ParseError::Invalid(String::from("!")))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_strand_for_char() {
+        assert_eq!(char::from(Strand::Forward), '+');
+        assert_eq!(char::from(Strand::Reverse), '-');
+        assert_eq!(char::from(Strand::Unknown), '?');
+        assert_eq!(char::from(Strand::Both), '.');
+    }
+}
@@ -206,6 +206,13 @@ where
         read_data_container(&mut self.inner, &mut self.buf).await
     }
 
+    pub(crate) async fn read_data_container_with_container_header(
+        &mut self,
+    ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
+        use self::data_container::read_data_container_with_container_header;
+        read_data_container_with_container_header(&mut self.inner, &mut self.buf).await
+    }
+
     /// Returns an (async) stream over records starting from the current (input) stream position.
     ///
     /// The (input) stream position is expected to be at the start of a data container.
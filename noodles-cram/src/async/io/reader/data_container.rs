@@ -6,13 +6,28 @@ use tokio::io::{self, AsyncRead, AsyncReadExt};
 use self::header::read_header;
 use crate::{
     data_container::DataContainer,
-    io::reader::data_container::{read_compression_header_from_block, read_slice},
+    io::reader::{
+        data_container::{read_compression_header_from_block, read_slice},
+        truncation_error::{map_unexpected_eof, TruncationError},
+    },
 };
 
 pub async fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
 ) -> io::Result<Option<DataContainer>>
+where
+    R: AsyncRead + Unpin,
+{
+    read_data_container_with_container_header(reader, buf)
+        .await
+        .map(|result| result.map(|(_, data_container)| data_container))
+}
+
+pub async fn read_data_container_with_container_header<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+) -> io::Result<Option<(crate::data_container::Header, DataContainer)>>
 where
     R: AsyncRead + Unpin,
 {
@@ -22,7 +37,10 @@ where
     };
 
     buf.resize(header.len(), 0);
-    reader.read_exact(buf).await?;
+    reader
+        .read_exact(buf)
+        .await
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))?;
     let mut buf = buf.split().freeze();
 
     let compression_header = read_compression_header_from_block(&mut buf)?;
@@ -35,5 +53,7 @@ where
         slices.push(slice);
     }
 
-    Ok(Some(DataContainer::new(compression_header, slices)))
+    let data_container = DataContainer::new(compression_header, slices);
+
+    Ok(Some((header, data_container)))
 }
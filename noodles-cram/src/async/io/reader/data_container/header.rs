@@ -12,13 +12,37 @@ pub async fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
 where
     R: AsyncRead + Unpin,
 {
-    use crate::io::reader::data_container::header::{build_reference_sequence_context, is_eof};
+    use crate::io::reader::truncation_error::{map_unexpected_eof, TruncationError};
+
+    read_header_inner(reader)
+        .await
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))
+}
+
+async fn read_header_inner<R>(reader: &mut R) -> io::Result<Option<Header>>
+where
+    R: AsyncRead + Unpin,
+{
+    use crate::io::reader::{
+        data_container::header::{build_reference_sequence_context, is_eof},
+        truncation_error::{map_unexpected_eof, TruncationError},
+    };
 
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32_le().await.and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let length = crc_reader
+        .read_i32_le()
+        .await
+        .map_err(|e| {
+            if crc_reader.crc().amount() == 0 {
+                map_unexpected_eof(e, TruncationError::MissingEofContainer)
+            } else {
+                e
+            }
+        })
+        .and_then(|n| {
+            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
 
     let reference_sequence_id = read_itf8(&mut crc_reader).await?;
     let alignment_start = read_itf8(&mut crc_reader).await?;
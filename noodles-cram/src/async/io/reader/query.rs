@@ -1,4 +1,4 @@
-use std::{io::SeekFrom, slice, vec};
+use std::{io::SeekFrom, iter::Peekable, slice, vec};
 
 use futures::{stream, Stream};
 use noodles_core::region::Interval;
@@ -6,14 +6,18 @@ use noodles_sam as sam;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{crai, Record};
+use crate::{
+    crai,
+    io::reader::query::{intersects, next_slice_landmarks, read_slice_records},
+    Record,
+};
 
 struct Context<'r, 'h: 'r, 'i: 'r, R> {
     reader: &'r mut Reader<R>,
 
     header: &'h sam::Header,
 
-    index: slice::Iter<'i, crai::Record>,
+    index: Peekable<slice::Iter<'i, crai::Record>>,
 
     reference_sequence_id: usize,
     interval: Interval,
@@ -36,7 +40,7 @@ where
 
         header,
 
-        index: index.iter(),
+        index: index.iter().peekable(),
 
         reference_sequence_id,
         interval,
@@ -48,12 +52,8 @@ where
         loop {
             match ctx.records.next() {
                 Some(r) => {
-                    if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
-                        let alignment_interval = (start..=end).into();
-
-                        if ctx.interval.intersects(alignment_interval) {
-                            return Ok(Some((r, ctx)));
-                        }
+                    if intersects(&r, ctx.reference_sequence_id, ctx.interval) {
+                        return Ok(Some((r, ctx)));
                     }
                 }
                 None => match read_next_container(&mut ctx).await {
@@ -70,55 +70,107 @@ async fn read_next_container<R>(ctx: &mut Context<'_, '_, '_, R>) -> Option<io::
 where
     R: AsyncRead + AsyncSeek + Unpin,
 {
-    let index_record = ctx.index.next()?;
+    let (offset, landmarks) =
+        next_slice_landmarks(&mut ctx.index, ctx.reference_sequence_id, ctx.interval)?;
 
-    if index_record.reference_sequence_id() != Some(ctx.reference_sequence_id) {
-        return Some(Ok(()));
+    if let Err(e) = ctx.reader.seek(SeekFrom::Start(offset)).await {
+        return Some(Err(e));
     }
 
-    if let Err(e) = ctx
-        .reader
-        .seek(SeekFrom::Start(index_record.offset()))
-        .await
-    {
-        return Some(Err(e));
+    let (container_header, data_container) =
+        match ctx.reader.read_data_container_with_container_header().await {
+            Ok(Some(c)) => c,
+            Ok(None) => return None,
+            Err(e) => return Some(Err(e)),
+        };
+
+    let records = read_slice_records(
+        ctx.reader.reference_sequence_repository(),
+        ctx.header,
+        &container_header,
+        &data_container,
+        &landmarks,
+    );
+
+    match records {
+        Ok(records) => {
+            ctx.records = records.into_iter();
+            Some(Ok(()))
+        }
+        Err(e) => Some(Err(e)),
     }
+}
 
-    let container = match ctx.reader.read_data_container().await {
-        Ok(Some(c)) => c,
-        Ok(None) => return None,
-        Err(e) => return Some(Err(e)),
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use futures::TryStreamExt;
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            io::Write as _,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
     };
 
-    let records = container
-        .slices()
-        .iter()
-        .map(|slice| {
-            let compression_header = container.compression_header();
-
-            slice.records(compression_header).and_then(|mut records| {
-                slice.resolve_records(
-                    ctx.reader.reference_sequence_repository(),
-                    ctx.header,
-                    compression_header,
-                    &mut records,
-                )?;
-
-                Ok(records)
-            })
-        })
-        .collect::<Result<Vec<_>, _>>();
-
-    let records = match records {
-        Ok(records) => records,
-        Err(e) => return Some(Err(e)),
-    };
+    use super::*;
+    use crate::io::writer;
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let mut writer = writer::Builder::default()
+            .no_ref(true)
+            .build_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in [1, 8] {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(i)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let index = writer
+            .index()
+            .map(|index| index.to_vec())
+            .unwrap_or_default();
 
-    ctx.records = records
-        .into_iter()
-        .flatten()
-        .collect::<Vec<_>>()
-        .into_iter();
+        let mut reader = crate::r#async::io::Reader::new(Cursor::new(writer.get_ref()));
+        reader.read_header().await?;
 
-    Some(Ok(()))
+        let region = "sq0:6-13".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .map_ok(|record| record.alignment_start())
+            .try_collect()
+            .await?;
+
+        assert_eq!(actual, [Position::new(8)]);
+
+        Ok(())
+    }
 }
@@ -118,7 +118,8 @@ where
     /// The position of the stream is expected to be directly after the file definition.
     ///
     /// Entries in the reference sequence dictionary that are missing MD5 checksums (`M5`) will
-    /// automatically be calculated and added to the written record.
+    /// automatically be calculated and added to the written record, unless sequences are encoded
+    /// without a reference.
     ///
     /// # Examples
     ///
@@ -141,14 +142,21 @@ where
     /// ```
     pub async fn write_file_header(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::header_container::write_header_container;
-        use crate::io::writer::add_missing_reference_sequence_checksums;
+        use crate::io::writer::{
+            add_missing_reference_sequence_checksums,
+            header_container::validate_reference_sequences,
+        };
 
         let mut header = header.clone();
 
-        add_missing_reference_sequence_checksums(
-            &self.reference_sequence_repository,
-            header.reference_sequences_mut(),
-        )?;
+        if !self.options.no_ref {
+            add_missing_reference_sequence_checksums(
+                &self.reference_sequence_repository,
+                header.reference_sequences_mut(),
+            )?;
+
+            validate_reference_sequences(header.reference_sequences())?;
+        }
 
         write_header_container(&mut self.inner, &header).await
     }
@@ -211,7 +219,10 @@ where
         use crate::data_container::builder::AddRecordError;
 
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::Path};
 
 use noodles_fasta as fasta;
 use tokio::{
@@ -8,7 +8,9 @@ use tokio::{
 
 use super::Writer;
 use crate::{
-    data_container::BlockContentEncoderMap, file_definition::Version, io::writer::Options,
+    data_container::BlockContentEncoderMap,
+    file_definition::Version,
+    io::writer::{Options, Profile},
     DataContainer,
 };
 
@@ -39,6 +41,17 @@ impl Builder {
         self
     }
 
+    /// Sets whether to encode sequences without a reference.
+    ///
+    /// If `true`, the reference required flag (`RR`) is unset, and slices and the reference
+    /// sequence dictionary are written without reference MD5 checksums.
+    ///
+    /// The default is `false`.
+    pub fn no_ref(mut self, value: bool) -> Self {
+        self.options.no_ref = value;
+        self
+    }
+
     /// Sets whether to encode alignment start positions as deltas.
     ///
     /// If `false`, record alignment start positions are written with their actual values.
@@ -55,6 +68,34 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// The default is 10240.
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// Slices in the same container share a compression header.
+    ///
+    /// The default is 1.
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets the encoding profile.
+    ///
+    /// This sets the number of records per slice and the block content-encoder map. Either can be
+    /// overridden by setting it after the profile.
+    pub fn set_profile(mut self, profile: Profile) -> Self {
+        self.options.records_per_slice = profile.records_per_slice();
+        self.options.block_content_encoder_map = profile.block_content_encoder_map();
+        self
+    }
+
     /// Builds an async CRAM writer from a path.
     ///
     /// # Examples
@@ -14,6 +14,7 @@ pub struct BlockContentEncoderMap {
     core_data_encoder: Option<Encoder>,
     data_series_encoders: Vec<Option<Encoder>>,
     tag_values_encoders: HashMap<block::ContentId, Option<Encoder>>,
+    default_tag_values_encoder: Option<Encoder>,
 }
 
 impl BlockContentEncoderMap {
@@ -49,6 +50,10 @@ impl BlockContentEncoderMap {
         &self.tag_values_encoders
     }
 
+    pub(crate) fn default_tag_values_encoder(&self) -> Option<&Encoder> {
+        self.default_tag_values_encoder.as_ref()
+    }
+
     pub(crate) fn get_tag_values_encoders(
         &self,
         block_content_id: block::ContentId,
@@ -6,8 +6,6 @@ use noodles_sam as sam;
 use super::{slice, CompressionHeader, DataContainer, Slice};
 use crate::{io::writer::Options, Record};
 
-const MAX_SLICE_COUNT: usize = 1;
-
 #[derive(Debug)]
 pub struct Builder {
     slice_builder: slice::Builder,
@@ -42,12 +40,15 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container.get() {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        match self
+            .slice_builder
+            .add_record(options.records_per_slice.get(), record)
+        {
             Ok(r) => {
                 self.base_count += u64::try_from(r.read_length())
                     .map_err(AddRecordError::InvalidRecordReadLength)?;
@@ -85,17 +86,20 @@ impl Builder {
 
         let compression_header = build_compression_header(&options, &self.slice_builders);
 
-        let record_counter = self.record_counter;
+        let mut record_counter = self.record_counter;
         let slices = self
             .slice_builders
             .into_iter()
             .map(|builder| {
+                let slice_record_counter = record_counter;
+                record_counter += builder.records().len() as u64;
+
                 builder.build(
                     &options.block_content_encoder_map,
                     reference_sequence_repository,
                     header,
                     &compression_header,
-                    record_counter,
+                    slice_record_counter,
                 )
             })
             .collect::<Result<_, _>>()?;
@@ -21,7 +21,6 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
-const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -48,8 +47,12 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
-        if self.records.len() >= MAX_RECORD_COUNT {
+    pub fn add_record(
+        &mut self,
+        max_record_count: usize,
+        record: Record,
+    ) -> Result<&Record, AddRecordError> {
+        if self.records.len() >= max_record_count {
             return Err(AddRecordError::SliceFull(record));
         }
 
@@ -99,8 +102,12 @@ impl Builder {
             block_content_ids.push(block.content_id());
         }
 
+        let is_reference_required = compression_header
+            .preservation_map()
+            .is_reference_required();
+
         let reference_md5 = match self.reference_sequence_context {
-            ReferenceSequenceContext::Some(context) => {
+            ReferenceSequenceContext::Some(context) if is_reference_required => {
                 let reference_sequence_name = header
                     .reference_sequences()
                     .get_index(context.reference_sequence_id())
@@ -227,7 +234,11 @@ fn write_records(
             {
                 set_block_data(builder, buf, encoder)?
             } else {
-                set_block_data(builder, buf, Some(&Encoder::Gzip(Default::default())))?
+                set_block_data(
+                    builder,
+                    buf,
+                    block_content_encoder_map.default_tag_values_encoder(),
+                )?
             };
 
             Ok(builder.build())
@@ -0,0 +1,83 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use crate::io::writer::container::EOF;
+
+/// Returns whether the stream ends with a CRAM end-of-file (EOF) container.
+///
+/// A missing EOF container typically indicates a truncated file. The position of the stream is
+/// restored after checking.
+///
+/// This checks for the EOF container defined by CRAM 3.0 and 3.1.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Cursor};
+/// use noodles_cram as cram;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let mut writer = cram::io::Writer::new(Vec::new());
+/// writer.write_header(&header)?;
+/// writer.try_finish(&header)?;
+///
+/// let data = writer.get_ref();
+/// assert!(cram::is_eof_present(&mut Cursor::new(data))?);
+///
+/// let mut reader = Cursor::new(&data[..data.len() - 1]);
+/// assert!(!cram::is_eof_present(&mut reader)?);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn is_eof_present<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let pos = reader.stream_position()?;
+    let result = read_eof_present(reader);
+    reader.seek(SeekFrom::Start(pos))?;
+    result
+}
+
+fn read_eof_present<R>(reader: &mut R) -> io::Result<bool>
+where
+    R: Read + Seek,
+{
+    let eof_len = EOF.len() as u64;
+    let len = reader.seek(SeekFrom::End(0))?;
+
+    if len < eof_len {
+        return Ok(false);
+    }
+
+    reader.seek(SeekFrom::Start(len - eof_len))?;
+
+    let mut buf = [0; 38];
+    reader.read_exact(&mut buf)?;
+
+    Ok(buf == EOF)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_is_eof_present() -> io::Result<()> {
+        let mut reader = Cursor::new(Vec::new());
+        assert!(!is_eof_present(&mut reader)?);
+
+        let mut reader = Cursor::new(EOF);
+        reader.set_position(8);
+        assert!(is_eof_present(&mut reader)?);
+        assert_eq!(reader.position(), 8);
+
+        let mut data = EOF.to_vec();
+        data.push(0x00);
+        let mut reader = Cursor::new(data);
+        assert!(!is_eof_present(&mut reader)?);
+
+        Ok(())
+    }
+}
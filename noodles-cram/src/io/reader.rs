@@ -5,11 +5,19 @@ pub(crate) mod container;
 pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
-mod query;
+pub(crate) mod query;
+pub mod raw_container;
 pub(crate) mod record;
 mod records;
-
-pub use self::{builder::Builder, query::Query, records::Records};
+pub(crate) mod truncation_error;
+
+pub use self::{
+    builder::Builder,
+    query::Query,
+    raw_container::{RawContainer, RawContainers},
+    records::Records,
+    truncation_error::TruncationError,
+};
 
 use std::io::{self, Read, Seek, SeekFrom};
 
@@ -278,6 +286,57 @@ where
         self.inner.stream_position()
     }
 
+    /// Reads a raw data container.
+    ///
+    /// The stream is expected to be at the start of a data container. Unlike
+    /// [`Self::read_data_container`], the blocks of the container are not decoded.
+    ///
+    /// This returns `None` when the end-of-file (EOF) container is read.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// while let Some(container) = reader.read_raw_container()? {
+    ///     println!("{:?}", container.range());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_raw_container(&mut self) -> io::Result<Option<RawContainer>> {
+        use self::raw_container::read_raw_container;
+
+        let offset = self.position()?;
+        read_raw_container(&mut self.inner, offset)
+    }
+
+    /// Returns an iterator over raw data containers starting from the current stream position.
+    ///
+    /// The stream is expected to be at the start of a data container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.raw_containers() {
+    ///     let container = result?;
+    ///     println!("{:?}", container.range());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn raw_containers(&mut self) -> RawContainers<'_, R> {
+        RawContainers::new(self)
+    }
+
     /// Returns an iterator over records that intersects the given region.
     ///
     /// # Examples
@@ -432,4 +491,54 @@ mod tests {
             Err(ref e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
+
+    #[test]
+    fn test_read_data_container_with_truncated_input() -> io::Result<()> {
+        use noodles_sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::io::Writer;
+
+        fn truncation_error(e: &io::Error) -> Option<TruncationError> {
+            assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+
+            e.get_ref()
+                .and_then(|inner| inner.downcast_ref::<TruncationError>())
+                .copied()
+        }
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder().set_name("r0").build();
+        writer.write_alignment_record(&header, &record)?;
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        // The EOF container is missing.
+        let src = &data[..data.len() - 38];
+        let mut reader = Reader::new(src);
+        reader.read_header()?;
+        assert!(reader.read_data_container()?.is_some());
+
+        assert!(matches!(
+            reader.read_data_container(),
+            Err(e) if truncation_error(&e) == Some(TruncationError::MissingEofContainer)
+        ));
+
+        // The data container is incomplete.
+        let src = &data[..data.len() - 48];
+        let mut reader = Reader::new(src);
+        reader.read_header()?;
+
+        assert!(matches!(
+            reader.read_data_container(),
+            Err(e) if truncation_error(&e) == Some(TruncationError::IncompleteContainer)
+        ));
+
+        Ok(())
+    }
 }
@@ -9,6 +9,7 @@ use std::io::{self, Read};
 use bytes::{Bytes, BytesMut};
 
 use self::header::read_header;
+use super::truncation_error::{map_unexpected_eof, TruncationError};
 use crate::{container::block::ContentType, data_container::CompressionHeader, DataContainer};
 
 pub fn read_data_container<R>(
@@ -23,7 +24,9 @@ where
     };
 
     buf.resize(header.len(), 0);
-    reader.read_exact(buf)?;
+    reader
+        .read_exact(buf)
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))?;
     let mut buf = buf.split().freeze();
 
     let compression_header = read_compression_header_from_block(&mut buf)?;
@@ -51,7 +54,9 @@ where
     };
 
     buf.resize(header.len(), 0);
-    reader.read_exact(buf)?;
+    reader
+        .read_exact(buf)
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))?;
     let mut buf = buf.split().freeze();
 
     let compression_header = read_compression_header_from_block(&mut buf)?;
@@ -9,7 +9,10 @@ use noodles_core::Position;
 
 use crate::{
     data_container::{Header, ReferenceSequenceContext},
-    io::reader::num::{read_itf8, read_ltf8},
+    io::reader::{
+        num::{read_itf8, read_ltf8},
+        truncation_error::{map_unexpected_eof, TruncationError},
+    },
 };
 
 // § 9 "End of file container" (2022-04-12)
@@ -20,14 +23,32 @@ const EOF_BLOCK_COUNT: usize = 1;
 const EOF_CRC32: u32 = 0x4f_d9_bd_05;
 
 pub fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+where
+    R: Read,
+{
+    read_header_inner(reader)
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))
+}
+
+fn read_header_inner<R>(reader: &mut R) -> io::Result<Option<Header>>
 where
     R: Read,
 {
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    // A stream that ends at a container boundary is missing the EOF container.
+    let length = crc_reader
+        .read_i32::<LittleEndian>()
+        .map_err(|e| {
+            if crc_reader.crc().amount() == 0 {
+                map_unexpected_eof(e, TruncationError::MissingEofContainer)
+            } else {
+                e
+            }
+        })
+        .and_then(|n| {
+            usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })?;
 
     let reference_sequence_id = read_itf8(&mut crc_reader)?;
     let alignment_start = read_itf8(&mut crc_reader)?;
@@ -246,4 +267,41 @@ mod tests {
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
+
+    #[test]
+    fn test_read_header_with_truncated_input() {
+        fn truncation_error(result: io::Result<Option<Header>>) -> Option<TruncationError> {
+            match result {
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => e
+                    .get_ref()
+                    .and_then(|inner| inner.downcast_ref::<TruncationError>())
+                    .copied(),
+                _ => None,
+            }
+        }
+
+        let data = [];
+        let mut reader = &data[..];
+        assert_eq!(
+            truncation_error(read_header(&mut reader)),
+            Some(TruncationError::MissingEofContainer)
+        );
+
+        let data = [0x90, 0x00];
+        let mut reader = &data[..];
+        assert_eq!(
+            truncation_error(read_header(&mut reader)),
+            Some(TruncationError::IncompleteContainer)
+        );
+
+        let data = [
+            0x90, 0x00, 0x00, 0x00, // length = 144 bytes
+            0x02, // reference sequence ID = 2
+        ];
+        let mut reader = &data[..];
+        assert_eq!(
+            truncation_error(read_header(&mut reader)),
+            Some(TruncationError::IncompleteContainer)
+        );
+    }
 }
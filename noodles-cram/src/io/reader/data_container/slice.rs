@@ -23,7 +23,7 @@ pub fn read_slice(src: &mut Bytes) -> io::Result<Slice> {
     Ok(Slice::new(header, core_data_block, external_blocks))
 }
 
-fn read_header_from_block(src: &mut Bytes) -> io::Result<slice::Header> {
+pub(crate) fn read_header_from_block(src: &mut Bytes) -> io::Result<slice::Header> {
     let block = read_block(src)?;
 
     if block.content_type() != ContentType::SliceHeader {
@@ -1,13 +1,15 @@
 use std::{
     io::{self, Read, Seek, SeekFrom},
+    iter::Peekable,
     slice, vec,
 };
 
 use noodles_core::region::Interval;
+use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use super::Reader;
-use crate::{crai, Record};
+use crate::{crai, data_container, DataContainer, Record};
 
 /// An iterator over records that intersect a given region.
 ///
@@ -20,7 +22,7 @@ where
 
     header: &'a sam::Header,
 
-    index: slice::Iter<'a, crai::Record>,
+    index: Peekable<slice::Iter<'a, crai::Record>>,
 
     reference_sequence_id: usize,
     interval: Interval,
@@ -44,7 +46,7 @@ where
 
             header,
 
-            index: index.iter(),
+            index: index.iter().peekable(),
 
             reference_sequence_id,
             interval,
@@ -54,53 +56,35 @@ where
     }
 
     fn read_next_container(&mut self) -> Option<io::Result<()>> {
-        let index_record = self.index.next()?;
+        let (offset, landmarks) =
+            next_slice_landmarks(&mut self.index, self.reference_sequence_id, self.interval)?;
 
-        if index_record.reference_sequence_id() != Some(self.reference_sequence_id) {
-            return Some(Ok(()));
-        }
-
-        if let Err(e) = self.reader.seek(SeekFrom::Start(index_record.offset())) {
+        if let Err(e) = self.reader.seek(SeekFrom::Start(offset)) {
             return Some(Err(e));
         }
 
-        let container = match self.reader.read_data_container() {
-            Ok(Some(c)) => c,
-            Ok(None) => return None,
-            Err(e) => return Some(Err(e)),
-        };
-
-        let records = container
-            .slices()
-            .iter()
-            .map(|slice| {
-                let compression_header = container.compression_header();
-
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
-            })
-            .collect::<Result<Vec<_>, _>>();
-
-        let records = match records {
-            Ok(records) => records,
-            Err(e) => return Some(Err(e)),
-        };
+        let (container_header, data_container) =
+            match self.reader.read_data_container_with_container_header() {
+                Ok(Some(c)) => c,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            };
 
-        self.records = records
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
+        let records = read_slice_records(
+            self.reader.reference_sequence_repository(),
+            self.header,
+            &container_header,
+            &data_container,
+            &landmarks,
+        );
 
-        Some(Ok(()))
+        match records {
+            Ok(records) => {
+                self.records = records.into_iter();
+                Some(Ok(()))
+            }
+            Err(e) => Some(Err(e)),
+        }
     }
 }
 
@@ -114,12 +98,8 @@ where
         loop {
             match self.records.next() {
                 Some(r) => {
-                    if let (Some(start), Some(end)) = (r.alignment_start(), r.alignment_end()) {
-                        let alignment_interval = (start..=end).into();
-
-                        if self.interval.intersects(alignment_interval) {
-                            return Some(Ok(r));
-                        }
+                    if intersects(&r, self.reference_sequence_id, self.interval) {
+                        return Some(Ok(r));
                     }
                 }
                 None => match self.read_next_container() {
@@ -131,3 +111,205 @@ where
         }
     }
 }
+
+// Returns the offset of the next container with slices that intersect the given region and the
+// landmarks of those slices.
+//
+// Consecutive index records of the same container are grouped so that the container is only read
+// once.
+pub(crate) fn next_slice_landmarks(
+    index: &mut Peekable<slice::Iter<'_, crai::Record>>,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> Option<(u64, Vec<u64>)> {
+    let is_match = |record: &crai::Record| {
+        record.reference_sequence_id() == Some(reference_sequence_id)
+            && index_record_intersects(record, interval)
+    };
+
+    let record = index.find(|record| is_match(record))?;
+    let offset = record.offset();
+    let mut landmarks = vec![record.landmark()];
+
+    while let Some(record) = index.next_if(|record| record.offset() == offset) {
+        if is_match(record) && !landmarks.contains(&record.landmark()) {
+            landmarks.push(record.landmark());
+        }
+    }
+
+    Some((offset, landmarks))
+}
+
+fn index_record_intersects(record: &crai::Record, interval: Interval) -> bool {
+    let Some(start) = record.alignment_start() else {
+        return false;
+    };
+
+    let end = start
+        .checked_add(record.alignment_span().saturating_sub(1))
+        .unwrap_or(start);
+
+    interval.intersects((start..=end).into())
+}
+
+// Decodes and resolves the records of the slices at the given landmarks.
+pub(crate) fn read_slice_records(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    container_header: &data_container::Header,
+    data_container: &DataContainer,
+    landmarks: &[u64],
+) -> io::Result<Vec<Record>> {
+    let compression_header = data_container.compression_header();
+    let mut records = Vec::new();
+
+    for (slice, &landmark) in data_container
+        .slices()
+        .iter()
+        .zip(container_header.landmarks())
+    {
+        if !landmarks.contains(&(landmark as u64)) {
+            continue;
+        }
+
+        let mut slice_records = slice.records(compression_header)?;
+
+        slice.resolve_records(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            &mut slice_records,
+        )?;
+
+        records.extend(slice_records);
+    }
+
+    Ok(records)
+}
+
+pub(crate) fn intersects(
+    record: &Record,
+    reference_sequence_id: usize,
+    interval: Interval,
+) -> bool {
+    if record.reference_sequence_id() != Some(reference_sequence_id) {
+        return false;
+    }
+
+    match (record.alignment_start(), record.alignment_end()) {
+        (Some(start), Some(end)) => interval.intersects((start..=end).into()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            io::Write as _,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+    use crate::io::writer;
+
+    #[test]
+    fn test_next_slice_landmarks() -> Result<(), noodles_core::position::TryFromIntError> {
+        let index = [
+            crai::Record::new(Some(0), Position::new(1), 10, 100, 10, 50),
+            crai::Record::new(Some(0), Position::new(11), 10, 100, 60, 50),
+            crai::Record::new(Some(1), Position::new(1), 10, 100, 60, 50),
+            crai::Record::new(Some(0), Position::new(21), 10, 200, 10, 50),
+            crai::Record::new(Some(0), Position::new(31), 10, 300, 10, 50),
+        ];
+
+        let mut iter = index.iter().peekable();
+        let interval = (Position::try_from(5)?..=Position::try_from(25)?).into();
+
+        assert_eq!(
+            next_slice_landmarks(&mut iter, 0, interval),
+            Some((100, vec![10, 60]))
+        );
+        assert_eq!(
+            next_slice_landmarks(&mut iter, 0, interval),
+            Some((200, vec![10]))
+        );
+        assert!(next_slice_landmarks(&mut iter, 0, interval).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        const RECORD_COUNT: usize = 25000;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(RECORD_COUNT + 3)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let build_record = |reference_sequence_id, alignment_start| {
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_alignment_start(alignment_start)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                .build()
+        };
+
+        let mut writer = writer::Builder::default()
+            .no_ref(true)
+            .build_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in 1..=RECORD_COUNT {
+            writer.write_alignment_record(&header, &build_record(0, Position::try_from(i)?))?;
+        }
+
+        writer.write_alignment_record(&header, &build_record(1, Position::MIN))?;
+        writer.try_finish(&header)?;
+
+        let index = writer
+            .index()
+            .map(|index| index.to_vec())
+            .unwrap_or_default();
+
+        let mut reader = Reader::new(Cursor::new(writer.get_ref()));
+        reader.read_header()?;
+
+        let region = "sq0:12000-12010".parse()?;
+        let actual: Vec<_> = reader
+            .query(&header, &index, &region)?
+            .map(|result| result.map(|record| record.alignment_start()))
+            .collect::<io::Result<_>>()?;
+
+        let expected: Vec<_> = (11997..=12010).map(Position::new).collect();
+        assert_eq!(actual, expected);
+
+        let region = "sq1".parse()?;
+        let actual = reader.query(&header, &index, &region)?.count();
+        assert_eq!(actual, 1);
+
+        Ok(())
+    }
+}
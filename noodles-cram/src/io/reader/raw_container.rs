@@ -0,0 +1,370 @@
+//! Raw CRAM container.
+
+use std::{
+    io::{self, Read, Seek},
+    ops::Range,
+};
+
+use bytes::Bytes;
+use noodles_core::Position;
+
+use super::Reader;
+use crate::{
+    data_container::{self, slice, ReferenceSequenceContext},
+    DataContainer,
+};
+
+/// A raw CRAM data container.
+///
+/// This holds the undecoded bytes of a container, including its header, together with its
+/// location in the input stream and the parsed container and slice headers. It is read without
+/// decoding any records, which allows containers to be copied as is (e.g., to split or
+/// concatenate CRAM files) or dispatched to workers to be decoded in parallel.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RawContainer {
+    offset: u64,
+    buf: Bytes,
+    header_len: usize,
+    header: data_container::Header,
+    slice_headers: Vec<SliceHeader>,
+}
+
+impl RawContainer {
+    /// Returns the position of the start of the container in the input stream.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the byte range of the container in the input stream.
+    ///
+    /// This includes the container header.
+    pub fn range(&self) -> Range<u64> {
+        self.offset..self.offset + self.buf.len() as u64
+    }
+
+    /// Returns the raw bytes of the container, including the container header.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the number of records in the container.
+    pub fn record_count(&self) -> usize {
+        self.slice_headers
+            .iter()
+            .map(|slice_header| slice_header.record_count())
+            .sum()
+    }
+
+    /// Returns the 0-based index of the first record in the container.
+    pub fn record_counter(&self) -> u64 {
+        self.header.record_counter()
+    }
+
+    /// Returns the number of read bases in the container.
+    pub fn base_count(&self) -> u64 {
+        self.header.base_count()
+    }
+
+    /// Returns the reference sequence ID of the records in the container.
+    ///
+    /// This is `None` if the records are unmapped or are mapped to multiple reference sequences.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        reference_sequence_id(self.header.reference_sequence_context())
+    }
+
+    /// Returns the alignment start of the records in the container.
+    pub fn alignment_start(&self) -> Option<Position> {
+        alignment_start(self.header.reference_sequence_context())
+    }
+
+    /// Returns the alignment end of the records in the container.
+    pub fn alignment_end(&self) -> Option<Position> {
+        alignment_end(self.header.reference_sequence_context())
+    }
+
+    /// Returns whether the records in the container are mapped to multiple reference sequences.
+    pub fn is_multi_reference(&self) -> bool {
+        self.header.reference_sequence_context().is_many()
+    }
+
+    /// Returns the headers of the slices in the container.
+    pub fn slice_headers(&self) -> &[SliceHeader] {
+        &self.slice_headers
+    }
+
+    /// Decodes the blocks of the container.
+    ///
+    /// The records of each slice can then be read using [`crate::data_container::Slice::records`].
+    pub fn decode(&self) -> io::Result<DataContainer> {
+        use super::data_container::{read_compression_header_from_block, read_slice};
+
+        let mut buf = self.buf.slice(self.header_len..);
+
+        let compression_header = read_compression_header_from_block(&mut buf)?;
+
+        let slices = (0..self.slice_headers.len())
+            .map(|_| read_slice(&mut buf))
+            .collect::<io::Result<_>>()?;
+
+        Ok(DataContainer::new(compression_header, slices))
+    }
+
+    pub(crate) fn header(&self) -> &data_container::Header {
+        &self.header
+    }
+}
+
+/// A raw CRAM slice header.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SliceHeader {
+    landmark: usize,
+    inner: slice::Header,
+}
+
+impl SliceHeader {
+    /// Returns the position of the slice relative to the end of the container header.
+    pub fn landmark(&self) -> usize {
+        self.landmark
+    }
+
+    /// Returns the number of records in the slice.
+    pub fn record_count(&self) -> usize {
+        self.inner.record_count()
+    }
+
+    /// Returns the 0-based index of the first record in the slice.
+    pub fn record_counter(&self) -> u64 {
+        self.inner.record_counter()
+    }
+
+    /// Returns the reference sequence ID of the records in the slice.
+    ///
+    /// This is `None` if the records are unmapped or are mapped to multiple reference sequences.
+    pub fn reference_sequence_id(&self) -> Option<usize> {
+        reference_sequence_id(self.inner.reference_sequence_context())
+    }
+
+    /// Returns the alignment start of the records in the slice.
+    pub fn alignment_start(&self) -> Option<Position> {
+        alignment_start(self.inner.reference_sequence_context())
+    }
+
+    /// Returns the alignment end of the records in the slice.
+    pub fn alignment_end(&self) -> Option<Position> {
+        alignment_end(self.inner.reference_sequence_context())
+    }
+
+    /// Returns whether the records in the slice are mapped to multiple reference sequences.
+    pub fn is_multi_reference(&self) -> bool {
+        self.inner.reference_sequence_context().is_many()
+    }
+}
+
+/// An iterator over raw containers.
+///
+/// This is created by calling [`Reader::raw_containers`].
+pub struct RawContainers<'a, R> {
+    reader: &'a mut Reader<R>,
+}
+
+impl<'a, R> RawContainers<'a, R>
+where
+    R: Read + Seek,
+{
+    pub(super) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self { reader }
+    }
+}
+
+impl<'a, R> Iterator for RawContainers<'a, R>
+where
+    R: Read + Seek,
+{
+    type Item = io::Result<RawContainer>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_raw_container().transpose()
+    }
+}
+
+// A reader that copies all bytes read from the inner reader into a buffer.
+struct RecordingReader<'a, R> {
+    inner: &'a mut R,
+    buf: &'a mut Vec<u8>,
+}
+
+impl<'a, R> Read for RecordingReader<'a, R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.buf.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+pub(super) fn read_raw_container<R>(reader: &mut R, offset: u64) -> io::Result<Option<RawContainer>>
+where
+    R: Read,
+{
+    use super::{
+        data_container::{header::read_header, slice::read_header_from_block},
+        truncation_error::{map_unexpected_eof, TruncationError},
+    };
+
+    let mut buf = Vec::new();
+
+    let header = {
+        let mut recording_reader = RecordingReader {
+            inner: reader,
+            buf: &mut buf,
+        };
+
+        match read_header(&mut recording_reader)? {
+            Some(header) => header,
+            None => return Ok(None),
+        }
+    };
+
+    let header_len = buf.len();
+    buf.resize(header_len + header.len(), 0);
+    reader
+        .read_exact(&mut buf[header_len..])
+        .map_err(|e| map_unexpected_eof(e, TruncationError::IncompleteContainer))?;
+
+    let buf = Bytes::from(buf);
+    let body = buf.slice(header_len..);
+
+    let slice_headers = header
+        .landmarks()
+        .iter()
+        .map(|&landmark| {
+            if landmark > body.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid landmark",
+                ));
+            }
+
+            let mut src = body.slice(landmark..);
+
+            read_header_from_block(&mut src).map(|inner| SliceHeader { landmark, inner })
+        })
+        .collect::<io::Result<_>>()?;
+
+    Ok(Some(RawContainer {
+        offset,
+        buf,
+        header_len,
+        header,
+        slice_headers,
+    }))
+}
+
+fn reference_sequence_id(context: ReferenceSequenceContext) -> Option<usize> {
+    match context {
+        ReferenceSequenceContext::Some(context) => Some(context.reference_sequence_id()),
+        _ => None,
+    }
+}
+
+fn alignment_start(context: ReferenceSequenceContext) -> Option<Position> {
+    match context {
+        ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
+        _ => None,
+    }
+}
+
+fn alignment_end(context: ReferenceSequenceContext) -> Option<Position> {
+    match context {
+        ReferenceSequenceContext::Some(context) => Some(context.alignment_end()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::{io::Write as _, RecordBuf},
+    };
+
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_read_raw_container() -> io::Result<()> {
+        const RECORD_COUNT: usize = 25000;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..RECORD_COUNT {
+            let record = RecordBuf::builder().set_name(format!("r{i}")).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let src = writer.get_ref();
+        let mut reader = Reader::new(Cursor::new(src));
+        reader.read_header()?;
+
+        let mut offset = reader.position()?;
+        let containers: Vec<_> = reader.raw_containers().collect::<io::Result<_>>()?;
+
+        assert_eq!(containers.len(), 3);
+
+        for container in &containers {
+            let range = container.range();
+            assert_eq!(range.start, offset);
+            assert_eq!(
+                container.as_bytes(),
+                &src[range.start as usize..range.end as usize]
+            );
+            offset = range.end;
+
+            assert!(container.reference_sequence_id().is_none());
+            assert!(!container.is_multi_reference());
+
+            let slice_headers = container.slice_headers();
+            assert_eq!(slice_headers.len(), 1);
+            assert_eq!(slice_headers[0].record_count(), container.record_count());
+            assert_eq!(
+                slice_headers[0].record_counter(),
+                container.record_counter()
+            );
+
+            let data_container = container.decode()?;
+            let records =
+                data_container.slices()[0].records(data_container.compression_header())?;
+            assert_eq!(records.len(), container.record_count());
+        }
+
+        assert_eq!(
+            containers
+                .iter()
+                .map(|container| container.record_counter())
+                .collect::<Vec<_>>(),
+            [0, 10240, 20480]
+        );
+
+        assert_eq!(
+            containers
+                .iter()
+                .map(|container| container.record_count())
+                .sum::<usize>(),
+            RECORD_COUNT
+        );
+
+        // The last data container is followed by the EOF container (38 bytes).
+        assert_eq!(offset + 38, src.len() as u64);
+
+        Ok(())
+    }
+}
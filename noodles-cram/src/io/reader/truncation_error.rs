@@ -0,0 +1,84 @@
+use std::{error, fmt, io};
+
+/// An error returned when a CRAM stream is truncated.
+///
+/// This is the inner error of an [`io::Error`] with the kind [`io::ErrorKind::UnexpectedEof`].
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_cram::io::reader::TruncationError;
+///
+/// fn is_truncated(e: &io::Error) -> bool {
+///     e.get_ref().is_some_and(|inner| inner.is::<TruncationError>())
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TruncationError {
+    /// The stream ended before the end-of-file (EOF) container.
+    MissingEofContainer,
+    /// The stream ended in the middle of a container.
+    IncompleteContainer,
+}
+
+impl error::Error for TruncationError {}
+
+impl fmt::Display for TruncationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingEofContainer => write!(f, "missing EOF container"),
+            Self::IncompleteContainer => write!(f, "incomplete container"),
+        }
+    }
+}
+
+// Converts an unexpected EOF error to a truncation error.
+//
+// Other errors, including those that are already truncation errors, are returned as is.
+pub(crate) fn map_unexpected_eof(e: io::Error, error: TruncationError) -> io::Error {
+    if e.kind() != io::ErrorKind::UnexpectedEof {
+        return e;
+    }
+
+    if e.get_ref()
+        .is_some_and(|inner| inner.is::<TruncationError>())
+    {
+        return e;
+    }
+
+    io::Error::new(io::ErrorKind::UnexpectedEof, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_unexpected_eof() {
+        let e = map_unexpected_eof(
+            io::Error::from(io::ErrorKind::UnexpectedEof),
+            TruncationError::MissingEofContainer,
+        );
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(matches!(
+            e.get_ref()
+                .and_then(|inner| inner.downcast_ref::<TruncationError>()),
+            Some(TruncationError::MissingEofContainer)
+        ));
+
+        let e = map_unexpected_eof(e, TruncationError::IncompleteContainer);
+        assert!(matches!(
+            e.get_ref()
+                .and_then(|inner| inner.downcast_ref::<TruncationError>()),
+            Some(TruncationError::MissingEofContainer)
+        ));
+
+        let e = map_unexpected_eof(
+            io::Error::from(io::ErrorKind::InvalidData),
+            TruncationError::IncompleteContainer,
+        );
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert!(e.get_ref().is_none());
+    }
+}
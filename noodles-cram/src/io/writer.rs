@@ -6,20 +6,30 @@ pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
+mod profile;
+mod quality_score_bins;
 pub(crate) mod record;
 
-pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+pub use self::{builder::Builder, profile::Profile, quality_score_bins::QualityScoreBins};
 
 use std::{
+    fs::File,
     io::{self, Write},
     mem,
+    num::NonZeroUsize,
+    panic,
+    path::PathBuf,
+    thread,
 };
 
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, header::ReferenceSequences};
 
-use crate::{file_definition::Version, DataContainer, FileDefinition, Record, MAGIC_NUMBER};
+use crate::{
+    crai, file_definition::Version, io::reader::RawContainer, DataContainer, FileDefinition,
+    Record, MAGIC_NUMBER,
+};
 
 /// A CRAM writer.
 ///
@@ -51,8 +61,13 @@ where
     inner: W,
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: NonZeroUsize,
     data_container_builder: crate::data_container::Builder,
+    pending_data_container_builders: Vec<crate::data_container::Builder>,
     record_counter: u64,
+    position: u64,
+    index: Option<crai::Index>,
+    index_dst: Option<PathBuf>,
 }
 
 impl<W> Writer<W>
@@ -84,6 +99,26 @@ where
         &self.inner
     }
 
+    /// Returns the index built while writing, if enabled.
+    ///
+    /// The index only includes containers that have been written. It is complete after
+    /// [`Self::try_finish`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    ///
+    /// let writer = Builder::default().build_index(true).build_from_writer(Vec::new());
+    /// assert_eq!(writer.index(), Some(&[][..]));
+    ///
+    /// let writer = Builder::default().build_from_writer(Vec::new());
+    /// assert!(writer.index().is_none());
+    /// ```
+    pub fn index(&self) -> Option<&[crai::Record]> {
+        self.index.as_deref()
+    }
+
     /// Attempts to finish the output stream by writing any pending containers and a final EOF
     /// container.
     ///
@@ -104,8 +139,17 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
+
         self.flush(header)?;
-        write_eof_container(&mut self.inner)
+        write_eof_container(&mut self.inner)?;
+
+        if let (Some(index), Some(dst)) = (&self.index, self.index_dst.take()) {
+            let mut writer = File::create(dst).map(crai::Writer::new)?;
+            writer.write_index(index)?;
+            writer.finish()?;
+        }
+
+        Ok(())
     }
 
     /// Writes a CRAM file definition.
@@ -134,7 +178,10 @@ where
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
         let file_definition = FileDefinition::new(self.options.version, Default::default());
-        write_file_definition(&mut self.inner, &file_definition)
+
+        let mut buf = Vec::new();
+        write_file_definition(&mut buf, &file_definition)?;
+        self.write_all(&buf)
     }
 
     /// Writes a CRAM file header container.
@@ -142,7 +189,8 @@ where
     /// The position of the stream is expected to be directly after the file definition.
     ///
     /// Entries in the reference sequence dictionary that are missing MD5 checksums (`M5`) will
-    /// automatically be calculated and added to the written record.
+    /// automatically be calculated and added to the written record, unless sequences are encoded
+    /// without a reference.
     ///
     /// # Examples
     ///
@@ -161,16 +209,22 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_header(&mut self, header: &sam::Header) -> io::Result<()> {
-        use self::header_container::write_header_container;
+        use self::header_container::{validate_reference_sequences, write_header_container};
 
         let mut header = header.clone();
 
-        add_missing_reference_sequence_checksums(
-            &self.reference_sequence_repository,
-            header.reference_sequences_mut(),
-        )?;
+        if !self.options.no_ref {
+            add_missing_reference_sequence_checksums(
+                &self.reference_sequence_repository,
+                header.reference_sequences_mut(),
+            )?;
+
+            validate_reference_sequences(header.reference_sequences())?;
+        }
 
-        write_header_container(&mut self.inner, &header)
+        let mut buf = Vec::new();
+        write_header_container(&mut buf, &header)?;
+        self.write_all(&buf)
     }
 
     /// Writes a SAM header.
@@ -217,8 +271,17 @@ where
     pub fn write_record(&mut self, header: &sam::Header, mut record: Record) -> io::Result<()> {
         use crate::data_container::builder::AddRecordError;
 
+        if self.options.drop_quality_scores_for_perfect_matches && is_perfect_match(&record) {
+            drop_quality_scores(&mut record);
+        } else if let Some(quality_score_bins) = &self.options.quality_score_bins {
+            quality_score_bins.apply(&mut record);
+        }
+
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
@@ -226,7 +289,11 @@ where
                 Err(e) => match e {
                     AddRecordError::ContainerFull(r) => {
                         record = r;
-                        self.flush(header)?;
+                        self.take_data_container_builder();
+
+                        if self.pending_data_container_builders.len() >= self.worker_count.get() {
+                            self.write_pending_data_containers(header)?;
+                        }
                     }
                     AddRecordError::SliceFull(r) => {
                         record = r;
@@ -237,11 +304,69 @@ where
         }
     }
 
+    /// Writes a raw data container.
+    ///
+    /// Any pending records are first written in their own containers. The raw container is then
+    /// copied as is, e.g., to concatenate CRAM files. The container, including its record
+    /// counter, is not modified, so the input must use the same header and CRAM version as the
+    /// output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut writer = cram::io::Writer::new(Vec::new());
+    /// writer.write_header(&header)?;
+    ///
+    /// for result in reader.raw_containers() {
+    ///     let container = result?;
+    ///     writer.write_raw_container(&header, &container)?;
+    /// }
+    ///
+    /// writer.try_finish(&header)?;
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_raw_container(
+        &mut self,
+        header: &sam::Header,
+        container: &RawContainer,
+    ) -> io::Result<()> {
+        use crate::indexer::push_data_container_index_records;
+
+        self.flush(header)?;
+
+        if let Some(index) = self.index.as_mut() {
+            let data_container = container.decode()?;
+
+            push_data_container_index_records(
+                index,
+                self.position,
+                container.header(),
+                &data_container,
+            )?;
+        }
+
+        self.write_all(container.as_bytes())?;
+
+        self.record_counter += container.record_count() as u64;
+        self.data_container_builder = DataContainer::builder(self.record_counter);
+
+        Ok(())
+    }
+
     fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
-        use self::data_container::write_data_container;
+        self.take_data_container_builder();
+        self.write_pending_data_containers(header)
+    }
 
+    fn take_data_container_builder(&mut self) {
         if self.data_container_builder.is_empty() {
-            return Ok(());
+            return;
         }
 
         let data_container_builder = mem::replace(
@@ -249,15 +374,46 @@ where
             DataContainer::builder(self.record_counter),
         );
 
-        let base_count = data_container_builder.base_count();
+        self.pending_data_container_builders
+            .push(data_container_builder);
+    }
+
+    fn write_pending_data_containers(&mut self, header: &sam::Header) -> io::Result<()> {
+        use self::data_container::write_data_container;
+        use crate::indexer::push_data_container_index_records;
+
+        let data_container_builders = mem::take(&mut self.pending_data_container_builders);
 
-        let data_container = data_container_builder.build(
+        let data_containers = build_data_containers(
+            data_container_builders,
             &self.options,
             &self.reference_sequence_repository,
             header,
         )?;
 
-        write_data_container(&mut self.inner, &data_container, base_count)
+        for (data_container, base_count) in data_containers {
+            let mut buf = Vec::new();
+            let container_header = write_data_container(&mut buf, &data_container, base_count)?;
+
+            if let Some(index) = self.index.as_mut() {
+                push_data_container_index_records(
+                    index,
+                    self.position,
+                    &container_header,
+                    &data_container,
+                )?;
+            }
+
+            self.write_all(&buf)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+        self.position += buf.len() as u64;
+        Ok(())
     }
 }
 
@@ -283,6 +439,105 @@ where
     }
 }
 
+// Builds and compresses each data container on its own thread when there is more than one. The
+// data containers are returned in the input order.
+fn build_data_containers(
+    data_container_builders: Vec<crate::data_container::Builder>,
+    options: &Options,
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+) -> io::Result<Vec<(DataContainer, u64)>> {
+    fn build(
+        data_container_builder: crate::data_container::Builder,
+        options: &Options,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+    ) -> io::Result<(DataContainer, u64)> {
+        let base_count = data_container_builder.base_count();
+        let data_container =
+            data_container_builder.build(options, reference_sequence_repository, header)?;
+        Ok((data_container, base_count))
+    }
+
+    if data_container_builders.len() <= 1 {
+        return data_container_builders
+            .into_iter()
+            .map(|builder| build(builder, options, reference_sequence_repository, header))
+            .collect();
+    }
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = data_container_builders
+            .into_iter()
+            .map(|builder| {
+                scope.spawn(move || build(builder, options, reference_sequence_repository, header))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+// A record is a perfect match if it is mapped, has no indels or clips, and has an edit distance
+// (`NM`) of 0.
+fn is_perfect_match(record: &Record) -> bool {
+    use sam::alignment::record::data::field::Tag;
+
+    use crate::record::Feature;
+
+    if record.bam_flags().is_unmapped() {
+        return false;
+    }
+
+    let has_only_matches = record.features().iter().all(|feature| {
+        matches!(
+            feature,
+            Feature::Bases(..)
+                | Feature::ReadBase(..)
+                | Feature::Scores(..)
+                | Feature::QualityScore(..)
+        )
+    });
+
+    let edit_distance = record
+        .tags()
+        .get(&Tag::EDIT_DISTANCE)
+        .and_then(|value| value.as_int());
+
+    has_only_matches && edit_distance == Some(0)
+}
+
+// Quality scores are written as an array of missing scores (`0xff`), which is read as an empty
+// list of quality scores.
+fn drop_quality_scores(record: &mut Record) {
+    use sam::alignment::record_buf::QualityScores;
+
+    use crate::record::{Feature, Flags};
+
+    const MISSING: u8 = 0xff;
+
+    record.features.retain_mut(|feature| match feature {
+        Feature::Scores(..) | Feature::QualityScore(..) => false,
+        Feature::ReadBase(position, base, _) => {
+            *feature = Feature::Bases(*position, vec![*base]);
+            true
+        }
+        _ => true,
+    });
+
+    record
+        .cram_bit_flags
+        .insert(Flags::QUALITY_SCORES_STORED_AS_ARRAY);
+    record.quality_scores = QualityScores::from(vec![MISSING; record.read_length]);
+}
+
 fn write_file_definition<W>(writer: &mut W, file_definition: &FileDefinition) -> io::Result<()>
 where
     W: Write,
@@ -398,4 +653,418 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_worker_count() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::io::Reader;
+
+        fn write(header: &sam::Header, worker_count: NonZeroUsize) -> io::Result<Vec<u8>> {
+            let mut writer = Builder::default()
+                .set_worker_count(worker_count)
+                .build_from_writer(Vec::new());
+
+            writer.write_header(header)?;
+
+            for i in 0..25000 {
+                let record = RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_sequence(b"ACGT".to_vec().into())
+                    .build();
+
+                writer.write_alignment_record(header, &record)?;
+            }
+
+            writer.try_finish(header)?;
+
+            Ok(writer.get_ref().clone())
+        }
+
+        let header = sam::Header::default();
+
+        let src = write(&header, NonZeroUsize::try_from(2)?)?;
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let mut n = 0;
+
+        for result in reader.records(&header) {
+            let record = result?;
+            assert_eq!(record.name(), Some(BString::from(format!("r{n}")).as_ref()));
+            n += 1;
+        }
+
+        assert_eq!(n, 25000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_slices_per_container() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Cursor, num::NonZeroUsize};
+
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::try_from(5000)?)
+            .set_slices_per_container(NonZeroUsize::try_from(2)?)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in 0..25000 {
+            let record = RecordBuf::builder().set_name(format!("r{i}")).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.get_ref()));
+        reader.read_header()?;
+
+        let containers: Vec<_> = reader.raw_containers().collect::<io::Result<_>>()?;
+
+        let actual: Vec<Vec<_>> = containers
+            .iter()
+            .map(|container| {
+                container
+                    .slice_headers()
+                    .iter()
+                    .map(|slice_header| {
+                        (slice_header.record_counter(), slice_header.record_count())
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let expected = [
+            vec![(0, 5000), (5000, 5000)],
+            vec![(10000, 5000), (15000, 5000)],
+            vec![(20000, 5000)],
+        ];
+
+        assert_eq!(actual, expected);
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+
+        for (i, result) in reader.records(&header).enumerate() {
+            let record = result?;
+            let expected = format!("r{i}");
+            assert_eq!(record.name(), Some(expected.as_bytes().into()));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_profile() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write as _,
+            record::data::field::Tag,
+            record_buf::{data::field::Value, QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let records: Vec<_> = (0..3)
+            .map(|i| {
+                RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_sequence(Sequence::from(b"ACGT".to_vec()))
+                    .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                    .set_data(
+                        [(Tag::COMMENT, Value::from(format!("c{i}")))]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .build()
+            })
+            .collect();
+
+        for profile in [
+            Profile::Fast,
+            Profile::Normal,
+            Profile::Small,
+            Profile::Archive,
+        ] {
+            let mut writer = Builder::default()
+                .set_profile(profile)
+                .build_from_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            for record in &records {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            let mut reader = Reader::new(writer.get_ref().as_slice());
+            reader.read_header()?;
+
+            let actual: Vec<_> = reader
+                .records(&header)
+                .map(|result| {
+                    result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(actual, records, "{profile:?}");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::{indexer::push_data_container_index_records, io::Reader};
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .build_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in 0..25000 {
+            let record = RecordBuf::builder().set_name(format!("r{i}")).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let actual = writer.index().map(|index| index.to_vec());
+
+        let mut reader = Reader::new(Cursor::new(writer.get_ref()));
+        reader.read_header()?;
+
+        let mut expected = crai::Index::new();
+        let mut container_position = reader.position()?;
+
+        while let Some((container_header, data_container)) =
+            reader.read_data_container_with_container_header()?
+        {
+            push_data_container_index_records(
+                &mut expected,
+                container_position,
+                &container_header,
+                &data_container,
+            )?;
+
+            container_position = reader.position()?;
+        }
+
+        assert_eq!(expected.len(), 3);
+        assert_eq!(actual, Some(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_raw_container() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        use sam::alignment::{io::Write as _, RecordBuf};
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for i in 0..3 {
+            let record = RecordBuf::builder().set_name(format!("r{i}")).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(Cursor::new(writer.get_ref()));
+        reader.read_header()?;
+        let containers: Vec<_> = reader.raw_containers().collect::<io::Result<_>>()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder().set_name("r3").build();
+        writer.write_alignment_record(&header, &record)?;
+
+        for container in &containers {
+            writer.write_raw_container(&header, container)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(writer.get_ref().as_slice());
+        reader.read_header()?;
+
+        let names = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        let expected: Vec<_> = ["r3", "r0", "r1", "r2"]
+            .into_iter()
+            .map(|name| Some(name.as_bytes().to_vec()))
+            .collect();
+
+        assert_eq!(names, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_no_ref() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::{cigar::op::Kind, Flags},
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::Reader;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(noodles_core::Position::try_from(3)?)
+            .set_cigar(
+                [sam::alignment::record::cigar::Op::new(Kind::Match, 4)]
+                    .into_iter()
+                    .collect::<Cigar>(),
+            )
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .build();
+
+        // The reference sequence repository is empty.
+        let mut writer = Builder::default()
+            .no_ref(true)
+            .build_from_writer(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        let actual_header = reader.read_header()?;
+        assert_eq!(actual_header, header);
+
+        let records = reader
+            .records(&header)
+            .map(|result| result.and_then(|r| r.try_into_alignment_record(&header)))
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(records, [record]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_quality_score_transforms() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write as _,
+                record::{
+                    cigar::{op::Kind, Op},
+                    data::field::Tag,
+                    Flags,
+                },
+                record_buf::{data::field::Value, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::Reader;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let build_record = |edit_distance: i32| -> Result<RecordBuf, Box<dyn std::error::Error>> {
+            Ok(RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT".to_vec()))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 41]))
+                .set_data(
+                    [(Tag::EDIT_DISTANCE, Value::from(edit_distance))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build())
+        };
+
+        let records = [build_record(0)?, build_record(1)?];
+
+        let mut writer = Builder::default()
+            .no_ref(true)
+            .set_quality_score_bins(QualityScoreBins::illumina_8())
+            .drop_quality_scores_for_perfect_matches(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| result.map(|record| record.quality_scores().clone()))
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            QualityScores::default(),
+            QualityScores::from(vec![6, 15, 22, 40]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
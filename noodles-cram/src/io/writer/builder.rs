@@ -1,22 +1,25 @@
 use std::{
+    ffi::OsString,
     fs::File,
     io::{self, Write},
-    path::Path,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
 };
 
 use noodles_fasta as fasta;
 
-use super::{Options, Writer};
+use super::{Options, Profile, QualityScoreBins, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder, crai, data_container::BlockContentEncoderMap, file_definition::Version,
     DataContainer,
 };
 
 /// A CRAM writer builder.
-#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    worker_count: NonZeroUsize,
+    build_index: bool,
 }
 
 impl Builder {
@@ -57,6 +60,26 @@ impl Builder {
         self
     }
 
+    /// Sets whether to encode sequences without a reference.
+    ///
+    /// If `true`, the reference required flag (`RR`) is unset, and slices and the reference
+    /// sequence dictionary are written without reference MD5 checksums. A reference sequence
+    /// repository is not needed to write or read the output. This is useful when no canonical
+    /// reference exists, e.g., for unaligned data.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().no_ref(true);
+    /// ```
+    pub fn no_ref(mut self, value: bool) -> Self {
+        self.options.no_ref = value;
+        self
+    }
+
     /// Sets whether to encode alignment start positions as deltas.
     ///
     /// If `false`, record alignment start positions are written with their actual values.
@@ -91,6 +114,135 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// The default is 10240.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_records_per_slice(NonZeroUsize::try_from(25000)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// Slices in the same container share a compression header.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_slices_per_container(NonZeroUsize::try_from(4)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets the encoding profile.
+    ///
+    /// This sets the number of records per slice and the block content-encoder map. Either can be
+    /// overridden by setting it after the profile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::{Builder, Profile};
+    /// let builder = Builder::default().set_profile(Profile::Archive);
+    /// ```
+    pub fn set_profile(mut self, profile: Profile) -> Self {
+        self.options.records_per_slice = profile.records_per_slice();
+        self.options.block_content_encoder_map = profile.block_content_encoder_map();
+        self
+    }
+
+    /// Sets the quality score binning table.
+    ///
+    /// Quality scores are binned when records are written. This is lossy. By default, quality
+    /// scores are not binned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::{Builder, QualityScoreBins};
+    /// let builder = Builder::default().set_quality_score_bins(QualityScoreBins::illumina_8());
+    /// ```
+    pub fn set_quality_score_bins(mut self, quality_score_bins: QualityScoreBins) -> Self {
+        self.options.quality_score_bins = Some(quality_score_bins);
+        self
+    }
+
+    /// Sets whether to drop quality scores of records that perfectly match the reference.
+    ///
+    /// A record is considered a perfect match if it is mapped, its alignment has no indels or
+    /// clips, and its edit distance (`NM`) is 0. Such records are written with missing quality
+    /// scores. This is lossy.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().drop_quality_scores_for_perfect_matches(true);
+    /// ```
+    pub fn drop_quality_scores_for_perfect_matches(mut self, value: bool) -> Self {
+        self.options.drop_quality_scores_for_perfect_matches = value;
+        self
+    }
+
+    /// Sets the worker count.
+    ///
+    /// When greater than 1, up to this many full data containers are built, i.e., their slices
+    /// are encoded and their blocks compressed, in parallel. Data containers are still written in
+    /// order.
+    ///
+    /// The default is 1, i.e., data containers are built on the calling thread.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::try_from(8)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = worker_count;
+        self
+    }
+
+    /// Sets whether to build a CRAM index (CRAI) while writing.
+    ///
+    /// This records the position of each slice as containers are written, avoiding a second pass
+    /// over the output to index it. The index is available from [`Writer::index`], and when the
+    /// writer is built from a path, it is also written to `<dst>.crai` when the writer is
+    /// finished.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().build_index(true);
+    /// ```
+    pub fn build_index(mut self, value: bool) -> Self {
+        self.build_index = value;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -104,7 +256,14 @@ impl Builder {
     where
         P: AsRef<Path>,
     {
-        File::create(dst).map(|file| self.build_from_writer(file))
+        let dst = dst.as_ref();
+
+        let index_dst = self.build_index.then(|| build_index_dst(dst));
+
+        let mut writer = File::create(dst).map(|file| self.build_from_writer(file))?;
+        writer.index_dst = index_dst;
+
+        Ok(writer)
     }
 
     /// Builds a CRAM writer from a path.
@@ -136,8 +295,13 @@ impl Builder {
             inner: writer,
             reference_sequence_repository: self.reference_sequence_repository,
             options: self.options,
+            worker_count: self.worker_count,
             data_container_builder: DataContainer::builder(0),
+            pending_data_container_builders: Vec::new(),
             record_counter: 0,
+            position: 0,
+            index: self.build_index.then(crai::Index::new),
+            index_dst: None,
         }
     }
 
@@ -151,6 +315,26 @@ impl Builder {
     }
 }
 
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            options: Options::default(),
+            worker_count: NonZeroUsize::MIN,
+            build_index: false,
+        }
+    }
+}
+
+fn build_index_dst<P>(dst: P) -> PathBuf
+where
+    P: AsRef<Path>,
+{
+    let mut s = OsString::from(dst.as_ref());
+    s.push(".crai");
+    PathBuf::from(s)
+}
+
 pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap) -> bool {
     fn is_cram_3_1_codec(encoder: &Encoder) -> bool {
         matches!(
@@ -170,6 +354,7 @@ pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap)
         .iter()
         .chain(block_content_encoder_map.tag_values_encoders().values())
         .flatten()
+        .chain(block_content_encoder_map.default_tag_values_encoder())
         .any(is_cram_3_1_codec)
 }
 
@@ -177,6 +362,11 @@ pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap)
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_build_index_dst() {
+        assert_eq!(build_index_dst("out.cram"), PathBuf::from("out.cram.crai"));
+    }
+
     #[test]
     fn test_uses_cram_3_1_codecs() {
         use crate::codecs::rans_nx16::Flags;
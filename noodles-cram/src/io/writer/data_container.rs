@@ -17,7 +17,7 @@ pub fn write_data_container<W>(
     writer: &mut W,
     data_container: &DataContainer,
     base_count: u64,
-) -> io::Result<()>
+) -> io::Result<Header>
 where
     W: Write,
 {
@@ -31,7 +31,7 @@ where
         write_block(writer, &block)?;
     }
 
-    Ok(())
+    Ok(header)
 }
 
 fn build_container(
@@ -49,6 +49,9 @@ fn build_container(
         .set_data(buf.into())
         .build();
 
+    // Landmarks are slice offsets relative to the start of the container body, i.e., they include
+    // the compression header block.
+    let mut landmark = block.len();
     let mut blocks = vec![block];
     let mut landmarks = Vec::new();
 
@@ -67,6 +70,8 @@ fn build_container(
 
         container_record_count += slice_header.record_count() as i32;
 
+        landmarks.push(landmark);
+
         let mut slice_len = 0;
 
         let mut slice_header_buf = Vec::new();
@@ -89,9 +94,7 @@ fn build_container(
             slice_len += external_block.len();
         }
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_len;
-        landmarks.push(landmark);
+        landmark += slice_len;
     }
 
     let len = blocks.iter().map(|b| b.len()).sum();
@@ -162,3 +165,36 @@ fn build_container_reference_sequence_context(
 
     Ok(container_reference_sequence_context)
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_fasta as fasta;
+    use noodles_sam as sam;
+
+    use super::*;
+    use crate::{io::writer::Options, Record};
+
+    #[test]
+    fn test_build_container() -> io::Result<()> {
+        let options = Options::default();
+
+        let mut builder = DataContainer::builder(0);
+        builder
+            .add_record(&options, Record::default())
+            .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
+
+        let data_container = builder.build(
+            &options,
+            &fasta::Repository::default(),
+            &sam::Header::default(),
+        )?;
+
+        let (header, blocks) = build_container(&data_container, 0)?;
+
+        // The first slice starts directly after the compression header block.
+        assert_eq!(header.landmarks(), [blocks[0].len()]);
+        assert_eq!(header.len(), blocks.iter().map(|b| b.len()).sum::<usize>());
+
+        Ok(())
+    }
+}
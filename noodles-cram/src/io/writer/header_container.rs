@@ -19,8 +19,6 @@ where
 {
     const ENCODER: Encoder = Encoder::Gzip(Compression::new(6));
 
-    validate_reference_sequences(header.reference_sequences())?;
-
     let header_data = serialize_header(header)?;
     let header_data_len = i32::try_from(header_data.len())
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
@@ -40,7 +38,7 @@ where
     Ok(())
 }
 
-fn validate_reference_sequences(
+pub(crate) fn validate_reference_sequences(
     reference_sequences: &sam::header::ReferenceSequences,
 ) -> io::Result<()> {
     use sam::header::record::value::map::reference_sequence::tag;
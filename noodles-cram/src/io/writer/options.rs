@@ -1,20 +1,40 @@
+use std::num::NonZeroUsize;
+
+use super::QualityScoreBins;
 use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
 
+pub(crate) const DEFAULT_RECORDS_PER_SLICE: NonZeroUsize = match NonZeroUsize::new(10240) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+pub(crate) const DEFAULT_SLICES_PER_CONTAINER: NonZeroUsize = NonZeroUsize::MIN;
+
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
+    pub no_ref: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
+    pub quality_score_bins: Option<QualityScoreBins>,
+    pub drop_quality_scores_for_perfect_matches: bool,
+    pub records_per_slice: NonZeroUsize,
+    pub slices_per_container: NonZeroUsize,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             preserve_read_names: true,
+            no_ref: false,
             encode_alignment_start_positions_as_deltas: true,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
+            quality_score_bins: None,
+            drop_quality_scores_for_perfect_matches: false,
+            records_per_slice: DEFAULT_RECORDS_PER_SLICE,
+            slices_per_container: DEFAULT_SLICES_PER_CONTAINER,
         }
     }
 }
@@ -0,0 +1,93 @@
+use std::num::NonZeroUsize;
+
+use crate::{
+    codecs::{rans_4x8, Encoder},
+    data_container::{
+        compression_header::data_series_encoding_map::{
+            data_series::STANDARD_DATA_SERIES, DataSeries,
+        },
+        BlockContentEncoderMap,
+    },
+};
+
+/// A CRAM encoding profile.
+///
+/// An encoding profile is a preset of the number of records per slice and block compression
+/// methods that trades off write speed for output size, similar to the profiles used by
+/// samtools. All profiles only use CRAM 3.0 codecs.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Profile {
+    /// Fast compression.
+    ///
+    /// This uses 10000 records per slice and gzip with the fastest compression level.
+    Fast,
+    /// Normal compression.
+    ///
+    /// This uses the writer defaults: 10240 records per slice and gzip with the default
+    /// compression level.
+    #[default]
+    Normal,
+    /// Small output.
+    ///
+    /// This uses 25000 records per slice, gzip with the best compression level, bzip2 for read
+    /// names, and rANS 4x8 (order-1) for quality scores.
+    Small,
+    /// Smallest output.
+    ///
+    /// This uses 100000 records per slice, bzip2 with the best compression level, xz for read
+    /// names, and rANS 4x8 (order-1) for quality scores.
+    Archive,
+}
+
+impl Profile {
+    pub(crate) fn records_per_slice(&self) -> NonZeroUsize {
+        use super::options::DEFAULT_RECORDS_PER_SLICE;
+
+        let n = match self {
+            Self::Fast => 10000,
+            Self::Normal => return DEFAULT_RECORDS_PER_SLICE,
+            Self::Small => 25000,
+            Self::Archive => 100000,
+        };
+
+        NonZeroUsize::new(n).unwrap()
+    }
+
+    pub(crate) fn block_content_encoder_map(&self) -> BlockContentEncoderMap {
+        use flate2::Compression;
+
+        let (encoder, read_names_encoder, quality_scores_encoder) = match self {
+            Self::Fast => {
+                let encoder = Encoder::Gzip(Compression::fast());
+                (encoder.clone(), encoder.clone(), encoder)
+            }
+            Self::Normal => return BlockContentEncoderMap::default(),
+            Self::Small => (
+                Encoder::Gzip(Compression::best()),
+                Encoder::Bzip2(bzip2::Compression::best()),
+                Encoder::Rans4x8(rans_4x8::Order::One),
+            ),
+            Self::Archive => (
+                Encoder::Bzip2(bzip2::Compression::best()),
+                Encoder::Lzma(9),
+                Encoder::Rans4x8(rans_4x8::Order::One),
+            ),
+        };
+
+        let mut builder = BlockContentEncoderMap::builder()
+            .set_core_data_encoder(Some(encoder.clone()))
+            .set_default_tag_values_encoder(Some(encoder.clone()));
+
+        for &data_series in STANDARD_DATA_SERIES {
+            let data_series_encoder = match data_series {
+                DataSeries::ReadNames => read_names_encoder.clone(),
+                DataSeries::QualityScores => quality_scores_encoder.clone(),
+                _ => encoder.clone(),
+            };
+
+            builder = builder.set_data_series_encoder(data_series, Some(data_series_encoder));
+        }
+
+        builder.build()
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::RangeInclusive;
+
+use crate::{record::Feature, Record};
+
+/// A quality score binning table.
+///
+/// Quality score binning lossily maps ranges of quality scores to a single representative score,
+/// reducing the number of distinct scores and improving compression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QualityScoreBins([u8; 256]);
+
+impl QualityScoreBins {
+    /// Creates a quality score binning table from a list of bins.
+    ///
+    /// Each bin maps a range of quality scores to a single score. Scores not in any bin are kept
+    /// as is. If bins overlap, the last bin takes precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::QualityScoreBins;
+    ///
+    /// let bins = QualityScoreBins::new([(0..=19, 10), (20..=u8::MAX, 30)]);
+    /// assert_eq!(bins.get(8), 10);
+    /// assert_eq!(bins.get(45), 30);
+    /// ```
+    pub fn new<I>(bins: I) -> Self
+    where
+        I: IntoIterator<Item = (RangeInclusive<u8>, u8)>,
+    {
+        let mut table = [0; 256];
+
+        for (score, value) in (0..=u8::MAX).zip(table.iter_mut()) {
+            *value = score;
+        }
+
+        for (range, score) in bins {
+            for i in range {
+                table[usize::from(i)] = score;
+            }
+        }
+
+        Self(table)
+    }
+
+    /// Creates the Illumina 8-level quality score binning table.
+    ///
+    /// | score | binned score |
+    /// |------:|-------------:|
+    /// | 0–1   | unchanged    |
+    /// | 2–9   | 6            |
+    /// | 10–19 | 15           |
+    /// | 20–24 | 22           |
+    /// | 25–29 | 27           |
+    /// | 30–34 | 33           |
+    /// | 35–39 | 37           |
+    /// | ≥ 40  | 40           |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::QualityScoreBins;
+    ///
+    /// let bins = QualityScoreBins::illumina_8();
+    /// assert_eq!(bins.get(1), 1);
+    /// assert_eq!(bins.get(13), 15);
+    /// assert_eq!(bins.get(41), 40);
+    /// ```
+    pub fn illumina_8() -> Self {
+        Self::new([
+            (2..=9, 6),
+            (10..=19, 15),
+            (20..=24, 22),
+            (25..=29, 27),
+            (30..=34, 33),
+            (35..=39, 37),
+            (40..=u8::MAX, 40),
+        ])
+    }
+
+    /// Returns the binned score of the given quality score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::QualityScoreBins;
+    /// let bins = QualityScoreBins::new([(2..=9, 6)]);
+    /// assert_eq!(bins.get(5), 6);
+    /// ```
+    pub fn get(&self, score: u8) -> u8 {
+        self.0[usize::from(score)]
+    }
+
+    pub(crate) fn apply(&self, record: &mut Record) {
+        for score in record.quality_scores.as_mut() {
+            *score = self.get(*score);
+        }
+
+        for feature in record.features.iter_mut() {
+            match feature {
+                Feature::Scores(_, scores) => {
+                    for score in scores {
+                        *score = self.get(*score);
+                    }
+                }
+                Feature::ReadBase(_, _, score) | Feature::QualityScore(_, score) => {
+                    *score = self.get(*score);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::record_buf::QualityScores;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let bins = QualityScoreBins::new([(0..=9, 5), (5..=14, 10)]);
+        assert_eq!(bins.get(0), 5);
+        assert_eq!(bins.get(4), 5);
+        assert_eq!(bins.get(5), 10);
+        assert_eq!(bins.get(14), 10);
+        assert_eq!(bins.get(15), 15);
+    }
+
+    #[test]
+    fn test_apply() -> Result<(), noodles_core::position::TryFromIntError> {
+        let bins = QualityScoreBins::illumina_8();
+
+        let mut record = Record::builder()
+            .set_read_length(4)
+            .set_features(
+                vec![
+                    Feature::Scores(Position::MIN, vec![8, 13]),
+                    Feature::ReadBase(Position::try_from(3)?, b'A', 21),
+                    Feature::QualityScore(Position::try_from(4)?, 41),
+                ]
+                .into(),
+            )
+            .set_quality_scores(QualityScores::from(vec![8, 13, 21, 41]))
+            .build();
+
+        bins.apply(&mut record);
+
+        assert_eq!(record.quality_scores.as_ref(), [6, 15, 22, 40]);
+        assert_eq!(
+            &record.features[..],
+            [
+                Feature::Scores(Position::MIN, vec![6, 15]),
+                Feature::ReadBase(Position::try_from(3)?, b'A', 22),
+                Feature::QualityScore(Position::try_from(4)?, 40),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -9,15 +9,18 @@ pub mod codecs;
 pub(crate) mod container;
 pub mod crai;
 pub mod data_container;
+mod eof;
 pub mod file_definition;
 mod huffman;
 mod indexer;
 pub mod io;
 mod num;
 pub mod record;
+pub mod reference_registry;
 
 pub use self::{
-    data_container::DataContainer, file_definition::FileDefinition, indexer::index, record::Record,
+    data_container::DataContainer, eof::is_eof_present, file_definition::FileDefinition,
+    indexer::index, record::Record,
 };
 
 #[cfg(feature = "async")]
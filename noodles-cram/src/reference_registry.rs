@@ -0,0 +1,369 @@
+//! CRAM reference sequence registry.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use noodles_fasta::{self as fasta, record::Definition};
+
+use crate::data_container::slice::builder::calculate_normalized_sequence_digest;
+
+type Fetch = Box<dyn Fn(&str) -> io::Result<Vec<u8>> + Send + Sync>;
+
+enum Source {
+    Repository(fasta::Repository),
+    CachePath(String),
+    Url(String, Fetch),
+}
+
+/// A CRAM reference sequence registry.
+///
+/// This resolves reference sequences by their MD5 checksums (`M5`) in the reference sequence
+/// dictionary, trying each source in the order they were added. A source can be
+///
+///   * a FASTA repository, where sequences are looked up by name and checked against their
+///     checksum;
+///   * a reference cache path template (cf. `REF_CACHE`); or
+///   * a URL template (cf. `REF_PATH` and refget), where the sequence is fetched using a given
+///     function.
+///
+/// Path and URL templates follow the format used by htslib: `%s` is replaced with the
+/// (remaining) MD5 checksum, and `%<n>s` is replaced with the next `n` characters of the
+/// checksum, e.g., `/data/cache/%2s/%2s/%s`.
+///
+/// Sequences that are read from a cache path or fetched from a URL are checked against their
+/// checksum. Fetched sequences are stored in the first cache path, if any. A checksum that is
+/// not 32 hexadecimal digits is invalid and fails to resolve.
+///
+/// A registry is a FASTA repository adapter, i.e., it is used by wrapping it in a
+/// [`fasta::Repository`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_cram::reference_registry::ReferenceRegistry;
+/// use noodles_fasta as fasta;
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+///
+/// let registry = ReferenceRegistry::builder()
+///     .add_cache_path("/data/cache/%2s/%2s/%s")
+///     .build(header.reference_sequences());
+///
+/// let repository = fasta::Repository::new(registry);
+/// ```
+pub struct ReferenceRegistry {
+    sources: Vec<Source>,
+    md5_checksums: HashMap<Vec<u8>, String>,
+}
+
+impl ReferenceRegistry {
+    /// Returns a builder to create a reference sequence registry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_registry::ReferenceRegistry;
+    /// let builder = ReferenceRegistry::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    fn resolve(&self, name: &[u8], md5_checksum: Option<&str>) -> io::Result<Option<Vec<u8>>> {
+        if let Some(md5_checksum) = md5_checksum {
+            if !is_valid_md5_checksum(md5_checksum) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid MD5 checksum",
+                ));
+            }
+        }
+
+        for source in &self.sources {
+            match (source, md5_checksum) {
+                (Source::Repository(repository), _) => {
+                    let Some(sequence) = repository.get(name).transpose()? else {
+                        continue;
+                    };
+
+                    let sequence = sequence.as_ref().to_vec();
+
+                    if md5_checksum.map_or(true, |expected| is_match(&sequence, expected)) {
+                        return Ok(Some(sequence));
+                    }
+                }
+                (Source::CachePath(template), Some(md5_checksum)) => {
+                    let src = expand_template(template, md5_checksum);
+
+                    match fs::read(src) {
+                        Ok(sequence) if is_match(&sequence, md5_checksum) => {
+                            return Ok(Some(sequence))
+                        }
+                        Ok(_) => {}
+                        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                        Err(e) => return Err(e),
+                    }
+                }
+                (Source::Url(template, fetch), Some(md5_checksum)) => {
+                    let url = expand_template(template, md5_checksum);
+
+                    let Ok(sequence) = fetch(&url) else {
+                        continue;
+                    };
+
+                    if !is_match(&sequence, md5_checksum) {
+                        continue;
+                    }
+
+                    if let Some(dst) = self.cache_dst(md5_checksum) {
+                        write_cache_entry(&dst, &sequence)?;
+                    }
+
+                    return Ok(Some(sequence));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn cache_dst(&self, md5_checksum: &str) -> Option<PathBuf> {
+        self.sources.iter().find_map(|source| match source {
+            Source::CachePath(template) => {
+                Some(PathBuf::from(expand_template(template, md5_checksum)))
+            }
+            _ => None,
+        })
+    }
+}
+
+impl fasta::repository::Adapter for ReferenceRegistry {
+    fn get(&mut self, name: &[u8]) -> Option<io::Result<fasta::Record>> {
+        let md5_checksum = self.md5_checksums.get(name).map(|s| s.as_str());
+
+        match self.resolve(name, md5_checksum) {
+            Ok(Some(sequence)) => Some(Ok(fasta::Record::new(
+                Definition::new(name, None),
+                fasta::record::Sequence::from(sequence),
+            ))),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn is_valid_md5_checksum(s: &str) -> bool {
+    const LEN: usize = 32;
+    s.len() == LEN && s.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn is_match(sequence: &[u8], md5_checksum: &str) -> bool {
+    let actual = to_hex(&calculate_normalized_sequence_digest(sequence));
+    actual.eq_ignore_ascii_case(md5_checksum)
+}
+
+fn to_hex(buf: &[u8]) -> String {
+    let mut s = String::with_capacity(buf.len() * 2);
+
+    for b in buf {
+        // Writing to a `String` is infallible.
+        write!(s, "{b:02x}").unwrap();
+    }
+
+    s
+}
+
+// `md5_checksum` is expected to be a valid MD5 checksum, i.e., ASCII.
+fn expand_template(template: &str, md5_checksum: &str) -> String {
+    let mut dst = String::new();
+    let mut remaining = md5_checksum;
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            dst.push(c);
+            continue;
+        }
+
+        let mut n = 0;
+
+        while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+            n = n * 10 + d as usize;
+            chars.next();
+        }
+
+        match chars.next() {
+            Some('s') => {
+                let len = if n == 0 { remaining.len() } else { n };
+                let (head, tail) = remaining.split_at(len.min(remaining.len()));
+                dst.push_str(head);
+                remaining = tail;
+            }
+            Some('%') => dst.push('%'),
+            Some(c) => {
+                dst.push('%');
+                dst.push(c);
+            }
+            None => dst.push('%'),
+        }
+    }
+
+    dst
+}
+
+fn write_cache_entry(dst: &Path, sequence: &[u8]) -> io::Result<()> {
+    if let Some(parent) = dst.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    // The entry is first written to a temporary file so that a partial entry is never read.
+    let mut tmp_dst = dst.as_os_str().to_owned();
+    tmp_dst.push(".tmp");
+
+    let mut file = fs::File::create(&tmp_dst)?;
+    file.write_all(sequence)?;
+    file.sync_all()?;
+
+    fs::rename(tmp_dst, dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    const SQ0_SEQUENCE: &[u8] = b"ACGTACGT";
+    const SQ0_MD5_CHECKSUM: &str = "cc0af3a4fedb18378b4b57b98068e69f";
+
+    #[test]
+    fn test_expand_template() {
+        let md5_checksum = "0123456789abcdef0123456789abcdef";
+
+        assert_eq!(
+            expand_template("/cache/%2s/%2s/%s", md5_checksum),
+            "/cache/01/23/456789abcdef0123456789abcdef"
+        );
+
+        assert_eq!(
+            expand_template("https://www.ebi.ac.uk/ena/cram/md5/%s", md5_checksum),
+            "https://www.ebi.ac.uk/ena/cram/md5/0123456789abcdef0123456789abcdef"
+        );
+
+        assert_eq!(expand_template("100%%/%4s", md5_checksum), "100%/0123");
+    }
+
+    #[test]
+    fn test_is_valid_md5_checksum() {
+        assert!(is_valid_md5_checksum(SQ0_MD5_CHECKSUM));
+        assert!(is_valid_md5_checksum("CC0AF3A4FEDB18378B4B57B98068E69F"));
+        assert!(!is_valid_md5_checksum(""));
+        assert!(!is_valid_md5_checksum("cc0af3a4"));
+        assert!(!is_valid_md5_checksum("../../../../../../../etc/passwd"));
+        assert!(!is_valid_md5_checksum("cc0af3a4fedb18378b4b57b98068e69g"));
+        assert!(!is_valid_md5_checksum("cc0af3a4fedb18378b4b57b98068e6é"));
+    }
+
+    #[test]
+    fn test_is_match() {
+        assert!(is_match(SQ0_SEQUENCE, SQ0_MD5_CHECKSUM));
+        assert!(is_match(b"acgt\nacgt", SQ0_MD5_CHECKSUM));
+        assert!(!is_match(b"ACGT", SQ0_MD5_CHECKSUM));
+    }
+
+    #[test]
+    fn test_get_with_url_and_cache_path() -> io::Result<()> {
+        use fasta::repository::Adapter;
+        use noodles_sam::{
+            self as sam,
+            header::record::value::{
+                map::{reference_sequence::tag, ReferenceSequence},
+                Map,
+            },
+        };
+
+        let cache_dir = env::temp_dir().join(format!(
+            "noodles-cram-reference-registry-test-{}",
+            process::id()
+        ));
+
+        let cache_path = format!("{}/%2s/%s", cache_dir.display());
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::builder()
+                    .set_length(std::num::NonZeroUsize::new(SQ0_SEQUENCE.len()).unwrap())
+                    .insert(tag::MD5_CHECKSUM, SQ0_MD5_CHECKSUM)
+                    .build()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            )
+            .build();
+
+        let mut registry = ReferenceRegistry::builder()
+            .add_cache_path(cache_path.clone())
+            .add_url("https://example.com/%s", |_| Ok(SQ0_SEQUENCE.to_vec()))
+            .build(header.reference_sequences());
+
+        let record = registry.get(b"sq0").transpose()?;
+        assert_eq!(
+            record.as_ref().map(|r| r.sequence().as_ref()),
+            Some(SQ0_SEQUENCE)
+        );
+
+        let cache_dst = expand_template(&cache_path, SQ0_MD5_CHECKSUM);
+        assert_eq!(fs::read(&cache_dst)?, SQ0_SEQUENCE);
+
+        // The sequence is now resolved from the cache.
+        let mut registry = ReferenceRegistry::builder()
+            .add_cache_path(cache_path)
+            .build(header.reference_sequences());
+
+        let record = registry.get(b"sq0").transpose()?;
+        assert_eq!(
+            record.as_ref().map(|r| r.sequence().as_ref()),
+            Some(SQ0_SEQUENCE)
+        );
+
+        assert!(registry.get(b"sq1").is_none());
+
+        // A cache entry that does not match its checksum is ignored.
+        fs::write(&cache_dst, b"NNNNNNNN")?;
+        assert!(registry.get(b"sq0").is_none());
+
+        fs::remove_dir_all(cache_dir)?;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::builder()
+                    .set_length(std::num::NonZeroUsize::new(SQ0_SEQUENCE.len()).unwrap())
+                    .insert(tag::MD5_CHECKSUM, "../sq0")
+                    .build()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?,
+            )
+            .build();
+
+        let mut registry = ReferenceRegistry::builder()
+            .add_url("https://example.com/%s", |_| Ok(SQ0_SEQUENCE.to_vec()))
+            .build(header.reference_sequences());
+
+        assert!(matches!(
+            registry.get(b"sq0"),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
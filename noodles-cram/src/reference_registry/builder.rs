@@ -0,0 +1,112 @@
+use std::{collections::HashMap, io};
+
+use noodles_fasta as fasta;
+use noodles_sam::header::ReferenceSequences;
+
+use super::{ReferenceRegistry, Source};
+
+/// A CRAM reference sequence registry builder.
+#[derive(Default)]
+pub struct Builder {
+    sources: Vec<Source>,
+}
+
+impl Builder {
+    /// Adds a FASTA repository as a source.
+    ///
+    /// Sequences are looked up by name. If the reference sequence has an MD5 checksum, the
+    /// sequence is only used if it matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_registry::ReferenceRegistry;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let repository = fasta::Repository::default();
+    /// let builder = ReferenceRegistry::builder().add_repository(repository);
+    /// ```
+    pub fn add_repository(mut self, repository: fasta::Repository) -> Self {
+        self.sources.push(Source::Repository(repository));
+        self
+    }
+
+    /// Adds a reference cache path template as a source.
+    ///
+    /// A cache entry is a file that contains only the sequence, e.g., as created by htslib's
+    /// `seq_cache_populate.pl`. The first cache path is also used to store sequences fetched from
+    /// a URL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_registry::ReferenceRegistry;
+    /// let builder = ReferenceRegistry::builder().add_cache_path("/data/cache/%2s/%2s/%s");
+    /// ```
+    pub fn add_cache_path<S>(mut self, template: S) -> Self
+    where
+        S: Into<String>,
+    {
+        self.sources.push(Source::CachePath(template.into()));
+        self
+    }
+
+    /// Adds a URL template as a source.
+    ///
+    /// `fetch` is called with the expanded URL and returns the response body. This allows the
+    /// use of any HTTP client, e.g., to query a refget server.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram::reference_registry::ReferenceRegistry;
+    ///
+    /// let builder = ReferenceRegistry::builder()
+    ///     .add_url("https://www.ebi.ac.uk/ena/cram/md5/%s", |_url| {
+    ///         Err(io::Error::from(io::ErrorKind::Unsupported))
+    ///     });
+    /// ```
+    pub fn add_url<S, F>(mut self, template: S, fetch: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn(&str) -> io::Result<Vec<u8>> + Send + Sync + 'static,
+    {
+        self.sources
+            .push(Source::Url(template.into(), Box::new(fetch)));
+        self
+    }
+
+    /// Builds a reference sequence registry.
+    ///
+    /// The MD5 checksums (`M5`) of the reference sequences are taken from the given reference
+    /// sequence dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::reference_registry::ReferenceRegistry;
+    /// use noodles_sam as sam;
+    ///
+    /// let header = sam::Header::default();
+    /// let registry = ReferenceRegistry::builder().build(header.reference_sequences());
+    /// ```
+    pub fn build(self, reference_sequences: &ReferenceSequences) -> ReferenceRegistry {
+        use noodles_sam::header::record::value::map::reference_sequence::tag;
+
+        let md5_checksums: HashMap<_, _> = reference_sequences
+            .iter()
+            .filter_map(|(name, reference_sequence)| {
+                reference_sequence
+                    .other_fields()
+                    .get(&tag::MD5_CHECKSUM)
+                    .map(|md5_checksum| (name.to_vec(), md5_checksum.to_string()))
+            })
+            .collect();
+
+        ReferenceRegistry {
+            sources: self.sources,
+            md5_checksums,
+        }
+    }
+}
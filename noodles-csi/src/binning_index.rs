@@ -1,5 +1,6 @@
 //! Binning index.
 
+pub mod binning;
 pub mod index;
 mod indexer;
 mod reference_sequence;
@@ -37,6 +38,76 @@ pub trait BinningIndex {
     /// This is the closest position to the unplaced, unmapped records, if any, that is available
     /// in an index.
     fn last_first_record_start_position(&self) -> Option<bgzf::VirtualPosition>;
+
+    /// Returns the number of records of a reference sequence.
+    ///
+    /// This is the sum of the mapped and unmapped record counts in the reference sequence
+    /// metadata. If the reference sequence does not exist or has no metadata, this returns
+    /// `None`.
+    fn record_count(&self, reference_sequence_id: usize) -> Option<u64> {
+        self.reference_sequences()
+            .nth(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+            .map(|metadata| metadata.mapped_record_count() + metadata.unmapped_record_count())
+    }
+
+    /// Estimates the number of records that overlap with the given region.
+    ///
+    /// If the interval is unbounded, i.e., the whole reference sequence, this is the exact record
+    /// count from the reference sequence metadata ([`Self::record_count`]). Otherwise, it is
+    /// approximated by the proportion of the reference sequence's data that is covered by the
+    /// query chunks. No records are read.
+    ///
+    /// If the reference sequence has no metadata, this returns `None`.
+    fn estimate_record_count(
+        &self,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Option<u64>> {
+        let Some(metadata) = self
+            .reference_sequences()
+            .nth(reference_sequence_id)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+            .cloned()
+        else {
+            return Ok(None);
+        };
+
+        let record_count = metadata.mapped_record_count() + metadata.unmapped_record_count();
+
+        if interval.start().is_none() && interval.end().is_none() {
+            return Ok(Some(record_count));
+        }
+
+        let chunks = self.query(reference_sequence_id, interval)?;
+        let query_size: u64 = chunks.iter().map(chunk_size).sum();
+
+        let reference_sequence_size = chunk_size(&Chunk::new(
+            metadata.start_position(),
+            metadata.end_position(),
+        ));
+
+        if reference_sequence_size == 0 {
+            return Ok(Some(record_count));
+        }
+
+        // Round to the nearest integer.
+        let numerator =
+            2 * u128::from(record_count) * u128::from(query_size.min(reference_sequence_size))
+                + u128::from(reference_sequence_size);
+        let n = numerator / (2 * u128::from(reference_sequence_size));
+
+        // SAFETY: `n <= record_count`.
+        Ok(Some(n as u64))
+    }
+}
+
+// The distance between the virtual positions of a chunk.
+//
+// This mixes compressed and uncompressed offsets, so it is only useful for comparing the relative
+// sizes of chunks in the same file.
+fn chunk_size(chunk: &Chunk) -> u64 {
+    u64::from(chunk.end()).saturating_sub(u64::from(chunk.start()))
 }
 
 impl<I> BinningIndex for Box<I>
@@ -245,4 +316,55 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn test_record_count_and_estimate_record_count() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+
+        use self::index::reference_sequence::index::LinearIndex;
+
+        let mut indexer = Indexer::<LinearIndex>::default();
+
+        // sq0:1-100 (1 MiB), sq0:1000001-1000100 (3 MiB)
+        indexer.add_record(
+            Some((0, Position::MIN, Position::try_from(100)?, true)),
+            Chunk::new(
+                bgzf::VirtualPosition::from(0),
+                bgzf::VirtualPosition::from(1 << 36),
+            ),
+        )?;
+
+        indexer.add_record(
+            Some((
+                0,
+                Position::try_from(1000001)?,
+                Position::try_from(1000100)?,
+                false,
+            )),
+            Chunk::new(
+                bgzf::VirtualPosition::from(1 << 36),
+                bgzf::VirtualPosition::from(4 << 36),
+            ),
+        )?;
+
+        let index = indexer.build(2);
+
+        assert_eq!(index.record_count(0), Some(2));
+        assert!(index.record_count(1).is_none());
+        assert!(index.record_count(2).is_none());
+
+        assert_eq!(index.estimate_record_count(0, Interval::from(..))?, Some(2));
+
+        let interval = (Position::try_from(1000001)?..=Position::try_from(1000100)?).into();
+        assert_eq!(index.estimate_record_count(0, interval)?, Some(2));
+
+        let interval = (Position::MIN..=Position::try_from(100)?).into();
+        assert_eq!(index.estimate_record_count(0, interval)?, Some(1));
+
+        assert!(index
+            .estimate_record_count(1, Interval::from(..))?
+            .is_none());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,354 @@
+//! Binning scheme arithmetic.
+//!
+//! A binning index with a given minimum shift (`min_shift`) and depth partitions each reference
+//! sequence into a hierarchy of bins. Level 0 is a single bin that covers the entire reference
+//! sequence, and each bin in level _l_ is split into 8 bins in level _l_ + 1. The bins in the
+//! deepest level (`depth`) each span 2^`min_shift` positions.
+//!
+//! Bins are numbered breadth-first, i.e., level _l_ starts at bin ID (8^_l_ - 1) / 7.
+//!
+//! All positions are 1-based, inclusive.
+
+use std::io;
+
+use noodles_core::Position;
+
+/// Calculates the maximum position that can be indexed using the given binning scheme.
+///
+/// This fails if the maximum position cannot be represented, e.g., when `min_shift` is 0 or the
+/// bin span overflows.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::binning::max_position;
+/// assert_eq!(max_position(14, 5)?, Position::try_from(536870911)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
+    if min_shift == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid min shift",
+        ));
+    }
+
+    let n = span(0, min_shift, depth)
+        .map(|n| n - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid depth"))?;
+
+    Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+/// Calculates the ID of the smallest bin that contains the given region.
+///
+/// This fails if the bin span of the binning scheme overflows.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::binning::reg2bin;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bin(start, end, 14, 5)?, 4681);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> io::Result<usize> {
+    validate(min_shift, depth)?;
+    Ok(reg2bin_unchecked(start, end, min_shift, depth))
+}
+
+pub(crate) fn reg2bin_unchecked(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
+    // `CSIv1.pdf` (2020-07-21)
+
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end);
+
+    let end = end - 1;
+    let mut l = depth;
+    let mut s = min_shift;
+    let mut t = ((1 << (depth * 3)) - 1) / 7;
+
+    while l > 0 {
+        if beg >> s == end >> s {
+            return t + (beg >> s);
+        }
+
+        l -= 1;
+        s += 3;
+        t -= 1 << (l * 3);
+    }
+
+    0
+}
+
+/// Calculates the IDs of all bins that overlap the given region.
+///
+/// The bin IDs are returned in ascending order. This fails if the bin span of the binning scheme
+/// overflows.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::binning::reg2bins;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bins(start, end, 4, 2)?, [0, 1, 9]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reg2bins(
+    start: Position,
+    end: Position,
+    min_shift: u8,
+    depth: u8,
+) -> io::Result<Vec<usize>> {
+    validate(min_shift, depth)?;
+    Ok(reg2bins_unchecked(start, end, min_shift, depth))
+}
+
+#[allow(clippy::many_single_char_names)]
+pub(crate) fn reg2bins_unchecked(
+    start: Position,
+    end: Position,
+    min_shift: u8,
+    depth: u8,
+) -> Vec<usize> {
+    // `CSIv1.pdf` (2020-07-21)
+
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end);
+
+    let end = end - 1;
+    let mut l = 0;
+    let mut t = 0;
+    let mut s = i32::from(min_shift) + i32::from(depth) * 3;
+
+    let mut bin_ids = Vec::new();
+
+    while l <= depth {
+        let b = t + (beg >> s);
+        let e = t + (end >> s);
+
+        bin_ids.extend(b..=e);
+
+        s -= 3;
+        t += 1 << (l * 3);
+        l += 1;
+    }
+
+    bin_ids
+}
+
+/// Calculates the start position of the given bin.
+///
+/// This returns `None` if the bin ID is not in the binning scheme.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::binning::bin_start;
+///
+/// assert_eq!(bin_start(0, 4, 2), Some(Position::MIN));
+/// assert_eq!(bin_start(10, 4, 2), Some(Position::try_from(17)?));
+/// assert!(bin_start(73, 4, 2).is_none());
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn bin_start(id: usize, min_shift: u8, depth: u8) -> Option<Position> {
+    let (level, offset) = resolve_bin(id, depth)?;
+    let start = span(level, min_shift, depth)?.checked_mul(offset)?;
+    Position::new(start + 1)
+}
+
+/// Calculates the end position of the given bin.
+///
+/// This returns `None` if the bin ID is not in the binning scheme.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::binning::bin_end;
+///
+/// assert_eq!(bin_end(0, 4, 2), Some(Position::try_from(1024)?));
+/// assert_eq!(bin_end(10, 4, 2), Some(Position::try_from(32)?));
+/// assert!(bin_end(73, 4, 2).is_none());
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+pub fn bin_end(id: usize, min_shift: u8, depth: u8) -> Option<Position> {
+    let (level, offset) = resolve_bin(id, depth)?;
+    let end = span(level, min_shift, depth)?.checked_mul(offset + 1)?;
+    Position::new(end)
+}
+
+fn validate(min_shift: u8, depth: u8) -> io::Result<()> {
+    span(0, min_shift, depth)
+        .map(|_| ())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid depth"))
+}
+
+// Returns the level of the bin and its offset within that level.
+fn resolve_bin(id: usize, depth: u8) -> Option<(u8, usize)> {
+    let mut first_id = 0usize;
+
+    for level in 0..=depth {
+        let bin_count = 1usize.checked_shl(3 * u32::from(level))?;
+
+        if id < first_id + bin_count {
+            return Some((level, id - first_id));
+        }
+
+        first_id += bin_count;
+    }
+
+    None
+}
+
+// Returns the number of positions spanned by a bin in the given level.
+fn span(level: u8, min_shift: u8, depth: u8) -> Option<usize> {
+    let bits = u32::from(min_shift) + 3 * u32::from(depth - level);
+    1usize.checked_shl(bits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_position() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let actual = max_position(MIN_SHIFT, DEPTH)?;
+        let expected = Position::try_from(536870911)?;
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            max_position(0, DEPTH),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            max_position(MIN_SHIFT, 21),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bin() -> Result<(), Box<dyn std::error::Error>> {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        let start = Position::try_from(8)?;
+        let end = start;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(13)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(16)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 9);
+
+        let end = Position::try_from(17)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 1);
+
+        let end = Position::try_from(143)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH)?, 0);
+
+        assert!(matches!(
+            reg2bin(start, end, 14, 17),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reg2bin(start, end, 14, u8::MAX),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bins() -> Result<(), Box<dyn std::error::Error>> {
+        // +------------------------------------------------------------------------------------...
+        // | 0                                                                                  ...
+        // | 0-1023                                                                             ...
+        // +-------------------------------------------------------------------------+----------...
+        // | 1                                                                       | 2        ...
+        // | 0-127                                                                   | 128-255  ...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+        // | 9      | 10     | 11     | 12     | 13     | 14     | 15      | 16      | 17      |...
+        // | 0-15   | 16-31  | 32-47  | 48-63  | 64-79  | 80-95  | 96-111  | 112-127 | 128-143 |...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        fn t(start: Position, end: Position, expected: &[usize]) {
+            assert_eq!(reg2bins(start, end, MIN_SHIFT, DEPTH).unwrap(), expected);
+        }
+
+        t(Position::try_from(1)?, Position::try_from(16)?, &[0, 1, 9]);
+        t(Position::try_from(9)?, Position::try_from(13)?, &[0, 1, 9]);
+
+        t(
+            Position::try_from(36)?,
+            Position::try_from(67)?,
+            &[0, 1, 11, 12, 13],
+        );
+
+        t(
+            Position::try_from(49)?,
+            Position::try_from(143)?,
+            &[0, 1, 2, 12, 13, 14, 15, 16, 17],
+        );
+
+        assert!(matches!(
+            reg2bins(Position::MIN, Position::MIN, 14, 17),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_bin_start_and_bin_end() {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        fn t(id: usize, expected_start: usize, expected_end: usize) {
+            assert_eq!(
+                bin_start(id, MIN_SHIFT, DEPTH),
+                Position::new(expected_start)
+            );
+            assert_eq!(bin_end(id, MIN_SHIFT, DEPTH), Position::new(expected_end));
+        }
+
+        t(0, 1, 1024);
+        t(1, 1, 128);
+        t(2, 129, 256);
+        t(8, 897, 1024);
+        t(9, 1, 16);
+        t(17, 129, 144);
+        t(72, 1009, 1024);
+
+        assert!(bin_start(73, MIN_SHIFT, DEPTH).is_none());
+        assert!(bin_end(73, MIN_SHIFT, DEPTH).is_none());
+
+        // Every bin contains the region it is assigned by `reg2bin`.
+        for id in 0..73 {
+            let start = bin_start(id, MIN_SHIFT, DEPTH).unwrap();
+            let end = bin_end(id, MIN_SHIFT, DEPTH).unwrap();
+            assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH).unwrap(), id);
+        }
+    }
+}
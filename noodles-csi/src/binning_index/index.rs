@@ -11,7 +11,7 @@ use std::io;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
 
-use super::{index::reference_sequence::bin::Chunk, BinningIndex};
+use super::{binning::max_position, index::reference_sequence::bin::Chunk, BinningIndex};
 
 /// A binning index.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -155,26 +155,3 @@ where
         Ok((start, end))
     }
 }
-
-fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
-    assert!(min_shift > 0);
-    let n = (1 << (usize::from(min_shift) + 3 * usize::from(depth))) - 1;
-    Position::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_max_position() -> Result<(), Box<dyn std::error::Error>> {
-        const MIN_SHIFT: u8 = 14;
-        const DEPTH: u8 = 5;
-
-        let actual = max_position(MIN_SHIFT, DEPTH)?;
-        let expected = Position::try_from(536870911)?;
-        assert_eq!(actual, expected);
-
-        Ok(())
-    }
-}
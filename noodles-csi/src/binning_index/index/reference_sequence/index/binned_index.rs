@@ -3,7 +3,10 @@ use noodles_bgzf as bgzf;
 use noodles_core::Position;
 
 use super::Index;
-use crate::binning_index::index::reference_sequence::{bin::Chunk, parent_id, reg2bin};
+use crate::binning_index::{
+    binning::reg2bin_unchecked,
+    index::reference_sequence::{bin::Chunk, parent_id},
+};
 
 /// A binned index.
 pub type BinnedIndex = IndexMap<usize, bgzf::VirtualPosition>;
@@ -11,7 +14,7 @@ pub type BinnedIndex = IndexMap<usize, bgzf::VirtualPosition>;
 impl Index for BinnedIndex {
     fn min_offset(&self, min_shift: u8, depth: u8, start: Position) -> bgzf::VirtualPosition {
         let end = start;
-        let mut bin_id = reg2bin(start, end, min_shift, depth);
+        let mut bin_id = reg2bin_unchecked(start, end, min_shift, depth);
 
         loop {
             if let Some(position) = self.get(&bin_id) {
@@ -32,7 +35,7 @@ impl Index for BinnedIndex {
     }
 
     fn update(&mut self, min_shift: u8, depth: u8, start: Position, end: Position, chunk: Chunk) {
-        let bin_id = reg2bin(start, end, min_shift, depth);
+        let bin_id = reg2bin_unchecked(start, end, min_shift, depth);
 
         self.entry(bin_id)
             .and_modify(|loffset| {
@@ -132,6 +132,8 @@ where
     pub fn build(mut self, reference_sequence_count: usize) -> Index<I> {
         if reference_sequence_count == 0 {
             return Index::builder()
+                .set_min_shift(self.min_shift)
+                .set_depth(self.depth)
                 .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count)
                 .build();
         }
@@ -140,6 +142,8 @@ where
         self.add_reference_sequences_until(reference_sequence_count - 1);
 
         let mut builder = Index::builder()
+            .set_min_shift(self.min_shift)
+            .set_depth(self.depth)
             .set_reference_sequences(self.reference_sequences)
             .set_unplaced_unmapped_record_count(self.unplaced_unmapped_record_count);
 
@@ -179,7 +183,13 @@ mod tests {
     use noodles_bgzf as bgzf;
 
     use super::*;
-    use crate::binning_index::index::reference_sequence::{index::LinearIndex, Bin, Metadata};
+    use crate::{
+        binning_index::index::reference_sequence::{
+            index::{BinnedIndex, LinearIndex},
+            Bin, Metadata,
+        },
+        BinningIndex,
+    };
 
     #[test]
     fn test_default() {
@@ -280,4 +290,12 @@ mod tests {
         let index = Indexer::<LinearIndex>::default().build(2);
         assert_eq!(index.reference_sequences().len(), 2);
     }
+
+    #[test]
+    fn test_build_with_min_shift_and_depth() {
+        let indexer = Indexer::<BinnedIndex>::new(12, 6);
+        let index = indexer.build(0);
+        assert_eq!(index.min_shift(), 12);
+        assert_eq!(index.depth(), 6);
+    }
 }
@@ -122,6 +122,9 @@ where
 
     /// Returns a stream over lines.
     ///
+    /// The stream stops at either EOF or after the `FASTA` directive is read, whichever comes
+    /// first.
+    ///
     /// # Examples
     ///
     /// ```
@@ -143,14 +146,21 @@ where
     /// ```
     pub fn lines(&mut self) -> impl Stream<Item = io::Result<Line>> + '_ {
         Box::pin(stream::try_unfold(
-            (self, String::new()),
-            |(reader, mut buf)| async {
+            (self, String::new(), false),
+            |(reader, mut buf, is_eof)| async move {
+                if is_eof {
+                    return Ok(None);
+                }
+
                 buf.clear();
 
                 reader.read_line(&mut buf).await.and_then(|n| match n {
                     0 => Ok(None),
                     _ => match buf.parse() {
-                        Ok(line) => Ok(Some((line, (reader, buf)))),
+                        Ok(line) => {
+                            let is_eof = matches!(line, Line::Directive(Directive::StartOfFasta));
+                            Ok(Some((line, (reader, buf, is_eof))))
+                        }
                         Err(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
                     },
                 })
@@ -0,0 +1,179 @@
+//! GFF feature hierarchy.
+//!
+//! Features are related by their `ID` and `Parent` attributes, e.g., a gene is the parent of its
+//! mRNAs, which are, in turn, the parents of their exons and CDSs. A [`Builder`] resolves these
+//! relations and builds a tree of [`Feature`]s for each top-level record.
+
+mod builder;
+mod features;
+
+pub use self::{builder::Builder, features::Features};
+
+use crate::{record::attributes::field::tag::ID, Record};
+
+/// A GFF feature with its child features.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Feature {
+    record: Record,
+    children: Vec<Feature>,
+}
+
+impl Feature {
+    /// Creates a feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// ```
+    pub fn new(record: Record, children: Vec<Feature>) -> Self {
+        Self { record, children }
+    }
+
+    /// Returns the record of the feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert_eq!(feature.record(), &gff::Record::default());
+    /// ```
+    pub fn record(&self) -> &Record {
+        &self.record
+    }
+
+    /// Returns the ID of the feature.
+    ///
+    /// This is the value of the `ID` attribute, if it is a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert!(feature.id().is_none());
+    /// ```
+    pub fn id(&self) -> Option<&str> {
+        self.record
+            .attributes()
+            .get(ID)
+            .and_then(|value| value.as_string())
+    }
+
+    /// Returns the child features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert!(feature.children().is_empty());
+    /// ```
+    pub fn children(&self) -> &[Feature] {
+        &self.children
+    }
+
+    /// Returns an iterator over all descendant features.
+    ///
+    /// The features are visited in depth-first pre-order, i.e., each feature is followed by its
+    /// own descendants before its next sibling. The feature itself is not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    ///
+    /// let exon = Feature::new(gff::Record::default(), Vec::new());
+    /// let mrna = Feature::new(gff::Record::default(), vec![exon]);
+    /// let gene = Feature::new(gff::Record::default(), vec![mrna]);
+    ///
+    /// assert_eq!(gene.descendants().count(), 2);
+    /// ```
+    pub fn descendants(&self) -> impl Iterator<Item = &Feature> {
+        let mut stack: Vec<_> = self.children.iter().rev().collect();
+
+        std::iter::from_fn(move || {
+            let feature = stack.pop()?;
+            stack.extend(feature.children.iter().rev());
+            Some(feature)
+        })
+    }
+
+    /// Returns the first descendant feature with the given ID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// assert!(feature.find("mrna0").is_none());
+    /// ```
+    pub fn find(&self, id: &str) -> Option<&Feature> {
+        self.descendants().find(|feature| feature.id() == Some(id))
+    }
+
+    /// Unwraps and returns the record and child features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy::Feature};
+    /// let feature = Feature::new(gff::Record::default(), Vec::new());
+    /// let (record, children) = feature.into_parts();
+    /// assert_eq!(record, gff::Record::default());
+    /// assert!(children.is_empty());
+    /// ```
+    pub fn into_parts(self) -> (Record, Vec<Feature>) {
+        (self.record, self.children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_feature(id: &str, children: Vec<Feature>) -> Feature {
+        use crate::record::attributes::field::{Tag, Value};
+
+        let record = Record::builder()
+            .set_attributes([(Tag::from(ID), Value::from(id))].into_iter().collect())
+            .build();
+
+        Feature::new(record, children)
+    }
+
+    #[test]
+    fn test_descendants() {
+        let gene = build_feature(
+            "gene0",
+            vec![
+                build_feature(
+                    "mrna0",
+                    vec![
+                        build_feature("exon0", Vec::new()),
+                        build_feature("exon1", Vec::new()),
+                    ],
+                ),
+                build_feature("mrna1", vec![build_feature("exon2", Vec::new())]),
+            ],
+        );
+
+        let actual: Vec<_> = gene.descendants().map(|feature| feature.id()).collect();
+        let expected = [
+            Some("mrna0"),
+            Some("exon0"),
+            Some("exon1"),
+            Some("mrna1"),
+            Some("exon2"),
+        ];
+        assert_eq!(actual, expected);
+
+        assert_eq!(
+            gene.find("exon2").map(|feature| feature.children().len()),
+            Some(0)
+        );
+        assert!(gene.find("gene0").is_none());
+    }
+}
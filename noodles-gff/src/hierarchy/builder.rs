@@ -0,0 +1,287 @@
+use std::{collections::HashMap, io, mem};
+
+use super::Feature;
+use crate::{
+    record::attributes::field::tag::{ID, PARENT},
+    Record,
+};
+
+/// A GFF feature hierarchy builder.
+///
+/// Records are buffered until the builder is flushed, at which point all `Parent` references must
+/// be resolvable to the `ID` of a buffered record. This is typically done after reading a `###`
+/// directive (see [`crate::Directive::ForwardReferencesAreResolved`]) or at the end of the
+/// records.
+///
+/// A feature with multiple parents is added as a child to each of them. If multiple records share
+/// an ID, children are added to the first record with that ID.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff::{self as gff, hierarchy};
+///
+/// let mut builder = hierarchy::Builder::default();
+///
+/// builder.add_record("sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0".parse()?);
+/// builder.add_record("sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna0;Parent=gene0".parse()?);
+/// builder.add_record("sq0\t.\texon\t1\t8\t.\t+\t.\tParent=mrna0".parse()?);
+///
+/// let features = builder.flush()?;
+/// assert_eq!(features.len(), 1);
+///
+/// let gene = &features[0];
+/// assert_eq!(gene.id(), Some("gene0"));
+/// assert_eq!(gene.descendants().count(), 2);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Default)]
+pub struct Builder {
+    records: Vec<Record>,
+}
+
+impl Builder {
+    /// Adds a record to the builder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, hierarchy};
+    /// let mut builder = hierarchy::Builder::default();
+    /// builder.add_record(gff::Record::default());
+    /// ```
+    pub fn add_record(&mut self, record: Record) {
+        self.records.push(record);
+    }
+
+    /// Returns whether there are no buffered records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::hierarchy;
+    /// let builder = hierarchy::Builder::default();
+    /// assert!(builder.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Builds the features of the buffered records.
+    ///
+    /// This returns a feature for each record without a parent, in the order the records were
+    /// added. The builder is empty afterward.
+    ///
+    /// An error is returned if a parent ID does not exist or if the parent references are
+    /// circular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::hierarchy;
+    /// let mut builder = hierarchy::Builder::default();
+    /// assert!(builder.flush()?.is_empty());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn flush(&mut self) -> io::Result<Vec<Feature>> {
+        let records = mem::take(&mut self.records);
+        build(records)
+    }
+}
+
+fn build(records: Vec<Record>) -> io::Result<Vec<Feature>> {
+    let mut ids = HashMap::new();
+
+    for (i, record) in records.iter().enumerate() {
+        if let Some(id) = get_id(record)? {
+            ids.entry(id.to_string()).or_insert(i);
+        }
+    }
+
+    let mut children = vec![Vec::new(); records.len()];
+    let mut ref_counts = vec![0; records.len()];
+    let mut roots = Vec::new();
+
+    for (i, record) in records.iter().enumerate() {
+        match record.attributes().get(PARENT) {
+            Some(value) => {
+                for parent_id in value.iter() {
+                    let j = ids.get(parent_id.as_str()).copied().ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("unresolved parent: {parent_id}"),
+                        )
+                    })?;
+
+                    children[j].push(i);
+                    ref_counts[i] += 1;
+                }
+            }
+            None => {
+                roots.push(i);
+                ref_counts[i] += 1;
+            }
+        }
+    }
+
+    let mut builder = TreeBuilder {
+        records: records.into_iter().map(Some).collect(),
+        children,
+        ref_counts,
+        visiting: Vec::new(),
+    };
+
+    let features = roots
+        .into_iter()
+        .map(|i| builder.build_feature(i))
+        .collect::<io::Result<_>>()?;
+
+    if builder.records.iter().any(|record| record.is_some()) {
+        return Err(circular_references_error());
+    }
+
+    Ok(features)
+}
+
+fn get_id(record: &Record) -> io::Result<Option<&str>> {
+    match record.attributes().get(ID) {
+        Some(value) => value
+            .as_string()
+            .map(Some)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid ID")),
+        None => Ok(None),
+    }
+}
+
+struct TreeBuilder {
+    records: Vec<Option<Record>>,
+    children: Vec<Vec<usize>>,
+    ref_counts: Vec<usize>,
+    visiting: Vec<usize>,
+}
+
+impl TreeBuilder {
+    fn build_feature(&mut self, i: usize) -> io::Result<Feature> {
+        if self.visiting.contains(&i) {
+            return Err(circular_references_error());
+        }
+
+        self.visiting.push(i);
+
+        let child_indices = self.children[i].clone();
+        let children = child_indices
+            .into_iter()
+            .map(|j| self.build_feature(j))
+            .collect::<io::Result<_>>()?;
+
+        self.visiting.pop();
+
+        // A record with multiple parents is cloned for all but its last use.
+        self.ref_counts[i] -= 1;
+
+        let record = if self.ref_counts[i] == 0 {
+            self.records[i].take()
+        } else {
+            self.records[i].clone()
+        }
+        .expect("missing record");
+
+        Ok(Feature::new(record, children))
+    }
+}
+
+fn circular_references_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, "circular parent references")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn add_records(
+        builder: &mut Builder,
+        lines: &[&str],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for s in lines {
+            builder.add_record(s.parse()?);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::default();
+
+        add_records(
+            &mut builder,
+            &[
+                "sq0\t.\texon\t1\t4\t.\t+\t.\tID=exon0;Parent=mrna0,mrna1",
+                "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+                "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna0;Parent=gene0",
+                "sq0\t.\tmRNA\t1\t4\t.\t+\t.\tID=mrna1;Parent=gene0",
+                "sq0\t.\tCDS\t2\t4\t.\t+\t0\tParent=mrna1",
+                "sq0\t.\tgene\t13\t21\t.\t+\t.\tID=gene1",
+            ],
+        )?;
+
+        let features = builder.flush()?;
+        assert!(builder.is_empty());
+
+        let actual: Vec<_> = features.iter().map(|feature| feature.id()).collect();
+        assert_eq!(actual, [Some("gene0"), Some("gene1")]);
+
+        let gene = &features[0];
+
+        let actual: Vec<_> = gene
+            .descendants()
+            .map(|feature| (feature.record().ty(), feature.id()))
+            .collect();
+
+        let expected = [
+            ("mRNA", Some("mrna0")),
+            ("exon", Some("exon0")),
+            ("mRNA", Some("mrna1")),
+            ("exon", Some("exon0")),
+            ("CDS", None),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush_with_unresolved_parent() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::default();
+        add_records(&mut builder, &["sq0\t.\texon\t1\t4\t.\t+\t.\tParent=mrna0"])?;
+
+        assert!(matches!(
+            builder.flush(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flush_with_circular_references() -> Result<(), Box<dyn std::error::Error>> {
+        let mut builder = Builder::default();
+
+        add_records(
+            &mut builder,
+            &[
+                "sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0",
+                "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna0;Parent=mrna1",
+                "sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna1;Parent=mrna0",
+            ],
+        )?;
+
+        assert!(matches!(
+            builder.flush(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
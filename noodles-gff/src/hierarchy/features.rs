@@ -0,0 +1,145 @@
+use std::{collections::VecDeque, io};
+
+use super::{Builder, Feature};
+use crate::{Directive, Line};
+
+/// An iterator over features built from GFF lines.
+///
+/// Records are buffered until either a `###` directive is read, a `FASTA` directive is read, or
+/// the lines end. The buffered records are then built into features (see [`Builder::flush`]).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff::{self as gff, hierarchy};
+///
+/// let data = b"##gff-version 3
+/// sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+/// sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna0;Parent=gene0
+/// ###
+/// sq0\t.\tgene\t13\t21\t.\t+\t.\tID=gene1
+/// ";
+///
+/// let mut reader = gff::io::Reader::new(&data[..]);
+/// let mut features = hierarchy::Features::new(reader.lines());
+///
+/// let gene = features.next().transpose()?.expect("missing feature");
+/// assert_eq!(gene.id(), Some("gene0"));
+/// assert_eq!(gene.children().len(), 1);
+///
+/// let gene = features.next().transpose()?.expect("missing feature");
+/// assert_eq!(gene.id(), Some("gene1"));
+///
+/// assert!(features.next().is_none());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Features<I> {
+    lines: I,
+    builder: Builder,
+    features: VecDeque<Feature>,
+    is_eof: bool,
+}
+
+impl<I> Features<I>
+where
+    I: Iterator<Item = io::Result<Line>>,
+{
+    /// Creates an iterator over features built from the given lines.
+    pub fn new(lines: I) -> Self {
+        Self {
+            lines,
+            builder: Builder::default(),
+            features: VecDeque::new(),
+            is_eof: false,
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        let features = self.builder.flush()?;
+        self.features.extend(features);
+        Ok(())
+    }
+}
+
+impl<I> Iterator for Features<I>
+where
+    I: Iterator<Item = io::Result<Line>>,
+{
+    type Item = io::Result<Feature>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(feature) = self.features.pop_front() {
+                return Some(Ok(feature));
+            } else if self.is_eof {
+                return None;
+            }
+
+            match self.lines.next() {
+                Some(Ok(Line::Record(record))) => self.builder.add_record(record),
+                Some(Ok(Line::Directive(Directive::ForwardReferencesAreResolved))) => {
+                    if let Err(e) = self.flush() {
+                        return Some(Err(e));
+                    }
+                }
+                Some(Ok(Line::Directive(Directive::StartOfFasta))) | None => {
+                    self.is_eof = true;
+
+                    if let Err(e) = self.flush() {
+                        return Some(Err(e));
+                    }
+                }
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\t.\tmRNA\t1\t8\t.\t+\t.\tID=mrna0;Parent=gene0
+sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+###
+sq0\t.\tgene\t13\t21\t.\t+\t.\tID=gene1
+sq0\t.\tmRNA\t13\t21\t.\t+\t.\tID=mrna1;Parent=gene0
+";
+
+        let mut reader = crate::io::Reader::new(&data[..]);
+        let mut features = Features::new(reader.lines());
+
+        let gene = features.next().transpose()?.expect("missing feature");
+        assert_eq!(gene.id(), Some("gene0"));
+        assert_eq!(gene.children().len(), 1);
+
+        // gene0 is not in scope after the `###` directive.
+        assert!(matches!(
+            features.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_fasta_directive() -> io::Result<()> {
+        let data = b"\
+sq0\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = crate::io::Reader::new(&data[..]);
+        let features: Vec<_> = Features::new(reader.lines()).collect::<io::Result<_>>()?;
+        assert_eq!(features.len(), 1);
+
+        Ok(())
+    }
+}
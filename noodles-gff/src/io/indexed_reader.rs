@@ -0,0 +1,196 @@
+//! Indexed GFF reader.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io::{self, BufRead, Read};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+
+use super::{
+    reader::{resolve_region, Lines, Query, Records},
+    Reader,
+};
+use crate::lazy;
+
+/// An indexed GFF reader.
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: Box<dyn BinningIndex>,
+}
+
+impl<R> IndexedReader<R> {
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &dyn BinningIndex {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: BufRead,
+{
+    /// Reads a raw GFF line.
+    pub fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        self.inner.read_line(buf)
+    }
+
+    /// Returns an iterator over lines starting from the current stream position.
+    pub fn lines(&mut self) -> Lines<'_, R> {
+        self.inner.lines()
+    }
+
+    /// Reads a single line without eagerly decoding it.
+    pub fn read_lazy_line(&mut self, line: &mut lazy::Line) -> io::Result<usize> {
+        self.inner.read_lazy_line(line)
+    }
+
+    /// Returns an iterator over records starting from the current stream position.
+    pub fn records(&mut self) -> Records<'_, R> {
+        self.inner.records()
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    /// Returns an iterator over lazy records that intersect the given region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_gff as gff;
+    ///
+    /// let mut reader = gff::io::indexed_reader::Builder::default()
+    ///     .build_from_path("annotations.gff3.gz")?;
+    ///
+    /// let region = "sq0:8-13".parse()?;
+    ///
+    /// for result in reader.query(&region)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query(&mut self, region: &Region) -> io::Result<Query<'_, R>> {
+        let header = self
+            .index
+            .header()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
+
+        let reference_sequence_id = resolve_region(header, region)?;
+        let chunks = self.index.query(reference_sequence_id, region.interval())?;
+
+        Ok(Query::new(
+            self.inner.get_mut(),
+            chunks,
+            region.name().to_vec(),
+            region.interval(),
+        ))
+    }
+}
+
+impl<R> IndexedReader<bgzf::Reader<R>>
+where
+    R: Read,
+{
+    /// Creates an indexed GFF reader.
+    pub fn new<I>(inner: R, index: I) -> Self
+    where
+        I: BinningIndex + 'static,
+    {
+        Self {
+            inner: Reader::new(bgzf::Reader::new(inner)),
+            index: Box::new(index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use noodles_core::Position;
+    use noodles_csi::binning_index::index::{header, reference_sequence::bin::Chunk};
+    use noodles_tabix as tabix;
+
+    use super::*;
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        const LINES: [&str; 4] = [
+            "sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tID=gene0",
+            "sq0\tNOODLES\tgene\t21\t34\t.\t+\t.\tID=gene1",
+            "sq1\tNOODLES\tgene\t5\t8\t.\t+\t.\tID=gene2",
+            "sq1\tNOODLES\tgene\t13\t21\t.\t+\t.\tID=gene3",
+        ];
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writeln!(writer, "##gff-version 3")?;
+
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(header::Builder::gff().build());
+
+        for line in LINES {
+            let record: crate::Record = line.parse()?;
+
+            let start_position = writer.virtual_position();
+            writeln!(writer, "{line}")?;
+            let end_position = writer.virtual_position();
+
+            indexer.add_record(
+                record.reference_sequence_name(),
+                record.start(),
+                record.end(),
+                Chunk::new(start_position, end_position),
+            )?;
+        }
+
+        let index = indexer.build();
+        let src = writer.finish()?;
+
+        let mut reader = IndexedReader::new(Cursor::new(src), index);
+
+        let region = "sq1:10-20".parse()?;
+        let actual: Vec<_> = reader
+            .query(&region)?
+            .map(|result| {
+                result.and_then(|record| {
+                    record
+                        .start()
+                        .map(|start| (record.reference_sequence_name().to_string(), start))
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        let expected = [(String::from("sq1"), Position::try_from(13)?)];
+        assert_eq!(actual, expected);
+
+        let region = "sq0".parse()?;
+        assert_eq!(reader.query(&region)?.count(), 2);
+
+        let region = "sq2".parse()?;
+        assert!(reader.query(&region).is_err());
+
+        Ok(())
+    }
+}
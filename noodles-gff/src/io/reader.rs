@@ -2,9 +2,10 @@
 
 mod lazy_line;
 mod lines;
+mod query;
 mod records;
 
-pub use self::{lines::Lines, records::Records};
+pub use self::{lines::Lines, query::Query, records::Records};
 
 use std::{
     io::{self, BufRead, Read, Seek},
@@ -14,6 +15,7 @@ use std::{
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_csi::{self as csi, BinningIndex};
+use noodles_fasta as fasta;
 
 pub(crate) use self::lazy_line::read_lazy_line;
 use crate::{lazy, Record};
@@ -118,8 +120,8 @@ where
 
     /// Returns an iterator over lines starting from the current stream position.
     ///
-    /// When using this, the caller is responsible to stop reading at either EOF or when the
-    /// `FASTA` directive is read, whichever comes first.
+    /// The iterator stops at either EOF or after the `FASTA` directive is read, whichever comes
+    /// first. The bundled reference sequences can then be read using [`Self::fasta_reader`].
     ///
     /// Unlike [`Self::read_line`], each line is parsed as a [`crate::Line`].
     ///
@@ -200,6 +202,40 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self.lines())
     }
+
+    /// Returns a FASTA reader for the bundled reference sequences.
+    ///
+    /// A GFF3 file can end with a `FASTA` directive followed by reference sequences in the FASTA
+    /// format. This first skips any lines up to and including the `FASTA` directive, so it can be
+    /// called either before or after reading records (see [`Self::records`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff as gff;
+    ///
+    /// let data = b"sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0\n##FASTA\n>sq0\nACGT\n";
+    /// let mut reader = gff::io::Reader::new(&data[..]);
+    ///
+    /// for result in reader.records() {
+    ///     let _record = result?;
+    /// }
+    ///
+    /// let mut fasta_reader = reader.fasta_reader()?;
+    /// let mut records = fasta_reader.records();
+    ///
+    /// let record = records.next().transpose()?.expect("missing record");
+    /// assert_eq!(record.name(), b"sq0");
+    /// assert_eq!(record.sequence().as_ref(), b"ACGT");
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn fasta_reader(&mut self) -> io::Result<fasta::io::Reader<&mut R>> {
+        skip_to_fasta(&mut self.inner)?;
+        Ok(fasta::io::Reader::new(&mut self.inner))
+    }
 }
 
 impl<R> Reader<bgzf::Reader<R>>
@@ -243,19 +279,7 @@ where
             .header()
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index header"))?;
 
-        let region_name = str::from_utf8(region.name())
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-
-        let reference_sequence_id = header
-            .reference_sequence_names()
-            .get_index_of(region_name)
-            .ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "missing reference sequence name",
-                )
-            })?;
-
+        let reference_sequence_id = resolve_region(header, region)?;
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
         let records = csi::io::Query::new(&mut self.inner, chunks)
@@ -273,6 +297,24 @@ where
     }
 }
 
+pub(super) fn resolve_region(
+    header: &csi::binning_index::index::Header,
+    region: &Region,
+) -> io::Result<usize> {
+    let region_name = str::from_utf8(region.name())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+    header
+        .reference_sequence_names()
+        .get_index_of(region_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "missing reference sequence name",
+            )
+        })
+}
+
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
 where
     R: BufRead,
@@ -297,6 +339,30 @@ where
     }
 }
 
+fn skip_to_fasta<R>(reader: &mut R) -> io::Result<()>
+where
+    R: BufRead,
+{
+    const DEFINITION_PREFIX: u8 = b'>';
+    const START_OF_FASTA: &str = "##FASTA";
+
+    let mut buf = String::new();
+
+    loop {
+        match reader.fill_buf()?.first() {
+            None | Some(&DEFINITION_PREFIX) => return Ok(()),
+            _ => {}
+        }
+
+        buf.clear();
+        read_line(reader, &mut buf)?;
+
+        if buf.trim_end() == START_OF_FASTA {
+            return Ok(());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -344,6 +410,22 @@ ACGT
         Ok(())
     }
 
+    #[test]
+    fn test_skip_to_fasta() -> io::Result<()> {
+        fn t(mut reader: &[u8], expected: &[u8]) -> io::Result<()> {
+            skip_to_fasta(&mut reader)?;
+            assert_eq!(reader, expected);
+            Ok(())
+        }
+
+        t(b"##gff-version 3\n##FASTA\n>sq0\nACGT\n", b">sq0\nACGT\n")?;
+        t(b">sq0\nACGT\n", b">sq0\nACGT\n")?;
+        t(b"##FASTA\n", b"")?;
+        t(b"##gff-version 3\n", b"")?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         fn t(buf: &mut String, mut reader: &[u8], expected: &str) -> io::Result<()> {
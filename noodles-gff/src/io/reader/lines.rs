@@ -1,18 +1,18 @@
 use std::io::{self, BufRead};
 
-use crate::Line;
+use crate::{Directive, Line};
 
 use super::Reader;
 
 /// An iterator over lines of a GFF reader.
 ///
-/// When using this, the caller is responsible to stop reading at either EOF or when the `FASTA`
-/// directive is read, whichever comes first.
+/// This stops at either EOF or after the `FASTA` directive is read, whichever comes first.
 ///
 /// This is created by calling [`Reader::lines`].
 pub struct Lines<'a, R> {
     inner: &'a mut Reader<R>,
     line_buf: String,
+    is_eof: bool,
 }
 
 impl<'a, R> Lines<'a, R>
@@ -23,6 +23,7 @@ where
         Self {
             inner,
             line_buf: String::new(),
+            is_eof: false,
         }
     }
 }
@@ -34,15 +35,49 @@ where
     type Item = io::Result<Line>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        if self.is_eof {
+            return None;
+        }
+
         self.line_buf.clear();
 
         match self.inner.read_line(&mut self.line_buf) {
             Ok(0) => None,
             Ok(_) => match self.line_buf.parse() {
-                Ok(line) => Some(Ok(line)),
+                Ok(line) => {
+                    if matches!(line, Line::Directive(Directive::StartOfFasta)) {
+                        self.is_eof = true;
+                    }
+
+                    Some(Ok(line))
+                }
                 Err(e) => Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
             },
             Err(e) => Some(Err(e)),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_with_fasta_directive() -> io::Result<()> {
+        let data = b"\
+##gff-version 3
+sq0\tNOODLES\tgene\t8\t13\t.\t+\t.\tgene_id=ndls0;gene_name=gene0
+##FASTA
+>sq0
+ACGT
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let lines: Vec<_> = reader.lines().collect::<io::Result<_>>()?;
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2], Line::Directive(Directive::StartOfFasta));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::Reader;
+use crate::lazy;
+
+/// An iterator over lazy records of a GFF reader that intersect a given region.
+///
+/// This is created by calling [`crate::io::IndexedReader::query`].
+pub struct Query<'r, R> {
+    reader: Reader<csi::io::Query<'r, R>>,
+    reference_sequence_name: Vec<u8>,
+    interval: Interval,
+    line: lazy::Line,
+}
+
+impl<'r, R> Query<'r, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(crate) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        reference_sequence_name: Vec<u8>,
+        interval: Interval,
+    ) -> Self {
+        Self {
+            reader: Reader::new(csi::io::Query::new(reader, chunks)),
+            reference_sequence_name,
+            interval,
+            line: lazy::Line::default(),
+        }
+    }
+}
+
+impl<'r, R> Iterator for Query<'r, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<lazy::Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_lazy_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    let lazy::Line::Record(record) = &self.line else {
+                        continue;
+                    };
+
+                    match intersects(record, &self.reference_sequence_name, self.interval) {
+                        Ok(true) => return Some(Ok(record.clone())),
+                        Ok(false) => {}
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn intersects(
+    record: &lazy::Record,
+    reference_sequence_name: &[u8],
+    region_interval: Interval,
+) -> io::Result<bool> {
+    let name = record.reference_sequence_name();
+
+    let start = record.start()?;
+    let end = record.end()?;
+    let record_interval = Interval::from(start..=end);
+
+    Ok(name.as_bytes() == reference_sequence_name && record_interval.intersects(region_interval))
+}
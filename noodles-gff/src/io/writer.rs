@@ -1,10 +1,23 @@
-use std::io::{self, Write};
+//! GFF writer.
 
-use crate::{Directive, Line, Record};
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    io::{self, Write},
+    mem,
+};
+
+use indexmap::IndexMap;
+use noodles_core::Position;
+
+use crate::{directive::SequenceRegion, Directive, Line, Record};
 
 /// A GFF writer.
 pub struct Writer<W> {
     inner: W,
+    pending_directives: Vec<Directive>,
 }
 
 impl<W> Writer<W>
@@ -20,7 +33,10 @@ where
     /// let writer = gff::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            pending_directives: Vec::new(),
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -64,7 +80,13 @@ where
     /// assert_eq!(&writer.get_ref()[..], &expected[..]);
     /// # Ok::<(), io::Error>(())
     pub fn write_line(&mut self, line: &Line) -> io::Result<()> {
-        writeln!(self.inner, "{line}")
+        match line {
+            Line::Directive(directive) => self.write_directive(directive),
+            _ => {
+                self.write_pending_directives()?;
+                writeln!(self.inner, "{line}")
+            }
+        }
     }
 
     /// Writes a GFF directive.
@@ -84,6 +106,15 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_directive(&mut self, directive: &Directive) -> io::Result<()> {
+        if matches!(directive, Directive::GffVersion(_)) && !self.pending_directives.is_empty() {
+            // A GFF version written as the first line replaces the pending one.
+            writeln!(self.inner, "{directive}")?;
+            self.pending_directives
+                .retain(|d| !matches!(d, Directive::GffVersion(_)));
+            return self.write_pending_directives();
+        }
+
+        self.write_pending_directives()?;
         writeln!(self.inner, "{directive}")
     }
 
@@ -111,6 +142,143 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, record: &Record) -> io::Result<()> {
+        self.write_pending_directives()?;
         writeln!(self.inner, "{record}")
     }
+
+    /// Writes any pending directives.
+    ///
+    /// Directives set by the [`Builder`] are written before the first line. This writes them if
+    /// no lines were written, e.g., when there are no records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_gff::{self as gff, directive::GffVersion};
+    ///
+    /// let mut writer = gff::io::writer::Builder::default()
+    ///     .set_gff_version(GffVersion::default())
+    ///     .build_from_writer(Vec::new());
+    ///
+    /// writer.finish()?;
+    ///
+    /// assert_eq!(writer.get_ref(), b"##gff-version 3\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn finish(&mut self) -> io::Result<()> {
+        self.write_pending_directives()
+    }
+
+    fn write_pending_directives(&mut self) -> io::Result<()> {
+        for directive in mem::take(&mut self.pending_directives) {
+            writeln!(self.inner, "{directive}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the sequence regions spanned by the given records.
+///
+/// This returns a sequence region for each reference sequence, in the order they first appear,
+/// from the minimum start to the maximum end of its records.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_gff::{self as gff, directive::SequenceRegion};
+///
+/// let records: Vec<gff::Record> = vec![
+///     "sq0\t.\tgene\t8\t13\t.\t+\t.\tID=gene0".parse()?,
+///     "sq1\t.\tgene\t1\t5\t.\t+\t.\tID=gene1".parse()?,
+///     "sq0\t.\tgene\t2\t10\t.\t+\t.\tID=gene2".parse()?,
+/// ];
+///
+/// let sequence_regions = gff::io::writer::sequence_regions(&records)?;
+///
+/// assert_eq!(
+///     sequence_regions,
+///     [
+///         SequenceRegion::new(String::from("sq0"), 2, 13),
+///         SequenceRegion::new(String::from("sq1"), 1, 5),
+///     ]
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn sequence_regions<'r, I>(records: I) -> io::Result<Vec<SequenceRegion>>
+where
+    I: IntoIterator<Item = &'r Record>,
+{
+    let mut intervals: IndexMap<&str, (Position, Position)> = IndexMap::new();
+
+    for record in records {
+        let (start, end) = (record.start(), record.end());
+
+        intervals
+            .entry(record.reference_sequence_name())
+            .and_modify(|(s, e)| {
+                *s = (*s).min(start);
+                *e = (*e).max(end);
+            })
+            .or_insert((start, end));
+    }
+
+    intervals
+        .into_iter()
+        .map(|(name, (start, end))| {
+            let start = position_to_i32(start)?;
+            let end = position_to_i32(end)?;
+            Ok(SequenceRegion::new(name.into(), start, end))
+        })
+        .collect()
+}
+
+fn position_to_i32(position: Position) -> io::Result<i32> {
+    i32::try_from(usize::from(position)).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::directive::GffVersion;
+
+    #[test]
+    fn test_write_with_pending_directives() -> io::Result<()> {
+        let sequence_regions = vec![SequenceRegion::new(String::from("sq0"), 1, 8)];
+
+        let mut writer = Builder::default()
+            .set_gff_version(GffVersion::default())
+            .set_sequence_regions(sequence_regions.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_record(&Record::default())?;
+        writer.write_directive(&Directive::ForwardReferencesAreResolved)?;
+        writer.finish()?;
+
+        let expected = b"##gff-version 3
+##sequence-region sq0 1 8
+.\t.\t.\t1\t1\t.\t.\t.\t.
+###
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut writer = Builder::default()
+            .set_gff_version(GffVersion::default())
+            .set_sequence_regions(sequence_regions)
+            .build_from_writer(Vec::new());
+
+        writer.write_line(&Line::Directive(Directive::GffVersion(
+            "3.1.26".parse().unwrap(),
+        )))?;
+
+        let expected = b"##gff-version 3.1.26
+##sequence-region sq0 1 8
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,102 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use super::Writer;
+use crate::{
+    directive::{GffVersion, SequenceRegion},
+    Directive,
+};
+
+/// A GFF writer builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    gff_version: Option<GffVersion>,
+    sequence_regions: Vec<SequenceRegion>,
+}
+
+impl Builder {
+    /// Sets the GFF version to write.
+    ///
+    /// The `gff-version` directive is written before the first line. If the first line written is
+    /// itself a `gff-version` directive, it is used instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, directive::GffVersion};
+    /// let builder = gff::io::writer::Builder::default().set_gff_version(GffVersion::default());
+    /// ```
+    pub fn set_gff_version(mut self, gff_version: GffVersion) -> Self {
+        self.gff_version = Some(gff_version);
+        self
+    }
+
+    /// Sets the sequence regions to write.
+    ///
+    /// A `sequence-region` directive is written for each sequence region before the first line,
+    /// after the `gff-version` directive. See [`super::sequence_regions`] to derive them from
+    /// records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, directive::SequenceRegion};
+    ///
+    /// let builder = gff::io::writer::Builder::default()
+    ///     .set_sequence_regions(vec![SequenceRegion::new(String::from("sq0"), 1, 8)]);
+    /// ```
+    pub fn set_sequence_regions(mut self, sequence_regions: Vec<SequenceRegion>) -> Self {
+        self.sequence_regions = sequence_regions;
+        self
+    }
+
+    /// Builds a GFF writer from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_gff as gff;
+    /// let writer = gff::io::writer::Builder::default().build_from_path("out.gff3")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, dst: P) -> io::Result<Writer<BufWriter<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        File::create(dst)
+            .map(BufWriter::new)
+            .map(|file| self.build_from_writer(file))
+    }
+
+    /// Builds a GFF writer from a writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let writer = gff::io::writer::Builder::default().build_from_writer(Vec::new());
+    /// ```
+    pub fn build_from_writer<W>(self, writer: W) -> Writer<W>
+    where
+        W: Write,
+    {
+        let directives = self
+            .gff_version
+            .map(Directive::GffVersion)
+            .into_iter()
+            .chain(
+                self.sequence_regions
+                    .into_iter()
+                    .map(Directive::SequenceRegion),
+            )
+            .collect();
+
+        Writer {
+            inner: writer,
+            pending_directives: directives,
+        }
+    }
+}
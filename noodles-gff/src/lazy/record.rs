@@ -10,7 +10,8 @@ use noodles_core::Position;
 
 pub use self::attributes::Attributes;
 pub(crate) use self::fields::Fields;
-use crate::record::Strand;
+use super::Line;
+use crate::{io::reader::read_lazy_line, record::Strand};
 
 /// An immutable, lazily-evalulated GFF record.
 #[derive(Clone, Default, Eq, PartialEq)]
@@ -79,8 +80,57 @@ impl fmt::Debug for Record {
     }
 }
 
+impl TryFrom<&crate::Record> for Record {
+    type Error = io::Error;
+
+    fn try_from(record: &crate::Record) -> Result<Self, Self::Error> {
+        let src = format!("{record}\n");
+        let mut line = Line::default();
+
+        read_lazy_line(&mut src.as_bytes(), &mut line)?;
+
+        match line {
+            Line::Record(record) => Ok(record),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidData, "invalid record")),
+        }
+    }
+}
+
 impl From<Record> for String {
     fn from(record: Record) -> Self {
         record.0.buf
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from_record_for_record() -> io::Result<()> {
+        use crate::record::attributes::field::{tag, Value};
+
+        let mut record = crate::Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_start(Position::MIN)
+            .set_end(Position::MIN)
+            .build();
+
+        record
+            .attributes_mut()
+            .insert(String::from(tag::NAME), Value::from("nd;ls"));
+
+        let lazy_record = Record::try_from(&record)?;
+        assert_eq!(lazy_record.reference_sequence_name(), "sq0");
+        assert_eq!(crate::Record::try_from(&lazy_record)?, record);
+
+        let mut record = crate::Record::default();
+        *record.reference_sequence_name_mut() = String::from("#sq0");
+        assert!(matches!(
+            Record::try_from(&record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
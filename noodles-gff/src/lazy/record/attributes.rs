@@ -2,7 +2,7 @@
 
 pub mod field;
 
-use std::{fmt, io, iter};
+use std::{borrow::Cow, fmt, io, iter};
 
 use self::field::{parse_field, Value};
 
@@ -20,6 +20,8 @@ impl<'a> Attributes<'a> {
     }
 
     /// Returns the value of the given tag.
+    ///
+    /// The tags of the fields are percent-decoded before being compared to the given tag.
     pub fn get(&self, tag: &str) -> Option<io::Result<Value<'_>>> {
         for result in self.iter() {
             match result {
@@ -36,7 +38,9 @@ impl<'a> Attributes<'a> {
     }
 
     /// Returns an iterator over all tag-value pairs.
-    pub fn iter(&self) -> impl Iterator<Item = io::Result<(&str, Value<'_>)>> {
+    ///
+    /// Tags and values are percent-decoded.
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<(Cow<'_, str>, Value<'_>)>> {
         let mut src = self.0;
 
         iter::from_fn(move || {
@@ -86,6 +90,9 @@ mod tests {
         let attributes = Attributes::new("gene_id=ndls0;gene_name=gene0");
         assert!(attributes.get("gene_name").is_some());
         assert!(attributes.get("comment").is_none());
+
+        let attributes = Attributes::new("%25s=ndls0");
+        assert!(attributes.get("%s").is_some());
     }
 
     #[test]
@@ -96,8 +103,16 @@ mod tests {
         let attributes = Attributes::new("gene_id=ndls0;gene_name=gene0");
         let actual: Vec<_> = attributes.iter().collect::<Result<_, _>>()?;
         let expected = vec![
-            ("gene_id", Value::String("ndls0")),
-            ("gene_name", Value::String("gene0")),
+            (Cow::from("gene_id"), Value::String(Cow::from("ndls0"))),
+            (Cow::from("gene_name"), Value::String(Cow::from("gene0"))),
+        ];
+        assert_eq!(actual, expected);
+
+        let attributes = Attributes::new("%25s=nd%3Bls;Note=a%2Cb");
+        let actual: Vec<_> = attributes.iter().collect::<Result<_, _>>()?;
+        let expected = vec![
+            (Cow::from("%s"), Value::String(Cow::from("nd;ls"))),
+            (Cow::from("Note"), Value::String(Cow::from("a,b"))),
         ];
         assert_eq!(actual, expected);
 
@@ -2,12 +2,12 @@
 
 mod value;
 
-use std::io;
+use std::{borrow::Cow, io};
 
 use self::value::parse_value;
 pub use self::value::Value;
 
-pub(super) fn parse_field<'a>(buf: &mut &'a str) -> io::Result<(&'a str, Value<'a>)> {
+pub(super) fn parse_field<'a>(buf: &mut &'a str) -> io::Result<(Cow<'a, str>, Value<'a>)> {
     const DELIMITER: u8 = b';';
     const SEPARATOR: char = '=';
 
@@ -21,11 +21,45 @@ pub(super) fn parse_field<'a>(buf: &mut &'a str) -> io::Result<(&'a str, Value<'
 
     *buf = rest;
 
-    let (key, raw_value) = raw_field
+    let (raw_key, raw_value) = raw_field
         .split_once(SEPARATOR)
         .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid field"))?;
 
-    let value = parse_value(raw_value);
+    let key = percent_decode(raw_key)?;
+    let value = parse_value(raw_value)?;
 
     Ok((key, value))
 }
+
+fn percent_decode(s: &str) -> io::Result<Cow<'_, str>> {
+    use crate::record::attributes::field::percent_decode;
+    percent_decode(s).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_field() -> io::Result<()> {
+        let mut src = "gene_id=ndls0;%25s=13%2C21";
+
+        let (key, value) = parse_field(&mut src)?;
+        assert_eq!(key, "gene_id");
+        assert_eq!(value, Value::String(Cow::from("ndls0")));
+
+        let (key, value) = parse_field(&mut src)?;
+        assert_eq!(key, "%s");
+        assert_eq!(value, Value::String(Cow::from("13,21")));
+
+        assert!(src.is_empty());
+
+        let mut src = "gene_id";
+        assert!(matches!(
+            parse_field(&mut src),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
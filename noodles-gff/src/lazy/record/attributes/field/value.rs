@@ -1,30 +1,36 @@
 mod array;
 
+use std::{borrow::Cow, io, iter};
+
 use self::array::Array;
+use super::percent_decode;
 
 /// A raw GFF record attributes field value.
 #[derive(Debug, Eq, PartialEq)]
 pub enum Value<'a> {
     /// A string.
-    String(&'a str),
+    String(Cow<'a, str>),
     /// An array.
     Array(Array<'a>),
 }
 
-impl<'a> AsRef<str> for Value<'a> {
-    fn as_ref(&self) -> &str {
+impl<'a> Value<'a> {
+    /// Returns an iterator over the percent-decoded values.
+    ///
+    /// A string value is a single value.
+    pub fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Cow<'a, str>>> + '_> {
         match self {
-            Value::String(s) => s,
-            Value::Array(array) => array.as_ref(),
+            Self::String(s) => Box::new(iter::once(Ok(s.clone()))),
+            Self::Array(array) => Box::new(array.iter()),
         }
     }
 }
 
-pub(super) fn parse_value(s: &str) -> Value<'_> {
+pub(super) fn parse_value(s: &str) -> io::Result<Value<'_>> {
     if is_array(s) {
-        Value::Array(Array::new(s))
+        Ok(Value::Array(Array::new(s)))
     } else {
-        Value::String(s)
+        percent_decode(s).map(Value::String)
     }
 }
 
@@ -38,9 +44,24 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_value() {
-        assert_eq!(parse_value("ndls"), Value::String("ndls"));
-        assert_eq!(parse_value("nd,ls"), Value::Array(Array::new("nd,ls")));
+    fn test_iter() -> io::Result<()> {
+        let value = Value::String(Cow::from("nd;ls"));
+        let actual: Vec<_> = value.iter().collect::<io::Result<_>>()?;
+        assert_eq!(actual, ["nd;ls"]);
+
+        let value = Value::Array(Array::new("nd%3Bls,ndls"));
+        let actual: Vec<_> = value.iter().collect::<io::Result<_>>()?;
+        assert_eq!(actual, ["nd;ls", "ndls"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_value() -> io::Result<()> {
+        assert_eq!(parse_value("ndls")?, Value::String(Cow::from("ndls")));
+        assert_eq!(parse_value("nd%3Bls")?, Value::String(Cow::from("nd;ls")));
+        assert_eq!(parse_value("nd,ls")?, Value::Array(Array::new("nd,ls")));
+        Ok(())
     }
 
     #[test]
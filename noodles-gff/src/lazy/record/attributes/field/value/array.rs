@@ -1,4 +1,6 @@
-use std::fmt;
+use std::{borrow::Cow, fmt, io};
+
+use super::percent_decode;
 
 /// A raw GFF record attributes field array value.
 #[derive(Eq, PartialEq)]
@@ -9,10 +11,10 @@ impl<'a> Array<'a> {
         Self(s)
     }
 
-    /// Returns an iterator over values.
-    pub fn iter(&self) -> impl Iterator<Item = &'a str> {
+    /// Returns an iterator over the percent-decoded values.
+    pub fn iter(&self) -> impl Iterator<Item = io::Result<Cow<'a, str>>> {
         const DELIMITER: char = ',';
-        self.0.split(DELIMITER)
+        self.0.split(DELIMITER).map(percent_decode)
     }
 }
 
@@ -24,7 +26,14 @@ impl<'a> AsRef<str> for Array<'a> {
 
 impl<'a> fmt::Debug for Array<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_list().entries(self.iter()).finish()
+        let mut formatter = f.debug_list();
+
+        for result in self.iter() {
+            let value = result.map_err(|_| fmt::Error)?;
+            formatter.entry(&value);
+        }
+
+        formatter.finish()
     }
 }
 
@@ -33,9 +42,15 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_iter() {
+    fn test_iter() -> io::Result<()> {
         let array = Array::new("nd,ls");
-        let actual: Vec<_> = array.iter().collect();
+        let actual: Vec<_> = array.iter().collect::<io::Result<_>>()?;
         assert_eq!(actual, ["nd", "ls"]);
+
+        let array = Array::new("nd%2Cls,%3D");
+        let actual: Vec<_> = array.iter().collect::<io::Result<_>>()?;
+        assert_eq!(actual, ["nd,ls", "="]);
+
+        Ok(())
     }
 }
@@ -35,10 +35,12 @@
 pub mod r#async;
 
 pub mod directive;
+pub mod hierarchy;
 pub mod io;
 pub mod lazy;
 pub mod line;
 pub mod record;
+pub mod sort;
 
 pub use self::{directive::Directive, line::Line, record::Record};
 
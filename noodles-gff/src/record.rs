@@ -7,17 +7,25 @@ mod phase;
 pub mod strand;
 
 pub use self::{
-    attributes::Attributes, builder::Builder, field::Field, phase::Phase, strand::Strand,
+    attributes::Attributes,
+    builder::{BuildError, Builder},
+    field::Field,
+    phase::Phase,
+    strand::Strand,
 };
 
-use std::{error, fmt, num, str::FromStr};
+use std::{error, fmt, io, num, str::FromStr};
 
 use noodles_core::Position;
 
+use crate::lazy;
+
 pub(crate) const MISSING_FIELD: &str = ".";
 const FIELD_DELIMITER: char = '\t';
 const MAX_FIELDS: usize = 9;
 
+const CDS: &str = "CDS";
+
 /// A GFF record.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Record {
@@ -63,6 +71,21 @@ impl Record {
         &self.reference_sequence_name
     }
 
+    /// Returns a mutable reference to the reference sequence name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.reference_sequence_name_mut() = String::from("sq0");
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// ```
+    pub fn reference_sequence_name_mut(&mut self) -> &mut String {
+        &mut self.reference_sequence_name
+    }
+
     /// Returns the source of the record.
     ///
     /// # Examples
@@ -76,6 +99,21 @@ impl Record {
         &self.source
     }
 
+    /// Returns a mutable reference to the source.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.source_mut() = String::from("NOODLES");
+    /// assert_eq!(record.source(), "NOODLES");
+    /// ```
+    pub fn source_mut(&mut self) -> &mut String {
+        &mut self.source
+    }
+
     /// Returns the feature type of the record.
     ///
     /// # Examples
@@ -89,6 +127,21 @@ impl Record {
         &self.ty
     }
 
+    /// Returns a mutable reference to the feature type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.type_mut() = String::from("gene");
+    /// assert_eq!(record.ty(), "gene");
+    /// ```
+    pub fn type_mut(&mut self) -> &mut String {
+        &mut self.ty
+    }
+
     /// Returns the start position of the record.
     ///
     /// This position is 1-based, inclusive.
@@ -105,6 +158,23 @@ impl Record {
         self.start
     }
 
+    /// Returns a mutable reference to the start position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.start_mut() = Position::try_from(8)?;
+    /// assert_eq!(usize::from(record.start()), 8);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn start_mut(&mut self) -> &mut Position {
+        &mut self.start
+    }
+
     /// Returns the end position of the record.
     ///
     /// This position is 1-based, inclusive.
@@ -121,6 +191,23 @@ impl Record {
         self.end
     }
 
+    /// Returns a mutable reference to the end position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.end_mut() = Position::try_from(13)?;
+    /// assert_eq!(usize::from(record.end()), 13);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn end_mut(&mut self) -> &mut Position {
+        &mut self.end
+    }
+
     /// Returns the score of the record.
     ///
     /// # Examples
@@ -134,6 +221,21 @@ impl Record {
         self.score
     }
 
+    /// Returns a mutable reference to the score.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.score_mut() = Some(21.0);
+    /// assert_eq!(record.score(), Some(21.0));
+    /// ```
+    pub fn score_mut(&mut self) -> &mut Option<f32> {
+        &mut self.score
+    }
+
     /// Returns the strand of the record.
     ///
     /// # Examples
@@ -141,12 +243,27 @@ impl Record {
     /// ```
     /// use noodles_gff::{self as gff, record::Strand};
     /// let record = gff::Record::default();
-    /// assert_eq!(record.strand(), Strand::None);
+    /// assert_eq!(record.strand(), Strand::Both);
     /// ```
     pub fn strand(&self) -> Strand {
         self.strand
     }
 
+    /// Returns a mutable reference to the strand.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::Strand};
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.strand_mut() = Strand::Forward;
+    /// assert_eq!(record.strand(), Strand::Forward);
+    /// ```
+    pub fn strand_mut(&mut self) -> &mut Strand {
+        &mut self.strand
+    }
+
     /// Returns the phase of the record.
     ///
     /// # Examples
@@ -160,6 +277,21 @@ impl Record {
         self.phase
     }
 
+    /// Returns a mutable reference to the phase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::Phase};
+    ///
+    /// let mut record = gff::Record::default();
+    /// *record.phase_mut() = Some(Phase::Zero);
+    /// assert_eq!(record.phase(), Some(Phase::Zero));
+    /// ```
+    pub fn phase_mut(&mut self) -> &mut Option<Phase> {
+        &mut self.phase
+    }
+
     /// Returns the attributes of the record.
     ///
     /// # Examples
@@ -172,6 +304,31 @@ impl Record {
     pub fn attributes(&self) -> &Attributes {
         &self.attributes
     }
+
+    /// Returns a mutable reference to the attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{
+    ///     self as gff,
+    ///     record::attributes::field::{tag, Value},
+    /// };
+    ///
+    /// let mut record = gff::Record::default();
+    ///
+    /// record
+    ///     .attributes_mut()
+    ///     .insert(String::from(tag::ID), Value::from("gene0"));
+    ///
+    /// assert_eq!(
+    ///     record.attributes().get(tag::ID).and_then(|value| value.as_string()),
+    ///     Some("gene0")
+    /// );
+    /// ```
+    pub fn attributes_mut(&mut self) -> &mut Attributes {
+        &mut self.attributes
+    }
 }
 
 impl Default for Record {
@@ -307,7 +464,7 @@ impl FromStr for Record {
 
         let phase = parse_string(&mut fields, Field::Phase).and_then(|s| {
             if s == MISSING_FIELD {
-                if ty == "CDS" {
+                if ty == CDS {
                     Err(ParseError::MissingPhase)
                 } else {
                     Ok(None)
@@ -336,6 +493,70 @@ impl FromStr for Record {
     }
 }
 
+impl TryFrom<&lazy::Record> for Record {
+    type Error = io::Error;
+
+    fn try_from(record: &lazy::Record) -> Result<Self, Self::Error> {
+        let ty = record.ty();
+
+        let score = match record.score() {
+            MISSING_FIELD => None,
+            s => s
+                .parse()
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        };
+
+        let phase = match record.phase() {
+            MISSING_FIELD if ty == CDS => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ParseError::MissingPhase,
+                ))
+            }
+            MISSING_FIELD => None,
+            s => s
+                .parse()
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        };
+
+        let mut attributes = Attributes::default();
+
+        for result in record.attributes().iter() {
+            let (tag, value) = result?;
+
+            let value = match value {
+                lazy::record::attributes::field::Value::String(s) => {
+                    attributes::field::Value::from(s.into_owned())
+                }
+                value => value
+                    .iter()
+                    .map(|result| result.map(|s| s.into_owned()))
+                    .collect::<io::Result<Vec<_>>>()
+                    .map(attributes::field::Value::from)?,
+            };
+
+            attributes
+                .entry(tag.into_owned())
+                .and_modify(|v| v.extend(value.iter().cloned()))
+                .or_insert(value);
+        }
+
+        Ok(Self {
+            reference_sequence_name: record.reference_sequence_name().into(),
+            source: record.source().into(),
+            ty: ty.into(),
+            start: record.start()?,
+            end: record.end()?,
+            score,
+            strand: record.strand()?,
+            phase,
+            attributes,
+        })
+    }
+}
+
 fn parse_string<'a, I>(fields: &mut I, field: Field) -> Result<&'a str, ParseError>
 where
     I: Iterator<Item = &'a str>,
@@ -396,6 +617,61 @@ mod tests {
         assert_eq!(s.parse::<Record>(), Err(ParseError::MissingPhase));
     }
 
+    #[test]
+    fn test_try_from_lazy_record_for_record() -> Result<(), Box<dyn std::error::Error>> {
+        use self::attributes::field::{Tag, Value};
+        use crate::io::reader::read_lazy_line;
+
+        fn build_lazy_record(src: &[u8]) -> io::Result<lazy::Record> {
+            let mut line = lazy::Line::default();
+            read_lazy_line(&mut &src[..], &mut line)?;
+
+            match line {
+                lazy::Line::Record(record) => Ok(record),
+                _ => Err(io::Error::from(io::ErrorKind::InvalidData)),
+            }
+        }
+
+        let lazy_record = build_lazy_record(
+            b"sq0\tNOODLES\tCDS\t8\t13\t5.5\t+\t0\tID=cds0;Name=nd%3Bls;Parent=tx0;Parent=tx1\n",
+        )?;
+
+        let actual = Record::try_from(&lazy_record)?;
+
+        let expected = Record::builder()
+            .set_reference_sequence_name(String::from("sq0"))
+            .set_source(String::from("NOODLES"))
+            .set_type(String::from("CDS"))
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_score(5.5)
+            .set_strand(Strand::Forward)
+            .set_phase(Phase::Zero)
+            .set_attributes(
+                [
+                    (Tag::from("ID"), Value::from("cds0")),
+                    (Tag::from("Name"), Value::from("nd;ls")),
+                    (
+                        Tag::from("Parent"),
+                        Value::from(vec![String::from("tx0"), String::from("tx1")]),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+
+        let lazy_record = build_lazy_record(b"sq0\tNOODLES\tCDS\t8\t13\t.\t+\t.\t.\n")?;
+        assert!(matches!(
+            Record::try_from(&lazy_record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_reference_sequence_name() {
         assert_eq!(
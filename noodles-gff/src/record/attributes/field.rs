@@ -88,7 +88,7 @@ pub(super) fn parse_field(s: &str) -> Result<(Tag, Value), ParseError> {
     Ok((key, value))
 }
 
-pub(super) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
+pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
     percent_decode_str(s).decode_utf8()
 }
 
@@ -1,6 +1,40 @@
+use std::{error, fmt};
+
 use noodles_core::Position;
 
-use super::{Attributes, Phase, Record, Strand, MISSING_FIELD};
+use super::{attributes::field::Tag, Attributes, Phase, Record, Strand, CDS, MISSING_FIELD};
+
+/// An error returned when a GFF record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The start is greater than the end.
+    InvalidInterval {
+        /// The start position.
+        start: Position,
+        /// The end position.
+        end: Position,
+    },
+    /// The phase is missing.
+    ///
+    /// The phase is required for CDS features.
+    MissingPhase,
+    /// An attribute tag is invalid.
+    InvalidAttributeTag(Tag),
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInterval { start, end } => {
+                write!(f, "invalid interval: start ({start}) > end ({end})")
+            }
+            Self::MissingPhase => write!(f, "missing phase"),
+            Self::InvalidAttributeTag(tag) => write!(f, "invalid attribute tag: {tag}"),
+        }
+    }
+}
 
 /// A GFF record builder.
 #[derive(Debug)]
@@ -212,6 +246,85 @@ impl Builder {
             attributes: self.attributes,
         }
     }
+
+    /// Validates the fields and builds a GFF record.
+    ///
+    /// The following rules are checked:
+    ///
+    ///   * The start must be less than or equal to the end.
+    ///   * CDS features must have a phase.
+    ///   * Attribute tags must be nonempty, and tags that start with an uppercase letter are
+    ///     reserved for the predefined tags (see [`super::attributes::field::tag`]).
+    ///
+    /// The strand is always valid, as [`Strand`] can only represent valid values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::{self as gff, record::{BuildError, Phase}};
+    ///
+    /// let result = gff::Record::builder()
+    ///     .set_type(String::from("CDS"))
+    ///     .try_build();
+    /// assert_eq!(result, Err(BuildError::MissingPhase));
+    ///
+    /// let record = gff::Record::builder()
+    ///     .set_type(String::from("CDS"))
+    ///     .set_phase(Phase::Zero)
+    ///     .try_build()?;
+    /// assert_eq!(record.phase(), Some(Phase::Zero));
+    /// # Ok::<_, BuildError>(())
+    /// ```
+    pub fn try_build(self) -> Result<Record, BuildError> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    fn validate(&self) -> Result<(), BuildError> {
+        if self.start > self.end {
+            return Err(BuildError::InvalidInterval {
+                start: self.start,
+                end: self.end,
+            });
+        }
+
+        if self.ty == CDS && self.phase.is_none() {
+            return Err(BuildError::MissingPhase);
+        }
+
+        if let Some(tag) = self.attributes.keys().find(|tag| !is_valid_tag(tag)) {
+            return Err(BuildError::InvalidAttributeTag(tag.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+fn is_valid_tag(s: &str) -> bool {
+    use super::attributes::field::tag::{
+        ALIAS, DBXREF, DERIVES_FROM, GAP, ID, IS_CIRCULAR, NAME, NOTE, ONTOLOGY_TERM, PARENT,
+        TARGET,
+    };
+
+    const PREDEFINED_TAGS: [&str; 11] = [
+        ID,
+        NAME,
+        ALIAS,
+        PARENT,
+        TARGET,
+        GAP,
+        DERIVES_FROM,
+        NOTE,
+        DBXREF,
+        ONTOLOGY_TERM,
+        IS_CIRCULAR,
+    ];
+
+    match s.chars().next() {
+        None => false,
+        Some(c) if c.is_ascii_uppercase() => PREDEFINED_TAGS.contains(&s),
+        Some(_) => true,
+    }
 }
 
 impl Default for Builder {
@@ -223,7 +336,7 @@ impl Default for Builder {
             start: Position::MIN,
             end: Position::MIN,
             score: None,
-            strand: Strand::default(),
+            strand: Strand::Both,
             phase: None,
             attributes: Attributes::default(),
         }
@@ -244,7 +357,7 @@ mod tests {
         assert_eq!(record.start, Position::MIN);
         assert_eq!(record.end, Position::MIN);
         assert!(record.score.is_none());
-        assert_eq!(record.strand, Strand::default());
+        assert_eq!(record.strand, Strand::Both);
         assert!(record.phase.is_none());
         assert!(record.attributes.is_empty());
     }
@@ -281,4 +394,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_build() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::record::attributes::field::Value;
+
+        let start = Position::try_from(13)?;
+        let end = Position::try_from(8)?;
+
+        assert_eq!(
+            Builder::new().set_start(start).set_end(end).try_build(),
+            Err(BuildError::InvalidInterval { start, end })
+        );
+
+        assert_eq!(
+            Builder::new().set_type(String::from("CDS")).try_build(),
+            Err(BuildError::MissingPhase)
+        );
+
+        for (tag, is_valid) in [
+            ("ID", true),
+            ("gene_id", true),
+            ("", false),
+            ("Gene", false),
+        ] {
+            let attributes = [(Tag::from(tag), Value::from("ndls0"))]
+                .into_iter()
+                .collect();
+
+            let result = Builder::new().set_attributes(attributes).try_build();
+
+            if is_valid {
+                assert!(result.is_ok());
+            } else {
+                assert_eq!(result, Err(BuildError::InvalidAttributeTag(Tag::from(tag))));
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_valid_tag() {
+        assert!(is_valid_tag("ID"));
+        assert!(is_valid_tag("Parent"));
+        assert!(is_valid_tag("gene_id"));
+        assert!(is_valid_tag("1"));
+        assert!(!is_valid_tag(""));
+        assert!(!is_valid_tag("Gene"));
+    }
 }
@@ -0,0 +1,335 @@
+//! GFF sorting.
+//!
+//! This sorts GFF lines by reference sequence name and start position, which is the order
+//! required by tabix (e.g., `tabix --preset gff`) after compressing the output with bgzip.
+
+pub mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    cmp::Ordering,
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter, Write},
+    mem,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{self, AtomicUsize},
+    vec,
+};
+
+use crate::{io::Reader, Directive, Line, Record};
+
+static TEMP_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A GFF sorter.
+///
+/// Records are buffered in memory. When the number of buffered records reaches the maximum record
+/// count, they are sorted and spilled to a temporary GFF file. On [`Sorter::finish`], the
+/// temporary files and remaining buffered records are merged into a single sorted stream.
+///
+/// Records are ordered by reference sequence name (lexicographically) and then by start position.
+/// The sort is stable, i.e., records that compare equal keep their input order.
+///
+/// Directives are moved before the records, in their input order, except for the `###` and
+/// `FASTA` directives, which are dropped: forward references are trivially resolved at the end
+/// of a sorted file, and a bundled FASTA section must be copied by the caller (see
+/// [`crate::io::Reader::fasta_reader`]). Comments are dropped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_gff as gff;
+///
+/// let data = b"sq1\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+/// sq0\t.\tgene\t13\t21\t.\t+\t.\tID=gene1
+/// sq0\t.\tgene\t5\t8\t.\t+\t.\tID=gene2
+/// ";
+///
+/// let mut reader = gff::io::Reader::new(&data[..]);
+/// let mut sorter = gff::sort::Builder::default().build();
+///
+/// for result in reader.lines() {
+///     let line = result?;
+///     sorter.push(line)?;
+/// }
+///
+/// let mut writer = gff::io::Writer::new(Vec::new());
+///
+/// for result in sorter.finish()? {
+///     let line = result?;
+///     writer.write_line(&line)?;
+/// }
+///
+/// let expected = b"sq0\t.\tgene\t5\t8\t.\t+\t.\tID=gene2
+/// sq0\t.\tgene\t13\t21\t.\t+\t.\tID=gene1
+/// sq1\t.\tgene\t1\t8\t.\t+\t.\tID=gene0
+/// ";
+///
+/// assert_eq!(writer.get_ref(), expected);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Sorter {
+    max_record_count: NonZeroUsize,
+    temp_dir: PathBuf,
+    directives: Vec<Directive>,
+    records: Vec<Record>,
+    chunks: Vec<PathBuf>,
+}
+
+impl Sorter {
+    fn new(max_record_count: NonZeroUsize, temp_dir: PathBuf) -> Self {
+        Self {
+            max_record_count,
+            temp_dir,
+            directives: Vec::new(),
+            records: Vec::new(),
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Adds a line to the sorter.
+    ///
+    /// This may spill the buffered records to a temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let mut sorter = gff::sort::Builder::default().build();
+    /// sorter.push(gff::Line::Record(gff::Record::default()))?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn push(&mut self, line: Line) -> io::Result<()> {
+        match line {
+            Line::Directive(Directive::ForwardReferencesAreResolved | Directive::StartOfFasta) => {}
+            Line::Directive(directive) => self.directives.push(directive),
+            Line::Comment(_) => {}
+            Line::Record(record) => {
+                self.records.push(record);
+
+                if self.records.len() >= self.max_record_count.get() {
+                    self.spill()?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finishes adding lines and returns an iterator over the sorted lines.
+    ///
+    /// Temporary files are removed when the returned iterator is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff as gff;
+    /// let sorter = gff::sort::Builder::default().build();
+    /// let mut lines = sorter.finish()?;
+    /// assert!(lines.next().is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<Lines> {
+        self.records.sort_by(cmp_records);
+
+        let mut lines = Lines {
+            directives: mem::take(&mut self.directives).into_iter(),
+            sources: Vec::with_capacity(self.chunks.len() + 1),
+            heads: Vec::with_capacity(self.chunks.len() + 1),
+            chunks: mem::take(&mut self.chunks),
+        };
+
+        for path in &lines.chunks {
+            let reader = File::open(path).map(BufReader::new).map(Reader::new)?;
+            lines.sources.push(Source::Chunk(reader, String::new()));
+        }
+
+        lines
+            .sources
+            .push(Source::Memory(mem::take(&mut self.records).into_iter()));
+
+        for source in &mut lines.sources {
+            let head = source.next()?;
+            lines.heads.push(head);
+        }
+
+        Ok(lines)
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        self.records.sort_by(cmp_records);
+
+        let (path, file) = create_temp_file(&self.temp_dir)?;
+        self.chunks.push(path);
+
+        let mut writer = BufWriter::new(file);
+
+        for record in self.records.drain(..) {
+            writeln!(writer, "{record}")?;
+        }
+
+        writer.flush()
+    }
+}
+
+impl Drop for Sorter {
+    fn drop(&mut self) {
+        remove_temp_files(&self.chunks);
+    }
+}
+
+/// An iterator over sorted GFF lines.
+///
+/// This is created by calling [`Sorter::finish`].
+pub struct Lines {
+    directives: vec::IntoIter<Directive>,
+    sources: Vec<Source>,
+    heads: Vec<Option<Record>>,
+    chunks: Vec<PathBuf>,
+}
+
+impl Iterator for Lines {
+    type Item = io::Result<Line>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(directive) = self.directives.next() {
+            return Some(Ok(Line::Directive(directive)));
+        }
+
+        // `min_by` returns the first of equal elements, which keeps the merge stable.
+        let (i, _) = self
+            .heads
+            .iter()
+            .enumerate()
+            .filter_map(|(i, head)| head.as_ref().map(|record| (i, record)))
+            .min_by(|(_, a), (_, b)| cmp_records(a, b))?;
+
+        let next_head = match self.sources[i].next() {
+            Ok(head) => head,
+            Err(e) => return Some(Err(e)),
+        };
+
+        mem::replace(&mut self.heads[i], next_head).map(|record| Ok(Line::Record(record)))
+    }
+}
+
+impl Drop for Lines {
+    fn drop(&mut self) {
+        // Readers are closed before their files are removed.
+        self.sources.clear();
+        remove_temp_files(&self.chunks);
+    }
+}
+
+enum Source {
+    Chunk(Reader<BufReader<File>>, String),
+    Memory(vec::IntoIter<Record>),
+}
+
+impl Source {
+    fn next(&mut self) -> io::Result<Option<Record>> {
+        match self {
+            Self::Chunk(reader, buf) => {
+                buf.clear();
+
+                match reader.read_line(buf)? {
+                    0 => Ok(None),
+                    _ => buf
+                        .parse()
+                        .map(Some)
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                }
+            }
+            Self::Memory(records) => Ok(records.next()),
+        }
+    }
+}
+
+fn cmp_records(a: &Record, b: &Record) -> Ordering {
+    a.reference_sequence_name()
+        .cmp(b.reference_sequence_name())
+        .then_with(|| a.start().cmp(&b.start()))
+}
+
+fn create_temp_file(dir: &Path) -> io::Result<(PathBuf, File)> {
+    loop {
+        let id = TEMP_FILE_ID.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = dir.join(format!("noodles-gff-sort-{}-{id}.gff3", process::id()));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn remove_temp_files(paths: &[PathBuf]) {
+    for path in paths {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn test_finish_with_spilled_records() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = env::temp_dir().join(format!("noodles-gff-sort-test-{}", process::id()));
+        fs::create_dir_all(&temp_dir)?;
+
+        let mut sorter = Builder::default()
+            .set_max_record_count(NonZeroUsize::try_from(2)?)
+            .set_temp_dir(&temp_dir)
+            .build();
+
+        let data = b"\
+##gff-version 3
+sq1\t.\tgene\t8\t13\t.\t+\t.\tID=gene0
+#noodles
+sq0\t.\tgene\t21\t34\t.\t+\t.\tID=gene1
+###
+##sequence-region sq0 1 34
+sq0\t.\tgene\t5\t8\t.\t+\t.\tID=gene2
+sq1\t.\tgene\t1\t5\t.\t+\t.\tID=gene3
+sq0\t.\tgene\t5\t13\t.\t+\t.\tID=gene4
+";
+
+        let mut reader = Reader::new(&data[..]);
+
+        for result in reader.lines() {
+            sorter.push(result?)?;
+        }
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 2);
+
+        let lines: Vec<_> = sorter
+            .finish()?
+            .map(|result| result.map(|line| line.to_string()))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            lines,
+            [
+                "##gff-version 3",
+                "##sequence-region sq0 1 34",
+                "sq0\t.\tgene\t5\t8\t.\t+\t.\tID=gene2",
+                "sq0\t.\tgene\t5\t13\t.\t+\t.\tID=gene4",
+                "sq0\t.\tgene\t21\t34\t.\t+\t.\tID=gene1",
+                "sq1\t.\tgene\t1\t5\t.\t+\t.\tID=gene3",
+                "sq1\t.\tgene\t8\t13\t.\t+\t.\tID=gene0",
+            ]
+        );
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 0);
+        fs::remove_dir(&temp_dir)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! GFF sorter builder.
+
+use std::{env, num::NonZeroUsize, path::PathBuf};
+
+use super::Sorter;
+
+const DEFAULT_MAX_RECORD_COUNT: NonZeroUsize = match NonZeroUsize::new(500_000) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// A GFF sorter builder.
+pub struct Builder {
+    max_record_count: NonZeroUsize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the maximum number of records to hold in memory.
+    ///
+    /// When this limit is reached, the buffered records are sorted and written to a temporary
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_gff::sort;
+    ///
+    /// let builder = sort::Builder::default().set_max_record_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_max_record_count(mut self, max_record_count: NonZeroUsize) -> Self {
+        self.max_record_count = max_record_count;
+        self
+    }
+
+    /// Sets the directory of temporary files.
+    ///
+    /// By default, this is [`std::env::temp_dir`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::sort;
+    /// let builder = sort::Builder::default().set_temp_dir("/tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// Builds a GFF sorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_gff::sort;
+    /// let sorter = sort::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Sorter {
+        Sorter::new(
+            self.max_record_count,
+            self.temp_dir.unwrap_or_else(env::temp_dir),
+        )
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            max_record_count: DEFAULT_MAX_RECORD_COUNT,
+            temp_dir: None,
+        }
+    }
+}
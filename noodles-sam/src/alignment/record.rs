@@ -4,6 +4,7 @@ pub mod cigar;
 pub mod data;
 mod flags;
 pub mod mapping_quality;
+pub mod position_map;
 mod quality_scores;
 mod sequence;
 
@@ -123,6 +124,40 @@ pub trait Record {
             None => Some(Ok(start)),
         }
     }
+
+    /// Maps a 0-based read offset to a reference position.
+    ///
+    /// This returns `None` if the record is unmapped or the read offset cannot be mapped. See
+    /// [`position_map::reference_position`].
+    fn reference_position(
+        &self,
+        read_offset: usize,
+        gap: position_map::Gap,
+    ) -> Option<io::Result<Position>> {
+        let start = match self.alignment_start().transpose() {
+            Ok(position) => position?,
+            Err(e) => return Some(Err(e)),
+        };
+
+        position_map::reference_position(start, &self.cigar(), read_offset, gap).transpose()
+    }
+
+    /// Maps a reference position to a 0-based read offset.
+    ///
+    /// This returns `None` if the record is unmapped or the reference position cannot be mapped.
+    /// See [`position_map::read_offset`].
+    fn read_offset(
+        &self,
+        reference_position: Position,
+        gap: position_map::Gap,
+    ) -> Option<io::Result<usize>> {
+        let start = match self.alignment_start().transpose() {
+            Ok(position) => position?,
+            Err(e) => return Some(Err(e)),
+        };
+
+        position_map::read_offset(start, &self.cigar(), reference_position, gap).transpose()
+    }
 }
 
 impl Record for Box<dyn Record> {
@@ -217,4 +252,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reference_position_and_read_offset() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::{
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        };
+
+        use self::position_map::Gap;
+
+        let record = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(8)?)
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 2), Op::new(Kind::Match, 5)]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let position = Position::try_from(9)?;
+
+        assert_eq!(
+            Record::reference_position(&record, 3, Gap::Exclude).transpose()?,
+            Some(position)
+        );
+        assert_eq!(
+            Record::read_offset(&record, position, Gap::Exclude).transpose()?,
+            Some(3)
+        );
+
+        let record = RecordBuf::default();
+        assert!(Record::reference_position(&record, 0, Gap::Exclude).is_none());
+        assert!(Record::read_offset(&record, position, Gap::Exclude).is_none());
+
+        Ok(())
+    }
 }
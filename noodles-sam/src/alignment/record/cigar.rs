@@ -7,6 +7,7 @@ pub mod iter;
 
 use std::io;
 
+use self::op::Kind;
 pub use self::op::Op;
 
 /// Alignment record CIGAR operations.
@@ -49,6 +50,65 @@ pub trait Cigar {
 
         Ok(length)
     }
+
+    /// Calculates the number of clipped bases at the start of the alignment.
+    ///
+    /// This sums the lengths of the leading soft clip (`S`) and hard clip (`H`) operations.
+    fn leading_clip_length(&self) -> io::Result<usize> {
+        let mut length = 0;
+
+        for result in self.iter() {
+            let op = result?;
+
+            if is_clip(op.kind()) {
+                length += op.len();
+            } else {
+                break;
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Calculates the number of clipped bases at the end of the alignment.
+    ///
+    /// This sums the lengths of the trailing soft clip (`S`) and hard clip (`H`) operations.
+    fn trailing_clip_length(&self) -> io::Result<usize> {
+        let mut length = 0;
+
+        for result in self.iter() {
+            let op = result?;
+
+            if is_clip(op.kind()) {
+                length += op.len();
+            } else {
+                length = 0;
+            }
+        }
+
+        Ok(length)
+    }
+
+    /// Calculates the total length of operations of the given kind.
+    ///
+    /// E.g., `total_len(Kind::Insertion)` is the number of inserted bases.
+    fn total_len(&self, kind: Kind) -> io::Result<usize> {
+        let mut length = 0;
+
+        for result in self.iter() {
+            let op = result?;
+
+            if op.kind() == kind {
+                length += op.len();
+            }
+        }
+
+        Ok(length)
+    }
+}
+
+fn is_clip(kind: Kind) -> bool {
+    matches!(kind, Kind::SoftClip | Kind::HardClip)
 }
 
 impl<'a> IntoIterator for &'a dyn Cigar {
@@ -77,7 +137,6 @@ impl Cigar for Box<dyn Cigar + '_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::alignment::record::cigar::op::Kind;
 
     struct T(Vec<Op>);
 
@@ -132,4 +191,56 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_leading_clip_length() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::HardClip, 2),
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 36),
+            Op::new(Kind::SoftClip, 8),
+        ]);
+
+        assert_eq!(cigar.leading_clip_length()?, 5);
+
+        let cigar: &dyn Cigar = &T(vec![Op::new(Kind::Match, 36)]);
+        assert_eq!(cigar.leading_clip_length()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing_clip_length() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::SoftClip, 3),
+            Op::new(Kind::Match, 36),
+            Op::new(Kind::SoftClip, 8),
+            Op::new(Kind::HardClip, 2),
+        ]);
+
+        assert_eq!(cigar.trailing_clip_length()?, 10);
+
+        let cigar: &dyn Cigar = &T(vec![Op::new(Kind::Match, 36)]);
+        assert_eq!(cigar.trailing_clip_length()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_len() -> io::Result<()> {
+        let cigar: &dyn Cigar = &T(vec![
+            Op::new(Kind::Match, 36),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 4),
+            Op::new(Kind::Insertion, 1),
+        ]);
+
+        assert_eq!(cigar.total_len(Kind::Match)?, 40);
+        assert_eq!(cigar.total_len(Kind::Insertion)?, 3);
+        assert_eq!(cigar.total_len(Kind::Deletion)?, 4);
+        assert_eq!(cigar.total_len(Kind::SoftClip)?, 0);
+
+        Ok(())
+    }
 }
@@ -85,6 +85,39 @@ impl<'a> Value<'a> {
             _ => None,
         }
     }
+
+    /// Returns the value as an array.
+    ///
+    /// Array values are borrowed from the record, and typed values can be iterated without
+    /// copying them into a buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::Value, record_buf::data::field::Value as ValueBuf,
+    /// };
+    ///
+    /// let value_buf = ValueBuf::from(vec![8u8, 13]);
+    /// let value = Value::from(&value_buf);
+    ///
+    /// let values = value
+    ///     .as_array()
+    ///     .and_then(|array| array.as_u8_array())
+    ///     .map(|values| values.iter().collect::<Result<Vec<_>, _>>())
+    ///     .transpose()?;
+    ///
+    /// assert_eq!(values, Some(vec![8, 13]));
+    ///
+    /// assert!(Value::UInt8(0).as_array().is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn as_array(&self) -> Option<&Array<'a>> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> TryFrom<Value<'a>> for crate::alignment::record_buf::data::field::Value {
@@ -38,6 +38,188 @@ impl<'a> Array<'a> {
             Array::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns the values if this is an 8-bit integer array (`B:c`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::Int8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_i8_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_i8_array().is_none());
+    /// ```
+    pub fn as_i8_array(&self) -> Option<&(dyn Values<'a, i8> + 'a)> {
+        match self {
+            Self::Int8(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is an 8-bit unsigned integer array (`B:C`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_u8_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::Int8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_u8_array().is_none());
+    /// ```
+    pub fn as_u8_array(&self) -> Option<&(dyn Values<'a, u8> + 'a)> {
+        match self {
+            Self::UInt8(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit integer array (`B:s`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::Int16(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_i16_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_i16_array().is_none());
+    /// ```
+    pub fn as_i16_array(&self) -> Option<&(dyn Values<'a, i16> + 'a)> {
+        match self {
+            Self::Int16(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit unsigned integer array (`B:S`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::UInt16(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_u16_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_u16_array().is_none());
+    /// ```
+    pub fn as_u16_array(&self) -> Option<&(dyn Values<'a, u16> + 'a)> {
+        match self {
+            Self::UInt16(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit integer array (`B:i`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::Int32(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_i32_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_i32_array().is_none());
+    /// ```
+    pub fn as_i32_array(&self) -> Option<&(dyn Values<'a, i32> + 'a)> {
+        match self {
+            Self::Int32(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit unsigned integer array (`B:I`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::UInt32(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_u32_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_u32_array().is_none());
+    /// ```
+    pub fn as_u32_array(&self) -> Option<&(dyn Values<'a, u32> + 'a)> {
+        match self {
+            Self::UInt32(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a single-precision floating-point array (`B:f`).
+    ///
+    /// The values are borrowed from the record and decoded lazily when iterated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::data::field::value::Array, record_buf::data::field::value::Array as ArrayBuf,
+    /// };
+    ///
+    /// let array_buf = ArrayBuf::Float(vec![0.0]);
+    /// let array = Array::from(&array_buf);
+    /// assert_eq!(array.as_f32_array().map(|values| values.len()), Some(1));
+    ///
+    /// let array_buf = ArrayBuf::UInt8(vec![0]);
+    /// let array = Array::from(&array_buf);
+    /// assert!(array.as_f32_array().is_none());
+    /// ```
+    pub fn as_f32_array(&self) -> Option<&(dyn Values<'a, f32> + 'a)> {
+        match self {
+            Self::Float(values) => Some(values.as_ref()),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> fmt::Debug for Array<'a> {
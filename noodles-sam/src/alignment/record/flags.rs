@@ -1,3 +1,5 @@
+use noodles_core::Strand;
+
 bitflags::bitflags! {
     /// Alignment record flags.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -126,6 +128,48 @@ impl Flags {
         self.contains(Self::MATE_REVERSE_COMPLEMENTED)
     }
 
+    /// Returns the strand of the sequence.
+    ///
+    /// This is [`Strand::Reverse`] if the `REVERSE_COMPLEMENTED` flag is set; otherwise,
+    /// [`Strand::Forward`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Strand;
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::empty().strand(), Strand::Forward);
+    /// assert_eq!(Flags::REVERSE_COMPLEMENTED.strand(), Strand::Reverse);
+    /// ```
+    pub fn strand(self) -> Strand {
+        if self.is_reverse_complemented() {
+            Strand::Reverse
+        } else {
+            Strand::Forward
+        }
+    }
+
+    /// Returns the strand of the mate sequence.
+    ///
+    /// This is [`Strand::Reverse`] if the `MATE_REVERSE_COMPLEMENTED` flag is set; otherwise,
+    /// [`Strand::Forward`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Strand;
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::empty().mate_strand(), Strand::Forward);
+    /// assert_eq!(Flags::MATE_REVERSE_COMPLEMENTED.mate_strand(), Strand::Reverse);
+    /// ```
+    pub fn mate_strand(self) -> Strand {
+        if self.is_mate_reverse_complemented() {
+            Strand::Reverse
+        } else {
+            Strand::Forward
+        }
+    }
+
     /// Returns whether the `FIRST_SEGMENT` flag is set.
     ///
     /// # Examples
@@ -0,0 +1,329 @@
+//! Alignment record read offset and reference position mapping.
+
+use std::io;
+
+use noodles_core::Position;
+
+use super::{cigar::Op, Cigar};
+
+/// The resolution of positions that do not have a counterpart in the other sequence.
+///
+/// When mapping a read offset to a reference position, these are bases in insertions (`I`) and
+/// soft clips (`S`). When mapping a reference position to a read offset, these are positions in
+/// deletions (`D`) and skipped regions (`N`).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Gap {
+    /// Do not map the position.
+    #[default]
+    Exclude,
+    /// Map to the closest aligned position before the gap.
+    Previous,
+    /// Map to the closest aligned position after the gap.
+    Next,
+}
+
+/// Maps a 0-based read offset to a reference position.
+///
+/// The read offset includes soft clipped bases, i.e., it is an index into the record sequence.
+///
+/// This returns `None` if the read offset is out of range or, depending on the given gap
+/// resolution, is not aligned to the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         position_map::{self, Gap},
+///     },
+///     record_buf::Cigar,
+/// };
+///
+/// // 2S3M1I2M
+/// let cigar: Cigar = [
+///     Op::new(Kind::SoftClip, 2),
+///     Op::new(Kind::Match, 3),
+///     Op::new(Kind::Insertion, 1),
+///     Op::new(Kind::Match, 2),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let alignment_start = Position::try_from(8)?;
+///
+/// assert_eq!(
+///     position_map::reference_position(alignment_start, &cigar, 2, Gap::Exclude)?,
+///     Position::new(8)
+/// );
+///
+/// assert!(position_map::reference_position(alignment_start, &cigar, 5, Gap::Exclude)?.is_none());
+///
+/// assert_eq!(
+///     position_map::reference_position(alignment_start, &cigar, 5, Gap::Previous)?,
+///     Position::new(10)
+/// );
+///
+/// assert_eq!(
+///     position_map::reference_position(alignment_start, &cigar, 5, Gap::Next)?,
+///     Position::new(11)
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn reference_position<C>(
+    alignment_start: Position,
+    cigar: &C,
+    read_offset: usize,
+    gap: Gap,
+) -> io::Result<Option<Position>>
+where
+    C: Cigar + ?Sized,
+{
+    let alignment_end = usize::from(alignment_start) + cigar.alignment_span()?;
+
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = 0;
+
+    for result in cigar.iter() {
+        let op = result?;
+        let kind = op.kind();
+        let len = op.len();
+
+        let is_in_op =
+            kind.consumes_read() && (read_position..read_position + len).contains(&read_offset);
+
+        if is_in_op {
+            let position = if kind.consumes_reference() {
+                Some(reference_position + (read_offset - read_position))
+            } else {
+                match gap {
+                    Gap::Exclude => None,
+                    Gap::Previous => (reference_position > usize::from(alignment_start))
+                        .then(|| reference_position - 1),
+                    Gap::Next => (reference_position < alignment_end).then_some(reference_position),
+                }
+            };
+
+            return Ok(position.and_then(Position::new));
+        }
+
+        if kind.consumes_read() {
+            read_position += len;
+        }
+
+        if kind.consumes_reference() {
+            reference_position += len;
+        }
+    }
+
+    Ok(None)
+}
+
+/// Maps a reference position to a 0-based read offset.
+///
+/// The read offset includes soft clipped bases, i.e., it is an index into the record sequence.
+///
+/// This returns `None` if the reference position is outside the alignment or, depending on the
+/// given gap resolution, is not aligned to a read base.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{
+///         cigar::{op::Kind, Op},
+///         position_map::{self, Gap},
+///     },
+///     record_buf::Cigar,
+/// };
+///
+/// // 2S3M1D2M
+/// let cigar: Cigar = [
+///     Op::new(Kind::SoftClip, 2),
+///     Op::new(Kind::Match, 3),
+///     Op::new(Kind::Deletion, 1),
+///     Op::new(Kind::Match, 2),
+/// ]
+/// .into_iter()
+/// .collect();
+///
+/// let alignment_start = Position::try_from(8)?;
+///
+/// assert_eq!(
+///     position_map::read_offset(alignment_start, &cigar, Position::try_from(8)?, Gap::Exclude)?,
+///     Some(2)
+/// );
+///
+/// let position = Position::try_from(11)?;
+/// assert!(position_map::read_offset(alignment_start, &cigar, position, Gap::Exclude)?.is_none());
+/// assert_eq!(
+///     position_map::read_offset(alignment_start, &cigar, position, Gap::Previous)?,
+///     Some(4)
+/// );
+/// assert_eq!(
+///     position_map::read_offset(alignment_start, &cigar, position, Gap::Next)?,
+///     Some(5)
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn read_offset<C>(
+    alignment_start: Position,
+    cigar: &C,
+    reference_position: Position,
+    gap: Gap,
+) -> io::Result<Option<usize>>
+where
+    C: Cigar + ?Sized,
+{
+    let target = usize::from(reference_position);
+
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = 0;
+    let mut last_aligned_read_offset = None;
+
+    let mut ops = cigar.iter();
+
+    while let Some(result) = ops.next() {
+        let op = result?;
+        let kind = op.kind();
+        let len = op.len();
+
+        let is_in_op = kind.consumes_reference()
+            && (reference_position..reference_position + len).contains(&target);
+
+        if is_in_op {
+            if kind.consumes_read() {
+                return Ok(Some(read_position + (target - reference_position)));
+            }
+
+            return match gap {
+                Gap::Exclude => Ok(None),
+                Gap::Previous => Ok(last_aligned_read_offset),
+                Gap::Next => next_aligned_read_offset(ops, read_position),
+            };
+        }
+
+        if kind.consumes_read() {
+            read_position += len;
+        }
+
+        if kind.consumes_reference() {
+            reference_position += len;
+
+            if kind.consumes_read() {
+                last_aligned_read_offset = Some(read_position - 1);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn next_aligned_read_offset<I>(ops: I, mut read_position: usize) -> io::Result<Option<usize>>
+where
+    I: Iterator<Item = io::Result<Op>>,
+{
+    for result in ops {
+        let op = result?;
+        let kind = op.kind();
+
+        if kind.consumes_read() && kind.consumes_reference() {
+            return Ok(Some(read_position));
+        } else if kind.consumes_read() {
+            read_position += op.len();
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{record::cigar::op::Kind, record_buf::Cigar as CigarBuf};
+
+    fn build_cigar(ops: &[(Kind, usize)]) -> CigarBuf {
+        ops.iter().map(|&(kind, len)| Op::new(kind, len)).collect()
+    }
+
+    #[test]
+    fn test_reference_position() -> Result<(), Box<dyn std::error::Error>> {
+        // 1H2S3M1I1D2M3S
+        let cigar = build_cigar(&[
+            (Kind::HardClip, 1),
+            (Kind::SoftClip, 2),
+            (Kind::Match, 3),
+            (Kind::Insertion, 1),
+            (Kind::Deletion, 1),
+            (Kind::Match, 2),
+            (Kind::SoftClip, 3),
+        ]);
+
+        let start = Position::try_from(8)?;
+
+        let t = |read_offset, gap| reference_position(start, &cigar, read_offset, gap);
+
+        assert_eq!(t(0, Gap::Exclude)?, None);
+        assert_eq!(t(0, Gap::Previous)?, None);
+        assert_eq!(t(0, Gap::Next)?, Position::new(8));
+
+        assert_eq!(t(2, Gap::Exclude)?, Position::new(8));
+        assert_eq!(t(4, Gap::Exclude)?, Position::new(10));
+
+        assert_eq!(t(5, Gap::Exclude)?, None);
+        assert_eq!(t(5, Gap::Previous)?, Position::new(10));
+        assert_eq!(t(5, Gap::Next)?, Position::new(11));
+
+        assert_eq!(t(6, Gap::Exclude)?, Position::new(12));
+        assert_eq!(t(7, Gap::Exclude)?, Position::new(13));
+
+        assert_eq!(t(8, Gap::Exclude)?, None);
+        assert_eq!(t(8, Gap::Previous)?, Position::new(13));
+        assert_eq!(t(8, Gap::Next)?, None);
+
+        assert_eq!(t(11, Gap::Exclude)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_offset() -> Result<(), Box<dyn std::error::Error>> {
+        // 1H2S3M1I2N2M3S
+        let cigar = build_cigar(&[
+            (Kind::HardClip, 1),
+            (Kind::SoftClip, 2),
+            (Kind::Match, 3),
+            (Kind::Insertion, 1),
+            (Kind::Skip, 2),
+            (Kind::Match, 2),
+            (Kind::SoftClip, 3),
+        ]);
+
+        let start = Position::try_from(8)?;
+
+        let t = |position: usize, gap| {
+            let position = Position::try_from(position).unwrap();
+            read_offset(start, &cigar, position, gap)
+        };
+
+        assert_eq!(t(7, Gap::Exclude)?, None);
+        assert_eq!(t(7, Gap::Next)?, None);
+
+        assert_eq!(t(8, Gap::Exclude)?, Some(2));
+        assert_eq!(t(10, Gap::Exclude)?, Some(4));
+
+        assert_eq!(t(11, Gap::Exclude)?, None);
+        assert_eq!(t(12, Gap::Previous)?, Some(4));
+        assert_eq!(t(12, Gap::Next)?, Some(6));
+
+        assert_eq!(t(13, Gap::Exclude)?, Some(6));
+        assert_eq!(t(14, Gap::Exclude)?, Some(7));
+
+        assert_eq!(t(15, Gap::Exclude)?, None);
+        assert_eq!(t(15, Gap::Previous)?, None);
+
+        Ok(())
+    }
+}
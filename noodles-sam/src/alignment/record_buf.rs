@@ -1,6 +1,6 @@
 //! Alignment record buffer.
 
-mod builder;
+pub mod builder;
 mod cigar;
 mod convert;
 pub mod data;
@@ -1,8 +1,68 @@
+//! Alignment record buffer builder.
+
+use std::{error, fmt};
+
 use bstr::BString;
 use noodles_core::Position;
 
 use super::{Cigar, Data, Flags, MappingQuality, QualityScores, RecordBuf, Sequence};
 
+/// An error returned when an alignment record buffer fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The reference sequence ID is missing.
+    MissingReferenceSequenceId,
+    /// The alignment start is missing.
+    MissingAlignmentStart,
+    /// The CIGAR is missing.
+    MissingCigar,
+    /// The mate reference sequence ID is missing.
+    MissingMateReferenceSequenceId,
+    /// The read length of the CIGAR does not match the sequence length.
+    MismatchedReadLength {
+        /// The read length of the CIGAR.
+        cigar_read_length: usize,
+        /// The sequence length.
+        sequence_length: usize,
+    },
+    /// The quality scores length does not match the sequence length.
+    MismatchedQualityScoresLength {
+        /// The quality scores length.
+        quality_scores_length: usize,
+        /// The sequence length.
+        sequence_length: usize,
+    },
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequenceId => write!(f, "missing reference sequence ID"),
+            Self::MissingAlignmentStart => write!(f, "missing alignment start"),
+            Self::MissingCigar => write!(f, "missing CIGAR"),
+            Self::MissingMateReferenceSequenceId => {
+                write!(f, "missing mate reference sequence ID")
+            }
+            Self::MismatchedReadLength {
+                cigar_read_length,
+                sequence_length,
+            } => write!(
+                f,
+                "CIGAR read length ({cigar_read_length}) != sequence length ({sequence_length})"
+            ),
+            Self::MismatchedQualityScoresLength {
+                quality_scores_length: len,
+                sequence_length,
+            } => write!(
+                f,
+                "quality scores length ({len}) != sequence length ({sequence_length})"
+            ),
+        }
+    }
+}
+
 /// An alignment record builder.
 #[derive(Debug)]
 pub struct Builder {
@@ -290,6 +350,84 @@ impl Builder {
             data: self.data,
         }
     }
+
+    /// Validates and builds the alignment record.
+    ///
+    /// Unlike [`Self::build`], this checks that the fields are consistent with each other:
+    ///
+    ///   * A mapped record must have a reference sequence ID, alignment start, and CIGAR.
+    ///   * An alignment start requires a reference sequence ID.
+    ///   * A mate alignment start requires a mate reference sequence ID.
+    ///   * If the CIGAR and sequence are both set, the read length of the CIGAR must equal the
+    ///     sequence length.
+    ///   * If the sequence and quality scores are both set, their lengths must be equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::Flags,
+    ///     record_buf::{builder::BuildError, RecordBuf},
+    /// };
+    ///
+    /// let result = RecordBuf::builder().set_flags(Flags::empty()).try_build();
+    /// assert_eq!(result, Err(BuildError::MissingReferenceSequenceId));
+    ///
+    /// let record = RecordBuf::builder().try_build()?;
+    /// assert_eq!(record, RecordBuf::default());
+    /// # Ok::<_, BuildError>(())
+    /// ```
+    pub fn try_build(self) -> Result<RecordBuf, BuildError> {
+        self.validate()?;
+        Ok(self.build())
+    }
+
+    fn validate(&self) -> Result<(), BuildError> {
+        if !self.flags.is_unmapped() {
+            if self.reference_sequence_id.is_none() {
+                return Err(BuildError::MissingReferenceSequenceId);
+            } else if self.alignment_start.is_none() {
+                return Err(BuildError::MissingAlignmentStart);
+            } else if self.cigar.as_ref().is_empty() {
+                return Err(BuildError::MissingCigar);
+            }
+        }
+
+        if self.alignment_start.is_some() && self.reference_sequence_id.is_none() {
+            return Err(BuildError::MissingReferenceSequenceId);
+        }
+
+        if self.mate_alignment_start.is_some() && self.mate_reference_sequence_id.is_none() {
+            return Err(BuildError::MissingMateReferenceSequenceId);
+        }
+
+        let sequence_length = self.sequence.len();
+
+        if !self.cigar.as_ref().is_empty() && sequence_length > 0 {
+            let cigar_read_length = self.cigar.read_length();
+
+            if cigar_read_length != sequence_length {
+                return Err(BuildError::MismatchedReadLength {
+                    cigar_read_length,
+                    sequence_length,
+                });
+            }
+        }
+
+        let quality_scores_length = self.quality_scores.as_ref().len();
+
+        if sequence_length > 0
+            && quality_scores_length > 0
+            && quality_scores_length != sequence_length
+        {
+            return Err(BuildError::MismatchedQualityScoresLength {
+                quality_scores_length,
+                sequence_length,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl Default for Builder {
@@ -332,4 +470,83 @@ mod tests {
         assert!(builder.quality_scores.is_empty());
         assert!(builder.data.is_empty());
     }
+
+    #[test]
+    fn test_try_build() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::record::cigar::{op::Kind, Op};
+
+        let alignment_start = Position::try_from(8)?;
+        let cigar: Cigar = [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 3)]
+            .into_iter()
+            .collect();
+
+        let build_mapped = || {
+            Builder::default()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(alignment_start)
+                .set_cigar(cigar.clone())
+        };
+
+        assert!(build_mapped()
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .try_build()
+            .is_ok());
+
+        assert_eq!(
+            Builder::default()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_cigar(cigar.clone())
+                .try_build(),
+            Err(BuildError::MissingAlignmentStart)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(alignment_start)
+                .try_build(),
+            Err(BuildError::MissingCigar)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_alignment_start(alignment_start)
+                .try_build(),
+            Err(BuildError::MissingReferenceSequenceId)
+        );
+
+        assert_eq!(
+            Builder::default()
+                .set_mate_alignment_start(alignment_start)
+                .try_build(),
+            Err(BuildError::MissingMateReferenceSequenceId)
+        );
+
+        assert_eq!(
+            build_mapped()
+                .set_sequence(Sequence::from(b"ACG"))
+                .try_build(),
+            Err(BuildError::MismatchedReadLength {
+                cigar_read_length: 4,
+                sequence_length: 3,
+            })
+        );
+
+        assert_eq!(
+            build_mapped()
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![45, 35]))
+                .try_build(),
+            Err(BuildError::MismatchedQualityScoresLength {
+                quality_scores_length: 2,
+                sequence_length: 4,
+            })
+        );
+
+        Ok(())
+    }
 }
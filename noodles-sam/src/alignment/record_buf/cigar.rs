@@ -2,7 +2,7 @@
 
 use std::io;
 
-use crate::alignment::record::cigar::Op;
+use crate::alignment::record::cigar::{op::Kind, Op};
 
 /// An alignment record CIGAR operations buffer.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -70,6 +70,107 @@ impl Cigar {
             .filter_map(|op| op.kind().consumes_read().then_some(op.len()))
             .sum()
     }
+
+    /// Calculates the number of clipped bases at the start of the alignment.
+    ///
+    /// This sums the lengths of the leading soft clip (`S`) and hard clip (`H`) operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::HardClip, 2),
+    ///     Op::new(Kind::SoftClip, 3),
+    ///     Op::new(Kind::Match, 36),
+    ///     Op::new(Kind::Insertion, 1),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::SoftClip, 8),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(cigar.leading_clip_length(), 5);
+    /// ```
+    pub fn leading_clip_length(&self) -> usize {
+        self.0
+            .iter()
+            .take_while(|op| is_clip(op.kind()))
+            .map(|op| op.len())
+            .sum()
+    }
+
+    /// Calculates the number of clipped bases at the end of the alignment.
+    ///
+    /// This sums the lengths of the trailing soft clip (`S`) and hard clip (`H`) operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::HardClip, 2),
+    ///     Op::new(Kind::SoftClip, 3),
+    ///     Op::new(Kind::Match, 36),
+    ///     Op::new(Kind::Insertion, 1),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::SoftClip, 8),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(cigar.trailing_clip_length(), 8);
+    /// ```
+    pub fn trailing_clip_length(&self) -> usize {
+        self.0
+            .iter()
+            .rev()
+            .take_while(|op| is_clip(op.kind()))
+            .map(|op| op.len())
+            .sum()
+    }
+
+    /// Calculates the total length of operations of the given kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::HardClip, 2),
+    ///     Op::new(Kind::SoftClip, 3),
+    ///     Op::new(Kind::Match, 36),
+    ///     Op::new(Kind::Insertion, 1),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::SoftClip, 8),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// assert_eq!(cigar.total_len(Kind::Match), 40);
+    /// ```
+    pub fn total_len(&self, kind: Kind) -> usize {
+        self.0
+            .iter()
+            .filter_map(|op| (op.kind() == kind).then_some(op.len()))
+            .sum()
+    }
+}
+
+fn is_clip(kind: Kind) -> bool {
+    matches!(kind, Kind::SoftClip | Kind::HardClip)
 }
 
 impl crate::alignment::record::Cigar for Cigar {
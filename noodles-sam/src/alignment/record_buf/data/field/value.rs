@@ -83,6 +83,25 @@ impl Value {
         }
     }
 
+    /// Returns the value as an array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::from(vec![0u8]);
+    /// assert_eq!(value.as_array().and_then(Array::as_u8_array), Some(&[0][..]));
+    ///
+    /// assert!(Value::from(0).as_array().is_none());
+    /// ```
+    pub fn as_array(&self) -> Option<&Array> {
+        match self {
+            Self::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
     /// Returns whether the value is an integer.
     ///
     /// # Examples
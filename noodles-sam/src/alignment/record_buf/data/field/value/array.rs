@@ -46,6 +46,118 @@ impl Array {
             Self::Float(_) => Subtype::Float,
         }
     }
+
+    /// Returns the values if this is an 8-bit integer array (`B:c`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::Int8(vec![0]).as_i8_array(), Some(&[0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_i8_array().is_none());
+    /// ```
+    pub fn as_i8_array(&self) -> Option<&[i8]> {
+        match self {
+            Self::Int8(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is an 8-bit unsigned integer array (`B:C`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::UInt8(vec![0]).as_u8_array(), Some(&[0][..]));
+    /// assert!(Array::Int8(vec![0]).as_u8_array().is_none());
+    /// ```
+    pub fn as_u8_array(&self) -> Option<&[u8]> {
+        match self {
+            Self::UInt8(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit integer array (`B:s`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::Int16(vec![0]).as_i16_array(), Some(&[0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_i16_array().is_none());
+    /// ```
+    pub fn as_i16_array(&self) -> Option<&[i16]> {
+        match self {
+            Self::Int16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 16-bit unsigned integer array (`B:S`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::UInt16(vec![0]).as_u16_array(), Some(&[0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_u16_array().is_none());
+    /// ```
+    pub fn as_u16_array(&self) -> Option<&[u16]> {
+        match self {
+            Self::UInt16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit integer array (`B:i`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::Int32(vec![0]).as_i32_array(), Some(&[0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_i32_array().is_none());
+    /// ```
+    pub fn as_i32_array(&self) -> Option<&[i32]> {
+        match self {
+            Self::Int32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a 32-bit unsigned integer array (`B:I`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::UInt32(vec![0]).as_u32_array(), Some(&[0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_u32_array().is_none());
+    /// ```
+    pub fn as_u32_array(&self) -> Option<&[u32]> {
+        match self {
+            Self::UInt32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if this is a single-precision floating-point array (`B:f`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::value::Array;
+    /// assert_eq!(Array::Float(vec![0.0]).as_f32_array(), Some(&[0.0][..]));
+    /// assert!(Array::UInt8(vec![0]).as_f32_array().is_none());
+    /// ```
+    pub fn as_f32_array(&self) -> Option<&[f32]> {
+        match self {
+            Self::Float(values) => Some(values),
+            _ => None,
+        }
+    }
 }
 
 impl<'a> From<&'a Array> for crate::alignment::record::data::field::value::Array<'a> {
@@ -12,6 +12,7 @@ use crate::{alignment::RecordBuf, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    line_number: Option<u64>,
 }
 
 impl<R> Reader<R> {
@@ -75,6 +76,7 @@ where
         Self {
             inner,
             buf: Vec::new(),
+            line_number: Some(0),
         }
     }
 
@@ -103,7 +105,7 @@ where
     /// # }
     /// ```
     pub async fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner).await
+        read_header(&mut self.inner, &mut self.line_number).await
     }
 
     /// Reads a record into an alignment record buffer.
@@ -145,7 +147,14 @@ where
         header: &Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, &mut self.buf, header, record).await
+        read_record_buf(
+            &mut self.inner,
+            &mut self.buf,
+            &mut self.line_number,
+            header,
+            record,
+        )
+        .await
     }
 
     /// Returns an (async) stream over alignment record buffers starting from the current (input)
@@ -225,7 +234,13 @@ where
     /// # }
     /// ```
     pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, &mut self.buf, record).await
+        read_record(
+            &mut self.inner,
+            &mut self.buf,
+            &mut self.line_number,
+            record,
+        )
+        .await
     }
 
     /// Returns an (async) stream over records.
@@ -308,6 +323,34 @@ mod tests {
         t(&mut buf, b"noodles\r\n", b"noodles").await?;
         t(&mut buf, b"noodles", b"noodles").await?;
 
+        Ok(())
+    }
+    #[tokio::test]
+    async fn test_read_record_buf_with_invalid_record() -> io::Result<()> {
+        use crate::io::reader::ParseError;
+
+        let data = b"@HD\tVN:1.6
+*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+*\t4\t*\t0\tndls\t*\t*\t0\t0\t*\t*
+";
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header().await?;
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record).await?;
+
+        let e = reader
+            .read_record_buf(&header, &mut record)
+            .await
+            .unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let e = e.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(e.line_number(), Some(3));
+        assert_eq!(e.field_number(), Some(5));
+        assert_eq!(e.value(), "ndls");
+
         Ok(())
     }
 }
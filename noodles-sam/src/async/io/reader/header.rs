@@ -1,8 +1,11 @@
 use tokio::io::{self, AsyncBufRead, AsyncBufReadExt};
 
-use crate::{header, Header};
+use crate::{header, io::reader::ParseError, Header};
 
-pub(super) async fn read_header<R>(reader: &mut R) -> io::Result<Header>
+pub(super) async fn read_header<R>(
+    reader: &mut R,
+    line_number: &mut Option<u64>,
+) -> io::Result<Header>
 where
     R: AsyncBufRead + Unpin,
 {
@@ -10,9 +13,14 @@ where
     let mut buf = Vec::new();
 
     while read_header_line(reader, &mut buf).await? != 0 {
-        parser
-            .parse_partial(&buf)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        *line_number = line_number.map(|n| n + 1);
+
+        parser.parse_partial(&buf).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseError::new(*line_number, None, &buf, e),
+            )
+        })?;
     }
 
     Ok(parser.finish())
@@ -64,7 +72,7 @@ mod tests {
     async fn test_read_header_with_no_header() -> io::Result<()> {
         let data = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
         let mut reader = &data[..];
-        assert!(read_header(&mut reader).await?.is_empty());
+        assert!(read_header(&mut reader, &mut Some(0)).await?.is_empty());
         Ok(())
     }
 
@@ -73,7 +81,7 @@ mod tests {
         let data = "@HD\tVN:1.6\n";
         let mut reader = data.as_bytes();
 
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, &mut Some(0)).await?;
 
         let expected = crate::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -96,7 +104,7 @@ mod tests {
         let data = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n";
         let mut reader = BufReader::with_capacity(16, data.as_bytes());
 
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, &mut Some(0)).await?;
 
         let expected = crate::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
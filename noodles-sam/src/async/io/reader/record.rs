@@ -5,6 +5,7 @@ use crate::Record;
 pub(super) async fn read_record<R>(
     reader: &mut R,
     buf: &mut Vec<u8>,
+    line_number: &mut Option<u64>,
     record: &mut Record,
 ) -> io::Result<usize>
 where
@@ -19,7 +20,7 @@ where
     }
 
     let mut src = &buf[..];
-    crate::io::reader::read_record(&mut src, record)
+    crate::io::reader::read_record(&mut src, line_number, record)
 }
 
 #[cfg(test)]
@@ -33,19 +34,19 @@ mod tests {
 
         let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n"[..];
         let mut record = Record::default();
-        read_record(&mut src, &mut buf, &mut record).await?;
+        read_record(&mut src, &mut buf, &mut Some(0), &mut record).await?;
         assert_eq!(record.fields().buf, b"*4*0255**00**");
         assert_eq!(record.fields().bounds, Bounds::default());
 
         let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\r\n"[..];
         let mut record = Record::default();
-        read_record(&mut src, &mut buf, &mut record).await?;
+        read_record(&mut src, &mut buf, &mut Some(0), &mut record).await?;
         assert_eq!(record.fields().buf, b"*4*0255**00**");
         assert_eq!(record.fields().bounds, Bounds::default());
 
         let mut src = &b"\n"[..];
         assert!(matches!(
-            read_record(&mut src, &mut buf, &mut record).await,
+            read_record(&mut src, &mut buf, &mut Some(0), &mut record).await,
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
 
@@ -6,21 +6,23 @@ use crate::{alignment::RecordBuf, Header};
 pub(super) async fn read_record_buf<R>(
     reader: &mut R,
     buf: &mut Vec<u8>,
+    line_number: &mut Option<u64>,
     header: &Header,
     record: &mut RecordBuf,
 ) -> io::Result<usize>
 where
     R: AsyncBufRead + Unpin,
 {
-    use crate::io::reader::record_buf::parse_record_buf;
+    use crate::io::reader::record_buf::{parse_error, parse_record_buf};
 
     buf.clear();
 
     match read_line(reader, buf).await? {
         0 => Ok(0),
         n => {
-            parse_record_buf(buf, header, record)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            *line_number = line_number.map(|n| n + 1);
+
+            parse_record_buf(buf, header, record).map_err(|e| parse_error(buf, *line_number, e))?;
 
             Ok(n)
         }
@@ -149,7 +149,7 @@ where
         use crate::io::writer::write_record;
 
         let mut buf = Vec::new();
-        write_record(&mut buf, header, record)?;
+        write_record(&mut buf, header, record, &Default::default())?;
         self.inner.write_all(&buf).await
     }
 }
@@ -74,14 +74,19 @@ mod builder;
 mod parser;
 mod programs;
 pub mod record;
+mod validate;
 
 pub use self::{
     builder::Builder,
     parser::{ParseError, Parser},
     record::Record,
+    validate::ValidationError,
 };
 
-use std::str::{self, FromStr};
+use std::{
+    io,
+    str::{self, FromStr},
+};
 
 use bstr::BString;
 use indexmap::IndexMap;
@@ -362,6 +367,53 @@ impl Header {
         self.comments.push(comment.into());
     }
 
+    /// Adds a program to the end of each program chain.
+    ///
+    /// This is meant for tools appending themselves to the header. The previous program ID (`PP`)
+    /// of the given program is set to the leaf program of each chain, adding one program record per
+    /// chain. If the given ID is already used, a suffix of `.1`, `.2`, etc. is appended to make it
+    /// unique, similar to htslib.
+    ///
+    /// This returns the IDs of the added programs.
+    ///
+    /// # Errors
+    ///
+    /// This returns an `io::Error` if any program chain has a cycle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bstr::BString;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::program::tag, Map},
+    /// };
+    ///
+    /// let mut header = sam::Header::builder()
+    ///     .add_program("samtools", Map::default())
+    ///     .build();
+    ///
+    /// let ids = header.add_program_chained("samtools", Map::default())?;
+    /// assert_eq!(ids, [BString::from("samtools.1")]);
+    ///
+    /// let program = header.programs().as_ref().get(&ids[0]).expect("missing program");
+    /// assert_eq!(
+    ///     program.other_fields().get(&tag::PREVIOUS_PROGRAM_ID),
+    ///     Some(&BString::from("samtools")),
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_program_chained<I>(
+        &mut self,
+        id: I,
+        map: Map<map::Program>,
+    ) -> io::Result<Vec<BString>>
+    where
+        I: Into<BString>,
+    {
+        self.programs.add_chained(id.into(), map)
+    }
+
     /// Returns whether there are no records in this SAM header.
     ///
     /// # Examples
@@ -403,6 +455,38 @@ impl Header {
         self.programs.as_mut().clear();
         self.comments.clear();
     }
+
+    /// Validates the header.
+    ///
+    /// This checks that
+    ///
+    ///   * the subsort order (`SS`) is prefixed by the sort order (`SO`);
+    ///   * the group order (`GO`) does not contradict the sort order (`SO`);
+    ///   * reference sequence lengths are in the range [1, 2^31 - 1];
+    ///   * reference sequence names and alternative names (`AN`) are unique; and
+    ///   * previous program IDs (`PP`) refer to programs in the header and do not form cycles.
+    ///
+    /// Duplicate reference sequence names, read group IDs, and program IDs are rejected when
+    /// parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::ValidationError};
+    ///
+    /// let header: sam::Header = "@HD\tVN:1.6\tSO:coordinate\n@SQ\tSN:sq0\tLN:8\n".parse()?;
+    /// assert!(header.validate().is_ok());
+    ///
+    /// let header: sam::Header = "@PG\tID:pg0\tPP:pg1\n".parse()?;
+    /// assert_eq!(
+    ///     header.validate(),
+    ///     Err(ValidationError::MissingPreviousProgram(b"pg0".into())),
+    /// );
+    /// # Ok::<_, sam::header::ParseError>(())
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        validate::validate(self)
+    }
 }
 
 impl FromStr for Header {
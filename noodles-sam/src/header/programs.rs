@@ -108,6 +108,52 @@ impl Programs {
         Ok(())
     }
 
+    pub(super) fn add_chained(
+        &mut self,
+        id: BString,
+        map: Map<Program>,
+    ) -> io::Result<Vec<BString>> {
+        let previous_program_ids: Vec<BString> = self.leaves()?.map(|(id, _)| id.into()).collect();
+
+        if previous_program_ids.is_empty() {
+            let id = self.unique_id(id.as_ref());
+            self.0.insert(id.clone(), map);
+            return Ok(vec![id]);
+        }
+
+        let mut ids = Vec::with_capacity(previous_program_ids.len());
+
+        for previous_program_id in previous_program_ids {
+            let id = self.unique_id(id.as_ref());
+
+            let mut map = map.clone();
+
+            map.other_fields_mut()
+                .insert(tag::PREVIOUS_PROGRAM_ID, previous_program_id);
+
+            self.0.insert(id.clone(), map);
+            ids.push(id);
+        }
+
+        Ok(ids)
+    }
+
+    fn unique_id(&self, id: &BStr) -> BString {
+        if !self.0.contains_key(id) {
+            return id.into();
+        }
+
+        (1..)
+            .map(|i| {
+                let mut candidate = BString::from(id);
+                candidate.push_byte(b'.');
+                candidate.push_str(i.to_string());
+                candidate
+            })
+            .find(|candidate| !self.0.contains_key(candidate))
+            .unwrap()
+    }
+
     /// Returns an iterator over root programs.
     ///
     /// A root program is a first program of a program chain.
@@ -290,6 +336,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_add_chained() -> Result<(), Box<dyn std::error::Error>> {
+        let mut programs = Programs::default();
+
+        let ids = programs.add_chained(BString::from("pg"), Map::default())?;
+        assert_eq!(ids, [BString::from("pg")]);
+
+        let ids = programs.add_chained(BString::from("pg"), Map::default())?;
+        assert_eq!(ids, [BString::from("pg.1")]);
+
+        programs
+            .as_mut()
+            .insert(BString::from("pg.2"), Map::default());
+
+        let map = Map::builder()
+            .insert(tag::PREVIOUS_PROGRAM_ID, "pg")
+            .build()?;
+        let ids = programs.add_chained(BString::from("pg"), map)?;
+        assert_eq!(ids, [BString::from("pg.3"), BString::from("pg.4")]);
+
+        let expected = Programs(
+            [
+                (BString::from("pg"), Map::default()),
+                (
+                    BString::from("pg.1"),
+                    Map::builder()
+                        .insert(tag::PREVIOUS_PROGRAM_ID, "pg")
+                        .build()?,
+                ),
+                (BString::from("pg.2"), Map::default()),
+                (
+                    BString::from("pg.3"),
+                    Map::builder()
+                        .insert(tag::PREVIOUS_PROGRAM_ID, "pg.2")
+                        .build()?,
+                ),
+                (
+                    BString::from("pg.4"),
+                    Map::builder()
+                        .insert(tag::PREVIOUS_PROGRAM_ID, "pg.1")
+                        .build()?,
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(programs, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_leaves() -> Result<(), Box<dyn std::error::Error>> {
         let header = Header::builder()
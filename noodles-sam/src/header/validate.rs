@@ -0,0 +1,287 @@
+use std::{collections::HashSet, error, fmt};
+
+use bstr::{BStr, BString, ByteSlice};
+
+use super::{
+    record::value::map::{
+        header::{group_order, sort_order, tag as header_tag},
+        program::tag as program_tag,
+        reference_sequence::tag as reference_sequence_tag,
+    },
+    Header,
+};
+
+// § 1.3 "The header section" (2023-05-24): "Range: [1, 2^31 - 1]"
+const MAX_REFERENCE_SEQUENCE_LENGTH: usize = (1 << 31) - 1;
+
+/// An error returned when a SAM header fails validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The subsort order (`SS`) is not prefixed by the sort order (`SO`).
+    MismatchedSubsortOrder,
+    /// The group order (`GO`) contradicts the sort order (`SO`).
+    MismatchedGroupOrder,
+    /// A reference sequence length is out of range.
+    InvalidReferenceSequenceLength(BString),
+    /// A reference sequence name or alternative name is duplicated.
+    DuplicateReferenceSequenceName(BString),
+    /// A program's previous program ID (`PP`) does not refer to a program in the header.
+    MissingPreviousProgram(BString),
+    /// A program chain has a cycle.
+    ProgramCycle(BString),
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MismatchedSubsortOrder => {
+                f.write_str("subsort order (SS) does not match sort order (SO)")
+            }
+            Self::MismatchedGroupOrder => {
+                f.write_str("group order (GO) does not match sort order (SO)")
+            }
+            Self::InvalidReferenceSequenceLength(name) => {
+                write!(f, "invalid reference sequence length: {name}")
+            }
+            Self::DuplicateReferenceSequenceName(name) => {
+                write!(f, "duplicate reference sequence name: {name}")
+            }
+            Self::MissingPreviousProgram(id) => {
+                write!(f, "missing previous program (PP) for program: {id}")
+            }
+            Self::ProgramCycle(id) => write!(f, "program chain has a cycle: {id}"),
+        }
+    }
+}
+
+pub(super) fn validate(header: &Header) -> Result<(), ValidationError> {
+    validate_header(header)?;
+    validate_reference_sequences(header)?;
+    validate_programs(header)?;
+    Ok(())
+}
+
+fn validate_header(header: &Header) -> Result<(), ValidationError> {
+    let Some(map) = header.header() else {
+        return Ok(());
+    };
+
+    let other_fields = map.other_fields();
+    let sort_order = other_fields.get(&header_tag::SORT_ORDER);
+
+    if let Some(subsort_order) = other_fields.get(&header_tag::SUBSORT_ORDER) {
+        let is_valid = sort_order
+            .and_then(|so| subsort_order.strip_prefix(so.as_slice()))
+            .map(|suffix| suffix.starts_with(b":"))
+            .unwrap_or(false);
+
+        if !is_valid {
+            return Err(ValidationError::MismatchedSubsortOrder);
+        }
+    }
+
+    if let (Some(sort_order), Some(group_order)) =
+        (sort_order, other_fields.get(&header_tag::GROUP_ORDER))
+    {
+        let is_mismatched = match sort_order.as_slice() {
+            sort_order::COORDINATE => group_order == group_order::QUERY,
+            sort_order::QUERY_NAME => group_order == group_order::REFERENCE,
+            _ => false,
+        };
+
+        if is_mismatched {
+            return Err(ValidationError::MismatchedGroupOrder);
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_reference_sequences(header: &Header) -> Result<(), ValidationError> {
+    const ALTERNATIVE_NAMES_DELIMITER: u8 = b',';
+
+    let mut names: HashSet<&BStr> = header
+        .reference_sequences()
+        .keys()
+        .map(|name| name.as_bstr())
+        .collect();
+
+    for (name, reference_sequence) in header.reference_sequences() {
+        if reference_sequence.length().get() > MAX_REFERENCE_SEQUENCE_LENGTH {
+            return Err(ValidationError::InvalidReferenceSequenceLength(
+                name.clone(),
+            ));
+        }
+
+        if let Some(alternative_names) = reference_sequence
+            .other_fields()
+            .get(&reference_sequence_tag::ALTERNATIVE_NAMES)
+        {
+            for alternative_name in alternative_names.split_str(&[ALTERNATIVE_NAMES_DELIMITER]) {
+                if !names.insert(alternative_name.as_bstr()) {
+                    return Err(ValidationError::DuplicateReferenceSequenceName(
+                        alternative_name.into(),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_programs(header: &Header) -> Result<(), ValidationError> {
+    let programs = header.programs().as_ref();
+
+    for (id, program) in programs {
+        let mut visited = HashSet::new();
+        visited.insert(id.as_bstr());
+
+        let mut previous_program_id = program
+            .other_fields()
+            .get(&program_tag::PREVIOUS_PROGRAM_ID);
+
+        while let Some(ppid) = previous_program_id {
+            let Some(previous_program) = programs.get(ppid) else {
+                return Err(ValidationError::MissingPreviousProgram(id.clone()));
+            };
+
+            if !visited.insert(ppid.as_bstr()) {
+                return Err(ValidationError::ProgramCycle(id.clone()));
+            }
+
+            previous_program_id = previous_program
+                .other_fields()
+                .get(&program_tag::PREVIOUS_PROGRAM_ID);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::header::record::value::{
+        map::{self, builder::BuildError, Program, ReferenceSequence},
+        Map,
+    };
+
+    const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+        Some(length) => length,
+        None => unreachable!(),
+    };
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "\
+@HD\tVN:1.6\tSO:coordinate\tGO:reference\tSS:coordinate:queryname
+@SQ\tSN:sq0\tLN:8\tAN:0,chr0
+@SQ\tSN:sq1\tLN:13
+@PG\tID:pg0
+@PG\tID:pg1\tPP:pg0
+"
+        .parse()?;
+
+        assert_eq!(validate(&header), Ok(()));
+        assert_eq!(validate(&Header::default()), Ok(()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_header() -> Result<(), Box<dyn std::error::Error>> {
+        let header: Header = "@HD\tVN:1.6\tSS:coordinate:queryname\n".parse()?;
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::MismatchedSubsortOrder)
+        );
+
+        let header: Header = "@HD\tVN:1.6\tSO:unsorted\tSS:coordinate:queryname\n".parse()?;
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::MismatchedSubsortOrder)
+        );
+
+        let header: Header = "@HD\tVN:1.6\tSO:coordinate\tGO:query\n".parse()?;
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::MismatchedGroupOrder)
+        );
+
+        let header: Header = "@HD\tVN:1.6\tSO:queryname\tGO:reference\n".parse()?;
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::MismatchedGroupOrder)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_reference_sequences() -> Result<(), BuildError> {
+        let length = NonZeroUsize::new(MAX_REFERENCE_SEQUENCE_LENGTH + 1).unwrap();
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(length))
+            .build();
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::InvalidReferenceSequenceLength(
+                BString::from("sq0")
+            ))
+        );
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::builder()
+                    .set_length(SQ0_LN)
+                    .insert(reference_sequence_tag::ALTERNATIVE_NAMES, "1,sq0")
+                    .build()?,
+            )
+            .build();
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::DuplicateReferenceSequenceName(
+                BString::from("sq0")
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_with_invalid_programs() -> Result<(), BuildError> {
+        fn program_with_previous_program_id(id: &str) -> Result<Map<Program>, BuildError> {
+            Map::<Program>::builder()
+                .insert(map::program::tag::PREVIOUS_PROGRAM_ID, id)
+                .build()
+        }
+
+        let header = Header::builder()
+            .add_program("pg0", program_with_previous_program_id("pg1")?)
+            .build();
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::MissingPreviousProgram(BString::from(
+                "pg0"
+            )))
+        );
+
+        let header = Header::builder()
+            .add_program("pg0", program_with_previous_program_id("pg1")?)
+            .add_program("pg1", program_with_previous_program_id("pg0")?)
+            .build();
+        assert_eq!(
+            validate(&header),
+            Err(ValidationError::ProgramCycle(BString::from("pg0")))
+        );
+
+        Ok(())
+    }
+}
@@ -2,6 +2,7 @@
 
 mod builder;
 mod header;
+mod parse_error;
 mod query;
 mod record;
 pub(crate) mod record_buf;
@@ -17,7 +18,7 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 pub(crate) use self::record::read_record;
-pub use self::{builder::Builder, record_bufs::RecordBufs};
+pub use self::{builder::Builder, parse_error::ParseError, record_bufs::RecordBufs};
 use self::{header::read_header, query::Query, record_buf::read_record_buf};
 use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 
@@ -48,6 +49,7 @@ use crate::{alignment::RecordBuf, header::ReferenceSequences, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    line_number: Option<u64>,
 }
 
 impl<R> Reader<R> {
@@ -142,7 +144,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner)
+        read_header(&mut self.inner, &mut self.line_number)
     }
 
     /// Reads a record into an alignment record buffer.
@@ -181,7 +183,13 @@ where
         header: &Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, &mut self.buf, header, record)
+        read_record_buf(
+            &mut self.inner,
+            &mut self.buf,
+            &mut self.line_number,
+            header,
+            record,
+        )
     }
 
     /// Returns an iterator over alignment record buffers starting from the current stream
@@ -239,7 +247,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        read_record(&mut self.inner, &mut self.line_number, record)
     }
 
     /// Returns an iterator over records.
@@ -286,6 +294,7 @@ where
         self.get_mut()
             .seek_to_virtual_position(bgzf::VirtualPosition::default())?;
 
+        self.line_number = Some(0);
         self.read_header()?;
 
         Ok(self.get_ref().virtual_position())
@@ -372,6 +381,7 @@ where
     {
         if let Some(pos) = index.last_first_record_start_position() {
             self.get_mut().seek_to_virtual_position(pos)?;
+            self.line_number = None;
         } else {
             self.seek_to_first_record()?;
         }
@@ -404,6 +414,7 @@ where
         Self {
             inner,
             buf: Vec::new(),
+            line_number: Some(0),
         }
     }
 }
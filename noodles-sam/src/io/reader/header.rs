@@ -1,8 +1,9 @@
 use std::io::{self, BufRead};
 
+use super::ParseError;
 use crate::{header, Header};
 
-pub(super) fn read_header<R>(reader: &mut R) -> io::Result<Header>
+pub(super) fn read_header<R>(reader: &mut R, line_number: &mut Option<u64>) -> io::Result<Header>
 where
     R: BufRead,
 {
@@ -10,9 +11,14 @@ where
     let mut buf = Vec::new();
 
     while read_header_line(reader, &mut buf)? != 0 {
-        parser
-            .parse_partial(&buf)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        *line_number = line_number.map(|n| n + 1);
+
+        parser.parse_partial(&buf).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                ParseError::new(*line_number, None, &buf, e),
+            )
+        })?;
     }
 
     Ok(parser.finish())
@@ -64,7 +70,7 @@ mod tests {
     fn test_read_header_with_no_header() -> io::Result<()> {
         let data = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
         let mut reader = &data[..];
-        assert!(read_header(&mut reader)?.is_empty());
+        assert!(read_header(&mut reader, &mut Some(0))?.is_empty());
         Ok(())
     }
 
@@ -73,7 +79,7 @@ mod tests {
         let data = "@HD\tVN:1.6\n";
         let mut reader = data.as_bytes();
 
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, &mut Some(0))?;
 
         let expected = crate::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -96,7 +102,7 @@ mod tests {
         let data = "@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n";
         let mut reader = BufReader::with_capacity(16, data.as_bytes());
 
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, &mut Some(0))?;
 
         let expected = crate::Header::builder()
             .set_header(Map::<map::Header>::new(Version::new(1, 6)))
@@ -107,4 +113,21 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_header_with_invalid_record() {
+        let data = b"@HD\tVN:1.6\n@SQ\tSN:sq0\n";
+        let mut reader = &data[..];
+        let mut line_number = Some(0);
+
+        let e = read_header(&mut reader, &mut line_number).unwrap_err();
+        let e = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParseError>())
+            .unwrap();
+
+        assert_eq!(e.line_number(), Some(2));
+        assert!(e.field_number().is_none());
+        assert_eq!(e.value(), "@SQ\tSN:sq0");
+    }
 }
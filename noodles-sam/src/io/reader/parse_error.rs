@@ -0,0 +1,108 @@
+use std::{error, fmt};
+
+use bstr::{BStr, BString};
+
+/// An error returned when a SAM line fails to parse.
+///
+/// This wraps the underlying parse error with the location of the malformed line. It is returned
+/// as the inner error of an [`std::io::Error`] with kind [`std::io::ErrorKind::InvalidData`].
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::{self as sam, alignment::RecordBuf, io::reader::ParseError};
+///
+/// let data = b"@HD\tVN:1.6
+/// r0\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+/// r1\t4\t*\tndls\t255\t*\t*\t0\t0\t*\t*
+/// ";
+///
+/// let mut reader = sam::io::Reader::new(&data[..]);
+/// let header = reader.read_header()?;
+///
+/// let mut record = RecordBuf::default();
+/// reader.read_record_buf(&header, &mut record)?;
+///
+/// let e = reader.read_record_buf(&header, &mut record).unwrap_err();
+/// let e = e
+///     .get_ref()
+///     .and_then(|e| e.downcast_ref::<ParseError>())
+///     .expect("missing parse error");
+///
+/// assert_eq!(e.line_number(), Some(3));
+/// assert_eq!(e.field_number(), Some(4));
+/// assert_eq!(e.value(), "ndls");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    line_number: Option<u64>,
+    field_number: Option<usize>,
+    value: BString,
+    source: Box<dyn error::Error + Send + Sync>,
+}
+
+impl ParseError {
+    pub(crate) fn new<E>(
+        line_number: Option<u64>,
+        field_number: Option<usize>,
+        value: &[u8],
+        source: E,
+    ) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        Self {
+            line_number,
+            field_number,
+            value: value.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Returns the line number of the malformed line, if known.
+    ///
+    /// Line numbers start at 1 and count the lines read by the reader, including header lines.
+    /// The line number is unknown after the reader seeks (e.g., when querying) until the stream is
+    /// read from the start again.
+    pub fn line_number(&self) -> Option<u64> {
+        self.line_number
+    }
+
+    /// Returns the number of the malformed field, if known.
+    ///
+    /// Field numbers start at 1, e.g., the position (`POS`) is field 4. An error in the optional
+    /// data fields is reported as field 12.
+    pub fn field_number(&self) -> Option<usize> {
+        self.field_number
+    }
+
+    /// Returns the malformed value.
+    ///
+    /// This is the malformed field, if known, or otherwise the entire line. For an error in the
+    /// optional data fields, this is all of the data fields.
+    pub fn value(&self) -> &BStr {
+        self.value.as_ref()
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line_number, self.field_number) {
+            (Some(line_number), Some(field_number)) => {
+                write!(f, "line {line_number}, field {field_number}: ")?
+            }
+            (Some(line_number), None) => write!(f, "line {line_number}: ")?,
+            (None, Some(field_number)) => write!(f, "field {field_number}: ")?,
+            (None, None) => {}
+        }
+
+        write!(f, "{}: {:?}", self.source, self.value)
+    }
+}
@@ -26,8 +26,11 @@ where
         reference_sequence_id: usize,
         interval: Interval,
     ) -> Self {
+        let mut reader = Reader::new(csi::io::Query::new(reader, chunks));
+        reader.line_number = None;
+
         Self {
-            reader: Reader::new(csi::io::Query::new(reader, chunks)),
+            reader,
             header,
             reference_sequence_id,
             interval,
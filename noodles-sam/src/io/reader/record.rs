@@ -1,12 +1,22 @@
 use std::io::{self, BufRead};
 
-use super::read_line;
+use super::{read_line, ParseError};
 use crate::Record;
 
-pub(crate) fn read_record<R>(reader: &mut R, record: &mut Record) -> io::Result<usize>
+pub(crate) fn read_record<R>(
+    reader: &mut R,
+    line_number: &mut Option<u64>,
+    record: &mut Record,
+) -> io::Result<usize>
 where
     R: BufRead,
 {
+    if reader.fill_buf()?.is_empty() {
+        return Ok(0);
+    }
+
+    *line_number = line_number.map(|n| n + 1);
+
     let fields = record.fields_mut();
 
     let buf = &mut fields.buf;
@@ -16,34 +26,34 @@ where
 
     let mut len = 0;
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 1)?;
     bounds.name_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 2)?;
     bounds.flags_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 3)?;
     bounds.reference_sequence_name_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 4)?;
     bounds.alignment_start_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 5)?;
     bounds.mapping_quality_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 6)?;
     bounds.cigar_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 7)?;
     bounds.mate_reference_sequence_name_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 8)?;
     bounds.mate_alignment_start_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 9)?;
     bounds.template_length_end = buf.len();
 
-    len += read_required_field(reader, buf)?;
+    len += read_required_field(reader, buf, *line_number, 10)?;
     bounds.sequence_end = buf.len();
 
     let (n, is_eol) = read_last_required_field(reader, buf)?;
@@ -57,14 +67,23 @@ where
     Ok(len)
 }
 
-fn read_required_field<R>(reader: &mut R, dst: &mut Vec<u8>) -> io::Result<usize>
+fn read_required_field<R>(
+    reader: &mut R,
+    dst: &mut Vec<u8>,
+    line_number: Option<u64>,
+    field_number: usize,
+) -> io::Result<usize>
 where
     R: BufRead,
 {
     let (len, is_eol) = read_field(reader, dst)?;
 
     if is_eol {
-        Err(io::Error::new(io::ErrorKind::InvalidData, "unexpected EOL"))
+        // The line ended before the next field.
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            ParseError::new(line_number, Some(field_number + 1), b"", "unexpected EOL"),
+        ))
     } else {
         Ok(len)
     }
@@ -128,22 +147,41 @@ mod tests {
     fn test_read_record() -> io::Result<()> {
         let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n"[..];
         let mut record = Record::default();
-        read_record(&mut src, &mut record)?;
+        read_record(&mut src, &mut Some(0), &mut record)?;
         assert_eq!(record.fields().buf, b"*4*0255**00**");
         assert_eq!(record.fields().bounds, Bounds::default());
 
         let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\r\n"[..];
         let mut record = Record::default();
-        read_record(&mut src, &mut record)?;
+        read_record(&mut src, &mut Some(0), &mut record)?;
         assert_eq!(record.fields().buf, b"*4*0255**00**");
         assert_eq!(record.fields().bounds, Bounds::default());
 
         let mut src = &b"\n"[..];
         assert!(matches!(
-            read_record(&mut src, &mut record),
+            read_record(&mut src, &mut Some(0), &mut record),
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
 
+        let mut src = &b""[..];
+        assert_eq!(read_record(&mut src, &mut Some(0), &mut record)?, 0);
+
         Ok(())
     }
+
+    #[test]
+    fn test_read_record_with_missing_field() {
+        let mut src = &b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n*\t4\t*\t0\n"[..];
+        let mut line_number = Some(2);
+        let mut record = Record::default();
+
+        assert!(read_record(&mut src, &mut line_number, &mut record).is_ok());
+
+        let e = read_record(&mut src, &mut line_number, &mut record).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let e = e.into_inner().unwrap().downcast::<ParseError>().unwrap();
+        assert_eq!(e.line_number(), Some(4));
+        assert_eq!(e.field_number(), Some(5));
+    }
 }
@@ -29,6 +29,7 @@ use crate::{alignment::RecordBuf, Header};
 pub fn read_record_buf<R>(
     reader: &mut R,
     buf: &mut Vec<u8>,
+    line_number: &mut Option<u64>,
     header: &Header,
     record: &mut RecordBuf,
 ) -> io::Result<usize>
@@ -40,14 +41,41 @@ where
     match read_line(reader, buf)? {
         0 => Ok(0),
         n => {
-            parse_record_buf(buf, header, record)
-                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            *line_number = line_number.map(|n| n + 1);
+
+            parse_record_buf(buf, header, record).map_err(|e| parse_error(buf, *line_number, e))?;
 
             Ok(n)
         }
     }
 }
 
+// Wraps a record parse error with the location of the malformed field.
+pub(crate) fn parse_error(buf: &[u8], line_number: Option<u64>, e: ParseError) -> io::Error {
+    let field_number = e.field_number();
+    let value = get_field(buf, field_number);
+
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        super::ParseError::new(line_number, Some(field_number), value, e),
+    )
+}
+
+// Returns the field with the given number. The data fields are returned as one field.
+fn get_field(mut src: &[u8], field_number: usize) -> &[u8] {
+    const DATA_FIELD_NUMBER: usize = 12;
+
+    for _ in 1..field_number {
+        next_field(&mut src);
+    }
+
+    if field_number < DATA_FIELD_NUMBER {
+        next_field(&mut src)
+    } else {
+        src
+    }
+}
+
 /// An error when a raw SAM record fails to parse.
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -78,6 +106,26 @@ pub enum ParseError {
     InvalidData(data::ParseError),
 }
 
+impl ParseError {
+    // Returns the 1-based number of the field that failed to parse.
+    fn field_number(&self) -> usize {
+        match self {
+            Self::InvalidName(_) => 1,
+            Self::InvalidFlags(_) => 2,
+            Self::InvalidReferenceSequenceId(_) => 3,
+            Self::InvalidPosition(_) => 4,
+            Self::InvalidMappingQuality(_) => 5,
+            Self::InvalidCigar(_) => 6,
+            Self::InvalidMateReferenceSequenceId(_) => 7,
+            Self::InvalidMatePosition(_) => 8,
+            Self::InvalidTemplateLength(_) => 9,
+            Self::InvalidSequence(_) => 10,
+            Self::InvalidQualityScores(_) => 11,
+            Self::InvalidData(_) => 12,
+        }
+    }
+}
+
 impl error::Error for ParseError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
@@ -3,13 +3,16 @@
 mod builder;
 mod header;
 mod num;
+mod options;
 pub mod record;
+mod tag_order;
 
 use std::io::{self, Write};
 
-pub use self::builder::Builder;
 use self::header::write_header;
+pub(crate) use self::options::Options;
 pub(crate) use self::record::write_record;
+pub use self::{builder::Builder, tag_order::TagOrder};
 use crate::{Header, Record};
 
 /// A SAM writer.
@@ -47,6 +50,7 @@ where
     W: Write,
 {
     inner: W,
+    options: Options,
 }
 
 impl<W> Writer<W>
@@ -62,7 +66,11 @@ where
     /// let writer = sam::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self::with_options(inner, Options::default())
+    }
+
+    pub(crate) fn with_options(inner: W, options: Options) -> Self {
+        Self { inner, options }
     }
 
     /// Returns a reference to the underlying writer.
@@ -142,7 +150,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, &self.options)
     }
 }
 
@@ -159,7 +167,7 @@ where
         header: &Header,
         record: &dyn crate::alignment::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, &self.options)
     }
 
     fn finish(&mut self, _: &Header) -> io::Result<()> {
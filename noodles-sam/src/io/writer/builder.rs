@@ -6,13 +6,14 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Writer;
-use crate::io::CompressionMethod;
+use super::{Options, TagOrder, Writer};
+use crate::{alignment::record::data::field::Tag, io::CompressionMethod};
 
 /// A SAM writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    options: Options,
 }
 
 impl Builder {
@@ -29,6 +30,39 @@ impl Builder {
         self
     }
 
+    /// Sets the output order of record data fields.
+    ///
+    /// By default, data fields are written in insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::io::writer::{Builder, TagOrder};
+    /// let builder = Builder::default().set_tag_order(TagOrder::Alphabetical);
+    /// ```
+    pub fn set_tag_order(mut self, tag_order: TagOrder) -> Self {
+        self.options.tag_order = tag_order;
+        self
+    }
+
+    /// Sets the tags of record data fields to exclude from the output.
+    ///
+    /// By default, no data fields are excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{alignment::record::data::field::Tag, io::writer::Builder};
+    /// let builder = Builder::default().set_excluded_tags([Tag::ORIGINAL_QUALITY_SCORES]);
+    /// ```
+    pub fn set_excluded_tags<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.options.excluded_tags = tags.into_iter().collect();
+        self
+    }
+
     /// Builds a SAM writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -77,6 +111,51 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::new(inner)
+        Writer::with_options(inner, self.options)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alignment::{io::Write as _, record_buf::data::field::Value, RecordBuf},
+        Header,
+    };
+
+    #[test]
+    fn test_build_from_writer_with_data_options() -> io::Result<()> {
+        let header = Header::default();
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [
+                    (Tag::READ_GROUP, Value::from("rg0")),
+                    (Tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+                    (Tag::COMMENT, Value::from("noodles")),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+
+        {
+            let mut writer = Builder::default()
+                .set_tag_order(TagOrder::Alphabetical)
+                .set_excluded_tags([Tag::ALIGNMENT_HIT_COUNT])
+                .build_from_writer(&mut buf);
+
+            writer.write_alignment_record(&header, &record)?;
+            writer.get_mut().flush()?;
+        }
+
+        assert_eq!(
+            buf,
+            b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tCO:Z:noodles\tRG:Z:rg0\n"
+        );
+
+        Ok(())
     }
 }
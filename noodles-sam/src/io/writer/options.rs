@@ -0,0 +1,10 @@
+use std::collections::HashSet;
+
+use super::TagOrder;
+use crate::alignment::record::data::field::Tag;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct Options {
+    pub(crate) tag_order: TagOrder,
+    pub(crate) excluded_tags: HashSet<Tag>,
+}
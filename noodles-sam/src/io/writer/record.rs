@@ -25,11 +25,17 @@ use self::{
     sequence::write_sequence,
     template_length::write_template_length,
 };
+use super::Options;
 use crate::{alignment::Record, Header};
 
 const MISSING: u8 = b'*';
 
-pub(crate) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+pub(crate) fn write_record<W, R>(
+    writer: &mut W,
+    header: &Header,
+    record: &R,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -95,7 +101,7 @@ where
     writer.write_all(DELIMITER)?;
     write_quality_scores(writer, base_count, record.quality_scores())?;
 
-    write_data(writer, record.data())?;
+    write_data(writer, record.data(), options)?;
 
     writeln!(writer)?;
 
@@ -120,7 +126,7 @@ mod tests {
             .collect();
         let record = RecordBuf::builder().set_data(data).build();
 
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, &Options::default())?;
 
         let expected = b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tRG:Z:rg0\n";
         assert_eq!(buf, expected);
@@ -3,18 +3,44 @@ mod field;
 use std::io::{self, Write};
 
 use self::field::write_field;
-use crate::alignment::record::Data;
+use crate::{
+    alignment::record::Data,
+    io::writer::{Options, TagOrder},
+};
 
-pub(super) fn write_data<W, D>(writer: &mut W, data: D) -> io::Result<()>
+pub(super) fn write_data<W, D>(writer: &mut W, data: D, options: &Options) -> io::Result<()>
 where
     W: Write,
     D: Data,
 {
     const DELIMITER: u8 = b'\t';
 
+    if options.tag_order == TagOrder::Insertion && options.excluded_tags.is_empty() {
+        for result in data.iter() {
+            let (tag, value) = result?;
+
+            writer.write_all(&[DELIMITER])?;
+            write_field(writer, tag, &value)?;
+        }
+
+        return Ok(());
+    }
+
+    let mut fields = Vec::new();
+
     for result in data.iter() {
         let (tag, value) = result?;
 
+        if !options.excluded_tags.contains(&tag) {
+            fields.push((tag, value));
+        }
+    }
+
+    if options.tag_order == TagOrder::Alphabetical {
+        fields.sort_by_key(|(tag, _)| *tag);
+    }
+
+    for (tag, value) in fields {
         writer.write_all(&[DELIMITER])?;
         write_field(writer, tag, &value)?;
     }
@@ -40,10 +66,41 @@ mod tests {
         .into_iter()
         .collect();
 
-        write_data(&mut buf, &data)?;
+        write_data(&mut buf, &data, &Options::default())?;
 
         assert_eq!(buf, b"\tNH:i:1\tCO:Z:noodles");
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_data_with_options() -> io::Result<()> {
+        use crate::alignment::{record::data::field::Tag, record_buf::data::field::Value};
+
+        let data: DataBuf = [
+            (Tag::READ_GROUP, Value::from("rg0")),
+            (Tag::ALIGNMENT_HIT_COUNT, Value::from(1)),
+            (Tag::COMMENT, Value::from("noodles")),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut buf = Vec::new();
+        let options = Options {
+            tag_order: TagOrder::Alphabetical,
+            ..Default::default()
+        };
+        write_data(&mut buf, &data, &options)?;
+        assert_eq!(buf, b"\tCO:Z:noodles\tNH:i:1\tRG:Z:rg0");
+
+        buf.clear();
+        let options = Options {
+            excluded_tags: [Tag::ALIGNMENT_HIT_COUNT].into_iter().collect(),
+            ..Default::default()
+        };
+        write_data(&mut buf, &data, &options)?;
+        assert_eq!(buf, b"\tRG:Z:rg0\tCO:Z:noodles");
+
+        Ok(())
+    }
 }
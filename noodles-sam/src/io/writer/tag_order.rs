@@ -0,0 +1,12 @@
+/// The output order of record data fields.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TagOrder {
+    /// Data fields are written in the order they are stored in the record.
+    #[default]
+    Insertion,
+    /// Data fields are written sorted by tag.
+    ///
+    /// Tags are compared bytewise, e.g., uppercase tags sort before lowercase tags. The sort is
+    /// stable.
+    Alphabetical,
+}
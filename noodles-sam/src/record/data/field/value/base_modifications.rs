@@ -1,11 +1,17 @@
 //! SAM record data field value for base modifications.
 
+mod call;
 pub mod group;
 mod parser;
 
-pub use self::group::Group;
+pub use self::{call::Call, group::Group};
 
-use crate::alignment::record_buf::Sequence;
+use std::io;
+
+use noodles_core::Position;
+
+use self::group::{Status, UnmodifiedBase};
+use crate::alignment::{record::Cigar, record_buf::Sequence};
 
 /// Base modifications.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -54,6 +60,208 @@ impl BaseModifications {
     ) -> Result<Self, parser::ParseError> {
         parser::parse(s, is_reverse_complemented, sequence)
     }
+
+    /// Resolves the base modifications to read and reference positions.
+    ///
+    /// The given sequence and reverse complement flag must be the same as the ones used to parse
+    /// the base modifications. `probabilities` are the optional values of the base modification
+    /// probabilities (`ML`) field. The alignment start and CIGAR map read positions to reference
+    /// positions; if the alignment start is `None`, e.g., the record is unmapped, no reference
+    /// positions are resolved.
+    ///
+    /// Calls are emitted in group order. Within a group, calls are ordered by the original read
+    /// orientation, i.e., from the end of the sequence for reverse complemented records, and each
+    /// position has one call per modification.
+    ///
+    /// Bases of the unmodified base type that are skipped are handled according to the status of
+    /// the group. If the status is implicit (`.` or unset), they are unmodified and emitted as
+    /// calls with a probability of 0. If the status is explicit (`?`), their state is unknown, and
+    /// no calls are emitted for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_sam::{
+    ///     alignment::{
+    ///         record::cigar::{op::Kind, Op},
+    ///         record_buf::{Cigar, Sequence},
+    ///     },
+    ///     record::data::field::value::BaseModifications,
+    /// };
+    ///
+    /// let is_reverse_complemented = false;
+    /// let sequence = Sequence::from(b"CACGC");
+    /// let base_modifications = BaseModifications::parse("C+m?,1;", is_reverse_complemented, &sequence)?;
+    ///
+    /// // 1S4M
+    /// let cigar: Cigar = [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 4)]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let calls = base_modifications.calls(
+    ///     &sequence,
+    ///     is_reverse_complemented,
+    ///     Some(&[204]),
+    ///     Some(Position::try_from(8)?),
+    ///     &cigar,
+    /// )?;
+    ///
+    /// assert_eq!(calls.len(), 1);
+    /// assert_eq!(calls[0].read_position(), 2);
+    /// assert_eq!(calls[0].reference_position(), Position::new(9));
+    /// assert_eq!(calls[0].probability(), Some(204));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn calls<C>(
+        &self,
+        sequence: &Sequence,
+        is_reverse_complemented: bool,
+        probabilities: Option<&[u8]>,
+        alignment_start: Option<Position>,
+        cigar: &C,
+    ) -> io::Result<Vec<Call>>
+    where
+        C: Cigar + ?Sized,
+    {
+        let reference_positions = match alignment_start {
+            Some(start) => resolve_reference_positions(start, cigar, sequence.len())?,
+            None => vec![None; sequence.len()],
+        };
+
+        let mut probabilities = probabilities;
+        let mut calls = Vec::new();
+
+        for group in &self.0 {
+            let modifications = group.modifications();
+            let modification_count = modifications.len();
+
+            let group_probabilities = match probabilities {
+                Some(buf) => {
+                    let len = group.positions().len() * modification_count;
+
+                    if buf.len() < len {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            "missing base modification probabilities",
+                        ));
+                    }
+
+                    let (head, tail) = buf.split_at(len);
+                    probabilities = Some(tail);
+                    Some(head)
+                }
+                None => None,
+            };
+
+            let is_implicit = group.status().unwrap_or_default() == Status::Implicit;
+            let mut positions = group.positions().iter().enumerate().peekable();
+
+            for read_position in unmodified_base_positions(
+                sequence,
+                is_reverse_complemented,
+                group.unmodified_base(),
+            ) {
+                let i = match positions.next_if(|(_, &position)| position == read_position) {
+                    Some((i, _)) => Some(i),
+                    None if is_implicit => None,
+                    None => continue,
+                };
+
+                for (j, &modification) in modifications.iter().enumerate() {
+                    let probability = match i {
+                        Some(i) => group_probabilities.map(|buf| buf[i * modification_count + j]),
+                        None => Some(0),
+                    };
+
+                    calls.push(Call {
+                        read_position,
+                        reference_position: reference_positions[read_position],
+                        strand: group.strand(),
+                        modification,
+                        probability,
+                    });
+                }
+            }
+
+            if positions.next().is_some() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "base modification position does not match sequence",
+                ));
+            }
+        }
+
+        Ok(calls)
+    }
+}
+
+// Returns the positions of the unmodified base in the sequence, in the original read orientation.
+pub(crate) fn unmodified_base_positions(
+    sequence: &Sequence,
+    is_reverse_complemented: bool,
+    unmodified_base: UnmodifiedBase,
+) -> Box<dyn Iterator<Item = usize> + '_> {
+    let unmodified_base = if is_reverse_complemented {
+        unmodified_base.complement()
+    } else {
+        unmodified_base
+    };
+
+    let positions = sequence
+        .as_ref()
+        .iter()
+        .enumerate()
+        .filter(move |(_, &base)| {
+            unmodified_base == UnmodifiedBase::N || base == u8::from(unmodified_base)
+        })
+        .map(|(i, _)| i);
+
+    if is_reverse_complemented {
+        Box::new(positions.rev())
+    } else {
+        Box::new(positions)
+    }
+}
+
+fn resolve_reference_positions<C>(
+    alignment_start: Position,
+    cigar: &C,
+    len: usize,
+) -> io::Result<Vec<Option<Position>>>
+where
+    C: Cigar + ?Sized,
+{
+    let mut reference_positions = vec![None; len];
+
+    let mut reference_position = usize::from(alignment_start);
+    let mut read_position = 0;
+
+    for result in cigar.iter() {
+        let op = result?;
+        let kind = op.kind();
+        let op_len = op.len();
+
+        if kind.consumes_read() && kind.consumes_reference() {
+            let end = (read_position + op_len).min(len);
+
+            if let Some(dst) = reference_positions.get_mut(read_position..end) {
+                for (i, position) in dst.iter_mut().enumerate() {
+                    *position = Position::new(reference_position + i);
+                }
+            }
+        }
+
+        if kind.consumes_read() {
+            read_position += op_len;
+        }
+
+        if kind.consumes_reference() {
+            reference_position += op_len;
+        }
+    }
+
+    Ok(reference_positions)
 }
 
 impl AsRef<[Group]> for BaseModifications {
@@ -79,3 +287,97 @@ impl From<BaseModifications> for Vec<Group> {
         base_modifications.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::{
+        record::cigar::{op::Kind, Op},
+        record_buf::Cigar as CigarBuf,
+    };
+
+    #[test]
+    fn test_calls() -> Result<(), Box<dyn std::error::Error>> {
+        fn summarize(calls: &[Call]) -> Vec<(usize, Option<usize>, u8, Option<u8>)> {
+            calls
+                .iter()
+                .map(|call| {
+                    let group::Modification::Code(code) = call.modification() else {
+                        unreachable!();
+                    };
+
+                    (
+                        call.read_position(),
+                        call.reference_position().map(usize::from),
+                        code,
+                        call.probability(),
+                    )
+                })
+                .collect()
+        }
+
+        let sequence = Sequence::from(b"CACCCGATGACCGGCT");
+        let cigar = CigarBuf::default();
+
+        let base_modifications = BaseModifications::parse("C+mh,1,3,0;", false, &sequence)?;
+        let probabilities = [10, 11, 20, 21, 30, 31];
+        let calls =
+            base_modifications.calls(&sequence, false, Some(&probabilities), None, &cigar)?;
+
+        assert_eq!(
+            summarize(&calls),
+            [
+                (0, None, b'm', Some(0)),
+                (0, None, b'h', Some(0)),
+                (2, None, b'm', Some(10)),
+                (2, None, b'h', Some(11)),
+                (3, None, b'm', Some(0)),
+                (3, None, b'h', Some(0)),
+                (4, None, b'm', Some(0)),
+                (4, None, b'h', Some(0)),
+                (10, None, b'm', Some(0)),
+                (10, None, b'h', Some(0)),
+                (11, None, b'm', Some(20)),
+                (11, None, b'h', Some(21)),
+                (14, None, b'm', Some(30)),
+                (14, None, b'h', Some(31)),
+            ]
+        );
+
+        // 4S8M2I2M
+        let cigar: CigarBuf = [
+            Op::new(Kind::SoftClip, 4),
+            Op::new(Kind::Match, 8),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Match, 2),
+        ]
+        .into_iter()
+        .collect();
+
+        let base_modifications = BaseModifications::parse("C+m?,0,1;", true, &sequence)?;
+        let calls = base_modifications.calls(&sequence, true, None, Position::new(100), &cigar)?;
+
+        assert_eq!(
+            summarize(&calls),
+            [(13, None, b'm', None), (8, Some(104), b'm', None)]
+        );
+        assert!(calls
+            .iter()
+            .all(|call| call.strand() == group::Strand::Forward));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calls_with_missing_probabilities() -> Result<(), Box<dyn std::error::Error>> {
+        let sequence = Sequence::from(b"CACCCGATGACCGGCT");
+        let base_modifications = BaseModifications::parse("C+m,1,3,0;", false, &sequence)?;
+
+        assert!(matches!(
+            base_modifications.calls(&sequence, false, Some(&[0, 0]), None, &CigarBuf::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+use noodles_core::Position;
+
+use super::group::{Modification, Strand};
+
+/// A resolved base modification call.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Call {
+    pub(super) read_position: usize,
+    pub(super) reference_position: Option<Position>,
+    pub(super) strand: Strand,
+    pub(super) modification: Modification,
+    pub(super) probability: Option<u8>,
+}
+
+impl Call {
+    /// Returns the 0-based position in the record sequence.
+    ///
+    /// Like the record sequence, this is relative to the reference strand, i.e., it is already
+    /// adjusted for reverse complemented records.
+    pub fn read_position(&self) -> usize {
+        self.read_position
+    }
+
+    /// Returns the aligned reference position.
+    ///
+    /// This is `None` if the record is unmapped or the base is not aligned to the reference
+    /// sequence, e.g., in an insertion or soft clip.
+    pub fn reference_position(&self) -> Option<Position> {
+        self.reference_position
+    }
+
+    /// Returns the strand on which the modification was observed.
+    ///
+    /// This is relative to the original read, not the reference.
+    pub fn strand(&self) -> Strand {
+        self.strand
+    }
+
+    /// Returns the modification.
+    pub fn modification(&self) -> Modification {
+        self.modification
+    }
+
+    /// Returns the probability of the modification (`ML`).
+    ///
+    /// The probability is in the range [0, 255], which maps to [0.0, 1.0]. This is `None` if no
+    /// probabilities were given.
+    pub fn probability(&self) -> Option<u8> {
+        self.probability
+    }
+}
@@ -2,7 +2,9 @@ use std::{error, fmt};
 
 use crate::{
     alignment::record_buf::Sequence,
-    record::data::field::value::base_modifications::{group::UnmodifiedBase, Group},
+    record::data::field::value::base_modifications::{
+        group::UnmodifiedBase, unmodified_base_positions, Group,
+    },
 };
 
 mod modifications;
@@ -135,30 +137,7 @@ fn decode_positions(
 ) -> Result<Vec<usize>, ParseError> {
     let mut positions = Vec::with_capacity(skip_counts.len());
 
-    let mut iter: Box<dyn Iterator<Item = usize>> = if is_reverse_complemented {
-        let unmodified_base = u8::from(unmodified_base.complement());
-
-        Box::new(
-            sequence
-                .as_ref()
-                .iter()
-                .enumerate()
-                .rev()
-                .filter(move |(_, &base)| base == unmodified_base)
-                .map(|(i, _)| i),
-        )
-    } else {
-        let unmodified_base = u8::from(unmodified_base);
-
-        Box::new(
-            sequence
-                .as_ref()
-                .iter()
-                .enumerate()
-                .filter(move |(_, &base)| base == unmodified_base)
-                .map(|(i, _)| i),
-        )
-    };
+    let mut iter = unmodified_base_positions(sequence, is_reverse_complemented, unmodified_base);
 
     for &count in skip_counts {
         let i = iter.nth(count).unwrap();
@@ -0,0 +1,40 @@
+//! Sorts an alignment file by coordinate.
+//!
+//! The output format is determined from the extension of the destination.
+
+use std::{env, io};
+
+use noodles_sam::alignment::RecordBuf;
+use noodles_util::alignment;
+
+fn main() -> io::Result<()> {
+    let mut args = env::args().skip(1);
+
+    let src = args.next().expect("missing src");
+    let dst = args.next().expect("missing dst");
+
+    let mut reader = alignment::io::reader::Builder::default().build_from_path(src)?;
+    let header = reader.read_header()?;
+
+    let mut sorter = alignment::sort::Builder::default().build(header.clone());
+
+    for result in reader.records(&header) {
+        let record = result?;
+        let record = RecordBuf::try_from_alignment_record(&header, &record)?;
+        sorter.push(record)?;
+    }
+
+    let header = sorter.header().clone();
+
+    let mut writer = alignment::io::writer::Builder::default().build_from_path(dst)?;
+    writer.write_header(&header)?;
+
+    for result in sorter.finish()? {
+        let record = result?;
+        writer.write_record(&header, &record)?;
+    }
+
+    writer.finish(&header)?;
+
+    Ok(())
+}
@@ -3,5 +3,15 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod calmd;
+pub mod clip;
+pub mod collate;
+pub mod coverage;
+pub mod expression;
 pub mod io;
 pub mod iter;
+pub mod orientation;
+pub mod sequence_dictionary;
+pub mod sort;
+pub mod stats;
+pub mod transcode;
@@ -0,0 +1,472 @@
+//! Alignment record MD and NM calculation.
+//!
+//! This calculates the mismatched positions (`MD`) and edit distance (`NM`) of an alignment
+//! record from its reference sequence, similar to `samtools calmd`.
+
+use std::{error, fmt, io};
+
+use bstr::{BStr, BString};
+use noodles_fasta as fasta;
+use noodles_sam::{
+    alignment::{
+        record::{
+            cigar::op::Kind,
+            data::field::{Tag, Value},
+        },
+        record_buf::data::field::Value as ValueBuf,
+        Record, RecordBuf,
+    },
+    Header,
+};
+
+/// Calculated mismatched positions (`MD`) and edit distance (`NM`).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tags {
+    mismatched_positions: BString,
+    edit_distance: u32,
+}
+
+impl Tags {
+    /// Returns the mismatched positions (`MD`).
+    pub fn mismatched_positions(&self) -> &BStr {
+        self.mismatched_positions.as_ref()
+    }
+
+    /// Returns the edit distance (`NM`).
+    pub fn edit_distance(&self) -> u32 {
+        self.edit_distance
+    }
+}
+
+/// An error returned when an alignment record fails verification.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VerifyError {
+    /// The mismatched positions (`MD`) differ from the calculated value.
+    InvalidMismatchedPositions {
+        /// The value in the record.
+        actual: BString,
+        /// The calculated value.
+        expected: BString,
+    },
+    /// The edit distance (`NM`) differs from the calculated value.
+    InvalidEditDistance {
+        /// The value in the record.
+        actual: i64,
+        /// The calculated value.
+        expected: u32,
+    },
+}
+
+impl error::Error for VerifyError {}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMismatchedPositions { actual, expected } => {
+                write!(f, "invalid MD: expected {expected}, got {actual}")
+            }
+            Self::InvalidEditDistance { actual, expected } => {
+                write!(f, "invalid NM: expected {expected}, got {actual}")
+            }
+        }
+    }
+}
+
+/// Calculates the mismatched positions (`MD`) and edit distance (`NM`) of a record.
+///
+/// The given reference sequence is the full reference sequence the record is aligned to. Bases
+/// are compared case-insensitively. An `N` never matches, and `=` in the read always matches.
+///
+/// This returns `None` if the record has no alignment start or no sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::calmd;
+///
+/// let record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"CGAA".to_vec().into())
+///     .build();
+///
+/// let tags = calmd::calculate(b"ACGTAC", &record)?.expect("missing tags");
+/// assert_eq!(tags.mismatched_positions(), "2T1");
+/// assert_eq!(tags.edit_distance(), 1);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn calculate<R>(reference_sequence: &[u8], record: &R) -> io::Result<Option<Tags>>
+where
+    R: Record + ?Sized,
+{
+    let Some(alignment_start) = record.alignment_start().transpose()? else {
+        return Ok(None);
+    };
+
+    let sequence: Vec<_> = record.sequence().iter().collect();
+
+    if sequence.is_empty() {
+        return Ok(None);
+    }
+
+    let mut reference_position = usize::from(alignment_start) - 1;
+    let mut read_position = 0;
+
+    let mut mismatched_positions = BString::default();
+    let mut edit_distance = 0;
+    let mut match_count = 0;
+
+    for result in record.cigar().iter() {
+        let op = result?;
+        let len = op.len();
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                let reference_bases =
+                    get_reference_bases(reference_sequence, reference_position, len)?;
+
+                let read_bases = sequence
+                    .get(read_position..read_position + len)
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "read position out of range")
+                    })?;
+
+                for (&reference_base, &read_base) in reference_bases.iter().zip(read_bases) {
+                    if is_match(reference_base, read_base) {
+                        match_count += 1;
+                    } else {
+                        push_match_count(&mut mismatched_positions, match_count);
+                        mismatched_positions.push(reference_base.to_ascii_uppercase());
+                        match_count = 0;
+                        edit_distance += 1;
+                    }
+                }
+
+                reference_position += len;
+                read_position += len;
+            }
+            Kind::Insertion => {
+                read_position += len;
+                edit_distance += len;
+            }
+            Kind::Deletion => {
+                let reference_bases =
+                    get_reference_bases(reference_sequence, reference_position, len)?;
+
+                push_match_count(&mut mismatched_positions, match_count);
+                mismatched_positions.push(b'^');
+                mismatched_positions.extend(reference_bases.iter().map(u8::to_ascii_uppercase));
+                match_count = 0;
+
+                reference_position += len;
+                edit_distance += len;
+            }
+            Kind::Skip => reference_position += len,
+            Kind::SoftClip => read_position += len,
+            Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    push_match_count(&mut mismatched_positions, match_count);
+
+    let edit_distance =
+        u32::try_from(edit_distance).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok(Some(Tags {
+        mismatched_positions,
+        edit_distance,
+    }))
+}
+
+/// Calculates and sets the mismatched positions (`MD`) and edit distance (`NM`) of a record.
+///
+/// The reference sequence is read from the given repository. Existing `MD` and `NM` fields are
+/// replaced. Records that are unmapped or have no sequence are not modified.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, data::field::Tag, Flags},
+///         record_buf::data::field::Value,
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::calmd;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(6)?))
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTAC".to_vec()),
+/// )]);
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"CGAA".to_vec().into())
+///     .build();
+///
+/// calmd::update(&header, &repository, &mut record)?;
+///
+/// assert_eq!(
+///     record.data().get(&Tag::MISMATCHED_POSITIONS),
+///     Some(&Value::from("2T1"))
+/// );
+/// assert_eq!(record.data().get(&Tag::EDIT_DISTANCE), Some(&Value::from(1u8)));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn update(
+    header: &Header,
+    repository: &fasta::Repository,
+    record: &mut RecordBuf,
+) -> io::Result<()> {
+    let Some(tags) = calculate_from_repository(header, repository, record)? else {
+        return Ok(());
+    };
+
+    let data = record.data_mut();
+
+    data.insert(
+        Tag::MISMATCHED_POSITIONS,
+        ValueBuf::String(tags.mismatched_positions),
+    );
+
+    data.insert(Tag::EDIT_DISTANCE, ValueBuf::from(tags.edit_distance));
+
+    Ok(())
+}
+
+/// Verifies the mismatched positions (`MD`) and edit distance (`NM`) of a record.
+///
+/// The reference sequence is read from the given repository. Only fields that are present in the
+/// record are checked. Records that are unmapped or have no sequence are not checked.
+///
+/// If a field differs from its calculated value, this returns an error of kind
+/// [`io::ErrorKind::InvalidData`] with a [`VerifyError`] as its inner error.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, data::field::Tag, Flags},
+///         record_buf::data::field::Value,
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::calmd;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(6)?))
+///     .build();
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     Definition::new("sq0", None),
+///     Sequence::from(b"ACGTAC".to_vec()),
+/// )]);
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"CGAA".to_vec().into())
+///     .set_data([(Tag::EDIT_DISTANCE, Value::from(0u8))].into_iter().collect())
+///     .build();
+///
+/// assert!(calmd::verify(&header, &repository, &record).is_err());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn verify<R>(header: &Header, repository: &fasta::Repository, record: &R) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    let Some(tags) = calculate_from_repository(header, repository, record)? else {
+        return Ok(());
+    };
+
+    let data = record.data();
+
+    if let Some(value) = data.get(&Tag::MISMATCHED_POSITIONS).transpose()? {
+        let Value::String(mismatched_positions) = value else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid MD type",
+            ));
+        };
+
+        if mismatched_positions != tags.mismatched_positions {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                VerifyError::InvalidMismatchedPositions {
+                    actual: mismatched_positions.into(),
+                    expected: tags.mismatched_positions,
+                },
+            ));
+        }
+    }
+
+    if let Some(value) = data.get(&Tag::EDIT_DISTANCE).transpose()? {
+        let edit_distance = value
+            .as_int()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid NM type"))?;
+
+        if edit_distance != i64::from(tags.edit_distance) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                VerifyError::InvalidEditDistance {
+                    actual: edit_distance,
+                    expected: tags.edit_distance,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn calculate_from_repository<R>(
+    header: &Header,
+    repository: &fasta::Repository,
+    record: &R,
+) -> io::Result<Option<Tags>>
+where
+    R: Record + ?Sized,
+{
+    if record.flags()?.is_unmapped() {
+        return Ok(None);
+    }
+
+    let Some((name, _)) = record.reference_sequence(header).transpose()? else {
+        return Ok(None);
+    };
+
+    let reference_sequence = repository.get(name).transpose()?.ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("missing reference sequence: {name}"),
+        )
+    })?;
+
+    calculate(reference_sequence.as_ref(), record)
+}
+
+fn get_reference_bases(reference_sequence: &[u8], start: usize, len: usize) -> io::Result<&[u8]> {
+    reference_sequence.get(start..start + len).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "reference sequence position out of range",
+        )
+    })
+}
+
+fn is_match(reference_base: u8, read_base: u8) -> bool {
+    read_base == b'='
+        || (read_base.eq_ignore_ascii_case(&reference_base)
+            && !read_base.eq_ignore_ascii_case(&b'N'))
+}
+
+fn push_match_count(dst: &mut BString, n: usize) {
+    dst.extend_from_slice(n.to_string().as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::record::cigar::Op;
+
+    use super::*;
+
+    #[test]
+    fn test_calculate() -> Result<(), Box<dyn std::error::Error>> {
+        // 1S3M1I2D2M
+        let record = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar(
+                [
+                    Op::new(Kind::SoftClip, 1),
+                    Op::new(Kind::Match, 3),
+                    Op::new(Kind::Insertion, 1),
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(b"NCATGGA".to_vec().into())
+            .build();
+
+        let tags = calculate(b"acgtacgtac", &record)?;
+
+        assert_eq!(
+            tags,
+            Some(Tags {
+                mismatched_positions: BString::from("1G1^AC1T0"),
+                edit_distance: 5,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_ambiguous_bases() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 3)].into_iter().collect())
+            .set_sequence(b"N=G".to_vec().into())
+            .build();
+
+        let tags = calculate(b"NCT", &record)?;
+
+        assert_eq!(
+            tags,
+            Some(Tags {
+                mismatched_positions: BString::from("0N1T0"),
+                edit_distance: 2,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_calculate_with_out_of_range_reference_sequence(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(2)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        assert!(matches!(
+            calculate(b"ACG", &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,576 @@
+//! Alignment record clipping.
+//!
+//! This soft clips or hard clips aligned bases from the ends of an alignment record, adjusting the
+//! CIGAR, alignment start, sequence, and quality scores.
+//!
+//! Clipping is done in reference orientation, i.e., leading bases are at the alignment start, and
+//! trailing bases are at the alignment end, regardless of the strand of the record. Existing
+//! clips are kept, and the given length is the number of additional aligned bases to clip.
+//!
+//! Deletions and skips adjacent to a new clip are removed, and insertions adjacent to a new clip
+//! are clipped.
+//!
+//! The mismatched positions (`MD`) and edit distance (`NM`) data fields are not updated. These
+//! can be recalculated using [`super::calmd::update`].
+
+use std::io;
+
+use noodles_core::{region::Interval, Position};
+use noodles_sam::alignment::{
+    record::cigar::{op::Kind, Op},
+    RecordBuf,
+};
+
+/// A clipping mode.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// Soft clip (`S`).
+    ///
+    /// Clipped bases are kept in the sequence and quality scores.
+    #[default]
+    Soft,
+    /// Hard clip (`H`).
+    ///
+    /// Clipped bases, including any existing soft clipped bases at the same end, are removed
+    /// from the sequence and quality scores.
+    Hard,
+}
+
+/// Clips aligned bases from the start of the alignment.
+///
+/// The alignment start is moved forward by the number of reference bases no longer covered by
+/// the alignment.
+///
+/// # Errors
+///
+/// This returns an error if the record has no alignment start or if the clip would remove all
+/// aligned bases. On error, the record is not modified.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::clip::{self, Mode};
+///
+/// let mut record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACGT".to_vec().into())
+///     .build();
+///
+/// clip::leading(&mut record, 1, Mode::Soft)?;
+///
+/// assert_eq!(record.alignment_start(), Some(Position::try_from(9)?));
+/// assert_eq!(
+///     record.cigar().as_ref(),
+///     [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 3)]
+/// );
+/// assert_eq!(record.sequence().as_ref(), b"ACGT");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn leading(record: &mut RecordBuf, len: usize, mode: Mode) -> io::Result<()> {
+    let alignment_start = get_alignment_start(record)?;
+
+    if len == 0 {
+        return Ok(());
+    }
+
+    let mut ops = record.cigar().as_ref().to_vec();
+    let (reference_len, removed_len) = clip_leading_ops(&mut ops, len, mode)?;
+
+    let alignment_start = alignment_start
+        .checked_add(reference_len)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid alignment start"))?;
+
+    remove_bases(record, 0..removed_len)?;
+    *record.alignment_start_mut() = Some(alignment_start);
+    *record.cigar_mut() = ops.into();
+
+    Ok(())
+}
+
+/// Clips aligned bases from the end of the alignment.
+///
+/// # Errors
+///
+/// This returns an error if the record has no alignment start or if the clip would remove all
+/// aligned bases. On error, the record is not modified.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::clip::{self, Mode};
+///
+/// let mut record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACGT".to_vec().into())
+///     .build();
+///
+/// clip::trailing(&mut record, 1, Mode::Hard)?;
+///
+/// assert_eq!(record.alignment_start(), Some(Position::try_from(8)?));
+/// assert_eq!(
+///     record.cigar().as_ref(),
+///     [Op::new(Kind::Match, 3), Op::new(Kind::HardClip, 1)]
+/// );
+/// assert_eq!(record.sequence().as_ref(), b"ACG");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn trailing(record: &mut RecordBuf, len: usize, mode: Mode) -> io::Result<()> {
+    get_alignment_start(record)?;
+
+    if len == 0 {
+        return Ok(());
+    }
+
+    let mut ops = record.cigar().as_ref().to_vec();
+
+    ops.reverse();
+    let (_, removed_len) = clip_leading_ops(&mut ops, len, mode)?;
+    ops.reverse();
+
+    let read_length = record.sequence().len();
+
+    if removed_len > 0 && !record.sequence().is_empty() {
+        let start = read_length.checked_sub(removed_len).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "sequence length mismatch")
+        })?;
+
+        remove_bases(record, start..read_length)?;
+    }
+
+    *record.cigar_mut() = ops.into();
+
+    Ok(())
+}
+
+/// Clips aligned bases outside of the given reference interval.
+///
+/// This is typically used to trim primer sequences from amplicon reads, where the interval is the
+/// amplicon insert.
+///
+/// # Errors
+///
+/// This returns an error if the record has no alignment start or if the alignment does not
+/// intersect the interval. On error, the record is not modified.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::cigar::{op::Kind, Op},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::clip::{self, Mode};
+///
+/// let mut record = RecordBuf::builder()
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+///     .set_sequence(b"ACGTACGT".to_vec().into())
+///     .build();
+///
+/// let interval = Position::try_from(10)?..=Position::try_from(13)?;
+/// clip::to_interval(&mut record, interval.into(), Mode::Soft)?;
+///
+/// assert_eq!(record.alignment_start(), Some(Position::try_from(10)?));
+/// assert_eq!(
+///     record.cigar().as_ref(),
+///     [
+///         Op::new(Kind::SoftClip, 2),
+///         Op::new(Kind::Match, 4),
+///         Op::new(Kind::SoftClip, 2),
+///     ]
+/// );
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_interval(record: &mut RecordBuf, interval: Interval, mode: Mode) -> io::Result<()> {
+    let alignment_start = get_alignment_start(record)?;
+    let alignment_end = record
+        .alignment_end()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing alignment end"))?;
+
+    if !interval.intersects((alignment_start..=alignment_end).into()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "alignment does not intersect interval",
+        ));
+    }
+
+    let ops = record.cigar().as_ref();
+
+    let leading_len = interval
+        .start()
+        .map(|start| count_leading_read_bases(ops, alignment_start, start))
+        .unwrap_or_default();
+
+    let trailing_len = interval
+        .end()
+        .map(|end| count_trailing_read_bases(ops, alignment_end, end))
+        .unwrap_or_default();
+
+    let mut clipped_record = record.clone();
+    leading(&mut clipped_record, leading_len, mode)?;
+    trailing(&mut clipped_record, trailing_len, mode)?;
+    *record = clipped_record;
+
+    Ok(())
+}
+
+fn get_alignment_start(record: &RecordBuf) -> io::Result<Position> {
+    record
+        .alignment_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing alignment start"))
+}
+
+// Clips `len` aligned read bases from the front of `ops`.
+//
+// This returns the number of reference bases no longer covered by the alignment and the number
+// of read bases to remove from the front of the sequence and quality scores.
+fn clip_leading_ops(ops: &mut Vec<Op>, len: usize, mode: Mode) -> io::Result<(usize, usize)> {
+    let mut i = 0;
+
+    let mut hard_clip_len = 0;
+
+    while let Some(op) = ops.get(i).filter(|op| op.kind() == Kind::HardClip) {
+        hard_clip_len += op.len();
+        i += 1;
+    }
+
+    let mut soft_clip_len = 0;
+
+    while let Some(op) = ops.get(i).filter(|op| op.kind() == Kind::SoftClip) {
+        soft_clip_len += op.len();
+        i += 1;
+    }
+
+    let mut body = ops.split_off(i);
+    let mut j = 0;
+
+    let mut remaining = len;
+    let mut clipped_len = 0;
+    let mut reference_len = 0;
+
+    while remaining > 0 {
+        let op = body.get_mut(j).ok_or_else(clip_length_error)?;
+        let kind = op.kind();
+
+        match kind {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Insertion => {
+                let n = op.len().min(remaining);
+
+                remaining -= n;
+                clipped_len += n;
+
+                if kind.consumes_reference() {
+                    reference_len += n;
+                }
+
+                if n < op.len() {
+                    *op = Op::new(kind, op.len() - n);
+                } else {
+                    j += 1;
+                }
+            }
+            Kind::Deletion | Kind::Skip => {
+                reference_len += op.len();
+                j += 1;
+            }
+            Kind::Pad => j += 1,
+            Kind::SoftClip | Kind::HardClip => return Err(clip_length_error()),
+        }
+    }
+
+    while let Some(op) = body.get(j) {
+        match op.kind() {
+            Kind::Deletion | Kind::Skip => reference_len += op.len(),
+            Kind::Insertion => clipped_len += op.len(),
+            Kind::Pad => {}
+            _ => break,
+        }
+
+        j += 1;
+    }
+
+    body.drain(..j);
+
+    let has_aligned_bases = body.iter().any(|op| {
+        matches!(
+            op.kind(),
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch
+        )
+    });
+
+    if !has_aligned_bases {
+        return Err(clip_length_error());
+    }
+
+    let (clips, removed_len) = match mode {
+        Mode::Soft => (
+            [
+                Op::new(Kind::HardClip, hard_clip_len),
+                Op::new(Kind::SoftClip, soft_clip_len + clipped_len),
+            ],
+            0,
+        ),
+        Mode::Hard => (
+            [
+                Op::new(Kind::HardClip, hard_clip_len + soft_clip_len + clipped_len),
+                Op::new(Kind::SoftClip, 0),
+            ],
+            soft_clip_len + clipped_len,
+        ),
+    };
+
+    ops.clear();
+    ops.extend(clips.into_iter().filter(|op| !op.is_empty()));
+    ops.extend(body);
+
+    Ok((reference_len, removed_len))
+}
+
+fn clip_length_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "clip length exceeds aligned read length",
+    )
+}
+
+fn remove_bases(record: &mut RecordBuf, range: std::ops::Range<usize>) -> io::Result<()> {
+    if range.is_empty() {
+        return Ok(());
+    }
+
+    let sequence = record.sequence_mut().as_mut();
+
+    if !sequence.is_empty() {
+        if range.end > sequence.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "sequence length mismatch",
+            ));
+        }
+
+        sequence.drain(range.clone());
+    }
+
+    let quality_scores = record.quality_scores_mut().as_mut();
+
+    if !quality_scores.is_empty() {
+        if range.end > quality_scores.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "quality scores length mismatch",
+            ));
+        }
+
+        quality_scores.drain(range);
+    }
+
+    Ok(())
+}
+
+fn count_leading_read_bases(ops: &[Op], alignment_start: Position, start: Position) -> usize {
+    let start = usize::from(start);
+    let mut reference_position = usize::from(alignment_start);
+    let mut n = 0;
+
+    for op in ops {
+        if reference_position >= start {
+            break;
+        }
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                n += op.len().min(start - reference_position);
+                reference_position += op.len();
+            }
+            Kind::Insertion => n += op.len(),
+            Kind::Deletion | Kind::Skip => reference_position += op.len(),
+            Kind::SoftClip | Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    n
+}
+
+fn count_trailing_read_bases(ops: &[Op], alignment_end: Position, end: Position) -> usize {
+    let end = usize::from(end);
+    let mut reference_position = usize::from(alignment_end);
+    let mut n = 0;
+
+    for op in ops.iter().rev() {
+        if reference_position <= end {
+            break;
+        }
+
+        match op.kind() {
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
+                n += op.len().min(reference_position - end);
+                reference_position = reference_position.saturating_sub(op.len());
+            }
+            Kind::Insertion => n += op.len(),
+            Kind::Deletion | Kind::Skip => {
+                reference_position = reference_position.saturating_sub(op.len());
+            }
+            Kind::SoftClip | Kind::HardClip | Kind::Pad => {}
+        }
+    }
+
+    n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_leading() -> Result<(), Box<dyn std::error::Error>> {
+        // 2S3M1I2M2D3M
+        let ops = [
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 3),
+            Op::new(Kind::Insertion, 1),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::Deletion, 2),
+            Op::new(Kind::Match, 3),
+        ];
+
+        let src = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(ops.into_iter().collect())
+            .set_sequence(b"NNACGTACGTA".to_vec().into())
+            .set_quality_scores(vec![30; 11].into())
+            .build();
+
+        let mut record = src.clone();
+        leading(&mut record, 3, Mode::Soft)?;
+
+        assert_eq!(record.alignment_start(), Position::new(13));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [
+                Op::new(Kind::SoftClip, 6),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::Deletion, 2),
+                Op::new(Kind::Match, 3),
+            ]
+        );
+        assert_eq!(record.sequence().as_ref(), b"NNACGTACGTA");
+
+        let mut record = src.clone();
+        leading(&mut record, 6, Mode::Hard)?;
+
+        assert_eq!(record.alignment_start(), Position::new(17));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [Op::new(Kind::HardClip, 8), Op::new(Kind::Match, 3)]
+        );
+        assert_eq!(record.sequence().as_ref(), b"GTA");
+        assert_eq!(record.quality_scores().as_ref(), [30, 30, 30]);
+
+        let mut record = src.clone();
+        assert!(matches!(
+            leading(&mut record, 9, Mode::Soft),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+        assert_eq!(record, src);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailing() -> Result<(), Box<dyn std::error::Error>> {
+        // 1H3M2D2M1S
+        let ops = [
+            Op::new(Kind::HardClip, 1),
+            Op::new(Kind::Match, 3),
+            Op::new(Kind::Deletion, 2),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::SoftClip, 1),
+        ];
+
+        let src = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(ops.into_iter().collect())
+            .set_sequence(b"ACGTAN".to_vec().into())
+            .set_quality_scores(vec![30; 6].into())
+            .build();
+
+        let mut record = src.clone();
+        trailing(&mut record, 2, Mode::Hard)?;
+
+        assert_eq!(record.alignment_start(), Position::new(10));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [
+                Op::new(Kind::HardClip, 1),
+                Op::new(Kind::Match, 3),
+                Op::new(Kind::HardClip, 3),
+            ]
+        );
+        assert_eq!(record.sequence().as_ref(), b"ACG");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_interval() -> Result<(), Box<dyn std::error::Error>> {
+        let ops = [
+            Op::new(Kind::Match, 4),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 4),
+        ];
+
+        let src = RecordBuf::builder()
+            .set_alignment_start(Position::try_from(10)?)
+            .set_cigar(ops.into_iter().collect())
+            .set_sequence(b"ACGTACGT".to_vec().into())
+            .set_quality_scores(vec![30; 8].into())
+            .build();
+
+        let mut record = src.clone();
+        let interval = Interval::from(Position::try_from(12)?..);
+        to_interval(&mut record, interval, Mode::Soft)?;
+
+        assert_eq!(record.alignment_start(), Position::new(12));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [
+                Op::new(Kind::SoftClip, 2),
+                Op::new(Kind::Match, 2),
+                Op::new(Kind::Deletion, 1),
+                Op::new(Kind::Match, 4),
+            ]
+        );
+
+        let mut record = src.clone();
+        let interval = Interval::from(..=Position::try_from(14)?);
+        to_interval(&mut record, interval, Mode::Soft)?;
+
+        assert_eq!(record.alignment_start(), Position::new(10));
+        assert_eq!(
+            record.cigar().as_ref(),
+            [Op::new(Kind::Match, 4), Op::new(Kind::SoftClip, 4)]
+        );
+
+        let mut record = src.clone();
+        let interval = Interval::from(Position::try_from(20)?..);
+        assert!(matches!(
+            to_interval(&mut record, interval, Mode::Soft),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,658 @@
+//! Alignment record collation.
+
+pub mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap, VecDeque},
+    fs::File,
+    hash::{Hash, Hasher},
+    io, mem,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    vec,
+};
+
+use bstr::{BStr, BString};
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{
+    alignment::RecordBuf,
+    header::record::value::{
+        map::{
+            self,
+            header::{group_order, sort_order, tag, Version},
+        },
+        Map,
+    },
+    Header,
+};
+
+use super::sort::{create_temp_file, remove_temp_files};
+
+/// An alignment record collator.
+///
+/// A collator groups records with the same read name together without fully sorting them, similar
+/// to `samtools collate`. This is typically much faster than a query name sort and is sufficient
+/// for consumers that only need the records of a template to be adjacent, e.g., converting to
+/// FASTQ or [`crate::alignment::iter::Templates`].
+///
+/// Records are distributed to buckets by a hash of their read names. When the number of buffered
+/// records reaches the maximum record count, the buffered records of each bucket are spilled to
+/// the bucket's temporary BAM file. On [`Collator::finish`], each bucket is read back, one at a
+/// time, and its records are grouped by read name. A bucket with more records than the maximum
+/// record count is first redistributed to new buckets using a different hash, so only records
+/// with the same read name can exceed the limit.
+///
+/// Groups are emitted in an arbitrary order, but the records within a group keep their input
+/// order. Records without a read name are each their own group.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::collate;
+///
+/// let mut collator = collate::Builder::default().build(sam::Header::default());
+///
+/// collator.push(RecordBuf::builder().set_name("r0").build())?;
+/// collator.push(RecordBuf::builder().set_name("r1").build())?;
+/// collator.push(RecordBuf::builder().set_name("r0").build())?;
+///
+/// let names: Vec<_> = collator
+///     .finish()?
+///     .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(names.len(), 3);
+/// assert!(names[0] == names[1] || names[1] == names[2]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Collator {
+    header: Header,
+    options: Options,
+    partition: Partition,
+    chunks: Vec<PathBuf>,
+}
+
+impl Collator {
+    fn new(
+        mut header: Header,
+        bucket_count: NonZeroUsize,
+        max_record_count: NonZeroUsize,
+        temp_dir: PathBuf,
+    ) -> Self {
+        set_group_order(&mut header);
+
+        Self {
+            header,
+            options: Options {
+                bucket_count,
+                max_record_count,
+                temp_dir,
+            },
+            partition: Partition::new(0, bucket_count),
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Returns the SAM header.
+    ///
+    /// This is the input header with the sort order (`SO`) set to `unsorted` and the group order
+    /// (`GO`) set to `query`. Any subsort order (`SS`) is removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::tag};
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let collator = collate::Builder::default().build(sam::Header::default());
+    ///
+    /// let group_order = collator
+    ///     .header()
+    ///     .header()
+    ///     .and_then(|map| map.other_fields().get(&tag::GROUP_ORDER));
+    ///
+    /// assert_eq!(group_order.map(|value| value.as_slice()), Some(&b"query"[..]));
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Adds a record to the collator.
+    ///
+    /// This may spill the buffered records to temporary files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let mut collator = collate::Builder::default().build(sam::Header::default());
+    /// collator.push(RecordBuf::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn push(&mut self, record: RecordBuf) -> io::Result<()> {
+        self.partition
+            .push(&self.header, &self.options, &mut self.chunks, record)
+    }
+
+    /// Finishes adding records and returns an iterator over the collated records.
+    ///
+    /// Temporary files are removed when the returned iterator is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let collator = collate::Builder::default().build(sam::Header::default());
+    /// let mut records = collator.finish()?;
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<Records> {
+        let partition = mem::replace(&mut self.partition, Partition::new(0, NonZeroUsize::MIN));
+        let buckets = partition.finish()?;
+
+        Ok(Records {
+            header: mem::take(&mut self.header),
+            options: self.options.clone(),
+            buckets: buckets.into(),
+            records: Vec::new().into_iter(),
+            chunks: mem::take(&mut self.chunks),
+        })
+    }
+}
+
+impl Drop for Collator {
+    fn drop(&mut self) {
+        // Writers are closed before their files are removed.
+        self.partition.buckets.clear();
+        remove_temp_files(&self.chunks);
+    }
+}
+
+/// An iterator over collated alignment records.
+///
+/// This is created by calling [`Collator::finish`].
+pub struct Records {
+    header: Header,
+    options: Options,
+    buckets: VecDeque<PendingBucket>,
+    records: vec::IntoIter<RecordBuf>,
+    chunks: Vec<PathBuf>,
+}
+
+impl Records {
+    /// Returns the SAM header.
+    ///
+    /// This is the same as [`Collator::header`].
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    fn split(&mut self, bucket: PendingBucket) -> io::Result<()> {
+        let mut partition = Partition::new(bucket.level + 1, self.options.bucket_count);
+        let record_count = bucket.record_count;
+
+        let mut first_name: Option<Option<BString>> = None;
+        let mut has_distinct_names = false;
+
+        let mut push = |partition: &mut Partition, record: RecordBuf| {
+            let name = record.name().map(BString::from);
+
+            match &first_name {
+                Some(first_name) => has_distinct_names |= *first_name != name,
+                None => first_name = Some(name),
+            }
+
+            partition.push(&self.header, &self.options, &mut self.chunks, record)
+        };
+
+        if let Some(path) = &bucket.chunk {
+            let mut reader = File::open(path).map(bam::io::Reader::new)?;
+
+            loop {
+                let mut record = RecordBuf::default();
+
+                match reader.read_record_buf(&self.header, &mut record)? {
+                    0 => break,
+                    _ => push(&mut partition, record)?,
+                }
+            }
+        }
+
+        for record in bucket.records {
+            push(&mut partition, record)?;
+        }
+
+        // A bucket of records with distinct read names can still land in a single sub-bucket if
+        // their hashes collide. This is only final when splitting cannot separate the records,
+        // i.e., they all have the same read name or there is only one bucket.
+        let is_splittable = has_distinct_names && self.options.bucket_count.get() > 1;
+
+        for mut sub_bucket in partition.finish()?.into_iter().rev() {
+            if sub_bucket.record_count == record_count && !is_splittable {
+                sub_bucket.is_splittable = false;
+            }
+
+            self.buckets.push_front(sub_bucket);
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for Records {
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(record) = self.records.next() {
+                return Some(Ok(record));
+            }
+
+            let bucket = self.buckets.pop_front()?;
+
+            if bucket.is_splittable && bucket.record_count > self.options.max_record_count.get() {
+                if let Err(e) = self.split(bucket) {
+                    return Some(Err(e));
+                }
+
+                continue;
+            }
+
+            match bucket.read_records(&self.header) {
+                Ok(records) => self.records = group_by_name(records).into_iter(),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+impl Drop for Records {
+    fn drop(&mut self) {
+        remove_temp_files(&self.chunks);
+    }
+}
+
+#[derive(Clone)]
+struct Options {
+    bucket_count: NonZeroUsize,
+    max_record_count: NonZeroUsize,
+    temp_dir: PathBuf,
+}
+
+// A set of buckets whose records are distributed using the hash for a given level.
+struct Partition {
+    level: u32,
+    buckets: Vec<Bucket>,
+    buffered_record_count: usize,
+    unnamed_record_count: usize,
+}
+
+impl Partition {
+    fn new(level: u32, bucket_count: NonZeroUsize) -> Self {
+        Self {
+            level,
+            buckets: (0..bucket_count.get()).map(|_| Bucket::default()).collect(),
+            buffered_record_count: 0,
+            unnamed_record_count: 0,
+        }
+    }
+
+    fn push(
+        &mut self,
+        header: &Header,
+        options: &Options,
+        chunks: &mut Vec<PathBuf>,
+        record: RecordBuf,
+    ) -> io::Result<()> {
+        let i = match record.name() {
+            Some(name) => bucket_index(name, self.level, self.buckets.len()),
+            None => {
+                // Records without a read name are not grouped, so they are distributed evenly.
+                self.unnamed_record_count += 1;
+                self.unnamed_record_count % self.buckets.len()
+            }
+        };
+
+        let bucket = &mut self.buckets[i];
+        bucket.records.push(record);
+        bucket.record_count += 1;
+
+        self.buffered_record_count += 1;
+
+        if self.buffered_record_count >= options.max_record_count.get() {
+            self.spill(header, &options.temp_dir, chunks)?;
+        }
+
+        Ok(())
+    }
+
+    fn spill(
+        &mut self,
+        header: &Header,
+        temp_dir: &Path,
+        chunks: &mut Vec<PathBuf>,
+    ) -> io::Result<()> {
+        for bucket in &mut self.buckets {
+            if bucket.records.is_empty() {
+                continue;
+            }
+
+            let (_, writer) = match &mut bucket.chunk {
+                Some(chunk) => chunk,
+                None => {
+                    let (path, file) = create_temp_file(temp_dir, "collate")?;
+                    chunks.push(path.clone());
+
+                    let writer = bgzf::writer::Builder::default()
+                        .set_compression_level(bgzf::writer::CompressionLevel::FAST)
+                        .build_from_writer(file)
+                        .into();
+
+                    bucket.chunk.insert((path, writer))
+                }
+            };
+
+            for record in bucket.records.drain(..) {
+                writer.write_alignment_record(header, &record)?;
+            }
+        }
+
+        self.buffered_record_count = 0;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> io::Result<Vec<PendingBucket>> {
+        let mut buckets = Vec::with_capacity(self.buckets.len());
+
+        for bucket in &mut self.buckets {
+            let chunk = match bucket.chunk.take() {
+                Some((path, mut writer)) => {
+                    writer.try_finish()?;
+                    Some(path)
+                }
+                None => None,
+            };
+
+            if chunk.is_none() && bucket.records.is_empty() {
+                continue;
+            }
+
+            buckets.push(PendingBucket {
+                level: self.level,
+                chunk,
+                records: mem::take(&mut bucket.records),
+                record_count: bucket.record_count,
+                is_splittable: true,
+            });
+        }
+
+        Ok(buckets)
+    }
+}
+
+#[derive(Default)]
+struct Bucket {
+    records: Vec<RecordBuf>,
+    chunk: Option<(PathBuf, bam::io::Writer<bgzf::Writer<File>>)>,
+    record_count: usize,
+}
+
+struct PendingBucket {
+    level: u32,
+    chunk: Option<PathBuf>,
+    records: Vec<RecordBuf>,
+    record_count: usize,
+    is_splittable: bool,
+}
+
+impl PendingBucket {
+    fn read_records(self, header: &Header) -> io::Result<Vec<RecordBuf>> {
+        let Some(path) = self.chunk else {
+            return Ok(self.records);
+        };
+
+        let mut reader = File::open(path).map(bam::io::Reader::new)?;
+        let mut records = Vec::with_capacity(self.record_count);
+
+        loop {
+            let mut record = RecordBuf::default();
+
+            match reader.read_record_buf(header, &mut record)? {
+                0 => break,
+                _ => records.push(record),
+            }
+        }
+
+        // Buffered records were pushed after all spilled records.
+        records.extend(self.records);
+
+        Ok(records)
+    }
+}
+
+fn bucket_index(name: &BStr, level: u32, bucket_count: usize) -> usize {
+    // `DefaultHasher::new` uses fixed keys, so the output order is deterministic. The level is
+    // hashed so that a split bucket is redistributed differently.
+    let mut hasher = DefaultHasher::new();
+    level.hash(&mut hasher);
+    name.hash(&mut hasher);
+
+    // The remainder is < `bucket_count`, which is a `usize`.
+    (hasher.finish() % (bucket_count as u64)) as usize
+}
+
+fn group_by_name(records: Vec<RecordBuf>) -> Vec<RecordBuf> {
+    let mut groups: Vec<Vec<RecordBuf>> = Vec::new();
+    let mut indices: HashMap<Vec<u8>, usize> = HashMap::new();
+
+    for record in records {
+        let i = match record.name().map(|name| name.to_vec()) {
+            Some(name) => *indices.entry(name).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            }),
+            None => {
+                groups.push(Vec::new());
+                groups.len() - 1
+            }
+        };
+
+        groups[i].push(record);
+    }
+
+    groups.into_iter().flatten().collect()
+}
+
+fn set_group_order(header: &mut Header) {
+    let map = header
+        .header_mut()
+        .get_or_insert_with(|| Map::<map::Header>::new(Version::default()));
+
+    let other_fields = map.other_fields_mut();
+    other_fields.insert(tag::SORT_ORDER, sort_order::UNSORTED.into());
+    other_fields.insert(tag::GROUP_ORDER, group_order::QUERY.into());
+    other_fields.shift_remove(&tag::SUBSORT_ORDER);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, process};
+
+    use noodles_sam::alignment::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_set_group_order() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header: Header = "@HD\tVN:1.6\tSO:coordinate\tSS:coordinate:x\n".parse()?;
+        set_group_order(&mut header);
+        let expected: Header = "@HD\tVN:1.6\tSO:unsorted\tGO:query\n".parse()?;
+        assert_eq!(header, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_name() {
+        let records = [
+            (Some("r0"), Flags::FIRST_SEGMENT),
+            (Some("r1"), Flags::FIRST_SEGMENT),
+            (None, Flags::empty()),
+            (Some("r0"), Flags::LAST_SEGMENT),
+            (None, Flags::empty()),
+            (Some("r1"), Flags::LAST_SEGMENT),
+        ]
+        .into_iter()
+        .map(|(name, flags)| {
+            let mut builder = RecordBuf::builder().set_flags(flags);
+
+            if let Some(name) = name {
+                builder = builder.set_name(name);
+            }
+
+            builder.build()
+        })
+        .collect();
+
+        let actual: Vec<_> = group_by_name(records)
+            .iter()
+            .map(|record| (record.name().map(|name| name.to_string()), record.flags()))
+            .collect();
+
+        let expected = [
+            (Some(String::from("r0")), Flags::FIRST_SEGMENT),
+            (Some(String::from("r0")), Flags::LAST_SEGMENT),
+            (Some(String::from("r1")), Flags::FIRST_SEGMENT),
+            (Some(String::from("r1")), Flags::LAST_SEGMENT),
+            (None, Flags::empty()),
+            (None, Flags::empty()),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_finish_with_spilled_records() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = env::temp_dir().join(format!("noodles-util-collate-test-{}", process::id()));
+        fs::create_dir_all(&temp_dir)?;
+
+        let mut collator = Builder::default()
+            .set_bucket_count(NonZeroUsize::try_from(2)?)
+            .set_max_record_count(NonZeroUsize::try_from(3)?)
+            .set_temp_dir(&temp_dir)
+            .build(Header::default());
+
+        let names = ["r0", "r1", "r2", "r3", "r2", "r1", "r0", "r3"];
+
+        for (i, name) in names.into_iter().enumerate() {
+            let record = RecordBuf::builder()
+                .set_name(name)
+                .set_template_length(i32::try_from(i)?)
+                .build();
+
+            collator.push(record)?;
+        }
+
+        assert!(fs::read_dir(&temp_dir)?.count() > 0);
+
+        let records: Vec<_> = collator
+            .finish()?
+            .map(|result| {
+                result.map(|record| {
+                    let name = record.name().map(|name| name.to_string());
+                    (name, record.template_length())
+                })
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), names.len());
+
+        for pair in records.chunks(2) {
+            assert_eq!(pair[0].0, pair[1].0);
+            assert!(pair[0].1 < pair[1].1);
+        }
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 0);
+        fs::remove_dir(&temp_dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_split_buckets() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir =
+            env::temp_dir().join(format!("noodles-util-collate-split-test-{}", process::id()));
+        fs::create_dir_all(&temp_dir)?;
+
+        let mut collator = Builder::default()
+            .set_bucket_count(NonZeroUsize::try_from(2)?)
+            .set_max_record_count(NonZeroUsize::try_from(2)?)
+            .set_temp_dir(&temp_dir)
+            .build(Header::default());
+
+        let names: Vec<_> = (0..16)
+            .map(|i| format!("r{}", i % 8))
+            .chain(["r8"; 5].map(String::from))
+            .collect();
+
+        for name in &names {
+            collator.push(RecordBuf::builder().set_name(name.as_str()).build())?;
+        }
+
+        let mut actual: Vec<_> = collator
+            .finish()?
+            .map(|result| result.map(|record| record.name().map(|name| name.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        actual.dedup();
+        assert_eq!(actual.len(), 9);
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 0);
+        fs::remove_dir(&temp_dir)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_one_bucket() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = env::temp_dir().join(format!(
+            "noodles-util-collate-one-bucket-test-{}",
+            process::id()
+        ));
+        fs::create_dir_all(&temp_dir)?;
+
+        let mut collator = Builder::default()
+            .set_bucket_count(NonZeroUsize::MIN)
+            .set_max_record_count(NonZeroUsize::try_from(2)?)
+            .set_temp_dir(&temp_dir)
+            .build(Header::default());
+
+        for name in ["r0", "r1", "r0", "r1"] {
+            collator.push(RecordBuf::builder().set_name(name).build())?;
+        }
+
+        let actual: Vec<_> = collator
+            .finish()?
+            .map(|result| result.map(|record| record.name().map(|name| name.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), 4);
+        assert_eq!(actual[0], actual[1]);
+        assert_eq!(actual[2], actual[3]);
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 0);
+        fs::remove_dir(&temp_dir)?;
+
+        Ok(())
+    }
+}
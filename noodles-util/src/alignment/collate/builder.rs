@@ -0,0 +1,111 @@
+//! Alignment record collator builder.
+
+use std::{env, num::NonZeroUsize, path::PathBuf};
+
+use noodles_sam::Header;
+
+use super::Collator;
+
+const DEFAULT_BUCKET_COUNT: NonZeroUsize = match NonZeroUsize::new(64) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+const DEFAULT_MAX_RECORD_COUNT: NonZeroUsize = match NonZeroUsize::new(500_000) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// An alignment record collator builder.
+pub struct Builder {
+    bucket_count: NonZeroUsize,
+    max_record_count: NonZeroUsize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the number of buckets.
+    ///
+    /// Each bucket that is spilled uses one temporary file. By default, this is 64.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let builder = collate::Builder::default().set_bucket_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_bucket_count(mut self, bucket_count: NonZeroUsize) -> Self {
+        self.bucket_count = bucket_count;
+        self
+    }
+
+    /// Sets the maximum number of records to hold in memory.
+    ///
+    /// When this limit is reached, the buffered records are written to the temporary files of
+    /// their buckets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let builder = collate::Builder::default().set_max_record_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_max_record_count(mut self, max_record_count: NonZeroUsize) -> Self {
+        self.max_record_count = max_record_count;
+        self
+    }
+
+    /// Sets the directory of temporary files.
+    ///
+    /// By default, this is [`std::env::temp_dir`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::collate;
+    /// let builder = collate::Builder::default().set_temp_dir("/tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// Builds an alignment record collator.
+    ///
+    /// The sort order (`SO`) and group order (`GO`) of the given header are set to `unsorted`
+    /// and `query`, respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::collate;
+    ///
+    /// let collator = collate::Builder::default().build(sam::Header::default());
+    /// ```
+    pub fn build(self, header: Header) -> Collator {
+        Collator::new(
+            header,
+            self.bucket_count,
+            self.max_record_count,
+            self.temp_dir.unwrap_or_else(env::temp_dir),
+        )
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            bucket_count: DEFAULT_BUCKET_COUNT,
+            max_record_count: DEFAULT_MAX_RECORD_COUNT,
+            temp_dir: None,
+        }
+    }
+}
@@ -0,0 +1,351 @@
+//! Alignment coverage.
+//!
+//! This calculates the per-base depth of alignment records over a region, similar to `samtools
+//! depth`.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{io, num::NonZeroUsize};
+
+use noodles_core::Position;
+use noodles_sam::{
+    alignment::{
+        record::{cigar::op::Kind, Flags},
+        Record,
+    },
+    Header,
+};
+
+/// An alignment coverage calculator.
+///
+/// Only aligned bases, i.e., alignment match (`M`), sequence match (`=`), and sequence mismatch
+/// (`X`) operations, are counted. Deletions and skips are not counted.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroUsize;
+///
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::coverage;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?))
+///     .build();
+///
+/// let region = "sq0".parse()?;
+/// let mut coverage = coverage::Builder::default().build(&header, &region)?;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(3)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// coverage.add(&header, &record)?;
+///
+/// assert_eq!(coverage.depths(), [0, 0, 1, 1, 1, 1, 0, 0]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Coverage {
+    reference_sequence_id: usize,
+    start: Position,
+    depths: Vec<u32>,
+    min_mapping_quality: u8,
+    excluded_flags: Flags,
+}
+
+impl Coverage {
+    /// Adds the aligned bases of a record to the coverage.
+    ///
+    /// Records on other reference sequences, records with any excluded flags, and records with
+    /// a mapping quality lower than the minimum are skipped. Records do not need to be sorted.
+    pub fn add<R>(&mut self, header: &Header, record: &R) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        if record.flags()?.intersects(self.excluded_flags) {
+            return Ok(());
+        }
+
+        if let Some(mapping_quality) = record.mapping_quality().transpose()? {
+            if mapping_quality.get() < self.min_mapping_quality {
+                return Ok(());
+            }
+        }
+
+        match record.reference_sequence_id(header).transpose()? {
+            Some(id) if id == self.reference_sequence_id => {}
+            _ => return Ok(()),
+        }
+
+        let Some(alignment_start) = record.alignment_start().transpose()? else {
+            return Ok(());
+        };
+
+        let region_start = usize::from(self.start);
+        let region_end = region_start + self.depths.len();
+
+        let mut reference_start = usize::from(alignment_start);
+
+        for result in record.cigar().iter() {
+            let op = result?;
+
+            if !op.kind().consumes_reference() {
+                continue;
+            }
+
+            let reference_end = reference_start + op.len();
+
+            if matches!(
+                op.kind(),
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch
+            ) {
+                let start = reference_start.max(region_start);
+                let end = reference_end.min(region_end);
+
+                if start < end {
+                    for depth in &mut self.depths[start - region_start..end - region_start] {
+                        *depth = depth.saturating_add(1);
+                    }
+                }
+            }
+
+            reference_start = reference_end;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the start position of the region.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the depth of each position in the region.
+    pub fn depths(&self) -> &[u32] {
+        &self.depths
+    }
+
+    /// Returns the mean depth of each window in the region.
+    ///
+    /// Windows are consecutive and start at the start of the region. The last window may be
+    /// shorter than the given size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{self as sam, header::record::value::{map::ReferenceSequence, Map}};
+    /// use noodles_util::alignment::coverage;
+    ///
+    /// let reference_sequence = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(5)?);
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence("sq0", reference_sequence)
+    ///     .build();
+    ///
+    /// let coverage = coverage::Builder::default().build(&header, &"sq0".parse()?)?;
+    /// let window_size = NonZeroUsize::try_from(2)?;
+    /// assert_eq!(coverage.mean_depths(window_size), [0.0, 0.0, 0.0]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mean_depths(&self, window_size: NonZeroUsize) -> Vec<f64> {
+        self.depths
+            .chunks(window_size.get())
+            .map(|window| {
+                let sum: u64 = window.iter().copied().map(u64::from).sum();
+                sum as f64 / window.len() as f64
+            })
+            .collect()
+    }
+
+    /// Returns an iterator over runs of equal depth.
+    ///
+    /// This is similar to the intervals in a bedGraph.
+    pub fn runs(&self) -> Runs<'_> {
+        Runs {
+            depths: &self.depths,
+            start: self.start,
+        }
+    }
+}
+
+/// A run of positions with the same depth.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Run {
+    start: Position,
+    end: Position,
+    depth: u32,
+}
+
+impl Run {
+    /// Returns the start position (1-based, inclusive).
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position (1-based, inclusive).
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the depth.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+}
+
+/// An iterator over runs of equal depth.
+pub struct Runs<'a> {
+    depths: &'a [u32],
+    start: Position,
+}
+
+impl<'a> Iterator for Runs<'a> {
+    type Item = Run;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (&depth, rest) = self.depths.split_first()?;
+        let len = 1 + rest.iter().take_while(|&&d| d == depth).count();
+
+        let start = self.start;
+        let end = start.checked_add(len - 1)?;
+
+        self.depths = &self.depths[len..];
+        self.start = end.checked_add(1).unwrap_or(end);
+
+        Some(Run { start, end, depth })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Region;
+    use noodles_sam::{
+        alignment::{
+            record::{cigar::Op, MappingQuality},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    fn build_header() -> Result<Header, Box<dyn std::error::Error>> {
+        Ok(Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(10)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(10)?),
+            )
+            .build())
+    }
+
+    #[test]
+    fn test_add() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let region: Region = "sq0:3-8".parse()?;
+
+        let mut coverage = Builder::default()
+            .set_min_mapping_quality(10)
+            .build(&header, &region)?;
+
+        let ops = [
+            Op::new(Kind::SoftClip, 2),
+            Op::new(Kind::Match, 2),
+            Op::new(Kind::Deletion, 1),
+            Op::new(Kind::Match, 3),
+        ];
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .set_cigar(ops.into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::DUPLICATE)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_mapping_quality(MappingQuality::new(5).unwrap())
+                .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(1)?)
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                .build(),
+        ];
+
+        for record in &records {
+            coverage.add(&header, record)?;
+        }
+
+        // positions 3..=8
+        assert_eq!(coverage.depths(), [1, 0, 2, 2, 2, 1]);
+
+        let runs: Vec<_> = coverage
+            .runs()
+            .map(|run| {
+                (
+                    usize::from(run.start()),
+                    usize::from(run.end()),
+                    run.depth(),
+                )
+            })
+            .collect();
+        assert_eq!(runs, [(3, 3, 1), (4, 4, 0), (5, 7, 2), (8, 8, 1)]);
+
+        let window_size = NonZeroUsize::try_from(4)?;
+        assert_eq!(coverage.mean_depths(window_size), [1.25, 1.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_with_missing_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header()?;
+        let region = "sq2".parse()?;
+
+        assert!(matches!(
+            Builder::default().build(&header, &region),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Alignment coverage builder.
+
+use std::io;
+
+use noodles_core::{Position, Region};
+use noodles_sam::{alignment::record::Flags, Header};
+
+use super::Coverage;
+
+const DEFAULT_EXCLUDED_FLAGS: Flags = Flags::UNMAPPED
+    .union(Flags::SECONDARY)
+    .union(Flags::QC_FAIL)
+    .union(Flags::DUPLICATE);
+
+/// An alignment coverage builder.
+pub struct Builder {
+    min_mapping_quality: u8,
+    excluded_flags: Flags,
+}
+
+impl Builder {
+    /// Sets the minimum mapping quality of a record to be counted.
+    ///
+    /// By default, this is 0. Records with a missing mapping quality are always counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::coverage;
+    /// let builder = coverage::Builder::default().set_min_mapping_quality(20);
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Sets the flags that exclude a record from being counted.
+    ///
+    /// By default, this is unmapped (`0x04`), secondary (`0x100`), QC fail (`0x200`), and
+    /// duplicate (`0x400`), like `samtools depth`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::coverage;
+    /// let builder = coverage::Builder::default().set_excluded_flags(Flags::UNMAPPED);
+    /// ```
+    pub fn set_excluded_flags(mut self, excluded_flags: Flags) -> Self {
+        self.excluded_flags = excluded_flags;
+        self
+    }
+
+    /// Builds an alignment coverage calculator over a region.
+    ///
+    /// The region must have a reference sequence name in the header. An unbounded region end is
+    /// set to the reference sequence length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{self as sam, header::record::value::{map::ReferenceSequence, Map}};
+    /// use noodles_util::alignment::coverage;
+    ///
+    /// let reference_sequence = Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?);
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence("sq0", reference_sequence)
+    ///     .build();
+    ///
+    /// let region = "sq0:2-5".parse()?;
+    /// let coverage = coverage::Builder::default().build(&header, &region)?;
+    /// assert_eq!(coverage.depths(), [0, 0, 0, 0]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn build(self, header: &Header, region: &Region) -> io::Result<Coverage> {
+        let (reference_sequence_id, _, reference_sequence) = header
+            .reference_sequences()
+            .get_full(region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing reference sequence: {}", region.name()),
+                )
+            })?;
+
+        let interval = region
+            .interval()
+            .clamp(usize::from(reference_sequence.length()))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid interval"))?;
+
+        // SAFETY: A clamped interval is bounded and nonempty.
+        let start = interval.start().unwrap_or(Position::MIN);
+        let end = interval.end().unwrap_or(start);
+        let len = usize::from(end) - usize::from(start) + 1;
+
+        Ok(Coverage {
+            reference_sequence_id,
+            start,
+            depths: vec![0; len],
+            min_mapping_quality: self.min_mapping_quality,
+            excluded_flags: self.excluded_flags,
+        })
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            min_mapping_quality: 0,
+            excluded_flags: DEFAULT_EXCLUDED_FLAGS,
+        }
+    }
+}
@@ -0,0 +1,164 @@
+//! Alignment record filter expressions.
+//!
+//! A filter expression is a predicate over alignment records, similar to the expressions used by
+//! `samtools view -e`, e.g., `mapq >= 30 && flag.proper_pair && [NM] <= 2`.
+//!
+//! # Syntax
+//!
+//! An expression is made of literals, record fields, data fields, and operators.
+//!
+//! Literals are integers (e.g., `8`, `0x10`), floats (e.g., `0.5`), and strings (e.g., `"r0"`).
+//!
+//! The following record fields are supported:
+//!
+//! | name      | description                                 |
+//! | --------- | ------------------------------------------- |
+//! | `qname`   | read name                                   |
+//! | `flag`    | flags                                       |
+//! | `pos`     | alignment start (1-based)                   |
+//! | `endpos`  | alignment end (1-based)                     |
+//! | `mapq`    | mapping quality                             |
+//! | `ncigar`  | number of CIGAR operations                  |
+//! | `rlen`    | alignment span, i.e., reference length      |
+//! | `qlen`    | sequence length                             |
+//! | `mpos`    | mate alignment start (1-based)              |
+//! | `tlen`    | template length                             |
+//!
+//! Individual flags are tested using `flag.<name>`, where `<name>` is one of `paired`,
+//! `proper_pair`, `unmap`, `munmap`, `reverse`, `mreverse`, `read1`, `read2`, `secondary`,
+//! `qcfail`, `dup`, or `supplementary`.
+//!
+//! Data fields are referenced by tag in brackets, e.g., `[NM]`. Array values are not supported.
+//!
+//! Operators, from highest to lowest precedence, are `!` and `-` (unary); `*` and `/`; `+` and
+//! `-`; `<`, `<=`, `>`, and `>=`; `==` and `!=`; `&` (bitwise); `|` (bitwise); `&&`; and `||`.
+//! Parentheses can be used for grouping.
+//!
+//! Missing values (e.g., a missing data field or the alignment start of an unmapped record) are
+//! null. A null value is false, and any comparison or arithmetic with a null value is null.
+
+mod node;
+mod parser;
+mod value;
+
+pub use self::parser::ParseError;
+
+use std::{io, str::FromStr};
+
+use noodles_sam::alignment::Record;
+
+use self::node::Node;
+
+/// An alignment record filter expression.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     record::{data::field::Tag, Flags, MappingQuality},
+///     record_buf::data::field::Value,
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::expression::Expression;
+///
+/// let expression: Expression = "mapq >= 30 && flag.proper_pair && [NM] <= 2".parse()?;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED)
+///     .set_mapping_quality(MappingQuality::new(60).unwrap())
+///     .set_data([(Tag::EDIT_DISTANCE, Value::from(1u8))].into_iter().collect())
+///     .build();
+///
+/// assert!(expression.evaluate(&record)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression(Node);
+
+impl Expression {
+    /// Evaluates the expression against a record.
+    ///
+    /// This returns whether the result of the expression is true.
+    pub fn evaluate<R>(&self, record: &R) -> io::Result<bool>
+    where
+        R: Record + ?Sized,
+    {
+        self.0.evaluate(record).map(|value| value.is_true())
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parser::parse(s).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::{
+        record::{
+            cigar::{op::Kind, Op},
+            data::field::Tag,
+            Flags, MappingQuality,
+        },
+        record_buf::data::field::Value,
+        RecordBuf,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_evaluate() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::REVERSE_COMPLEMENTED)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_mapping_quality(MappingQuality::new(13).unwrap())
+            .set_cigar(
+                [Op::new(Kind::Match, 2), Op::new(Kind::Deletion, 1)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(b"AC".to_vec().into())
+            .set_template_length(-5)
+            .set_data(
+                [
+                    (Tag::EDIT_DISTANCE, Value::from(1u8)),
+                    (Tag::READ_GROUP, Value::from("rg0")),
+                    (Tag::ALIGNMENT_SCORE, Value::from(0.5f32)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let t = |s: &str| -> Result<bool, Box<dyn std::error::Error>> {
+            let expression: Expression = s.parse()?;
+            Ok(expression.evaluate(&record)?)
+        };
+
+        assert!(t("qname == \"r0\"")?);
+        assert!(t("flag == 0x51")?);
+        assert!(t("flag & 16")?);
+        assert!(t("flag.paired && flag.read1 && flag.reverse")?);
+        assert!(!t("flag.read2 || flag.unmap")?);
+        assert!(t("pos == 8 && endpos == 10")?);
+        assert!(t("mapq > 10 && mapq <= 13")?);
+        assert!(t("ncigar == 2 && rlen == 3 && qlen == 2")?);
+        assert!(t("tlen == -5")?);
+        assert!(t("[NM] == 1 && [RG] == \"rg0\" && [AS] < 1.0")?);
+        assert!(t("[NM] * 2 + 1 == 3")?);
+        assert!(t("!(mapq < 10)")?);
+
+        assert!(!t("mpos")?);
+        assert!(!t("[XS]")?);
+        assert!(!t("[XS] < 1")?);
+        assert!(t("!([XS] < 1)")?);
+        assert!(t("![XS]")?);
+
+        Ok(())
+    }
+}
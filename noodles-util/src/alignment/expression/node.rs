@@ -0,0 +1,217 @@
+use std::{cmp::Ordering, io};
+
+use bstr::BString;
+use noodles_sam::alignment::{
+    record::{
+        data::field::{Tag, Value as FieldValue},
+        Flags,
+    },
+    Record,
+};
+
+use super::value::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Literal(Value),
+    Field(Field),
+    Flag(Flags),
+    Tag(Tag),
+    Unary(UnaryOperator, Box<Node>),
+    Binary(BinaryOperator, Box<Node>, Box<Node>),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    Name,
+    Flags,
+    AlignmentStart,
+    AlignmentEnd,
+    MappingQuality,
+    CigarOpCount,
+    AlignmentSpan,
+    SequenceLength,
+    MateAlignmentStart,
+    TemplateLength,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnaryOperator {
+    Not,
+    Negate,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BinaryOperator {
+    Multiply,
+    Divide,
+    Add,
+    Subtract,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    BitAnd,
+    BitOr,
+    And,
+    Or,
+}
+
+impl Node {
+    pub fn evaluate<R>(&self, record: &R) -> io::Result<Value>
+    where
+        R: Record + ?Sized,
+    {
+        match self {
+            Self::Literal(value) => Ok(value.clone()),
+            Self::Field(field) => evaluate_field(record, *field),
+            Self::Flag(flag) => record
+                .flags()
+                .map(|flags| Value::from(flags.intersects(*flag))),
+            Self::Tag(tag) => evaluate_tag(record, *tag),
+            Self::Unary(operator, node) => {
+                let value = node.evaluate(record)?;
+                Ok(evaluate_unary(*operator, value))
+            }
+            Self::Binary(BinaryOperator::And, lhs, rhs) => {
+                let value = lhs.evaluate(record)?.is_true() && rhs.evaluate(record)?.is_true();
+                Ok(Value::from(value))
+            }
+            Self::Binary(BinaryOperator::Or, lhs, rhs) => {
+                let value = lhs.evaluate(record)?.is_true() || rhs.evaluate(record)?.is_true();
+                Ok(Value::from(value))
+            }
+            Self::Binary(operator, lhs, rhs) => {
+                let a = lhs.evaluate(record)?;
+                let b = rhs.evaluate(record)?;
+                Ok(evaluate_binary(*operator, a, b))
+            }
+        }
+    }
+}
+
+fn evaluate_field<R>(record: &R, field: Field) -> io::Result<Value>
+where
+    R: Record + ?Sized,
+{
+    fn from_usize(n: usize) -> io::Result<Value> {
+        i64::try_from(n)
+            .map(Value::Int)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn from_option<T, F>(value: Option<io::Result<T>>, f: F) -> io::Result<Value>
+    where
+        F: FnOnce(T) -> io::Result<Value>,
+    {
+        value.transpose()?.map(f).unwrap_or(Ok(Value::Null))
+    }
+
+    match field {
+        Field::Name => Ok(record
+            .name()
+            .map(|name| Value::String(BString::from(name.to_vec())))
+            .unwrap_or(Value::Null)),
+        Field::Flags => record
+            .flags()
+            .map(|flags| Value::Int(i64::from(flags.bits()))),
+        Field::AlignmentStart => from_option(record.alignment_start(), |position| {
+            from_usize(usize::from(position))
+        }),
+        Field::AlignmentEnd => from_option(record.alignment_end(), |position| {
+            from_usize(usize::from(position))
+        }),
+        Field::MappingQuality => from_option(record.mapping_quality(), |mapping_quality| {
+            Ok(Value::Int(i64::from(mapping_quality.get())))
+        }),
+        Field::CigarOpCount => from_usize(record.cigar().len()),
+        Field::AlignmentSpan => from_option(record.alignment_span(), from_usize),
+        Field::SequenceLength => from_usize(record.sequence().len()),
+        Field::MateAlignmentStart => from_option(record.mate_alignment_start(), |position| {
+            from_usize(usize::from(position))
+        }),
+        Field::TemplateLength => record.template_length().map(|n| Value::Int(i64::from(n))),
+    }
+}
+
+fn evaluate_tag<R>(record: &R, tag: Tag) -> io::Result<Value>
+where
+    R: Record + ?Sized,
+{
+    let data = record.data();
+
+    let Some(value) = data.get(&tag).transpose()? else {
+        return Ok(Value::Null);
+    };
+
+    let value = match value {
+        FieldValue::Character(c) => Value::String(BString::from(vec![c])),
+        FieldValue::Float(n) => Value::Float(f64::from(n)),
+        FieldValue::String(s) | FieldValue::Hex(s) => Value::String(s.into()),
+        FieldValue::Array(_) => Value::Null,
+        _ => value.as_int().map(Value::Int).unwrap_or(Value::Null),
+    };
+
+    Ok(value)
+}
+
+fn evaluate_unary(operator: UnaryOperator, value: Value) -> Value {
+    match operator {
+        UnaryOperator::Not => Value::from(!value.is_true()),
+        UnaryOperator::Negate => match value {
+            Value::Int(n) => n.checked_neg().map(Value::Int).unwrap_or(Value::Null),
+            Value::Float(n) => Value::Float(-n),
+            _ => Value::Null,
+        },
+    }
+}
+
+fn evaluate_binary(operator: BinaryOperator, a: Value, b: Value) -> Value {
+    match operator {
+        BinaryOperator::Lt => compare(&a, &b, Ordering::is_lt),
+        BinaryOperator::Le => compare(&a, &b, Ordering::is_le),
+        BinaryOperator::Gt => compare(&a, &b, Ordering::is_gt),
+        BinaryOperator::Ge => compare(&a, &b, Ordering::is_ge),
+        BinaryOperator::Eq => compare(&a, &b, Ordering::is_eq),
+        BinaryOperator::Ne => compare(&a, &b, Ordering::is_ne),
+        BinaryOperator::BitAnd => match (a, b) {
+            (Value::Int(a), Value::Int(b)) => Value::Int(a & b),
+            _ => Value::Null,
+        },
+        BinaryOperator::BitOr => match (a, b) {
+            (Value::Int(a), Value::Int(b)) => Value::Int(a | b),
+            _ => Value::Null,
+        },
+        BinaryOperator::Multiply => arithmetic(a, b, i64::checked_mul, |a, b| a * b),
+        BinaryOperator::Divide => arithmetic(a, b, i64::checked_div, |a, b| a / b),
+        BinaryOperator::Add => arithmetic(a, b, i64::checked_add, |a, b| a + b),
+        BinaryOperator::Subtract => arithmetic(a, b, i64::checked_sub, |a, b| a - b),
+        BinaryOperator::And => Value::from(a.is_true() && b.is_true()),
+        BinaryOperator::Or => Value::from(a.is_true() || b.is_true()),
+    }
+}
+
+fn compare<F>(a: &Value, b: &Value, f: F) -> Value
+where
+    F: FnOnce(Ordering) -> bool,
+{
+    a.compare(b)
+        .map(|ordering| Value::from(f(ordering)))
+        .unwrap_or(Value::Null)
+}
+
+fn arithmetic<F, G>(a: Value, b: Value, f: F, g: G) -> Value
+where
+    F: FnOnce(i64, i64) -> Option<i64>,
+    G: FnOnce(f64, f64) -> f64,
+{
+    match (a, b) {
+        (Value::Int(a), Value::Int(b)) => f(a, b).map(Value::Int).unwrap_or(Value::Null),
+        (Value::Int(a), Value::Float(b)) => Value::Float(g(a as f64, b)),
+        (Value::Float(a), Value::Int(b)) => Value::Float(g(a, b as f64)),
+        (Value::Float(a), Value::Float(b)) => Value::Float(g(a, b)),
+        _ => Value::Null,
+    }
+}
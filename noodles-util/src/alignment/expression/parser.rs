@@ -0,0 +1,413 @@
+use std::{error, fmt, iter::Peekable, str::Chars};
+
+use bstr::BString;
+use noodles_sam::alignment::record::{data::field::Tag, Flags};
+
+use super::{
+    node::{BinaryOperator, Field, Node, UnaryOperator},
+    value::Value,
+};
+
+/// An error returned when a filter expression fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+    /// A character is unexpected.
+    UnexpectedCharacter(char),
+    /// A token is unexpected.
+    UnexpectedToken(String),
+    /// A string is not terminated.
+    UnterminatedString,
+    /// A number is invalid.
+    InvalidNumber(String),
+    /// A data field tag is invalid.
+    InvalidTag(String),
+    /// A field name is invalid.
+    InvalidField(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEof => write!(f, "unexpected EOF"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character: {c:?}"),
+            Self::UnexpectedToken(s) => write!(f, "unexpected token: {s}"),
+            Self::UnterminatedString => write!(f, "unterminated string"),
+            Self::InvalidNumber(s) => write!(f, "invalid number: {s}"),
+            Self::InvalidTag(s) => write!(f, "invalid tag: {s}"),
+            Self::InvalidField(s) => write!(f, "invalid field: {s}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Int(i64),
+    Float(f64),
+    String(String),
+    Identifier(String),
+    Tag(Tag),
+    LeftParen,
+    RightParen,
+    Not,
+    Minus,
+    Plus,
+    Star,
+    Slash,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Eq,
+    Ne,
+    Ampersand,
+    Pipe,
+    And,
+    Or,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(n) => write!(f, "{n}"),
+            Self::Float(n) => write!(f, "{n}"),
+            Self::String(s) => write!(f, "{s:?}"),
+            Self::Identifier(s) => f.write_str(s),
+            Self::Tag(tag) => {
+                let [a, b] = tag.as_ref();
+                write!(f, "[{}{}]", char::from(*a), char::from(*b))
+            }
+            Self::LeftParen => f.write_str("("),
+            Self::RightParen => f.write_str(")"),
+            Self::Not => f.write_str("!"),
+            Self::Minus => f.write_str("-"),
+            Self::Plus => f.write_str("+"),
+            Self::Star => f.write_str("*"),
+            Self::Slash => f.write_str("/"),
+            Self::Lt => f.write_str("<"),
+            Self::Le => f.write_str("<="),
+            Self::Gt => f.write_str(">"),
+            Self::Ge => f.write_str(">="),
+            Self::Eq => f.write_str("=="),
+            Self::Ne => f.write_str("!="),
+            Self::Ampersand => f.write_str("&"),
+            Self::Pipe => f.write_str("|"),
+            Self::And => f.write_str("&&"),
+            Self::Or => f.write_str("||"),
+        }
+    }
+}
+
+// Binary operators grouped by precedence, from lowest to highest.
+const BINARY_OPERATORS: &[&[(Token, BinaryOperator)]] = &[
+    &[(Token::Or, BinaryOperator::Or)],
+    &[(Token::And, BinaryOperator::And)],
+    &[(Token::Pipe, BinaryOperator::BitOr)],
+    &[(Token::Ampersand, BinaryOperator::BitAnd)],
+    &[
+        (Token::Eq, BinaryOperator::Eq),
+        (Token::Ne, BinaryOperator::Ne),
+    ],
+    &[
+        (Token::Lt, BinaryOperator::Lt),
+        (Token::Le, BinaryOperator::Le),
+        (Token::Gt, BinaryOperator::Gt),
+        (Token::Ge, BinaryOperator::Ge),
+    ],
+    &[
+        (Token::Plus, BinaryOperator::Add),
+        (Token::Minus, BinaryOperator::Subtract),
+    ],
+    &[
+        (Token::Star, BinaryOperator::Multiply),
+        (Token::Slash, BinaryOperator::Divide),
+    ],
+];
+
+pub(super) fn parse(s: &str) -> Result<Node, ParseError> {
+    let tokens = tokenize(s)?;
+    let mut parser = Parser {
+        tokens: tokens.into_iter().peekable(),
+    };
+
+    let node = parser.parse_binary(0)?;
+
+    match parser.tokens.next() {
+        Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+        None => Ok(node),
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut chars = s.chars().peekable();
+    let mut tokens = Vec::new();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            ' ' | '\t' | '\n' | '\r' => continue,
+            '(' => Token::LeftParen,
+            ')' => Token::RightParen,
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '!' => next_if_eq(&mut chars, '=', Token::Ne, Token::Not),
+            '<' => next_if_eq(&mut chars, '=', Token::Le, Token::Lt),
+            '>' => next_if_eq(&mut chars, '=', Token::Ge, Token::Gt),
+            '=' if chars.next_if_eq(&'=').is_some() => Token::Eq,
+            '&' => next_if_eq(&mut chars, '&', Token::And, Token::Ampersand),
+            '|' => next_if_eq(&mut chars, '|', Token::Or, Token::Pipe),
+            '"' => tokenize_string(&mut chars)?,
+            '[' => tokenize_tag(&mut chars)?,
+            '0'..='9' | '.' => tokenize_number(&mut chars, c)?,
+            _ if c.is_ascii_alphabetic() || c == '_' => tokenize_identifier(&mut chars, c),
+            _ => return Err(ParseError::UnexpectedCharacter(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn next_if_eq(chars: &mut Peekable<Chars<'_>>, c: char, a: Token, b: Token) -> Token {
+    if chars.next_if_eq(&c).is_some() {
+        a
+    } else {
+        b
+    }
+}
+
+fn tokenize_string(chars: &mut Peekable<Chars<'_>>) -> Result<Token, ParseError> {
+    let mut s = String::new();
+
+    loop {
+        match chars.next() {
+            Some('"') => return Ok(Token::String(s)),
+            Some('\\') => match chars.next() {
+                Some(c) => s.push(c),
+                None => return Err(ParseError::UnterminatedString),
+            },
+            Some(c) => s.push(c),
+            None => return Err(ParseError::UnterminatedString),
+        }
+    }
+}
+
+fn tokenize_tag(chars: &mut Peekable<Chars<'_>>) -> Result<Token, ParseError> {
+    let mut s = String::new();
+
+    loop {
+        match chars.next() {
+            Some(']') => break,
+            Some(c) => s.push(c),
+            None => return Err(ParseError::UnexpectedEof),
+        }
+    }
+
+    match s.as_bytes() {
+        &[a, b] if a.is_ascii_alphabetic() && b.is_ascii_alphanumeric() => {
+            Ok(Token::Tag(Tag::new(a, b)))
+        }
+        _ => Err(ParseError::InvalidTag(s)),
+    }
+}
+
+fn tokenize_number(chars: &mut Peekable<Chars<'_>>, c: char) -> Result<Token, ParseError> {
+    let mut s = String::from(c);
+
+    while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '.') {
+        s.push(c);
+    }
+
+    let result = if let Some(t) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        i64::from_str_radix(t, 16).map(Token::Int).ok()
+    } else if s.contains(['.', 'e', 'E']) {
+        s.parse().map(Token::Float).ok()
+    } else {
+        s.parse().map(Token::Int).ok()
+    };
+
+    result.ok_or(ParseError::InvalidNumber(s))
+}
+
+fn tokenize_identifier(chars: &mut Peekable<Chars<'_>>, c: char) -> Token {
+    let mut s = String::from(c);
+
+    while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '.') {
+        s.push(c);
+    }
+
+    Token::Identifier(s)
+}
+
+struct Parser {
+    tokens: Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl Parser {
+    fn parse_binary(&mut self, level: usize) -> Result<Node, ParseError> {
+        let Some(operators) = BINARY_OPERATORS.get(level) else {
+            return self.parse_unary();
+        };
+
+        let mut lhs = self.parse_binary(level + 1)?;
+
+        while let Some(operator) = self.tokens.peek().and_then(|token| {
+            operators
+                .iter()
+                .find_map(|(t, operator)| (t == token).then_some(*operator))
+        }) {
+            self.tokens.next();
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = Node::Binary(operator, Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Node, ParseError> {
+        let operator = match self.tokens.peek() {
+            Some(Token::Not) => UnaryOperator::Not,
+            Some(Token::Minus) => UnaryOperator::Negate,
+            _ => return self.parse_primary(),
+        };
+
+        self.tokens.next();
+        let node = self.parse_unary()?;
+
+        Ok(Node::Unary(operator, Box::new(node)))
+    }
+
+    fn parse_primary(&mut self) -> Result<Node, ParseError> {
+        match self.tokens.next() {
+            Some(Token::Int(n)) => Ok(Node::Literal(Value::Int(n))),
+            Some(Token::Float(n)) => Ok(Node::Literal(Value::Float(n))),
+            Some(Token::String(s)) => Ok(Node::Literal(Value::String(BString::from(s)))),
+            Some(Token::Tag(tag)) => Ok(Node::Tag(tag)),
+            Some(Token::Identifier(s)) => parse_identifier(&s),
+            Some(Token::LeftParen) => {
+                let node = self.parse_binary(0)?;
+
+                match self.tokens.next() {
+                    Some(Token::RightParen) => Ok(node),
+                    Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+                    None => Err(ParseError::UnexpectedEof),
+                }
+            }
+            Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+            None => Err(ParseError::UnexpectedEof),
+        }
+    }
+}
+
+fn parse_identifier(s: &str) -> Result<Node, ParseError> {
+    if let Some(name) = s.strip_prefix("flag.") {
+        return parse_flag(name)
+            .map(Node::Flag)
+            .ok_or_else(|| ParseError::InvalidField(s.into()));
+    }
+
+    let field = match s {
+        "qname" => Field::Name,
+        "flag" => Field::Flags,
+        "pos" => Field::AlignmentStart,
+        "endpos" => Field::AlignmentEnd,
+        "mapq" => Field::MappingQuality,
+        "ncigar" => Field::CigarOpCount,
+        "rlen" => Field::AlignmentSpan,
+        "qlen" => Field::SequenceLength,
+        "mpos" => Field::MateAlignmentStart,
+        "tlen" => Field::TemplateLength,
+        _ => return Err(ParseError::InvalidField(s.into())),
+    };
+
+    Ok(Node::Field(field))
+}
+
+fn parse_flag(s: &str) -> Option<Flags> {
+    match s {
+        "paired" => Some(Flags::SEGMENTED),
+        "proper_pair" => Some(Flags::PROPERLY_SEGMENTED),
+        "unmap" => Some(Flags::UNMAPPED),
+        "munmap" => Some(Flags::MATE_UNMAPPED),
+        "reverse" => Some(Flags::REVERSE_COMPLEMENTED),
+        "mreverse" => Some(Flags::MATE_REVERSE_COMPLEMENTED),
+        "read1" => Some(Flags::FIRST_SEGMENT),
+        "read2" => Some(Flags::LAST_SEGMENT),
+        "secondary" => Some(Flags::SECONDARY),
+        "qcfail" => Some(Flags::QC_FAIL),
+        "dup" => Some(Flags::DUPLICATE),
+        "supplementary" => Some(Flags::SUPPLEMENTARY),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() -> Result<(), ParseError> {
+        assert_eq!(
+            parse("mapq >= 30 && !flag.dup")?,
+            Node::Binary(
+                BinaryOperator::And,
+                Box::new(Node::Binary(
+                    BinaryOperator::Ge,
+                    Box::new(Node::Field(Field::MappingQuality)),
+                    Box::new(Node::Literal(Value::Int(30))),
+                )),
+                Box::new(Node::Unary(
+                    UnaryOperator::Not,
+                    Box::new(Node::Flag(Flags::DUPLICATE))
+                )),
+            )
+        );
+
+        assert_eq!(
+            parse("1 + 2 * 3")?,
+            Node::Binary(
+                BinaryOperator::Add,
+                Box::new(Node::Literal(Value::Int(1))),
+                Box::new(Node::Binary(
+                    BinaryOperator::Multiply,
+                    Box::new(Node::Literal(Value::Int(2))),
+                    Box::new(Node::Literal(Value::Int(3))),
+                )),
+            )
+        );
+
+        assert_eq!(parse("([NM])")?, Node::Tag(Tag::EDIT_DISTANCE),);
+
+        assert_eq!(parse(""), Err(ParseError::UnexpectedEof));
+        assert_eq!(parse("(mapq"), Err(ParseError::UnexpectedEof));
+        assert_eq!(
+            parse("mapq 30"),
+            Err(ParseError::UnexpectedToken(String::from("30")))
+        );
+        assert_eq!(
+            parse("mapq = 30"),
+            Err(ParseError::UnexpectedCharacter('='))
+        );
+        assert_eq!(parse("\"r0"), Err(ParseError::UnterminatedString));
+        assert_eq!(
+            parse("0xzz"),
+            Err(ParseError::InvalidNumber(String::from("0xzz")))
+        );
+        assert_eq!(parse("[N]"), Err(ParseError::InvalidTag(String::from("N"))));
+        assert_eq!(
+            parse("flag.ndp"),
+            Err(ParseError::InvalidField(String::from("flag.ndp")))
+        );
+        assert_eq!(
+            parse("rname"),
+            Err(ParseError::InvalidField(String::from("rname")))
+        );
+
+        Ok(())
+    }
+}
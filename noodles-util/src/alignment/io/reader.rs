@@ -4,13 +4,20 @@ pub(crate) mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, Read};
+use std::{
+    io::{self, Read},
+    iter,
+};
 
+use bstr::BString;
 use noodles_sam as sam;
 
+use crate::alignment::iter::FilterByReadGroup;
+
 /// An alignment reader.
 pub struct Reader<R> {
     inner: Box<dyn sam::alignment::io::Read<R>>,
+    read_group_ids: Option<Vec<BString>>,
 }
 
 impl<R> Reader<R>
@@ -49,6 +56,9 @@ where
 
     /// Returns an iterator over records starting from the current stream position.
     ///
+    /// If read group IDs were set on the builder, only records in those read groups are emitted.
+    /// If a read group ID is not in the given header, the iterator emits an error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -73,6 +83,15 @@ where
         &'a mut self,
         header: &'a sam::Header,
     ) -> impl Iterator<Item = io::Result<Box<dyn sam::alignment::Record>>> + 'a {
-        self.inner.alignment_records(header)
+        let records = self.inner.alignment_records(header);
+
+        let Some(read_group_ids) = &self.read_group_ids else {
+            return records;
+        };
+
+        match FilterByReadGroup::new(header, records, read_group_ids.iter().cloned()) {
+            Ok(records) => Box::new(records),
+            Err(e) => Box::new(iter::once(Err(e))),
+        }
     }
 }
@@ -4,6 +4,7 @@ use std::{
     path::Path,
 };
 
+use bstr::BString;
 use noodles_bam as bam;
 use noodles_bgzf as bgzf;
 use noodles_cram as cram;
@@ -19,6 +20,7 @@ pub struct Builder {
     compression_method: Option<Option<CompressionMethod>>,
     format: Option<Format>,
     reference_sequence_repository: fasta::Repository,
+    read_group_ids: Option<Vec<BString>>,
 }
 
 impl Builder {
@@ -76,6 +78,26 @@ impl Builder {
         self
     }
 
+    /// Sets the read groups to filter records by.
+    ///
+    /// When set, [`Reader::records`] only emits records with a read group (`RG`) in the given
+    /// read group IDs. By default, records are not filtered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment;
+    /// let builder = alignment::io::reader::Builder::default().set_read_group_ids(["rg0", "rg1"]);
+    /// ```
+    pub fn set_read_group_ids<I, N>(mut self, read_group_ids: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<BString>,
+    {
+        self.read_group_ids = Some(read_group_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
     /// Builds an alignment reader from a path.
     ///
     /// By default, the format will be autodetected. This can be overridden by using
@@ -162,7 +184,10 @@ impl Builder {
             }
         };
 
-        Ok(Reader { inner })
+        Ok(Reader {
+            inner,
+            read_group_ids: self.read_group_ids,
+        })
     }
 }
 
@@ -1,5 +1,10 @@
 //! Composable iterators for alignment records.
 
-mod pileup;
+mod depth;
+mod filter_by_read_group;
+pub mod pileup;
+pub mod templates;
 
-pub use self::pileup::Pileup as Depth;
+pub use self::{
+    depth::Depth, filter_by_read_group::FilterByReadGroup, pileup::Pileup, templates::Templates,
+};
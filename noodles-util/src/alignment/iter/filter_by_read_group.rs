@@ -0,0 +1,188 @@
+use std::{collections::HashSet, io};
+
+use bstr::BString;
+use noodles_sam::{
+    alignment::{
+        record::data::field::{Tag, Value},
+        Record,
+    },
+    Header,
+};
+
+/// A read group filter iterator.
+///
+/// This takes an iterator of records and only emits records with a read group (`RG`) in a given
+/// set of read group IDs. Records without a read group are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::data::field::Tag,
+///         record_buf::data::field::Value,
+///         RecordBuf,
+///     },
+///     header::record::value::Map,
+/// };
+/// use noodles_util::alignment::iter::FilterByReadGroup;
+///
+/// let header = sam::Header::builder()
+///     .add_read_group("rg0", Map::default())
+///     .add_read_group("rg1", Map::default())
+///     .build();
+///
+/// let build_record = |read_group_id: &str| {
+///     RecordBuf::builder()
+///         .set_data([(Tag::READ_GROUP, Value::from(read_group_id))].into_iter().collect())
+///         .build()
+/// };
+///
+/// let records: Vec<io::Result<RecordBuf>> = vec![
+///     Ok(build_record("rg0")),
+///     Ok(build_record("rg1")),
+///     Ok(build_record("rg0")),
+/// ];
+///
+/// let filter = FilterByReadGroup::new(&header, records.into_iter(), ["rg1"])?;
+/// assert_eq!(filter.count(), 1);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct FilterByReadGroup<I> {
+    records: I,
+    read_group_ids: HashSet<BString>,
+}
+
+impl<I> FilterByReadGroup<I> {
+    /// Creates a read group filter iterator.
+    ///
+    /// This returns an error if a read group ID is not in the header.
+    pub fn new<J, N>(header: &Header, records: I, read_group_ids: J) -> io::Result<Self>
+    where
+        J: IntoIterator<Item = N>,
+        N: Into<BString>,
+    {
+        let read_group_ids: HashSet<_> = read_group_ids.into_iter().map(Into::into).collect();
+
+        for id in &read_group_ids {
+            if !header.read_groups().contains_key(id) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing read group in header: {id}"),
+                ));
+            }
+        }
+
+        Ok(Self {
+            records,
+            read_group_ids,
+        })
+    }
+}
+
+impl<I, R> Iterator for FilterByReadGroup<I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in &mut self.records {
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            match has_read_group(&record, &self.read_group_ids) {
+                Ok(true) => return Some(Ok(record)),
+                Ok(false) => {}
+                Err(e) => return Some(Err(e)),
+            }
+        }
+
+        None
+    }
+}
+
+fn has_read_group<R>(record: &R, read_group_ids: &HashSet<BString>) -> io::Result<bool>
+where
+    R: Record,
+{
+    match record.data().get(&Tag::READ_GROUP).transpose()? {
+        Some(Value::String(id)) => Ok(read_group_ids.contains(id)),
+        Some(_) => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid read group type",
+        )),
+        None => Ok(false),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::{
+        alignment::{record_buf::data::field::Value as ValueBuf, RecordBuf},
+        header::record::value::Map,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        let header = Header::builder()
+            .add_read_group("rg0", Map::default())
+            .add_read_group("rg1", Map::default())
+            .add_read_group("rg2", Map::default())
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_data(
+                    [(Tag::READ_GROUP, ValueBuf::from("rg0"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::builder()
+                .set_data(
+                    [(Tag::READ_GROUP, ValueBuf::from("rg1"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+            RecordBuf::default(),
+            RecordBuf::builder()
+                .set_data(
+                    [(Tag::READ_GROUP, ValueBuf::from("rg2"))]
+                        .into_iter()
+                        .collect(),
+                )
+                .build(),
+        ];
+
+        let filter =
+            FilterByReadGroup::new(&header, records.iter().cloned().map(Ok), ["rg0", "rg2"])?;
+        let actual: Vec<_> = filter.collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, [records[0].clone(), records[3].clone()]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_missing_read_group() {
+        let header = Header::builder()
+            .add_read_group("rg0", Map::default())
+            .build();
+
+        let records: Vec<io::Result<RecordBuf>> = Vec::new();
+
+        assert!(matches!(
+            FilterByReadGroup::new(&header, records.into_iter(), ["rg1"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
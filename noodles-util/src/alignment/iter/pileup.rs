@@ -1,34 +1,52 @@
-use std::{collections::VecDeque, io};
+//! Alignment record pileup.
+
+mod column;
+mod entry;
+
+pub use self::{column::Column, entry::Entry};
+
+use std::{io, rc::Rc};
 
 use noodles_core::Position;
 use noodles_sam::{
-    self as sam,
-    alignment::{record::Flags, Record},
+    alignment::{
+        record::cigar::{op::Kind, Op},
+        Record,
+    },
     Header,
 };
 
-type ActiveWindowRange = (Position, Position);
-
-#[derive(Debug)]
-enum State {
-    Empty,
-    Pile(ActiveWindowRange),
-    Pop(ActiveWindowRange),
-    Drain,
-    Done,
-}
+use super::depth::{alignment_context, filter};
 
 /// A pileup iterator.
 ///
-/// This takes an iterator of coordinate-sorted records and emits reference sequence column
-/// statistics.
+/// This takes an iterator of coordinate-sorted records and emits a column for each reference
+/// sequence position that is covered by at least one record, similar to `samtools mpileup`. Each
+/// column holds the aligned bases, quality scores, and indels of its overlapping records.
+///
+/// Unmapped, secondary, QC fail, and duplicate records are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{alignment::Record, Header};
+/// use noodles_util::alignment::iter::Pileup;
+///
+/// let header = Header::default();
+/// let records: Vec<io::Result<Box<dyn Record>>> = Vec::new();
+///
+/// let mut pileup = Pileup::new(&header, records.into_iter());
+/// assert!(pileup.next().is_none());
+/// ```
 pub struct Pileup<'h, I> {
     header: &'h Header,
     records: I,
-    state: State,
-    position: Position,
-    window: VecDeque<u64>,
-    next_record: Option<Box<dyn Record>>,
+    is_eof: bool,
+    last_start: Option<(usize, Position)>,
+    next_record: Option<ActiveRecord>,
+    active_records: Vec<ActiveRecord>,
+    position: Option<(usize, Position)>,
 }
 
 impl<'h, I> Pileup<'h, I>
@@ -37,291 +55,396 @@ where
 {
     /// Creates a pileup iterator.
     ///
-    /// The given iterator must be coordinate-sorted on a single reference sequence.
+    /// The given iterator must be coordinate-sorted.
     pub fn new(header: &'h Header, records: I) -> Self {
         Self {
             header,
             records,
-            state: State::Empty,
-            position: Position::MIN,
-            window: VecDeque::new(),
+            is_eof: false,
+            last_start: None,
             next_record: None,
+            active_records: Vec::new(),
+            position: None,
         }
     }
 
-    fn initialize(&mut self) -> io::Result<Option<ActiveWindowRange>> {
-        if self.next_record.is_none() {
-            for result in &mut self.records {
-                let record = result?;
-                let flags = record.flags()?;
-
-                if filter(flags) {
-                    continue;
-                }
-
-                self.next_record = Some(record);
+    fn read_record(&mut self) -> io::Result<Option<ActiveRecord>> {
+        for result in &mut self.records {
+            let record = result?;
 
-                break;
+            if filter(record.flags()?) {
+                continue;
             }
-        }
-
-        if let Some(record) = self.next_record.take() {
-            let (_, start, end) = alignment_context(self.header, &record)?;
-            self.position = start;
-            pile_record(&mut self.window, start, end, &record)?;
-            Ok(Some((start, end)))
-        } else {
-            Ok(None)
-        }
-    }
-
-    fn pile_records(
-        &mut self,
-        active_window_range: ActiveWindowRange,
-    ) -> io::Result<Option<ActiveWindowRange>> {
-        let (mut active_window_start, mut active_window_end) = active_window_range;
-
-        if let Some(record) = self.next_record.take() {
-            let (_, start, end) = alignment_context(self.header, &record)?;
-            pile_record(&mut self.window, start, end, &record)?;
-            active_window_end = end.max(active_window_end);
-        }
-
-        while let Some(record) = self.records.next().transpose()? {
-            let flags = record.flags()?;
 
-            if filter(flags) {
+            let Some(active_record) = ActiveRecord::new(self.header, record)? else {
                 continue;
-            }
+            };
 
-            let (_, start, end) = alignment_context(self.header, &record)?;
+            let start = (active_record.reference_sequence_id, active_record.start);
 
-            if start > active_window_end {
-                self.next_record = Some(record);
-                return Ok(None);
-            } else if start > active_window_start {
-                self.next_record = Some(record);
-                active_window_start = start;
-                return Ok(Some((active_window_start, active_window_end)));
+            if self.last_start.is_some_and(|last_start| start < last_start) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "records are not coordinate-sorted",
+                ));
             }
 
-            pile_record(&mut self.window, start, end, &record)?;
-            active_window_end = end.max(active_window_end);
+            self.last_start = Some(start);
+
+            return Ok(Some(active_record));
         }
 
         Ok(None)
     }
-
-    fn pop_front_full(&mut self) -> Option<(Position, u64)> {
-        let position = self.position;
-        let record = self.window.pop_front()?;
-
-        self.position = self
-            .position
-            .checked_add(1)
-            .expect("attempt to add with overflow");
-
-        Some((position, record))
-    }
 }
 
-impl<'a, I> Iterator for Pileup<'a, I>
+impl<'h, I> Iterator for Pileup<'h, I>
 where
     I: Iterator<Item = io::Result<Box<dyn Record>>>,
 {
-    type Item = io::Result<(Position, u64)>;
+    type Item = io::Result<Column>;
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            self.state = match self.state {
-                State::Empty => match self.initialize() {
-                    Ok(None) => State::Done,
-                    Ok(Some(active_window_range)) => State::Pile(active_window_range),
+            if self.next_record.is_none() && !self.is_eof {
+                match self.read_record() {
+                    Ok(Some(record)) => self.next_record = Some(record),
+                    Ok(None) => self.is_eof = true,
                     Err(e) => return Some(Err(e)),
-                },
-                State::Pile(active_window_range) => match self.pile_records(active_window_range) {
-                    Ok(None) => State::Drain,
-                    Ok(Some(next_active_window_range)) => State::Pop(next_active_window_range),
-                    Err(e) => return Some(Err(e)),
-                },
-                State::Pop((active_window_start, active_window_end)) => {
-                    if self.position < active_window_start {
-                        // SAFETY: active_window_start - self.position < self.window.len()
-                        let value = self.pop_front_full().unwrap();
-                        return Some(Ok(value));
-                    } else {
-                        State::Pile((active_window_start, active_window_end))
-                    }
                 }
-                State::Drain => match self.pop_front_full() {
-                    Some(value) => return Some(Ok(value)),
-                    None => State::Empty,
-                },
-                State::Done => return None,
             }
+
+            let (reference_sequence_id, position) = match self.position {
+                Some(position) => position,
+                None => {
+                    let record = self.next_record.as_ref()?;
+                    (record.reference_sequence_id, record.start)
+                }
+            };
+
+            let is_next_record_active = self.next_record.as_ref().is_some_and(|record| {
+                record.reference_sequence_id == reference_sequence_id && record.start <= position
+            });
+
+            if is_next_record_active {
+                self.active_records.extend(self.next_record.take());
+                self.position = Some((reference_sequence_id, position));
+                continue;
+            }
+
+            let entries = self
+                .active_records
+                .iter_mut()
+                .map(|record| record.next_entry(position))
+                .collect();
+
+            self.active_records.retain(|record| record.end > position);
+
+            self.position = if self.active_records.is_empty() {
+                None
+            } else {
+                position
+                    .checked_add(1)
+                    .map(|next_position| (reference_sequence_id, next_position))
+            };
+
+            return Some(Ok(Column {
+                reference_sequence_id,
+                position,
+                entries,
+            }));
         }
     }
 }
 
-fn alignment_context<R>(header: &Header, record: &R) -> io::Result<(usize, Position, Position)>
-where
-    R: Record,
-{
-    match (
-        record.reference_sequence_id(header).transpose()?,
-        record.alignment_start().transpose()?,
-        record.alignment_end().transpose()?,
-    ) {
-        (Some(id), Some(start), Some(end)) => Ok((id, start, end)),
-        _ => Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "missing reference sequence ID or alignment start",
-        )),
-    }
+struct ActiveRecord {
+    record: Rc<dyn Record>,
+    reference_sequence_id: usize,
+    start: Position,
+    end: Position,
+    ops: Vec<Op>,
+    sequence: Vec<u8>,
+    quality_scores: Vec<u8>,
+    op_index: usize,
+    op_offset: usize,
+    read_offset: usize,
+    is_head: bool,
 }
 
-fn filter(flags: Flags) -> bool {
-    flags.is_unmapped() || flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate()
-}
+impl ActiveRecord {
+    fn new(header: &Header, record: Box<dyn Record>) -> io::Result<Option<Self>> {
+        let (reference_sequence_id, start, end) = alignment_context(header, &record)?;
 
-fn pile_record<R>(
-    window: &mut VecDeque<u64>,
-    start: Position,
-    end: Position,
-    record: &R,
-) -> io::Result<()>
-where
-    R: Record,
-{
-    let span = usize::from(end) - usize::from(start) + 1;
+        let ops: Vec<_> = record.cigar().iter().collect::<io::Result<_>>()?;
+
+        if !ops
+            .iter()
+            .any(|op| op.kind().consumes_reference() && !op.is_empty())
+        {
+            return Ok(None);
+        }
 
-    if span > window.len() {
-        window.resize(span, 0);
+        let sequence = record.sequence().iter().collect();
+        let quality_scores = record.quality_scores().iter().collect();
+
+        Ok(Some(Self {
+            record: Rc::from(record),
+            reference_sequence_id,
+            start,
+            end,
+            ops,
+            sequence,
+            quality_scores,
+            op_index: 0,
+            op_offset: 0,
+            read_offset: 0,
+            is_head: true,
+        }))
     }
 
-    let cigar = record.cigar();
-    pile(window, start, start, &cigar)
-}
+    fn next_entry(&mut self, position: Position) -> Entry {
+        while let Some(op) = self.ops.get(self.op_index) {
+            if op.kind().consumes_reference() && !op.is_empty() {
+                break;
+            }
 
-fn pile<C>(
-    window: &mut VecDeque<u64>,
-    offset: Position,
-    start: Position,
-    cigar: &C,
-) -> io::Result<()>
-where
-    C: sam::alignment::record::Cigar,
-{
-    use sam::alignment::record::cigar::op::Kind;
+            if op.kind().consumes_read() {
+                self.read_offset += op.len();
+            }
 
-    let offset = usize::from(offset) - 1;
-    let start = usize::from(start) - 1;
-    let mut i = start - offset;
+            self.op_index += 1;
+        }
 
-    for result in cigar.iter() {
-        let op = result?;
+        // SAFETY: The position is <= the alignment end, so there is at least one remaining
+        // operation that consumes the reference.
+        let op = self.ops[self.op_index];
 
-        match op.kind() {
-            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => {
-                let end = i + op.len();
+        let (read_offset, is_skip) = if op.kind().consumes_read() {
+            (Some(self.read_offset + self.op_offset), false)
+        } else {
+            (None, op.kind() == Kind::Skip)
+        };
 
-                for depth in window.range_mut(i..end) {
-                    *depth += 1;
-                }
+        self.op_offset += 1;
 
-                i = end;
+        let mut insertion_len = 0;
+        let mut deletion_len = 0;
+
+        if self.op_offset >= op.len() {
+            if op.kind().consumes_read() {
+                self.read_offset += op.len();
+            }
+
+            self.op_index += 1;
+            self.op_offset = 0;
+
+            if read_offset.is_some() {
+                for op in &self.ops[self.op_index..] {
+                    match op.kind() {
+                        Kind::Insertion => insertion_len += op.len(),
+                        Kind::Pad => {}
+                        Kind::Deletion => {
+                            deletion_len = op.len();
+                            break;
+                        }
+                        _ => break,
+                    }
+                }
             }
-            Kind::Deletion | Kind::Skip => i += op.len(),
-            _ => {}
         }
-    }
 
-    Ok(())
+        let is_head = self.is_head;
+        self.is_head = false;
+
+        Entry {
+            record: self.record.clone(),
+            read_offset,
+            base: read_offset.and_then(|i| self.sequence.get(i).copied()),
+            quality_score: read_offset.and_then(|i| self.quality_scores.get(i).copied()),
+            is_skip,
+            insertion_len,
+            deletion_len,
+            is_head,
+            is_tail: position == self.end,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use std::num::NonZeroUsize;
 
+    use noodles_sam::{
+        alignment::{record::Flags, RecordBuf},
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
     use super::*;
-    use sam::alignment::RecordBuf;
 
     #[test]
     fn test_next() -> Result<(), Box<dyn std::error::Error>> {
-        use sam::{
-            alignment::record::cigar::{op::Kind, Op},
-            header::record::value::{map::ReferenceSequence, Map},
-        };
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let records: Vec<Box<dyn Record>> = vec![
+            Box::new(
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(2)?)
+                    .set_cigar(
+                        [
+                            Op::new(Kind::Match, 3),
+                            Op::new(Kind::Insertion, 1),
+                            Op::new(Kind::Deletion, 1),
+                            Op::new(Kind::Match, 2),
+                        ]
+                        .into_iter()
+                        .collect(),
+                    )
+                    .set_sequence(b"ACGTAC".to_vec().into())
+                    .set_quality_scores(vec![0, 1, 2, 3, 4, 5].into())
+                    .build(),
+            ),
+            Box::new(
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(4)?)
+                    .set_cigar(
+                        [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 2)]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .set_sequence(b"TGG".to_vec().into())
+                    .set_quality_scores(vec![0, 1, 2].into())
+                    .build(),
+            ),
+            Box::new(
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(1)
+                    .set_alignment_start(Position::try_from(3)?)
+                    .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+                    .set_sequence(b"N".to_vec().into())
+                    .set_quality_scores(vec![0].into())
+                    .build(),
+            ),
+        ];
 
-        // 1 2 3 4 5 6 7 8 9
-        //   [   ]
-        //   [     ]
-        //     [ ]
-        //       [ ]
-        //             [ ]
-        //             [   ]
-        let records: Vec<_> = [
+        let pileup = Pileup::new(&header, records.into_iter().map(Ok));
+        let columns: Vec<_> = pileup.collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = columns
+            .iter()
+            .map(|column| {
+                let entries: Vec<_> = column
+                    .entries()
+                    .iter()
+                    .map(|entry| {
+                        (
+                            entry.read_offset(),
+                            entry.base(),
+                            entry.quality_score(),
+                            entry.is_deletion(),
+                            entry.insertion_len(),
+                            entry.deletion_len(),
+                            entry.is_head(),
+                            entry.is_tail(),
+                        )
+                    })
+                    .collect();
+
+                (
+                    column.reference_sequence_id(),
+                    usize::from(column.position()),
+                    entries,
+                )
+            })
+            .collect();
+
+        let expected = vec![
             (
                 0,
-                Position::try_from(2)?,
-                [Op::new(Kind::Match, 3)].into_iter().collect(),
+                2,
+                vec![(Some(0), Some(b'A'), Some(0), false, 0, 0, true, false)],
             ),
             (
                 0,
-                Position::try_from(2)?,
-                [Op::new(Kind::Match, 4)].into_iter().collect(),
+                3,
+                vec![(Some(1), Some(b'C'), Some(1), false, 0, 0, false, false)],
             ),
             (
                 0,
-                Position::try_from(3)?,
-                [Op::new(Kind::Match, 2)].into_iter().collect(),
+                4,
+                vec![
+                    (Some(2), Some(b'G'), Some(2), false, 1, 1, false, false),
+                    (Some(1), Some(b'G'), Some(1), false, 0, 0, true, false),
+                ],
             ),
             (
                 0,
-                Position::try_from(4)?,
-                [Op::new(Kind::Match, 2)].into_iter().collect(),
+                5,
+                vec![
+                    (None, None, None, true, 0, 0, false, false),
+                    (Some(2), Some(b'G'), Some(2), false, 0, 0, false, true),
+                ],
             ),
             (
                 0,
-                Position::try_from(7)?,
-                [Op::new(Kind::Match, 2)].into_iter().collect(),
+                6,
+                vec![(Some(4), Some(b'A'), Some(4), false, 0, 0, false, false)],
             ),
             (
                 0,
-                Position::try_from(7)?,
-                [Op::new(Kind::Match, 3)].into_iter().collect(),
+                7,
+                vec![(Some(5), Some(b'C'), Some(5), false, 0, 0, false, true)],
+            ),
+            (
+                1,
+                3,
+                vec![(Some(0), Some(b'N'), Some(0), false, 0, 0, true, true)],
             ),
-        ]
-        .into_iter()
-        .map(|(reference_sequence_id, position, cigar)| {
-            RecordBuf::builder()
-                .set_flags(Flags::empty())
-                .set_reference_sequence_id(reference_sequence_id)
-                .set_alignment_start(position)
-                .set_cigar(cigar)
-                .build()
-        })
-        .map(|record| Ok(Box::new(record) as Box<dyn Record>))
-        .collect();
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 
+    #[test]
+    fn test_next_with_unsorted_records() -> Result<(), noodles_core::position::TryFromIntError> {
         let header = Header::builder()
             .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
             .build();
 
-        let pileup = Pileup::new(&header, records.into_iter());
-        let actual: Vec<_> = pileup.collect::<Result<_, _>>()?;
-
-        let expected = [
-            (Position::try_from(2)?, 2),
-            (Position::try_from(3)?, 3),
-            (Position::try_from(4)?, 4),
-            (Position::try_from(5)?, 2),
-            (Position::try_from(7)?, 2),
-            (Position::try_from(8)?, 2),
-            (Position::try_from(9)?, 1),
+        let records: Vec<Box<dyn Record>> = vec![
+            Box::new(
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(8)?)
+                    .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+                    .build(),
+            ),
+            Box::new(
+                RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(2)?)
+                    .set_cigar([Op::new(Kind::Match, 1)].into_iter().collect())
+                    .build(),
+            ),
         ];
 
-        assert_eq!(actual, expected);
+        let mut pileup = Pileup::new(&header, records.into_iter().map(Ok));
+
+        assert!(matches!(
+            pileup.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
 
         Ok(())
     }
@@ -0,0 +1,36 @@
+use noodles_core::Position;
+
+use super::Entry;
+
+/// A pileup column.
+///
+/// A column is the set of alignment records that overlap a single reference sequence position.
+pub struct Column {
+    pub(super) reference_sequence_id: usize,
+    pub(super) position: Position,
+    pub(super) entries: Vec<Entry>,
+}
+
+impl Column {
+    /// Returns the reference sequence ID.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the reference sequence position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the entries.
+    ///
+    /// Entries are ordered by the position of their records in the input.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the number of entries, including deletions and skips.
+    pub fn depth(&self) -> usize {
+        self.entries.len()
+    }
+}
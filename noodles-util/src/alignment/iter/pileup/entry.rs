@@ -0,0 +1,80 @@
+use std::rc::Rc;
+
+use noodles_sam::alignment::Record;
+
+/// A pileup column entry.
+///
+/// An entry is the contribution of a single alignment record to a pileup column.
+pub struct Entry {
+    pub(super) record: Rc<dyn Record>,
+    pub(super) read_offset: Option<usize>,
+    pub(super) base: Option<u8>,
+    pub(super) quality_score: Option<u8>,
+    pub(super) is_skip: bool,
+    pub(super) insertion_len: usize,
+    pub(super) deletion_len: usize,
+    pub(super) is_head: bool,
+    pub(super) is_tail: bool,
+}
+
+impl Entry {
+    /// Returns the originating alignment record.
+    pub fn record(&self) -> &dyn Record {
+        self.record.as_ref()
+    }
+
+    /// Returns the 0-based offset of the aligned base in the read.
+    ///
+    /// This is `None` if the column position is deleted from or skipped in the read.
+    pub fn read_offset(&self) -> Option<usize> {
+        self.read_offset
+    }
+
+    /// Returns the aligned base.
+    ///
+    /// This is `None` if the column position is deleted from or skipped in the read or the record
+    /// has no sequence.
+    pub fn base(&self) -> Option<u8> {
+        self.base
+    }
+
+    /// Returns the quality score of the aligned base.
+    ///
+    /// This is `None` if the column position is deleted from or skipped in the read or the record
+    /// has no quality scores.
+    pub fn quality_score(&self) -> Option<u8> {
+        self.quality_score
+    }
+
+    /// Returns whether the column position is deleted from the read (`D`).
+    pub fn is_deletion(&self) -> bool {
+        self.read_offset.is_none() && !self.is_skip
+    }
+
+    /// Returns whether the column position is a skipped region in the read (`N`).
+    pub fn is_skip(&self) -> bool {
+        self.is_skip
+    }
+
+    /// Returns the length of the insertion immediately following the column position.
+    ///
+    /// The inserted bases start at [`Self::read_offset`] + 1 in the record sequence.
+    pub fn insertion_len(&self) -> usize {
+        self.insertion_len
+    }
+
+    /// Returns the length of the deletion immediately following the column position.
+    pub fn deletion_len(&self) -> usize {
+        self.deletion_len
+    }
+
+    /// Returns whether the column position is the first aligned position of the record.
+    pub fn is_head(&self) -> bool {
+        self.is_head
+    }
+
+    /// Returns whether the column position is the last aligned position of the record.
+    pub fn is_tail(&self) -> bool {
+        self.is_tail
+    }
+}
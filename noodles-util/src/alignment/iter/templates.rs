@@ -0,0 +1,199 @@
+//! Alignment record templates.
+
+mod template;
+
+pub use self::template::Template;
+
+use std::io;
+
+use noodles_sam::alignment::Record;
+
+/// A template iterator.
+///
+/// This takes an iterator of query name-sorted or collated records and groups consecutive records
+/// with the same read name into a template. A template holds the primary records of the first and
+/// last segments (i.e., the read pair) and any secondary and supplementary records.
+///
+/// Records without a read name are each emitted as their own template.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::iter::Templates;
+///
+/// let records: Vec<io::Result<RecordBuf>> = vec![
+///     Ok(RecordBuf::builder()
+///         .set_name("r0")
+///         .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::UNMAPPED)
+///         .build()),
+///     Ok(RecordBuf::builder()
+///         .set_name("r0")
+///         .set_flags(Flags::SEGMENTED | Flags::LAST_SEGMENT | Flags::UNMAPPED)
+///         .build()),
+///     Ok(RecordBuf::builder()
+///         .set_name("r1")
+///         .set_flags(Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::UNMAPPED)
+///         .build()),
+/// ];
+///
+/// let mut templates = Templates::new(records.into_iter());
+///
+/// let template = templates.next().transpose()?.expect("missing template");
+/// assert!(template.pair().is_some());
+///
+/// let template = templates.next().transpose()?.expect("missing template");
+/// assert!(template.is_orphan());
+///
+/// assert!(templates.next().is_none());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Templates<I, R> {
+    records: I,
+    next_record: Option<R>,
+}
+
+impl<I, R> Templates<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates a template iterator.
+    ///
+    /// The given iterator must be query name-sorted or collated, i.e., records with the same read
+    /// name must be adjacent.
+    pub fn new(records: I) -> Self {
+        Self {
+            records,
+            next_record: None,
+        }
+    }
+
+    fn read_template(&mut self, record: R) -> io::Result<Template<R>> {
+        let mut template = Template::new(record)?;
+
+        for result in &mut self.records {
+            let record = result?;
+
+            let is_mate = template.name().is_some() && record.name() == template.name();
+
+            if is_mate {
+                template.push(record)?;
+            } else {
+                self.next_record = Some(record);
+                break;
+            }
+        }
+
+        Ok(template)
+    }
+}
+
+impl<I, R> Iterator for Templates<I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<Template<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.next_record.take() {
+            Some(record) => record,
+            None => match self.records.next()? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            },
+        };
+
+        Some(self.read_template(record))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const R1: Flags = Flags::SEGMENTED.union(Flags::FIRST_SEGMENT);
+        const R2: Flags = Flags::SEGMENTED.union(Flags::LAST_SEGMENT);
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(R2 | Flags::UNMAPPED)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(R1 | Flags::SUPPLEMENTARY | Flags::UNMAPPED)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(R1 | Flags::UNMAPPED)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(R2 | Flags::SECONDARY | Flags::UNMAPPED)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(R2 | Flags::UNMAPPED)
+                .build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+            RecordBuf::builder().set_flags(Flags::UNMAPPED).build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::UNMAPPED)
+                .build(),
+        ];
+
+        let templates: Vec<_> =
+            Templates::new(records.into_iter().map(Ok)).collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = templates
+            .iter()
+            .map(|template| {
+                (
+                    template.name().map(|name| name.to_string()),
+                    template.first_segment().is_some(),
+                    template.last_segment().is_some(),
+                    template.secondary().len(),
+                    template.supplementary().len(),
+                    template.is_orphan(),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (Some(String::from("r0")), true, true, 1, 1, false),
+            (Some(String::from("r1")), false, true, 0, 0, true),
+            (None, true, false, 0, 0, false),
+            (None, true, false, 0, 0, false),
+            (Some(String::from("r2")), true, false, 0, 0, false),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_duplicate_primary_records() {
+        let flags = Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::UNMAPPED;
+
+        let records = [
+            RecordBuf::builder().set_name("r0").set_flags(flags).build(),
+            RecordBuf::builder().set_name("r0").set_flags(flags).build(),
+        ];
+
+        let mut templates = Templates::new(records.into_iter().map(Ok));
+
+        assert!(matches!(
+            templates.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use std::io;
+
+use bstr::BStr;
+use noodles_sam::alignment::Record;
+
+/// An alignment record template.
+///
+/// A template is the set of records that share a read name.
+pub struct Template<R> {
+    first_segment: Option<R>,
+    last_segment: Option<R>,
+    secondary: Vec<R>,
+    supplementary: Vec<R>,
+    is_segmented: bool,
+}
+
+impl<R> Template<R>
+where
+    R: Record,
+{
+    pub(super) fn new(record: R) -> io::Result<Self> {
+        let mut template = Self {
+            first_segment: None,
+            last_segment: None,
+            secondary: Vec::new(),
+            supplementary: Vec::new(),
+            is_segmented: false,
+        };
+
+        template.push(record)?;
+
+        Ok(template)
+    }
+
+    pub(super) fn push(&mut self, record: R) -> io::Result<()> {
+        let flags = record.flags()?;
+
+        if flags.is_secondary() {
+            self.secondary.push(record);
+            return Ok(());
+        } else if flags.is_supplementary() {
+            self.supplementary.push(record);
+            return Ok(());
+        }
+
+        let segment = if !flags.is_segmented() {
+            &mut self.first_segment
+        } else {
+            self.is_segmented = true;
+
+            match (flags.is_first_segment(), flags.is_last_segment()) {
+                (true, false) => &mut self.first_segment,
+                (false, true) => &mut self.last_segment,
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unsupported segment",
+                    ))
+                }
+            }
+        };
+
+        if segment.is_some() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "duplicate primary record",
+            ));
+        }
+
+        *segment = Some(record);
+
+        Ok(())
+    }
+
+    /// Returns the read name.
+    pub fn name(&self) -> Option<&BStr> {
+        self.first_segment
+            .iter()
+            .chain(&self.last_segment)
+            .chain(&self.secondary)
+            .chain(&self.supplementary)
+            .next()
+            .and_then(|record| record.name())
+    }
+
+    /// Returns the primary record of the first segment.
+    ///
+    /// For a record that is not segmented, this is the primary record.
+    pub fn first_segment(&self) -> Option<&R> {
+        self.first_segment.as_ref()
+    }
+
+    /// Returns the primary record of the last segment.
+    pub fn last_segment(&self) -> Option<&R> {
+        self.last_segment.as_ref()
+    }
+
+    /// Returns the primary records of the first and last segments if both are present.
+    pub fn pair(&self) -> Option<(&R, &R)> {
+        self.first_segment.as_ref().zip(self.last_segment.as_ref())
+    }
+
+    /// Returns whether this is a segmented template with only one primary segment.
+    ///
+    /// The mate of an orphan was either filtered out or is not adjacent in the input.
+    pub fn is_orphan(&self) -> bool {
+        self.is_segmented && (self.first_segment.is_some() != self.last_segment.is_some())
+    }
+
+    /// Returns the secondary records.
+    pub fn secondary(&self) -> &[R] {
+        &self.secondary
+    }
+
+    /// Returns the supplementary records.
+    pub fn supplementary(&self) -> &[R] {
+        &self.supplementary
+    }
+}
@@ -0,0 +1,218 @@
+//! Alignment record read orientation.
+//!
+//! The sequence and quality scores of a record are stored in alignment orientation, i.e., if the
+//! record is reverse complemented (`0x10`), they are the reverse complement of the read as it was
+//! sequenced. These functions convert between alignment orientation and original read
+//! orientation, e.g., when exporting reads to FASTQ.
+
+use std::io;
+
+use noodles_sam::alignment::{
+    record_buf::{QualityScores, Sequence},
+    Record, RecordBuf,
+};
+
+/// Returns the sequence of a record in original read orientation.
+///
+/// If the record is reverse complemented, this is the reverse complement of the stored sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::orientation;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .set_sequence(b"AACG".to_vec().into())
+///     .build();
+///
+/// let sequence = orientation::original_sequence(&record)?;
+/// assert_eq!(sequence.as_ref(), b"CGTT");
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn original_sequence<R>(record: &R) -> io::Result<Sequence>
+where
+    R: Record + ?Sized,
+{
+    let sequence: Vec<_> = record.sequence().iter().collect();
+
+    if record.flags()?.is_reverse_complemented() {
+        Ok(reverse_complement(&sequence).into())
+    } else {
+        Ok(sequence.into())
+    }
+}
+
+/// Returns the quality scores of a record in original read orientation.
+///
+/// If the record is reverse complemented, this is the reverse of the stored quality scores.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::orientation;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .set_sequence(b"AACG".to_vec().into())
+///     .set_quality_scores(vec![10, 20, 30, 40].into())
+///     .build();
+///
+/// let quality_scores = orientation::original_quality_scores(&record)?;
+/// assert_eq!(quality_scores.as_ref(), [40, 30, 20, 10]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn original_quality_scores<R>(record: &R) -> io::Result<QualityScores>
+where
+    R: Record + ?Sized,
+{
+    let mut quality_scores: Vec<_> = record.quality_scores().iter().collect();
+
+    if record.flags()?.is_reverse_complemented() {
+        quality_scores.reverse();
+    }
+
+    Ok(quality_scores.into())
+}
+
+/// Sets the sequence of a record from a sequence in original read orientation.
+///
+/// This is the inverse of [`original_sequence`]. If the record is reverse complemented, the
+/// reverse complement of the given sequence is stored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::orientation;
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .build();
+///
+/// orientation::set_original_sequence(&mut record, b"CGTT".to_vec().into());
+/// assert_eq!(record.sequence().as_ref(), b"AACG");
+/// ```
+pub fn set_original_sequence(record: &mut RecordBuf, sequence: Sequence) {
+    *record.sequence_mut() = if record.flags().is_reverse_complemented() {
+        reverse_complement(sequence.as_ref()).into()
+    } else {
+        sequence
+    };
+}
+
+/// Sets the quality scores of a record from quality scores in original read orientation.
+///
+/// This is the inverse of [`original_quality_scores`]. If the record is reverse complemented, the
+/// reverse of the given quality scores is stored.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::orientation;
+///
+/// let mut record = RecordBuf::builder()
+///     .set_flags(Flags::REVERSE_COMPLEMENTED)
+///     .build();
+///
+/// orientation::set_original_quality_scores(&mut record, vec![40, 30, 20, 10].into());
+/// assert_eq!(record.quality_scores().as_ref(), [10, 20, 30, 40]);
+/// ```
+pub fn set_original_quality_scores(record: &mut RecordBuf, quality_scores: QualityScores) {
+    let mut quality_scores = quality_scores;
+
+    if record.flags().is_reverse_complemented() {
+        quality_scores.as_mut().reverse();
+    }
+
+    *record.quality_scores_mut() = quality_scores;
+}
+
+/// Returns the reverse complement of a sequence.
+///
+/// IUPAC nucleotide codes are complemented, preserving case. All other bases, e.g., `=`, are
+/// unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::alignment::orientation;
+/// assert_eq!(orientation::reverse_complement(b"ACGTn="), b"=nACGT");
+/// ```
+pub fn reverse_complement(sequence: &[u8]) -> Vec<u8> {
+    sequence.iter().rev().copied().map(complement).collect()
+}
+
+fn complement(b: u8) -> u8 {
+    match b {
+        b'A' => b'T',
+        b'C' => b'G',
+        b'G' => b'C',
+        b'T' => b'A',
+        b'U' => b'A',
+        b'M' => b'K',
+        b'K' => b'M',
+        b'R' => b'Y',
+        b'Y' => b'R',
+        b'B' => b'V',
+        b'V' => b'B',
+        b'D' => b'H',
+        b'H' => b'D',
+
+        b'a' => b't',
+        b'c' => b'g',
+        b'g' => b'c',
+        b't' => b'a',
+        b'u' => b'a',
+        b'm' => b'k',
+        b'k' => b'm',
+        b'r' => b'y',
+        b'y' => b'r',
+        b'b' => b'v',
+        b'v' => b'b',
+        b'd' => b'h',
+        b'h' => b'd',
+
+        _ => b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::Flags;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> io::Result<()> {
+        for flags in [Flags::empty(), Flags::REVERSE_COMPLEMENTED] {
+            let record = RecordBuf::builder()
+                .set_flags(flags)
+                .set_sequence(b"ACGTNRY".to_vec().into())
+                .set_quality_scores(vec![1, 2, 3, 4, 5, 6, 7].into())
+                .build();
+
+            let sequence = original_sequence(&record)?;
+            let quality_scores = original_quality_scores(&record)?;
+
+            if flags.is_reverse_complemented() {
+                assert_eq!(sequence.as_ref(), b"RYNACGT");
+                assert_eq!(quality_scores.as_ref(), [7, 6, 5, 4, 3, 2, 1]);
+            } else {
+                assert_eq!(sequence.as_ref(), b"ACGTNRY");
+                assert_eq!(quality_scores.as_ref(), [1, 2, 3, 4, 5, 6, 7]);
+            }
+
+            let mut actual = RecordBuf::builder().set_flags(flags).build();
+            set_original_sequence(&mut actual, sequence);
+            set_original_quality_scores(&mut actual, quality_scores);
+
+            assert_eq!(actual, record);
+        }
+
+        Ok(())
+    }
+}
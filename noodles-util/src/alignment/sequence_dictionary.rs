@@ -0,0 +1,191 @@
+//! Reference sequence dictionary creation.
+//!
+//! This builds a SAM header reference sequence dictionary from an indexed FASTA, similar to
+//! Picard `CreateSequenceDictionary`.
+
+use std::{
+    fs,
+    io::{self, BufRead, Seek},
+    num::NonZeroUsize,
+    path::Path,
+};
+
+use md5::{Digest, Md5};
+use noodles_core::Region;
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    header::{
+        record::value::{
+            map::{
+                reference_sequence::{tag, Md5Checksum},
+                ReferenceSequence,
+            },
+            Map,
+        },
+        ReferenceSequences,
+    },
+};
+
+/// Reads a reference sequence dictionary from an indexed FASTA reader.
+///
+/// Each record in the FASTA index creates a reference sequence with its name (`SN`), length
+/// (`LN`), and the MD5 checksum of its sequence (`M5`).
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+///
+/// use noodles_fasta::{self as fasta, fai};
+/// use noodles_util::alignment::sequence_dictionary;
+///
+/// let data = b">sq0\nACGT\n>sq1\nNNNNNNNN\n";
+/// let index = fai::Index::from(vec![
+///     fai::Record::new("sq0", 4, 5, 4, 5),
+///     fai::Record::new("sq1", 8, 15, 8, 9),
+/// ]);
+///
+/// let mut reader = fasta::io::IndexedReader::new(io::Cursor::new(data), index);
+/// let reference_sequences = sequence_dictionary::read(&mut reader)?;
+///
+/// assert_eq!(reference_sequences.len(), 2);
+/// assert_eq!(usize::from(reference_sequences[0].length()), 4);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn read<R>(reader: &mut fasta::io::IndexedReader<R>) -> io::Result<ReferenceSequences>
+where
+    R: BufRead + Seek,
+{
+    let names: Vec<Vec<u8>> = reader
+        .index()
+        .as_ref()
+        .iter()
+        .map(|record| record.name().to_vec())
+        .collect();
+
+    let mut reference_sequences = ReferenceSequences::with_capacity(names.len());
+
+    for name in names {
+        let region = Region::new(name, ..);
+        let record = reader.query(&region)?;
+        let sequence = record.sequence().as_ref();
+
+        let length = NonZeroUsize::new(sequence.len()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid reference sequence length",
+            )
+        })?;
+
+        let md5_checksum = Md5Checksum::from(calculate_normalized_sequence_digest(sequence));
+
+        let reference_sequence = Map::<ReferenceSequence>::builder()
+            .set_length(length)
+            .insert(tag::MD5_CHECKSUM, md5_checksum.to_string())
+            .build()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        reference_sequences.insert(region.name().into(), reference_sequence);
+    }
+
+    Ok(reference_sequences)
+}
+
+/// Builds a SAM header with a reference sequence dictionary from an indexed FASTA file.
+///
+/// The associated index is expected to be at `<src>.fai`. In addition to the fields set by
+/// [`read`], each reference sequence has its URI (`UR`) set to the absolute path of the FASTA
+/// file as a `file` URI.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_util::alignment::sequence_dictionary;
+/// let header = sequence_dictionary::read_from_path("reference.fa")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn read_from_path<P>(src: P) -> io::Result<sam::Header>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+
+    let mut reader = fasta::io::indexed_reader::Builder::default().build_from_path(src)?;
+    let mut reference_sequences = read(&mut reader)?;
+
+    let uri = fs::canonicalize(src).map(|path| format!("file://{}", path.display()))?;
+
+    for reference_sequence in reference_sequences.values_mut() {
+        reference_sequence
+            .other_fields_mut()
+            .insert(tag::URI, uri.clone().into());
+    }
+
+    Ok(sam::Header::builder()
+        .set_reference_sequences(reference_sequences)
+        .build())
+}
+
+// _Sequence Alignment/Map Format Specification_ (2021-06-03) § 1.3.2 "Reference MD5 calculation"
+fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16] {
+    let mut hasher = Md5::new();
+
+    for &b in sequence {
+        // "All characters outside of the inclusive range 33 ('!') to 126 ('~') are stripped out."
+        if b.is_ascii_graphic() {
+            // "All lowercase characters are converted to uppercase."
+            hasher.update([b.to_ascii_uppercase()]);
+        }
+    }
+
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read() -> io::Result<()> {
+        let data = b">sq0\nacgt\n>sq1\nACGTAC\nGT\n";
+        let index = fasta::fai::Index::from(vec![
+            fasta::fai::Record::new("sq0", 4, 5, 4, 5),
+            fasta::fai::Record::new("sq1", 8, 15, 6, 7),
+        ]);
+
+        let mut reader = fasta::io::IndexedReader::new(io::Cursor::new(data), index);
+        let reference_sequences = read(&mut reader)?;
+
+        let actual: Vec<_> = reference_sequences
+            .iter()
+            .map(|(name, reference_sequence)| {
+                (
+                    name.to_string(),
+                    usize::from(reference_sequence.length()),
+                    reference_sequence
+                        .other_fields()
+                        .get(&tag::MD5_CHECKSUM)
+                        .map(|value| value.to_string()),
+                )
+            })
+            .collect();
+
+        let expected = [
+            (
+                String::from("sq0"),
+                4,
+                Some(String::from("f1f8f4bf413b16ad135722aa4591043e")),
+            ),
+            (
+                String::from("sq1"),
+                8,
+                Some(String::from("cc0af3a4fedb18378b4b57b98068e69f")),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
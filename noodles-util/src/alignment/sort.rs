@@ -0,0 +1,442 @@
+//! Alignment record sorting.
+
+pub mod builder;
+mod sort_order;
+
+pub use self::{builder::Builder, sort_order::SortOrder};
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io, mem,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{self, AtomicUsize},
+    vec,
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{
+    self as sam,
+    alignment::RecordBuf,
+    header::record::value::{
+        map::{
+            self,
+            header::{tag, Version},
+        },
+        Map,
+    },
+    Header,
+};
+
+static TEMP_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// An alignment record sorter.
+///
+/// Records are buffered in memory. When the number of buffered records reaches the maximum record
+/// count, they are sorted and spilled to a temporary BAM file. On [`Sorter::finish`], the
+/// temporary files and remaining buffered records are merged into a single sorted stream, similar
+/// to `samtools sort`.
+///
+/// The sort is stable, i.e., records that compare equal keep their input order.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::{self as sam, alignment::RecordBuf};
+/// use noodles_util::alignment::sort::{self, SortOrder};
+///
+/// let mut sorter = sort::Builder::default()
+///     .set_sort_order(SortOrder::QueryName)
+///     .build(sam::Header::default());
+///
+/// sorter.push(RecordBuf::builder().set_name("r1").build())?;
+/// sorter.push(RecordBuf::builder().set_name("r0").build())?;
+///
+/// let names: Vec<_> = sorter
+///     .finish()?
+///     .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+///     .collect::<io::Result<_>>()?;
+///
+/// assert_eq!(names, [Some(b"r0".to_vec()), Some(b"r1".to_vec())]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub struct Sorter {
+    header: Header,
+    sort_order: SortOrder,
+    max_record_count: NonZeroUsize,
+    temp_dir: PathBuf,
+    records: Vec<RecordBuf>,
+    chunks: Vec<PathBuf>,
+}
+
+impl Sorter {
+    fn new(
+        mut header: Header,
+        sort_order: SortOrder,
+        max_record_count: NonZeroUsize,
+        temp_dir: PathBuf,
+    ) -> Self {
+        set_sort_order(&mut header, sort_order);
+
+        Self {
+            header,
+            sort_order,
+            max_record_count,
+            temp_dir,
+            records: Vec::new(),
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Returns the SAM header.
+    ///
+    /// This is the input header with the sort order (`SO`) set. Any group order (`GO`) and
+    /// subsort order (`SS`) are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::header::tag};
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    ///
+    /// let sort_order = sorter
+    ///     .header()
+    ///     .header()
+    ///     .and_then(|map| map.other_fields().get(&tag::SORT_ORDER));
+    ///
+    /// assert_eq!(sort_order.map(|value| value.as_slice()), Some(&b"coordinate"[..]));
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Returns the sort order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort::{self, SortOrder};
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// assert_eq!(sorter.sort_order(), SortOrder::Coordinate);
+    /// ```
+    pub fn sort_order(&self) -> SortOrder {
+        self.sort_order
+    }
+
+    /// Adds a record to the sorter.
+    ///
+    /// This may spill the buffered records to a temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::RecordBuf};
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let mut sorter = sort::Builder::default().build(sam::Header::default());
+    /// sorter.push(RecordBuf::default())?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn push(&mut self, record: RecordBuf) -> io::Result<()> {
+        self.records.push(record);
+
+        if self.records.len() >= self.max_record_count.get() {
+            self.spill()?;
+        }
+
+        Ok(())
+    }
+
+    /// Finishes adding records and returns an iterator over the sorted records.
+    ///
+    /// Temporary files are removed when the returned iterator is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// let mut records = sorter.finish()?;
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn finish(mut self) -> io::Result<Records> {
+        let sort_order = self.sort_order;
+        self.records.sort_by(|a, b| sort_order.cmp(a, b));
+
+        let mut records = Records {
+            header: mem::take(&mut self.header),
+            sort_order,
+            sources: Vec::with_capacity(self.chunks.len() + 1),
+            heads: Vec::with_capacity(self.chunks.len() + 1),
+            chunks: mem::take(&mut self.chunks),
+        };
+
+        for path in &records.chunks {
+            let reader = File::open(path).map(bam::io::Reader::new)?;
+            records.sources.push(Source::Chunk(reader));
+        }
+
+        records
+            .sources
+            .push(Source::Memory(mem::take(&mut self.records).into_iter()));
+
+        for source in &mut records.sources {
+            let head = source.next(&records.header)?;
+            records.heads.push(head);
+        }
+
+        Ok(records)
+    }
+
+    fn spill(&mut self) -> io::Result<()> {
+        let sort_order = self.sort_order;
+        self.records.sort_by(|a, b| sort_order.cmp(a, b));
+
+        let (path, file) = create_temp_file(&self.temp_dir, "sort")?;
+        self.chunks.push(path);
+
+        let mut writer = bgzf::writer::Builder::default()
+            .set_compression_level(bgzf::writer::CompressionLevel::FAST)
+            .build_from_writer(file)
+            .into();
+
+        write_records(&mut writer, &self.header, self.records.drain(..))?;
+
+        writer.try_finish()
+    }
+}
+
+impl Drop for Sorter {
+    fn drop(&mut self) {
+        remove_temp_files(&self.chunks);
+    }
+}
+
+/// An iterator over sorted alignment records.
+///
+/// This is created by calling [`Sorter::finish`].
+pub struct Records {
+    header: Header,
+    sort_order: SortOrder,
+    sources: Vec<Source>,
+    heads: Vec<Option<RecordBuf>>,
+    chunks: Vec<PathBuf>,
+}
+
+impl Records {
+    /// Returns the SAM header.
+    ///
+    /// This is the same as [`Sorter::header`].
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+}
+
+impl Iterator for Records {
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sort_order = self.sort_order;
+
+        // `min_by` returns the first of equal elements, which keeps the merge stable.
+        let (i, _) = self
+            .heads
+            .iter()
+            .enumerate()
+            .filter_map(|(i, head)| head.as_ref().map(|record| (i, record)))
+            .min_by(|(_, a), (_, b)| sort_order.cmp(a, b))?;
+
+        let next_head = match self.sources[i].next(&self.header) {
+            Ok(head) => head,
+            Err(e) => return Some(Err(e)),
+        };
+
+        mem::replace(&mut self.heads[i], next_head).map(Ok)
+    }
+}
+
+impl Drop for Records {
+    fn drop(&mut self) {
+        // Readers are closed before their files are removed.
+        self.sources.clear();
+        remove_temp_files(&self.chunks);
+    }
+}
+
+enum Source {
+    Chunk(bam::io::Reader<bgzf::Reader<File>>),
+    Memory(vec::IntoIter<RecordBuf>),
+}
+
+impl Source {
+    fn next(&mut self, header: &Header) -> io::Result<Option<RecordBuf>> {
+        match self {
+            Self::Chunk(reader) => {
+                let mut record = RecordBuf::default();
+
+                match reader.read_record_buf(header, &mut record)? {
+                    0 => Ok(None),
+                    _ => Ok(Some(record)),
+                }
+            }
+            Self::Memory(records) => Ok(records.next()),
+        }
+    }
+}
+
+fn set_sort_order(header: &mut Header, sort_order: SortOrder) {
+    let map = header
+        .header_mut()
+        .get_or_insert_with(|| Map::<map::Header>::new(Version::default()));
+
+    let other_fields = map.other_fields_mut();
+    other_fields.insert(tag::SORT_ORDER, sort_order.as_ref().into());
+    other_fields.shift_remove(&tag::GROUP_ORDER);
+    other_fields.shift_remove(&tag::SUBSORT_ORDER);
+}
+
+pub(super) fn create_temp_file(dir: &Path, name: &str) -> io::Result<(PathBuf, File)> {
+    loop {
+        let id = TEMP_FILE_ID.fetch_add(1, atomic::Ordering::Relaxed);
+        let path = dir.join(format!("noodles-{name}-{}-{id}.bam", process::id()));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => return Ok((path, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn write_records<W, I>(
+    writer: &mut bam::io::Writer<W>,
+    header: &sam::Header,
+    records: I,
+) -> io::Result<()>
+where
+    W: io::Write,
+    I: Iterator<Item = RecordBuf>,
+{
+    for record in records {
+        writer.write_alignment_record(header, &record)?;
+    }
+
+    Ok(())
+}
+
+pub(super) fn remove_temp_files(paths: &[PathBuf]) {
+    for path in paths {
+        let _ = fs::remove_file(path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::record::Flags,
+        header::record::value::map::{header::sort_order, ReferenceSequence},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_set_sort_order() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header: Header = "@HD\tVN:1.6\tSO:unsorted\tGO:query\tSS:unsorted:x\n".parse()?;
+        set_sort_order(&mut header, SortOrder::Coordinate);
+        let expected: Header = "@HD\tVN:1.6\tSO:coordinate\n".parse()?;
+        assert_eq!(header, expected);
+
+        let mut header = Header::default();
+        set_sort_order(&mut header, SortOrder::QueryName);
+        let actual = header
+            .header()
+            .and_then(|map| map.other_fields().get(&tag::SORT_ORDER));
+        assert_eq!(
+            actual.map(|value| value.as_slice()),
+            Some(sort_order::QUERY_NAME)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_spilled_records() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = env::temp_dir().join(format!("noodles-util-sort-test-{}", process::id()));
+        fs::create_dir_all(&temp_dir)?;
+
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build();
+
+        let mut sorter = Builder::default()
+            .set_max_record_count(NonZeroUsize::try_from(2)?)
+            .set_temp_dir(&temp_dir)
+            .build(header);
+
+        let records = [
+            ("r0", Some((1, 5))),
+            ("r1", None),
+            ("r2", Some((0, 8))),
+            ("r3", Some((1, 2))),
+            ("r4", Some((0, 8))),
+        ];
+
+        for (name, alignment) in records {
+            let mut builder = RecordBuf::builder().set_name(name);
+
+            builder = match alignment {
+                Some((reference_sequence_id, alignment_start)) => builder
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(alignment_start)?),
+                None => builder.set_flags(Flags::UNMAPPED),
+            };
+
+            sorter.push(builder.build())?;
+        }
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 2);
+
+        let records = sorter.finish()?;
+
+        let names: Vec<_> = records
+            .map(|result| result.map(|record| record.name().map(|name| name.to_string())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            names,
+            [
+                Some(String::from("r2")),
+                Some(String::from("r4")),
+                Some(String::from("r3")),
+                Some(String::from("r0")),
+                Some(String::from("r1")),
+            ]
+        );
+
+        assert_eq!(fs::read_dir(&temp_dir)?.count(), 0);
+        fs::remove_dir(&temp_dir)?;
+
+        Ok(())
+    }
+}
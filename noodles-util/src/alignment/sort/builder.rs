@@ -0,0 +1,103 @@
+//! Alignment record sorter builder.
+
+use std::{env, num::NonZeroUsize, path::PathBuf};
+
+use noodles_sam::Header;
+
+use super::{SortOrder, Sorter};
+
+const DEFAULT_MAX_RECORD_COUNT: NonZeroUsize = match NonZeroUsize::new(500_000) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// An alignment record sorter builder.
+pub struct Builder {
+    sort_order: SortOrder,
+    max_record_count: NonZeroUsize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl Builder {
+    /// Sets the sort order.
+    ///
+    /// By default, this is [`SortOrder::Coordinate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort::{self, SortOrder};
+    /// let builder = sort::Builder::default().set_sort_order(SortOrder::QueryName);
+    /// ```
+    pub fn set_sort_order(mut self, sort_order: SortOrder) -> Self {
+        self.sort_order = sort_order;
+        self
+    }
+
+    /// Sets the maximum number of records to hold in memory.
+    ///
+    /// When this limit is reached, the buffered records are sorted and written to a temporary
+    /// file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let builder = sort::Builder::default().set_max_record_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_max_record_count(mut self, max_record_count: NonZeroUsize) -> Self {
+        self.max_record_count = max_record_count;
+        self
+    }
+
+    /// Sets the directory of temporary files.
+    ///
+    /// By default, this is [`std::env::temp_dir`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::sort;
+    /// let builder = sort::Builder::default().set_temp_dir("/tmp");
+    /// ```
+    pub fn set_temp_dir<P>(mut self, temp_dir: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        self.temp_dir = Some(temp_dir.into());
+        self
+    }
+
+    /// Builds an alignment record sorter.
+    ///
+    /// The sort order (`SO`) of the given header is set to the sort order of the sorter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam as sam;
+    /// use noodles_util::alignment::sort;
+    ///
+    /// let sorter = sort::Builder::default().build(sam::Header::default());
+    /// ```
+    pub fn build(self, header: Header) -> Sorter {
+        Sorter::new(
+            header,
+            self.sort_order,
+            self.max_record_count,
+            self.temp_dir.unwrap_or_else(env::temp_dir),
+        )
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            sort_order: SortOrder::default(),
+            max_record_count: DEFAULT_MAX_RECORD_COUNT,
+            temp_dir: None,
+        }
+    }
+}
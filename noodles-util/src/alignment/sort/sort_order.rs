@@ -0,0 +1,121 @@
+use std::cmp::Ordering;
+
+use noodles_sam::alignment::{record::Flags, RecordBuf};
+
+/// An alignment record sort order.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Sort by reference sequence ID, alignment start, and strand (`SO:coordinate`).
+    ///
+    /// Records without a reference sequence ID are placed last.
+    #[default]
+    Coordinate,
+    /// Sort by read name and segment (`SO:queryname`).
+    ///
+    /// Read names are compared lexicographically. Records with the same name are ordered first
+    /// segment before last segment.
+    QueryName,
+}
+
+impl SortOrder {
+    pub(super) fn cmp(&self, a: &RecordBuf, b: &RecordBuf) -> Ordering {
+        match self {
+            Self::Coordinate => cmp_by_coordinate(a, b),
+            Self::QueryName => cmp_by_query_name(a, b),
+        }
+    }
+}
+
+impl AsRef<[u8]> for SortOrder {
+    fn as_ref(&self) -> &[u8] {
+        use noodles_sam::header::record::value::map::header::sort_order;
+
+        match self {
+            Self::Coordinate => sort_order::COORDINATE,
+            Self::QueryName => sort_order::QUERY_NAME,
+        }
+    }
+}
+
+fn cmp_by_coordinate(a: &RecordBuf, b: &RecordBuf) -> Ordering {
+    fn key(record: &RecordBuf) -> impl Ord {
+        let reference_sequence_id = record.reference_sequence_id();
+
+        (
+            reference_sequence_id.is_none(),
+            reference_sequence_id,
+            record.alignment_start(),
+            record.flags().is_reverse_complemented(),
+        )
+    }
+
+    key(a).cmp(&key(b))
+}
+
+fn cmp_by_query_name(a: &RecordBuf, b: &RecordBuf) -> Ordering {
+    fn key(record: &RecordBuf) -> impl Ord + '_ {
+        let segment = record.flags() & (Flags::FIRST_SEGMENT | Flags::LAST_SEGMENT);
+        (record.name(), segment.bits())
+    }
+
+    key(a).cmp(&key(b))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_cmp_by_coordinate() -> Result<(), noodles_core::position::TryFromIntError> {
+        let build = |reference_sequence_id, alignment_start, flags| {
+            let mut builder = RecordBuf::builder().set_flags(flags);
+
+            if let Some(id) = reference_sequence_id {
+                builder = builder.set_reference_sequence_id(id);
+            }
+
+            if let Some(position) = alignment_start {
+                builder = builder.set_alignment_start(position);
+            }
+
+            builder.build()
+        };
+
+        let p8 = Position::try_from(8)?;
+        let p13 = Position::try_from(13)?;
+
+        let a = build(Some(0), Some(p13), Flags::empty());
+        let b = build(Some(1), Some(p8), Flags::empty());
+        assert_eq!(cmp_by_coordinate(&a, &b), Ordering::Less);
+
+        let a = build(Some(0), Some(p8), Flags::empty());
+        let b = build(Some(0), Some(p13), Flags::empty());
+        assert_eq!(cmp_by_coordinate(&a, &b), Ordering::Less);
+
+        let a = build(Some(0), Some(p8), Flags::REVERSE_COMPLEMENTED);
+        let b = build(Some(0), Some(p8), Flags::empty());
+        assert_eq!(cmp_by_coordinate(&a, &b), Ordering::Greater);
+
+        let a = build(None, None, Flags::UNMAPPED);
+        let b = build(Some(1), Some(p8), Flags::empty());
+        assert_eq!(cmp_by_coordinate(&a, &b), Ordering::Greater);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cmp_by_query_name() {
+        let build =
+            |name: &str, flags| RecordBuf::builder().set_name(name).set_flags(flags).build();
+
+        let a = build("r0", Flags::LAST_SEGMENT);
+        let b = build("r1", Flags::FIRST_SEGMENT);
+        assert_eq!(cmp_by_query_name(&a, &b), Ordering::Less);
+
+        let a = build("r0", Flags::LAST_SEGMENT | Flags::REVERSE_COMPLEMENTED);
+        let b = build("r0", Flags::FIRST_SEGMENT);
+        assert_eq!(cmp_by_query_name(&a, &b), Ordering::Greater);
+    }
+}
@@ -0,0 +1,330 @@
+//! Alignment record quality statistics.
+//!
+//! This accumulates per-cycle base quality score distributions, mapping quality, and error rate
+//! summaries from alignment records, similar to a subset of `samtools stats`.
+
+use std::io;
+
+use noodles_sam::alignment::{
+    record::{cigar::op::Kind, data::field::Tag},
+    Record,
+};
+
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+
+/// An alignment record statistics accumulator.
+///
+/// Secondary and supplementary records are counted but otherwise not included in the statistics.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam::alignment::{record::Flags, RecordBuf};
+/// use noodles_util::alignment::stats::Accumulator;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::UNMAPPED)
+///     .set_sequence(b"ACGT".to_vec().into())
+///     .set_quality_scores(vec![30, 30, 20, 10].into())
+///     .build();
+///
+/// let mut accumulator = Accumulator::default();
+/// accumulator.add(&record)?;
+///
+/// let report = accumulator.report();
+/// assert_eq!(report.record_count(), 1);
+/// assert_eq!(report.mean_quality_scores(), [30.0, 30.0, 20.0, 10.0]);
+/// # Ok::<_, io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Accumulator {
+    report: Report,
+}
+
+impl Accumulator {
+    /// Adds a record to the statistics.
+    pub fn add<R>(&mut self, record: &R) -> io::Result<()>
+    where
+        R: Record + ?Sized,
+    {
+        let report = &mut self.report;
+
+        report.record_count += 1;
+
+        let flags = record.flags()?;
+
+        if flags.is_secondary() {
+            report.secondary_record_count += 1;
+            return Ok(());
+        } else if flags.is_supplementary() {
+            report.supplementary_record_count += 1;
+            return Ok(());
+        }
+
+        add_quality_scores(report, record, flags.is_reverse_complemented());
+
+        if flags.is_unmapped() {
+            return Ok(());
+        }
+
+        report.mapped_record_count += 1;
+
+        if let Some(mapping_quality) = record.mapping_quality().transpose()? {
+            report.mapping_quality_count += 1;
+            report.mapping_quality_sum += u64::from(mapping_quality.get());
+        }
+
+        add_mismatches(report, record)?;
+
+        Ok(())
+    }
+
+    /// Returns a report of the statistics accumulated so far.
+    pub fn report(&self) -> Report {
+        self.report.clone()
+    }
+}
+
+/// An alignment record statistics report.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Report {
+    record_count: u64,
+    secondary_record_count: u64,
+    supplementary_record_count: u64,
+    mapped_record_count: u64,
+    quality_score_distributions: Vec<Vec<u64>>,
+    mapping_quality_count: u64,
+    mapping_quality_sum: u64,
+    mapped_base_count: u64,
+    mismatch_count: u64,
+}
+
+impl Report {
+    /// Returns the number of records.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the number of secondary records.
+    pub fn secondary_record_count(&self) -> u64 {
+        self.secondary_record_count
+    }
+
+    /// Returns the number of supplementary records.
+    pub fn supplementary_record_count(&self) -> u64 {
+        self.supplementary_record_count
+    }
+
+    /// Returns the number of mapped primary records.
+    pub fn mapped_record_count(&self) -> u64 {
+        self.mapped_record_count
+    }
+
+    /// Returns the base quality score distribution of each cycle.
+    ///
+    /// Cycles are in sequencing order, i.e., the sequences of reverse complemented records are
+    /// counted from the end. For each cycle, the count at index `i` is the number of bases with a
+    /// quality score of `i`.
+    pub fn quality_score_distributions(&self) -> &[Vec<u64>] {
+        &self.quality_score_distributions
+    }
+
+    /// Returns the mean base quality score of each cycle.
+    pub fn mean_quality_scores(&self) -> Vec<f64> {
+        self.quality_score_distributions
+            .iter()
+            .map(|distribution| {
+                let (n, sum) = distribution
+                    .iter()
+                    .enumerate()
+                    .fold((0, 0), |(n, sum), (score, &count)| {
+                        (n + count, sum + (score as u64) * count)
+                    });
+
+                if n == 0 {
+                    0.0
+                } else {
+                    sum as f64 / n as f64
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the mean mapping quality of mapped primary records.
+    ///
+    /// Records with a missing mapping quality are excluded. This returns `None` if there are no
+    /// mapping qualities.
+    pub fn mean_mapping_quality(&self) -> Option<f64> {
+        (self.mapping_quality_count > 0)
+            .then(|| self.mapping_quality_sum as f64 / self.mapping_quality_count as f64)
+    }
+
+    /// Returns the number of read bases aligned to the reference.
+    ///
+    /// This is the total length of the alignment match (`M`), sequence match (`=`), sequence
+    /// mismatch (`X`), and insertion (`I`) operations of mapped primary records with an edit
+    /// distance (`NM`).
+    pub fn mapped_base_count(&self) -> u64 {
+        self.mapped_base_count
+    }
+
+    /// Returns the number of mismatches.
+    ///
+    /// This is the sum of the edit distances (`NM`) of mapped primary records.
+    pub fn mismatch_count(&self) -> u64 {
+        self.mismatch_count
+    }
+
+    /// Returns the error rate.
+    ///
+    /// This is the number of mismatches divided by the number of mapped bases. This returns
+    /// `None` if there are no mapped bases.
+    pub fn error_rate(&self) -> Option<f64> {
+        (self.mapped_base_count > 0)
+            .then(|| self.mismatch_count as f64 / self.mapped_base_count as f64)
+    }
+}
+
+fn add_quality_scores<R>(report: &mut Report, record: &R, is_reverse_complemented: bool)
+where
+    R: Record + ?Sized,
+{
+    let quality_scores = record.quality_scores();
+
+    let mut scores: Vec<_> = quality_scores.iter().collect();
+
+    if scores.first() == Some(&MISSING_QUALITY_SCORE) {
+        return;
+    }
+
+    let distributions = &mut report.quality_score_distributions;
+
+    if distributions.len() < scores.len() {
+        distributions.resize_with(scores.len(), Vec::new);
+    }
+
+    if is_reverse_complemented {
+        scores.reverse();
+    }
+
+    for (distribution, score) in distributions.iter_mut().zip(scores) {
+        let i = usize::from(score);
+
+        if distribution.len() <= i {
+            distribution.resize(i + 1, 0);
+        }
+
+        distribution[i] += 1;
+    }
+}
+
+fn add_mismatches<R>(report: &mut Report, record: &R) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    let data = record.data();
+
+    let Some(value) = data.get(&Tag::EDIT_DISTANCE).transpose()? else {
+        return Ok(());
+    };
+
+    let edit_distance = value
+        .as_int()
+        .and_then(|n| u64::try_from(n).ok())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid edit distance"))?;
+
+    let mut mapped_base_count = 0;
+
+    for result in record.cigar().iter() {
+        let op = result?;
+
+        if matches!(
+            op.kind(),
+            Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Insertion
+        ) {
+            mapped_base_count += op.len() as u64;
+        }
+    }
+
+    report.mapped_base_count += mapped_base_count;
+    report.mismatch_count += edit_distance;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::{
+        record::{cigar::Op, Flags, MappingQuality},
+        record_buf::data::field::Value,
+        RecordBuf,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_add() -> io::Result<()> {
+        let mut accumulator = Accumulator::default();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_mapping_quality(MappingQuality::new(60).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![40, 30, 20, 10].into())
+            .set_data(
+                [(Tag::EDIT_DISTANCE, Value::from(1u8))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        accumulator.add(&record)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::REVERSE_COMPLEMENTED)
+            .set_mapping_quality(MappingQuality::new(20).unwrap())
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![10, 20, 30, 40].into())
+            .set_data(
+                [(Tag::EDIT_DISTANCE, Value::from(0u8))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        accumulator.add(&record)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SECONDARY)
+            .set_mapping_quality(MappingQuality::new(0).unwrap())
+            .build();
+
+        accumulator.add(&record)?;
+
+        let report = accumulator.report();
+
+        assert_eq!(report.record_count(), 3);
+        assert_eq!(report.secondary_record_count(), 1);
+        assert_eq!(report.mapped_record_count(), 2);
+        assert_eq!(report.mean_quality_scores(), [40.0, 30.0, 20.0, 10.0]);
+        assert_eq!(report.quality_score_distributions()[3][10], 2);
+        assert_eq!(report.mean_mapping_quality(), Some(40.0));
+        assert_eq!(report.mapped_base_count(), 8);
+        assert_eq!(report.mismatch_count(), 1);
+        assert_eq!(report.error_rate(), Some(0.125));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_with_no_records() {
+        let report = Accumulator::default().report();
+        assert!(report.mean_quality_scores().is_empty());
+        assert!(report.mean_mapping_quality().is_none());
+        assert!(report.error_rate().is_none());
+    }
+}
@@ -0,0 +1,231 @@
+//! Alignment format transcoding.
+//!
+//! This converts SAM and BAM streams from one format to the other. Records are copied using the
+//! lazy records of the input format, i.e., without decoding them to alignment record buffers.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    num::NonZeroUsize,
+};
+
+use noodles_bam as bam;
+use noodles_bgzf as bgzf;
+use noodles_sam::{self as sam, alignment::io::Write as _};
+
+use super::io::{
+    reader::builder::{detect_compression_method, detect_format},
+    CompressionMethod, Format,
+};
+
+/// An alignment format transcoder.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::alignment::{io::Format, transcode};
+///
+/// let src = b"@HD\tVN:1.6\n*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\n";
+///
+/// let transcoder = transcode::Builder::default().set_format(Format::Bam).build();
+/// let dst = transcoder.transcode(&src[..], Vec::new())?;
+///
+/// assert_eq!(&dst[..4], [0x1f, 0x8b, 0x08, 0x04]); // BGZF magic number
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Transcoder {
+    format: Format,
+    worker_count: NonZeroUsize,
+    compression_level: bgzf::writer::CompressionLevel,
+}
+
+impl Transcoder {
+    /// Transcodes an alignment stream.
+    ///
+    /// The input format (SAM, bgzip-compressed SAM, or BAM) is autodetected. The header and
+    /// records are written to the output in the format of the transcoder. On success, this
+    /// returns the underlying writer.
+    pub fn transcode<R, W>(&self, reader: R, writer: W) -> io::Result<W>
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut reader = self.build_reader(reader)?;
+        let mut writer = self.build_writer(writer)?;
+
+        let header = match &mut reader {
+            Reader::Sam(reader) => reader.read_header()?,
+            Reader::Bam(reader) => reader.read_header()?,
+        };
+
+        writer.write_alignment_header(&header)?;
+
+        match &mut reader {
+            Reader::Sam(reader) => {
+                let mut record = sam::Record::default();
+
+                while reader.read_record(&mut record)? != 0 {
+                    writer.write_alignment_record(&header, &record)?;
+                }
+            }
+            Reader::Bam(reader) => {
+                let mut record = bam::Record::default();
+
+                while reader.read_record(&mut record)? != 0 {
+                    writer.write_alignment_record(&header, &record)?;
+                }
+            }
+        }
+
+        writer.finish(&header)
+    }
+
+    fn build_reader<R>(&self, reader: R) -> io::Result<Reader>
+    where
+        R: Read + Send + 'static,
+    {
+        let mut reader = BufReader::new(reader);
+
+        let compression_method = detect_compression_method(&mut reader)?;
+        let format = detect_format(&mut reader, compression_method)?;
+
+        match (format, compression_method) {
+            (Format::Sam, None) => Ok(Reader::Sam(sam::io::Reader::new(Box::new(reader)))),
+            (Format::Sam, Some(CompressionMethod::Bgzf)) => {
+                let decoder =
+                    bgzf::MultithreadedReader::with_worker_count(self.worker_count, reader);
+                Ok(Reader::Sam(sam::io::Reader::new(Box::new(decoder))))
+            }
+            (Format::Bam, Some(CompressionMethod::Bgzf)) => {
+                let decoder =
+                    bgzf::MultithreadedReader::with_worker_count(self.worker_count, reader);
+                let inner: Box<dyn Read> = Box::new(decoder);
+                Ok(Reader::Bam(bam::io::Reader::from(inner)))
+            }
+            (Format::Bam, None) => {
+                let inner: Box<dyn Read> = Box::new(reader);
+                Ok(Reader::Bam(bam::io::Reader::from(inner)))
+            }
+            (Format::Cram, _) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unsupported input format: CRAM",
+            )),
+        }
+    }
+
+    fn build_writer<W>(&self, writer: W) -> io::Result<Writer<W>>
+    where
+        W: Write + Send + 'static,
+    {
+        match self.format {
+            Format::Sam => Ok(Writer::Sam(sam::io::Writer::new(BufWriter::new(writer)))),
+            Format::Bam => {
+                let encoder = bgzf::multithreaded_writer::Builder::default()
+                    .set_worker_count(self.worker_count)
+                    .set_compression_level(self.compression_level)
+                    .build_from_writer(writer);
+
+                Ok(Writer::Bam(bam::io::Writer::from(encoder)))
+            }
+            Format::Cram => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "unsupported output format: CRAM",
+            )),
+        }
+    }
+}
+
+enum Reader {
+    Sam(sam::io::Reader<Box<dyn BufRead>>),
+    Bam(bam::io::Reader<Box<dyn Read>>),
+}
+
+enum Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    Sam(sam::io::Writer<BufWriter<W>>),
+    Bam(bam::io::Writer<bgzf::MultithreadedWriter<W>>),
+}
+
+impl<W> Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write_alignment_header(&mut self, header: &sam::Header) -> io::Result<()> {
+        match self {
+            Self::Sam(writer) => writer.write_alignment_header(header),
+            Self::Bam(writer) => writer.write_alignment_header(header),
+        }
+    }
+
+    fn write_alignment_record(
+        &mut self,
+        header: &sam::Header,
+        record: &dyn sam::alignment::Record,
+    ) -> io::Result<()> {
+        match self {
+            Self::Sam(writer) => writer.write_alignment_record(header, record),
+            Self::Bam(writer) => writer.write_alignment_record(header, record),
+        }
+    }
+
+    fn finish(self, header: &sam::Header) -> io::Result<W> {
+        match self {
+            Self::Sam(mut writer) => {
+                writer.finish(header)?;
+                writer.into_inner().into_inner().map_err(|e| e.into_error())
+            }
+            Self::Bam(mut writer) => {
+                writer.finish(header)?;
+                writer.get_mut().finish()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transcode() -> io::Result<()> {
+        const SRC: &[u8] = b"@HD\tVN:1.6\tSO:coordinate
+@SQ\tSN:sq0\tLN:8
+r0\t0\tsq0\t1\t60\t4M\t*\t0\t0\tACGT\tNDLS\tNH:i:1
+r1\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*
+";
+
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+
+        let bam = Builder::default()
+            .set_format(Format::Bam)
+            .set_worker_count(worker_count)
+            .build()
+            .transcode(SRC, Vec::new())?;
+
+        let sam = Builder::default()
+            .set_format(Format::Sam)
+            .set_worker_count(worker_count)
+            .build()
+            .transcode(io::Cursor::new(bam), Vec::new())?;
+
+        assert_eq!(sam, SRC);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transcode_with_cram_output() {
+        let transcoder = Builder::default().set_format(Format::Cram).build();
+
+        assert!(matches!(
+            transcoder.transcode(io::empty(), io::sink()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
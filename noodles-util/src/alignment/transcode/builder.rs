@@ -0,0 +1,82 @@
+use std::num::NonZeroUsize;
+
+use noodles_bgzf as bgzf;
+
+use super::Transcoder;
+use crate::alignment::io::Format;
+
+/// An alignment format transcoder builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    format: Option<Format>,
+    worker_count: Option<NonZeroUsize>,
+    compression_level: Option<bgzf::writer::CompressionLevel>,
+}
+
+impl Builder {
+    /// Sets the output format.
+    ///
+    /// By default, this is BAM. Only SAM and BAM are supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::{io::Format, transcode};
+    /// let builder = transcode::Builder::default().set_format(Format::Sam);
+    /// ```
+    pub fn set_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the number of worker threads used to decompress and compress BGZF blocks.
+    ///
+    /// By default, this is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_util::alignment::transcode;
+    /// let builder = transcode::Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = Some(worker_count);
+        self
+    }
+
+    /// Sets the compression level of the output.
+    ///
+    /// This is only used when the output format is BAM.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf::writer::CompressionLevel;
+    /// use noodles_util::alignment::transcode;
+    /// let builder = transcode::Builder::default().set_compression_level(CompressionLevel::BEST);
+    /// ```
+    pub fn set_compression_level(
+        mut self,
+        compression_level: bgzf::writer::CompressionLevel,
+    ) -> Self {
+        self.compression_level = Some(compression_level);
+        self
+    }
+
+    /// Builds an alignment format transcoder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::transcode;
+    /// let transcoder = transcode::Builder::default().build();
+    /// ```
+    pub fn build(self) -> Transcoder {
+        Transcoder {
+            format: self.format.unwrap_or(Format::Bam),
+            worker_count: self.worker_count.unwrap_or(NonZeroUsize::MIN),
+            compression_level: self.compression_level.unwrap_or_default(),
+        }
+    }
+}
@@ -4,3 +4,4 @@
 pub mod r#async;
 
 pub mod io;
+pub mod transcode;
@@ -0,0 +1,216 @@
+//! Variant format transcoding.
+//!
+//! This converts VCF and BCF streams from one format to the other. Records are copied using the
+//! lazy records of the input format, i.e., without decoding them to variant record buffers.
+//!
+//! When writing BCF, the header dictionaries of strings and contigs are built from the input
+//! header, and each INFO, FILTER, FORMAT, and contig header record without an `IDX` field is
+//! written with its dictionary index. Existing `IDX` fields are kept, and they are written to
+//! both output formats, so header record order and dictionary indices survive round trips.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    num::NonZeroUsize,
+};
+
+use noodles_bcf as bcf;
+use noodles_bgzf as bgzf;
+use noodles_vcf::{self as vcf, variant::io::Write as _};
+
+use super::io::{
+    reader::builder::{detect_compression_method, detect_format},
+    CompressionMethod, Format,
+};
+
+/// A variant format transcoder.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_util::variant::{io::Format, transcode};
+///
+/// let src = b"##fileformat=VCFv4.5\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n";
+///
+/// let transcoder = transcode::Builder::default().set_format(Format::Bcf).build();
+/// let dst = transcoder.transcode(&src[..], Vec::new())?;
+///
+/// assert_eq!(&dst[..4], [0x1f, 0x8b, 0x08, 0x04]); // BGZF magic number
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct Transcoder {
+    format: Format,
+    worker_count: NonZeroUsize,
+    compression_level: bgzf::writer::CompressionLevel,
+}
+
+impl Transcoder {
+    /// Transcodes a variant stream.
+    ///
+    /// The input format (VCF, bgzip-compressed VCF, or BCF) is autodetected. The header and
+    /// records are written to the output in the format of the transcoder. On success, this
+    /// returns the underlying writer.
+    pub fn transcode<R, W>(&self, reader: R, writer: W) -> io::Result<W>
+    where
+        R: Read + Send + 'static,
+        W: Write + Send + 'static,
+    {
+        let mut reader = self.build_reader(reader)?;
+        let mut writer = self.build_writer(writer);
+
+        let header = match &mut reader {
+            Reader::Vcf(reader) => reader.read_header()?,
+            Reader::Bcf(reader) => reader.read_header()?,
+        };
+
+        writer.write_variant_header(&header)?;
+
+        match &mut reader {
+            Reader::Vcf(reader) => {
+                let mut record = vcf::Record::default();
+
+                while reader.read_record(&mut record)? != 0 {
+                    writer.write_variant_record(&header, &record)?;
+                }
+            }
+            Reader::Bcf(reader) => {
+                let mut record = bcf::Record::default();
+
+                while reader.read_record(&mut record)? != 0 {
+                    writer.write_variant_record(&header, &record)?;
+                }
+            }
+        }
+
+        writer.finish()
+    }
+
+    fn build_reader<R>(&self, reader: R) -> io::Result<Reader>
+    where
+        R: Read + Send + 'static,
+    {
+        let mut reader = BufReader::new(reader);
+
+        let compression_method = detect_compression_method(&mut reader)?;
+        let format = detect_format(&mut reader, compression_method)?;
+
+        match (format, compression_method) {
+            (Format::Vcf, None) => Ok(Reader::Vcf(vcf::io::Reader::new(Box::new(reader)))),
+            (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
+                let decoder =
+                    bgzf::MultithreadedReader::with_worker_count(self.worker_count, reader);
+                Ok(Reader::Vcf(vcf::io::Reader::new(Box::new(decoder))))
+            }
+            (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
+                let decoder =
+                    bgzf::MultithreadedReader::with_worker_count(self.worker_count, reader);
+                let inner: Box<dyn Read> = Box::new(decoder);
+                Ok(Reader::Bcf(bcf::io::Reader::from(inner)))
+            }
+            (Format::Bcf, None) => {
+                let inner: Box<dyn Read> = Box::new(reader);
+                Ok(Reader::Bcf(bcf::io::Reader::from(inner)))
+            }
+        }
+    }
+
+    fn build_writer<W>(&self, writer: W) -> Writer<W>
+    where
+        W: Write + Send + 'static,
+    {
+        match self.format {
+            Format::Vcf => Writer::Vcf(vcf::io::Writer::new(BufWriter::new(writer))),
+            Format::Bcf => {
+                let encoder = bgzf::multithreaded_writer::Builder::default()
+                    .set_worker_count(self.worker_count)
+                    .set_compression_level(self.compression_level)
+                    .build_from_writer(writer);
+
+                Writer::Bcf(Box::new(bcf::io::Writer::from(encoder)))
+            }
+        }
+    }
+}
+
+enum Reader {
+    Vcf(vcf::io::Reader<Box<dyn BufRead>>),
+    Bcf(bcf::io::Reader<Box<dyn Read>>),
+}
+
+enum Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    Vcf(vcf::io::Writer<BufWriter<W>>),
+    Bcf(Box<bcf::io::Writer<bgzf::MultithreadedWriter<W>>>),
+}
+
+impl<W> Writer<W>
+where
+    W: Write + Send + 'static,
+{
+    fn write_variant_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        match self {
+            Self::Vcf(writer) => writer.write_variant_header(header),
+            Self::Bcf(writer) => writer.write_variant_header(header),
+        }
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &dyn vcf::variant::Record,
+    ) -> io::Result<()> {
+        match self {
+            Self::Vcf(writer) => writer.write_variant_record(header, record),
+            Self::Bcf(writer) => writer.write_variant_record(header, record),
+        }
+    }
+
+    fn finish(self) -> io::Result<W> {
+        match self {
+            Self::Vcf(writer) => writer.into_inner().into_inner().map_err(|e| e.into_error()),
+            Self::Bcf(mut writer) => writer.get_mut().finish(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transcode() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\",IDX=1>
+##FILTER=<ID=PASS,Description=\"All filters passed\",IDX=0>
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\",IDX=2>
+##contig=<ID=sq0,length=8,IDX=0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\tDP=13\tGT\t0|0
+sq0\t3\t.\tG\tT\t5.5\t.\t.\tGT\t0/1
+";
+
+        let worker_count = NonZeroUsize::try_from(2).unwrap();
+
+        let bcf = Builder::default()
+            .set_format(Format::Bcf)
+            .set_worker_count(worker_count)
+            .build()
+            .transcode(SRC, Vec::new())?;
+
+        let vcf = Builder::default()
+            .set_format(Format::Vcf)
+            .set_worker_count(worker_count)
+            .build()
+            .transcode(io::Cursor::new(bcf), Vec::new())?;
+
+        assert_eq!(vcf, SRC);
+
+        Ok(())
+    }
+}
@@ -16,7 +16,7 @@ use std::{hash::Hash, str::FromStr};
 use indexmap::{IndexMap, IndexSet};
 
 use self::record::value::{
-    map::{AlternativeAllele, Contig, Filter, Format, Info},
+    map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
     Map,
 };
 
@@ -35,6 +35,15 @@ pub type AlternativeAlleles = IndexMap<String, Map<AlternativeAllele>>;
 /// VCF header contig records.
 pub type Contigs = IndexMap<String, Map<Contig>>;
 
+/// VCF header meta records.
+pub type Metas = IndexMap<String, Map<Meta>>;
+
+/// VCF header sample records.
+pub type Samples = IndexMap<String, Map<Sample>>;
+
+/// VCF header pedigree records.
+pub type Pedigrees = IndexMap<String, Map<Pedigree>>;
+
 /// VCF header sample names.
 pub type SampleNames = IndexSet<String>;
 
@@ -50,6 +59,9 @@ pub struct Header {
     formats: Formats,
     alternative_alleles: AlternativeAlleles,
     contigs: Contigs,
+    metas: Metas,
+    samples: Samples,
+    pedigrees: Pedigrees,
     sample_names: SampleNames,
     other_records: OtherRecords,
     string_maps: StringMaps,
@@ -338,6 +350,136 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Returns a map of meta records (`META`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Meta, Map}};
+    ///
+    /// let meta = Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")]);
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_meta("Assay", meta.clone())
+    ///     .build();
+    ///
+    /// let metas = header.metas();
+    /// assert_eq!(metas.len(), 1);
+    /// assert_eq!(&metas[0], &meta);
+    /// ```
+    pub fn metas(&self) -> &Metas {
+        &self.metas
+    }
+
+    /// Returns a mutable reference to a map of meta records (`META`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Meta, Map}};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let meta = Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")]);
+    /// header.metas_mut().insert(String::from("Assay"), meta.clone());
+    ///
+    /// let metas = header.metas();
+    /// assert_eq!(metas.len(), 1);
+    /// assert_eq!(&metas[0], &meta);
+    /// ```
+    pub fn metas_mut(&mut self) -> &mut Metas {
+        &mut self.metas
+    }
+
+    /// Returns a map of sample records (`SAMPLE`).
+    ///
+    /// These are the descriptions of samples given in the meta records and are independent of the
+    /// sample names in the header record (see [`Self::sample_names`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let sample = Map::<Sample>::new();
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample("sample0", sample.clone())
+    ///     .build();
+    ///
+    /// let samples = header.samples();
+    /// assert_eq!(samples.len(), 1);
+    /// assert_eq!(&samples[0], &sample);
+    /// ```
+    pub fn samples(&self) -> &Samples {
+        &self.samples
+    }
+
+    /// Returns a mutable reference to a map of sample records (`SAMPLE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let sample = Map::<Sample>::new();
+    /// header.samples_mut().insert(String::from("sample0"), sample.clone());
+    ///
+    /// let samples = header.samples();
+    /// assert_eq!(samples.len(), 1);
+    /// assert_eq!(&samples[0], &sample);
+    /// ```
+    pub fn samples_mut(&mut self) -> &mut Samples {
+        &mut self.samples
+    }
+
+    /// Returns a map of pedigree records (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let pedigree = Map::<Pedigree>::builder()
+    ///     .set_father("fid")
+    ///     .set_mother("mid")
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("cid", pedigree.clone())
+    ///     .build();
+    ///
+    /// let pedigrees = header.pedigrees();
+    /// assert_eq!(pedigrees.len(), 1);
+    /// assert_eq!(&pedigrees[0], &pedigree);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn pedigrees(&self) -> &Pedigrees {
+        &self.pedigrees
+    }
+
+    /// Returns a mutable reference to a map of pedigree records (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let mut header = vcf::Header::default();
+    ///
+    /// let pedigree = Map::<Pedigree>::new();
+    /// header.pedigrees_mut().insert(String::from("cid"), pedigree.clone());
+    ///
+    /// let pedigrees = header.pedigrees();
+    /// assert_eq!(pedigrees.len(), 1);
+    /// assert_eq!(&pedigrees[0], &pedigree);
+    /// ```
+    pub fn pedigrees_mut(&mut self) -> &mut Pedigrees {
+        &mut self.pedigrees
+    }
+
     /// Returns a list of sample names that come after the FORMAT column in the header record.
     ///
     /// # Examples
@@ -385,8 +527,8 @@ impl Header {
 
     /// Returns a map of records with nonstandard keys.
     ///
-    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`, and
-    /// `contig`.
+    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
+    /// `contig`, `META`, `SAMPLE`, and `PEDIGREE`.
     ///
     /// # Examples
     ///
@@ -406,8 +548,8 @@ impl Header {
 
     /// Returns a mutable reference to a map of collections of records with nonstandard keys.
     ///
-    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`, and
-    /// `contig`.
+    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
+    /// `contig`, `META`, `SAMPLE`, and `PEDIGREE`.
     ///
     /// To simply add an nonstandard record, consider using [`Self::insert`] instead.
     ///
@@ -433,8 +575,8 @@ impl Header {
 
     /// Returns a collection of header values with the given key.
     ///
-    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`, and
-    /// `contig`.
+    /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`,
+    /// `contig`, `META`, `SAMPLE`, and `PEDIGREE`.
     ///
     /// # Examples
     ///
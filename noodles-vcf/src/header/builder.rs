@@ -2,12 +2,12 @@ use super::{
     record::{
         self,
         value::{
-            map::{AlternativeAllele, Contig, Filter, Format, Info},
+            map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
             Map,
         },
     },
-    AlternativeAlleles, Contigs, FileFormat, Filters, Formats, Header, Infos, OtherRecords,
-    SampleNames, StringMaps,
+    AlternativeAlleles, Contigs, FileFormat, Filters, Formats, Header, Infos, Metas, OtherRecords,
+    Pedigrees, SampleNames, Samples, StringMaps,
 };
 
 use indexmap::IndexMap;
@@ -21,6 +21,9 @@ pub struct Builder {
     formats: Formats,
     alternative_alleles: AlternativeAlleles,
     contigs: Contigs,
+    metas: Metas,
+    samples: Samples,
+    pedigrees: Pedigrees,
     sample_names: SampleNames,
     other_records: OtherRecords,
 }
@@ -188,6 +191,84 @@ impl Builder {
         self
     }
 
+    /// Adds a meta record (`META`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Meta, Map}};
+    ///
+    /// let meta = Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")]);
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_meta("Assay", meta.clone())
+    ///     .build();
+    ///
+    /// let metas = header.metas();
+    /// assert_eq!(metas.len(), 1);
+    /// assert_eq!(&metas[0], &meta);
+    /// ```
+    pub fn add_meta<I>(mut self, id: I, meta: Map<Meta>) -> Self
+    where
+        I: Into<String>,
+    {
+        self.metas.insert(id.into(), meta);
+        self
+    }
+
+    /// Adds a sample record (`SAMPLE`).
+    ///
+    /// This does not add a sample name to the header record. See [`Self::add_sample_name`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Sample, Map}};
+    ///
+    /// let sample = Map::<Sample>::new();
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample("sample0", sample.clone())
+    ///     .build();
+    ///
+    /// let samples = header.samples();
+    /// assert_eq!(samples.len(), 1);
+    /// assert_eq!(&samples[0], &sample);
+    /// ```
+    pub fn add_sample<I>(mut self, id: I, sample: Map<Sample>) -> Self
+    where
+        I: Into<String>,
+    {
+        self.samples.insert(id.into(), sample);
+        self
+    }
+
+    /// Adds a pedigree record (`PEDIGREE`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Pedigree, Map}};
+    ///
+    /// let pedigree = Map::<Pedigree>::builder().set_original("oid").build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("did", pedigree.clone())
+    ///     .build();
+    ///
+    /// let pedigrees = header.pedigrees();
+    /// assert_eq!(pedigrees.len(), 1);
+    /// assert_eq!(&pedigrees[0], &pedigree);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_pedigree<I>(mut self, id: I, pedigree: Map<Pedigree>) -> Self
+    where
+        I: Into<String>,
+    {
+        self.pedigrees.insert(id.into(), pedigree);
+        self
+    }
+
     /// Sets sample names.
     ///
     /// # Examples
@@ -294,6 +375,9 @@ impl Builder {
             formats: self.formats,
             alternative_alleles: self.alternative_alleles,
             contigs: self.contigs,
+            metas: self.metas,
+            samples: self.samples,
+            pedigrees: self.pedigrees,
             sample_names: self.sample_names,
             other_records: self.other_records,
             string_maps: StringMaps::default(),
@@ -15,11 +15,11 @@ pub use self::{
 use super::{
     file_format::FileFormat,
     record::value::{
-        map::{AlternativeAllele, Contig, Filter, Format, Info},
+        map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
         Map,
     },
-    AlternativeAlleles, Contigs, Filters, Formats, Header, Infos, OtherRecords, Record,
-    SampleNames, StringMaps,
+    AlternativeAlleles, Contigs, Filters, Formats, Header, Infos, Metas, OtherRecords, Pedigrees,
+    Record, SampleNames, Samples, StringMaps,
 };
 
 #[derive(Debug, Default, Eq, PartialEq)]
@@ -41,6 +41,9 @@ pub struct Parser {
     formats: Formats,
     alternative_alleles: AlternativeAlleles,
     contigs: Contigs,
+    metas: Metas,
+    samples: Samples,
+    pedigrees: Pedigrees,
     sample_names: SampleNames,
     other_records: OtherRecords,
 }
@@ -101,6 +104,11 @@ impl Parser {
                 try_insert_alternative_allele(&mut self.alternative_alleles, id, alternative_allele)
             }
             Record::Contig(id, contig) => try_insert_contig(&mut self.contigs, id, contig),
+            Record::Meta(id, meta) => try_insert_meta(&mut self.metas, id, meta),
+            Record::Sample(id, sample) => try_insert_sample(&mut self.samples, id, sample),
+            Record::Pedigree(id, pedigree) => {
+                try_insert_pedigree(&mut self.pedigrees, id, pedigree)
+            }
             Record::Other(key, value) => insert_other_record(&mut self.other_records, key, value),
         }
     }
@@ -117,6 +125,9 @@ impl Parser {
                 formats: self.formats,
                 alternative_alleles: self.alternative_alleles,
                 contigs: self.contigs,
+                metas: self.metas,
+                samples: self.samples,
+                pedigrees: self.pedigrees,
                 sample_names: self.sample_names,
                 other_records: self.other_records,
                 string_maps: StringMaps::default(),
@@ -148,6 +159,12 @@ pub enum ParseError {
     DuplicateAlternativeAlleleId(String),
     /// A contig ID is duplicated.
     DuplicateContigId(String),
+    /// A meta ID is duplicated.
+    DuplicateMetaId(String),
+    /// A sample ID is duplicated.
+    DuplicateSampleId(String),
+    /// A pedigree ID is duplicated.
+    DuplicatePedigreeId(String),
     /// A record has an invalid value.
     InvalidRecordValue(super::record::value::collection::AddError),
     /// The header is missing.
@@ -189,6 +206,9 @@ impl std::fmt::Display for ParseError {
             Self::DuplicateFormatId(id) => write!(f, "duplicate FORMAT ID: {id}"),
             Self::DuplicateAlternativeAlleleId(id) => write!(f, "duplicate ALT ID: {id}"),
             Self::DuplicateContigId(id) => write!(f, "duplicate contig ID: {id}"),
+            Self::DuplicateMetaId(id) => write!(f, "duplicate META ID: {id}"),
+            Self::DuplicateSampleId(id) => write!(f, "duplicate SAMPLE ID: {id}"),
+            Self::DuplicatePedigreeId(id) => write!(f, "duplicate PEDIGREE ID: {id}"),
             Self::InvalidRecordValue(_) => f.write_str("invalid record value"),
             Self::MissingHeader => f.write_str("missing header"),
             Self::InvalidHeader(actual, expected) => {
@@ -347,6 +367,84 @@ fn try_insert_contig(
     }
 }
 
+fn try_insert_meta(
+    metas: &mut Metas,
+    id: String,
+    meta: Map<Meta>,
+) -> Result<Entry<'_>, ParseError> {
+    use indexmap::map::Entry;
+
+    match metas.entry(id) {
+        Entry::Vacant(entry) => {
+            let i = entry.index();
+
+            entry.insert(meta);
+
+            // SAFETY: The entry was inserted at `i`.
+            Ok(metas
+                .get_index(i)
+                .map(|(k, v)| self::Entry::Meta(k, v))
+                .unwrap())
+        }
+        Entry::Occupied(entry) => {
+            let (id, _) = entry.swap_remove_entry();
+            Err(ParseError::DuplicateMetaId(id))
+        }
+    }
+}
+
+fn try_insert_sample(
+    samples: &mut Samples,
+    id: String,
+    sample: Map<Sample>,
+) -> Result<Entry<'_>, ParseError> {
+    use indexmap::map::Entry;
+
+    match samples.entry(id) {
+        Entry::Vacant(entry) => {
+            let i = entry.index();
+
+            entry.insert(sample);
+
+            // SAFETY: The entry was inserted at `i`.
+            Ok(samples
+                .get_index(i)
+                .map(|(k, v)| self::Entry::Sample(k, v))
+                .unwrap())
+        }
+        Entry::Occupied(entry) => {
+            let (id, _) = entry.swap_remove_entry();
+            Err(ParseError::DuplicateSampleId(id))
+        }
+    }
+}
+
+fn try_insert_pedigree(
+    pedigrees: &mut Pedigrees,
+    id: String,
+    pedigree: Map<Pedigree>,
+) -> Result<Entry<'_>, ParseError> {
+    use indexmap::map::Entry;
+
+    match pedigrees.entry(id) {
+        Entry::Vacant(entry) => {
+            let i = entry.index();
+
+            entry.insert(pedigree);
+
+            // SAFETY: The entry was inserted at `i`.
+            Ok(pedigrees
+                .get_index(i)
+                .map(|(k, v)| self::Entry::Pedigree(k, v))
+                .unwrap())
+        }
+        Entry::Occupied(entry) => {
+            let (id, _) = entry.swap_remove_entry();
+            Err(ParseError::DuplicatePedigreeId(id))
+        }
+    }
+}
+
 fn insert_other_record(
     other_records: &mut OtherRecords,
     key: super::record::key::Other,
@@ -414,7 +512,7 @@ mod tests {
     #[test]
     fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
         use crate::{
-            header::record::{value::map::Other, Value},
+            header::record::Value,
             variant::record::{info, samples},
         };
 
@@ -456,36 +554,23 @@ mod tests {
                 Map::<Format>::from(samples::keys::key::GENOTYPE),
             )
             .add_alternative_allele("DEL", Map::<AlternativeAllele>::new("Deletion"))
-            .insert(
-                "META".parse()?,
-                Value::Map(
-                    String::from("Assay"),
-                    Map::<Other>::builder()
-                        .insert("Type".parse()?, "String")
-                        .insert("Number".parse()?, ".")
-                        .insert("Values".parse()?, "[WholeGenome, Exome]")
-                        .build()?,
-                ),
-            )?
-            .insert(
-                "SAMPLE".parse()?,
-                Value::Map(
-                    String::from("sample0"),
-                    Map::<Other>::builder()
-                        .insert("Assay".parse()?, "WholeGenome")
-                        .build()?,
-                ),
-            )?
-            .insert(
-                "PEDIGREE".parse()?,
-                Value::Map(
-                    String::from("cid"),
-                    Map::<Other>::builder()
-                        .insert("Father".parse()?, "fid")
-                        .insert("Mother".parse()?, "mid")
-                        .build()?,
-                ),
-            )?
+            .add_meta(
+                "Assay",
+                Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")]),
+            )
+            .add_sample(
+                "sample0",
+                Map::<Sample>::builder()
+                    .insert("Assay".parse()?, "WholeGenome")
+                    .build()?,
+            )
+            .add_pedigree(
+                "cid",
+                Map::<Pedigree>::builder()
+                    .set_father("fid")
+                    .set_mother("mid")
+                    .build()?,
+            )
             .add_sample_name("sample0")
             .build();
 
@@ -650,10 +735,32 @@ mod tests {
 #CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
 "#;
 
-        assert!(matches!(
+        assert_eq!(
             Parser::default().parse(s),
-            Err(ParseError::InvalidRecordValue(_))
-        ));
+            Err(ParseError::DuplicateSampleId(String::from("sample0")))
+        );
+
+        let s = r#"##fileformat=VCFv4.3
+##META=<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>
+##META=<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            Parser::default().parse(s),
+            Err(ParseError::DuplicateMetaId(String::from("Assay")))
+        );
+
+        let s = r#"##fileformat=VCFv4.3
+##PEDIGREE=<ID=cid,Father=fid,Mother=mid>
+##PEDIGREE=<ID=cid,Father=fid,Mother=mid>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        assert_eq!(
+            Parser::default().parse(s),
+            Err(ParseError::DuplicatePedigreeId(String::from("cid")))
+        );
     }
 
     #[test]
@@ -1,6 +1,6 @@
 use crate::header::{
     record::value::{
-        map::{AlternativeAllele, Contig, Filter, Format, Info},
+        map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
         Map,
     },
     FileFormat,
@@ -20,6 +20,12 @@ pub enum Entry<'a> {
     AlternativeAllele(&'a str, &'a Map<AlternativeAllele>),
     /// A `contig` entry.
     Contig(&'a str, &'a Map<Contig>),
+    /// A `META` entry.
+    Meta(&'a str, &'a Map<Meta>),
+    /// A `SAMPLE` entry.
+    Sample(&'a str, &'a Map<Sample>),
+    /// A `PEDIGREE` entry.
+    Pedigree(&'a str, &'a Map<Pedigree>),
     /// A nonstadard entry.
     Other,
     /// A header entry.
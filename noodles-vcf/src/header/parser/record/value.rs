@@ -18,6 +18,9 @@ pub enum ParseError {
     InvalidFormat(map::format::ParseError),
     InvalidAlternativeAllele(map::alternative_allele::ParseError),
     InvalidContig(map::contig::ParseError),
+    InvalidMeta(map::meta::ParseError),
+    InvalidSample(map::sample::ParseError),
+    InvalidPedigree(map::pedigree::ParseError),
     InvalidOtherString(key::Other, string::ParseError),
     InvalidOtherMap(key::Other, map::other::ParseError),
     FormatDefinitionMismatch {
@@ -53,6 +56,9 @@ impl error::Error for ParseError {
             Self::InvalidFormat(e) => Some(e),
             Self::InvalidAlternativeAllele(e) => Some(e),
             Self::InvalidContig(e) => Some(e),
+            Self::InvalidMeta(e) => Some(e),
+            Self::InvalidSample(e) => Some(e),
+            Self::InvalidPedigree(e) => Some(e),
             Self::InvalidOtherString(_, e) => Some(e),
             Self::InvalidOtherMap(_, e) => Some(e),
             _ => None,
@@ -113,6 +119,33 @@ impl fmt::Display for ParseError {
 
                 Ok(())
             }
+            Self::InvalidMeta(e) => {
+                write!(f, "invalid {}", key::META)?;
+
+                if let Some(id) = e.id() {
+                    write!(f, ": ID={id}")?;
+                }
+
+                Ok(())
+            }
+            Self::InvalidSample(e) => {
+                write!(f, "invalid {}", key::SAMPLE)?;
+
+                if let Some(id) = e.id() {
+                    write!(f, ": ID={id}")?;
+                }
+
+                Ok(())
+            }
+            Self::InvalidPedigree(e) => {
+                write!(f, "invalid {}", key::PEDIGREE)?;
+
+                if let Some(id) = e.id() {
+                    write!(f, ": ID={id}")?;
+                }
+
+                Ok(())
+            }
             Self::InvalidOtherString(key, _) => write!(f, "invalid other string: {key}"),
             Self::InvalidOtherMap(key, e) => {
                 write!(f, "invalid other map: {key}")?;
@@ -164,9 +197,6 @@ pub(super) fn parse_value(
     file_format: FileFormat,
     key: Key,
 ) -> Result<Record, ParseError> {
-    const META: &str = "META";
-    const PEDIGREE: &str = "PEDIGREE";
-
     match key {
         key::FILE_FORMAT => string::parse_file_format(src)
             .map(Record::FileFormat)
@@ -191,16 +221,17 @@ pub(super) fn parse_value(
         key::CONTIG => map::parse_contig(src)
             .map(|(id, map)| Record::Contig(id, map))
             .map_err(ParseError::InvalidContig),
+        key::META => map::parse_meta(src, file_format)
+            .map(|(id, map)| Record::Meta(id, map))
+            .map_err(ParseError::InvalidMeta),
+        key::SAMPLE => map::parse_sample(src)
+            .map(|(id, map)| Record::Sample(id, map))
+            .map_err(ParseError::InvalidSample),
+        key::PEDIGREE => map::parse_pedigree(src, file_format)
+            .map(|(id, map)| Record::Pedigree(id, map))
+            .map_err(ParseError::InvalidPedigree),
         Key::Other(k) => {
-            let v = if k.as_ref() == META {
-                map::other::parse_meta(src, file_format)
-                    .map(Value::from)
-                    .map_err(|e| ParseError::InvalidOtherMap(k.clone(), e))?
-            } else if k.as_ref() == PEDIGREE {
-                map::other::parse_pedigree(src, file_format)
-                    .map(Value::from)
-                    .map_err(|e| ParseError::InvalidOtherMap(k.clone(), e))?
-            } else if map::is_map(src, file_format) {
+            let v = if map::is_map(src, file_format) {
                 map::parse_other(src)
                     .map(Value::from)
                     .map_err(|e| ParseError::InvalidOtherMap(k.clone(), e))?
@@ -4,14 +4,18 @@ mod field;
 pub mod filter;
 pub mod format;
 pub mod info;
+pub mod meta;
 pub mod other;
+pub mod pedigree;
+pub mod sample;
 
 use std::{error, fmt};
 
 use self::field::split_field;
 pub use self::{
     alternative_allele::parse_alternative_allele, contig::parse_contig, filter::parse_filter,
-    format::parse_format, info::parse_info, other::parse_other,
+    format::parse_format, info::parse_info, meta::parse_meta, other::parse_other,
+    pedigree::parse_pedigree, sample::parse_sample,
 };
 use crate::header::FileFormat;
 
@@ -0,0 +1,273 @@
+use std::{error, fmt, str};
+
+use super::field::{parse_key, parse_value};
+use crate::header::{
+    record::value::{
+        map::{
+            self,
+            meta::{tag, Tag},
+            Meta, OtherFields,
+        },
+        Map,
+    },
+    FileFormat,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ParseErrorKind {
+    InvalidMap(super::ParseError),
+    InvalidField(super::field::ParseError),
+    InvalidKey(super::field::key::ParseError),
+    InvalidValue(Tag, super::field::value::ParseError),
+    MissingId,
+    InvalidValues(str::Utf8Error),
+    DuplicateTag(Tag),
+}
+
+/// An error returned when a VCF header record meta map value fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    id: Option<String>,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(id: Option<String>, kind: ParseErrorKind) -> Self {
+        Self { id, kind }
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidMap(e) => Some(e),
+            ParseErrorKind::InvalidField(e) => Some(e),
+            ParseErrorKind::InvalidKey(e) => Some(e),
+            ParseErrorKind::InvalidValue(_, e) => Some(e),
+            ParseErrorKind::InvalidValues(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::InvalidMap(_) => write!(f, "invalid map"),
+            ParseErrorKind::InvalidField(_) => write!(f, "invalid field"),
+            ParseErrorKind::InvalidKey(_) => write!(f, "invalid key"),
+            ParseErrorKind::InvalidValue(tag, _) => write!(f, "invalid value: {tag}"),
+            ParseErrorKind::MissingId => write!(f, "missing ID"),
+            ParseErrorKind::InvalidValues(_) => write!(f, "invalid values"),
+            ParseErrorKind::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
+        }
+    }
+}
+
+pub fn parse_meta(
+    src: &mut &[u8],
+    file_format: FileFormat,
+) -> Result<(String, Map<Meta>), ParseError> {
+    const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+    super::consume_prefix(src).map_err(|e| ParseError::new(None, ParseErrorKind::InvalidMap(e)))?;
+
+    let mut id = None;
+    let mut ty = None;
+    let mut number = None;
+    let mut values = None;
+
+    let mut other_fields = OtherFields::new();
+
+    loop {
+        let tag = parse_key(src)
+            .map(Tag::from)
+            .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidKey(e)))?;
+
+        match tag {
+            tag::ID => parse_string(src, &id, tag::ID)
+                .and_then(|v| try_replace(&mut id, &None, tag::ID, v))?,
+            tag::TYPE => parse_string(src, &id, tag::TYPE)
+                .and_then(|v| try_replace(&mut ty, &id, tag::TYPE, v))?,
+            tag::NUMBER => parse_string(src, &id, tag::NUMBER)
+                .and_then(|v| try_replace(&mut number, &id, tag::NUMBER, v))?,
+            tag::VALUES => {
+                let vs = if file_format >= VCF_4_3 {
+                    parse_values(src, &id)?
+                } else {
+                    parse_string(src, &id, tag::VALUES).map(|v| vec![v])?
+                };
+
+                try_replace(&mut values, &id, tag::VALUES, vs)?;
+            }
+            Tag::Other(t) => parse_string(src, &id, Tag::Other(t.clone()))
+                .and_then(|v| try_insert(&mut other_fields, &id, t, v))?,
+        }
+
+        let has_separator = super::field::consume_separator(src)
+            .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidField(e)))?;
+
+        if !has_separator {
+            break;
+        }
+    }
+
+    super::consume_suffix(src)
+        .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidMap(e)))?;
+
+    let id = id.ok_or_else(|| ParseError::new(None, ParseErrorKind::MissingId))?;
+
+    let mut meta = Meta {
+        values: values.unwrap_or_default(),
+        ..Default::default()
+    };
+
+    if let Some(ty) = ty {
+        meta.ty = ty;
+    }
+
+    if let Some(number) = number {
+        meta.number = number;
+    }
+
+    Ok((
+        id,
+        Map {
+            inner: meta,
+            other_fields,
+        },
+    ))
+}
+
+fn parse_string(src: &mut &[u8], id: &Option<String>, tag: Tag) -> Result<String, ParseError> {
+    parse_value(src)
+        .map(String::from)
+        .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidValue(tag, e)))
+}
+
+// § 1.4.8 "META field format" (VCF 4.3): `Values` is a bracketed, comma-separated list.
+fn parse_values(src: &mut &[u8], id: &Option<String>) -> Result<Vec<String>, ParseError> {
+    use memchr::memchr;
+
+    const PREFIX: u8 = b'[';
+    const SUFFIX: u8 = b']';
+    const DELIMITER: char = ',';
+
+    let is_delimited = src.first().map(|&b| b == PREFIX).unwrap_or_default();
+
+    if is_delimited {
+        if let Some(i) = memchr(SUFFIX, src) {
+            let (buf, rest) = src.split_at(i + 1);
+
+            let s = str::from_utf8(&buf[1..i])
+                .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidValues(e)))?;
+
+            *src = rest;
+
+            let values = if s.trim().is_empty() {
+                Vec::new()
+            } else {
+                s.split(DELIMITER).map(|t| t.trim().into()).collect()
+            };
+
+            return Ok(values);
+        }
+    }
+
+    parse_string(src, id, tag::VALUES).map(|v| vec![v])
+}
+
+fn try_replace<T>(
+    option: &mut Option<T>,
+    id: &Option<String>,
+    tag: Tag,
+    value: T,
+) -> Result<(), ParseError> {
+    if option.replace(value).is_none() {
+        Ok(())
+    } else {
+        Err(ParseError::new(
+            id.clone(),
+            ParseErrorKind::DuplicateTag(tag),
+        ))
+    }
+}
+
+fn try_insert(
+    other_fields: &mut OtherFields<tag::Standard>,
+    id: &Option<String>,
+    tag: map::tag::Other<tag::Standard>,
+    value: String,
+) -> Result<(), ParseError> {
+    use indexmap::map::Entry;
+
+    match other_fields.entry(tag) {
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+            Ok(())
+        }
+        Entry::Occupied(entry) => {
+            let (t, _) = entry.swap_remove_entry();
+            Err(ParseError::new(
+                id.clone(),
+                ParseErrorKind::DuplicateTag(Tag::Other(t)),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_meta() -> Result<(), Box<dyn std::error::Error>> {
+        const VCF_4_2: FileFormat = FileFormat::new(4, 2);
+        const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+        let mut src = &b"<ID=Assay,Type=String,Number=.,Values=[WholeGenome, Exome]>"[..];
+        assert_eq!(
+            parse_meta(&mut src, VCF_4_3),
+            Ok((
+                String::from("Assay"),
+                Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")])
+            ))
+        );
+
+        let mut src = &b"<ID=Assay,Values=[]>"[..];
+        assert_eq!(
+            parse_meta(&mut src, VCF_4_3),
+            Ok((String::from("Assay"), Map::<Meta>::new(Vec::new())))
+        );
+
+        let mut src = &b"<ID=Assay,Values=WholeGenome>"[..];
+        assert_eq!(
+            parse_meta(&mut src, VCF_4_2),
+            Ok((
+                String::from("Assay"),
+                Map::<Meta>::new(vec![String::from("WholeGenome")])
+            ))
+        );
+
+        let mut src = &b"<ID=Assay,Values=[WholeGenome, Exome]>"[..];
+        assert!(matches!(
+            parse_meta(&mut src, VCF_4_2),
+            Err(ParseError {
+                id,
+                kind: ParseErrorKind::InvalidKey(_)
+            }) if id == Some(String::from("Assay"))
+        ));
+
+        let mut src = &b"<Values=[WholeGenome, Exome]>"[..];
+        assert_eq!(
+            parse_meta(&mut src, VCF_4_3),
+            Err(ParseError::new(None, ParseErrorKind::MissingId))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use std::{error, fmt};
+
+use crate::header::record::value::{
+    map::{
+        self,
+        other::{tag, Tag},
+        OtherFields, Sample,
+    },
+    Map,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum ParseErrorKind {
+    InvalidMap(super::ParseError),
+    InvalidField(super::field::ParseError),
+    MissingId,
+    DuplicateTag(Tag),
+}
+
+/// An error returned when a VCF header record sample map value fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    id: Option<String>,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(id: Option<String>, kind: ParseErrorKind) -> Self {
+        Self { id, kind }
+    }
+
+    pub(crate) fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            ParseErrorKind::InvalidMap(e) => Some(e),
+            ParseErrorKind::InvalidField(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::InvalidMap(_) => write!(f, "invalid map"),
+            ParseErrorKind::InvalidField(_) => write!(f, "invalid field"),
+            ParseErrorKind::MissingId => write!(f, "missing ID"),
+            ParseErrorKind::DuplicateTag(tag) => write!(f, "duplicate tag: {tag}"),
+        }
+    }
+}
+
+pub fn parse_sample(src: &mut &[u8]) -> Result<(String, Map<Sample>), ParseError> {
+    super::consume_prefix(src).map_err(|e| ParseError::new(None, ParseErrorKind::InvalidMap(e)))?;
+
+    let mut id = None;
+    let mut other_fields = OtherFields::new();
+
+    while let Some((raw_key, raw_value)) = super::split_field(src)
+        .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidField(e)))?
+    {
+        match Tag::from(raw_key) {
+            tag::ID => try_replace(&mut id, &None, tag::ID, raw_value.into())?,
+            Tag::Other(t) => try_insert(&mut other_fields, &id, t, raw_value.into())?,
+        }
+    }
+
+    super::consume_suffix(src)
+        .map_err(|e| ParseError::new(id.clone(), ParseErrorKind::InvalidMap(e)))?;
+
+    let id = id.ok_or_else(|| ParseError::new(None, ParseErrorKind::MissingId))?;
+
+    Ok((
+        id,
+        Map {
+            inner: Sample,
+            other_fields,
+        },
+    ))
+}
+
+fn try_replace<T>(
+    option: &mut Option<T>,
+    id: &Option<String>,
+    tag: Tag,
+    value: T,
+) -> Result<(), ParseError> {
+    if option.replace(value).is_none() {
+        Ok(())
+    } else {
+        Err(ParseError::new(
+            id.clone(),
+            ParseErrorKind::DuplicateTag(tag),
+        ))
+    }
+}
+
+fn try_insert(
+    other_fields: &mut OtherFields<tag::Standard>,
+    id: &Option<String>,
+    tag: map::tag::Other<tag::Standard>,
+    value: String,
+) -> Result<(), ParseError> {
+    use indexmap::map::Entry;
+
+    match other_fields.entry(tag) {
+        Entry::Vacant(entry) => {
+            entry.insert(value);
+            Ok(())
+        }
+        Entry::Occupied(entry) => {
+            let (t, _) = entry.swap_remove_entry();
+            Err(ParseError::new(
+                id.clone(),
+                ParseErrorKind::DuplicateTag(Tag::Other(t)),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sample() -> Result<(), Box<dyn std::error::Error>> {
+        let mut src = &br#"<ID=sample0,Assay=WholeGenome>"#[..];
+
+        let id = String::from("sample0");
+        let map = Map::<Sample>::builder()
+            .insert("Assay".parse()?, "WholeGenome")
+            .build()?;
+        let expected = (id, map);
+
+        assert_eq!(parse_sample(&mut src), Ok(expected));
+
+        let mut src = &br#"<Assay=WholeGenome>"#[..];
+        assert_eq!(
+            parse_sample(&mut src),
+            Err(ParseError::new(None, ParseErrorKind::MissingId))
+        );
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ pub use self::{key::Key, value::Value};
 use std::str::FromStr;
 
 use self::value::{
-    map::{AlternativeAllele, Contig, Filter, Format, Info},
+    map::{AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample},
     Map,
 };
 use super::{parser::record::ParseError, FileFormat};
@@ -28,6 +28,12 @@ pub enum Record {
     Format(String, Map<Format>),
     /// An `INFO` record.
     Info(String, Map<Info>),
+    /// A `META` record.
+    Meta(String, Map<Meta>),
+    /// A `PEDIGREE` record.
+    Pedigree(String, Map<Pedigree>),
+    /// A `SAMPLE` record.
+    Sample(String, Map<Sample>),
     /// A nonstadard record.
     Other(key::Other, Value),
 }
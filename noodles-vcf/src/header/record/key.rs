@@ -24,6 +24,15 @@ pub const ALTERNATIVE_ALLELE: Key = Key::Standard(Standard::AlternativeAllele);
 /// VCF header record contig key.
 pub const CONTIG: Key = Key::Standard(Standard::Contig);
 
+/// VCF header record meta key.
+pub const META: Key = Key::Standard(Standard::Meta);
+
+/// VCF header record sample key.
+pub const SAMPLE: Key = Key::Standard(Standard::Sample);
+
+/// VCF header record pedigree key.
+pub const PEDIGREE: Key = Key::Standard(Standard::Pedigree);
+
 /// A standard VCF record key.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Standard {
@@ -39,6 +48,12 @@ pub enum Standard {
     AlternativeAllele,
     /// Contig (`contig`).
     Contig,
+    /// Meta-information (`META`).
+    Meta,
+    /// Sample (`SAMPLE`).
+    Sample,
+    /// Pedigree (`PEDIGREE`).
+    Pedigree,
 }
 
 impl Standard {
@@ -50,6 +65,9 @@ impl Standard {
             "FORMAT" => Some(Self::Format),
             "ALT" => Some(Self::AlternativeAllele),
             "contig" => Some(Self::Contig),
+            "META" => Some(Self::Meta),
+            "SAMPLE" => Some(Self::Sample),
+            "PEDIGREE" => Some(Self::Pedigree),
             _ => None,
         }
     }
@@ -64,6 +82,9 @@ impl AsRef<str> for Standard {
             Self::Format => "FORMAT",
             Self::AlternativeAllele => "ALT",
             Self::Contig => "contig",
+            Self::Meta => "META",
+            Self::Sample => "SAMPLE",
+            Self::Pedigree => "PEDIGREE",
         }
     }
 }
@@ -146,6 +167,9 @@ mod tests {
         assert_eq!(FORMAT.to_string(), "FORMAT");
         assert_eq!(ALTERNATIVE_ALLELE.to_string(), "ALT");
         assert_eq!(CONTIG.to_string(), "contig");
+        assert_eq!(META.to_string(), "META");
+        assert_eq!(SAMPLE.to_string(), "SAMPLE");
+        assert_eq!(PEDIGREE.to_string(), "PEDIGREE");
         assert_eq!(
             Key::Other(Other(String::from("fileDate"))).to_string(),
             "fileDate"
@@ -160,6 +184,9 @@ mod tests {
         assert_eq!(Key::from("FORMAT"), FORMAT);
         assert_eq!(Key::from("ALT"), ALTERNATIVE_ALLELE);
         assert_eq!(Key::from("contig"), CONTIG);
+        assert_eq!(Key::from("META"), META);
+        assert_eq!(Key::from("SAMPLE"), SAMPLE);
+        assert_eq!(Key::from("PEDIGREE"), PEDIGREE);
         assert_eq!(
             Key::from("fileDate"),
             Key::Other(Other(String::from("fileDate")))
@@ -6,12 +6,15 @@ pub mod contig;
 pub mod filter;
 pub mod format;
 pub mod info;
+pub mod meta;
 pub mod other;
+pub mod pedigree;
+pub mod sample;
 pub(crate) mod tag;
 
 pub use self::{
     alternative_allele::AlternativeAllele, builder::Builder, contig::Contig, filter::Filter,
-    format::Format, info::Info, other::Other,
+    format::Format, info::Info, meta::Meta, other::Other, pedigree::Pedigree, sample::Sample,
 };
 
 use std::fmt;
@@ -0,0 +1,141 @@
+//! Inner VCF header meta map value.
+
+mod builder;
+pub(crate) mod tag;
+
+pub use self::tag::Tag;
+
+use super::{Inner, Map, OtherFields};
+
+const DEFAULT_TYPE: &str = "String";
+const DEFAULT_NUMBER: &str = ".";
+
+/// An inner VCF header meta map value.
+///
+/// A meta record (`META`) describes the allowed values of a sample (`SAMPLE`) field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Meta {
+    pub(crate) ty: String,
+    pub(crate) number: String,
+    pub(crate) values: Vec<String>,
+}
+
+impl Inner for Meta {
+    type StandardTag = tag::Standard;
+    type Builder = builder::Builder;
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self {
+            ty: String::from(DEFAULT_TYPE),
+            number: String::from(DEFAULT_NUMBER),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl Map<Meta> {
+    /// Creates a VCF header meta map value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    /// let map = Map::<Meta>::new(vec![String::from("WholeGenome"), String::from("Exome")]);
+    /// ```
+    pub fn new(values: Vec<String>) -> Self {
+        Self {
+            inner: Meta {
+                values,
+                ..Default::default()
+            },
+            other_fields: OtherFields::new(),
+        }
+    }
+
+    /// Returns the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    /// let map = Map::<Meta>::new(Vec::new());
+    /// assert_eq!(map.ty(), "String");
+    /// ```
+    pub fn ty(&self) -> &str {
+        &self.inner.ty
+    }
+
+    /// Returns a mutable reference to the type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    ///
+    /// let mut map = Map::<Meta>::new(Vec::new());
+    /// *map.type_mut() = String::from("Integer");
+    /// assert_eq!(map.ty(), "Integer");
+    /// ```
+    pub fn type_mut(&mut self) -> &mut String {
+        &mut self.inner.ty
+    }
+
+    /// Returns the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    /// let map = Map::<Meta>::new(Vec::new());
+    /// assert_eq!(map.number(), ".");
+    /// ```
+    pub fn number(&self) -> &str {
+        &self.inner.number
+    }
+
+    /// Returns a mutable reference to the number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    ///
+    /// let mut map = Map::<Meta>::new(Vec::new());
+    /// *map.number_mut() = String::from("1");
+    /// assert_eq!(map.number(), "1");
+    /// ```
+    pub fn number_mut(&mut self) -> &mut String {
+        &mut self.inner.number
+    }
+
+    /// Returns the list of allowed values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    /// let values = vec![String::from("WholeGenome"), String::from("Exome")];
+    /// let map = Map::<Meta>::new(values.clone());
+    /// assert_eq!(map.values(), &values);
+    /// ```
+    pub fn values(&self) -> &[String] {
+        &self.inner.values
+    }
+
+    /// Returns a mutable reference to the list of allowed values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Meta, Map};
+    ///
+    /// let mut map = Map::<Meta>::new(Vec::new());
+    /// map.values_mut().push(String::from("WholeGenome"));
+    /// assert_eq!(map.values(), [String::from("WholeGenome")]);
+    /// ```
+    pub fn values_mut(&mut self) -> &mut Vec<String> {
+        &mut self.inner.values
+    }
+}
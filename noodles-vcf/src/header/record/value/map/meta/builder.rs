@@ -0,0 +1,24 @@
+use super::Meta;
+use crate::header::record::value::map::{self, builder::BuildError};
+
+#[derive(Default)]
+pub struct Builder {
+    values: Vec<String>,
+}
+
+impl map::builder::Inner<Meta> for Builder {
+    fn build(self) -> Result<Meta, BuildError> {
+        Ok(Meta {
+            values: self.values,
+            ..Default::default()
+        })
+    }
+}
+
+impl map::Builder<Meta> {
+    /// Sets the list of allowed values.
+    pub fn set_values(mut self, values: Vec<String>) -> Self {
+        self.inner.values = values;
+        self
+    }
+}
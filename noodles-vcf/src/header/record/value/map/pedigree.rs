@@ -0,0 +1,123 @@
+//! Inner VCF header pedigree map value.
+
+mod builder;
+pub(crate) mod tag;
+
+pub use self::tag::Tag;
+
+use super::{Inner, Map};
+
+/// An inner VCF header pedigree map value.
+///
+/// A pedigree record (`PEDIGREE`) either describes the parents of a sample (`Father` and
+/// `Mother`) or the sample a genome is derived from (`Original`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pedigree {
+    pub(crate) father: Option<String>,
+    pub(crate) mother: Option<String>,
+    pub(crate) original: Option<String>,
+}
+
+impl Inner for Pedigree {
+    type StandardTag = tag::Standard;
+    type Builder = builder::Builder;
+}
+
+impl Map<Pedigree> {
+    /// Creates a VCF header pedigree map value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ID of the father.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::builder().set_father("fid").build()?;
+    /// assert_eq!(map.father(), Some("fid"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn father(&self) -> Option<&str> {
+        self.inner.father.as_deref()
+    }
+
+    /// Returns a mutable reference to the ID of the father.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    ///
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.father_mut() = Some(String::from("fid"));
+    /// assert_eq!(map.father(), Some("fid"));
+    /// ```
+    pub fn father_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.father
+    }
+
+    /// Returns the ID of the mother.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::new();
+    /// assert!(map.mother().is_none());
+    /// ```
+    pub fn mother(&self) -> Option<&str> {
+        self.inner.mother.as_deref()
+    }
+
+    /// Returns a mutable reference to the ID of the mother.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    ///
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.mother_mut() = Some(String::from("mid"));
+    /// assert_eq!(map.mother(), Some("mid"));
+    /// ```
+    pub fn mother_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.mother
+    }
+
+    /// Returns the ID of the original sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    /// let map = Map::<Pedigree>::new();
+    /// assert!(map.original().is_none());
+    /// ```
+    pub fn original(&self) -> Option<&str> {
+        self.inner.original.as_deref()
+    }
+
+    /// Returns a mutable reference to the ID of the original sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Pedigree, Map};
+    ///
+    /// let mut map = Map::<Pedigree>::new();
+    /// *map.original_mut() = Some(String::from("oid"));
+    /// assert_eq!(map.original(), Some("oid"));
+    /// ```
+    pub fn original_mut(&mut self) -> &mut Option<String> {
+        &mut self.inner.original
+    }
+}
@@ -0,0 +1,48 @@
+use super::Pedigree;
+use crate::header::record::value::map::{self, builder::BuildError};
+
+#[derive(Default)]
+pub struct Builder {
+    father: Option<String>,
+    mother: Option<String>,
+    original: Option<String>,
+}
+
+impl map::builder::Inner<Pedigree> for Builder {
+    fn build(self) -> Result<Pedigree, BuildError> {
+        Ok(Pedigree {
+            father: self.father,
+            mother: self.mother,
+            original: self.original,
+        })
+    }
+}
+
+impl map::Builder<Pedigree> {
+    /// Sets the ID of the father.
+    pub fn set_father<I>(mut self, id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.father = Some(id.into());
+        self
+    }
+
+    /// Sets the ID of the mother.
+    pub fn set_mother<I>(mut self, id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.mother = Some(id.into());
+        self
+    }
+
+    /// Sets the ID of the original sample.
+    pub fn set_original<I>(mut self, id: I) -> Self
+    where
+        I: Into<String>,
+    {
+        self.inner.original = Some(id.into());
+        self
+    }
+}
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use crate::header::record::value::map;
+
+/// A VCF header pedigree map tag.
+pub type Tag = map::tag::Tag<Standard>;
+
+// For some reason, using the `Tag` type alias produces a `nontrivial_structural_match` warning
+// when pattern matching, so it's avoided here.
+pub(crate) const ID: Tag = map::tag::Tag::Standard(Standard::Id);
+pub(crate) const FATHER: Tag = map::tag::Tag::Standard(Standard::Father);
+pub(crate) const MOTHER: Tag = map::tag::Tag::Standard(Standard::Mother);
+pub(crate) const ORIGINAL: Tag = map::tag::Tag::Standard(Standard::Original);
+
+// § 1.4.9 "Pedigree field format" (VCF 4.2): Prior to VCF 4.3, the ID of a pedigree record is
+// given by either the `Child` or `Derived` field.
+pub(crate) const CHILD: &str = "Child";
+pub(crate) const DERIVED: &str = "Derived";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Standard {
+    Id,
+    Father,
+    Mother,
+    Original,
+}
+
+impl map::tag::Standard for Standard {}
+
+impl AsRef<str> for Standard {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Id => "ID",
+            Self::Father => "Father",
+            Self::Mother => "Mother",
+            Self::Original => "Original",
+        }
+    }
+}
+
+impl FromStr for Standard {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ID" => Ok(Self::Id),
+            "Father" => Ok(Self::Father),
+            "Mother" => Ok(Self::Mother),
+            "Original" => Ok(Self::Original),
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_str_for_standard() {
+        assert_eq!(Standard::Id.as_ref(), "ID");
+        assert_eq!(Standard::Father.as_ref(), "Father");
+        assert_eq!(Standard::Mother.as_ref(), "Mother");
+        assert_eq!(Standard::Original.as_ref(), "Original");
+    }
+}
@@ -0,0 +1,29 @@
+//! Inner VCF header sample map value.
+
+use super::{builder, tag, Inner, Map};
+
+/// An inner VCF header sample map value.
+///
+/// A sample record (`SAMPLE`) has no standard fields other than its ID. Its fields are stored as
+/// other fields and are typically described by meta records (`META`).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sample;
+
+impl Inner for Sample {
+    type StandardTag = tag::Identity;
+    type Builder = builder::Identity;
+}
+
+impl Map<Sample> {
+    /// Creates a VCF header sample map value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::Sample, Map};
+    /// let map = Map::<Sample>::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
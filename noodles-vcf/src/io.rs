@@ -2,10 +2,13 @@
 
 mod compression_method;
 pub mod indexed_reader;
+pub mod indexed_writer;
 pub mod reader;
+mod sample_selection;
 pub mod writer;
 
 pub use self::{
-    compression_method::CompressionMethod, indexed_reader::IndexedReader, reader::Reader,
+    compression_method::CompressionMethod, indexed_reader::IndexedReader,
+    indexed_writer::IndexedWriter, reader::Reader, sample_selection::SampleSelection,
     writer::Writer,
 };
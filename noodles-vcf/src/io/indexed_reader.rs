@@ -11,7 +11,7 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 use super::{
-    reader::{Query, RecordBufs},
+    reader::{MultiQuery, Query, RecordBufs},
     Reader,
 };
 use crate::{variant::RecordBuf, Header, Record};
@@ -90,6 +90,15 @@ where
     ) -> io::Result<Query<'r, 'h, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    pub fn query_many<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'r, 'h, R>> {
+        self.inner.query_many(header, &self.index, regions)
+    }
 }
 
 impl<R> IndexedReader<bgzf::Reader<R>>
@@ -0,0 +1,436 @@
+//! Indexed VCF writer.
+
+mod builder;
+mod index;
+mod index_format;
+
+use std::{
+    io::{self, Write},
+    path::PathBuf,
+};
+
+use noodles_bgzf as bgzf;
+use noodles_core::Position;
+use noodles_csi::{
+    self as csi,
+    binning_index::{
+        binning,
+        index::{
+            header::{self, ReferenceSequenceNames},
+            reference_sequence::{bin::Chunk, index::BinnedIndex},
+        },
+        Indexer as BinningIndexer,
+    },
+};
+use noodles_tabix as tabix;
+
+pub use self::{builder::Builder, index::Index, index_format::IndexFormat};
+use super::Writer;
+use crate::{variant::io::Write as _, Header, Record};
+
+const TBI_MIN_SHIFT: u8 = 14;
+const TBI_DEPTH: u8 = 5;
+
+enum Indexer {
+    Tbi(tabix::index::Indexer),
+    Csi {
+        reference_sequence_names: ReferenceSequenceNames,
+        indexer: BinningIndexer<BinnedIndex>,
+    },
+}
+
+impl Indexer {
+    fn new(index_format: IndexFormat) -> Self {
+        match index_format {
+            IndexFormat::Tbi => {
+                let mut indexer = tabix::index::Indexer::default();
+                indexer.set_header(header::Builder::vcf().build());
+                Self::Tbi(indexer)
+            }
+            IndexFormat::Csi { min_shift, depth } => Self::Csi {
+                reference_sequence_names: ReferenceSequenceNames::new(),
+                indexer: BinningIndexer::new(min_shift, depth),
+            },
+        }
+    }
+
+    fn add_record(
+        &mut self,
+        reference_sequence_name: &str,
+        start: Position,
+        end: Position,
+        chunk: Chunk,
+    ) -> io::Result<()> {
+        match self {
+            Self::Tbi(indexer) => indexer.add_record(reference_sequence_name, start, end, chunk),
+            Self::Csi {
+                reference_sequence_names,
+                indexer,
+            } => {
+                let (reference_sequence_id, _) =
+                    reference_sequence_names.insert_full(reference_sequence_name.into());
+
+                let alignment_context = Some((reference_sequence_id, start, end, true));
+                indexer.add_record(alignment_context, chunk)
+            }
+        }
+    }
+
+    fn build(self) -> Index {
+        match self {
+            Self::Tbi(indexer) => Index::Tbi(indexer.build()),
+            Self::Csi {
+                reference_sequence_names,
+                indexer,
+            } => {
+                let reference_sequence_count = reference_sequence_names.len();
+
+                let header = header::Builder::vcf()
+                    .set_reference_sequence_names(reference_sequence_names)
+                    .build();
+
+                Index::Csi(indexer.set_header(header).build(reference_sequence_count))
+            }
+        }
+    }
+}
+
+/// An indexed VCF writer.
+///
+/// This writes bgzip-compressed VCF and builds a tabix index (TBI) or a coordinate-sorted index
+/// (CSI) while writing records, removing the need to index the output in a separate pass. Records
+/// must be sorted by reference sequence and position.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::BinningIndex;
+/// use noodles_vcf::{self as vcf, variant::io::Write};
+///
+/// let mut writer = vcf::io::IndexedWriter::new(Vec::new());
+///
+/// let header = vcf::Header::default();
+/// writer.write_header(&header)?;
+///
+/// let record = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// writer.write_variant_record(&header, &record)?;
+///
+/// let index = writer.finish()?;
+///
+/// if let vcf::io::indexed_writer::Index::Tbi(index) = index {
+///     assert_eq!(index.header().map(|h| h.reference_sequence_names().len()), Some(1));
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct IndexedWriter<W>
+where
+    W: Write,
+{
+    inner: Writer<bgzf::Writer<W>>,
+    index_format: IndexFormat,
+    indexer: Option<Indexer>,
+    index_dst: Option<PathBuf>,
+}
+
+impl<W> IndexedWriter<W>
+where
+    W: Write,
+{
+    /// Creates an indexed VCF writer with a default compression level.
+    ///
+    /// The given stream is wrapped in a BGZF encoder. This builds a tabix index (TBI).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    /// let writer = vcf::io::IndexedWriter::new(io::sink());
+    /// ```
+    pub fn new(inner: W) -> Self {
+        Self::with_index_format(inner, IndexFormat::default())
+    }
+
+    fn with_index_format(inner: W, index_format: IndexFormat) -> Self {
+        Self {
+            inner: Writer::new(bgzf::Writer::new(inner)),
+            index_format,
+            indexer: Some(Indexer::new(index_format)),
+            index_dst: None,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &bgzf::Writer<W> {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut bgzf::Writer<W> {
+        self.inner.get_mut()
+    }
+
+    /// Writes a VCF header.
+    ///
+    /// This fails if a contig is too long to be indexed with the index format.
+    pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
+        let (min_shift, depth) = match self.index_format {
+            IndexFormat::Tbi => (TBI_MIN_SHIFT, TBI_DEPTH),
+            IndexFormat::Csi { min_shift, depth } => (min_shift, depth),
+        };
+
+        let max_position = usize::from(binning::max_position(min_shift, depth)?);
+
+        for (name, contig) in header.contigs() {
+            if let Some(length) = contig.length() {
+                if length > max_position {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("contig too long to index: {name} ({length} > {max_position})"),
+                    ));
+                }
+            }
+        }
+
+        self.inner.write_header(header)
+    }
+
+    /// Writes a VCF record.
+    pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
+        self.write_variant_record(header, record)
+    }
+
+    /// Finishes the output stream and builds the index.
+    ///
+    /// If the writer was built from a path, the index is also written to `<dst>.tbi` or
+    /// `<dst>.csi`.
+    ///
+    /// This method can only be called once. Writing records or finishing the writer again returns
+    /// an error.
+    pub fn finish(&mut self) -> io::Result<Index> {
+        let indexer = self.indexer.take().ok_or_else(finished_error)?;
+        self.inner.get_mut().try_finish()?;
+
+        let index = indexer.build();
+
+        if let Some(dst) = self.index_dst.take() {
+            match &index {
+                Index::Tbi(index) => tabix::write(dst, index)?,
+                Index::Csi(index) => csi::write(dst, index)?,
+            }
+        }
+
+        Ok(index)
+    }
+}
+
+impl<W> crate::variant::io::Write for IndexedWriter<W>
+where
+    W: Write,
+{
+    fn write_variant_header(&mut self, header: &Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &Header,
+        record: &dyn crate::variant::Record,
+    ) -> io::Result<()> {
+        let indexer = self.indexer.as_mut().ok_or_else(finished_error)?;
+
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        let start = record
+            .variant_start()
+            .transpose()?
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing position"))?;
+
+        let end = record.variant_end(header)?;
+
+        let start_position = self.inner.get_ref().virtual_position();
+        self.inner.write_variant_record(header, record)?;
+        let end_position = self.inner.get_ref().virtual_position();
+
+        let chunk = Chunk::new(start_position, end_position);
+
+        indexer.add_record(reference_sequence_name, start, end.max(start), chunk)
+    }
+}
+
+fn finished_error() -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, "writer already finished")
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::BinningIndex;
+
+    use super::*;
+    use crate::{
+        header::record::value::{map::Contig, Map},
+        variant::RecordBuf,
+    };
+
+    #[test]
+    fn test_finish() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("ACGT")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(5)?)
+                .set_reference_bases("ACGT")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(3)?)
+                .set_reference_bases("ACGT")
+                .build(),
+        ];
+
+        for record in &records {
+            writer.write_variant_record(&header, record)?;
+        }
+
+        let Index::Tbi(index) = writer.finish()? else {
+            panic!("expected TBI");
+        };
+
+        let names = index.header().map(|h| h.reference_sequence_names());
+        assert_eq!(
+            names.map(|names| names.iter().collect::<Vec<_>>()),
+            Some(vec![&String::from("sq0"), &String::from("sq1")])
+        );
+
+        let start = Position::try_from(4)?;
+        let end = Position::try_from(6)?;
+        let chunks = index.query(0, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &records[0]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            writer.finish(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_with_csi() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::builder().set_length(1 << 30).build()?)
+            .build();
+
+        let mut writer = IndexedWriter::new(io::sink());
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let index_format = IndexFormat::Csi {
+            min_shift: 14,
+            depth: 6,
+        };
+        let mut writer = IndexedWriter::with_index_format(io::sink(), index_format);
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from((1 << 29) + 1)?)
+            .set_reference_bases("ACGT")
+            .build();
+        writer.write_variant_record(&header, &record)?;
+
+        let Index::Csi(index) = writer.finish()? else {
+            panic!("expected CSI");
+        };
+
+        assert_eq!(index.min_shift(), 14);
+        assert_eq!(index.depth(), 6);
+
+        let start = Position::try_from(1 << 29)?;
+        let end = Position::try_from((1 << 29) + 8)?;
+        let chunks = index.query(0, (start..=end).into())?;
+        assert_eq!(chunks.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let mut writer = IndexedWriter::new(io::sink());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("ACGT")
+            .build();
+        writer.write_variant_record(&header, &record)?;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("ACGT")
+            .build();
+        writer.write_variant_record(&header, &record)?;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("ACGT")
+            .build();
+        assert!(matches!(
+            writer.write_variant_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_missing_position() {
+        let header = Header::default();
+        let mut writer = IndexedWriter::new(Vec::new());
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_reference_bases("ACGT")
+            .build();
+
+        *record.variant_start_mut() = None;
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert_eq!(
+            writer.get_ref().virtual_position(),
+            bgzf::VirtualPosition::default()
+        );
+    }
+}
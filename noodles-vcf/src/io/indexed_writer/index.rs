@@ -0,0 +1,11 @@
+use noodles_csi as csi;
+use noodles_tabix as tabix;
+
+/// An index built by an indexed VCF writer.
+#[derive(Debug)]
+pub enum Index {
+    /// A tabix index (TBI).
+    Tbi(tabix::Index),
+    /// A coordinate-sorted index (CSI).
+    Csi(csi::Index),
+}
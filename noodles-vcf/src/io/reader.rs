@@ -2,6 +2,7 @@
 
 mod builder;
 mod header;
+mod multi_query;
 pub(crate) mod query;
 pub(crate) mod record;
 pub mod record_buf;
@@ -9,7 +10,7 @@ mod record_bufs;
 
 use self::record::read_record;
 pub(crate) use self::record_buf::parse_record_buf;
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{builder::Builder, multi_query::MultiQuery, query::Query, record_bufs::RecordBufs};
 
 use std::{
     io::{self, BufRead},
@@ -21,6 +22,10 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 
 use self::header::read_header;
+use super::{
+    sample_selection::{select_sample_columns, select_sample_names},
+    SampleSelection,
+};
 use crate::{variant::RecordBuf, Header, Record};
 
 /// A VCF reader.
@@ -50,6 +55,9 @@ use crate::{variant::RecordBuf, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    sample_selection: Option<SampleSelection>,
+    sample_indices: Option<Vec<usize>>,
+    samples_buf: String,
 }
 
 impl<R> Reader<R> {
@@ -118,6 +126,9 @@ where
         Self {
             inner,
             buf: String::new(),
+            sample_selection: None,
+            sample_indices: None,
+            samples_buf: String::new(),
         }
     }
 
@@ -144,7 +155,15 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner)
+        let mut header = read_header(&mut self.inner)?;
+
+        if let Some(sample_selection) = &self.sample_selection {
+            let indices = sample_selection.resolve(header.sample_names())?;
+            *header.sample_names_mut() = select_sample_names(header.sample_names(), &indices);
+            self.sample_indices = Some(indices);
+        }
+
+        Ok(header)
     }
 
     /// Reads a single VCF record.
@@ -187,6 +206,10 @@ where
         match read_line(&mut self.inner, &mut self.buf)? {
             0 => Ok(0),
             n => {
+                if let Some(indices) = &self.sample_indices {
+                    select_line_sample_columns(&mut self.buf, indices, &mut self.samples_buf)?;
+                }
+
                 parse_record_buf(&self.buf, header, record)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
@@ -250,7 +273,15 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if let Some(indices) = &self.sample_indices {
+            let fields = record.fields_mut();
+            let start = fields.bounds.info_end;
+            select_sample_columns(&mut fields.buf, start, indices, &mut self.samples_buf)?;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records.
@@ -330,12 +361,77 @@ where
         let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
         let chunks = index.query(reference_sequence_id, region.interval())?;
 
+        let sample_indices = self.sample_indices.clone();
+
         Ok(Query::new(
             self.get_mut(),
             chunks,
             reference_sequence_name,
             region.interval(),
             header,
+            sample_indices,
+        ))
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// The chunks of all regions are merged, so each record is read at most once, even if it
+    /// intersects more than one region. Each record is returned with the indices of the regions
+    /// it intersects, in the order the regions are given.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::read("sample.vcf.gz.tbi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq0:10-21".parse()?];
+    /// let query = reader.query_many(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let (record, region_indices) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_many<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<MultiQuery<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::merge_chunks;
+
+        let mut chunks = Vec::new();
+        let mut resolved_regions = Vec::with_capacity(regions.len());
+
+        for region in regions {
+            let (reference_sequence_id, reference_sequence_name) = resolve_region(index, region)?;
+            chunks.extend(index.query(reference_sequence_id, region.interval())?);
+            resolved_regions.push((reference_sequence_name, region.interval()));
+        }
+
+        let chunks = merge_chunks(&chunks);
+        let sample_indices = self.sample_indices.clone();
+
+        Ok(MultiQuery::new(
+            self.get_mut(),
+            chunks,
+            resolved_regions,
+            header,
+            sample_indices,
         ))
     }
 }
@@ -387,6 +483,28 @@ where
     }
 }
 
+// Rewrites the samples columns of a raw record line to only include the selected samples.
+fn select_line_sample_columns(
+    line: &mut String,
+    indices: &[usize],
+    scratch: &mut String,
+) -> io::Result<()> {
+    const DELIMITER: char = '\t';
+    const INFO_INDEX: usize = 7;
+
+    let Some((i, _)) = line.match_indices(DELIMITER).nth(INFO_INDEX) else {
+        return Ok(());
+    };
+
+    select_sample_columns(line, i + 1, indices, scratch)?;
+
+    if line.len() == i + 1 {
+        line.pop();
+    }
+
+    Ok(())
+}
+
 pub(crate) fn resolve_region<I>(index: &I, region: &Region) -> io::Result<(usize, Vec<u8>)>
 where
     I: BinningIndex,
@@ -440,6 +558,79 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_sample_selection() -> io::Result<()> {
+        use crate::variant::record_buf::samples::sample::Value;
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1\tsample2
+sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1\t1|1
+sq0\t2\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1\t1|1
+";
+
+        let mut reader = Builder::default()
+            .set_sample_selection(SampleSelection::Names(vec![
+                String::from("sample2"),
+                String::from("sample0"),
+            ]))
+            .build_from_reader(DATA)?;
+
+        let header = reader.read_header()?;
+
+        let expected: crate::header::SampleNames =
+            [String::from("sample2"), String::from("sample0")]
+                .into_iter()
+                .collect();
+        assert_eq!(header.sample_names(), &expected);
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(
+            &record.fields().buf[record.fields().bounds.info_end..],
+            "GT\t1|1\t0|0"
+        );
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+
+        let genotypes: Vec<_> = record
+            .samples()
+            .values()
+            .map(|sample| sample.values().to_vec())
+            .collect();
+
+        assert_eq!(
+            genotypes,
+            [
+                vec![Some(Value::Genotype("1|1".parse().unwrap()))],
+                vec![Some(Value::Genotype("0|0".parse().unwrap()))],
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_line_sample_columns() -> io::Result<()> {
+        let mut scratch = String::new();
+
+        let mut line = String::from("sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1");
+        select_line_sample_columns(&mut line, &[1], &mut scratch)?;
+        assert_eq!(line, "sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|1");
+
+        let mut line = String::from("sq0\t1\t.\tA\t.\t.\tPASS\t.\tGT\t0|0\t0|1");
+        select_line_sample_columns(&mut line, &[], &mut scratch)?;
+        assert_eq!(line, "sq0\t1\t.\tA\t.\t.\tPASS\t.");
+
+        let mut line = String::from("sq0\t1\t.\tA\t.\t.\tPASS\t.");
+        select_line_sample_columns(&mut line, &[0], &mut scratch)?;
+        assert_eq!(line, "sq0\t1\t.\tA\t.\t.\tPASS\t.");
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
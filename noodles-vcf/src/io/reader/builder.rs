@@ -7,12 +7,13 @@ use std::{
 use noodles_bgzf as bgzf;
 
 use super::Reader;
-use crate::io::CompressionMethod;
+use crate::io::{CompressionMethod, SampleSelection};
 
 /// A VCF reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    sample_selection: Option<SampleSelection>,
 }
 
 impl Builder {
@@ -29,6 +30,24 @@ impl Builder {
         self
     }
 
+    /// Restricts reading to a selection of samples.
+    ///
+    /// The sample names in the header returned by [`Reader::read_header`] are restricted to the
+    /// selection, and sample columns that are not selected are dropped from each record before
+    /// their values are parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::{reader::Builder, SampleSelection};
+    /// let builder = Builder::default()
+    ///     .set_sample_selection(SampleSelection::Names(vec![String::from("sample0")]));
+    /// ```
+    pub fn set_sample_selection(mut self, sample_selection: SampleSelection) -> Self {
+        self.sample_selection = Some(sample_selection);
+        self
+    }
+
     /// Builds a VCF reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -77,6 +96,9 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+        reader.sample_selection = self.sample_selection;
+
+        Ok(reader)
     }
 }
@@ -0,0 +1,151 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::Interval;
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::{query::intersects, Reader};
+use crate::{Header, Record};
+
+/// An iterator over records of a VCF reader that intersect any of the given regions.
+///
+/// This is created by calling [`Reader::query_many`].
+pub struct MultiQuery<'r, 'h, R> {
+    reader: Reader<csi::io::Query<'r, R>>,
+    regions: Vec<(Vec<u8>, Interval)>,
+    header: &'h Header,
+    record: Record,
+}
+
+impl<'r, 'h, R> MultiQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        regions: Vec<(Vec<u8>, Interval)>,
+        header: &'h Header,
+        sample_indices: Option<Vec<usize>>,
+    ) -> Self {
+        let mut reader = Reader::new(csi::io::Query::new(reader, chunks));
+        reader.sample_indices = sample_indices;
+
+        Self {
+            reader,
+            regions,
+            header,
+            record: Record::default(),
+        }
+    }
+}
+
+impl<'r, 'h, R> Iterator for MultiQuery<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<(Record, Vec<usize>)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.reader.read_record(&mut self.record) {
+                Ok(0) => return None,
+                Ok(_) => match intersecting_regions(self.header, &self.record, &self.regions) {
+                    Ok(indices) if indices.is_empty() => {}
+                    Ok(indices) => return Some(Ok((self.record.clone(), indices))),
+                    Err(e) => return Some(Err(e)),
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+fn intersecting_regions(
+    header: &Header,
+    record: &Record,
+    regions: &[(Vec<u8>, Interval)],
+) -> io::Result<Vec<usize>> {
+    let mut indices = Vec::new();
+
+    for (i, (reference_sequence_name, interval)) in regions.iter().enumerate() {
+        if intersects(header, record, reference_sequence_name, *interval)? {
+            indices.push(i);
+        }
+    }
+
+    Ok(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        header::record::value::{map::Contig, Map},
+        io::{indexed_writer::Index, IndexedWriter},
+        variant::{io::Write, RecordBuf},
+    };
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .build();
+
+        let records = [("sq0", 1), ("sq1", 1), ("sq1", 5), ("sq1", 10)]
+            .into_iter()
+            .map(|(reference_sequence_name, position)| {
+                Position::try_from(position).map(|position| {
+                    RecordBuf::builder()
+                        .set_reference_sequence_name(reference_sequence_name)
+                        .set_variant_start(position)
+                        .set_reference_bases("ACGT")
+                        .build()
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut writer = IndexedWriter::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_variant_record(&header, record)?;
+        }
+
+        let Index::Tbi(index) = writer.finish()? else {
+            panic!("expected TBI");
+        };
+
+        let src = writer.get_ref().get_ref().clone();
+        let mut reader = Reader::new(bgzf::Reader::new(Cursor::new(src)));
+        reader.read_header()?;
+
+        let regions = ["sq1:2-6".parse()?, "sq1:4-11".parse()?, "sq0".parse()?];
+        let query = reader.query_many(&header, &index, &regions)?;
+
+        let actual: Vec<_> = query
+            .map(|result| {
+                result.and_then(|(record, region_indices)| {
+                    RecordBuf::try_from_variant_record(&header, &record)
+                        .map(|record| (record, region_indices))
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = [
+            (records[0].clone(), vec![2]),
+            (records[1].clone(), vec![0, 1]),
+            (records[2].clone(), vec![0, 1]),
+            (records[3].clone(), vec![1]),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -28,9 +28,13 @@ where
         reference_sequence_name: Vec<u8>,
         interval: Interval,
         header: &'h Header,
+        sample_indices: Option<Vec<usize>>,
     ) -> Self {
+        let mut reader = Reader::new(csi::io::Query::new(reader, chunks));
+        reader.sample_indices = sample_indices;
+
         Self {
-            reader: Reader::new(csi::io::Query::new(reader, chunks)),
+            reader,
             reference_sequence_name,
             interval,
             header,
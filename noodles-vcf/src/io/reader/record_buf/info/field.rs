@@ -69,14 +69,14 @@ pub(super) fn parse_field(header: &Header, s: &str) -> Result<(String, Option<Va
         if matches!(ty, Type::Flag) {
             match raw_value.unwrap_or_default() {
                 MISSING => None,
-                t => parse_value(number, ty, t)
+                t => parse_value(header.file_format(), number, ty, t)
                     .map(Some)
                     .map_err(|e| ParseError::InvalidValue(key.into(), e))?,
             }
         } else if let Some(t) = raw_value {
             match t {
                 MISSING => None,
-                _ => parse_value(number, ty, t)
+                _ => parse_value(header.file_format(), number, ty, t)
                     .map(Some)
                     .map_err(|e| ParseError::InvalidValue(key.into(), e))?,
             }
@@ -86,7 +86,7 @@ pub(super) fn parse_field(header: &Header, s: &str) -> Result<(String, Option<Va
     } else {
         match raw_value {
             Some(MISSING) => None,
-            Some(t) => parse_value(Number::Count(1), Type::String, t)
+            Some(t) => parse_value(header.file_format(), Number::Count(1), Type::String, t)
                 .map(Some)
                 .map_err(|e| ParseError::InvalidValue(key.into(), e))?,
             None => Some(Value::Flag),
@@ -1,7 +1,10 @@
 use std::{error, fmt, num, str};
 
 use crate::{
-    header::record::value::map::info::{Number, Type},
+    header::{
+        record::value::map::info::{Number, Type},
+        FileFormat,
+    },
     io::reader::record_buf::{value, MISSING},
     variant::record_buf::info::field::{value::Array, Value},
 };
@@ -52,7 +55,12 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub(super) fn parse_value(number: Number, ty: Type, s: &str) -> Result<Value, ParseError> {
+pub(super) fn parse_value(
+    file_format: FileFormat,
+    number: Number,
+    ty: Type,
+    s: &str,
+) -> Result<Value, ParseError> {
     match (number, ty) {
         (Number::Count(0), Type::Flag) => parse_flag(s),
         (Number::Count(0), _) | (_, Type::Flag) => {
@@ -61,11 +69,11 @@ pub(super) fn parse_value(number: Number, ty: Type, s: &str) -> Result<Value, Pa
         (Number::Count(1), Type::Integer) => parse_i32(s),
         (Number::Count(1), Type::Float) => parse_f32(s),
         (Number::Count(1), Type::Character) => parse_char(s),
-        (Number::Count(1), Type::String) => parse_string(s),
+        (Number::Count(1), Type::String) => parse_string(file_format, s),
         (_, Type::Integer) => parse_i32_array(s),
         (_, Type::Float) => parse_f32_array(s),
         (_, Type::Character) => parse_char_array(s),
-        (_, Type::String) => parse_string_array(s),
+        (_, Type::String) => parse_string_array(file_format, s),
     }
 }
 
@@ -135,21 +143,21 @@ fn parse_char_array(s: &str) -> Result<Value, ParseError> {
         .map(|values| Value::Array(Array::Character(values)))
 }
 
-fn parse_raw_string(s: &str) -> Result<String, ParseError> {
-    value::percent_decode(s)
+fn parse_raw_string(file_format: FileFormat, s: &str) -> Result<String, ParseError> {
+    value::percent_decode(file_format, s)
         .map(|t| t.into())
         .map_err(ParseError::InvalidString)
 }
 
-fn parse_string(s: &str) -> Result<Value, ParseError> {
-    parse_raw_string(s).map(Value::String)
+fn parse_string(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
+    parse_raw_string(file_format, s).map(Value::String)
 }
 
-fn parse_string_array(s: &str) -> Result<Value, ParseError> {
+fn parse_string_array(file_format: FileFormat, s: &str) -> Result<Value, ParseError> {
     s.split(DELIMITER)
         .map(|t| match t {
             MISSING => Ok(None),
-            _ => parse_raw_string(t).map(Some),
+            _ => parse_raw_string(file_format, t).map(Some),
         })
         .collect::<Result<_, _>>()
         .map(|values| Value::Array(Array::String(values)))
@@ -159,10 +167,12 @@ fn parse_string_array(s: &str) -> Result<Value, ParseError> {
 mod tests {
     use super::*;
 
+    const VCF_4_4: FileFormat = FileFormat::new(4, 4);
+
     #[test]
     fn test_parse_value_with_integer() {
         assert_eq!(
-            parse_value(Number::Count(0), Type::Integer, "8"),
+            parse_value(VCF_4_4, Number::Count(0), Type::Integer, "8"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Integer
@@ -170,16 +180,16 @@ mod tests {
         );
 
         assert_eq!(
-            parse_value(Number::Count(1), Type::Integer, "8"),
+            parse_value(VCF_4_4, Number::Count(1), Type::Integer, "8"),
             Ok(Value::from(8))
         );
 
         assert_eq!(
-            parse_value(Number::Count(2), Type::Integer, "8,13"),
+            parse_value(VCF_4_4, Number::Count(2), Type::Integer, "8,13"),
             Ok(Value::from(vec![Some(8), Some(13)])),
         );
         assert_eq!(
-            parse_value(Number::Count(2), Type::Integer, "8,."),
+            parse_value(VCF_4_4, Number::Count(2), Type::Integer, "8,."),
             Ok(Value::from(vec![Some(8), None])),
         );
     }
@@ -187,7 +197,7 @@ mod tests {
     #[test]
     fn test_parse_value_with_float() {
         assert_eq!(
-            parse_value(Number::Count(0), Type::Float, "0.333"),
+            parse_value(VCF_4_4, Number::Count(0), Type::Float, "0.333"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Float
@@ -195,16 +205,16 @@ mod tests {
         );
 
         assert_eq!(
-            parse_value(Number::Count(1), Type::Float, "0.333"),
+            parse_value(VCF_4_4, Number::Count(1), Type::Float, "0.333"),
             Ok(Value::from(0.333))
         );
 
         assert_eq!(
-            parse_value(Number::Count(2), Type::Float, "0.333,0.667"),
+            parse_value(VCF_4_4, Number::Count(2), Type::Float, "0.333,0.667"),
             Ok(Value::from(vec![Some(0.333), Some(0.667)]))
         );
         assert_eq!(
-            parse_value(Number::Count(2), Type::Float, "0.333,."),
+            parse_value(VCF_4_4, Number::Count(2), Type::Float, "0.333,."),
             Ok(Value::from(vec![Some(0.333), None]))
         );
     }
@@ -212,17 +222,17 @@ mod tests {
     #[test]
     fn test_parse_value_with_flag() {
         assert_eq!(
-            parse_value(Number::Count(0), Type::Flag, ""),
+            parse_value(VCF_4_4, Number::Count(0), Type::Flag, ""),
             Ok(Value::Flag)
         );
 
         assert_eq!(
-            parse_value(Number::Count(0), Type::Flag, "true"),
+            parse_value(VCF_4_4, Number::Count(0), Type::Flag, "true"),
             Err(ParseError::InvalidFlag)
         );
 
         assert_eq!(
-            parse_value(Number::Count(1), Type::Flag, ""),
+            parse_value(VCF_4_4, Number::Count(1), Type::Flag, ""),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(1),
                 Type::Flag
@@ -233,7 +243,7 @@ mod tests {
     #[test]
     fn test_parse_value_with_character() {
         assert_eq!(
-            parse_value(Number::Count(0), Type::Character, "n"),
+            parse_value(VCF_4_4, Number::Count(0), Type::Character, "n"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::Character
@@ -241,12 +251,12 @@ mod tests {
         );
 
         assert_eq!(
-            parse_value(Number::Count(1), Type::Character, "n"),
+            parse_value(VCF_4_4, Number::Count(1), Type::Character, "n"),
             Ok(Value::from('n'))
         );
 
         assert_eq!(
-            parse_value(Number::Count(2), Type::Character, "n,d,l,s"),
+            parse_value(VCF_4_4, Number::Count(2), Type::Character, "n,d,l,s"),
             Ok(Value::from(vec![
                 Some('n'),
                 Some('d'),
@@ -255,7 +265,7 @@ mod tests {
             ]))
         );
         assert_eq!(
-            parse_value(Number::Count(2), Type::Character, "n,d,l,."),
+            parse_value(VCF_4_4, Number::Count(2), Type::Character, "n,d,l,."),
             Ok(Value::from(vec![Some('n'), Some('d'), Some('l'), None]))
         );
     }
@@ -263,7 +273,7 @@ mod tests {
     #[test]
     fn test_parse_value_with_string() {
         assert_eq!(
-            parse_value(Number::Count(0), Type::String, "noodles"),
+            parse_value(VCF_4_4, Number::Count(0), Type::String, "noodles"),
             Err(ParseError::InvalidNumberForType(
                 Number::Count(0),
                 Type::String
@@ -271,27 +281,36 @@ mod tests {
         );
 
         assert_eq!(
-            parse_value(Number::Count(1), Type::String, "noodles"),
+            parse_value(VCF_4_4, Number::Count(1), Type::String, "noodles"),
             Ok(Value::from("noodles"))
         );
         assert_eq!(
-            parse_value(Number::Count(1), Type::String, "8%25"),
+            parse_value(VCF_4_4, Number::Count(1), Type::String, "8%25"),
             Ok(Value::from("8%"))
         );
+        assert_eq!(
+            parse_value(
+                FileFormat::new(4, 2),
+                Number::Count(1),
+                Type::String,
+                "8%25"
+            ),
+            Ok(Value::from("8%25"))
+        );
 
         assert_eq!(
-            parse_value(Number::Count(2), Type::String, "noodles,vcf"),
+            parse_value(VCF_4_4, Number::Count(2), Type::String, "noodles,vcf"),
             Ok(Value::from(vec![
                 Some(String::from("noodles")),
                 Some(String::from("vcf"))
             ]))
         );
         assert_eq!(
-            parse_value(Number::Count(2), Type::String, "noodles,."),
+            parse_value(VCF_4_4, Number::Count(2), Type::String, "noodles,."),
             Ok(Value::from(vec![Some(String::from("noodles")), None]))
         );
         assert_eq!(
-            parse_value(Number::Count(2), Type::String, "8%25,13%25"),
+            parse_value(VCF_4_4, Number::Count(2), Type::String, "8%25,13%25"),
             Ok(Value::from(vec![
                 Some(String::from("8%")),
                 Some(String::from("13%"))
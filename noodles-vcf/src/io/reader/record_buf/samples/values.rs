@@ -78,7 +78,7 @@ pub(super) fn parse_values(
                         .or_else(|| definition(header.file_format(), key).map(|(n, t, _)| (n, t)))
                         .unwrap_or_default();
 
-                    parse_value(number, ty, raw_value)
+                    parse_value(header.file_format(), number, ty, raw_value)
                         .map(Some)
                         .map_err(ParseError::InvalidValue)?
                 }
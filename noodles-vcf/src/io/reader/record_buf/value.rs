@@ -2,8 +2,22 @@ use std::{borrow::Cow, str};
 
 use percent_encoding::percent_decode_str;
 
-pub(crate) fn percent_decode(s: &str) -> Result<Cow<'_, str>, str::Utf8Error> {
-    percent_decode_str(s).decode_utf8()
+use crate::header::FileFormat;
+
+// § 1.2 "Character encoding, non-printable characters and characters with special meaning"
+// (2023-08-23): Percent-encoding was introduced in VCF 4.3. Values in earlier versions are read
+// verbatim.
+pub(crate) fn percent_decode(
+    file_format: FileFormat,
+    s: &str,
+) -> Result<Cow<'_, str>, str::Utf8Error> {
+    const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+    if file_format < VCF_4_3 {
+        Ok(Cow::Borrowed(s))
+    } else {
+        percent_decode_str(s).decode_utf8()
+    }
 }
 
 #[cfg(test)]
@@ -12,8 +26,13 @@ mod tests {
 
     #[test]
     fn test_percent_decode() -> Result<(), str::Utf8Error> {
-        assert_eq!(percent_decode("noodles")?, "noodles");
-        assert_eq!(percent_decode("noodles%3Dvcf")?, "noodles=vcf");
+        const VCF_4_2: FileFormat = FileFormat::new(4, 2);
+        const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+        assert_eq!(percent_decode(VCF_4_3, "noodles")?, "noodles");
+        assert_eq!(percent_decode(VCF_4_3, "noodles%3Dvcf")?, "noodles=vcf");
+        assert_eq!(percent_decode(VCF_4_2, "noodles%3Dvcf")?, "noodles%3Dvcf");
+
         Ok(())
     }
 }
@@ -0,0 +1,203 @@
+use std::io;
+
+use crate::{header::SampleNames, Header};
+
+/// A selection of samples to restrict reading or writing to.
+///
+/// Selected samples are kept in the order given.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SampleSelection {
+    /// Samples selected by name.
+    Names(Vec<String>),
+    /// Samples selected by index.
+    Indices(Vec<usize>),
+}
+
+impl SampleSelection {
+    /// Resolves the selection to sample indices using the given sample names.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if a sample name does not exist or a sample index is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::SampleSelection};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .add_sample_name("sample2")
+    ///     .build();
+    ///
+    /// let selection = SampleSelection::Names(vec![String::from("sample2"), String::from("sample0")]);
+    /// assert_eq!(selection.resolve(header.sample_names())?, [2, 0]);
+    ///
+    /// let selection = SampleSelection::Indices(vec![1]);
+    /// assert_eq!(selection.resolve(header.sample_names())?, [1]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn resolve(&self, sample_names: &SampleNames) -> io::Result<Vec<usize>> {
+        match self {
+            Self::Names(names) => names
+                .iter()
+                .map(|name| {
+                    sample_names.get_index_of(name).ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("sample does not exist: {name}"),
+                        )
+                    })
+                })
+                .collect(),
+            Self::Indices(indices) => indices
+                .iter()
+                .map(|&i| {
+                    if i < sample_names.len() {
+                        Ok(i)
+                    } else {
+                        Err(io::Error::new(
+                            io::ErrorKind::InvalidInput,
+                            format!("sample index out of range: {i}"),
+                        ))
+                    }
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns a copy of the given header with only the selected sample names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::SampleSelection};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_sample_name("sample0")
+    ///     .add_sample_name("sample1")
+    ///     .build();
+    ///
+    /// let selection = SampleSelection::Indices(vec![1]);
+    /// let actual = selection.subset_header(&header)?;
+    ///
+    /// let expected = vcf::Header::builder().add_sample_name("sample1").build();
+    /// assert_eq!(actual, expected);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn subset_header(&self, header: &Header) -> io::Result<Header> {
+        let indices = self.resolve(header.sample_names())?;
+
+        let mut subset_header = header.clone();
+        *subset_header.sample_names_mut() = select_sample_names(header.sample_names(), &indices);
+
+        Ok(subset_header)
+    }
+}
+
+pub(crate) fn select_sample_names(sample_names: &SampleNames, indices: &[usize]) -> SampleNames {
+    indices
+        .iter()
+        .filter_map(|&i| sample_names.get_index(i))
+        .cloned()
+        .collect()
+}
+
+// Rewrites a raw samples field (`FORMAT\tsample0\tsample1...`) starting at `start` in `buf` to only
+// include the selected sample columns. If no samples are selected, the field is removed.
+//
+// Sample values are not parsed.
+pub(crate) fn select_sample_columns(
+    buf: &mut String,
+    start: usize,
+    indices: &[usize],
+    scratch: &mut String,
+) -> io::Result<()> {
+    const DELIMITER: char = '\t';
+
+    if buf.len() <= start {
+        return Ok(());
+    }
+
+    scratch.clear();
+
+    if !indices.is_empty() {
+        let mut fields = buf[start..].split(DELIMITER);
+
+        if let Some(keys) = fields.next() {
+            scratch.push_str(keys);
+        }
+
+        let columns: Vec<_> = fields.collect();
+
+        for &i in indices {
+            let column = columns.get(i).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, "missing sample column")
+            })?;
+
+            scratch.push(DELIMITER);
+            scratch.push_str(column);
+        }
+    }
+
+    buf.truncate(start);
+    buf.push_str(scratch);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() -> io::Result<()> {
+        let sample_names: SampleNames = [String::from("sample0"), String::from("sample1")]
+            .into_iter()
+            .collect();
+
+        let selection = SampleSelection::Names(vec![String::from("sample1")]);
+        assert_eq!(selection.resolve(&sample_names)?, [1]);
+
+        let selection = SampleSelection::Names(vec![String::from("sample2")]);
+        assert!(matches!(
+            selection.resolve(&sample_names),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let selection = SampleSelection::Indices(vec![1, 0]);
+        assert_eq!(selection.resolve(&sample_names)?, [1, 0]);
+
+        let selection = SampleSelection::Indices(vec![2]);
+        assert!(matches!(
+            selection.resolve(&sample_names),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_sample_columns() -> io::Result<()> {
+        fn t(src: &str, indices: &[usize]) -> io::Result<String> {
+            let mut buf = format!("INFO{src}");
+            select_sample_columns(&mut buf, 4, indices, &mut String::new())?;
+            Ok(buf)
+        }
+
+        assert_eq!(
+            t("GT:GQ\t0|0:13\t0/1:8\t1/1:5", &[2, 0])?,
+            "INFOGT:GQ\t1/1:5\t0|0:13"
+        );
+        assert_eq!(t("GT:GQ\t0|0:13", &[])?, "INFO");
+        assert_eq!(t("", &[])?, "INFO");
+
+        assert!(matches!(
+            t("GT:GQ\t0|0:13", &[1]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
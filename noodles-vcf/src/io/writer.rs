@@ -2,13 +2,15 @@
 
 mod builder;
 mod header;
+mod options;
 mod record;
 
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::{header::write_header, record::write_record};
-use crate::{Header, Record};
+use self::{header::write_header, options::Options, record::write_record};
+use super::{sample_selection::select_sample_names, SampleSelection};
+use crate::{header::FileFormat, Header, Record};
 
 /// A VCF writer.
 ///
@@ -55,6 +57,12 @@ use crate::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    file_format: Option<FileFormat>,
+    float_precision: Option<usize>,
+    trim_trailing_missing_sample_fields: bool,
+    info_fields_in_header_order: bool,
+    sample_selection: Option<SampleSelection>,
+    sample_indices: Option<Vec<usize>>,
 }
 
 impl<W> Writer<W>
@@ -70,7 +78,15 @@ where
     /// let writer = vcf::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            file_format: None,
+            float_precision: None,
+            trim_trailing_missing_sample_fields: false,
+            info_fields_in_header_order: false,
+            sample_selection: None,
+            sample_indices: None,
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -127,7 +143,26 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write_header(&mut self.inner, header)
+        if self.sample_selection.is_none() && self.file_format.is_none() {
+            return write_header(&mut self.inner, header);
+        }
+
+        let mut output_header = header.clone();
+
+        if let Some(file_format) = self.file_format {
+            *output_header.file_format_mut() = file_format;
+        }
+
+        if let Some(sample_selection) = &self.sample_selection {
+            let indices = sample_selection.resolve(header.sample_names())?;
+
+            *output_header.sample_names_mut() =
+                select_sample_names(header.sample_names(), &indices);
+
+            self.sample_indices = Some(indices);
+        }
+
+        write_header(&mut self.inner, &output_header)
     }
 
     /// Writes a VCF record.
@@ -148,7 +183,24 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        let options = self.options(header);
+
+        write_record(
+            &mut self.inner,
+            header,
+            &options,
+            self.sample_indices.as_deref(),
+            record,
+        )
+    }
+
+    fn options(&self, header: &Header) -> Options {
+        Options {
+            file_format: self.file_format.unwrap_or(header.file_format()),
+            float_precision: self.float_precision,
+            trim_trailing_missing_sample_fields: self.trim_trailing_missing_sample_fields,
+            info_fields_in_header_order: self.info_fields_in_header_order,
+        }
     }
 }
 
@@ -165,7 +217,15 @@ where
         header: &Header,
         record: &dyn crate::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        let options = self.options(header);
+
+        write_record(
+            &mut self.inner,
+            header,
+            &options,
+            self.sample_indices.as_deref(),
+            record,
+        )
     }
 }
 
@@ -232,4 +292,175 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_with_sample_selection() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{
+            record::samples::keys::key,
+            record_buf::{samples::sample::Value, Samples},
+        };
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let samples = Samples::new(
+            [String::from(key::GENOTYPE)].into_iter().collect(),
+            vec![
+                vec![Some(Value::String(String::from("0|0")))],
+                vec![Some(Value::String(String::from("0|1")))],
+                vec![Some(Value::String(String::from("1|1")))],
+            ],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_samples(samples)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.sample_selection = Some(SampleSelection::Indices(vec![2, 1]));
+
+        writer.write_header(&header)?;
+        writer.get_mut().clear();
+        writer.write_variant_record(&header, &record)?;
+
+        let expected = b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t1|1\t0|1\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut writer = Writer::new(Vec::new());
+        writer.sample_selection = Some(SampleSelection::Indices(Vec::new()));
+
+        writer.write_header(&header)?;
+        assert!(writer
+            .get_ref()
+            .ends_with(b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n"));
+
+        writer.get_mut().clear();
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_file_format() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::record_buf::{info::field::Value, Info};
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .build();
+
+        let info: Info = [(String::from("NOTE"), Some(Value::from("50%")))]
+            .into_iter()
+            .collect();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(info)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\tNOTE=50%25\n");
+
+        let mut writer = Writer::new(Vec::new());
+        writer.file_format = Some(FileFormat::new(4, 2));
+
+        writer.write_header(&header)?;
+        assert!(writer.get_ref().starts_with(b"##fileformat=VCFv4.2\n"));
+
+        writer.get_mut().clear();
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\tNOTE=50%\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_formatting_options() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::Info as InfoMap, Map},
+            variant::{
+                record::{info::field::key as info_key, samples::keys::key},
+                record_buf::{
+                    info::field::Value as InfoValue, samples::sample::Value, Info, Samples,
+                },
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<InfoMap>::from(info_key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<InfoMap>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let info: Info = [
+            (
+                String::from(info_key::TOTAL_DEPTH),
+                Some(InfoValue::from(13)),
+            ),
+            (
+                String::from(info_key::ALLELE_FREQUENCIES),
+                Some(InfoValue::from(1.0 / 3.0)),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let samples = Samples::new(
+            [
+                String::from(key::GENOTYPE),
+                String::from(key::READ_DEPTH),
+                String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            ]
+            .into_iter()
+            .collect(),
+            vec![
+                vec![Some(Value::from("0/1")), None, None],
+                vec![None, Some(Value::from(8)), None],
+            ],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_quality_score(13.5)
+            .set_info(info)
+            .set_samples(samples)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t1\t.\tA\t.\t13.5\t.\tDP=13;AF=0.33333334\tGT:DP:GQ\t0/1:.:.\t.:8:.\n"
+        );
+
+        let mut writer = Writer::new(Vec::new());
+        writer.float_precision = Some(0);
+        writer.trim_trailing_missing_sample_fields = true;
+        writer.info_fields_in_header_order = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t1\t.\tA\t.\t14\t.\tAF=0;DP=13\tGT:DP:GQ\t0/1\t.:8\n"
+        );
+
+        Ok(())
+    }
 }
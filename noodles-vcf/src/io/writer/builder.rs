@@ -7,12 +7,20 @@ use std::{
 use noodles_bgzf as bgzf;
 
 use super::Writer;
-use crate::io::CompressionMethod;
+use crate::{
+    header::FileFormat,
+    io::{CompressionMethod, SampleSelection},
+};
 
 /// A BAM writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    file_format: Option<FileFormat>,
+    float_precision: Option<usize>,
+    trim_trailing_missing_sample_fields: bool,
+    info_fields_in_header_order: bool,
+    sample_selection: Option<SampleSelection>,
 }
 
 impl Builder {
@@ -29,6 +37,95 @@ impl Builder {
         self
     }
 
+    /// Sets the output file format version.
+    ///
+    /// By default, the file format of the header is used. When set, the header is written with
+    /// this file format, and records are written using the rules of this version, e.g., string
+    /// values are only percent-encoded in VCF 4.3 and later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{header::FileFormat, io::writer::Builder};
+    /// let builder = Builder::default().set_file_format(FileFormat::new(4, 2));
+    /// ```
+    pub fn set_file_format(mut self, file_format: FileFormat) -> Self {
+        self.file_format = Some(file_format);
+        self
+    }
+
+    /// Sets the number of decimal places of floating-point values.
+    ///
+    /// This applies to QUAL and to INFO and FORMAT float values. Values are rounded to the given
+    /// number of decimal places, and trailing zeros are trimmed, e.g., with 3 decimal places,
+    /// 0.33333 is written as `0.333` and 0.5 as `0.5`. By default, values are written with the
+    /// shortest representation that round-trips.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_float_precision(3);
+    /// ```
+    pub fn set_float_precision(mut self, float_precision: usize) -> Self {
+        self.float_precision = Some(float_precision);
+        self
+    }
+
+    /// Sets whether trailing missing FORMAT fields of each sample are trimmed.
+    ///
+    /// When enabled, a sample `0/1:.:.` is written as `0/1`. The first field is always written.
+    /// This is disabled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_trim_trailing_missing_sample_fields(true);
+    /// ```
+    pub fn set_trim_trailing_missing_sample_fields(
+        mut self,
+        trim_trailing_missing_sample_fields: bool,
+    ) -> Self {
+        self.trim_trailing_missing_sample_fields = trim_trailing_missing_sample_fields;
+        self
+    }
+
+    /// Sets whether INFO fields are written in header order.
+    ///
+    /// When enabled, INFO fields are written in the order of the header INFO records. Fields
+    /// that are not defined in the header are written last, in record order. By default, fields
+    /// are written in record order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_info_fields_in_header_order(true);
+    /// ```
+    pub fn set_info_fields_in_header_order(mut self, info_fields_in_header_order: bool) -> Self {
+        self.info_fields_in_header_order = info_fields_in_header_order;
+        self
+    }
+
+    /// Restricts writing to a selection of samples.
+    ///
+    /// The header written by [`Writer::write_header`] only includes the selected sample names, and
+    /// only the selected samples of each record are written. Records are still written using the
+    /// full header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::{writer::Builder, SampleSelection};
+    /// let builder = Builder::default()
+    ///     .set_sample_selection(SampleSelection::Names(vec![String::from("sample0")]));
+    /// ```
+    pub fn set_sample_selection(mut self, sample_selection: SampleSelection) -> Self {
+        self.sample_selection = Some(sample_selection);
+        self
+    }
+
     /// Builds a VCF writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -77,6 +174,13 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::new(inner)
+        let mut writer = Writer::new(inner);
+        writer.file_format = self.file_format;
+        writer.float_precision = self.float_precision;
+        writer.trim_trailing_missing_sample_fields = self.trim_trailing_missing_sample_fields;
+        writer.info_fields_in_header_order = self.info_fields_in_header_order;
+        writer.sample_selection = self.sample_selection;
+
+        writer
     }
 }
@@ -4,7 +4,7 @@ use std::io::{self, Write};
 
 use self::record::{
     write_alternative_allele, write_contig, write_file_format, write_filter, write_format,
-    write_info, write_other,
+    write_info, write_meta, write_other, write_pedigree, write_sample,
 };
 use crate::{header::SampleNames, Header};
 
@@ -25,7 +25,7 @@ where
     }
 
     for (id, format) in header.formats() {
-        write_format(writer, id, format)?;
+        write_format(writer, file_format, id, format)?;
     }
 
     for (id, alternative_allele) in header.alternative_alleles() {
@@ -36,6 +36,18 @@ where
         write_contig(writer, id, contig)?;
     }
 
+    for (id, meta) in header.metas() {
+        write_meta(writer, id, meta)?;
+    }
+
+    for (id, sample) in header.samples() {
+        write_sample(writer, id, sample)?;
+    }
+
+    for (id, pedigree) in header.pedigrees() {
+        write_pedigree(writer, file_format, id, pedigree)?;
+    }
+
     for (key, collection) in header.other_records() {
         write_other(writer, file_format, key, collection)?;
     }
@@ -9,7 +9,9 @@ use crate::header::{
     record::{
         self,
         value::{
-            map::{AlternativeAllele, Contig, Filter, Format, Info},
+            map::{
+                format, AlternativeAllele, Contig, Filter, Format, Info, Meta, Pedigree, Sample,
+            },
             Collection, Map,
         },
     },
@@ -66,15 +68,46 @@ where
     })
 }
 
-pub(super) fn write_format<W>(writer: &mut W, id: &str, format: &Map<Format>) -> io::Result<()>
+pub(super) fn write_format<W>(
+    writer: &mut W,
+    file_format: FileFormat,
+    id: &str,
+    format: &Map<Format>,
+) -> io::Result<()>
 where
     W: Write,
 {
+    if !is_valid_format_number(file_format, format.number()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid format number for VCFv{}.{}: {id}",
+                file_format.major(),
+                file_format.minor()
+            ),
+        ));
+    }
+
     write_record(writer, &record::key::FORMAT, |w| {
         value::write_map(w, id, |x| value::map::write_format(x, format))
     })
 }
 
+// `P` was introduced in VCF 4.4, and `LA`, `LR`, `LG`, and `M`, in VCF 4.5.
+fn is_valid_format_number(file_format: FileFormat, number: format::Number) -> bool {
+    const VCF_4_4: FileFormat = FileFormat::new(4, 4);
+    const VCF_4_5: FileFormat = FileFormat::new(4, 5);
+
+    match number {
+        format::Number::Ploidy => file_format >= VCF_4_4,
+        format::Number::LocalAlternateBases
+        | format::Number::LocalReferenceAlternateBases
+        | format::Number::LocalSamples
+        | format::Number::BaseModifications => file_format >= VCF_4_5,
+        _ => true,
+    }
+}
+
 pub(super) fn write_alternative_allele<W>(
     writer: &mut W,
     id: &str,
@@ -99,6 +132,54 @@ where
     })
 }
 
+pub(super) fn write_meta<W>(writer: &mut W, id: &str, meta: &Map<Meta>) -> io::Result<()>
+where
+    W: Write,
+{
+    write_record(writer, &record::key::META, |w| {
+        value::write_map(w, id, |x| value::map::write_meta(x, meta))
+    })
+}
+
+pub(super) fn write_sample<W>(writer: &mut W, id: &str, sample: &Map<Sample>) -> io::Result<()>
+where
+    W: Write,
+{
+    write_record(writer, &record::key::SAMPLE, |w| {
+        value::write_map(w, id, |x| value::map::write_sample(x, sample))
+    })
+}
+
+pub(super) fn write_pedigree<W>(
+    writer: &mut W,
+    file_format: FileFormat,
+    id: &str,
+    pedigree: &Map<Pedigree>,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    use crate::header::record::value::map::{pedigree::tag, tag::ID};
+
+    const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+    // Prior to VCF 4.3, the ID of a pedigree record is given by either the `Child` or `Derived`
+    // field.
+    let id_tag = if file_format >= VCF_4_3 {
+        ID
+    } else if pedigree.father().is_some() || pedigree.mother().is_some() {
+        tag::CHILD
+    } else if pedigree.original().is_some() {
+        tag::DERIVED
+    } else {
+        ID
+    };
+
+    write_record(writer, &record::key::PEDIGREE, |w| {
+        value::write_other_map(w, id_tag, id, |x| value::map::write_pedigree(x, pedigree))
+    })
+}
+
 pub(super) fn write_other<W>(
     writer: &mut W,
     file_format: FileFormat,
@@ -108,8 +189,6 @@ pub(super) fn write_other<W>(
 where
     W: Write,
 {
-    const META: &str = "META";
-
     match collection {
         Collection::Unstructured(vs) => {
             for v in vs {
@@ -119,13 +198,7 @@ where
         Collection::Structured(maps) => {
             for (id, map) in maps {
                 write_record(writer, key, |w| {
-                    value::write_other_map(w, map.id_tag(), id, |x| {
-                        if key.as_ref() == META {
-                            value::map::write_meta(x, map)
-                        } else {
-                            value::map::write_other(x, map)
-                        }
-                    })
+                    value::write_map(w, id, |x| value::map::write_other(x, map))
                 })?;
             }
         }
@@ -146,6 +219,69 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_write_format() -> io::Result<()> {
+        use crate::header::record::value::map::format::Type;
+
+        let mut buf = Vec::new();
+
+        let map = Map::<Format>::new(format::Number::Ploidy, Type::String, "Phase set list");
+
+        buf.clear();
+        write_format(&mut buf, FileFormat::new(4, 4), "PSL", &map)?;
+        assert_eq!(
+            buf,
+            br#"##FORMAT=<ID=PSL,Number=P,Type=String,Description="Phase set list">
+"#
+        );
+
+        buf.clear();
+        assert!(matches!(
+            write_format(&mut buf, FileFormat::new(4, 3), "PSL", &map),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let map = Map::<Format>::new(
+            format::Number::LocalAlternateBases,
+            Type::Integer,
+            "Local allele counts",
+        );
+
+        buf.clear();
+        assert!(matches!(
+            write_format(&mut buf, FileFormat::new(4, 4), "LAC", &map),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_pedigree() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+
+        let map = Map::<Pedigree>::builder()
+            .set_father("fid")
+            .set_mother("mid")
+            .build()?;
+
+        buf.clear();
+        write_pedigree(&mut buf, FileFormat::new(4, 3), "cid", &map)?;
+        assert_eq!(buf, b"##PEDIGREE=<ID=cid,Father=fid,Mother=mid>\n");
+
+        buf.clear();
+        write_pedigree(&mut buf, FileFormat::new(4, 2), "cid", &map)?;
+        assert_eq!(buf, b"##PEDIGREE=<Child=cid,Father=fid,Mother=mid>\n");
+
+        let map = Map::<Pedigree>::builder().set_original("oid").build()?;
+
+        buf.clear();
+        write_pedigree(&mut buf, FileFormat::new(4, 2), "did", &map)?;
+        assert_eq!(buf, b"##PEDIGREE=<Derived=did,Original=oid>\n");
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_other() -> Result<(), Box<dyn std::error::Error>> {
         let mut buf = Vec::new();
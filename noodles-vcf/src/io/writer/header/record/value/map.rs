@@ -5,15 +5,18 @@ mod format;
 mod info;
 mod meta;
 mod other;
+mod pedigree;
+mod sample;
 
 use std::io::{self, Write};
 
 pub(crate) use self::{
     alternative_allele::write_alternative_allele, contig::write_contig, filter::write_filter,
     format::write_format, info::write_info, meta::write_meta, other::write_other,
+    pedigree::write_pedigree, sample::write_sample,
 };
 use crate::{
-    header::record::value::map::{self, tag, OtherFields},
+    header::record::value::map::{tag, OtherFields},
     io::writer::header::record::write_separator,
 };
 
@@ -30,14 +33,10 @@ where
     Ok(())
 }
 
-pub(crate) fn write_other_map<W, I, F>(
-    writer: &mut W,
-    id_tag: &map::other::Tag,
-    id: I,
-    f: F,
-) -> io::Result<()>
+pub(crate) fn write_other_map<W, K, I, F>(writer: &mut W, id_tag: K, id: I, f: F) -> io::Result<()>
 where
     W: Write,
+    K: AsRef<str>,
     I: AsRef<str>,
     F: Fn(&mut W) -> io::Result<()>,
 {
@@ -85,6 +84,22 @@ where
     Ok(())
 }
 
+// The IDX field is set in headers read from BCF and in BCF headers with explicit dictionary
+// indices.
+fn write_idx_field<W>(writer: &mut W, idx: Option<usize>) -> io::Result<()>
+where
+    W: Write,
+{
+    const IDX: &str = "IDX";
+
+    if let Some(i) = idx {
+        write_delimiter(writer)?;
+        write_value_field(writer, IDX, i.to_string())?;
+    }
+
+    Ok(())
+}
+
 fn write_value_field<W, K, V>(writer: &mut W, key: K, value: V) -> io::Result<()>
 where
     W: Write,
@@ -1,6 +1,6 @@
 use std::io::{self, Write};
 
-use super::{write_delimiter, write_other_fields, write_value_field};
+use super::{write_delimiter, write_idx_field, write_other_fields, write_value_field};
 use crate::header::record::value::{
     map::{contig::tag, Contig},
     Map,
@@ -26,6 +26,7 @@ where
     }
 
     write_other_fields(writer, contig.other_fields())?;
+    write_idx_field(writer, contig.idx())?;
 
     Ok(())
 }
@@ -56,6 +57,11 @@ mod tests {
             br#",length=8,md5=d7eba311421bbc9d3ada44709dd61534,URL=https://example.com/reference.fa,noodles="vcf""#
         );
 
+        buf.clear();
+        let map = Map::<Contig>::builder().set_length(8).set_idx(2).build()?;
+        write_contig(&mut buf, &map)?;
+        assert_eq!(buf, b",length=8,IDX=2");
+
         Ok(())
     }
 }
@@ -1,28 +1,41 @@
 use std::io::{self, Write};
 
-use super::{write_delimiter, write_string_field, write_value_field};
-use crate::header::record::value::{map::Other, Map};
+use super::{write_delimiter, write_other_fields, write_value_field};
+use crate::header::record::value::{
+    map::{meta::tag, Meta},
+    Map,
+};
 
-pub(crate) fn write_meta<W>(writer: &mut W, meta: &Map<Other>) -> io::Result<()>
+pub(crate) fn write_meta<W>(writer: &mut W, meta: &Map<Meta>) -> io::Result<()>
 where
     W: Write,
 {
-    const NUMBER: &str = "Number";
-    const TYPE: &str = "Type";
-    const VALUES: &str = "Values";
+    write_delimiter(writer)?;
+    write_value_field(writer, tag::TYPE, meta.ty())?;
 
-    for (key, value) in meta.other_fields() {
-        write_delimiter(writer)?;
+    write_delimiter(writer)?;
+    write_value_field(writer, tag::NUMBER, meta.number())?;
 
-        match key.as_ref() {
-            NUMBER | TYPE | VALUES => write_value_field(writer, key, value)?,
-            _ => write_string_field(writer, key, value)?,
-        }
-    }
+    write_delimiter(writer)?;
+    write_values_field(writer, meta.values())?;
+
+    write_other_fields(writer, meta.other_fields())?;
 
     Ok(())
 }
 
+fn write_values_field<W>(writer: &mut W, values: &[String]) -> io::Result<()>
+where
+    W: Write,
+{
+    const PREFIX: char = '[';
+    const SUFFIX: char = ']';
+    const DELIMITER: &str = ", ";
+
+    let s = format!("{PREFIX}{}{SUFFIX}", values.join(DELIMITER));
+    write_value_field(writer, tag::VALUES, s)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -32,10 +45,13 @@ mod tests {
         let mut buf = Vec::new();
 
         buf.clear();
-        let map = Map::<Other>::builder()
-            .insert("Type".parse()?, "String")
-            .insert("Number".parse()?, ".")
-            .insert("Values".parse()?, "[WholeGenome, Exome]")
+        let map = Map::<Meta>::new(Vec::new());
+        write_meta(&mut buf, &map)?;
+        assert_eq!(buf, b",Type=String,Number=.,Values=[]");
+
+        buf.clear();
+        let map = Map::<Meta>::builder()
+            .set_values(vec![String::from("WholeGenome"), String::from("Exome")])
             .insert("noodles".parse()?, "vcf")
             .build()?;
         write_meta(&mut buf, &map)?;
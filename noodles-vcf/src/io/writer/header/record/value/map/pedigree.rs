@@ -0,0 +1,61 @@
+use std::io::{self, Write};
+
+use super::{write_delimiter, write_other_fields, write_value_field};
+use crate::header::record::value::{
+    map::{pedigree::tag, Pedigree},
+    Map,
+};
+
+pub(crate) fn write_pedigree<W>(writer: &mut W, pedigree: &Map<Pedigree>) -> io::Result<()>
+where
+    W: Write,
+{
+    if let Some(father) = pedigree.father() {
+        write_delimiter(writer)?;
+        write_value_field(writer, tag::FATHER, father)?;
+    }
+
+    if let Some(mother) = pedigree.mother() {
+        write_delimiter(writer)?;
+        write_value_field(writer, tag::MOTHER, mother)?;
+    }
+
+    if let Some(original) = pedigree.original() {
+        write_delimiter(writer)?;
+        write_value_field(writer, tag::ORIGINAL, original)?;
+    }
+
+    write_other_fields(writer, pedigree.other_fields())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_pedigree() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+
+        buf.clear();
+        let map = Map::<Pedigree>::new();
+        write_pedigree(&mut buf, &map)?;
+        assert!(buf.is_empty());
+
+        buf.clear();
+        let map = Map::<Pedigree>::builder()
+            .set_father("fid")
+            .set_mother("mid")
+            .build()?;
+        write_pedigree(&mut buf, &map)?;
+        assert_eq!(buf, b",Father=fid,Mother=mid");
+
+        buf.clear();
+        let map = Map::<Pedigree>::builder().set_original("oid").build()?;
+        write_pedigree(&mut buf, &map)?;
+        assert_eq!(buf, b",Original=oid");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use std::io::{self, Write};
+
+use super::write_other_fields;
+use crate::header::record::value::{map::Sample, Map};
+
+pub(crate) fn write_sample<W>(writer: &mut W, sample: &Map<Sample>) -> io::Result<()>
+where
+    W: Write,
+{
+    write_other_fields(writer, sample.other_fields())
+}
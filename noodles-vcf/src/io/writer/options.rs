@@ -0,0 +1,21 @@
+use crate::header::FileFormat;
+
+/// Record formatting options.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Options {
+    pub(super) file_format: FileFormat,
+    pub(super) float_precision: Option<usize>,
+    pub(super) trim_trailing_missing_sample_fields: bool,
+    pub(super) info_fields_in_header_order: bool,
+}
+
+impl From<FileFormat> for Options {
+    fn from(file_format: FileFormat) -> Self {
+        Self {
+            file_format,
+            float_precision: None,
+            trim_trailing_missing_sample_fields: false,
+            info_fields_in_header_order: false,
+        }
+    }
+}
@@ -17,11 +17,18 @@ use self::{
     reference_bases::write_reference_bases, reference_sequence_name::write_reference_sequence_name,
     samples::write_samples,
 };
+use super::options::Options;
 use crate::{variant::Record, Header};
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+pub(super) fn write_record<W, R>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    sample_indices: Option<&[usize]>,
+    record: &R,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -47,19 +54,24 @@ where
 
     writer.write_all(DELIMITER)?;
     let quality_score = record.quality_score().transpose()?;
-    write_quality_score(writer, quality_score)?;
+    write_quality_score(writer, options.float_precision, quality_score)?;
 
     writer.write_all(DELIMITER)?;
     write_filters(writer, header, record.filters())?;
 
     writer.write_all(DELIMITER)?;
-    write_info(writer, header, record.info())?;
+    write_info(writer, header, options, record.info())?;
 
     let samples = record.samples()?;
 
-    if !samples.is_empty() {
+    let has_samples = match sample_indices {
+        Some(indices) => !indices.is_empty() && !samples.is_empty(),
+        None => !samples.is_empty(),
+    };
+
+    if has_samples {
         writer.write_all(DELIMITER)?;
-        write_samples(writer, header, samples)?;
+        write_samples(writer, header, options, sample_indices, samples)?;
     }
 
     writer.write_all(b"\n")?;
@@ -84,7 +96,35 @@ mod tests {
 
         let header = Header::default();
         let mut buf = Vec::new();
-        write_record(&mut buf, &header, &record)?;
+        write_record(
+            &mut buf,
+            &header,
+            &Options::from(header.file_format()),
+            None,
+            &record,
+        )?;
+        assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_sample_indices_and_no_samples() -> io::Result<()> {
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        let header = Header::builder().add_sample_name("sample0").build();
+        let mut buf = Vec::new();
+        write_record(
+            &mut buf,
+            &header,
+            &Options::from(header.file_format()),
+            Some(&[0]),
+            &record,
+        )?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
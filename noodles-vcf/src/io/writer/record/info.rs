@@ -4,9 +4,14 @@ use std::io::{self, Write};
 
 use self::field::write_field;
 use super::MISSING;
-use crate::{variant::record::Info, Header};
-
-pub(super) fn write_info<W, I>(writer: &mut W, header: &Header, info: I) -> io::Result<()>
+use crate::{io::writer::options::Options, variant::record::Info, Header};
+
+pub(super) fn write_info<W, I>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    info: I,
+) -> io::Result<()>
 where
     W: Write,
     I: Info,
@@ -15,6 +20,20 @@ where
 
     if info.is_empty() {
         writer.write_all(MISSING)?;
+    } else if options.info_fields_in_header_order {
+        let mut fields: Vec<_> = info.iter(header).collect::<io::Result<_>>()?;
+
+        // Fields with keys that are not defined in the header are written last, in their
+        // original order.
+        fields.sort_by_key(|(key, _)| header.infos().get_index_of(*key).unwrap_or(usize::MAX));
+
+        for (i, (key, value)) in fields.into_iter().enumerate() {
+            if i > 0 {
+                writer.write_all(DELIMITER)?;
+            }
+
+            write_field(writer, options, key, value.as_ref())?;
+        }
     } else {
         for (i, result) in info.iter(header).enumerate() {
             let (key, value) = result?;
@@ -23,7 +42,7 @@ where
                 writer.write_all(DELIMITER)?;
             }
 
-            write_field(writer, key, value.as_ref())?;
+            write_field(writer, options, key, value.as_ref())?;
         }
     }
 
@@ -48,7 +67,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_info(buf, header, info)?;
+            write_info(buf, header, &Options::from(header.file_format()), info)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -81,4 +100,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_info_with_info_fields_in_header_order() -> io::Result<()> {
+        use crate::{
+            header::record::value::{map::Info as InfoMap, Map},
+            variant::{
+                record::info::field::key,
+                record_buf::{info::field::Value as ValueBuf, Info as InfoBuf},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(key::IS_IN_DB_SNP, Map::<InfoMap>::from(key::IS_IN_DB_SNP))
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<InfoMap>::from(key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .build();
+
+        let info: InfoBuf = [
+            (String::from("NOTE"), Some(ValueBuf::from("ndls"))),
+            (
+                String::from(key::SAMPLES_WITH_DATA_COUNT),
+                Some(ValueBuf::from(2)),
+            ),
+            (String::from(key::IS_IN_DB_SNP), Some(ValueBuf::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut options = Options::from(header.file_format());
+        options.info_fields_in_header_order = true;
+
+        let mut buf = Vec::new();
+        write_info(&mut buf, &header, &options, &info)?;
+        assert_eq!(buf, b"DB;NS=2;NOTE=ndls");
+
+        Ok(())
+    }
 }
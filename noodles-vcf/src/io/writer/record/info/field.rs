@@ -4,9 +4,17 @@ mod value;
 use std::io::{self, Write};
 
 use self::{key::write_key, value::write_value};
-use crate::{io::writer::record::MISSING, variant::record::info::field::Value};
+use crate::{
+    io::writer::{options::Options, record::MISSING},
+    variant::record::info::field::Value,
+};
 
-pub(super) fn write_field<W>(writer: &mut W, key: &str, value: Option<&Value>) -> io::Result<()>
+pub(super) fn write_field<W>(
+    writer: &mut W,
+    options: &Options,
+    key: &str,
+    value: Option<&Value>,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -18,7 +26,7 @@ where
         Some(Value::Flag) => {}
         Some(v) => {
             writer.write_all(SEPARATOR)?;
-            write_value(writer, v)?;
+            write_value(writer, options, v)?;
         }
         None => {
             writer.write_all(SEPARATOR)?;
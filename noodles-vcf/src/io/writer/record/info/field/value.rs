@@ -4,18 +4,21 @@ mod string;
 use std::io::{self, Write};
 
 use self::{array::write_array, string::write_string};
-use crate::variant::record::info::field::Value;
+use crate::{
+    io::writer::{options::Options, record::value::write_float},
+    variant::record::info::field::Value,
+};
 
-pub(super) fn write_value<W>(writer: &mut W, value: &Value) -> io::Result<()>
+pub(super) fn write_value<W>(writer: &mut W, options: &Options, value: &Value) -> io::Result<()>
 where
     W: Write,
 {
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, options.float_precision, *n),
         Value::Flag => Ok(()),
         Value::Character(c) => write!(writer, "{c}"),
-        Value::String(s) => write_string(writer, s),
-        Value::Array(array) => write_array(writer, array),
+        Value::String(s) => write_string(writer, options.file_format, s),
+        Value::Array(array) => write_array(writer, options, array),
     }
 }
@@ -1,8 +1,17 @@
 use std::io::{self, Write};
 
-use crate::{io::writer::record::MISSING, variant::record::info::field::value::Array};
+use crate::{
+    io::writer::{
+        options::Options,
+        record::{
+            value::{percent_encode, write_float},
+            MISSING,
+        },
+    },
+    variant::record::info::field::value::Array,
+};
 
-pub(super) fn write_array<W>(writer: &mut W, array: &Array) -> io::Result<()>
+pub(super) fn write_array<W>(writer: &mut W, options: &Options, array: &Array) -> io::Result<()>
 where
     W: Write,
 {
@@ -31,7 +40,7 @@ where
                 }
 
                 if let Some(n) = result? {
-                    write!(writer, "{n}")?;
+                    write_float(writer, options.float_precision, n)?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -61,7 +70,7 @@ where
                 }
 
                 if let Some(s) = result? {
-                    writer.write_all(s.as_bytes())?;
+                    writer.write_all(percent_encode(options.file_format, &s).as_bytes())?;
                 } else {
                     writer.write_all(MISSING)?;
                 }
@@ -1,34 +1,36 @@
 use std::io::{self, Write};
 
-use crate::io::writer::record::value::percent_encode;
+use crate::{header::FileFormat, io::writer::record::value::percent_encode};
 
-pub(super) fn write_string<W>(writer: &mut W, s: &str) -> io::Result<()>
+pub(super) fn write_string<W>(writer: &mut W, file_format: FileFormat, s: &str) -> io::Result<()>
 where
     W: Write,
 {
-    for t in percent_encode(s) {
-        writer.write_all(t.as_bytes())?;
-    }
-
-    Ok(())
+    writer.write_all(percent_encode(file_format, s).as_bytes())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const VCF_4_4: FileFormat = FileFormat::new(4, 4);
+
     #[test]
     fn test_write_string() -> io::Result<()> {
         let mut buf = Vec::new();
 
         buf.clear();
-        write_string(&mut buf, "ndls")?;
+        write_string(&mut buf, VCF_4_4, "ndls")?;
         assert_eq!(buf, b"ndls");
 
         buf.clear();
-        write_string(&mut buf, "noodles=vcf;")?;
+        write_string(&mut buf, VCF_4_4, "noodles=vcf;")?;
         assert_eq!(buf, b"noodles%3Dvcf%3B");
 
+        buf.clear();
+        write_string(&mut buf, FileFormat::new(4, 2), "noodles%vcf")?;
+        assert_eq!(buf, b"noodles%vcf");
+
         Ok(())
     }
 }
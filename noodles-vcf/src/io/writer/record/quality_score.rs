@@ -1,13 +1,17 @@
 use std::io::{self, Write};
 
-use super::MISSING;
+use super::{value::write_float, MISSING};
 
-pub(super) fn write_quality_score<W>(writer: &mut W, quality_score: Option<f32>) -> io::Result<()>
+pub(super) fn write_quality_score<W>(
+    writer: &mut W,
+    float_precision: Option<usize>,
+    quality_score: Option<f32>,
+) -> io::Result<()>
 where
     W: Write,
 {
     if let Some(n) = quality_score {
-        write!(writer, "{n}")?;
+        write_float(writer, float_precision, n)?;
     } else {
         writer.write_all(MISSING)?;
     }
@@ -23,7 +27,7 @@ mod tests {
     fn test_write_quality_score() -> io::Result<()> {
         fn t(buf: &mut Vec<u8>, quality_score: Option<f32>, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_quality_score(buf, quality_score)?;
+            write_quality_score(buf, None, quality_score)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -4,9 +4,15 @@ mod sample;
 use std::io::{self, Write};
 
 use self::{keys::write_keys, sample::write_sample};
-use crate::{variant::record::Samples, Header};
+use crate::{io::writer::options::Options, variant::record::Samples, Header};
 
-pub(super) fn write_samples<W, S>(writer: &mut W, header: &Header, samples: S) -> io::Result<()>
+pub(super) fn write_samples<W, S>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    sample_indices: Option<&[usize]>,
+    samples: S,
+) -> io::Result<()>
 where
     W: Write,
     S: Samples,
@@ -15,9 +21,22 @@ where
 
     write_keys(writer, samples.column_names(header))?;
 
-    for sample in samples.iter() {
-        writer.write_all(DELIMITER)?;
-        write_sample(writer, header, sample)?;
+    if let Some(indices) = sample_indices {
+        let samples: Vec<_> = samples.iter().collect();
+
+        for &i in indices {
+            let sample = samples
+                .get(i)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing sample"))?;
+
+            writer.write_all(DELIMITER)?;
+            write_sample(writer, header, options, sample.as_ref())?;
+        }
+    } else {
+        for sample in samples.iter() {
+            writer.write_all(DELIMITER)?;
+            write_sample(writer, header, options, &sample)?;
+        }
     }
 
     Ok(())
@@ -39,7 +58,13 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_samples(buf, header, genotypes)?;
+            write_samples(
+                buf,
+                header,
+                &Options::from(header.file_format()),
+                None,
+                genotypes,
+            )?;
             assert_eq!(buf, expected);
             Ok(())
         }
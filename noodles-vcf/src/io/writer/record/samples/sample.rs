@@ -3,24 +3,68 @@ mod value;
 use std::io::{self, Write};
 
 use self::value::write_value;
-use crate::{io::writer::record::MISSING, variant::record::samples::Sample, Header};
+use crate::{
+    io::writer::{options::Options, record::MISSING},
+    variant::record::samples::{series::Value, Sample},
+    Header,
+};
 
-pub(super) fn write_sample<W, S>(writer: &mut W, header: &Header, sample: S) -> io::Result<()>
+pub(super) fn write_sample<W, S>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    sample: &S,
+) -> io::Result<()>
 where
     W: Write,
-    S: Sample,
+    S: Sample + ?Sized,
+{
+    let values = sample
+        .iter(header)
+        .map(|result| result.map(|(_, value)| value));
+
+    if options.trim_trailing_missing_sample_fields {
+        let values: Vec<_> = values.collect::<io::Result<_>>()?;
+
+        // The first field is always written, even if it is missing.
+        let len = values
+            .iter()
+            .rposition(Option::is_some)
+            .map(|i| i + 1)
+            .unwrap_or(1);
+
+        write_values(
+            writer,
+            header,
+            options,
+            values.into_iter().take(len).map(Ok),
+        )
+    } else {
+        write_values(writer, header, options, values)
+    }
+}
+
+fn write_values<'a, W, I>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    values: I,
+) -> io::Result<()>
+where
+    W: Write,
+    I: Iterator<Item = io::Result<Option<Value<'a>>>>,
 {
     const DELIMITER: &[u8] = b":";
 
-    for (i, result) in sample.iter(header).enumerate() {
-        let (_, value) = result?;
+    for (i, result) in values.enumerate() {
+        let value = result?;
 
         if i > 0 {
             writer.write_all(DELIMITER)?;
         }
 
         match value {
-            Some(v) => write_value(writer, header, &v)?,
+            Some(v) => write_value(writer, header, options, &v)?,
             None => writer.write_all(MISSING)?,
         }
     }
@@ -5,24 +5,35 @@ mod string;
 use std::io::{self, Write};
 
 use self::{array::write_array, genotype::write_genotype, string::write_string};
-use crate::{variant::record::samples::series::Value, Header};
-
-pub(super) fn write_value<W>(writer: &mut W, header: &Header, value: &Value) -> io::Result<()>
+use crate::{
+    io::writer::{options::Options, record::value::write_float},
+    variant::record::samples::series::Value,
+    Header,
+};
+
+pub(super) fn write_value<W>(
+    writer: &mut W,
+    header: &Header,
+    options: &Options,
+    value: &Value,
+) -> io::Result<()>
 where
     W: Write,
 {
     match value {
         Value::Integer(n) => write!(writer, "{n}"),
-        Value::Float(n) => write!(writer, "{n}"),
+        Value::Float(n) => write_float(writer, options.float_precision, *n),
         Value::Character(c) => write!(writer, "{c}"),
-        Value::String(s) => write_string(writer, s),
+        Value::String(s) => write_string(writer, options.file_format, s),
         Value::Genotype(genotype) => write_genotype(writer, header, genotype.as_ref()),
-        Value::Array(array) => write_array(writer, array),
+        Value::Array(array) => write_array(writer, options, array),
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use super::*;
     use crate::variant::record::samples::series::value::Array;
 
@@ -43,21 +54,21 @@ mod tests {
             }
         }
 
-        impl<'a> crate::variant::record::samples::series::value::array::Values<'a, &'a str>
+        impl<'a> crate::variant::record::samples::series::value::array::Values<'a, Cow<'a, str>>
             for Values<'a, String>
         {
             fn len(&self) -> usize {
                 self.0.len()
             }
 
-            fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<&'a str>>> + '_> {
-                Box::new(self.0.iter().map(|s| Ok(s.as_deref())))
+            fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<Cow<'a, str>>>> + '_> {
+                Box::new(self.0.iter().map(|s| Ok(s.as_deref().map(Cow::from))))
             }
         }
 
         fn t(buf: &mut Vec<u8>, header: &Header, value: &Value, expected: &[u8]) -> io::Result<()> {
             buf.clear();
-            write_value(buf, header, value)?;
+            write_value(buf, header, &Options::from(header.file_format()), value)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -68,7 +79,12 @@ mod tests {
         t(&mut buf, &header, &Value::Integer(8), b"8")?;
         t(&mut buf, &header, &Value::Float(0.333), b"0.333")?;
         t(&mut buf, &header, &Value::Character('n'), b"n")?;
-        t(&mut buf, &header, &Value::String("noodles"), b"noodles")?;
+        t(
+            &mut buf,
+            &header,
+            &Value::String(Cow::from("noodles")),
+            b"noodles",
+        )?;
 
         t(
             &mut buf,
@@ -153,6 +169,15 @@ mod tests {
             ])))),
             b"noodles,.",
         )?;
+        t(
+            &mut buf,
+            &header,
+            &Value::Array(Array::String(Box::new(Values(&[
+                Some(String::from("noodles,vcf")),
+                Some(String::from("n;d%s")),
+            ])))),
+            b"noodles%2Cvcf,n%3Bd%25s",
+        )?;
 
         Ok(())
     }
@@ -1,4 +1,11 @@
-use percent_encoding::{utf8_percent_encode, AsciiSet, PercentEncode, CONTROLS};
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+use crate::header::FileFormat;
 
 // § 1.2 "Character encoding, non-printable characters and characters with special meaning" (2023-08-23)
 const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
@@ -11,6 +18,73 @@ const PERCENT_ENCODE_SET: &AsciiSet = &CONTROLS
     .add(b'\n')
     .add(b'\t');
 
-pub(super) fn percent_encode(s: &str) -> PercentEncode<'_> {
-    utf8_percent_encode(s, PERCENT_ENCODE_SET)
+// Percent-encoding was introduced in VCF 4.3. Values in earlier versions are written verbatim.
+pub(super) fn percent_encode(file_format: FileFormat, s: &str) -> Cow<'_, str> {
+    const VCF_4_3: FileFormat = FileFormat::new(4, 3);
+
+    if file_format < VCF_4_3 {
+        Cow::Borrowed(s)
+    } else {
+        utf8_percent_encode(s, PERCENT_ENCODE_SET).into()
+    }
+}
+
+// With a precision, floats are rounded to the given number of decimal places, and trailing zeros
+// are trimmed, e.g., 0.5 (not 0.500) and 30 (not 30.000) for 3 decimal places.
+pub(super) fn write_float<W>(
+    writer: &mut W,
+    float_precision: Option<usize>,
+    n: f32,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    match float_precision {
+        Some(precision) if n.is_finite() => {
+            let s = format!("{n:.precision$}");
+
+            let t = if s.contains('.') {
+                s.trim_end_matches('0').trim_end_matches('.')
+            } else {
+                &s
+            };
+
+            let t = if t == "-0" { "0" } else { t };
+
+            writer.write_all(t.as_bytes())
+        }
+        _ => write!(writer, "{n}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_float() -> io::Result<()> {
+        fn t(
+            buf: &mut Vec<u8>,
+            float_precision: Option<usize>,
+            n: f32,
+            expected: &[u8],
+        ) -> io::Result<()> {
+            buf.clear();
+            write_float(buf, float_precision, n)?;
+            assert_eq!(buf, expected);
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+
+        t(&mut buf, None, 0.333_333, b"0.333333")?;
+        t(&mut buf, Some(3), 0.333_333, b"0.333")?;
+        t(&mut buf, Some(3), 0.5, b"0.5")?;
+        t(&mut buf, Some(3), 30.0, b"30")?;
+        t(&mut buf, Some(0), 29.6, b"30")?;
+        t(&mut buf, Some(2), -0.001, b"0")?;
+        t(&mut buf, Some(2), f32::NAN, b"NaN")?;
+
+        Ok(())
+    }
 }
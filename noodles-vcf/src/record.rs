@@ -6,6 +6,7 @@ mod filters;
 mod ids;
 mod info;
 pub mod samples;
+mod value;
 
 use std::{fmt, io};
 
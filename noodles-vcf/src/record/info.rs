@@ -128,4 +128,17 @@ mod tests {
 
         assert!(matches!(iter.next(), Some(Ok((key::TOTAL_DEPTH, None)))));
     }
+
+    #[test]
+    fn test_get_with_percent_encoded_string() {
+        use crate::variant::record::info::field::key;
+
+        let header = Header::default();
+        let info = Info::new("AA=n%3Bd%25s");
+
+        assert!(matches!(
+            info.get(&header, key::ANCESTRAL_ALLELE),
+            Some(Ok(Some(Value::String(s)))) if s == "n;d%s"
+        ));
+    }
 }
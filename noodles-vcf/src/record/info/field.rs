@@ -48,7 +48,7 @@ pub(super) fn parse_field<'a>(
 
     let value = match raw_value {
         Some(MISSING) => None,
-        Some(t) => parse_value(t, number, ty).map(Some)?,
+        Some(t) => parse_value(t, header.file_format(), number, ty).map(Some)?,
         None if ty == Type::Flag => Some(Value::Flag),
         None => return Err(io::Error::new(io::ErrorKind::InvalidData, "missing value")),
     };
@@ -1,11 +1,20 @@
 use std::io;
 
 use crate::{
-    header::record::value::map::info::{Number, Type},
+    header::{
+        record::value::map::info::{Number, Type},
+        FileFormat,
+    },
+    record::value::{decode_string, Strings},
     variant::record::info::field::{value::Array, Value},
 };
 
-pub(super) fn parse_value(src: &str, number: Number, ty: Type) -> io::Result<Value<'_>> {
+pub(super) fn parse_value(
+    src: &str,
+    file_format: FileFormat,
+    number: Number,
+    ty: Type,
+) -> io::Result<Value<'_>> {
     match (number, ty) {
         (Number::Count(0), Type::Flag) => parse_flag_value(src),
         (Number::Count(0), _) | (_, Type::Flag) => Err(io::Error::new(
@@ -15,11 +24,11 @@ pub(super) fn parse_value(src: &str, number: Number, ty: Type) -> io::Result<Val
         (Number::Count(1), Type::Integer) => parse_integer_value(src),
         (Number::Count(1), Type::Float) => parse_float_value(src),
         (Number::Count(1), Type::Character) => parse_character_value(src),
-        (Number::Count(1), Type::String) => parse_string_value(src),
+        (Number::Count(1), Type::String) => parse_string_value(src, file_format),
         (_, Type::Integer) => parse_integer_array_value(src),
         (_, Type::Float) => parse_float_array_value(src),
         (_, Type::Character) => parse_character_array_value(src),
-        (_, Type::String) => parse_string_array_value(src),
+        (_, Type::String) => parse_string_array_value(src, file_format),
     }
 }
 
@@ -58,8 +67,8 @@ fn parse_character_value(src: &str) -> io::Result<Value<'_>> {
     ))
 }
 
-fn parse_string_value(src: &str) -> io::Result<Value<'_>> {
-    Ok(Value::String(src))
+fn parse_string_value(src: &str, file_format: FileFormat) -> io::Result<Value<'_>> {
+    decode_string(file_format, src).map(Value::String)
 }
 
 fn parse_integer_array_value(src: &str) -> io::Result<Value<'_>> {
@@ -74,6 +83,9 @@ fn parse_character_array_value(src: &str) -> io::Result<Value<'_>> {
     Ok(Value::Array(Array::Character(Box::new(src))))
 }
 
-fn parse_string_array_value(src: &str) -> io::Result<Value<'_>> {
-    Ok(Value::Array(Array::String(Box::new(src))))
+fn parse_string_array_value(src: &str, file_format: FileFormat) -> io::Result<Value<'_>> {
+    Ok(Value::Array(Array::String(Box::new(Strings::new(
+        src,
+        file_format,
+    )))))
 }
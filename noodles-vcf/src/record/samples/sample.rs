@@ -2,6 +2,8 @@ use std::{io, iter};
 
 use super::Keys;
 use crate::{
+    header::FileFormat,
+    record::value::{decode_string, Strings},
     variant::record::samples::series::{value::Array, Value},
     Header,
 };
@@ -131,11 +133,11 @@ fn parse_value<'a>(src: &'a str, header: &Header, key: &str) -> io::Result<Optio
         (Number::Count(1), Type::Integer) => parse_integer_value(src)?,
         (Number::Count(1), Type::Float) => parse_float_value(src)?,
         (Number::Count(1), Type::Character) => parse_character_value(src)?,
-        (Number::Count(1), Type::String) => parse_string_value(src)?,
+        (Number::Count(1), Type::String) => parse_string_value(src, header.file_format())?,
         (_, Type::Integer) => parse_integer_array_value(src)?,
         (_, Type::Float) => parse_float_array_value(src)?,
         (_, Type::Character) => parse_character_array_value(src)?,
-        (_, Type::String) => parse_string_array_value(src)?,
+        (_, Type::String) => parse_string_array_value(src, header.file_format())?,
     };
 
     Ok(Some(value))
@@ -168,8 +170,8 @@ fn parse_character_value(src: &str) -> io::Result<Value<'_>> {
     ))
 }
 
-fn parse_string_value(src: &str) -> io::Result<Value<'_>> {
-    Ok(Value::String(src))
+fn parse_string_value(src: &str, file_format: FileFormat) -> io::Result<Value<'_>> {
+    decode_string(file_format, src).map(Value::String)
 }
 
 fn parse_genotype_value(src: &str) -> io::Result<Value<'_>> {
@@ -190,8 +192,11 @@ fn parse_character_array_value(src: &str) -> io::Result<Value<'_>> {
     Ok(Value::Array(Array::Character(Box::new(src))))
 }
 
-fn parse_string_array_value(src: &str) -> io::Result<Value<'_>> {
-    Ok(Value::Array(Array::String(Box::new(src))))
+fn parse_string_array_value(src: &str, file_format: FileFormat) -> io::Result<Value<'_>> {
+    Ok(Value::Array(Array::String(Box::new(Strings::new(
+        src,
+        file_format,
+    )))))
 }
 
 #[cfg(test)]
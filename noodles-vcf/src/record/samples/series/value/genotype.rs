@@ -147,4 +147,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_genotype_for_genotype_buf() -> io::Result<()> {
+        use crate::variant::record_buf::samples::sample::value::Genotype as GenotypeBuf;
+
+        fn t(src: &str) -> io::Result<()> {
+            let genotype = Genotype::new(src);
+            let genotype_buf = GenotypeBuf::try_from(
+                &genotype as &dyn crate::variant::record::samples::series::value::Genotype,
+            )?;
+            assert_eq!(genotype_buf.to_string(), src);
+            Ok(())
+        }
+
+        t("0")?;
+        t("0|1")?;
+        t("0/1")?;
+        t("./.")?;
+        t("|0/1")?;
+
+        let genotype = Genotype::new("1/.");
+        let genotype_buf = GenotypeBuf::try_from(
+            &genotype as &dyn crate::variant::record::samples::series::value::Genotype,
+        )?;
+        assert_eq!(genotype_buf.ploidy(), 2);
+        assert!(!genotype_buf.is_phased());
+        assert!(!genotype_buf.is_missing());
+        assert!(genotype_buf.as_ref()[1].is_missing());
+
+        Ok(())
+    }
 }
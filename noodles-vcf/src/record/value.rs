@@ -0,0 +1,84 @@
+use std::{borrow::Cow, io};
+
+use crate::{
+    header::FileFormat,
+    io::reader::record_buf::value::percent_decode,
+    variant::record::{info, samples},
+};
+
+const DELIMITER: char = ',';
+const MISSING: &str = ".";
+
+pub(super) fn decode_string(file_format: FileFormat, src: &str) -> io::Result<Cow<'_, str>> {
+    percent_decode(file_format, src).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+// A raw string array value that is percent-decoded per value on iteration.
+pub(super) struct Strings<'a> {
+    src: &'a str,
+    file_format: FileFormat,
+}
+
+impl<'a> Strings<'a> {
+    pub(super) fn new(src: &'a str, file_format: FileFormat) -> Self {
+        Self { src, file_format }
+    }
+
+    fn len(&self) -> usize {
+        if self.src.is_empty() {
+            0
+        } else {
+            self.src.matches(DELIMITER).count() + 1
+        }
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<Cow<'a, str>>>> + '_> {
+        let (src, file_format) = (self.src, self.file_format);
+
+        Box::new(src.split(DELIMITER).map(move |s| match s {
+            MISSING => Ok(None),
+            _ => decode_string(file_format, s).map(Some),
+        }))
+    }
+}
+
+impl<'a> info::field::value::array::Values<'a, Cow<'a, str>> for Strings<'a> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<Cow<'a, str>>>> + '_> {
+        self.iter()
+    }
+}
+
+impl<'a> samples::series::value::array::Values<'a, Cow<'a, str>> for Strings<'a> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<Cow<'a, str>>>> + '_> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strings() -> io::Result<()> {
+        let strings = Strings::new("a%3Bb,.,c", FileFormat::new(4, 3));
+        assert_eq!(strings.len(), 3);
+
+        let actual: Vec<_> = strings.iter().collect::<io::Result<_>>()?;
+        let expected = [Some(Cow::from("a;b")), None, Some(Cow::from("c"))];
+        assert_eq!(actual, expected);
+
+        let strings = Strings::new("a%3Bb", FileFormat::new(4, 2));
+        let actual: Vec<_> = strings.iter().collect::<io::Result<_>>()?;
+        assert_eq!(actual, [Some(Cow::from("a%3Bb"))]);
+
+        Ok(())
+    }
+}
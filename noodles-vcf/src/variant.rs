@@ -1,7 +1,13 @@
 //! Variant format.
 
+pub mod filter;
+pub mod gvcf;
 pub mod io;
+pub mod merge;
+pub mod order;
 pub mod record;
 pub mod record_buf;
+pub mod stats;
+pub mod validator;
 
 pub use self::{record::Record, record_buf::RecordBuf};
@@ -0,0 +1,631 @@
+//! Variant record filter expressions.
+//!
+//! A filter expression is a predicate over variant records, similar to the expressions given to
+//! `bcftools view -i/-e`, e.g., `QUAL>30 && INFO/DP>10 && GT[0]="het"`.
+//!
+//! # Syntax
+//!
+//! An expression is a combination of comparisons joined by `&&` (or `&`), `||` (or `|`), and
+//! negated by `!`. Parentheses can be used for grouping. The comparison operators are `==` (or
+//! `=`), `!=`, `<`, `<=`, `>`, and `>=`. An operand is a number, a string literal in double or
+//! single quotes, or a field. A field without a comparison is true if it has any nonmissing value.
+//!
+//! The following fields are supported:
+//!
+//!   * `CHROM`, `POS`, `ID`, `REF`, `ALT`, `QUAL`, and `FILTER`;
+//!   * `INFO/<key>`, an INFO field;
+//!   * `FMT/<key>` or `FORMAT/<key>`, a FORMAT field, optionally followed by a sample index, e.g.,
+//!     `FMT/DP[1]`;
+//!   * `GT`, the genotype, optionally followed by a sample index; and
+//!   * `<key>`, an INFO field or, if there is no INFO definition with the key, a FORMAT field.
+//!
+//! INFO and FORMAT keys must be defined in the header.
+//!
+//! # Semantics
+//!
+//! A field can have multiple values, e.g., an array value, multiple alternate bases, or a FORMAT
+//! field over all samples. A comparison is true if it is true for any pair of values. Missing
+//! values never compare true. A missing `ID`, `ALT`, or `FILTER` field is compared as `.`. A
+//! present flag has the value `1`.
+//!
+//! A genotype compares equal to the string `hom` (homozygous), `het` (heterozygous), `ref`
+//! (homozygous reference), `alt` (has an alternate allele), `hap` (haploid), or `mis` (missing).
+//! Otherwise, it is compared to its text representation, e.g., `0/1`.
+
+mod parser;
+
+use std::{cmp::Ordering, io};
+
+pub use self::parser::ParseError;
+use self::parser::{parse_expression, Expression, Field, Operand, Operator};
+use super::record::{
+    info::field::{value::Array as InfoArray, Value as InfoValue},
+    samples::{
+        series::{value::Array as SampleArray, value::Genotype, Value as SampleValue},
+        Sample,
+    },
+    Record,
+};
+use crate::Header;
+
+/// A variant record filter.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::{filter::Filter, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::default();
+/// let filter = Filter::new(&header, "QUAL >= 30 && POS < 100")?;
+///
+/// let record = RecordBuf::builder()
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_quality_score(34.0)
+///     .build();
+///
+/// assert!(filter.matches(&header, &record)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+    expression: Expression,
+}
+
+impl Filter {
+    /// Parses a filter expression.
+    ///
+    /// INFO and FORMAT fields are resolved against the given header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::filter::{Filter, ParseError}};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// assert!(Filter::new(&header, "QUAL > 30").is_ok());
+    ///
+    /// assert_eq!(
+    ///     Filter::new(&header, "INFO/DP > 10"),
+    ///     Err(ParseError::UndefinedField(String::from("INFO/DP")))
+    /// );
+    /// ```
+    pub fn new(header: &Header, s: &str) -> Result<Self, ParseError> {
+        parse_expression(header, s).map(|expression| Self { expression })
+    }
+
+    /// Returns whether the given record matches the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{filter::Filter, RecordBuf},
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    /// let filter = Filter::new(&header, r#"FILTER = "PASS""#)?;
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_filters([String::from("PASS")].into_iter().collect())
+    ///     .build();
+    ///
+    /// assert!(filter.matches(&header, &record)?);
+    ///
+    /// let record = RecordBuf::default();
+    /// assert!(!filter.matches(&header, &record)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn matches(&self, header: &Header, record: &dyn Record) -> io::Result<bool> {
+        evaluate(header, record, &self.expression)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Value {
+    Number(f64),
+    String(String),
+    Genotype(Vec<Option<usize>>, String),
+}
+
+fn evaluate(header: &Header, record: &dyn Record, expression: &Expression) -> io::Result<bool> {
+    match expression {
+        Expression::Or(lhs, rhs) => {
+            Ok(evaluate(header, record, lhs)? || evaluate(header, record, rhs)?)
+        }
+        Expression::And(lhs, rhs) => {
+            Ok(evaluate(header, record, lhs)? && evaluate(header, record, rhs)?)
+        }
+        Expression::Not(expression) => evaluate(header, record, expression).map(|b| !b),
+        Expression::Compare(lhs, operator, rhs) => {
+            let lhs = resolve_operand(header, record, lhs)?;
+            let rhs = resolve_operand(header, record, rhs)?;
+
+            let is_match = lhs.iter().flatten().any(|a| {
+                rhs.iter()
+                    .flatten()
+                    .any(|b| compare_values(a, *operator, b))
+            });
+
+            Ok(is_match)
+        }
+        Expression::Test(operand) => match operand {
+            Operand::Number(n) => Ok(*n != 0.0),
+            Operand::String(s) => Ok(!s.is_empty()),
+            Operand::Field(_) => resolve_operand(header, record, operand)
+                .map(|values| values.iter().any(Option::is_some)),
+        },
+    }
+}
+
+fn compare_values(a: &Value, operator: Operator, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Genotype(alleles, _), Value::String(s)) => {
+            compare_genotype(alleles, a, operator, s)
+        }
+        (Value::String(s), Value::Genotype(alleles, _)) => {
+            compare_genotype(alleles, b, reverse(operator), s)
+        }
+        _ => match (as_number(a), as_number(b)) {
+            (Some(m), Some(n)) if is_number(a) || is_number(b) => m
+                .partial_cmp(&n)
+                .map(|ordering| is_match(operator, ordering))
+                .unwrap_or(false),
+            _ if is_number(a) || is_number(b) => false,
+            _ => is_match(operator, as_str(a).cmp(as_str(b))),
+        },
+    }
+}
+
+fn compare_genotype(
+    alleles: &[Option<usize>],
+    genotype: &Value,
+    operator: Operator,
+    s: &str,
+) -> bool {
+    if let Some(is_class) = genotype_class(alleles, s) {
+        match operator {
+            Operator::Eq => return is_class,
+            Operator::Ne => return !is_class,
+            _ => {}
+        }
+    }
+
+    is_match(operator, as_str(genotype).cmp(s))
+}
+
+fn genotype_class(alleles: &[Option<usize>], s: &str) -> Option<bool> {
+    let is_complete = !alleles.is_empty() && alleles.iter().all(Option::is_some);
+    let is_uniform = alleles.windows(2).all(|w| w[0] == w[1]);
+
+    let is_class = match s {
+        "hom" => is_complete && alleles.len() > 1 && is_uniform,
+        "het" => is_complete && !is_uniform,
+        "ref" => is_complete && alleles.iter().all(|a| *a == Some(0)),
+        "alt" => alleles.iter().flatten().any(|&a| a > 0),
+        "hap" => is_complete && alleles.len() == 1,
+        "mis" => alleles.iter().all(Option::is_none),
+        _ => return None,
+    };
+
+    Some(is_class)
+}
+
+fn is_match(operator: Operator, ordering: Ordering) -> bool {
+    match operator {
+        Operator::Eq => ordering.is_eq(),
+        Operator::Ne => ordering.is_ne(),
+        Operator::Lt => ordering.is_lt(),
+        Operator::Le => ordering.is_le(),
+        Operator::Gt => ordering.is_gt(),
+        Operator::Ge => ordering.is_ge(),
+    }
+}
+
+fn reverse(operator: Operator) -> Operator {
+    match operator {
+        Operator::Lt => Operator::Gt,
+        Operator::Le => Operator::Ge,
+        Operator::Gt => Operator::Lt,
+        Operator::Ge => Operator::Le,
+        _ => operator,
+    }
+}
+
+fn is_number(value: &Value) -> bool {
+    matches!(value, Value::Number(_))
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(n) => Some(*n),
+        Value::String(s) => s.parse().ok(),
+        Value::Genotype(..) => None,
+    }
+}
+
+fn as_str(value: &Value) -> &str {
+    match value {
+        Value::Number(_) => "",
+        Value::String(s) | Value::Genotype(_, s) => s,
+    }
+}
+
+fn resolve_operand(
+    header: &Header,
+    record: &dyn Record,
+    operand: &Operand,
+) -> io::Result<Vec<Option<Value>>> {
+    match operand {
+        Operand::Number(n) => Ok(vec![Some(Value::Number(*n))]),
+        Operand::String(s) => Ok(vec![Some(Value::String(s.clone()))]),
+        Operand::Field(field) => resolve_field(header, record, field),
+    }
+}
+
+fn resolve_field(
+    header: &Header,
+    record: &dyn Record,
+    field: &Field,
+) -> io::Result<Vec<Option<Value>>> {
+    const MISSING: &str = ".";
+
+    fn or_missing(mut values: Vec<Option<Value>>) -> Vec<Option<Value>> {
+        if values.is_empty() {
+            values.push(Some(Value::String(MISSING.into())));
+        }
+
+        values
+    }
+
+    match field {
+        Field::ReferenceSequenceName => {
+            let name = record.reference_sequence_name(header)?;
+            Ok(vec![Some(Value::String(name.into()))])
+        }
+        Field::VariantStart => {
+            let position = record.variant_start().transpose()?;
+            Ok(vec![position.map(|p| Value::Number(usize::from(p) as f64))])
+        }
+        Field::Ids => {
+            let values = record
+                .ids()
+                .iter()
+                .map(|id| Some(Value::String(id.into())))
+                .collect();
+
+            Ok(or_missing(values))
+        }
+        Field::ReferenceBases => {
+            let bases = record
+                .reference_bases()
+                .iter()
+                .map(|result| result.map(char::from))
+                .collect::<io::Result<String>>()?;
+
+            Ok(vec![Some(Value::String(bases))])
+        }
+        Field::AlternateBases => {
+            let values = record
+                .alternate_bases()
+                .iter()
+                .map(|result| result.map(|allele| Some(Value::String(allele.into()))))
+                .collect::<io::Result<_>>()?;
+
+            Ok(or_missing(values))
+        }
+        Field::QualityScore => {
+            let quality_score = record.quality_score().transpose()?;
+            Ok(vec![quality_score.map(|n| Value::Number(f64::from(n)))])
+        }
+        Field::Filters => {
+            let values = record
+                .filters()
+                .iter(header)
+                .map(|result| result.map(|id| Some(Value::String(id.into()))))
+                .collect::<io::Result<_>>()?;
+
+            Ok(or_missing(values))
+        }
+        Field::Info(key) => {
+            let mut values = Vec::new();
+
+            if let Some(value) = record.info().get(header, key).transpose()? {
+                push_info_value(&mut values, value)?;
+            }
+
+            Ok(values)
+        }
+        Field::Format(key, sample_index) => {
+            let samples = record.samples()?;
+            let mut values = Vec::new();
+
+            for (i, sample) in samples.iter().enumerate() {
+                if sample_index.map(|j| i == j).unwrap_or(true) {
+                    push_sample_value(header, &mut values, sample.as_ref(), key)?;
+                }
+            }
+
+            Ok(values)
+        }
+    }
+}
+
+fn push_info_value(
+    values: &mut Vec<Option<Value>>,
+    value: Option<InfoValue<'_>>,
+) -> io::Result<()> {
+    match value {
+        None => values.push(None),
+        Some(InfoValue::Integer(n)) => values.push(Some(Value::Number(f64::from(n)))),
+        Some(InfoValue::Float(n)) => values.push(Some(Value::Number(f64::from(n)))),
+        Some(InfoValue::Flag) => values.push(Some(Value::Number(1.0))),
+        Some(InfoValue::Character(c)) => values.push(Some(Value::String(c.into()))),
+        Some(InfoValue::String(s)) => values.push(Some(Value::String(s.into()))),
+        Some(InfoValue::Array(array)) => match array {
+            InfoArray::Integer(vs) => {
+                push_values(values, vs.iter(), |n| Value::Number(f64::from(n)))?
+            }
+            InfoArray::Float(vs) => {
+                push_values(values, vs.iter(), |n| Value::Number(f64::from(n)))?
+            }
+            InfoArray::Character(vs) => {
+                push_values(values, vs.iter(), |c| Value::String(c.into()))?
+            }
+            InfoArray::String(vs) => push_values(values, vs.iter(), |s| Value::String(s.into()))?,
+        },
+    }
+
+    Ok(())
+}
+
+fn push_sample_value(
+    header: &Header,
+    values: &mut Vec<Option<Value>>,
+    sample: &dyn Sample,
+    key: &str,
+) -> io::Result<()> {
+    let value = match sample.get(header, key).transpose()? {
+        Some(value) => value,
+        None => return Ok(()),
+    };
+
+    match value {
+        None => values.push(None),
+        Some(SampleValue::Integer(n)) => values.push(Some(Value::Number(f64::from(n)))),
+        Some(SampleValue::Float(n)) => values.push(Some(Value::Number(f64::from(n)))),
+        Some(SampleValue::Character(c)) => values.push(Some(Value::String(c.into()))),
+        Some(SampleValue::String(s)) => values.push(Some(Value::String(s.into()))),
+        Some(SampleValue::Genotype(genotype)) => {
+            values.push(Some(resolve_genotype(genotype.as_ref())?));
+        }
+        Some(SampleValue::Array(array)) => match array {
+            SampleArray::Integer(vs) => {
+                push_values(values, vs.iter(), |n| Value::Number(f64::from(n)))?
+            }
+            SampleArray::Float(vs) => {
+                push_values(values, vs.iter(), |n| Value::Number(f64::from(n)))?
+            }
+            SampleArray::Character(vs) => {
+                push_values(values, vs.iter(), |c| Value::String(c.into()))?
+            }
+            SampleArray::String(vs) => push_values(values, vs.iter(), |s| Value::String(s.into()))?,
+        },
+    }
+
+    Ok(())
+}
+
+fn push_values<'a, T, F>(
+    values: &mut Vec<Option<Value>>,
+    iter: Box<dyn Iterator<Item = io::Result<Option<T>>> + 'a>,
+    f: F,
+) -> io::Result<()>
+where
+    F: Fn(T) -> Value,
+{
+    for result in iter {
+        values.push(result?.map(&f));
+    }
+
+    Ok(())
+}
+
+fn resolve_genotype(genotype: &dyn Genotype) -> io::Result<Value> {
+    use std::fmt::Write;
+
+    use super::record::samples::series::value::genotype::Phasing;
+
+    let mut alleles = Vec::new();
+    let mut s = String::new();
+
+    for (i, result) in genotype.iter().enumerate() {
+        let (position, phasing) = result?;
+
+        if i > 0 {
+            s.push(match phasing {
+                Phasing::Phased => '|',
+                Phasing::Unphased => '/',
+            });
+        }
+
+        match position {
+            Some(n) => write!(s, "{n}").expect("write to string failed"),
+            None => s.push('.'),
+        }
+
+        alleles.push(position);
+    }
+
+    Ok(Value::Genotype(alleles, s))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        header::record::value::{
+            map::{Format, Info},
+            Map,
+        },
+        variant::{
+            record::{info::field::key as info_key, samples::keys::key},
+            record_buf::{
+                info::field::{value::Array as ArrayBuf, Value as InfoValueBuf},
+                samples::sample::{value::Genotype as GenotypeBuf, Value as SampleValueBuf},
+                AlternateBases, Samples,
+            },
+            RecordBuf,
+        },
+    };
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(info_key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                info_key::IS_IN_DB_SNP,
+                Map::<Info>::from(info_key::IS_IN_DB_SNP),
+            )
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_format(key::READ_DEPTH, Map::<Format>::from(key::READ_DEPTH))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build()
+    }
+
+    #[test]
+    fn test_matches() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let info = [
+            (
+                String::from(info_key::TOTAL_DEPTH),
+                Some(InfoValueBuf::Integer(13)),
+            ),
+            (
+                String::from(info_key::ALLELE_FREQUENCIES),
+                Some(InfoValueBuf::Array(ArrayBuf::Float(vec![
+                    Some(0.25),
+                    None,
+                    Some(0.75),
+                ]))),
+            ),
+            (
+                String::from(info_key::IS_IN_DB_SNP),
+                Some(InfoValueBuf::Flag),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let samples = Samples::new(
+            [String::from(key::GENOTYPE), String::from(key::READ_DEPTH)]
+                .into_iter()
+                .collect(),
+            vec![
+                vec![
+                    Some(SampleValueBuf::Genotype("0/1".parse::<GenotypeBuf>()?)),
+                    Some(SampleValueBuf::Integer(8)),
+                ],
+                vec![
+                    Some(SampleValueBuf::Genotype("1|1".parse::<GenotypeBuf>()?)),
+                    None,
+                ],
+            ],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_ids([String::from("rs1")].into_iter().collect())
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("G"),
+                String::from("T"),
+            ]))
+            .set_quality_score(34.0)
+            .set_info(info)
+            .set_samples(samples)
+            .build();
+
+        let t = |s: &str| -> Result<bool, Box<dyn std::error::Error>> {
+            let filter = Filter::new(&header, s)?;
+            Ok(filter.matches(&header, &record)?)
+        };
+
+        assert!(t(r#"QUAL>30 && INFO/DP>10 && GT[0]="het""#)?);
+        assert!(!t(r#"QUAL>30 && INFO/DP>20"#)?);
+        assert!(t(r#"QUAL<30 || DP=13"#)?);
+        assert!(t(r#"!(QUAL<30)"#)?);
+
+        assert!(t(r#"CHROM="sq0" && POS>=8 && POS<9"#)?);
+        assert!(t(r#"ID="rs1" && REF="A" && ALT="G""#)?);
+        assert!(!t(r#"ALT="AC""#)?);
+        assert!(t(r#"FILTER=".""#)?);
+
+        assert!(t("AF>0.5")?);
+        assert!(!t("AF>0.8")?);
+        assert!(t("DB && INFO/DB=1")?);
+
+        assert!(t(r#"GT[1]="hom" && GT[1]="alt" && GT[1]="1|1""#)?);
+        assert!(!t(r#"GT[1]="ref""#)?);
+        assert!(t(r#"GT="het""#)?);
+        assert!(!t(r#"GT="mis""#)?);
+
+        assert!(t("FMT/DP[0]=8")?);
+        assert!(!t("FMT/DP[1]")?);
+        assert!(!t("FMT/DP[1]<8 || FMT/DP[1]>=8")?);
+        assert!(t("FORMAT/DP")?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_matches_with_missing_fields() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+        let record = RecordBuf::default();
+
+        let t = |s: &str| -> Result<bool, Box<dyn std::error::Error>> {
+            let filter = Filter::new(&header, s)?;
+            Ok(filter.matches(&header, &record)?)
+        };
+
+        assert!(!t("QUAL>0 || QUAL<=0")?);
+        assert!(!t("DP>0 || DP<=0")?);
+        assert!(!t("DB")?);
+        assert!(!t("GT!=\"het\"")?);
+        assert!(t(r#"ID="." && ALT=".""#)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_class() {
+        assert_eq!(genotype_class(&[Some(0), Some(0)], "hom"), Some(true));
+        assert_eq!(genotype_class(&[Some(0), Some(0)], "ref"), Some(true));
+        assert_eq!(genotype_class(&[Some(0), Some(0)], "alt"), Some(false));
+        assert_eq!(genotype_class(&[Some(0), Some(1)], "het"), Some(true));
+        assert_eq!(genotype_class(&[Some(0), Some(1)], "hom"), Some(false));
+        assert_eq!(genotype_class(&[Some(1)], "hap"), Some(true));
+        assert_eq!(genotype_class(&[Some(1)], "hom"), Some(false));
+        assert_eq!(genotype_class(&[None, Some(1)], "het"), Some(false));
+        assert_eq!(genotype_class(&[None, Some(1)], "alt"), Some(true));
+        assert_eq!(genotype_class(&[None, None], "mis"), Some(true));
+        assert_eq!(genotype_class(&[None, None], "0/0"), None);
+    }
+}
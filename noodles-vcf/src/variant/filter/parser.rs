@@ -0,0 +1,513 @@
+use std::{error, fmt, iter::Peekable, str::CharIndices};
+
+use crate::{variant::record::samples::keys::key, Header};
+
+/// A filter expression.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Expression {
+    Or(Box<Expression>, Box<Expression>),
+    And(Box<Expression>, Box<Expression>),
+    Not(Box<Expression>),
+    Compare(Operand, Operator, Operand),
+    Test(Operand),
+}
+
+/// A filter expression operand.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum Operand {
+    Number(f64),
+    String(String),
+    Field(Field),
+}
+
+/// A record field referenced by a filter expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(super) enum Field {
+    ReferenceSequenceName,
+    VariantStart,
+    Ids,
+    ReferenceBases,
+    AlternateBases,
+    QualityScore,
+    Filters,
+    Info(String),
+    Format(String, Option<usize>),
+}
+
+/// A filter expression comparison operator.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// An error returned when a filter expression fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input ended unexpectedly.
+    UnexpectedEof,
+    /// An unexpected character was found.
+    UnexpectedCharacter(char),
+    /// An unexpected token was found.
+    UnexpectedToken(String),
+    /// A string literal is not terminated.
+    UnterminatedString,
+    /// A number literal is invalid.
+    InvalidNumber(String),
+    /// A sample index is invalid.
+    ///
+    /// This is either not a nonnegative integer or out of range of the header sample names.
+    InvalidSampleIndex(String),
+    /// A sample index is given for a field that is not a FORMAT field.
+    UnexpectedSampleIndex(String),
+    /// The field is not defined in the header.
+    UndefinedField(String),
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::UnexpectedEof => write!(f, "unexpected end of input"),
+            Self::UnexpectedCharacter(c) => write!(f, "unexpected character: {c}"),
+            Self::UnexpectedToken(s) => write!(f, "unexpected token: {s}"),
+            Self::UnterminatedString => write!(f, "unterminated string"),
+            Self::InvalidNumber(s) => write!(f, "invalid number: {s}"),
+            Self::InvalidSampleIndex(s) => write!(f, "invalid sample index: {s}"),
+            Self::UnexpectedSampleIndex(s) => write!(f, "unexpected sample index for field: {s}"),
+            Self::UndefinedField(s) => write!(f, "undefined field: {s}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Identifier(String),
+    Number(String),
+    String(String),
+    Operator(Operator),
+    And,
+    Or,
+    Not,
+    LeftParenthesis,
+    RightParenthesis,
+    LeftBracket,
+    RightBracket,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(s) | Self::Number(s) => f.write_str(s),
+            Self::String(s) => write!(f, "\"{s}\""),
+            Self::Operator(Operator::Eq) => f.write_str("=="),
+            Self::Operator(Operator::Ne) => f.write_str("!="),
+            Self::Operator(Operator::Lt) => f.write_str("<"),
+            Self::Operator(Operator::Le) => f.write_str("<="),
+            Self::Operator(Operator::Gt) => f.write_str(">"),
+            Self::Operator(Operator::Ge) => f.write_str(">="),
+            Self::And => f.write_str("&&"),
+            Self::Or => f.write_str("||"),
+            Self::Not => f.write_str("!"),
+            Self::LeftParenthesis => f.write_str("("),
+            Self::RightParenthesis => f.write_str(")"),
+            Self::LeftBracket => f.write_str("["),
+            Self::RightBracket => f.write_str("]"),
+        }
+    }
+}
+
+/// Parses a filter expression, resolving fields against the given header.
+pub(super) fn parse_expression(header: &Header, s: &str) -> Result<Expression, ParseError> {
+    let tokens = tokenize(s)?;
+
+    if tokens.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut parser = Parser {
+        header,
+        tokens: tokens.into_iter().peekable(),
+    };
+
+    let expression = parser.parse_or()?;
+
+    match parser.tokens.next() {
+        Some(token) => Err(ParseError::UnexpectedToken(token.to_string())),
+        None => Ok(expression),
+    }
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() => continue,
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            '[' => Token::LeftBracket,
+            ']' => Token::RightBracket,
+            '&' => {
+                next_if_eq(&mut chars, '&');
+                Token::And
+            }
+            '|' => {
+                next_if_eq(&mut chars, '|');
+                Token::Or
+            }
+            '=' => {
+                next_if_eq(&mut chars, '=');
+                Token::Operator(Operator::Eq)
+            }
+            '!' => {
+                if next_if_eq(&mut chars, '=') {
+                    Token::Operator(Operator::Ne)
+                } else {
+                    Token::Not
+                }
+            }
+            '<' => {
+                if next_if_eq(&mut chars, '=') {
+                    Token::Operator(Operator::Le)
+                } else {
+                    Token::Operator(Operator::Lt)
+                }
+            }
+            '>' => {
+                if next_if_eq(&mut chars, '=') {
+                    Token::Operator(Operator::Ge)
+                } else {
+                    Token::Operator(Operator::Gt)
+                }
+            }
+            '"' | '\'' => {
+                let mut t = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some((_, d)) if d == c => break,
+                        Some((_, d)) => t.push(d),
+                        None => return Err(ParseError::UnterminatedString),
+                    }
+                }
+
+                Token::String(t)
+            }
+            '0'..='9' | '.' | '-' | '+' => {
+                let mut end = i + c.len_utf8();
+                let mut prev = c;
+
+                while let Some(&(j, d)) = chars.peek() {
+                    let is_exponent_sign = matches!(d, '-' | '+') && matches!(prev, 'e' | 'E');
+
+                    if d.is_ascii_alphanumeric() || d == '.' || is_exponent_sign {
+                        chars.next();
+                        end = j + d.len_utf8();
+                        prev = d;
+                    } else {
+                        break;
+                    }
+                }
+
+                Token::Number(s[i..end].into())
+            }
+            c if is_identifier_start(c) => {
+                let mut end = i + c.len_utf8();
+
+                while let Some(&(j, d)) = chars.peek() {
+                    if is_identifier_char(d) {
+                        chars.next();
+                        end = j + d.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+
+                Token::Identifier(s[i..end].into())
+            }
+            _ => return Err(ParseError::UnexpectedCharacter(c)),
+        };
+
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn next_if_eq(chars: &mut Peekable<CharIndices<'_>>, c: char) -> bool {
+    chars.next_if(|&(_, d)| d == c).is_some()
+}
+
+fn is_identifier_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '/' | '.')
+}
+
+struct Parser<'h> {
+    header: &'h Header,
+    tokens: Peekable<std::vec::IntoIter<Token>>,
+}
+
+impl<'h> Parser<'h> {
+    fn next(&mut self) -> Result<Token, ParseError> {
+        self.tokens.next().ok_or(ParseError::UnexpectedEof)
+    }
+
+    fn parse_or(&mut self) -> Result<Expression, ParseError> {
+        let mut lhs = self.parse_and()?;
+
+        while self.tokens.next_if_eq(&Token::Or).is_some() {
+            let rhs = self.parse_and()?;
+            lhs = Expression::Or(Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expression, ParseError> {
+        let mut lhs = self.parse_not()?;
+
+        while self.tokens.next_if_eq(&Token::And).is_some() {
+            let rhs = self.parse_not()?;
+            lhs = Expression::And(Box::new(lhs), Box::new(rhs));
+        }
+
+        Ok(lhs)
+    }
+
+    fn parse_not(&mut self) -> Result<Expression, ParseError> {
+        if self.tokens.next_if_eq(&Token::Not).is_some() {
+            let expression = self.parse_not()?;
+            Ok(Expression::Not(Box::new(expression)))
+        } else {
+            self.parse_comparison()
+        }
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expression, ParseError> {
+        if self.tokens.next_if_eq(&Token::LeftParenthesis).is_some() {
+            let expression = self.parse_or()?;
+
+            return match self.next()? {
+                Token::RightParenthesis => Ok(expression),
+                token => Err(ParseError::UnexpectedToken(token.to_string())),
+            };
+        }
+
+        let lhs = self.parse_operand()?;
+
+        if let Some(Token::Operator(operator)) = self.tokens.peek().cloned() {
+            self.tokens.next();
+            let rhs = self.parse_operand()?;
+            Ok(Expression::Compare(lhs, operator, rhs))
+        } else {
+            Ok(Expression::Test(lhs))
+        }
+    }
+
+    fn parse_operand(&mut self) -> Result<Operand, ParseError> {
+        match self.next()? {
+            Token::Number(s) => parse_number(&s).map(Operand::Number),
+            Token::String(s) => Ok(Operand::String(s)),
+            Token::Identifier(name) => self.parse_field(name).map(Operand::Field),
+            token => Err(ParseError::UnexpectedToken(token.to_string())),
+        }
+    }
+
+    fn parse_field(&mut self, name: String) -> Result<Field, ParseError> {
+        let sample_index = if self.tokens.next_if_eq(&Token::LeftBracket).is_some() {
+            let i = match self.next()? {
+                Token::Number(s) => s
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&i| i < self.header.sample_names().len())
+                    .ok_or(ParseError::InvalidSampleIndex(s))?,
+                token => return Err(ParseError::InvalidSampleIndex(token.to_string())),
+            };
+
+            match self.next()? {
+                Token::RightBracket => Some(i),
+                token => return Err(ParseError::UnexpectedToken(token.to_string())),
+            }
+        } else {
+            None
+        };
+
+        let field = resolve_field(self.header, &name)?;
+
+        match field {
+            Field::Format(key, _) => Ok(Field::Format(key, sample_index)),
+            _ if sample_index.is_some() => Err(ParseError::UnexpectedSampleIndex(name)),
+            _ => Ok(field),
+        }
+    }
+}
+
+fn parse_number(s: &str) -> Result<f64, ParseError> {
+    s.parse()
+        .ok()
+        .filter(|n: &f64| n.is_finite())
+        .ok_or_else(|| ParseError::InvalidNumber(s.into()))
+}
+
+fn resolve_field(header: &Header, name: &str) -> Result<Field, ParseError> {
+    let field = match name {
+        "CHROM" => Field::ReferenceSequenceName,
+        "POS" => Field::VariantStart,
+        "ID" => Field::Ids,
+        "REF" => Field::ReferenceBases,
+        "ALT" => Field::AlternateBases,
+        "QUAL" => Field::QualityScore,
+        "FILTER" => Field::Filters,
+        key::GENOTYPE => Field::Format(key::GENOTYPE.into(), None),
+        _ => {
+            if let Some(key) = name.strip_prefix("INFO/") {
+                Field::Info(key.into())
+            } else if let Some(key) = name
+                .strip_prefix("FMT/")
+                .or_else(|| name.strip_prefix("FORMAT/"))
+            {
+                Field::Format(key.into(), None)
+            } else if header.infos().contains_key(name) {
+                Field::Info(name.into())
+            } else if header.formats().contains_key(name) {
+                Field::Format(name.into(), None)
+            } else {
+                return Err(ParseError::UndefinedField(name.into()));
+            }
+        }
+    };
+
+    match &field {
+        Field::Info(key) if !header.infos().contains_key(key.as_str()) => {
+            Err(ParseError::UndefinedField(name.into()))
+        }
+        Field::Format(key, _) if !header.formats().contains_key(key.as_str()) => {
+            Err(ParseError::UndefinedField(name.into()))
+        }
+        _ => Ok(field),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{
+        map::{Format, Info},
+        Map,
+    };
+
+    fn build_header() -> Header {
+        use crate::variant::record::info::field::key as info_key;
+
+        Header::builder()
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<Info>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_format(key::READ_DEPTH, Map::<Format>::from(key::READ_DEPTH))
+            .add_sample_name("sample0")
+            .build()
+    }
+
+    #[test]
+    fn test_parse_expression() {
+        let header = build_header();
+
+        assert_eq!(
+            parse_expression(&header, r#"QUAL>30 && INFO/DP>=10 || !GT[0]="het""#),
+            Ok(Expression::Or(
+                Box::new(Expression::And(
+                    Box::new(Expression::Compare(
+                        Operand::Field(Field::QualityScore),
+                        Operator::Gt,
+                        Operand::Number(30.0),
+                    )),
+                    Box::new(Expression::Compare(
+                        Operand::Field(Field::Info(String::from("DP"))),
+                        Operator::Ge,
+                        Operand::Number(10.0),
+                    )),
+                )),
+                Box::new(Expression::Not(Box::new(Expression::Compare(
+                    Operand::Field(Field::Format(String::from("GT"), Some(0))),
+                    Operator::Eq,
+                    Operand::String(String::from("het")),
+                )))),
+            ))
+        );
+
+        assert_eq!(
+            parse_expression(&header, "(DP < -1.5e-2)"),
+            Ok(Expression::Compare(
+                Operand::Field(Field::Info(String::from("DP"))),
+                Operator::Lt,
+                Operand::Number(-1.5e-2),
+            ))
+        );
+
+        assert_eq!(
+            parse_expression(&header, "FMT/DP"),
+            Ok(Expression::Test(Operand::Field(Field::Format(
+                String::from("DP"),
+                None
+            ))))
+        );
+
+        assert_eq!(parse_expression(&header, " "), Err(ParseError::Empty));
+        assert_eq!(
+            parse_expression(&header, "QUAL >"),
+            Err(ParseError::UnexpectedEof)
+        );
+        assert_eq!(
+            parse_expression(&header, "(QUAL > 1"),
+            Err(ParseError::UnexpectedEof)
+        );
+        assert_eq!(
+            parse_expression(&header, "QUAL > 1)"),
+            Err(ParseError::UnexpectedToken(String::from(")")))
+        );
+        assert_eq!(
+            parse_expression(&header, "QUAL # 1"),
+            Err(ParseError::UnexpectedCharacter('#'))
+        );
+        assert_eq!(
+            parse_expression(&header, "ID = \"rs"),
+            Err(ParseError::UnterminatedString)
+        );
+        assert_eq!(
+            parse_expression(&header, "QUAL > 1x"),
+            Err(ParseError::InvalidNumber(String::from("1x")))
+        );
+        assert_eq!(
+            parse_expression(&header, "GT[1] = \"het\""),
+            Err(ParseError::InvalidSampleIndex(String::from("1")))
+        );
+        assert_eq!(
+            parse_expression(&header, "QUAL[0] > 1"),
+            Err(ParseError::UnexpectedSampleIndex(String::from("QUAL")))
+        );
+        assert_eq!(
+            parse_expression(&header, "INFO/AF > 0.5"),
+            Err(ParseError::UndefinedField(String::from("INFO/AF")))
+        );
+        assert_eq!(
+            parse_expression(&header, "AF > 0.5"),
+            Err(ParseError::UndefinedField(String::from("AF")))
+        );
+    }
+}
@@ -0,0 +1,359 @@
+//! Genomic VCF (gVCF) blocks.
+//!
+//! A gVCF has records for every position of the genome, either as variant sites or as reference
+//! blocks. A reference block is a record whose only alternate allele is the unspecified allele
+//! (`<NON_REF>` or `<*>`) and whose INFO END field gives the last position of the block.
+
+mod block;
+mod expand;
+mod intersect;
+
+use std::io;
+
+use noodles_core::Region;
+
+pub use self::{block::Block, expand::Expand, intersect::Intersect};
+use super::Record;
+use crate::Header;
+
+/// The gVCF unspecified alternate allele (`<NON_REF>`).
+pub const NON_REF: &str = "<NON_REF>";
+
+/// The VCF 4.3+ unspecified alternate allele (`<*>`).
+pub const UNSPECIFIED: &str = "<*>";
+
+/// An iterator over gVCF blocks.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::{
+///         gvcf::Blocks,
+///         record::info::field::key,
+///         record_buf::{info::field::Value, AlternateBases},
+///         RecordBuf,
+///     },
+/// };
+///
+/// let header = vcf::Header::default();
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("<NON_REF>")]))
+///     .set_info(
+///         [(String::from(key::END_POSITION), Some(Value::Integer(13)))]
+///             .into_iter()
+///             .collect(),
+///     )
+///     .build();
+///
+/// let mut blocks = Blocks::new(&header, [Ok(record)].into_iter());
+///
+/// let block = blocks.next().transpose()?.expect("missing block");
+/// assert!(block.is_reference_block());
+/// assert_eq!(block.start(), Position::try_from(8)?);
+/// assert_eq!(block.end(), Position::try_from(13)?);
+///
+/// assert!(blocks.next().is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct Blocks<'h, I> {
+    header: &'h Header,
+    records: I,
+}
+
+impl<'h, I, R> Blocks<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates an iterator over gVCF blocks from an iterator over records.
+    pub fn new(header: &'h Header, records: I) -> Self {
+        Self { header, records }
+    }
+
+    /// Returns an iterator over single-position blocks.
+    ///
+    /// Each block is split into a block for each position it covers. This gives the per-position
+    /// coverage of a gVCF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::{gvcf::Blocks, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::try_from(8)?)
+    ///     .set_reference_bases("AC")
+    ///     .build();
+    ///
+    /// let positions: Vec<_> = Blocks::new(&header, [Ok(record)].into_iter())
+    ///     .expand()
+    ///     .map(|result| result.map(|block| usize::from(block.start())))
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(positions, [8, 9]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn expand(self) -> Expand<'h, I, R>
+    where
+        R: Clone,
+    {
+        Expand::new(self)
+    }
+
+    /// Returns an iterator over blocks clipped to the given regions.
+    ///
+    /// Blocks that do not intersect any region are skipped. A block that intersects more than
+    /// one region is returned once per region, in the order the regions are given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::{gvcf::Blocks, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::try_from(8)?)
+    ///     .set_reference_bases("ACGT")
+    ///     .build();
+    ///
+    /// let regions = ["sq0:10-13".parse()?];
+    ///
+    /// let intervals: Vec<_> = Blocks::new(&header, [Ok(record)].into_iter())
+    ///     .intersect(&regions)
+    ///     .map(|result| result.map(|block| (usize::from(block.start()), usize::from(block.end()))))
+    ///     .collect::<Result<_, _>>()?;
+    ///
+    /// assert_eq!(intervals, [(10, 11)]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn intersect(self, regions: &[Region]) -> Intersect<'h, I, R>
+    where
+        R: Clone,
+    {
+        Intersect::new(self, regions.to_vec())
+    }
+}
+
+impl<'h, I, R> Iterator for Blocks<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<Block<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.records.next()? {
+            Ok(record) => Some(build_block(self.header, record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn build_block<R>(header: &Header, record: R) -> io::Result<Block<R>>
+where
+    R: Record,
+{
+    let reference_sequence_name = record.reference_sequence_name(header)?.into();
+
+    let start = record
+        .variant_start()
+        .transpose()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing position"))?;
+
+    let end = record.variant_end(header)?;
+
+    if end < start {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid block end position",
+        ));
+    }
+
+    let is_reference_block = is_reference_block(&record)?;
+
+    Ok(Block::new(
+        reference_sequence_name,
+        start,
+        end,
+        is_reference_block,
+        record,
+    ))
+}
+
+fn is_reference_block<R>(record: &R) -> io::Result<bool>
+where
+    R: Record,
+{
+    let alternate_bases = record.alternate_bases();
+
+    if alternate_bases.is_empty() {
+        return Ok(false);
+    }
+
+    for result in alternate_bases.iter() {
+        let allele = result?;
+
+        if allele != NON_REF && allele != UNSPECIFIED {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::variant::{
+        record::info::field::key,
+        record_buf::{info::field::Value, AlternateBases},
+        RecordBuf,
+    };
+
+    fn build_reference_block(
+        reference_sequence_name: &str,
+        start: usize,
+        end: i32,
+    ) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .set_reference_sequence_name(reference_sequence_name)
+            .set_variant_start(Position::try_from(start)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from(NON_REF)]))
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::Integer(end)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build())
+    }
+
+    fn build_variant(
+        reference_sequence_name: &str,
+        start: usize,
+    ) -> Result<RecordBuf, Box<dyn std::error::Error>> {
+        Ok(RecordBuf::builder()
+            .set_reference_sequence_name(reference_sequence_name)
+            .set_variant_start(Position::try_from(start)?)
+            .set_reference_bases("AC")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("A"),
+                String::from(NON_REF),
+            ]))
+            .build())
+    }
+
+    fn build_records() -> Result<Vec<RecordBuf>, Box<dyn std::error::Error>> {
+        Ok(vec![
+            build_reference_block("sq0", 1, 4)?,
+            build_variant("sq0", 5)?,
+            build_reference_block("sq0", 7, 10)?,
+            build_reference_block("sq1", 1, 3)?,
+        ])
+    }
+
+    fn intervals<I, R>(blocks: I) -> io::Result<Vec<(String, usize, usize, bool)>>
+    where
+        I: Iterator<Item = io::Result<Block<R>>>,
+    {
+        blocks
+            .map(|result| {
+                result.map(|block| {
+                    (
+                        block.reference_sequence_name().into(),
+                        usize::from(block.start()),
+                        usize::from(block.end()),
+                        block.is_reference_block(),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = build_records()?;
+
+        let blocks = Blocks::new(&header, records.into_iter().map(Ok));
+
+        assert_eq!(
+            intervals(blocks)?,
+            [
+                (String::from("sq0"), 1, 4, true),
+                (String::from("sq0"), 5, 6, false),
+                (String::from("sq0"), 7, 10, true),
+                (String::from("sq1"), 1, 3, true),
+            ]
+        );
+
+        let record = build_reference_block("sq0", 8, 5)?;
+        let mut blocks = Blocks::new(&header, [Ok(record)].into_iter());
+        assert!(matches!(
+            blocks.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expand() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = build_records()?;
+
+        let blocks = Blocks::new(&header, records.into_iter().map(Ok)).expand();
+        let actual = intervals(blocks)?;
+
+        assert_eq!(actual.len(), 13);
+        assert_eq!(actual[3], (String::from("sq0"), 4, 4, true));
+        assert_eq!(actual[4], (String::from("sq0"), 5, 5, false));
+        assert_eq!(actual[5], (String::from("sq0"), 6, 6, false));
+        assert_eq!(actual[12], (String::from("sq1"), 3, 3, true));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let records = build_records()?;
+
+        let regions = [
+            "sq0:3-5".parse()?,
+            "sq0:9".parse()?,
+            "sq1:2-8".parse()?,
+            "sq2".parse()?,
+        ];
+
+        let blocks = Blocks::new(&header, records.into_iter().map(Ok)).intersect(&regions);
+
+        assert_eq!(
+            intervals(blocks)?,
+            [
+                (String::from("sq0"), 3, 4, true),
+                (String::from("sq0"), 5, 5, false),
+                (String::from("sq0"), 9, 10, true),
+                (String::from("sq1"), 2, 3, true),
+            ]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use noodles_core::{region::Interval, Position};
+
+/// A gVCF block.
+///
+/// A block is the interval covered by a gVCF record. This is either a reference block, i.e., a
+/// record whose only alternate allele is the unspecified allele (`<NON_REF>` or `<*>`), with an
+/// end position given by the INFO END field, or a variant site, covering its reference bases.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block<R> {
+    reference_sequence_name: String,
+    start: Position,
+    end: Position,
+    is_reference_block: bool,
+    record: R,
+}
+
+impl<R> Block<R> {
+    pub(super) fn new(
+        reference_sequence_name: String,
+        start: Position,
+        end: Position,
+        is_reference_block: bool,
+        record: R,
+    ) -> Self {
+        Self {
+            reference_sequence_name,
+            start,
+            end,
+            is_reference_block,
+            record,
+        }
+    }
+
+    /// Returns the reference sequence name.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the start position.
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
+    /// Returns the end position.
+    pub fn end(&self) -> Position {
+        self.end
+    }
+
+    /// Returns the interval covered by the block.
+    pub fn interval(&self) -> Interval {
+        Interval::from(self.start..=self.end)
+    }
+
+    /// Returns the number of positions covered by the block.
+    pub fn len(&self) -> usize {
+        usize::from(self.end) - usize::from(self.start) + 1
+    }
+
+    /// Returns whether the block covers no positions.
+    ///
+    /// This is always false.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns whether the block is a reference block.
+    pub fn is_reference_block(&self) -> bool {
+        self.is_reference_block
+    }
+
+    /// Returns the record the block was read from.
+    ///
+    /// An expanded or clipped block keeps the record of the original block.
+    pub fn record(&self) -> &R {
+        &self.record
+    }
+
+    /// Unwraps and returns the record the block was read from.
+    pub fn into_record(self) -> R {
+        self.record
+    }
+
+    pub(super) fn with_interval(&self, start: Position, end: Position) -> Self
+    where
+        R: Clone,
+    {
+        Self {
+            reference_sequence_name: self.reference_sequence_name.clone(),
+            start,
+            end,
+            is_reference_block: self.is_reference_block,
+            record: self.record.clone(),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::io;
+
+use noodles_core::Position;
+
+use super::{Block, Blocks};
+use crate::variant::Record;
+
+/// An iterator over gVCF blocks expanded to single positions.
+///
+/// This is created by calling [`Blocks::expand`].
+pub struct Expand<'h, I, R> {
+    blocks: Blocks<'h, I>,
+    block: Option<(Block<R>, Position)>,
+}
+
+impl<'h, I, R> Expand<'h, I, R> {
+    pub(super) fn new(blocks: Blocks<'h, I>) -> Self {
+        Self {
+            blocks,
+            block: None,
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for Expand<'h, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record + Clone,
+{
+    type Item = io::Result<Block<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((block, position)) = self.block.take() {
+                let site = block.with_interval(position, position);
+
+                if let Some(next_position) = position.checked_add(1) {
+                    if next_position <= block.end() {
+                        self.block = Some((block, next_position));
+                    }
+                }
+
+                return Some(Ok(site));
+            }
+
+            match self.blocks.next()? {
+                Ok(block) => {
+                    let start = block.start();
+                    self.block = Some((block, start));
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::{collections::VecDeque, io};
+
+use noodles_core::{Position, Region};
+
+use super::{Block, Blocks};
+use crate::variant::Record;
+
+/// An iterator over gVCF blocks clipped to a set of regions.
+///
+/// This is created by calling [`Blocks::intersect`].
+pub struct Intersect<'h, I, R> {
+    blocks: Blocks<'h, I>,
+    regions: Vec<Region>,
+    pending: VecDeque<Block<R>>,
+}
+
+impl<'h, I, R> Intersect<'h, I, R> {
+    pub(super) fn new(blocks: Blocks<'h, I>, regions: Vec<Region>) -> Self {
+        Self {
+            blocks,
+            regions,
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for Intersect<'h, I, R>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record + Clone,
+{
+    type Item = io::Result<Block<R>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(block) = self.pending.pop_front() {
+                return Some(Ok(block));
+            }
+
+            let block = match self.blocks.next()? {
+                Ok(block) => block,
+                Err(e) => return Some(Err(e)),
+            };
+
+            for region in &self.regions {
+                if let Some((start, end)) = clip(&block, region) {
+                    self.pending.push_back(block.with_interval(start, end));
+                }
+            }
+        }
+    }
+}
+
+fn clip<R>(block: &Block<R>, region: &Region) -> Option<(Position, Position)> {
+    if region.name() != block.reference_sequence_name().as_bytes() {
+        return None;
+    }
+
+    let interval = region.interval();
+
+    if !interval.intersects(block.interval()) {
+        return None;
+    }
+
+    let start = interval
+        .start()
+        .map(|start| start.max(block.start()))
+        .unwrap_or(block.start());
+
+    let end = interval
+        .end()
+        .map(|end| end.min(block.end()))
+        .unwrap_or(block.end());
+
+    Some((start, end))
+}
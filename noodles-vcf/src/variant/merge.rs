@@ -0,0 +1,779 @@
+//! Variant record merging.
+//!
+//! This merges multiple sorted inputs into a single multi-sample output, similar to `bcftools
+//! merge`.
+
+mod header;
+
+use std::io;
+
+use indexmap::IndexSet;
+
+pub use self::header::{merge_headers, MergeError};
+use super::{
+    record::samples::keys::key,
+    record_buf::{
+        info::field::Value as InfoValue,
+        norm::{
+            format_cardinality, genotype, genotype_index, info_cardinality, ploidy, Cardinality,
+            Values, DEFAULT_PLOIDY,
+        },
+        samples::{
+            keys::Keys,
+            sample::{
+                value::{genotype::Allele, Genotype},
+                Value as SampleValue,
+            },
+        },
+        AlternateBases, Info, RecordBuf, Samples,
+    },
+};
+use crate::Header;
+
+const PASS: &str = "PASS";
+
+/// A k-way merger of sorted variant record inputs.
+///
+/// Each input must be sorted by reference sequence, in the order of the merged header contigs, and
+/// variant start. Records at the same position with compatible reference bases, i.e., where one
+/// is a prefix of the other, are merged into a single record:
+///
+///   * reference bases are the longest of the records, and alternate bases are extended to match
+///     and deduplicated;
+///   * IDs and filters are the union of the records, where `PASS` is dropped if any other filter
+///     is set;
+///   * the quality score is the maximum of the records;
+///   * `Number=A`, `Number=R`, and `Number=G` info and sample values are remapped to the merged
+///     alleles, and genotypes are renumbered; and
+///   * samples of inputs without a record at the position are set to missing.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::{merge::Merger, record_buf::AlternateBases, RecordBuf},
+/// };
+///
+/// let headers = [vcf::Header::default(), vcf::Header::default()];
+///
+/// let build_record = |alternate_base: &str| {
+///     RecordBuf::builder()
+///         .set_reference_sequence_name("sq0")
+///         .set_variant_start(Position::MIN)
+///         .set_reference_bases("A")
+///         .set_alternate_bases(AlternateBases::from(vec![String::from(alternate_base)]))
+///         .build()
+/// };
+///
+/// let inputs = vec![
+///     vec![Ok(build_record("C"))].into_iter(),
+///     vec![Ok(build_record("G"))].into_iter(),
+/// ];
+///
+/// let mut merger = Merger::new(&headers, inputs)?;
+///
+/// let record = merger.next().transpose()?.expect("missing record");
+/// assert_eq!(
+///     record.alternate_bases().as_ref(),
+///     [String::from("C"), String::from("G")]
+/// );
+///
+/// assert!(merger.next().is_none());
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct Merger<I> {
+    header: Header,
+    sample_counts: Vec<usize>,
+    inputs: Vec<I>,
+    peeked: Vec<Option<RecordBuf>>,
+    reference_sequence_names: IndexSet<String>,
+}
+
+impl<I> Merger<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    /// Creates a merger from the headers of the inputs and the inputs.
+    ///
+    /// The headers are merged using [`merge_headers`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io, iter};
+    /// use noodles_vcf::{self as vcf, variant::{merge::Merger, RecordBuf}};
+    ///
+    /// let headers = [vcf::Header::default()];
+    /// let inputs = vec![iter::empty::<io::Result<RecordBuf>>()];
+    /// let merger = Merger::new(&headers, inputs)?;
+    /// # Ok::<_, vcf::variant::merge::MergeError>(())
+    /// ```
+    pub fn new(headers: &[Header], inputs: Vec<I>) -> Result<Self, MergeError> {
+        if headers.len() != inputs.len() {
+            return Err(MergeError::InputCountMismatch {
+                header_count: headers.len(),
+                input_count: inputs.len(),
+            });
+        }
+
+        let header = merge_headers(headers)?;
+        let sample_counts = headers.iter().map(|h| h.sample_names().len()).collect();
+        let reference_sequence_names = header.contigs().keys().cloned().collect();
+        let peeked = inputs.iter().map(|_| None).collect();
+
+        Ok(Self {
+            header,
+            sample_counts,
+            inputs,
+            peeked,
+            reference_sequence_names,
+        })
+    }
+
+    /// Returns the merged header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::{io, iter};
+    /// use noodles_vcf::{self as vcf, variant::{merge::Merger, RecordBuf}};
+    ///
+    /// let headers = [
+    ///     vcf::Header::builder().add_sample_name("sample0").build(),
+    ///     vcf::Header::builder().add_sample_name("sample1").build(),
+    /// ];
+    ///
+    /// let inputs = vec![
+    ///     iter::empty::<io::Result<RecordBuf>>(),
+    ///     iter::empty::<io::Result<RecordBuf>>(),
+    /// ];
+    ///
+    /// let merger = Merger::new(&headers, inputs)?;
+    /// assert_eq!(merger.header().sample_names().len(), 2);
+    /// # Ok::<_, vcf::variant::merge::MergeError>(())
+    /// ```
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    fn read_next(&mut self) -> io::Result<Option<RecordBuf>> {
+        for (slot, input) in self.peeked.iter_mut().zip(&mut self.inputs) {
+            if slot.is_none() {
+                *slot = input.next().transpose()?;
+            }
+        }
+
+        let mut min_key = None;
+
+        for record in self.peeked.iter().flatten() {
+            let key = sort_key(&mut self.reference_sequence_names, record);
+
+            if min_key.map(|k| key < k).unwrap_or(true) {
+                min_key = Some(key);
+            }
+        }
+
+        let Some(min_key) = min_key else {
+            return Ok(None);
+        };
+
+        let mut group: Vec<(usize, RecordBuf)> = Vec::new();
+        let mut reference_bases = String::new();
+
+        for (i, slot) in self.peeked.iter_mut().enumerate() {
+            let Some(record) = slot.as_ref() else {
+                continue;
+            };
+
+            if sort_key(&mut self.reference_sequence_names, record) != min_key {
+                continue;
+            }
+
+            let bases = record.reference_bases();
+
+            if group.is_empty() || bases.starts_with(&reference_bases) {
+                reference_bases = bases.into();
+            } else if !reference_bases.starts_with(bases) {
+                continue;
+            }
+
+            if let Some(record) = slot.take() {
+                group.push((i, record));
+            }
+        }
+
+        merge_records(&self.header, &self.sample_counts, &reference_bases, group).map(Some)
+    }
+}
+
+impl<I> Iterator for Merger<I>
+where
+    I: Iterator<Item = io::Result<RecordBuf>>,
+{
+    type Item = io::Result<RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_next().transpose()
+    }
+}
+
+fn sort_key(reference_sequence_names: &mut IndexSet<String>, record: &RecordBuf) -> (usize, usize) {
+    let name = record.reference_sequence_name();
+
+    let i = match reference_sequence_names.get_index_of(name) {
+        Some(i) => i,
+        None => reference_sequence_names.insert_full(name.into()).0,
+    };
+
+    let position = record.variant_start().map(usize::from).unwrap_or_default();
+
+    (i, position)
+}
+
+fn is_sequence(allele: &str) -> bool {
+    !allele.is_empty() && allele.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn merge_records(
+    header: &Header,
+    sample_counts: &[usize],
+    reference_bases: &str,
+    group: Vec<(usize, RecordBuf)>,
+) -> io::Result<RecordBuf> {
+    let mut alternate_bases: Vec<String> = Vec::new();
+    let mut allele_maps = Vec::with_capacity(group.len());
+
+    for (_, record) in &group {
+        let suffix = &reference_bases[record.reference_bases().len()..];
+        let mut allele_map = vec![0];
+
+        for allele in record.alternate_bases().as_ref() {
+            let allele = if is_sequence(allele) {
+                format!("{allele}{suffix}")
+            } else {
+                allele.clone()
+            };
+
+            let i = match alternate_bases.iter().position(|a| *a == allele) {
+                Some(i) => i,
+                None => {
+                    alternate_bases.push(allele);
+                    alternate_bases.len() - 1
+                }
+            };
+
+            allele_map.push(i + 1);
+        }
+
+        allele_maps.push(allele_map);
+    }
+
+    let allele_count = alternate_bases.len() + 1;
+
+    let mut iter = group.iter().map(|(_, record)| record);
+    // SAFETY: A group has at least one record.
+    let mut record = iter.next().cloned().unwrap();
+
+    for r in iter {
+        record.ids_mut().extend(r.ids().as_ref().iter().cloned());
+        record
+            .filters_mut()
+            .extend(r.filters().as_ref().iter().cloned());
+
+        if let Some(quality_score) = r.quality_score() {
+            let q = record.quality_score_mut();
+
+            if q.map(|n| quality_score > n).unwrap_or(true) {
+                *q = Some(quality_score);
+            }
+        }
+    }
+
+    if record.filters().as_ref().len() > 1 {
+        record.filters_mut().as_mut().shift_remove(PASS);
+    }
+
+    *record.reference_bases_mut() = reference_bases.into();
+    *record.alternate_bases_mut() = AlternateBases::from(alternate_bases);
+    *record.info_mut() = merge_info(header, &group, &allele_maps, allele_count)?;
+    *record.samples_mut() =
+        merge_samples(header, sample_counts, &group, &allele_maps, allele_count);
+
+    Ok(record)
+}
+
+fn merge_info(
+    header: &Header,
+    group: &[(usize, RecordBuf)],
+    allele_maps: &[Vec<usize>],
+    allele_count: usize,
+) -> io::Result<Info> {
+    let mut keys: IndexSet<&String> = IndexSet::new();
+
+    for (_, record) in group {
+        keys.extend(record.info().keys());
+    }
+
+    let mut info = Info::default();
+
+    for key in keys {
+        let cardinality = info_cardinality(header, key);
+
+        let values: Vec<_> = group
+            .iter()
+            .zip(allele_maps)
+            .filter_map(|((_, record), allele_map)| {
+                let value = record.info().get(key).flatten()?;
+                Some((value, allele_map))
+            })
+            .collect();
+
+        let mut remapped_values = Vec::new();
+
+        for (value, allele_map) in &values {
+            let indices = remap_indices(cardinality, allele_map, allele_count, DEFAULT_PLOIDY);
+
+            if let (Some(indices), Some(v)) = (indices, Values::from_info_value(value)) {
+                remapped_values.push(v.select(&indices));
+            }
+        }
+
+        let value = match coalesce(remapped_values)? {
+            Some(v) => Some(InfoValue::from(v)),
+            None => values.first().map(|(value, _)| (*value).clone()),
+        };
+
+        info.insert(key.clone(), value);
+    }
+
+    Ok(info)
+}
+
+fn merge_samples(
+    header: &Header,
+    sample_counts: &[usize],
+    group: &[(usize, RecordBuf)],
+    allele_maps: &[Vec<usize>],
+    allele_count: usize,
+) -> Samples {
+    let mut keys: IndexSet<String> = IndexSet::new();
+
+    for (_, record) in group {
+        keys.extend(record.samples().keys().as_ref().iter().cloned());
+    }
+
+    if let Some(i) = keys.get_index_of(key::GENOTYPE) {
+        keys.move_index(i, 0);
+    }
+
+    let mut values = Vec::new();
+
+    for (input_index, &sample_count) in sample_counts.iter().enumerate() {
+        let entry = group
+            .iter()
+            .zip(allele_maps)
+            .find(|((i, _), _)| *i == input_index);
+
+        let Some(((_, record), allele_map)) = entry else {
+            values.extend((0..sample_count).map(|_| vec![None; keys.len()]));
+            continue;
+        };
+
+        let samples = record.samples();
+        let record_keys: Vec<_> = samples.keys().as_ref().iter().map(|k| k.as_str()).collect();
+
+        // Missing samples are padded, and samples not in the input header are dropped.
+        for i in 0..sample_count {
+            let Some(sample) = samples.values.get(i) else {
+                values.push(vec![None; keys.len()]);
+                continue;
+            };
+
+            let genotype = genotype(&record_keys, sample);
+            let ploidy = ploidy(genotype.as_ref());
+
+            let sample_values = keys
+                .iter()
+                .map(|key| {
+                    let i = record_keys.iter().position(|k| k == key)?;
+                    let value = sample.get(i)?.as_ref()?;
+
+                    let value = match value {
+                        SampleValue::Genotype(genotype) => {
+                            SampleValue::Genotype(remap_genotype(genotype, allele_map))
+                        }
+                        _ => {
+                            let cardinality = format_cardinality(header, key);
+
+                            remap_indices(cardinality, allele_map, allele_count, ploidy)
+                                .and_then(|indices| {
+                                    Values::from_sample_value(value)
+                                        .map(|v| SampleValue::from(v.select(&indices)))
+                                })
+                                .unwrap_or_else(|| value.clone())
+                        }
+                    };
+
+                    Some(value)
+                })
+                .collect();
+
+            values.push(sample_values);
+        }
+    }
+
+    let keys: Keys = keys.into_iter().collect();
+    Samples::new(keys, values)
+}
+
+fn remap_genotype(genotype: &Genotype, allele_map: &[usize]) -> Genotype {
+    genotype
+        .as_ref()
+        .iter()
+        .map(|allele| {
+            let position = allele
+                .position()
+                .map(|i| allele_map.get(i).copied().unwrap_or(i));
+
+            Allele::new(position, allele.phasing())
+        })
+        .collect()
+}
+
+// Returns the indices into the values of a record that build the values of the merged record.
+fn remap_indices(
+    cardinality: Cardinality,
+    allele_map: &[usize],
+    allele_count: usize,
+    ploidy: usize,
+) -> Option<Vec<Option<usize>>> {
+    let mut inverse_allele_map = vec![None; allele_count];
+
+    for (old, &new) in allele_map.iter().enumerate() {
+        if inverse_allele_map[new].is_none() {
+            inverse_allele_map[new] = Some(old);
+        }
+    }
+
+    match cardinality {
+        Cardinality::AlternateBases => Some(
+            inverse_allele_map[1..]
+                .iter()
+                .map(|i| i.and_then(|j| j.checked_sub(1)))
+                .collect(),
+        ),
+        Cardinality::ReferenceAlternateBases => Some(inverse_allele_map),
+        Cardinality::Genotypes => {
+            let max_allele = allele_count - 1;
+            let genotype_count = genotype_index(&vec![max_allele; ploidy]) + 1;
+            let mut indices = vec![None; genotype_count];
+
+            let mut alleles = vec![0; ploidy];
+
+            loop {
+                let old_alleles: Option<Vec<_>> =
+                    alleles.iter().map(|&a| inverse_allele_map[a]).collect();
+
+                if let Some(mut old_alleles) = old_alleles {
+                    old_alleles.sort_unstable();
+                    indices[genotype_index(&alleles)] = Some(genotype_index(&old_alleles));
+                }
+
+                // Advance to the next nondecreasing allele tuple.
+                let Some(i) = alleles.iter().rposition(|&a| a < max_allele) else {
+                    break;
+                };
+
+                let a = alleles[i] + 1;
+                alleles[i..].fill(a);
+            }
+
+            Some(indices)
+        }
+        Cardinality::Other => None,
+    }
+}
+
+// Merges lists of values by taking the first present value at each position.
+fn coalesce(values: Vec<Values>) -> io::Result<Option<Values>> {
+    fn fill<T: Clone>(dst: &mut [Option<T>], src: &[Option<T>]) {
+        for (a, b) in dst.iter_mut().zip(src) {
+            if a.is_none() {
+                a.clone_from(b);
+            }
+        }
+    }
+
+    let mut iter = values.into_iter();
+
+    let Some(mut merged) = iter.next() else {
+        return Ok(None);
+    };
+
+    for v in iter {
+        match (&mut merged, &v) {
+            (Values::Integer(a), Values::Integer(b)) => fill(a, b),
+            (Values::Float(a), Values::Float(b)) => fill(a, b),
+            (Values::Character(a), Values::Character(b)) => fill(a, b),
+            (Values::String(a), Values::String(b)) => fill(a, b),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "value type mismatch",
+                ))
+            }
+        }
+    }
+
+    Ok(Some(merged))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        header::record::value::{
+            map::{format, info, Contig, Format, Info},
+            Map,
+        },
+        variant::record_buf::{info::field::value::Array as InfoArray, samples::Keys},
+    };
+
+    fn build_header(sample_name: &str) -> Header {
+        Header::builder()
+            .add_info(
+                "AC",
+                Map::<Info>::new(info::Number::AlternateBases, info::Type::Integer, ""),
+            )
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_format(
+                "AD",
+                Map::<Format>::new(
+                    format::Number::ReferenceAlternateBases,
+                    format::Type::Integer,
+                    "",
+                ),
+            )
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_sample_name(sample_name)
+            .build()
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let headers = [build_header("sample0"), build_header("sample1")];
+
+        let keys: Keys = [String::from(key::GENOTYPE), String::from("AD")]
+            .into_iter()
+            .collect();
+
+        let inputs = vec![
+            vec![
+                Ok(RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(Position::try_from(1)?)
+                    .set_reference_bases("A")
+                    .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                    .set_info(
+                        [(String::from("AC"), Some(InfoValue::from(vec![Some(1)])))]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .set_samples(Samples::new(
+                        keys.clone(),
+                        vec![vec![
+                            Some(SampleValue::Genotype("0/1".parse()?)),
+                            Some(SampleValue::from(vec![Some(5), Some(3)])),
+                        ]],
+                    ))
+                    .build()),
+                Ok(RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(Position::try_from(5)?)
+                    .set_reference_bases("G")
+                    .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                    .set_info(
+                        [(String::from("AC"), Some(InfoValue::from(vec![Some(1)])))]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .set_samples(Samples::new(
+                        keys.clone(),
+                        vec![vec![
+                            Some(SampleValue::Genotype("0/1".parse()?)),
+                            Some(SampleValue::from(vec![Some(2), Some(2)])),
+                        ]],
+                    ))
+                    .build()),
+            ]
+            .into_iter(),
+            vec![
+                Ok(RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(Position::try_from(1)?)
+                    .set_reference_bases("AT")
+                    .set_alternate_bases(AlternateBases::from(vec![
+                        String::from("GT"),
+                        String::from("A"),
+                    ]))
+                    .set_info(
+                        [(
+                            String::from("AC"),
+                            Some(InfoValue::from(vec![Some(2), Some(1)])),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    )
+                    .set_samples(Samples::new(
+                        keys.clone(),
+                        vec![vec![
+                            Some(SampleValue::Genotype("1/2".parse()?)),
+                            Some(SampleValue::from(vec![Some(0), Some(4), Some(2)])),
+                        ]],
+                    ))
+                    .build()),
+                Ok(RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(Position::try_from(3)?)
+                    .set_reference_bases("C")
+                    .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                    .set_info(
+                        [(String::from("AC"), Some(InfoValue::from(vec![Some(2)])))]
+                            .into_iter()
+                            .collect(),
+                    )
+                    .set_samples(Samples::new(
+                        keys.clone(),
+                        vec![vec![
+                            Some(SampleValue::Genotype("1/1".parse()?)),
+                            Some(SampleValue::from(vec![Some(0), Some(6)])),
+                        ]],
+                    ))
+                    .build()),
+            ]
+            .into_iter(),
+        ];
+
+        let mut merger = Merger::new(&headers, inputs)?;
+        let records: Vec<_> = merger.by_ref().collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 3);
+
+        let record = &records[0];
+        assert_eq!(record.reference_bases(), "AT");
+        assert_eq!(
+            record.alternate_bases().as_ref(),
+            [String::from("CT"), String::from("GT"), String::from("A")]
+        );
+        assert_eq!(
+            record.info().get("AC"),
+            Some(Some(&InfoValue::Array(InfoArray::Integer(vec![
+                Some(1),
+                Some(2),
+                Some(1)
+            ]))))
+        );
+
+        let expected = Samples::new(
+            keys,
+            vec![
+                vec![
+                    Some(SampleValue::Genotype("0/1".parse()?)),
+                    Some(SampleValue::from(vec![Some(5), Some(3), None, None])),
+                ],
+                vec![
+                    Some(SampleValue::Genotype("2/3".parse()?)),
+                    Some(SampleValue::from(vec![Some(0), None, Some(4), Some(2)])),
+                ],
+            ],
+        );
+        assert_eq!(record.samples(), &expected);
+
+        let record = &records[1];
+        assert_eq!(record.variant_start(), Some(Position::try_from(3)?));
+        assert_eq!(
+            record
+                .samples()
+                .values()
+                .next()
+                .map(|s| s.values().to_vec()),
+            Some(vec![None, None])
+        );
+
+        let record = &records[2];
+        assert_eq!(record.variant_start(), Some(Position::try_from(5)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_sites_only_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = build_header("sample0");
+        header.sample_names_mut().insert(String::from("sample1"));
+        let headers = [header, build_header("sample2")];
+
+        let inputs = vec![
+            vec![Ok(RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .build())]
+            .into_iter(),
+            vec![Ok(RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .set_samples(Samples::new(
+                    [String::from(key::GENOTYPE)].into_iter().collect(),
+                    vec![vec![Some(SampleValue::Genotype("0/1".parse()?))]],
+                ))
+                .build())]
+            .into_iter(),
+        ];
+
+        let records: Vec<_> = Merger::new(&headers, inputs)?.collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        let expected = Samples::new(
+            [String::from(key::GENOTYPE)].into_iter().collect(),
+            vec![
+                vec![None],
+                vec![None],
+                vec![Some(SampleValue::Genotype("0/1".parse()?))],
+            ],
+        );
+        assert_eq!(records[0].samples(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_remap_indices() {
+        // 0 => 0, 1 => 2
+        let allele_map = [0, 2];
+
+        assert_eq!(
+            remap_indices(Cardinality::AlternateBases, &allele_map, 3, 2),
+            Some(vec![None, Some(0)])
+        );
+
+        assert_eq!(
+            remap_indices(Cardinality::ReferenceAlternateBases, &allele_map, 3, 2),
+            Some(vec![Some(0), None, Some(1)])
+        );
+
+        // 0/0, 0/1, 1/1, 0/2, 1/2, 2/2
+        assert_eq!(
+            remap_indices(Cardinality::Genotypes, &allele_map, 3, 2),
+            Some(vec![Some(0), None, None, Some(1), None, Some(2)])
+        );
+
+        assert!(remap_indices(Cardinality::Other, &allele_map, 3, 2).is_none());
+    }
+}
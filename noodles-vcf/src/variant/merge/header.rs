@@ -0,0 +1,257 @@
+use std::{error, fmt};
+
+use crate::{
+    header::{self, StringMaps},
+    Header,
+};
+
+/// An error returned when inputs fail to merge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MergeError {
+    /// No headers were given.
+    Empty,
+    /// The number of headers does not match the number of inputs.
+    InputCountMismatch {
+        /// The number of headers.
+        header_count: usize,
+        /// The number of inputs.
+        input_count: usize,
+    },
+    /// An info definition conflicts with a definition of the same ID.
+    InfoConflict(String),
+    /// A format definition conflicts with a definition of the same ID.
+    FormatConflict(String),
+    /// A contig length conflicts with the length of a contig of the same ID.
+    ContigConflict(String),
+    /// A sample name is in more than one header.
+    DuplicateSampleName(String),
+    /// The string maps of the merged header are invalid.
+    InvalidStringMaps(header::ParseError),
+}
+
+impl error::Error for MergeError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidStringMaps(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InputCountMismatch {
+                header_count,
+                input_count,
+            } => write!(
+                f,
+                "input count mismatch: expected {header_count}, got {input_count}"
+            ),
+            Self::InfoConflict(id) => write!(f, "conflicting info definitions: {id}"),
+            Self::FormatConflict(id) => write!(f, "conflicting format definitions: {id}"),
+            Self::ContigConflict(id) => write!(f, "conflicting contig lengths: {id}"),
+            Self::DuplicateSampleName(name) => write!(f, "duplicate sample name: {name}"),
+            Self::InvalidStringMaps(_) => f.write_str("invalid string maps"),
+        }
+    }
+}
+
+/// Merges headers into a single header.
+///
+/// The merged header starts as a copy of the first header. Info, filter, format, alternative
+/// allele, and contig records of the remaining headers are appended when their IDs are not yet
+/// defined, and sample names are concatenated in input order. Record indices (`IDX`) are removed.
+///
+/// # Errors
+///
+/// An error is returned if an info or format definition of the same ID has a different number or
+/// type, if a contig of the same ID has a different length, or if a sample name is in more than
+/// one header.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::{self as vcf, variant::merge};
+///
+/// let a = vcf::Header::builder().add_sample_name("sample0").build();
+/// let b = vcf::Header::builder().add_sample_name("sample1").build();
+///
+/// let header = merge::merge_headers(&[a, b])?;
+///
+/// let expected = vcf::Header::builder()
+///     .add_sample_name("sample0")
+///     .add_sample_name("sample1")
+///     .build();
+///
+/// assert_eq!(header, expected);
+/// # Ok::<_, merge::MergeError>(())
+/// ```
+pub fn merge_headers(headers: &[Header]) -> Result<Header, MergeError> {
+    let (first, rest) = headers.split_first().ok_or(MergeError::Empty)?;
+
+    let mut header = first.clone();
+
+    for h in rest {
+        for (id, info) in h.infos() {
+            match header.infos().get(id) {
+                Some(i) if i.number() != info.number() || i.ty() != info.ty() => {
+                    return Err(MergeError::InfoConflict(id.clone()));
+                }
+                Some(_) => {}
+                None => {
+                    header.infos_mut().insert(id.clone(), info.clone());
+                }
+            }
+        }
+
+        for (id, filter) in h.filters() {
+            if !header.filters().contains_key(id) {
+                header.filters_mut().insert(id.clone(), filter.clone());
+            }
+        }
+
+        for (id, format) in h.formats() {
+            match header.formats().get(id) {
+                Some(f) if f.number() != format.number() || f.ty() != format.ty() => {
+                    return Err(MergeError::FormatConflict(id.clone()));
+                }
+                Some(_) => {}
+                None => {
+                    header.formats_mut().insert(id.clone(), format.clone());
+                }
+            }
+        }
+
+        for (id, alternative_allele) in h.alternative_alleles() {
+            if !header.alternative_alleles().contains_key(id) {
+                header
+                    .alternative_alleles_mut()
+                    .insert(id.clone(), alternative_allele.clone());
+            }
+        }
+
+        for (id, contig) in h.contigs() {
+            match header.contigs_mut().get_mut(id) {
+                Some(c) => match (c.length(), contig.length()) {
+                    (Some(m), Some(n)) if m != n => {
+                        return Err(MergeError::ContigConflict(id.clone()));
+                    }
+                    (None, Some(n)) => *c.length_mut() = Some(n),
+                    _ => {}
+                },
+                None => {
+                    header.contigs_mut().insert(id.clone(), contig.clone());
+                }
+            }
+        }
+
+        for sample_name in h.sample_names() {
+            if !header.sample_names_mut().insert(sample_name.clone()) {
+                return Err(MergeError::DuplicateSampleName(sample_name.clone()));
+            }
+        }
+    }
+
+    for info in header.infos_mut().values_mut() {
+        *info.idx_mut() = None;
+    }
+
+    for filter in header.filters_mut().values_mut() {
+        *filter.idx_mut() = None;
+    }
+
+    for format in header.formats_mut().values_mut() {
+        *format.idx_mut() = None;
+    }
+
+    for contig in header.contigs_mut().values_mut() {
+        *contig.idx_mut() = None;
+    }
+
+    *header.string_maps_mut() =
+        StringMaps::try_from(&header).map_err(MergeError::InvalidStringMaps)?;
+
+    Ok(header)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{
+        map::{info, Contig, Info},
+        Map,
+    };
+
+    #[test]
+    fn test_merge_headers() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .add_info("DP", Map::<Info>::from("DP"))
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_sample_name("sample0")
+            .build();
+
+        let b = Header::builder()
+            .add_info("DP", Map::<Info>::from("DP"))
+            .add_info("AF", Map::<Info>::from("AF"))
+            .add_contig("sq0", Map::<Contig>::builder().set_length(8).build()?)
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_sample_name("sample1")
+            .build();
+
+        let actual = merge_headers(&[a, b])?;
+
+        assert_eq!(
+            actual.infos().keys().collect::<Vec<_>>(),
+            [&String::from("DP"), &String::from("AF")]
+        );
+        assert_eq!(
+            actual.contigs().keys().collect::<Vec<_>>(),
+            [&String::from("sq0"), &String::from("sq1")]
+        );
+        assert_eq!(actual.contigs()["sq0"].length(), Some(8));
+        assert_eq!(actual.sample_names().len(), 2);
+
+        assert_eq!(merge_headers(&[]), Err(MergeError::Empty));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_headers_with_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        let a = Header::builder()
+            .add_info("DP", Map::<Info>::from("DP"))
+            .build();
+        let b = Header::builder()
+            .add_info(
+                "DP",
+                Map::<Info>::new(info::Number::Count(1), info::Type::Float, ""),
+            )
+            .build();
+        assert_eq!(
+            merge_headers(&[a, b]),
+            Err(MergeError::InfoConflict(String::from("DP")))
+        );
+
+        let a = Header::builder()
+            .add_contig("sq0", Map::<Contig>::builder().set_length(8).build()?)
+            .build();
+        let b = Header::builder()
+            .add_contig("sq0", Map::<Contig>::builder().set_length(13).build()?)
+            .build();
+        assert_eq!(
+            merge_headers(&[a, b]),
+            Err(MergeError::ContigConflict(String::from("sq0")))
+        );
+
+        let a = Header::builder().add_sample_name("sample0").build();
+        let b = Header::builder().add_sample_name("sample0").build();
+        assert_eq!(
+            merge_headers(&[a, b]),
+            Err(MergeError::DuplicateSampleName(String::from("sample0")))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,430 @@
+//! Variant record ordering.
+//!
+//! Records are ordered by the declaration order of their reference sequences in the header
+//! contigs, then by position, then by reference bases and alternate bases.
+
+use std::{cmp::Ordering, io};
+
+use indexmap::IndexSet;
+use noodles_core::Position;
+
+use super::Record;
+use crate::Header;
+
+/// A variant record sort key.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::{order::Key, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq1", Map::<Contig>::new())
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let a = RecordBuf::builder()
+///     .set_reference_sequence_name("sq1")
+///     .set_variant_start(Position::try_from(13)?)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// let b = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_reference_bases("A")
+///     .build();
+///
+/// assert!(Key::new(&header, &a)? < Key::new(&header, &b)?);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Key {
+    reference_sequence_id: usize,
+    variant_start: Option<Position>,
+    reference_bases: String,
+    alternate_bases: Vec<String>,
+}
+
+impl Key {
+    /// Builds a sort key from a record.
+    ///
+    /// This fails if the reference sequence of the record is not a contig in the header.
+    pub fn new(header: &Header, record: &dyn Record) -> io::Result<Self> {
+        let name = record.reference_sequence_name(header)?;
+
+        let reference_sequence_id = header.contigs().get_index_of(name).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("undeclared contig: {name}"),
+            )
+        })?;
+
+        Self::with_reference_sequence_id(reference_sequence_id, record)
+    }
+
+    fn with_reference_sequence_id(
+        reference_sequence_id: usize,
+        record: &dyn Record,
+    ) -> io::Result<Self> {
+        let variant_start = record.variant_start().transpose()?;
+
+        let reference_bases = record
+            .reference_bases()
+            .iter()
+            .map(|result| result.map(char::from))
+            .collect::<io::Result<_>>()?;
+
+        let alternate_bases = record
+            .alternate_bases()
+            .iter()
+            .map(|result| result.map(String::from))
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            reference_sequence_id,
+            variant_start,
+            reference_bases,
+            alternate_bases,
+        })
+    }
+
+    /// Returns the index of the reference sequence in the header contigs.
+    pub fn reference_sequence_id(&self) -> usize {
+        self.reference_sequence_id
+    }
+
+    /// Returns the variant start position.
+    pub fn variant_start(&self) -> Option<Position> {
+        self.variant_start
+    }
+}
+
+/// Compares two records by header contig order, position, and alleles.
+///
+/// This fails if the reference sequence of either record is not a contig in the header.
+///
+/// # Examples
+///
+/// ```
+/// use std::cmp::Ordering;
+///
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::{order, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .build();
+///
+/// let a = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(vec![String::from("C")].into())
+///     .build();
+///
+/// let b = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(8)?)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(vec![String::from("G")].into())
+///     .build();
+///
+/// assert_eq!(order::compare(&header, &a, &b)?, Ordering::Less);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn compare(header: &Header, a: &dyn Record, b: &dyn Record) -> io::Result<Ordering> {
+    let a = Key::new(header, a)?;
+    let b = Key::new(header, b)?;
+    Ok(a.cmp(&b))
+}
+
+/// An iterator adaptor that checks that records are sorted.
+///
+/// Records must be ordered by reference sequence, in header contig order, and then by position.
+/// Records on reference sequences that are not contigs in the header are ordered after all
+/// declared contigs, in the order they are first seen. Records at the same position may be in any
+/// order.
+///
+/// An out-of-order record results in an error with the [`io::ErrorKind::InvalidData`] kind.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+///
+/// use noodles_core::Position;
+/// use noodles_vcf::{self as vcf, variant::{order::CheckSorted, RecordBuf}};
+///
+/// let header = vcf::Header::default();
+///
+/// let build_record = |position| -> Result<RecordBuf, Box<dyn std::error::Error>> {
+///     Ok(RecordBuf::builder()
+///         .set_reference_sequence_name("sq0")
+///         .set_variant_start(Position::try_from(position)?)
+///         .set_reference_bases("A")
+///         .build())
+/// };
+///
+/// let records = [build_record(13)?, build_record(8)?];
+/// let mut iter = CheckSorted::new(&header, records.into_iter().map(Ok));
+///
+/// assert!(iter.next().transpose()?.is_some());
+/// assert!(matches!(iter.next(), Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct CheckSorted<'h, I> {
+    header: &'h Header,
+    records: I,
+    reference_sequence_names: IndexSet<String>,
+    last_key: Option<(usize, Option<Position>)>,
+}
+
+impl<'h, I, R> CheckSorted<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates an iterator adaptor that checks that records are sorted.
+    pub fn new(header: &'h Header, records: I) -> Self {
+        Self {
+            header,
+            records,
+            reference_sequence_names: header.contigs().keys().cloned().collect(),
+            last_key: None,
+        }
+    }
+
+    fn check(&mut self, record: &R) -> io::Result<()> {
+        let name = record.reference_sequence_name(self.header)?;
+
+        let reference_sequence_id = match self.reference_sequence_names.get_index_of(name) {
+            Some(i) => i,
+            None => self.reference_sequence_names.insert_full(name.into()).0,
+        };
+
+        let variant_start = record.variant_start().transpose()?;
+        let key = (reference_sequence_id, variant_start);
+
+        match self.last_key.replace(key) {
+            Some(last_key) if key < last_key => {
+                let (last_reference_sequence_id, last_variant_start) = last_key;
+
+                let last_name = self
+                    .reference_sequence_names
+                    .get_index(last_reference_sequence_id)
+                    .map(|s| s.as_str())
+                    .unwrap_or_default();
+
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "unsorted record: {}:{} after {}:{}",
+                        name,
+                        format_position(variant_start),
+                        last_name,
+                        format_position(last_variant_start),
+                    ),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'h, I, R> Iterator for CheckSorted<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.records.next()? {
+            Ok(record) => Some(self.check(&record).map(|_| record)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn format_position(position: Option<Position>) -> String {
+    position
+        .map(|p| p.to_string())
+        .unwrap_or_else(|| String::from("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        header::record::value::{map::Contig, Map},
+        variant::{record_buf::AlternateBases, RecordBuf},
+    };
+
+    #[test]
+    fn test_compare() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(8)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(8)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![
+                    String::from("C"),
+                    String::from("G"),
+                ]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(8)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(5)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .build(),
+        ];
+
+        let mut actual = records.clone();
+        actual.sort_by(|a, b| compare(&header, a, b).unwrap());
+
+        let expected = [
+            records[4].clone(),
+            records[3].clone(),
+            records[2].clone(),
+            records[1].clone(),
+            records[0].clone(),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq2")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("A")
+            .build();
+        assert!(matches!(
+            compare(&header, &records[0], &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_sorted() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(5)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(5)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq2")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq3")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+        ];
+
+        let iter = CheckSorted::new(&header, records.into_iter().map(Ok));
+        assert_eq!(iter.collect::<io::Result<Vec<_>>>()?.len(), 5);
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq1")
+                .set_variant_start(Position::try_from(5)?)
+                .set_reference_bases("A")
+                .build(),
+        ];
+
+        let mut iter = CheckSorted::new(&header, records.into_iter().map(Ok));
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq2")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq3")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("A")
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq2")
+                .set_variant_start(Position::try_from(2)?)
+                .set_reference_bases("A")
+                .build(),
+        ];
+
+        let mut iter = CheckSorted::new(&header, records.into_iter().map(Ok));
+        assert!(iter.next().transpose()?.is_some());
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use std::io;
+use std::{borrow::Cow, io};
 
 /// Variant record info field array value values.
 #[allow(clippy::len_without_is_empty)]
@@ -69,17 +69,17 @@ impl<'a> Values<'a, char> for &'a str {
     }
 }
 
-impl<'a> Values<'a, &'a str> for &'a str {
+impl<'a> Values<'a, Cow<'a, str>> for &'a str {
     fn len(&self) -> usize {
         count(self)
     }
 
-    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<&'a str>>> + '_> {
+    fn iter(&self) -> Box<dyn Iterator<Item = io::Result<Option<Cow<'a, str>>>> + '_> {
         Box::new(
             self.split(DELIMITER)
                 .map(|s| match s {
                     MISSING => None,
-                    _ => Some(s),
+                    _ => Some(Cow::from(s)),
                 })
                 .map(Ok),
         )
@@ -3,7 +3,7 @@
 pub mod array;
 pub mod genotype;
 
-use std::io;
+use std::{borrow::Cow, io};
 
 pub use self::{array::Array, genotype::Genotype};
 
@@ -17,7 +17,7 @@ pub enum Value<'a> {
     /// A character.
     Character(char),
     /// A string.
-    String(&'a str),
+    String(Cow<'a, str>),
     /// A genotype.
     Genotype(Box<dyn Genotype + 'a>),
     /// An array.
@@ -1,11 +1,12 @@
 //! Variant record buffer.
 
-mod alternate_bases;
+pub mod alternate_bases;
 pub mod builder;
 mod convert;
 mod filters;
 pub mod ids;
 pub mod info;
+pub mod norm;
 pub mod samples;
 
 use std::io;
@@ -1,5 +1,11 @@
+//! Variant record alternate bases buffer.
+
+pub mod breakend;
+
 use std::io;
 
+pub use self::breakend::Breakend;
+
 /// A variant record alternate bases buffer.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AlternateBases(Vec<String>);
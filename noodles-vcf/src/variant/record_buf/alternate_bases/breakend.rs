@@ -0,0 +1,646 @@
+//! Variant record alternate bases breakend.
+
+use std::{error, fmt, num, str::FromStr};
+
+use noodles_core::Position;
+
+use crate::variant::{
+    record::info::field::key,
+    record_buf::{
+        info::field::{value::Array, Value},
+        RecordBuf,
+    },
+};
+
+const MISSING: char = '.';
+const OPEN_BRACKET: char = '[';
+const CLOSE_BRACKET: char = ']';
+
+/// The side of a breakend join.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Side {
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+/// A breakend mate.
+///
+/// This is the `p` component of a breakend replacement string, e.g., `chr2:321682` in
+/// `G]chr2:321682]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mate {
+    reference_sequence_name: String,
+    position: Position,
+    is_assembled_contig: bool,
+    extension: Side,
+}
+
+impl Mate {
+    /// Creates a breakend mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::new("sq0", Position::MIN, Side::Right);
+    /// ```
+    pub fn new<N>(reference_sequence_name: N, position: Position, extension: Side) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            reference_sequence_name: reference_sequence_name.into(),
+            position,
+            is_assembled_contig: false,
+            extension,
+        }
+    }
+
+    /// Creates a breakend mate that refers to an assembled contig.
+    ///
+    /// These are written with the reference sequence name in angle brackets, e.g., `<ctg1>:7`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::assembled_contig("ctg1", Position::MIN, Side::Right);
+    /// assert!(mate.is_assembled_contig());
+    /// ```
+    pub fn assembled_contig<N>(
+        reference_sequence_name: N,
+        position: Position,
+        extension: Side,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            is_assembled_contig: true,
+            ..Self::new(reference_sequence_name, position, extension)
+        }
+    }
+
+    /// Returns the reference sequence name of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::new("sq0", Position::MIN, Side::Right);
+    /// assert_eq!(mate.reference_sequence_name(), "sq0");
+    /// ```
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the position of the mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::new("sq0", Position::MIN, Side::Right);
+    /// assert_eq!(mate.position(), Position::MIN);
+    /// ```
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns whether the mate refers to an assembled contig.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::new("sq0", Position::MIN, Side::Right);
+    /// assert!(!mate.is_assembled_contig());
+    /// ```
+    pub fn is_assembled_contig(&self) -> bool {
+        self.is_assembled_contig
+    }
+
+    /// Returns the side of the mate position that the joined piece extends to.
+    ///
+    /// This is [`Side::Right`] when the mate is enclosed in open brackets (`[p[`) and
+    /// [`Side::Left`] when enclosed in close brackets (`]p]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::breakend::{Mate, Side};
+    /// let mate = Mate::new("sq0", Position::MIN, Side::Right);
+    /// assert_eq!(mate.extension(), Side::Right);
+    /// ```
+    pub fn extension(&self) -> Side {
+        self.extension
+    }
+}
+
+/// A variant record alternate bases breakend.
+///
+/// This is a typed breakend replacement string (VCF 4.4 § 5.4 "Specifying complex
+/// rearrangements with breakends"), e.g., `G]chr2:321682]`, `[chr17:198983[A`, or, for a single
+/// breakend, `G.`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakend {
+    bases: String,
+    side: Side,
+    mate: Option<Mate>,
+}
+
+impl Breakend {
+    /// Creates a breakend.
+    ///
+    /// `side` is the side of the join the bases are on, i.e., [`Side::Left`] when the bases are
+    /// written before the mate (`t[p[`) and [`Side::Right`] when written after (`]p]t`). A mate
+    /// of `None` is a single breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record_buf::alternate_bases::{
+    ///     breakend::{Mate, Side},
+    ///     Breakend,
+    /// };
+    ///
+    /// let mate = Mate::new("sq0", Position::try_from(8)?, Side::Left);
+    /// let breakend = Breakend::new("G", Side::Left, Some(mate));
+    /// assert_eq!(breakend.to_string(), "G]sq0:8]");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn new<B>(bases: B, side: Side, mate: Option<Mate>) -> Self
+    where
+        B: Into<String>,
+    {
+        Self {
+            bases: bases.into(),
+            side,
+            mate,
+        }
+    }
+
+    /// Returns the bases.
+    ///
+    /// This includes the reference base and any inserted sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::alternate_bases::Breakend;
+    /// let breakend: Breakend = "GTC[sq0:8[".parse()?;
+    /// assert_eq!(breakend.bases(), "GTC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn bases(&self) -> &str {
+        &self.bases
+    }
+
+    /// Returns the side of the join the bases are on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::alternate_bases::{breakend::Side, Breakend};
+    /// let breakend: Breakend = "]sq0:8]G".parse()?;
+    /// assert_eq!(breakend.side(), Side::Right);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Returns the mate.
+    ///
+    /// This is `None` for a single breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::alternate_bases::Breakend;
+    /// let breakend: Breakend = "G.".parse()?;
+    /// assert!(breakend.mate().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn mate(&self) -> Option<&Mate> {
+        self.mate.as_ref()
+    }
+
+    /// Returns whether the breakend is a single breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::alternate_bases::Breakend;
+    /// let breakend: Breakend = ".G".parse()?;
+    /// assert!(breakend.is_single());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_single(&self) -> bool {
+        self.mate.is_none()
+    }
+
+    /// Returns the inserted sequence.
+    ///
+    /// This is the bases excluding the reference base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::alternate_bases::Breakend;
+    ///
+    /// let breakend: Breakend = "GTC[sq0:8[".parse()?;
+    /// assert_eq!(breakend.inserted_sequence(), "TC");
+    ///
+    /// let breakend: Breakend = "]sq0:8]TCG".parse()?;
+    /// assert_eq!(breakend.inserted_sequence(), "TC");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn inserted_sequence(&self) -> &str {
+        match self.side {
+            Side::Left => self.bases.get(1..).unwrap_or_default(),
+            Side::Right => {
+                let end = self.bases.len().saturating_sub(1);
+                self.bases.get(..end).unwrap_or_default()
+            }
+        }
+    }
+}
+
+impl fmt::Display for Breakend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.mate, self.side) {
+            (None, Side::Left) => write!(f, "{}{MISSING}", self.bases),
+            (None, Side::Right) => write!(f, "{MISSING}{}", self.bases),
+            (Some(mate), Side::Left) => write!(f, "{}{mate}", self.bases),
+            (Some(mate), Side::Right) => write!(f, "{mate}{}", self.bases),
+        }
+    }
+}
+
+impl fmt::Display for Mate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bracket = match self.extension {
+            Side::Left => CLOSE_BRACKET,
+            Side::Right => OPEN_BRACKET,
+        };
+
+        if self.is_assembled_contig {
+            write!(
+                f,
+                "{bracket}<{}>:{}{bracket}",
+                self.reference_sequence_name, self.position
+            )
+        } else {
+            write!(
+                f,
+                "{bracket}{}:{}{bracket}",
+                self.reference_sequence_name, self.position
+            )
+        }
+    }
+}
+
+/// An error returned when a breakend fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is not a breakend.
+    Invalid,
+    /// The bases are missing.
+    MissingBases,
+    /// The mate is invalid.
+    InvalidMate,
+    /// The mate position is invalid.
+    InvalidMatePosition(num::ParseIntError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidMatePosition(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::MissingBases => f.write_str("missing bases"),
+            Self::InvalidMate => f.write_str("invalid mate"),
+            Self::InvalidMatePosition(_) => f.write_str("invalid mate position"),
+        }
+    }
+}
+
+impl FromStr for Breakend {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (bases, side, mate) = if let Some(bases) = s.strip_suffix(MISSING) {
+            (bases, Side::Left, None)
+        } else if let Some(bases) = s.strip_prefix(MISSING) {
+            (bases, Side::Right, None)
+        } else if let Some(bracket) = s.chars().next().filter(|&c| is_bracket(c)) {
+            let t = &s[1..];
+            let i = t.find(bracket).ok_or(ParseError::Invalid)?;
+            let mate = parse_mate(&t[..i], bracket)?;
+            (&t[i + 1..], Side::Right, Some(mate))
+        } else if let Some(bracket) = s.chars().last().filter(|&c| is_bracket(c)) {
+            let t = &s[..s.len() - 1];
+            let i = t.find(bracket).ok_or(ParseError::Invalid)?;
+            let mate = parse_mate(&t[i + 1..], bracket)?;
+            (&t[..i], Side::Left, Some(mate))
+        } else {
+            return Err(ParseError::Invalid);
+        };
+
+        if bases.is_empty() {
+            return Err(ParseError::MissingBases);
+        } else if bases.contains(is_bracket) || bases.contains(MISSING) {
+            return Err(ParseError::Invalid);
+        }
+
+        Ok(Self::new(bases, side, mate))
+    }
+}
+
+fn is_bracket(c: char) -> bool {
+    matches!(c, OPEN_BRACKET | CLOSE_BRACKET)
+}
+
+fn parse_mate(s: &str, bracket: char) -> Result<Mate, ParseError> {
+    let extension = match bracket {
+        OPEN_BRACKET => Side::Right,
+        _ => Side::Left,
+    };
+
+    let (name, raw_position) = s.rsplit_once(':').ok_or(ParseError::InvalidMate)?;
+
+    if name.is_empty() {
+        return Err(ParseError::InvalidMate);
+    }
+
+    let position = raw_position
+        .parse()
+        .map_err(ParseError::InvalidMatePosition)?;
+
+    match name.strip_prefix('<').and_then(|t| t.strip_suffix('>')) {
+        Some(contig_name) => Ok(Mate::assembled_contig(contig_name, position, extension)),
+        None => Ok(Mate::new(name, position, extension)),
+    }
+}
+
+/// Returns the records that are mates of the given record.
+///
+/// Mates are the records whose IDs are listed in the `MATEID` info field of the given record.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::{
+///     record::info::field::key,
+///     record_buf::{alternate_bases::breakend, info::field::Value, RecordBuf},
+/// };
+///
+/// let record = RecordBuf::builder()
+///     .set_ids([String::from("bnd0")].into_iter().collect())
+///     .set_info(
+///         [(String::from(key::MATE_BREAKEND_IDS), Some(Value::from("bnd1")))]
+///             .into_iter()
+///             .collect(),
+///     )
+///     .build();
+///
+/// let mate = RecordBuf::builder()
+///     .set_ids([String::from("bnd1")].into_iter().collect())
+///     .build();
+///
+/// let records = [record.clone(), mate.clone()];
+/// let mates: Vec<_> = breakend::find_mates(&record, &records).collect();
+/// assert_eq!(mates, [&mate]);
+/// ```
+pub fn find_mates<'r, I>(record: &RecordBuf, records: I) -> impl Iterator<Item = &'r RecordBuf>
+where
+    I: IntoIterator<Item = &'r RecordBuf>,
+{
+    let mate_ids = info_strings(record, key::MATE_BREAKEND_IDS);
+
+    records.into_iter().filter(move |r| {
+        r.ids()
+            .as_ref()
+            .iter()
+            .any(|id| mate_ids.iter().any(|mate_id| mate_id == id))
+    })
+}
+
+/// Returns the other records that are in the same event as the given record.
+///
+/// Records are in the same event when they share an `EVENT` info field value.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::{
+///     record::info::field::key,
+///     record_buf::{alternate_bases::breakend, info::field::Value, RecordBuf},
+/// };
+///
+/// let build_record = |id: &str| {
+///     RecordBuf::builder()
+///         .set_ids([String::from(id)].into_iter().collect())
+///         .set_info(
+///             [(String::from(key::BREAKEND_EVENT_ID), Some(Value::from("event0")))]
+///                 .into_iter()
+///                 .collect(),
+///         )
+///         .build()
+/// };
+///
+/// let records = [build_record("bnd0"), build_record("bnd1"), RecordBuf::default()];
+/// let event_records: Vec<_> = breakend::find_event_records(&records[0], &records).collect();
+/// assert_eq!(event_records, [&records[1]]);
+/// ```
+pub fn find_event_records<'r, I>(
+    record: &RecordBuf,
+    records: I,
+) -> impl Iterator<Item = &'r RecordBuf>
+where
+    I: IntoIterator<Item = &'r RecordBuf>,
+{
+    let event_ids = info_strings(record, key::BREAKEND_EVENT_ID);
+    let record: *const RecordBuf = record;
+
+    records.into_iter().filter(move |r| {
+        !std::ptr::eq(*r, record)
+            && !event_ids.is_empty()
+            && info_strings(r, key::BREAKEND_EVENT_ID) == event_ids
+    })
+}
+
+fn info_strings(record: &RecordBuf, key: &str) -> Vec<String> {
+    match record.info().get(key) {
+        Some(Some(Value::String(s))) => vec![s.clone()],
+        Some(Some(Value::Array(Array::String(values)))) => {
+            values.iter().flatten().cloned().collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() -> Result<(), Box<dyn std::error::Error>> {
+        let position = Position::try_from(8)?;
+
+        let breakend = Breakend::new(
+            "G",
+            Side::Left,
+            Some(Mate::new("sq0", position, Side::Left)),
+        );
+        assert_eq!(breakend.to_string(), "G]sq0:8]");
+
+        let breakend = Breakend::new(
+            "G",
+            Side::Left,
+            Some(Mate::new("sq0", position, Side::Right)),
+        );
+        assert_eq!(breakend.to_string(), "G[sq0:8[");
+
+        let breakend = Breakend::new(
+            "G",
+            Side::Right,
+            Some(Mate::new("sq0", position, Side::Left)),
+        );
+        assert_eq!(breakend.to_string(), "]sq0:8]G");
+
+        let breakend = Breakend::new(
+            "G",
+            Side::Right,
+            Some(Mate::assembled_contig("ctg0", position, Side::Right)),
+        );
+        assert_eq!(breakend.to_string(), "[<ctg0>:8[G");
+
+        let breakend = Breakend::new("G", Side::Left, None);
+        assert_eq!(breakend.to_string(), "G.");
+
+        let breakend = Breakend::new("G", Side::Right, None);
+        assert_eq!(breakend.to_string(), ".G");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let position = Position::try_from(8)?;
+
+        assert_eq!(
+            "G]sq0:8]".parse(),
+            Ok(Breakend::new(
+                "G",
+                Side::Left,
+                Some(Mate::new("sq0", position, Side::Left))
+            ))
+        );
+
+        assert_eq!(
+            "GTC[sq0:8[".parse(),
+            Ok(Breakend::new(
+                "GTC",
+                Side::Left,
+                Some(Mate::new("sq0", position, Side::Right))
+            ))
+        );
+
+        assert_eq!(
+            "]HLA:A*01:01:8]G".parse(),
+            Ok(Breakend::new(
+                "G",
+                Side::Right,
+                Some(Mate::new("HLA:A*01:01", position, Side::Left))
+            ))
+        );
+
+        assert_eq!(
+            "[<ctg0>:8[G".parse(),
+            Ok(Breakend::new(
+                "G",
+                Side::Right,
+                Some(Mate::assembled_contig("ctg0", position, Side::Right))
+            ))
+        );
+
+        assert_eq!("G.".parse(), Ok(Breakend::new("G", Side::Left, None)));
+        assert_eq!(".G".parse(), Ok(Breakend::new("G", Side::Right, None)));
+
+        assert_eq!("".parse::<Breakend>(), Err(ParseError::Empty));
+        assert_eq!("G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("<DEL>".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G]sq0:8[".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("]sq0:8]".parse::<Breakend>(), Err(ParseError::MissingBases));
+        assert_eq!(".".parse::<Breakend>(), Err(ParseError::MissingBases));
+        assert_eq!("G]sq0]".parse::<Breakend>(), Err(ParseError::InvalidMate));
+        assert_eq!("G]:8]".parse::<Breakend>(), Err(ParseError::InvalidMate));
+        assert!(matches!(
+            "G]sq0:ndls]".parse::<Breakend>(),
+            Err(ParseError::InvalidMatePosition(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_mates() {
+        let build_record = |id: &str, mate_ids: Option<Value>| {
+            RecordBuf::builder()
+                .set_ids([String::from(id)].into_iter().collect())
+                .set_info(
+                    [(String::from(key::MATE_BREAKEND_IDS), mate_ids)]
+                        .into_iter()
+                        .collect(),
+                )
+                .build()
+        };
+
+        let records = [
+            build_record(
+                "bnd0",
+                Some(Value::from(vec![
+                    Some(String::from("bnd1")),
+                    Some(String::from("bnd2")),
+                ])),
+            ),
+            build_record("bnd1", Some(Value::from("bnd0"))),
+            build_record("bnd2", Some(Value::from("bnd0"))),
+            build_record("bnd3", None),
+        ];
+
+        let actual: Vec<_> = find_mates(&records[0], &records).collect();
+        assert_eq!(actual, [&records[1], &records[2]]);
+
+        let actual: Vec<_> = find_mates(&records[1], &records).collect();
+        assert_eq!(actual, [&records[0]]);
+
+        assert_eq!(find_mates(&records[3], &records).count(), 0);
+    }
+}
@@ -1,8 +1,110 @@
 //! VCF record builder.
 
+use std::{error, fmt};
+
 use noodles_core::Position;
 
 use super::{AlternateBases, Filters, Ids, Info, RecordBuf, Samples};
+use crate::Header;
+
+/// An error returned when a VCF record fails to build.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BuildError {
+    /// The reference sequence name is missing.
+    MissingReferenceSequenceName,
+    /// The reference sequence name is not defined in the header contigs.
+    UndefinedReferenceSequenceName(String),
+    /// The reference bases are missing.
+    MissingReferenceBases,
+    /// The variant end is past the end of the reference sequence.
+    InvalidVariantEnd {
+        /// The variant end.
+        variant_end: usize,
+        /// The length of the reference sequence.
+        reference_sequence_length: usize,
+    },
+    /// A filter is not defined in the header.
+    UndefinedFilter(String),
+    /// An info field key is not defined in the header.
+    UndefinedInfoField(String),
+    /// An info field value type does not match its header definition.
+    InfoFieldTypeMismatch(String),
+    /// An info field value count does not match its header definition.
+    InfoFieldCountMismatch {
+        /// The info field key.
+        key: String,
+        /// The number of values expected by the header definition.
+        expected: usize,
+        /// The number of values.
+        actual: usize,
+    },
+    /// A samples key is not defined in the header.
+    UndefinedFormat(String),
+    /// A sample value type does not match its header definition.
+    FormatTypeMismatch(String),
+    /// A sample value count does not match its header definition.
+    FormatCountMismatch {
+        /// The samples key.
+        key: String,
+        /// The number of values expected by the header definition.
+        expected: usize,
+        /// The number of values.
+        actual: usize,
+    },
+    /// The number of samples does not match the number of sample names in the header.
+    SampleCountMismatch {
+        /// The number of sample names in the header.
+        expected: usize,
+        /// The number of samples.
+        actual: usize,
+    },
+}
+
+impl error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingReferenceSequenceName => write!(f, "missing reference sequence name"),
+            Self::UndefinedReferenceSequenceName(name) => {
+                write!(f, "undefined reference sequence name: {name}")
+            }
+            Self::MissingReferenceBases => write!(f, "missing reference bases"),
+            Self::InvalidVariantEnd {
+                variant_end,
+                reference_sequence_length,
+            } => write!(
+                f,
+                "invalid variant end: expected <= {reference_sequence_length}, got {variant_end}"
+            ),
+            Self::UndefinedFilter(id) => write!(f, "undefined filter: {id}"),
+            Self::UndefinedInfoField(key) => write!(f, "undefined info field: {key}"),
+            Self::InfoFieldTypeMismatch(key) => write!(f, "info field type mismatch: {key}"),
+            Self::InfoFieldCountMismatch {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "info field count mismatch: {key}: expected {expected}, got {actual}"
+            ),
+            Self::UndefinedFormat(key) => write!(f, "undefined format: {key}"),
+            Self::FormatTypeMismatch(key) => write!(f, "format type mismatch: {key}"),
+            Self::FormatCountMismatch {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "format count mismatch: {key}: expected {expected}, got {actual}"
+            ),
+            Self::SampleCountMismatch { expected, actual } => write!(
+                f,
+                "sample count mismatch: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
 
 /// A VCF record builder.
 #[derive(Debug, PartialEq)]
@@ -242,6 +344,77 @@ impl Builder {
             samples: self.samples,
         }
     }
+
+    /// Validates the fields against the given header and builds a VCF record.
+    ///
+    /// This checks that
+    ///
+    ///   * the reference sequence name and reference bases are set;
+    ///   * if the header has contigs, the reference sequence name is one of them and, if its
+    ///     length is known, the variant end is within the reference sequence;
+    ///   * all filters other than `PASS` are defined in the header;
+    ///   * all info fields and samples keys are defined in the header, and their values match
+    ///     the types and, where it can be determined, the numbers of the definitions; and
+    ///   * the number of samples is equal to the number of sample names in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info, Map},
+    ///     variant::{
+    ///         record::info::field::key,
+    ///         record_buf::{builder::BuildError, info::field::Value},
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(
+    ///         key::SAMPLES_WITH_DATA_COUNT,
+    ///         Map::<Info>::from(key::SAMPLES_WITH_DATA_COUNT),
+    ///     )
+    ///     .build();
+    ///
+    /// let record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .set_info(
+    ///         [(String::from(key::SAMPLES_WITH_DATA_COUNT), Some(Value::from(2)))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .try_build(&header)?;
+    ///
+    /// let result = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_reference_bases("A")
+    ///     .set_info(
+    ///         [(String::from(key::TOTAL_DEPTH), Some(Value::from(13)))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .try_build(&header);
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(BuildError::UndefinedInfoField(String::from(key::TOTAL_DEPTH)))
+    /// );
+    /// # Ok::<_, BuildError>(())
+    /// ```
+    pub fn try_build(self, header: &Header) -> Result<RecordBuf, BuildError> {
+        let record = self.build();
+
+        let mut errors = Vec::new();
+        validate_record(header, &record, &mut errors);
+
+        match errors.into_iter().next() {
+            Some(e) => Err(e),
+            None => Ok(record),
+        }
+    }
 }
 
 impl Default for Builder {
@@ -260,6 +433,211 @@ impl Default for Builder {
     }
 }
 
+// Validates a record against the header, adding all errors found to `errors`.
+pub(crate) fn validate_record(header: &Header, record: &RecordBuf, errors: &mut Vec<BuildError>) {
+    let reference_sequence_name = record.reference_sequence_name();
+    let reference_bases = record.reference_bases();
+
+    if reference_sequence_name.is_empty() {
+        errors.push(BuildError::MissingReferenceSequenceName);
+    }
+
+    if reference_bases.is_empty() {
+        errors.push(BuildError::MissingReferenceBases);
+    }
+
+    if !reference_sequence_name.is_empty() && !header.contigs().is_empty() {
+        match header.contigs().get(reference_sequence_name) {
+            Some(contig) => {
+                if let (Some(reference_sequence_length), Some(start)) =
+                    (contig.length(), record.variant_start())
+                {
+                    let variant_end =
+                        (usize::from(start) + reference_bases.len()).saturating_sub(1);
+
+                    if variant_end > reference_sequence_length {
+                        errors.push(BuildError::InvalidVariantEnd {
+                            variant_end,
+                            reference_sequence_length,
+                        });
+                    }
+                }
+            }
+            None => errors.push(BuildError::UndefinedReferenceSequenceName(
+                reference_sequence_name.into(),
+            )),
+        }
+    }
+
+    validate_filters(header, record.filters(), errors);
+
+    let alternate_base_count = record.alternate_bases().as_ref().len();
+    validate_info(header, record.info(), alternate_base_count, errors);
+    validate_samples(header, record.samples(), alternate_base_count, errors);
+}
+
+fn validate_filters(header: &Header, filters: &Filters, errors: &mut Vec<BuildError>) {
+    const PASS: &str = "PASS";
+
+    for id in filters.as_ref() {
+        if id != PASS && !header.filters().contains_key(id) {
+            errors.push(BuildError::UndefinedFilter(id.clone()));
+        }
+    }
+}
+
+fn validate_info(
+    header: &Header,
+    info: &Info,
+    alternate_base_count: usize,
+    errors: &mut Vec<BuildError>,
+) {
+    use super::info::field::{value::Array, Value};
+    use crate::header::record::value::map::info::{Number, Type};
+
+    for (key, value) in info.as_ref() {
+        let Some(definition) = header.infos().get(key) else {
+            errors.push(BuildError::UndefinedInfoField(key.clone()));
+            continue;
+        };
+
+        let Some(value) = value else {
+            continue;
+        };
+
+        let (ty, actual) = match value {
+            Value::Integer(_) => (Type::Integer, 1),
+            Value::Float(_) => (Type::Float, 1),
+            Value::Flag => (Type::Flag, 0),
+            Value::Character(_) => (Type::Character, 1),
+            Value::String(_) => (Type::String, 1),
+            Value::Array(Array::Integer(values)) => (Type::Integer, values.len()),
+            Value::Array(Array::Float(values)) => (Type::Float, values.len()),
+            Value::Array(Array::Character(values)) => (Type::Character, values.len()),
+            Value::Array(Array::String(values)) => (Type::String, values.len()),
+        };
+
+        if ty != definition.ty() {
+            errors.push(BuildError::InfoFieldTypeMismatch(key.clone()));
+            continue;
+        }
+
+        let expected = match definition.number() {
+            Number::Count(n) => Some(n),
+            Number::AlternateBases => Some(alternate_base_count),
+            Number::ReferenceAlternateBases => Some(alternate_base_count + 1),
+            Number::Samples => Some(genotype_count(alternate_base_count, DEFAULT_PLOIDY)),
+            Number::Unknown => None,
+        };
+
+        if let Some(expected) = expected {
+            if ty != Type::Flag && actual != expected {
+                errors.push(BuildError::InfoFieldCountMismatch {
+                    key: key.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+}
+
+fn validate_samples(
+    header: &Header,
+    samples: &Samples,
+    alternate_base_count: usize,
+    errors: &mut Vec<BuildError>,
+) {
+    use super::samples::sample::{value::Array, Value};
+    use crate::{
+        header::record::value::map::format::{Number, Type},
+        variant::record::samples::keys::key,
+    };
+
+    let keys = samples.keys();
+
+    for k in keys.as_ref() {
+        if !header.formats().contains_key(k) {
+            errors.push(BuildError::UndefinedFormat(k.clone()));
+        }
+    }
+
+    let sample_count = samples.values().count();
+
+    if sample_count > 0 && sample_count != header.sample_names().len() {
+        errors.push(BuildError::SampleCountMismatch {
+            expected: header.sample_names().len(),
+            actual: sample_count,
+        });
+    }
+
+    for sample in samples.values() {
+        let ploidy = match sample.get(key::GENOTYPE) {
+            Some(Some(Value::Genotype(genotype))) => genotype.ploidy(),
+            _ => DEFAULT_PLOIDY,
+        };
+
+        for (k, value) in keys.as_ref().iter().zip(sample.values()) {
+            let Some(definition) = header.formats().get(k) else {
+                continue;
+            };
+
+            let Some(value) = value else {
+                continue;
+            };
+
+            let (ty, actual) = match value {
+                Value::Integer(_) => (Type::Integer, 1),
+                Value::Float(_) => (Type::Float, 1),
+                Value::Character(_) => (Type::Character, 1),
+                Value::String(_) | Value::Genotype(_) => (Type::String, 1),
+                Value::Array(Array::Integer(values)) => (Type::Integer, values.len()),
+                Value::Array(Array::Float(values)) => (Type::Float, values.len()),
+                Value::Array(Array::Character(values)) => (Type::Character, values.len()),
+                Value::Array(Array::String(values)) => (Type::String, values.len()),
+            };
+
+            if ty != definition.ty() {
+                errors.push(BuildError::FormatTypeMismatch(k.clone()));
+                continue;
+            }
+
+            let expected = match definition.number() {
+                Number::Count(n) => Some(n),
+                Number::AlternateBases => Some(alternate_base_count),
+                Number::ReferenceAlternateBases => Some(alternate_base_count + 1),
+                Number::Samples => Some(genotype_count(alternate_base_count, ploidy)),
+                Number::Ploidy => Some(ploidy),
+                Number::LocalAlternateBases
+                | Number::LocalReferenceAlternateBases
+                | Number::LocalSamples
+                | Number::BaseModifications
+                | Number::Unknown => None,
+            };
+
+            if let Some(expected) = expected {
+                if actual != expected {
+                    errors.push(BuildError::FormatCountMismatch {
+                        key: k.clone(),
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+}
+
+const DEFAULT_PLOIDY: usize = 2;
+
+// § 1.6.2 "Genotype fields" (2023-08-23): The number of genotypes is the number of combinations
+// with repetition of `ploidy` alleles from `alternate_base_count + 1` alleles, i.e.,
+// `(alternate_base_count + ploidy) choose ploidy`.
+fn genotype_count(alternate_base_count: usize, ploidy: usize) -> usize {
+    let n = alternate_base_count + ploidy;
+    (1..=ploidy).fold(1, |acc, k| acc * (n - ploidy + k) / k)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -279,4 +657,210 @@ mod tests {
         assert!(record.info.as_ref().is_empty());
         assert!(record.samples.is_empty());
     }
+
+    #[test]
+    fn test_try_build() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{
+                map::{Contig, Filter, Format, Info},
+                Map,
+            },
+            variant::{
+                record::{info::field::key as info_key, samples::keys::key as format_key},
+                record_buf::{info::field::Value as InfoValue, samples::sample::Value},
+            },
+        };
+
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::builder().set_length(8).build()?)
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_info(
+                info_key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(info_key::ALLELE_FREQUENCIES),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(format_key::GENOTYPE),
+            )
+            .add_format(
+                format_key::READ_DEPTHS,
+                Map::<Format>::from(format_key::READ_DEPTHS),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        let builder = || -> Result<Builder, Box<dyn std::error::Error>> {
+            Ok(Builder::default()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(8)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .set_filters(Filters::pass())
+                .set_info(
+                    [(
+                        String::from(info_key::ALLELE_FREQUENCIES),
+                        Some(InfoValue::from(vec![Some(0.5)])),
+                    )]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_samples(Samples::new(
+                    [
+                        String::from(format_key::GENOTYPE),
+                        String::from(format_key::READ_DEPTHS),
+                    ]
+                    .into_iter()
+                    .collect(),
+                    vec![vec![
+                        Some(Value::Genotype("0/1".parse()?)),
+                        Some(Value::from(vec![Some(5), Some(8)])),
+                    ]],
+                )))
+        };
+
+        assert!(builder()?.try_build(&header).is_ok());
+
+        assert_eq!(
+            builder()?
+                .set_reference_sequence_name("")
+                .try_build(&header),
+            Err(BuildError::MissingReferenceSequenceName)
+        );
+
+        assert_eq!(
+            builder()?
+                .set_reference_sequence_name("sq1")
+                .try_build(&header),
+            Err(BuildError::UndefinedReferenceSequenceName(String::from(
+                "sq1"
+            )))
+        );
+
+        assert_eq!(
+            builder()?.set_reference_bases("").try_build(&header),
+            Err(BuildError::MissingReferenceBases)
+        );
+
+        assert_eq!(
+            builder()?.set_reference_bases("AC").try_build(&header),
+            Err(BuildError::InvalidVariantEnd {
+                variant_end: 9,
+                reference_sequence_length: 8
+            })
+        );
+
+        assert_eq!(
+            builder()?
+                .set_filters([String::from("q20")].into_iter().collect())
+                .try_build(&header),
+            Err(BuildError::UndefinedFilter(String::from("q20")))
+        );
+
+        assert_eq!(
+            builder()?
+                .set_info(
+                    [(
+                        String::from(info_key::TOTAL_DEPTH),
+                        Some(InfoValue::from(13))
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+                .try_build(&header),
+            Err(BuildError::UndefinedInfoField(String::from(
+                info_key::TOTAL_DEPTH
+            )))
+        );
+
+        assert_eq!(
+            builder()?
+                .set_info(
+                    [(
+                        String::from(info_key::ALLELE_FREQUENCIES),
+                        Some(InfoValue::from(vec![Some(1)]))
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+                .try_build(&header),
+            Err(BuildError::InfoFieldTypeMismatch(String::from(
+                info_key::ALLELE_FREQUENCIES
+            )))
+        );
+
+        assert_eq!(
+            builder()?
+                .set_info(
+                    [(
+                        String::from(info_key::ALLELE_FREQUENCIES),
+                        Some(InfoValue::from(vec![Some(0.25), Some(0.5)]))
+                    )]
+                    .into_iter()
+                    .collect()
+                )
+                .try_build(&header),
+            Err(BuildError::InfoFieldCountMismatch {
+                key: String::from(info_key::ALLELE_FREQUENCIES),
+                expected: 1,
+                actual: 2,
+            })
+        );
+
+        assert_eq!(
+            builder()?
+                .set_samples(Samples::new(
+                    [String::from(format_key::READ_DEPTHS)]
+                        .into_iter()
+                        .collect(),
+                    vec![vec![Some(Value::from(vec![Some(5)]))]],
+                ))
+                .try_build(&header),
+            Err(BuildError::FormatCountMismatch {
+                key: String::from(format_key::READ_DEPTHS),
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        assert_eq!(
+            builder()?
+                .set_samples(Samples::new(
+                    [String::from(format_key::CONDITIONAL_GENOTYPE_QUALITY)]
+                        .into_iter()
+                        .collect(),
+                    vec![vec![Some(Value::from(13))]],
+                ))
+                .try_build(&header),
+            Err(BuildError::UndefinedFormat(String::from(
+                format_key::CONDITIONAL_GENOTYPE_QUALITY
+            )))
+        );
+
+        assert_eq!(
+            builder()?
+                .set_samples(Samples::new(
+                    [String::from(format_key::GENOTYPE)].into_iter().collect(),
+                    vec![
+                        vec![Some(Value::Genotype("0/1".parse()?))],
+                        vec![Some(Value::Genotype("0/0".parse()?))],
+                    ],
+                ))
+                .try_build(&header),
+            Err(BuildError::SampleCountMismatch {
+                expected: 1,
+                actual: 2
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_count() {
+        assert_eq!(genotype_count(0, 2), 1);
+        assert_eq!(genotype_count(1, 2), 3);
+        assert_eq!(genotype_count(2, 2), 6);
+        assert_eq!(genotype_count(1, 1), 2);
+        assert_eq!(genotype_count(1, 3), 4);
+    }
 }
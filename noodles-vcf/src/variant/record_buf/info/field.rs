@@ -1,5 +1,6 @@
 //! Variant record info field.
 
+pub mod annotations;
 pub mod value;
 
 pub use self::value::Value;
@@ -0,0 +1,287 @@
+//! Variant record info field annotations.
+//!
+//! This parses packed functional annotations, e.g., VEP `CSQ` and SnpEff `ANN`, into rows of
+//! sub-field values using a [`Schema`] read from the header.
+
+pub mod schema;
+
+use std::{error, fmt};
+
+pub use self::schema::Schema;
+use super::{value::Array, Value};
+use crate::variant::record_buf::Info;
+
+/// The VEP consequence annotations info field key (`CSQ`).
+pub const CSQ: &str = "CSQ";
+
+/// The SnpEff functional annotations info field key (`ANN`).
+pub const ANN: &str = "ANN";
+
+const DELIMITER: char = '|';
+
+/// A variant record info field annotation.
+///
+/// This is a single annotation, typically for one allele and transcript, keyed by its schema.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation<'s> {
+    schema: &'s Schema,
+    values: Vec<Option<String>>,
+}
+
+impl<'s> Annotation<'s> {
+    /// Returns the value of the given sub-field.
+    ///
+    /// This returns `None` if the sub-field is not in the schema and `Some(None)` if the value is
+    /// empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    ///
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("SYMBOL")]);
+    /// let annotation = schema.parse("C|")?;
+    ///
+    /// assert_eq!(annotation.get("Allele"), Some(Some("C")));
+    /// assert_eq!(annotation.get("SYMBOL"), Some(None));
+    /// assert!(annotation.get("IMPACT").is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn get(&self, key: &str) -> Option<Option<&str>> {
+        self.schema
+            .get_index_of(key)
+            .map(|i| self.values[i].as_deref())
+    }
+
+    /// Returns the schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    ///
+    /// let schema = Schema::new(vec![String::from("Allele")]);
+    /// let annotation = schema.parse("C")?;
+    ///
+    /// assert_eq!(annotation.schema(), &schema);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn schema(&self) -> &'s Schema {
+        self.schema
+    }
+
+    /// Returns the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    ///
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("SYMBOL")]);
+    /// let annotation = schema.parse("C|")?;
+    ///
+    /// assert_eq!(annotation.values(), [Some(String::from("C")), None]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn values(&self) -> &[Option<String>] {
+        &self.values
+    }
+
+    /// Returns an iterator over sub-field name-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    ///
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("SYMBOL")]);
+    /// let annotation = schema.parse("C|")?;
+    ///
+    /// let mut iter = annotation.iter();
+    /// assert_eq!(iter.next(), Some(("Allele", Some("C"))));
+    /// assert_eq!(iter.next(), Some(("SYMBOL", None)));
+    /// assert!(iter.next().is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Option<&str>)> {
+        self.schema
+            .keys()
+            .iter()
+            .map(|key| key.as_str())
+            .zip(self.values.iter().map(|value| value.as_deref()))
+    }
+}
+
+/// An error returned when an annotation fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The number of values does not match the number of schema sub-fields.
+    InvalidValueCount {
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+    /// The info field value is not a string or string array.
+    InvalidValueType,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidValueCount { expected, actual } => {
+                write!(f, "invalid value count: expected {expected}, got {actual}")
+            }
+            Self::InvalidValueType => f.write_str("invalid value type"),
+        }
+    }
+}
+
+impl Schema {
+    /// Parses a raw annotation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    ///
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("Consequence")]);
+    /// let annotation = schema.parse("C|missense_variant")?;
+    ///
+    /// assert_eq!(annotation.get("Consequence"), Some(Some("missense_variant")));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn parse(&self, s: &str) -> Result<Annotation<'_>, ParseError> {
+        let values: Vec<_> = s
+            .split(DELIMITER)
+            .map(|t| (!t.is_empty()).then(|| t.into()))
+            .collect();
+
+        if values.len() != self.keys().len() {
+            return Err(ParseError::InvalidValueCount {
+                expected: self.keys().len(),
+                actual: values.len(),
+            });
+        }
+
+        Ok(Annotation {
+            schema: self,
+            values,
+        })
+    }
+}
+
+/// Parses the annotations of the given info field.
+///
+/// Each value of the info field is parsed as a separate annotation. If the info field is missing,
+/// no annotations are returned.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::record_buf::{
+///     info::field::{
+///         annotations::{self, Schema},
+///         Value,
+///     },
+///     Info,
+/// };
+///
+/// let schema = Schema::new(vec![String::from("Allele"), String::from("SYMBOL")]);
+///
+/// let info: Info = [(
+///     String::from(annotations::CSQ),
+///     Some(Value::from(vec![
+///         Some(String::from("C|GENE0")),
+///         Some(String::from("C|GENE1")),
+///     ])),
+/// )]
+/// .into_iter()
+/// .collect();
+///
+/// let annotations = annotations::parse_annotations(&schema, &info, annotations::CSQ)?;
+///
+/// assert_eq!(annotations.len(), 2);
+/// assert_eq!(annotations[1].get("SYMBOL"), Some(Some("GENE1")));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn parse_annotations<'s>(
+    schema: &'s Schema,
+    info: &Info,
+    key: &str,
+) -> Result<Vec<Annotation<'s>>, ParseError> {
+    match info.get(key).flatten() {
+        None => Ok(Vec::new()),
+        Some(Value::String(s)) => schema.parse(s).map(|annotation| vec![annotation]),
+        Some(Value::Array(Array::String(values))) => {
+            values.iter().flatten().map(|s| schema.parse(s)).collect()
+        }
+        Some(_) => Err(ParseError::InvalidValueType),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_schema() -> Schema {
+        Schema::new(vec![
+            String::from("Allele"),
+            String::from("Consequence"),
+            String::from("SYMBOL"),
+        ])
+    }
+
+    #[test]
+    fn test_parse() {
+        let schema = build_schema();
+
+        let annotation = schema.parse("C|missense_variant|").unwrap();
+        assert_eq!(
+            annotation.values(),
+            [
+                Some(String::from("C")),
+                Some(String::from("missense_variant")),
+                None
+            ]
+        );
+
+        assert_eq!(
+            schema.parse("C|missense_variant"),
+            Err(ParseError::InvalidValueCount {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_annotations() -> Result<(), ParseError> {
+        let schema = build_schema();
+
+        let info: Info = [
+            (
+                String::from(CSQ),
+                Some(Value::from("C|missense_variant|GENE0")),
+            ),
+            (String::from(ANN), Some(Value::from(8))),
+        ]
+        .into_iter()
+        .collect();
+
+        let annotations = parse_annotations(&schema, &info, CSQ)?;
+        assert_eq!(annotations.len(), 1);
+        assert_eq!(annotations[0].get("SYMBOL"), Some(Some("GENE0")));
+
+        assert_eq!(
+            parse_annotations(&schema, &info, ANN),
+            Err(ParseError::InvalidValueType)
+        );
+
+        assert!(parse_annotations(&schema, &info, "NDLS")?.is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+//! Variant record info field annotations schema.
+
+use std::{error, fmt};
+
+use crate::{
+    header::record::value::{map::Info, Map},
+    Header,
+};
+
+const DELIMITER: char = '|';
+
+/// A variant record info field annotations schema.
+///
+/// This is the ordered list of sub-field names of a packed annotation, e.g., those listed in the
+/// header INFO description of VEP `CSQ` or SnpEff `ANN` fields.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Schema(Vec<String>);
+
+impl Schema {
+    /// Creates an annotations schema.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("Consequence")]);
+    /// ```
+    pub fn new(keys: Vec<String>) -> Self {
+        Self(keys)
+    }
+
+    /// Reads the annotations schema of the given info field from the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{
+    ///         map::{info::{Number, Type}, Info},
+    ///         Map,
+    ///     },
+    ///     variant::record_buf::info::field::annotations::{self, Schema},
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(
+    ///         annotations::CSQ,
+    ///         Map::<Info>::new(
+    ///             Number::Unknown,
+    ///             Type::String,
+    ///             "Consequence annotations from Ensembl VEP. Format: Allele|Consequence|SYMBOL",
+    ///         ),
+    ///     )
+    ///     .build();
+    ///
+    /// let schema = Schema::from_header(&header, annotations::CSQ)?;
+    /// assert_eq!(schema.keys(), ["Allele", "Consequence", "SYMBOL"]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_header(header: &Header, key: &str) -> Result<Self, ParseError> {
+        header
+            .infos()
+            .get(key)
+            .ok_or_else(|| ParseError::MissingDefinition(key.into()))
+            .and_then(Self::try_from)
+    }
+
+    /// Returns the sub-field names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("Consequence")]);
+    /// assert_eq!(schema.keys(), ["Allele", "Consequence"]);
+    /// ```
+    pub fn keys(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns the index of the given sub-field name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::info::field::annotations::Schema;
+    /// let schema = Schema::new(vec![String::from("Allele"), String::from("Consequence")]);
+    /// assert_eq!(schema.get_index_of("Consequence"), Some(1));
+    /// assert!(schema.get_index_of("SYMBOL").is_none());
+    /// ```
+    pub fn get_index_of(&self, key: &str) -> Option<usize> {
+        self.0.iter().position(|k| k == key)
+    }
+}
+
+/// An error returned when an annotations schema fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The info field definition is missing from the header.
+    MissingDefinition(String),
+    /// The sub-field list is missing from the description.
+    MissingFormat,
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDefinition(key) => write!(f, "missing info definition: {key}"),
+            Self::MissingFormat => f.write_str("missing format"),
+        }
+    }
+}
+
+impl TryFrom<&Map<Info>> for Schema {
+    type Error = ParseError;
+
+    fn try_from(info: &Map<Info>) -> Result<Self, Self::Error> {
+        parse_description(info.description())
+    }
+}
+
+// Parses the sub-field names from a description.
+//
+// VEP writes the names after `Format: `, e.g., `Consequence annotations from Ensembl VEP.
+// Format: Allele|Consequence|IMPACT`. SnpEff writes them in single quotes, e.g., `Functional
+// annotations: 'Allele | Annotation | Annotation_Impact'`.
+fn parse_description(s: &str) -> Result<Schema, ParseError> {
+    const VEP_PREFIX: &str = "Format:";
+    const QUOTE: char = '\'';
+
+    let raw_keys = if let Some((_, t)) = s.split_once(VEP_PREFIX) {
+        t
+    } else if let Some((_, t)) = s.split_once(QUOTE) {
+        t.split_once(QUOTE).map(|(u, _)| u).unwrap_or(t)
+    } else {
+        return Err(ParseError::MissingFormat);
+    };
+
+    let raw_keys = raw_keys.trim().trim_matches(QUOTE);
+
+    if !raw_keys.contains(DELIMITER) {
+        return Err(ParseError::MissingFormat);
+    }
+
+    let keys = raw_keys
+        .split(DELIMITER)
+        .map(|key| key.trim().into())
+        .collect();
+
+    Ok(Schema::new(keys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_description() {
+        assert_eq!(
+            parse_description(
+                "Consequence annotations from Ensembl VEP. Format: Allele|Consequence|IMPACT"
+            ),
+            Ok(Schema::new(vec![
+                String::from("Allele"),
+                String::from("Consequence"),
+                String::from("IMPACT"),
+            ]))
+        );
+
+        assert_eq!(
+            parse_description(
+                "Functional annotations: 'Allele | Annotation | Annotation_Impact | cDNA.pos / cDNA.length' "
+            ),
+            Ok(Schema::new(vec![
+                String::from("Allele"),
+                String::from("Annotation"),
+                String::from("Annotation_Impact"),
+                String::from("cDNA.pos / cDNA.length"),
+            ]))
+        );
+
+        assert_eq!(
+            parse_description("Allele frequency"),
+            Err(ParseError::MissingFormat)
+        );
+        assert_eq!(
+            parse_description("Format: Allele"),
+            Err(ParseError::MissingFormat)
+        );
+    }
+
+    #[test]
+    fn test_from_header() {
+        let header = Header::default();
+        assert_eq!(
+            Schema::from_header(&header, "CSQ"),
+            Err(ParseError::MissingDefinition(String::from("CSQ")))
+        );
+    }
+}
@@ -0,0 +1,938 @@
+//! Variant record buffer normalization.
+//!
+//! This splits multiallelic records into biallelic records and joins biallelic records back into
+//! a multiallelic record, similar to `bcftools norm --multiallelics`, and left-aligns and trims
+//! indels against a reference sequence repository.
+
+mod left_align;
+
+use std::io;
+
+use super::{
+    info::field::{value::Array as InfoArray, Value as InfoValue},
+    samples::sample::{
+        value::{genotype::Allele, Array as SampleArray, Genotype},
+        Value as SampleValue,
+    },
+    AlternateBases, Info, RecordBuf, Samples,
+};
+use crate::{
+    header::record::value::map::{format, info},
+    variant::record::samples::keys::key,
+    Header,
+};
+
+pub use self::left_align::left_align;
+
+pub(crate) const DEFAULT_PLOIDY: usize = 2;
+
+/// Splits a multiallelic record into biallelic records.
+///
+/// One record is returned per alternate base. Info fields and sample values are subset using their
+/// header definitions: `Number=A` values keep the value of the alternate base; `Number=R` values
+/// keep the reference and alternate base values; and `Number=G` values keep the values of the
+/// genotypes composed of only the reference and alternate base. Genotype alleles that refer to
+/// other alternate bases are set to the reference allele. All other fields are copied as is.
+///
+/// Records with at most one alternate base are returned unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::record_buf::{norm, AlternateBases},
+/// };
+///
+/// let header = vcf::Header::default();
+///
+/// let record = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("C"), String::from("G")]))
+///     .build();
+///
+/// let records = norm::split_multiallelic(&header, &record);
+///
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0].alternate_bases().as_ref(), [String::from("C")]);
+/// assert_eq!(records[1].alternate_bases().as_ref(), [String::from("G")]);
+/// ```
+pub fn split_multiallelic(header: &Header, record: &RecordBuf) -> Vec<RecordBuf> {
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if alternate_bases.len() <= 1 {
+        return vec![record.clone()];
+    }
+
+    alternate_bases
+        .iter()
+        .enumerate()
+        .map(|(i, alternate_base)| {
+            let allele = i + 1;
+
+            let mut split_record = record.clone();
+            *split_record.alternate_bases_mut() =
+                AlternateBases::from(vec![alternate_base.clone()]);
+            *split_record.info_mut() = split_info(header, record.info(), allele);
+            *split_record.samples_mut() = split_samples(header, record.samples(), allele);
+
+            split_record
+        })
+        .collect()
+}
+
+/// Joins biallelic records at the same site into a multiallelic record.
+///
+/// This is the inverse of [`split_multiallelic`]. The records must have the same reference
+/// sequence name, variant start, reference bases, and sample keys, and each must have exactly one
+/// alternate base.
+///
+/// The IDs of all records are merged, and the quality score and filters are taken from the first
+/// record. `Number=A` and `Number=R` values are concatenated, and `Number=G` values are gathered,
+/// with values for genotypes that cannot be derived from any one record set to missing. Genotypes
+/// are merged by taking, for each allele, the first record that calls its alternate base. All
+/// other values are taken from the first record that has them.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::record_buf::{norm, AlternateBases},
+/// };
+///
+/// let header = vcf::Header::default();
+///
+/// let build_record = |alternate_base: &str| {
+///     vcf::variant::RecordBuf::builder()
+///         .set_reference_sequence_name("sq0")
+///         .set_variant_start(Position::MIN)
+///         .set_reference_bases("A")
+///         .set_alternate_bases(AlternateBases::from(vec![String::from(alternate_base)]))
+///         .build()
+/// };
+///
+/// let records = [build_record("C"), build_record("G")];
+///
+/// let record = norm::join_biallelic(&header, &records)?;
+///
+/// assert_eq!(
+///     record.alternate_bases().as_ref(),
+///     [String::from("C"), String::from("G")]
+/// );
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn join_biallelic(header: &Header, records: &[RecordBuf]) -> io::Result<RecordBuf> {
+    let Some((first_record, rest)) = records.split_first() else {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, "no records"));
+    };
+
+    for record in records {
+        if record.alternate_bases().as_ref().len() != 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "record is not biallelic",
+            ));
+        }
+    }
+
+    for record in rest {
+        if record.reference_sequence_name() != first_record.reference_sequence_name()
+            || record.variant_start() != first_record.variant_start()
+            || record.reference_bases() != first_record.reference_bases()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "records are not at the same site",
+            ));
+        }
+
+        if record.samples().keys() != first_record.samples().keys()
+            || record.samples().values.len() != first_record.samples().values.len()
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "records have different sample keys",
+            ));
+        }
+    }
+
+    let mut record = first_record.clone();
+
+    for r in rest {
+        record.ids_mut().extend(r.ids().as_ref().iter().cloned());
+    }
+
+    *record.alternate_bases_mut() = records
+        .iter()
+        .flat_map(|r| r.alternate_bases().as_ref().iter().cloned())
+        .collect::<Vec<_>>()
+        .into();
+
+    *record.info_mut() = join_info(header, records)?;
+    *record.samples_mut() = join_samples(header, records)?;
+
+    Ok(record)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Cardinality {
+    AlternateBases,
+    ReferenceAlternateBases,
+    Genotypes,
+    Other,
+}
+
+impl From<info::Number> for Cardinality {
+    fn from(number: info::Number) -> Self {
+        match number {
+            info::Number::AlternateBases => Self::AlternateBases,
+            info::Number::ReferenceAlternateBases => Self::ReferenceAlternateBases,
+            info::Number::Samples => Self::Genotypes,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl From<format::Number> for Cardinality {
+    fn from(number: format::Number) -> Self {
+        match number {
+            format::Number::AlternateBases => Self::AlternateBases,
+            format::Number::ReferenceAlternateBases => Self::ReferenceAlternateBases,
+            format::Number::Samples => Self::Genotypes,
+            _ => Self::Other,
+        }
+    }
+}
+
+pub(crate) fn info_cardinality(header: &Header, key: &str) -> Cardinality {
+    header
+        .infos()
+        .get(key)
+        .map(|definition| Cardinality::from(definition.number()))
+        .unwrap_or(Cardinality::Other)
+}
+
+pub(crate) fn format_cardinality(header: &Header, key: &str) -> Cardinality {
+    if key == key::GENOTYPE {
+        return Cardinality::Other;
+    }
+
+    header
+        .formats()
+        .get(key)
+        .map(|definition| Cardinality::from(definition.number()))
+        .unwrap_or(Cardinality::Other)
+}
+
+// An untyped list of values, used to subset info and sample arrays alike.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Values {
+    Integer(Vec<Option<i32>>),
+    Float(Vec<Option<f32>>),
+    Character(Vec<Option<char>>),
+    String(Vec<Option<String>>),
+}
+
+impl Values {
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::Integer(values) => values.len(),
+            Self::Float(values) => values.len(),
+            Self::Character(values) => values.len(),
+            Self::String(values) => values.len(),
+        }
+    }
+
+    pub(crate) fn select(&self, indices: &[Option<usize>]) -> Self {
+        fn select<T: Clone>(values: &[Option<T>], indices: &[Option<usize>]) -> Vec<Option<T>> {
+            indices
+                .iter()
+                .map(|i| i.and_then(|j| values.get(j).cloned().flatten()))
+                .collect()
+        }
+
+        match self {
+            Self::Integer(values) => Self::Integer(select(values, indices)),
+            Self::Float(values) => Self::Float(select(values, indices)),
+            Self::Character(values) => Self::Character(select(values, indices)),
+            Self::String(values) => Self::String(select(values, indices)),
+        }
+    }
+
+    fn try_extend(&mut self, other: Self) -> io::Result<()> {
+        match (self, other) {
+            (Self::Integer(a), Self::Integer(b)) => a.extend(b),
+            (Self::Float(a), Self::Float(b)) => a.extend(b),
+            (Self::Character(a), Self::Character(b)) => a.extend(b),
+            (Self::String(a), Self::String(b)) => a.extend(b),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "value type mismatch",
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn from_info_value(value: &InfoValue) -> Option<Self> {
+        match value {
+            InfoValue::Integer(n) => Some(Self::Integer(vec![Some(*n)])),
+            InfoValue::Float(n) => Some(Self::Float(vec![Some(*n)])),
+            InfoValue::Flag => None,
+            InfoValue::Character(c) => Some(Self::Character(vec![Some(*c)])),
+            InfoValue::String(s) => Some(Self::String(vec![Some(s.clone())])),
+            InfoValue::Array(InfoArray::Integer(values)) => Some(Self::Integer(values.clone())),
+            InfoValue::Array(InfoArray::Float(values)) => Some(Self::Float(values.clone())),
+            InfoValue::Array(InfoArray::Character(values)) => Some(Self::Character(values.clone())),
+            InfoValue::Array(InfoArray::String(values)) => Some(Self::String(values.clone())),
+        }
+    }
+
+    pub(crate) fn from_sample_value(value: &SampleValue) -> Option<Self> {
+        match value {
+            SampleValue::Integer(n) => Some(Self::Integer(vec![Some(*n)])),
+            SampleValue::Float(n) => Some(Self::Float(vec![Some(*n)])),
+            SampleValue::Character(c) => Some(Self::Character(vec![Some(*c)])),
+            SampleValue::String(s) => Some(Self::String(vec![Some(s.clone())])),
+            SampleValue::Genotype(_) => None,
+            SampleValue::Array(SampleArray::Integer(values)) => Some(Self::Integer(values.clone())),
+            SampleValue::Array(SampleArray::Float(values)) => Some(Self::Float(values.clone())),
+            SampleValue::Array(SampleArray::Character(values)) => {
+                Some(Self::Character(values.clone()))
+            }
+            SampleValue::Array(SampleArray::String(values)) => Some(Self::String(values.clone())),
+        }
+    }
+}
+
+impl From<Values> for InfoValue {
+    fn from(values: Values) -> Self {
+        match values {
+            Values::Integer(values) => Self::Array(InfoArray::Integer(values)),
+            Values::Float(values) => Self::Array(InfoArray::Float(values)),
+            Values::Character(values) => Self::Array(InfoArray::Character(values)),
+            Values::String(values) => Self::Array(InfoArray::String(values)),
+        }
+    }
+}
+
+impl From<Values> for SampleValue {
+    fn from(values: Values) -> Self {
+        match values {
+            Values::Integer(values) => Self::Array(SampleArray::Integer(values)),
+            Values::Float(values) => Self::Array(SampleArray::Float(values)),
+            Values::Character(values) => Self::Array(SampleArray::Character(values)),
+            Values::String(values) => Self::Array(SampleArray::String(values)),
+        }
+    }
+}
+
+// § 1.6.2 "Genotype fields" (2023-08-23): the index of the genotype with sorted alleles
+// `k_1 <= ... <= k_P` is `sum_{m=1}^{P} C(k_m + m - 1, m)`.
+pub(crate) fn genotype_index(alleles: &[usize]) -> usize {
+    alleles
+        .iter()
+        .enumerate()
+        .map(|(i, &k)| binomial(k + i, i + 1))
+        .sum()
+}
+
+fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+// Returns the indices of the genotypes composed of only the reference allele and the given
+// allele, in order of increasing copies of the given allele.
+fn biallelic_genotype_indices(allele: usize, ploidy: usize) -> Vec<usize> {
+    (0..=ploidy)
+        .map(|n| {
+            let mut alleles = vec![0; ploidy - n];
+            alleles.resize(ploidy, allele);
+            genotype_index(&alleles)
+        })
+        .collect()
+}
+
+fn split_indices(cardinality: Cardinality, allele: usize, ploidy: usize) -> Option<Vec<usize>> {
+    match cardinality {
+        Cardinality::AlternateBases => Some(vec![allele - 1]),
+        Cardinality::ReferenceAlternateBases => Some(vec![0, allele]),
+        Cardinality::Genotypes => Some(biallelic_genotype_indices(allele, ploidy)),
+        Cardinality::Other => None,
+    }
+}
+
+fn split_info(header: &Header, info: &Info, allele: usize) -> Info {
+    info.as_ref()
+        .iter()
+        .map(|(key, value)| {
+            let cardinality = info_cardinality(header, key);
+
+            let value = value.as_ref().map(|v| {
+                split_indices(cardinality, allele, DEFAULT_PLOIDY)
+                    .and_then(|indices| {
+                        let indices: Vec<_> = indices.into_iter().map(Some).collect();
+                        Values::from_info_value(v).map(|values| values.select(&indices).into())
+                    })
+                    .unwrap_or_else(|| v.clone())
+            });
+
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+pub(crate) fn genotype(keys: &[&str], sample: &[Option<SampleValue>]) -> Option<Genotype> {
+    keys.iter()
+        .position(|k| *k == key::GENOTYPE)
+        .and_then(|i| sample.get(i))
+        .and_then(|value| match value {
+            Some(SampleValue::Genotype(genotype)) => Some(genotype.clone()),
+            _ => None,
+        })
+}
+
+pub(crate) fn ploidy(genotype: Option<&Genotype>) -> usize {
+    genotype
+        .map(|genotype| genotype.ploidy())
+        .filter(|&n| n > 0)
+        .unwrap_or(DEFAULT_PLOIDY)
+}
+
+fn split_genotype(genotype: &Genotype, allele: usize) -> Genotype {
+    genotype
+        .as_ref()
+        .iter()
+        .map(|a| {
+            let position = a.position().map(|i| usize::from(i == allele));
+            Allele::new(position, a.phasing())
+        })
+        .collect()
+}
+
+fn split_samples(header: &Header, samples: &Samples, allele: usize) -> Samples {
+    let keys: Vec<_> = samples.keys().as_ref().iter().map(|k| k.as_str()).collect();
+
+    let values = samples
+        .values
+        .iter()
+        .map(|sample| {
+            let genotype = genotype(&keys, sample);
+            let ploidy = ploidy(genotype.as_ref());
+
+            keys.iter()
+                .zip(sample)
+                .map(|(key, value)| {
+                    value.as_ref().map(|v| match v {
+                        SampleValue::Genotype(genotype) => {
+                            SampleValue::Genotype(split_genotype(genotype, allele))
+                        }
+                        _ => split_indices(format_cardinality(header, key), allele, ploidy)
+                            .and_then(|indices| {
+                                let indices: Vec<_> = indices.into_iter().map(Some).collect();
+                                Values::from_sample_value(v)
+                                    .map(|values| values.select(&indices).into())
+                            })
+                            .unwrap_or_else(|| v.clone()),
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    Samples::new(samples.keys().clone(), values)
+}
+
+// The start and length of a record's values in the concatenated values.
+type Range = (usize, usize);
+
+// Concatenates the values of all records and returns the offset of each record's values.
+//
+// Records that are missing the value contribute no values.
+fn concat(values: Vec<Option<Values>>) -> io::Result<Option<(Values, Vec<Range>)>> {
+    let mut joined: Option<Values> = None;
+    let mut ranges = Vec::with_capacity(values.len());
+
+    for v in values {
+        let start = joined.as_ref().map(|j| j.len()).unwrap_or_default();
+        let len = v.as_ref().map(|v| v.len()).unwrap_or_default();
+        ranges.push((start, len));
+
+        if let Some(v) = v {
+            match joined.as_mut() {
+                Some(j) => j.try_extend(v)?,
+                None => joined = Some(v),
+            }
+        }
+    }
+
+    Ok(joined.map(|j| (j, ranges)))
+}
+
+// Returns the indices into the concatenated values that build the joined values.
+fn join_indices(cardinality: Cardinality, ranges: &[Range], ploidy: usize) -> Vec<Option<usize>> {
+    let get = |(start, len): Range, i: usize| (i < len).then_some(start + i);
+
+    match cardinality {
+        Cardinality::AlternateBases => ranges.iter().map(|&r| get(r, 0)).collect(),
+        Cardinality::ReferenceAlternateBases => {
+            let reference = ranges.iter().find_map(|&r| get(r, 0));
+            std::iter::once(reference)
+                .chain(ranges.iter().map(|&r| get(r, 1)))
+                .collect()
+        }
+        Cardinality::Genotypes => {
+            let max_allele = ranges.len();
+            let genotype_count = genotype_index(&vec![max_allele; ploidy]) + 1;
+            let mut indices = vec![None; genotype_count];
+
+            for (i, &range) in ranges.iter().enumerate() {
+                let allele = i + 1;
+
+                for (n, j) in biallelic_genotype_indices(allele, ploidy)
+                    .into_iter()
+                    .enumerate()
+                {
+                    if indices[j].is_none() {
+                        indices[j] = get(range, n);
+                    }
+                }
+            }
+
+            indices
+        }
+        Cardinality::Other => Vec::new(),
+    }
+}
+
+fn join_info(header: &Header, records: &[RecordBuf]) -> io::Result<Info> {
+    let mut keys: Vec<&String> = Vec::new();
+
+    for record in records {
+        for key in record.info().keys() {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+
+    let mut info = Info::default();
+
+    for key in keys {
+        let cardinality = info_cardinality(header, key);
+
+        let values: Vec<_> = records
+            .iter()
+            .map(|r| r.info().get(key).flatten())
+            .collect();
+
+        let value = if cardinality == Cardinality::Other {
+            None
+        } else {
+            let values: Vec<_> = values
+                .iter()
+                .map(|v| v.and_then(Values::from_info_value))
+                .collect();
+
+            concat(values)?.map(|(joined, ranges)| {
+                let indices = join_indices(cardinality, &ranges, DEFAULT_PLOIDY);
+                InfoValue::from(joined.select(&indices))
+            })
+        };
+
+        let value = value.or_else(|| values.into_iter().flatten().next().cloned());
+        info.insert(key.clone(), value);
+    }
+
+    Ok(info)
+}
+
+fn join_genotypes(genotypes: &[Option<Genotype>]) -> Option<Genotype> {
+    let first = genotypes.iter().flatten().next()?;
+
+    let genotype = (0..first.as_ref().len())
+        .map(|k| {
+            let phasing = first.as_ref()[k].phasing();
+
+            let mut position = None;
+
+            for (i, genotype) in genotypes.iter().enumerate() {
+                match genotype
+                    .as_ref()
+                    .and_then(|g| g.as_ref().get(k)?.position())
+                {
+                    Some(0) => position = position.or(Some(0)),
+                    Some(_) => {
+                        position = Some(i + 1);
+                        break;
+                    }
+                    None => {}
+                }
+            }
+
+            Allele::new(position, phasing)
+        })
+        .collect();
+
+    Some(genotype)
+}
+
+fn join_samples(header: &Header, records: &[RecordBuf]) -> io::Result<Samples> {
+    let first_samples = records[0].samples();
+    let keys: Vec<_> = first_samples
+        .keys()
+        .as_ref()
+        .iter()
+        .map(|k| k.as_str())
+        .collect();
+
+    let mut values = Vec::with_capacity(first_samples.values.len());
+
+    for (i, _) in first_samples.values.iter().enumerate() {
+        let samples: Vec<_> = records.iter().map(|r| &r.samples().values[i]).collect();
+
+        let genotypes: Vec<_> = samples.iter().map(|s| genotype(&keys, s)).collect();
+        let genotype = join_genotypes(&genotypes);
+        let ploidy = ploidy(genotype.as_ref());
+
+        let mut sample = Vec::with_capacity(keys.len());
+
+        for (j, key) in keys.iter().enumerate() {
+            let cardinality = format_cardinality(header, key);
+            let record_values: Vec<_> = samples
+                .iter()
+                .map(|s| s.get(j).and_then(|v| v.as_ref()))
+                .collect();
+
+            let value = if *key == key::GENOTYPE {
+                genotype.clone().map(SampleValue::Genotype)
+            } else if cardinality == Cardinality::Other {
+                None
+            } else {
+                let record_values: Vec<_> = record_values
+                    .iter()
+                    .map(|v| v.and_then(Values::from_sample_value))
+                    .collect();
+
+                concat(record_values)?.map(|(joined, ranges)| {
+                    let indices = join_indices(cardinality, &ranges, ploidy);
+                    SampleValue::from(joined.select(&indices))
+                })
+            };
+
+            let value = if *key == key::GENOTYPE {
+                value
+            } else {
+                value.or_else(|| record_values.into_iter().flatten().next().cloned())
+            };
+
+            sample.push(value);
+        }
+
+        values.push(sample);
+    }
+
+    Ok(Samples::new(first_samples.keys().clone(), values))
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{header::record::value::Map, variant::record_buf::samples::Keys};
+
+    fn build_header() -> Header {
+        Header::builder()
+            .add_info(
+                "AC",
+                Map::<crate::header::record::value::map::Info>::new(
+                    info::Number::AlternateBases,
+                    info::Type::Integer,
+                    "",
+                ),
+            )
+            .add_info(
+                "DP",
+                Map::<crate::header::record::value::map::Info>::new(
+                    info::Number::Count(1),
+                    info::Type::Integer,
+                    "",
+                ),
+            )
+            .add_format(
+                key::GENOTYPE,
+                Map::<crate::header::record::value::map::Format>::new(
+                    format::Number::Count(1),
+                    format::Type::String,
+                    "",
+                ),
+            )
+            .add_format(
+                "AD",
+                Map::<crate::header::record::value::map::Format>::new(
+                    format::Number::ReferenceAlternateBases,
+                    format::Type::Integer,
+                    "",
+                ),
+            )
+            .add_format(
+                "PL",
+                Map::<crate::header::record::value::map::Format>::new(
+                    format::Number::Samples,
+                    format::Type::Integer,
+                    "",
+                ),
+            )
+            .add_sample_name("sample0")
+            .build()
+    }
+
+    #[test]
+    fn test_split_multiallelic() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from("AD"),
+            String::from("PL"),
+        ]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("G"),
+            ]))
+            .set_info(
+                [
+                    (
+                        String::from("AC"),
+                        Some(InfoValue::from(vec![Some(1), Some(1)])),
+                    ),
+                    (String::from("DP"), Some(InfoValue::Integer(13))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                keys.clone(),
+                vec![vec![
+                    Some(SampleValue::Genotype("1/2".parse()?)),
+                    Some(SampleValue::from(vec![Some(3), Some(5), Some(8)])),
+                    Some(SampleValue::from(vec![
+                        Some(0),
+                        Some(1),
+                        Some(2),
+                        Some(3),
+                        Some(4),
+                        Some(5),
+                    ])),
+                ]],
+            ))
+            .build();
+
+        let actual = split_multiallelic(&header, &record);
+
+        let expected = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .set_info(
+                    [
+                        (String::from("AC"), Some(InfoValue::from(vec![Some(1)]))),
+                        (String::from("DP"), Some(InfoValue::Integer(13))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![
+                        Some(SampleValue::Genotype("1/0".parse()?)),
+                        Some(SampleValue::from(vec![Some(3), Some(5)])),
+                        Some(SampleValue::from(vec![Some(0), Some(1), Some(2)])),
+                    ]],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .set_info(
+                    [
+                        (String::from("AC"), Some(InfoValue::from(vec![Some(1)]))),
+                        (String::from("DP"), Some(InfoValue::Integer(13))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![
+                        Some(SampleValue::Genotype("0/1".parse()?)),
+                        Some(SampleValue::from(vec![Some(3), Some(8)])),
+                        Some(SampleValue::from(vec![Some(0), Some(3), Some(5)])),
+                    ]],
+                ))
+                .build(),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .build();
+
+        assert_eq!(split_multiallelic(&header, &record), [record]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_biallelic() -> Result<(), Box<dyn std::error::Error>> {
+        let header = build_header();
+
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from("AD"),
+            String::from("PL"),
+        ]
+        .into_iter()
+        .collect();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                .set_info(
+                    [
+                        (String::from("AC"), Some(InfoValue::from(vec![Some(1)]))),
+                        (String::from("DP"), Some(InfoValue::Integer(13))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![
+                        Some(SampleValue::Genotype("1/0".parse()?)),
+                        Some(SampleValue::from(vec![Some(3), Some(5)])),
+                        Some(SampleValue::from(vec![Some(0), Some(1), Some(2)])),
+                    ]],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .set_info(
+                    [
+                        (String::from("AC"), Some(InfoValue::from(vec![Some(1)]))),
+                        (String::from("DP"), Some(InfoValue::Integer(13))),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![
+                        Some(SampleValue::Genotype("0/1".parse()?)),
+                        Some(SampleValue::from(vec![Some(3), Some(8)])),
+                        Some(SampleValue::from(vec![Some(0), Some(3), Some(5)])),
+                    ]],
+                ))
+                .build(),
+        ];
+
+        let actual = join_biallelic(&header, &records)?;
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("G"),
+            ]))
+            .set_info(
+                [
+                    (
+                        String::from("AC"),
+                        Some(InfoValue::from(vec![Some(1), Some(1)])),
+                    ),
+                    (String::from("DP"), Some(InfoValue::Integer(13))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                keys.clone(),
+                vec![vec![
+                    Some(SampleValue::Genotype("1/2".parse()?)),
+                    Some(SampleValue::from(vec![Some(3), Some(5), Some(8)])),
+                    Some(SampleValue::from(vec![
+                        Some(0),
+                        Some(1),
+                        Some(2),
+                        Some(3),
+                        None,
+                        Some(5),
+                    ])),
+                ]],
+            ))
+            .build();
+
+        assert_eq!(actual, expected);
+
+        assert!(matches!(
+            join_biallelic(&header, &[]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let mut record = records[1].clone();
+        *record.reference_bases_mut() = String::from("T");
+        assert!(matches!(
+            join_biallelic(&header, &[records[0].clone(), record]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_index() {
+        assert_eq!(genotype_index(&[0, 0]), 0);
+        assert_eq!(genotype_index(&[0, 1]), 1);
+        assert_eq!(genotype_index(&[1, 1]), 2);
+        assert_eq!(genotype_index(&[0, 2]), 3);
+        assert_eq!(genotype_index(&[1, 2]), 4);
+        assert_eq!(genotype_index(&[2, 2]), 5);
+        assert_eq!(genotype_index(&[2]), 2);
+        assert_eq!(genotype_index(&[0, 0, 1]), 1);
+    }
+}
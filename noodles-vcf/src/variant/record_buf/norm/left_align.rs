@@ -0,0 +1,335 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::super::{AlternateBases, RecordBuf};
+
+/// Left-aligns and trims the alleles of a record.
+///
+/// The reference bases of the record are first checked against the reference sequence. Then, while
+/// all alleles end with the same base, the last base is trimmed, extending the alleles to the left
+/// using the reference sequence when an allele would become empty. Finally, while all alleles
+/// have at least two bases and start with the same base, the first base is trimmed.
+///
+/// The variant start, reference bases, and alternate bases of the returned record are rewritten
+/// accordingly. All other fields are copied as is. Records with symbolic, breakend, missing, or
+/// overlapping deletion alternate bases are returned unchanged.
+///
+/// # Errors
+///
+/// An error is returned if the variant start or reference bases are missing, the reference
+/// sequence is not in the repository, or the reference bases do not match the reference sequence.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_fasta as fasta;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::record_buf::{norm, AlternateBases},
+/// };
+///
+/// let repository = fasta::Repository::new(vec![fasta::Record::new(
+///     fasta::record::Definition::new("sq0", None),
+///     fasta::record::Sequence::from(b"GCACACA".to_vec()),
+/// )]);
+///
+/// let record = vcf::variant::RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::try_from(5)?)
+///     .set_reference_bases("ACA")
+///     .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+///     .build();
+///
+/// let actual = norm::left_align(&repository, &record)?;
+///
+/// assert_eq!(actual.variant_start(), Some(Position::MIN));
+/// assert_eq!(actual.reference_bases(), "GCA");
+/// assert_eq!(actual.alternate_bases().as_ref(), [String::from("G")]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn left_align(repository: &fasta::Repository, record: &RecordBuf) -> io::Result<RecordBuf> {
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if alternate_bases.is_empty() || alternate_bases.iter().any(|a| !is_sequence(a)) {
+        return Ok(record.clone());
+    }
+
+    let variant_start = record
+        .variant_start()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing variant start"))?;
+
+    let name = record.reference_sequence_name();
+
+    let sequence = repository.get(name.as_bytes()).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("missing reference sequence: {name}"),
+        )
+    })??;
+
+    let reference_bases = record.reference_bases();
+
+    if reference_bases.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "missing reference bases",
+        ));
+    }
+
+    let end = variant_start
+        .checked_add(reference_bases.len() - 1)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid variant end"))?;
+
+    let expected_reference_bases = sequence.get(variant_start..=end).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "reference bases are out of range of the reference sequence",
+        )
+    })?;
+
+    if !reference_bases
+        .as_bytes()
+        .eq_ignore_ascii_case(expected_reference_bases)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "reference bases do not match the reference sequence",
+        ));
+    }
+
+    let mut start = usize::from(variant_start);
+
+    let mut alleles: Vec<Vec<u8>> = std::iter::once(reference_bases)
+        .chain(alternate_bases.iter().map(|a| a.as_str()))
+        .map(|a| a.as_bytes().to_vec())
+        .collect();
+
+    while have_same_last_base(&alleles) {
+        if alleles.iter().any(|a| a.len() == 1) {
+            if start == 1 {
+                break;
+            }
+
+            start -= 1;
+
+            // SAFETY: `start` is > 0.
+            let position = Position::new(start).unwrap();
+
+            let base = sequence.get(position).copied().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "variant start is out of range of the reference sequence",
+                )
+            })?;
+
+            for allele in &mut alleles {
+                allele.insert(0, base);
+            }
+        }
+
+        for allele in &mut alleles {
+            allele.pop();
+        }
+    }
+
+    while have_same_first_base(&alleles) {
+        for allele in &mut alleles {
+            allele.remove(0);
+        }
+
+        start += 1;
+    }
+
+    let mut alleles = alleles
+        .into_iter()
+        .map(|a| String::from_utf8(a).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)));
+
+    // SAFETY: `alleles` always includes the reference bases.
+    let reference_bases = alleles.next().unwrap()?;
+    let alternate_bases = alleles.collect::<io::Result<Vec<_>>>()?;
+
+    let mut left_aligned_record = record.clone();
+    *left_aligned_record.variant_start_mut() = Position::new(start);
+    *left_aligned_record.reference_bases_mut() = reference_bases;
+    *left_aligned_record.alternate_bases_mut() = AlternateBases::from(alternate_bases);
+
+    Ok(left_aligned_record)
+}
+
+fn is_sequence(allele: &str) -> bool {
+    !allele.is_empty() && allele.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn have_same_last_base(alleles: &[Vec<u8>]) -> bool {
+    let mut last_bases = alleles
+        .iter()
+        .map(|a| a.last().map(|b| b.to_ascii_uppercase()));
+
+    match last_bases.next() {
+        Some(Some(b)) => last_bases.all(|c| c == Some(b)),
+        _ => false,
+    }
+}
+
+fn have_same_first_base(alleles: &[Vec<u8>]) -> bool {
+    if alleles.iter().any(|a| a.len() < 2) {
+        return false;
+    }
+
+    let mut first_bases = alleles.iter().map(|a| a[0].to_ascii_uppercase());
+
+    match first_bases.next() {
+        Some(b) => first_bases.all(|c| c == b),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"GCACACATTTT".to_vec()),
+        )])
+    }
+
+    #[test]
+    fn test_left_align() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        // deletion in a repeat
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("ACA")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+        assert_eq!(
+            left_align(&repository, &record)?,
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("GCA")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .build()
+        );
+
+        // insertion in a repeat
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(7)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("ACA")]))
+            .build();
+        assert_eq!(
+            left_align(&repository, &record)?,
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(1)?)
+                .set_reference_bases("G")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("GCA")]))
+                .build()
+        );
+
+        // untrimmed deletion
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(7)?)
+            .set_reference_bases("ATT")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("AT")]))
+            .build();
+        assert_eq!(
+            left_align(&repository, &record)?,
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(7)?)
+                .set_reference_bases("AT")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+                .build()
+        );
+
+        // untrimmed substitution
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(6)?)
+            .set_reference_bases("CAT")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("CGT")]))
+            .build();
+        assert_eq!(
+            left_align(&repository, &record)?,
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(7)?)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .build()
+        );
+
+        // multiallelic
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(7)?)
+            .set_reference_bases("ATT")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("AT"),
+                String::from("A"),
+            ]))
+            .build();
+        assert_eq!(left_align(&repository, &record)?, record);
+
+        // already normalized
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("GCA")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+            .build();
+        assert_eq!(left_align(&repository, &record)?, record);
+
+        // symbolic
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+            .build();
+        assert_eq!(left_align(&repository, &record)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_left_align_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("TTT")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+        assert!(matches!(
+            left_align(&repository, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("ACA")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+        *record.reference_sequence_name_mut() = String::from("sq1");
+        assert!(matches!(
+            left_align(&repository, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -6,12 +6,73 @@ mod parser;
 pub use self::{allele::Allele, parser::ParseError};
 use crate::variant::record::samples::series::value::genotype::Phasing;
 
-use std::{io, str::FromStr};
+use std::{fmt, io, str::FromStr};
 
 /// A variant record samples genotype value.
+///
+/// A genotype is a list of alleles, each with a position (an index into the reference and
+/// alternate bases) and a phasing.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns the number of alleles in the genotype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert_eq!(genotype.ploidy(), 2);
+    ///
+    /// let genotype: Genotype = "1".parse()?;
+    /// assert_eq!(genotype.ploidy(), 1);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn ploidy(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether all alleles in the genotype are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "./.".parse()?;
+    /// assert!(genotype.is_missing());
+    ///
+    /// let genotype: Genotype = "0/.".parse()?;
+    /// assert!(!genotype.is_missing());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.0.iter().all(|allele| allele.is_missing())
+    }
+
+    /// Returns whether all alleles in the genotype are phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0|1".parse()?;
+    /// assert!(genotype.is_phased());
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(!genotype.is_phased());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.0
+            .iter()
+            .all(|allele| allele.phasing() == Phasing::Phased)
+    }
+}
+
 impl AsRef<[Allele]> for Genotype {
     fn as_ref(&self) -> &[Allele] {
         &self.0
@@ -24,6 +85,54 @@ impl AsMut<Vec<Allele>> for Genotype {
     }
 }
 
+impl fmt::Display for Genotype {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const PHASED: &str = "|";
+        const UNPHASED: &str = "/";
+        const MISSING: &str = ".";
+
+        fn fmt_phasing(f: &mut fmt::Formatter<'_>, phasing: Phasing) -> fmt::Result {
+            match phasing {
+                Phasing::Phased => f.write_str(PHASED),
+                Phasing::Unphased => f.write_str(UNPHASED),
+            }
+        }
+
+        let Some((first_allele, alleles)) = self.0.split_first() else {
+            return Ok(());
+        };
+
+        // § 1.6.2 "Genotype fields" (2023-08-23): "...the phasing of the first allele is implied
+        // by the phasing of the other alleles: if any is unphased, the first is unphased;
+        // otherwise, it is phased."
+        let implicit_first_phasing = if alleles
+            .iter()
+            .any(|allele| allele.phasing() == Phasing::Unphased)
+        {
+            Phasing::Unphased
+        } else {
+            Phasing::Phased
+        };
+
+        if first_allele.phasing() != implicit_first_phasing {
+            fmt_phasing(f, first_allele.phasing())?;
+        }
+
+        for (i, allele) in self.0.iter().enumerate() {
+            if i > 0 {
+                fmt_phasing(f, allele.phasing())?;
+            }
+
+            match allele.position() {
+                Some(n) => write!(f, "{n}")?,
+                None => f.write_str(MISSING)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl FromStr for Genotype {
     type Err = ParseError;
 
@@ -74,6 +183,39 @@ impl crate::variant::record::samples::series::value::Genotype for &Genotype {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_fmt() -> Result<(), ParseError> {
+        fn t(s: &str, expected: &str) -> Result<(), ParseError> {
+            let genotype: Genotype = s.parse()?;
+            assert_eq!(genotype.to_string(), expected);
+            Ok(())
+        }
+
+        t("0", "0")?;
+        t("0/1", "0/1")?;
+        t("0|1", "0|1")?;
+        t("./.", "./.")?;
+        t("0/1|2", "0/1|2")?;
+        t("|0/1/2", "|0/1/2")?;
+        t("/0|1", "/0|1")?;
+        t("/0", "/0")?;
+        t("|0", "0")?;
+
+        assert!(Genotype::default().to_string().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_missing() -> Result<(), ParseError> {
+        assert!(Genotype::default().is_missing());
+        assert!(".".parse::<Genotype>()?.is_missing());
+        assert!("./.".parse::<Genotype>()?.is_missing());
+        assert!(!"./1".parse::<Genotype>()?.is_missing());
+        assert!(!"0/1".parse::<Genotype>()?.is_missing());
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(
@@ -63,6 +63,26 @@ impl Allele {
         &mut self.position
     }
 
+    /// Returns whether the allele is missing, i.e., has no position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::series::value::genotype::Phasing,
+    ///     record_buf::samples::sample::value::genotype::Allele,
+    /// };
+    ///
+    /// let allele = Allele::new(None, Phasing::Unphased);
+    /// assert!(allele.is_missing());
+    ///
+    /// let allele = Allele::new(Some(0), Phasing::Unphased);
+    /// assert!(!allele.is_missing());
+    /// ```
+    pub fn is_missing(&self) -> bool {
+        self.position.is_none()
+    }
+
     /// Returns the phasing of the allele.
     ///
     /// # Examples
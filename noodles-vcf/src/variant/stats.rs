@@ -0,0 +1,405 @@
+//! Variant record statistics.
+//!
+//! This accumulates summary statistics over a stream of variant records, similar to `bcftools
+//! stats`.
+
+mod sample_stats;
+mod variant_class;
+
+use std::io;
+
+use self::variant_class::{is_transition, is_transversion};
+pub use self::{sample_stats::SampleStats, variant_class::VariantClass};
+use super::{
+    record::samples::{keys::key, series::Value},
+    record_buf::samples::sample::value::Genotype as GenotypeBuf,
+    Record,
+};
+use crate::Header;
+
+const DEFAULT_ALLELE_FREQUENCY_BIN_COUNT: usize = 10;
+
+/// Variant record statistics.
+///
+/// Variant class counts and transition/transversion counts are per alternate allele. Allele
+/// frequencies are calculated from the called genotypes of each record, and records without
+/// called genotypes are not included in the allele frequency histogram.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     variant::{stats::{Stats, VariantClass}, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::default();
+/// let mut stats = Stats::new(&header);
+///
+/// let record = RecordBuf::builder()
+///     .set_reference_sequence_name("sq0")
+///     .set_variant_start(Position::MIN)
+///     .set_reference_bases("A")
+///     .set_alternate_bases(vec![String::from("G"), String::from("AC")].into())
+///     .build();
+///
+/// stats.add(&header, &record)?;
+///
+/// assert_eq!(stats.record_count(), 1);
+/// assert_eq!(stats.variant_class_count(VariantClass::Snv), 1);
+/// assert_eq!(stats.variant_class_count(VariantClass::Indel), 1);
+/// assert_eq!(stats.transition_count(), 1);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Stats {
+    record_count: u64,
+    variant_class_counts: [u64; 5],
+    transition_count: u64,
+    transversion_count: u64,
+    sample_stats: Vec<SampleStats>,
+    allele_frequency_histogram: Vec<u64>,
+}
+
+impl Stats {
+    /// Creates variant record statistics for the samples in the given header.
+    ///
+    /// The allele frequency histogram has 10 bins.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::stats::Stats};
+    /// let header = vcf::Header::default();
+    /// let stats = Stats::new(&header);
+    /// assert_eq!(stats.allele_frequency_histogram().len(), 10);
+    /// ```
+    pub fn new(header: &Header) -> Self {
+        Self::with_allele_frequency_bin_count(header, DEFAULT_ALLELE_FREQUENCY_BIN_COUNT)
+    }
+
+    /// Creates variant record statistics with the given number of allele frequency bins.
+    ///
+    /// The bins evenly divide [0, 1]. The last bin includes an allele frequency of 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bin count is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::stats::Stats};
+    /// let header = vcf::Header::default();
+    /// let stats = Stats::with_allele_frequency_bin_count(&header, 20);
+    /// assert_eq!(stats.allele_frequency_histogram().len(), 20);
+    /// ```
+    pub fn with_allele_frequency_bin_count(header: &Header, bin_count: usize) -> Self {
+        assert!(bin_count > 0, "invalid allele frequency bin count");
+
+        Self {
+            record_count: 0,
+            variant_class_counts: [0; 5],
+            transition_count: 0,
+            transversion_count: 0,
+            sample_stats: vec![SampleStats::default(); header.sample_names().len()],
+            allele_frequency_histogram: vec![0; bin_count],
+        }
+    }
+
+    /// Returns the number of records added.
+    pub fn record_count(&self) -> u64 {
+        self.record_count
+    }
+
+    /// Returns the number of alternate alleles of the given variant class.
+    pub fn variant_class_count(&self, variant_class: VariantClass) -> u64 {
+        self.variant_class_counts[variant_class_index(variant_class)]
+    }
+
+    /// Returns the number of SNV transitions.
+    pub fn transition_count(&self) -> u64 {
+        self.transition_count
+    }
+
+    /// Returns the number of SNV transversions.
+    pub fn transversion_count(&self) -> u64 {
+        self.transversion_count
+    }
+
+    /// Returns the transition/transversion (ts/tv) ratio.
+    ///
+    /// This is `None` if there are no transversions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::stats::Stats};
+    /// let header = vcf::Header::default();
+    /// let stats = Stats::new(&header);
+    /// assert!(stats.ts_tv_ratio().is_none());
+    /// ```
+    pub fn ts_tv_ratio(&self) -> Option<f64> {
+        if self.transversion_count == 0 {
+            None
+        } else {
+            Some(self.transition_count as f64 / self.transversion_count as f64)
+        }
+    }
+
+    /// Returns the per-sample statistics, in header sample order.
+    pub fn sample_stats(&self) -> &[SampleStats] {
+        &self.sample_stats
+    }
+
+    /// Returns the allele frequency histogram.
+    ///
+    /// Each bin is the number of alternate alleles with an allele frequency in the bin.
+    pub fn allele_frequency_histogram(&self) -> &[u64] {
+        &self.allele_frequency_histogram
+    }
+
+    /// Adds a record to the statistics.
+    pub fn add(&mut self, header: &Header, record: &dyn Record) -> io::Result<()> {
+        let reference_bases: Vec<u8> =
+            record.reference_bases().iter().collect::<io::Result<_>>()?;
+
+        let reference_bases = String::from_utf8(reference_bases)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let alternate_bases: Vec<String> = record
+            .alternate_bases()
+            .iter()
+            .map(|result| result.map(String::from))
+            .collect::<io::Result<_>>()?;
+
+        for allele in &alternate_bases {
+            let variant_class = VariantClass::classify(&reference_bases, allele);
+            self.variant_class_counts[variant_class_index(variant_class)] += 1;
+
+            if variant_class == VariantClass::Snv {
+                let (a, b) = (reference_bases.as_bytes()[0], allele.as_bytes()[0]);
+
+                if is_transition(a, b) {
+                    self.transition_count += 1;
+                } else if is_transversion(a, b) {
+                    self.transversion_count += 1;
+                }
+            }
+        }
+
+        self.add_genotypes(header, record, alternate_bases.len())?;
+
+        self.record_count += 1;
+
+        Ok(())
+    }
+
+    fn add_genotypes(
+        &mut self,
+        header: &Header,
+        record: &dyn Record,
+        alternate_allele_count: usize,
+    ) -> io::Result<()> {
+        let samples = record.samples()?;
+
+        if samples.is_empty() {
+            return Ok(());
+        }
+
+        let mut allele_counts = vec![0u64; alternate_allele_count + 1];
+        let mut carriers = vec![None; alternate_allele_count + 1];
+
+        for (i, sample) in samples.iter().enumerate() {
+            let positions = match sample.get(header, key::GENOTYPE).transpose()? {
+                Some(Some(value)) => genotype_allele_positions(value)?,
+                _ => Vec::new(),
+            };
+
+            if positions.iter().all(Option::is_none) {
+                if let Some(sample_stats) = self.sample_stats.get_mut(i) {
+                    sample_stats.missing_count += 1;
+                }
+            }
+
+            for j in positions.into_iter().flatten() {
+                if let Some(n) = allele_counts.get_mut(j) {
+                    *n += 1;
+                    carriers[j] = Some(i);
+                }
+            }
+        }
+
+        let called_allele_count: u64 = allele_counts.iter().sum();
+
+        for (j, &allele_count) in allele_counts.iter().enumerate().skip(1) {
+            if allele_count == 1 {
+                if let Some(sample_stats) = carriers[j].and_then(|i| self.sample_stats.get_mut(i)) {
+                    sample_stats.singleton_count += 1;
+                }
+            }
+
+            if called_allele_count > 0 {
+                let allele_frequency = allele_count as f64 / called_allele_count as f64;
+                let bin_count = self.allele_frequency_histogram.len();
+                let i = ((allele_frequency * bin_count as f64) as usize).min(bin_count - 1);
+                self.allele_frequency_histogram[i] += 1;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn variant_class_index(variant_class: VariantClass) -> usize {
+    match variant_class {
+        VariantClass::Snv => 0,
+        VariantClass::Mnv => 1,
+        VariantClass::Indel => 2,
+        VariantClass::StructuralVariant => 3,
+        VariantClass::Other => 4,
+    }
+}
+
+fn genotype_allele_positions(value: Value<'_>) -> io::Result<Vec<Option<usize>>> {
+    match value {
+        Value::Genotype(genotype) => genotype
+            .iter()
+            .map(|result| result.map(|(position, _)| position))
+            .collect(),
+        Value::String(s) => {
+            let genotype: GenotypeBuf = s
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            Ok(genotype
+                .as_ref()
+                .iter()
+                .map(|allele| allele.position())
+                .collect())
+        }
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid genotype value",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::variant::{
+        record_buf::{
+            samples::{sample::Value as ValueBuf, Keys},
+            AlternateBases, Samples,
+        },
+        RecordBuf,
+    };
+
+    #[test]
+    fn test_add() -> io::Result<()> {
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let mut stats = Stats::with_allele_frequency_bin_count(&header, 4);
+
+        let keys: Keys = [String::from(key::GENOTYPE)].into_iter().collect();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![
+                        vec![Some(ValueBuf::from("0/1"))],
+                        vec![Some(ValueBuf::from("0/0"))],
+                    ],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("C")
+                .set_alternate_bases(AlternateBases::from(vec![
+                    String::from("A"),
+                    String::from("CT"),
+                ]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![
+                        vec![Some(ValueBuf::from("1/1"))],
+                        vec![Some(ValueBuf::from("0/2"))],
+                    ],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("AC")
+                .set_alternate_bases(AlternateBases::from(vec![
+                    String::from("GT"),
+                    String::from("<DEL>"),
+                ]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![vec![Some(ValueBuf::from("./."))], vec![None]],
+                ))
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("T")
+                .set_alternate_bases(AlternateBases::from(vec![
+                    String::from("C"),
+                    String::from("*"),
+                ]))
+                .set_samples(Samples::new(
+                    keys.clone(),
+                    vec![
+                        vec![Some(ValueBuf::from("0/0"))],
+                        vec![Some(ValueBuf::from("1/1"))],
+                    ],
+                ))
+                .build(),
+        ];
+
+        for record in &records {
+            stats.add(&header, record)?;
+        }
+
+        assert_eq!(stats.record_count(), 4);
+        assert_eq!(stats.variant_class_count(VariantClass::Snv), 3);
+        assert_eq!(stats.variant_class_count(VariantClass::Mnv), 1);
+        assert_eq!(stats.variant_class_count(VariantClass::Indel), 1);
+        assert_eq!(
+            stats.variant_class_count(VariantClass::StructuralVariant),
+            1
+        );
+        assert_eq!(stats.variant_class_count(VariantClass::Other), 1);
+
+        assert_eq!(stats.transition_count(), 2);
+        assert_eq!(stats.transversion_count(), 1);
+        assert_eq!(stats.ts_tv_ratio(), Some(2.0));
+
+        assert_eq!(
+            stats.sample_stats(),
+            [
+                SampleStats::new(1, 1), // record 0 (G) singleton; record 2 missing
+                SampleStats::new(1, 1), // record 1 (CT) singleton; record 2 missing
+            ]
+        );
+
+        // AFs: 0.25 (G); 0.5 (A), 0.25 (CT); none; 0.5 (C), 0 (*)
+        assert_eq!(stats.allele_frequency_histogram(), [1, 2, 2, 0]);
+
+        Ok(())
+    }
+}
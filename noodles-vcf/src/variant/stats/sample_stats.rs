@@ -0,0 +1,51 @@
+/// Per-sample variant record statistics.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct SampleStats {
+    pub(super) singleton_count: u64,
+    pub(super) missing_count: u64,
+}
+
+impl SampleStats {
+    /// Creates per-sample statistics.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::stats::SampleStats;
+    /// let sample_stats = SampleStats::new(2, 5);
+    /// ```
+    pub fn new(singleton_count: u64, missing_count: u64) -> Self {
+        Self {
+            singleton_count,
+            missing_count,
+        }
+    }
+
+    /// Returns the number of singletons.
+    ///
+    /// A singleton is an alternate allele that is called once across all samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::stats::SampleStats;
+    /// let sample_stats = SampleStats::new(2, 5);
+    /// assert_eq!(sample_stats.singleton_count(), 2);
+    /// ```
+    pub fn singleton_count(&self) -> u64 {
+        self.singleton_count
+    }
+
+    /// Returns the number of records with a missing genotype.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::stats::SampleStats;
+    /// let sample_stats = SampleStats::new(2, 5);
+    /// assert_eq!(sample_stats.missing_count(), 5);
+    /// ```
+    pub fn missing_count(&self) -> u64 {
+        self.missing_count
+    }
+}
@@ -0,0 +1,109 @@
+/// A variant class.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VariantClass {
+    /// A single-nucleotide variant (SNV), e.g., `A>G`.
+    Snv,
+    /// A multi-nucleotide variant (MNV), e.g., `AC>GT`.
+    Mnv,
+    /// An insertion or deletion, e.g., `A>AC` or `AC>A`.
+    Indel,
+    /// A structural variant, i.e., a symbolic allele (e.g., `<DEL>`) or a breakend.
+    StructuralVariant,
+    /// Any other allele, e.g., the spanning deletion allele (`*`) or the unspecified allele
+    /// (`<*>` or `<NON_REF>`).
+    Other,
+}
+
+impl VariantClass {
+    /// Classifies an alternate allele against the reference bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::stats::VariantClass;
+    ///
+    /// assert_eq!(VariantClass::classify("A", "G"), VariantClass::Snv);
+    /// assert_eq!(VariantClass::classify("AC", "GT"), VariantClass::Mnv);
+    /// assert_eq!(VariantClass::classify("A", "AC"), VariantClass::Indel);
+    /// assert_eq!(VariantClass::classify("A", "<DEL>"), VariantClass::StructuralVariant);
+    /// assert_eq!(VariantClass::classify("A", "*"), VariantClass::Other);
+    /// ```
+    pub fn classify(reference_bases: &str, allele: &str) -> Self {
+        const UNSPECIFIED_ALLELES: [&str; 2] = ["<*>", "<NON_REF>"];
+
+        if allele == "*" || allele == "." || UNSPECIFIED_ALLELES.contains(&allele) {
+            Self::Other
+        } else if allele.starts_with('<') || allele.contains(['[', ']']) {
+            Self::StructuralVariant
+        } else if reference_bases.len() != allele.len() {
+            Self::Indel
+        } else if allele.len() == 1 {
+            Self::Snv
+        } else {
+            Self::Mnv
+        }
+    }
+}
+
+/// Returns whether a SNV is a transition, i.e., a purine to purine (`A<->G`) or pyrimidine to
+/// pyrimidine (`C<->T`) substitution.
+pub(super) fn is_transition(reference_base: u8, alternate_base: u8) -> bool {
+    matches!(
+        (
+            reference_base.to_ascii_uppercase(),
+            alternate_base.to_ascii_uppercase()
+        ),
+        (b'A', b'G') | (b'G', b'A') | (b'C', b'T') | (b'T', b'C')
+    )
+}
+
+/// Returns whether a SNV is a transversion, i.e., a purine to pyrimidine substitution or vice
+/// versa.
+pub(super) fn is_transversion(reference_base: u8, alternate_base: u8) -> bool {
+    const BASES: &[u8] = b"ACGT";
+
+    let a = reference_base.to_ascii_uppercase();
+    let b = alternate_base.to_ascii_uppercase();
+
+    BASES.contains(&a) && BASES.contains(&b) && a != b && !is_transition(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify() {
+        assert_eq!(VariantClass::classify("A", "G"), VariantClass::Snv);
+        assert_eq!(VariantClass::classify("AC", "GT"), VariantClass::Mnv);
+        assert_eq!(VariantClass::classify("A", "AC"), VariantClass::Indel);
+        assert_eq!(VariantClass::classify("AC", "A"), VariantClass::Indel);
+        assert_eq!(
+            VariantClass::classify("A", "<DUP>"),
+            VariantClass::StructuralVariant
+        );
+        assert_eq!(
+            VariantClass::classify("A", "A[sq1:5["),
+            VariantClass::StructuralVariant
+        );
+        assert_eq!(VariantClass::classify("A", "*"), VariantClass::Other);
+        assert_eq!(VariantClass::classify("A", "<*>"), VariantClass::Other);
+        assert_eq!(
+            VariantClass::classify("A", "<NON_REF>"),
+            VariantClass::Other
+        );
+    }
+
+    #[test]
+    fn test_is_transition_and_is_transversion() {
+        assert!(is_transition(b'A', b'G'));
+        assert!(is_transition(b'c', b't'));
+        assert!(!is_transition(b'A', b'C'));
+
+        assert!(is_transversion(b'A', b'C'));
+        assert!(is_transversion(b'g', b't'));
+        assert!(!is_transversion(b'A', b'G'));
+        assert!(!is_transversion(b'A', b'N'));
+        assert!(!is_transversion(b'A', b'A'));
+    }
+}
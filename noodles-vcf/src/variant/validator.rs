@@ -0,0 +1,437 @@
+//! Variant record validator.
+//!
+//! This checks a stream of records for consistency with a header, similar to `vcf-validator`.
+
+use std::{error, fmt};
+
+use indexmap::IndexSet;
+use noodles_core::Position;
+
+use super::{
+    record_buf::builder::{validate_record, BuildError},
+    RecordBuf,
+};
+use crate::Header;
+
+/// A variant record validation diagnostic kind.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiagnosticKind {
+    /// The record is inconsistent with the header.
+    Invalid(BuildError),
+    /// The record is out of order, i.e., its reference sequence is before the previous record's
+    /// reference sequence in the header contig order, or it is on the same reference sequence and
+    /// starts before the previous record.
+    Unsorted,
+}
+
+impl fmt::Display for DiagnosticKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(e) => e.fmt(f),
+            Self::Unsorted => f.write_str("unsorted record"),
+        }
+    }
+}
+
+/// A variant record validation diagnostic.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    record_index: usize,
+    reference_sequence_name: String,
+    variant_start: Option<Position>,
+    kind: DiagnosticKind,
+}
+
+impl Diagnostic {
+    /// Returns the 0-based index of the record in the validated stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::{validator::Validator, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    ///
+    /// let record = RecordBuf::builder().set_reference_sequence_name("sq0").build();
+    /// let diagnostics = validator.validate(&record);
+    /// assert_eq!(diagnostics[0].record_index(), 0);
+    /// ```
+    pub fn record_index(&self) -> usize {
+        self.record_index
+    }
+
+    /// Returns the reference sequence name of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::{validator::Validator, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    ///
+    /// let record = RecordBuf::builder().set_reference_sequence_name("sq0").build();
+    /// let diagnostics = validator.validate(&record);
+    /// assert_eq!(diagnostics[0].reference_sequence_name(), "sq0");
+    /// ```
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the variant start position of the record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::{validator::Validator, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    ///
+    /// let record = RecordBuf::builder().set_reference_sequence_name("sq0").build();
+    /// let diagnostics = validator.validate(&record);
+    /// assert_eq!(diagnostics[0].variant_start(), Some(Position::MIN));
+    /// ```
+    pub fn variant_start(&self) -> Option<Position> {
+        self.variant_start
+    }
+
+    /// Returns the diagnostic kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record_buf::builder::BuildError,
+    ///         validator::{DiagnosticKind, Validator},
+    ///         RecordBuf,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    ///
+    /// let record = RecordBuf::builder().set_reference_sequence_name("sq0").build();
+    /// let diagnostics = validator.validate(&record);
+    ///
+    /// assert_eq!(
+    ///     diagnostics[0].kind(),
+    ///     &DiagnosticKind::Invalid(BuildError::MissingReferenceBases)
+    /// );
+    /// ```
+    pub fn kind(&self) -> &DiagnosticKind {
+        &self.kind
+    }
+}
+
+impl error::Error for Diagnostic {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match &self.kind {
+            DiagnosticKind::Invalid(e) => Some(e),
+            DiagnosticKind::Unsorted => None,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "record {}", self.record_index + 1)?;
+
+        if !self.reference_sequence_name.is_empty() {
+            write!(f, " ({}", self.reference_sequence_name)?;
+
+            if let Some(position) = self.variant_start {
+                write!(f, ":{position}")?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        write!(f, ": {}", self.kind)
+    }
+}
+
+/// A variant record validator.
+///
+/// A validator checks each record against the header and the previously validated record. Each
+/// record is checked for
+///
+///   * a reference sequence name and reference bases;
+///   * a reference sequence defined in the header contigs, if any are declared, and a variant end
+///     within the contig length;
+///   * filters, info fields, and samples keys defined in the header;
+///   * info and sample values that match the types and numbers of their definitions;
+///   * the number of samples matching the number of header sample names; and
+///   * sort order by reference sequence, in header contig declaration order, and then position.
+///     Reference sequences not declared in the header are ordered by first appearance.
+///
+/// Unlike [`super::record_buf::Builder::try_build`], all issues of a record are reported.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::{
+///     self as vcf,
+///     header::record::value::{map::Contig, Map},
+///     variant::{validator::{DiagnosticKind, Validator}, RecordBuf},
+/// };
+///
+/// let header = vcf::Header::builder()
+///     .add_contig("sq0", Map::<Contig>::new())
+///     .add_contig("sq1", Map::<Contig>::new())
+///     .build();
+///
+/// let mut validator = Validator::new(&header);
+///
+/// let build_record = |reference_sequence_name| {
+///     RecordBuf::builder()
+///         .set_reference_sequence_name(reference_sequence_name)
+///         .set_variant_start(Position::MIN)
+///         .set_reference_bases("A")
+///         .build()
+/// };
+///
+/// assert!(validator.validate(&build_record("sq1")).is_empty());
+///
+/// let diagnostics = validator.validate(&build_record("sq0"));
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(diagnostics[0].kind(), &DiagnosticKind::Unsorted);
+/// ```
+#[derive(Debug)]
+pub struct Validator<'h> {
+    header: &'h Header,
+    record_count: usize,
+    reference_sequence_names: IndexSet<String>,
+    last_key: Option<(usize, Option<Position>)>,
+}
+
+impl<'h> Validator<'h> {
+    /// Creates a variant record validator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::validator::Validator};
+    /// let header = vcf::Header::default();
+    /// let validator = Validator::new(&header);
+    /// ```
+    pub fn new(header: &'h Header) -> Self {
+        Self {
+            header,
+            record_count: 0,
+            reference_sequence_names: header.contigs().keys().cloned().collect(),
+            last_key: None,
+        }
+    }
+
+    /// Returns the number of validated records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::{validator::Validator, RecordBuf}};
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    /// assert_eq!(validator.record_count(), 0);
+    ///
+    /// validator.validate(&RecordBuf::default());
+    /// assert_eq!(validator.record_count(), 1);
+    /// ```
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Validates a record and returns its diagnostics.
+    ///
+    /// An empty list means the record is consistent with the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record_buf::{builder::BuildError, Filters},
+    ///         validator::{DiagnosticKind, Validator},
+    ///         RecordBuf,
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    /// let mut validator = Validator::new(&header);
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_reference_bases("A")
+    ///     .set_filters([String::from("q10"), String::from("s50")].into_iter().collect())
+    ///     .build();
+    ///
+    /// let diagnostics = validator.validate(&record);
+    ///
+    /// let kinds: Vec<_> = diagnostics.iter().map(|d| d.kind().clone()).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [
+    ///         DiagnosticKind::Invalid(BuildError::UndefinedFilter(String::from("q10"))),
+    ///         DiagnosticKind::Invalid(BuildError::UndefinedFilter(String::from("s50"))),
+    ///     ]
+    /// );
+    /// ```
+    pub fn validate(&mut self, record: &RecordBuf) -> Vec<Diagnostic> {
+        let mut errors = Vec::new();
+        validate_record(self.header, record, &mut errors);
+
+        let mut kinds: Vec<_> = errors.into_iter().map(DiagnosticKind::Invalid).collect();
+
+        if !self.is_sorted(record) {
+            kinds.push(DiagnosticKind::Unsorted);
+        }
+
+        let record_index = self.record_count;
+        self.record_count += 1;
+
+        kinds
+            .into_iter()
+            .map(|kind| Diagnostic {
+                record_index,
+                reference_sequence_name: record.reference_sequence_name().into(),
+                variant_start: record.variant_start(),
+                kind,
+            })
+            .collect()
+    }
+
+    fn is_sorted(&mut self, record: &RecordBuf) -> bool {
+        let name = record.reference_sequence_name();
+
+        let i = match self.reference_sequence_names.get_index_of(name) {
+            Some(i) => i,
+            None => self.reference_sequence_names.insert_full(name.into()).0,
+        };
+
+        let key = (i, record.variant_start());
+
+        match self.last_key.replace(key) {
+            Some(last_key) => key >= last_key,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{map::Contig, Map};
+
+    fn kinds(diagnostics: &[Diagnostic]) -> Vec<DiagnosticKind> {
+        diagnostics.iter().map(|d| d.kind().clone()).collect()
+    }
+
+    #[test]
+    fn test_validate() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::builder().set_length(8).build()?)
+            .build();
+
+        let mut validator = Validator::new(&header);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .build();
+        assert!(validator.validate(&record).is_empty());
+        assert!(validator.validate(&record).is_empty());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("A")
+            .build();
+        let diagnostics = validator.validate(&record);
+        assert_eq!(kinds(&diagnostics), [DiagnosticKind::Unsorted]);
+        assert_eq!(diagnostics[0].record_index(), 2);
+        assert_eq!(
+            diagnostics[0].to_string(),
+            "record 3 (sq0:5): unsorted record"
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(13)?)
+            .set_reference_bases("A")
+            .build();
+        assert_eq!(
+            kinds(&validator.validate(&record)),
+            [DiagnosticKind::Invalid(BuildError::InvalidVariantEnd {
+                variant_end: 13,
+                reference_sequence_length: 8
+            })]
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq2")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("A")
+            .build();
+        assert_eq!(
+            kinds(&validator.validate(&record)),
+            [DiagnosticKind::Invalid(
+                BuildError::UndefinedReferenceSequenceName(String::from("sq2"))
+            )]
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("A")
+            .build();
+        assert_eq!(
+            kinds(&validator.validate(&record)),
+            [DiagnosticKind::Unsorted]
+        );
+
+        assert_eq!(validator.record_count(), 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_without_contigs() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::default();
+        let mut validator = Validator::new(&header);
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("A")
+            .build();
+        assert!(validator.validate(&record).is_empty());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(1)?)
+            .set_reference_bases("A")
+            .build();
+        assert!(validator.validate(&record).is_empty());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(2)?)
+            .set_reference_bases("A")
+            .build();
+        assert_eq!(
+            kinds(&validator.validate(&record)),
+            [DiagnosticKind::Unsorted]
+        );
+
+        Ok(())
+    }
+}